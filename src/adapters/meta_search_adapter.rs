@@ -425,6 +425,13 @@ mod tests {
             search_type: crate::tools::search::SearchType::Title,
             limit: 10,
             offset: 0,
+            require_pdf: false,
+            verify: false,
+            min_year: None,
+            abstract_required: false,
+            venue: None,
+            year: None,
+            abstract_keywords: Vec::new(),
         };
 
         let key1 = MetaSearchAdapter::generate_cache_key(&input);
@@ -447,6 +454,13 @@ mod tests {
             search_type: crate::tools::search::SearchType::Title,
             limit: 10,
             offset: 0,
+            require_pdf: false,
+            verify: false,
+            min_year: None,
+            abstract_required: false,
+            venue: None,
+            year: None,
+            abstract_keywords: Vec::new(),
         };
 
         let result = SearchResult {
@@ -194,7 +194,9 @@ impl MetadataServicePort for PdfMetadataAdapter {
             use_cache: true,
             validate_external: false,
             extract_references: false,
+            extract_captions: false,
             batch_files: None,
+            output_format: Default::default(),
         };
 
         let start_time = SystemTime::now();
@@ -390,9 +392,12 @@ mod tests {
             doi: Some("10.1234/test".to_string()),
             keywords: vec![],
             references: vec![],
+            captions: vec![],
             volume: None,
             issue: None,
             pages: None,
+            page_count: None,
+            word_count: None,
             confidence_score: 0.8,
             metadata_source: "pdf".to_string(),
             extracted_at: SystemTime::now(),
@@ -0,0 +1,192 @@
+//! Cleanup and comparison of abstracts reported by providers.
+//!
+//! Publisher abstracts often arrive with an "Abstract:" label in front and
+//! copyright or license statements at the end. [`clean_abstract`] strips
+//! both so merged results show only the abstract itself.
+//! [`AbstractSignature`] estimates how alike two abstracts are, to spot the
+//! same paper listed under different DOIs (e.g. a preprint and its journal
+//! version).
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+/// Labels publishers put in front of the abstract text
+const LEADING_LABELS: &[&str] = &["abstract", "summary"];
+
+/// Separators that mark a leading word as a label rather than prose
+const LABEL_SEPARATORS: &[char] = &[':', '.', '-', '–', '—'];
+
+/// Openings of trailing license sentences
+const LICENSE_PREFIXES: &[&str] = &[
+    "published by ",
+    "this is an open access article",
+    "this article is an open access article",
+    "this article is licensed under",
+    "this article is distributed under",
+    "licensed under a creative commons",
+];
+
+/// Remove a leading "Abstract"/"Summary" label and trailing copyright and
+/// license sentences. Text that would be left empty is returned trimmed but
+/// otherwise unchanged.
+#[must_use]
+pub fn clean_abstract(raw: &str) -> String {
+    let text = strip_trailing_boilerplate(strip_leading_label(raw.trim()));
+    if text.is_empty() {
+        raw.trim().to_string()
+    } else {
+        text.to_string()
+    }
+}
+
+/// Strip a label such as "Abstract:", "ABSTRACT" or "Summary." Prose that
+/// merely starts with the word ("Abstract syntax trees ...") is kept.
+fn strip_leading_label(text: &str) -> &str {
+    for label in LEADING_LABELS {
+        let Some(head) = text.get(..label.len()) else {
+            continue;
+        };
+        if !head.eq_ignore_ascii_case(label) {
+            continue;
+        }
+
+        let rest = &text[label.len()..];
+        if let Some(stripped) = rest.strip_prefix(LABEL_SEPARATORS) {
+            return stripped.trim_start();
+        }
+        let on_own_line = rest.starts_with(&['\n', '\r'][..]);
+        let upper_case =
+            head.chars().all(|c| c.is_ascii_uppercase()) && rest.starts_with(char::is_whitespace);
+        if on_own_line || upper_case || rest.is_empty() {
+            return rest.trim_start();
+        }
+    }
+    text
+}
+
+/// Drop trailing sentences that are copyright or license statements
+fn strip_trailing_boilerplate(mut text: &str) -> &str {
+    loop {
+        text = text.trim_end();
+        let body = text.trim_end_matches('.');
+        let start = body
+            .rfind(". ")
+            .map(|i| i + 2)
+            .max(body.rfind('\n').map(|i| i + 1))
+            .unwrap_or(0);
+        if start == 0 || !is_boilerplate(&text[start..]) {
+            return text;
+        }
+        text = &text[..start];
+    }
+}
+
+fn is_boilerplate(sentence: &str) -> bool {
+    let sentence = sentence.trim().to_ascii_lowercase();
+    if sentence.starts_with('©') || sentence.starts_with("(c) ") {
+        return true;
+    }
+    if let Some(rest) = sentence.strip_prefix("copyright") {
+        let rest = rest.trim_start();
+        if rest.starts_with('©')
+            || rest.starts_with("(c)")
+            || rest.starts_with(|c: char| c.is_ascii_digit())
+        {
+            return true;
+        }
+    }
+    sentence.contains("all rights reserved")
+        || LICENSE_PREFIXES
+            .iter()
+            .any(|prefix| sentence.starts_with(prefix))
+}
+
+/// Hash functions in an [`AbstractSignature`]
+const MINHASH_PERMUTATIONS: usize = 64;
+
+/// Words per shingle
+const SHINGLE_WORDS: usize = 3;
+
+/// Shorter abstracts are too generic to identify a paper by
+const MIN_SIGNATURE_WORDS: usize = 20;
+
+/// `MinHash` signature over the word 3-grams of an abstract. Comparing two
+/// signatures estimates the Jaccard similarity of the abstracts' 3-gram sets.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AbstractSignature([u64; MINHASH_PERMUTATIONS]);
+
+impl AbstractSignature {
+    /// Signature of `abstract_text`, ignoring case and punctuation. `None`
+    /// for abstracts under 20 words.
+    #[must_use]
+    pub fn new(abstract_text: &str) -> Option<Self> {
+        let words: Vec<String> = abstract_text
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .map(str::to_lowercase)
+            .collect();
+        if words.len() < MIN_SIGNATURE_WORDS {
+            return None;
+        }
+
+        let mut minimums = [u64::MAX; MINHASH_PERMUTATIONS];
+        for shingle in words.windows(SHINGLE_WORDS) {
+            let mut hasher = DefaultHasher::new();
+            shingle.hash(&mut hasher);
+            let base = hasher.finish();
+            for (seed, minimum) in (0u64..).zip(minimums.iter_mut()) {
+                *minimum = (*minimum).min(mix(base ^ seed.wrapping_mul(0x9E37_79B9_7F4A_7C15)));
+            }
+        }
+        Some(Self(minimums))
+    }
+
+    /// Estimated similarity of the two abstracts, from 0.0 to 1.0
+    #[must_use]
+    pub fn similarity(&self, other: &Self) -> f64 {
+        let matching = self
+            .0
+            .iter()
+            .zip(other.0.iter())
+            .filter(|(a, b)| a == b)
+            .count();
+        #[allow(clippy::cast_precision_loss)]
+        let similarity = matching as f64 / MINHASH_PERMUTATIONS as f64;
+        similarity
+    }
+}
+
+/// `SplitMix64` finalizer, turning one shingle hash into a family of
+/// independent-looking hashes
+const fn mix(mut x: u64) -> u64 {
+    x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    x ^ (x >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clean_abstract_strips_label_and_copyright() {
+        let raw = "Abstract: We present a method for protein folding. It is fast. \
+                   © 2023 Elsevier Ltd. All rights reserved.";
+        assert_eq!(
+            clean_abstract(raw),
+            "We present a method for protein folding. It is fast."
+        );
+
+        let raw = "ABSTRACT\nDeep networks generalize.\n\
+                   Copyright © 2021 The Authors. Published by Elsevier B.V. \
+                   This is an open access article under the CC BY license.";
+        assert_eq!(clean_abstract(raw), "Deep networks generalize.");
+    }
+
+    #[test]
+    fn test_clean_abstract_keeps_prose() {
+        let raw = "Abstract syntax trees are widely used. Copyright law is not discussed.";
+        assert_eq!(clean_abstract(raw), raw);
+        assert_eq!(clean_abstract("© 2020 IEEE."), "© 2020 IEEE.");
+    }
+}
@@ -0,0 +1,266 @@
+//! Canonical formatting for author names reported by providers.
+//!
+//! Providers spell the same author as "J. Smith", "Smith, John", "Smith JA"
+//! or "JOHN SMITH". [`normalize_author_name`] rewrites all of these into one
+//! form so merged results and generated citations are consistent.
+
+use crate::config::AuthorNameFormat;
+
+/// Lowercase particles that belong to the family name ("van Beethoven")
+const NAME_PARTICLES: &[&str] = &[
+    "van", "von", "der", "den", "de", "del", "della", "di", "da", "du", "la", "le", "dos", "das",
+    "ter", "ten", "bin", "ibn",
+];
+
+/// Generational suffixes kept after the family name
+const NAME_SUFFIXES: &[&str] = &["jr", "jr.", "sr", "sr.", "ii", "iii", "iv"];
+
+/// Rewrite an author name into `format`. Names that cannot be split into
+/// given and family names are returned with whitespace tidied only.
+///
+/// A trailing capitalized token is only read as MEDLINE initials ("Smith
+/// JR") when it cannot be a family name: a single letter or letters without
+/// vowels. "Wei LI" is Wei Li; use [`normalize_medline_author_name`] for
+/// sources that report names as "Smith JA".
+#[must_use]
+pub fn normalize_author_name(raw: &str, format: AuthorNameFormat) -> String {
+    normalize(raw, format, false)
+}
+
+/// Like [`normalize_author_name`], for sources that report names in MEDLINE
+/// style, where a trailing run of up to three capitals is always initials
+#[must_use]
+pub fn normalize_medline_author_name(raw: &str, format: AuthorNameFormat) -> String {
+    normalize(raw, format, true)
+}
+
+fn normalize(raw: &str, format: AuthorNameFormat, medline: bool) -> String {
+    let raw = raw.split_whitespace().collect::<Vec<_>>().join(" ");
+    let raw = raw.trim_matches(|c: char| c == ',' || c == ';' || c.is_whitespace());
+    if raw.is_empty() {
+        return String::new();
+    }
+
+    let (given, family) = split_name(raw, medline);
+    let given: Vec<String> = given.into_iter().map(format_given).collect();
+    let family = family
+        .into_iter()
+        .map(format_family)
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    if given.is_empty() {
+        return family;
+    }
+    let given = given.join(" ");
+    match format {
+        AuthorNameFormat::FirstLast => format!("{given} {family}"),
+        AuthorNameFormat::LastFirst => format!("{family}, {given}"),
+    }
+}
+
+/// Split a tidied name into given-name parts and family-name parts. With
+/// `medline`, a trailing run of capitals is read as initials even when it
+/// could be a family name.
+fn split_name(name: &str, medline: bool) -> (Vec<&str>, Vec<&str>) {
+    // Capitals can only be read as initials ("JA") when the rest of the name
+    // is not also in capitals ("WEI LI")
+    let bare_initials = name.chars().any(char::is_lowercase);
+
+    // "Smith, John" / "Smith, J. A." / "Smith, Jr., John" is ambiguous, so
+    // only a single comma is treated as a family/given separator
+    let mut comma_parts = name.split(',').map(str::trim);
+    if let (Some(family), Some(given), None) =
+        (comma_parts.next(), comma_parts.next(), comma_parts.next())
+    {
+        if !given.is_empty() && !is_suffix(given) {
+            return (
+                given
+                    .split(' ')
+                    .flat_map(|part| split_initials(part, bare_initials))
+                    .collect(),
+                family.split(' ').collect(),
+            );
+        }
+    }
+
+    let parts: Vec<&str> = name.split([' ', ',']).filter(|p| !p.is_empty()).collect();
+    if parts.len() < 2 {
+        return (Vec::new(), parts);
+    }
+
+    // MEDLINE style "Smith JA": trailing run of capital initials. Outside
+    // MEDLINE sources "Wei LI" and "John DOE" end in a family name, so only
+    // runs that cannot be one are read as initials.
+    let last = parts[parts.len() - 1];
+    if last.len() <= 3
+        && last.chars().all(|c| c.is_ascii_uppercase())
+        && (medline || last.len() == 1 || !last.contains(['A', 'E', 'I', 'O', 'U']))
+        && parts[..parts.len() - 1]
+            .iter()
+            .any(|p| p.chars().any(char::is_lowercase))
+    {
+        return (
+            split_initials(last, bare_initials),
+            parts[..parts.len() - 1].to_vec(),
+        );
+    }
+
+    // "John Smith", "J. R. R. Tolkien", "Ludwig van Beethoven", "John Smith Jr."
+    let mut family_start = parts.len() - 1;
+    if is_suffix(parts[family_start]) && family_start > 1 {
+        family_start -= 1;
+    }
+    while family_start > 1 && is_particle(parts[family_start - 1]) {
+        family_start -= 1;
+    }
+    let (given, family) = parts.split_at(family_start);
+    (
+        given
+            .iter()
+            .flat_map(|part| split_initials(part, bare_initials))
+            .collect(),
+        family.to_vec(),
+    )
+}
+
+/// "J.A." (and "JA" when `bare` is set) become separate initials; other
+/// parts are left whole
+fn split_initials(part: &str, bare: bool) -> Vec<&str> {
+    let letters = part.trim_end_matches('.');
+    if letters.contains('.') && letters.split('.').all(|l| l.chars().count() == 1) {
+        return letters.split('.').collect();
+    }
+    if bare && (2..=3).contains(&part.len()) && part.chars().all(|c| c.is_ascii_uppercase()) {
+        return (0..part.len()).map(|i| &part[i..=i]).collect();
+    }
+    vec![part]
+}
+
+fn format_given(part: &str) -> String {
+    let letters = part.trim_end_matches('.');
+    if letters.chars().count() == 1 {
+        return format!("{}.", letters.to_uppercase());
+    }
+    fix_case(part)
+}
+
+fn format_family(part: &str) -> String {
+    if is_particle(part) || is_suffix(part) {
+        return part.to_string();
+    }
+    fix_case(part)
+}
+
+/// Title-case words given entirely in capitals or lowercase; mixed case
+/// ("McDonald", "DiCaprio") is kept as written
+fn fix_case(word: &str) -> String {
+    let has_upper = word.chars().any(char::is_uppercase);
+    let has_lower = word.chars().any(char::is_lowercase);
+    if has_upper && has_lower {
+        return word.to_string();
+    }
+
+    let mut capitalize = true;
+    word.chars()
+        .map(|c| {
+            let out = if capitalize {
+                c.to_uppercase().collect::<String>()
+            } else {
+                c.to_lowercase().collect::<String>()
+            };
+            capitalize = c == '-' || c == '\'';
+            out
+        })
+        .collect()
+}
+
+fn is_particle(part: &str) -> bool {
+    NAME_PARTICLES.contains(&part)
+}
+
+fn is_suffix(part: &str) -> bool {
+    NAME_SUFFIXES.contains(&part.to_ascii_lowercase().as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_author_name_first_last() {
+        let cases = [
+            ("John Smith", "John Smith"),
+            ("Smith, John", "John Smith"),
+            ("  smith ,  john ", "John Smith"),
+            ("J. Smith", "J. Smith"),
+            ("Smith, J.", "J. Smith"),
+            ("Smith J", "J. Smith"),
+            ("Smith JK", "J. K. Smith"),
+            ("J.A. Smith", "J. A. Smith"),
+            ("JOHN SMITH", "John Smith"),
+            ("WEI LI", "Wei Li"),
+            ("Wei LI", "Wei Li"),
+            ("John DOE", "John Doe"),
+            ("Ludwig van Beethoven", "Ludwig van Beethoven"),
+            ("Martin Luther King Jr.", "Martin Luther King Jr."),
+            ("Mary-Jane O'NEIL", "Mary-Jane O'Neil"),
+            ("Ronald McDonald", "Ronald McDonald"),
+            ("Aristotle", "Aristotle"),
+        ];
+        for (raw, expected) in cases {
+            assert_eq!(
+                normalize_author_name(raw, AuthorNameFormat::FirstLast),
+                expected,
+                "normalizing {raw:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_normalize_author_name_last_first() {
+        let cases = [
+            ("John Smith", "Smith, John"),
+            ("Smith, John", "Smith, John"),
+            ("Smith JK", "Smith, J. K."),
+            ("Ludwig van Beethoven", "van Beethoven, Ludwig"),
+            ("Aristotle", "Aristotle"),
+        ];
+        for (raw, expected) in cases {
+            assert_eq!(
+                normalize_author_name(raw, AuthorNameFormat::LastFirst),
+                expected,
+                "normalizing {raw:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_normalize_medline_author_name_trailing_capitals_initials() {
+        let cases = [
+            ("Smith JA", "J. A. Smith"),
+            ("Li W", "W. Li"),
+            ("Smith, John", "John Smith"),
+            ("JOHN SMITH", "John Smith"),
+        ];
+        for (raw, expected) in cases {
+            assert_eq!(
+                normalize_medline_author_name(raw, AuthorNameFormat::FirstLast),
+                expected,
+                "normalizing {raw:?}"
+            );
+        }
+        assert_eq!(
+            normalize_author_name("Smith JA", AuthorNameFormat::FirstLast),
+            "Smith Ja"
+        );
+    }
+
+    #[test]
+    fn test_normalize_author_name_empty_input_empty() {
+        assert_eq!(
+            normalize_author_name(" , ", AuthorNameFormat::FirstLast),
+            ""
+        );
+    }
+}
@@ -1,4 +1,5 @@
 use crate::resilience::circuit_breaker::{CircuitBreaker, CircuitBreakerConfig};
+use crate::resilience::{retry_with_policy, RetryPolicy};
 use crate::{Error, Result};
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -10,6 +11,8 @@ use tracing::{debug, info, warn};
 pub struct CircuitBreakerService {
     circuit_breakers: Arc<RwLock<HashMap<String, Arc<CircuitBreaker>>>>,
     default_config: CircuitBreakerConfig,
+    /// Retries for requests that fail before reaching the server
+    retry_policy: RetryPolicy,
 }
 
 impl CircuitBreakerService {
@@ -27,6 +30,7 @@ impl CircuitBreakerService {
         Self {
             circuit_breakers: Arc::new(RwLock::new(HashMap::new())),
             default_config,
+            retry_policy: RetryPolicy::connection_failures(),
         }
     }
 
@@ -36,6 +40,7 @@ impl CircuitBreakerService {
         Self {
             circuit_breakers: Arc::new(RwLock::new(HashMap::new())),
             default_config: config,
+            retry_policy: RetryPolicy::connection_failures(),
         }
     }
 
@@ -66,30 +71,44 @@ impl CircuitBreakerService {
         circuit_breaker.call(operation).await
     }
 
-    /// Execute a HTTP request with circuit breaker protection
+    /// Execute a HTTP request with circuit breaker protection. DNS and
+    /// connection failures are retried with backoff before counting against
+    /// the breaker.
     pub async fn call_http<T, F, Fut>(&self, service_name: &str, operation: F) -> Result<T>
     where
-        F: FnOnce() -> Fut,
-        Fut: std::future::Future<Output = std::result::Result<T, reqwest::Error>>,
+        T: Send,
+        F: Fn() -> Fut + Send + Sync,
+        Fut: std::future::Future<Output = std::result::Result<T, reqwest::Error>> + Send,
     {
         let circuit_breaker = self.get_circuit_breaker(service_name).await;
 
         circuit_breaker
-            .call(|| async {
-                operation().await.map_err(|e| {
-                    if e.is_timeout() {
-                        Error::NetworkTimeout {
-                            timeout: Duration::from_secs(30),
-                            message: format!("HTTP request to {service_name} timed out: {e}"),
-                        }
-                    } else if e.is_connect() {
-                        Error::ConnectionRefused {
-                            endpoint: service_name.to_string(),
-                        }
-                    } else {
-                        Error::Http(e)
-                    }
-                })
+            .call(|| {
+                retry_with_policy(
+                    || async {
+                        operation().await.map_err(|e| {
+                            if e.is_timeout() {
+                                Error::NetworkTimeout {
+                                    timeout: Duration::from_secs(30),
+                                    message: format!(
+                                        "HTTP request to {service_name} timed out: {e}"
+                                    ),
+                                }
+                            } else if e.is_connect() {
+                                match Error::from(e) {
+                                    dns @ Error::DnsFailure { .. } => dns,
+                                    _ => Error::ConnectionRefused {
+                                        endpoint: service_name.to_string(),
+                                    },
+                                }
+                            } else {
+                                Error::Http(e)
+                            }
+                        })
+                    },
+                    &self.retry_policy,
+                    service_name,
+                )
             })
             .await
     }
@@ -319,4 +338,87 @@ mod tests {
         let result = service.reset("non_existent").await;
         assert!(result.is_err());
     }
+
+    /// Resolver that fails the first lookup and then resolves to localhost
+    struct FlakyResolver {
+        lookups: Arc<AtomicUsize>,
+    }
+
+    impl reqwest::dns::Resolve for FlakyResolver {
+        fn resolve(&self, _name: hyper::client::connect::dns::Name) -> reqwest::dns::Resolving {
+            let lookup = self.lookups.fetch_add(1, Ordering::SeqCst);
+            Box::pin(async move {
+                if lookup == 0 {
+                    return Err(std::io::Error::new(
+                        std::io::ErrorKind::Other,
+                        "failed to lookup address information: Temporary failure in name resolution",
+                    )
+                    .into());
+                }
+                let addrs: reqwest::dns::Addrs = Box::new(std::iter::once(
+                    std::net::SocketAddr::from(([127, 0, 0, 1], 0)),
+                ));
+                Ok(addrs)
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_call_http_retries_transient_dns_failure() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/works"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("ok"))
+            .mount(&server)
+            .await;
+
+        let lookups = Arc::new(AtomicUsize::new(0));
+        let client = reqwest::Client::builder()
+            .dns_resolver(Arc::new(FlakyResolver {
+                lookups: lookups.clone(),
+            }))
+            .build()
+            .unwrap();
+        let url = format!("http://papers.test:{}/works", server.address().port());
+
+        let service = CircuitBreakerService::new();
+        let response = service
+            .call_http("flaky_dns", || async { client.get(&url).send().await })
+            .await
+            .expect("request should succeed after retrying the DNS failure");
+
+        assert_eq!(response.status(), 200);
+        assert_eq!(lookups.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_http_client_error_not_retryable() {
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(404))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let result = crate::resilience::retry(
+            || async {
+                reqwest::get(server.uri())
+                    .await?
+                    .error_for_status()
+                    .map_err(Error::from)
+            },
+            "not_found",
+        )
+        .await;
+
+        let error = result.unwrap_err();
+        assert!(matches!(error, Error::Http(_)));
+        assert!(!error.is_retryable());
+    }
 }
@@ -0,0 +1,84 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tracing::debug;
+
+/// Caps the number of in-flight requests per remote host.
+///
+/// Several providers can sit behind the same host (for example multiple NCBI
+/// endpoints), so limiting each provider individually is not enough to stay
+/// within a host's published concurrency policy. Hosts without a configured
+/// cap are not limited.
+#[derive(Debug, Default)]
+pub struct HostConcurrencyLimiter {
+    semaphores: HashMap<String, Arc<Semaphore>>,
+}
+
+impl HostConcurrencyLimiter {
+    /// Create a limiter from a map of host name to maximum concurrent requests
+    #[must_use]
+    pub fn new(limits: &HashMap<String, usize>) -> Self {
+        let semaphores = limits
+            .iter()
+            .map(|(host, max)| {
+                debug!("Per-host concurrency cap: {} -> {}", host, max);
+                (
+                    host.to_ascii_lowercase(),
+                    Arc::new(Semaphore::new((*max).max(1))),
+                )
+            })
+            .collect();
+
+        Self { semaphores }
+    }
+
+    /// Free request slots for `host`, or `None` if the host is uncapped
+    #[must_use]
+    pub fn available(&self, host: &str) -> Option<usize> {
+        self.semaphores
+            .get(&host.to_ascii_lowercase())
+            .map(|semaphore| semaphore.available_permits())
+    }
+
+    /// Wait for a request slot on `host`. Returns `None` when the host is
+    /// unknown or uncapped; otherwise the slot is held until the permit drops.
+    pub async fn acquire(&self, host: Option<&str>) -> Option<OwnedSemaphorePermit> {
+        let semaphore = self.semaphores.get(&host?.to_ascii_lowercase())?.clone();
+        semaphore.acquire_owned().await.ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_acquire_uncapped_host_returns_none() {
+        let limiter = HostConcurrencyLimiter::new(&HashMap::new());
+        assert!(limiter.acquire(Some("api.crossref.org")).await.is_none());
+        assert!(limiter.acquire(None).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_acquire_capped_host_blocks_when_exhausted() {
+        let limits = HashMap::from([("EUtils.ncbi.nlm.nih.gov".to_string(), 1)]);
+        let limiter = HostConcurrencyLimiter::new(&limits);
+
+        let permit = limiter.acquire(Some("eutils.ncbi.nlm.nih.gov")).await;
+        assert!(permit.is_some());
+        assert_eq!(limiter.available("eutils.ncbi.nlm.nih.gov"), Some(0));
+
+        let second = tokio::time::timeout(
+            std::time::Duration::from_millis(50),
+            limiter.acquire(Some("eutils.ncbi.nlm.nih.gov")),
+        )
+        .await;
+        assert!(second.is_err(), "second request should wait for the first");
+
+        drop(permit);
+        assert!(limiter
+            .acquire(Some("eutils.ncbi.nlm.nih.gov"))
+            .await
+            .is_some());
+    }
+}
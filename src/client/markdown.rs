@@ -0,0 +1,468 @@
+use crate::client::{MetaSearchResult, PaperMetadata};
+
+/// Layout used when rendering papers as markdown
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MarkdownLayout {
+    /// One table row per paper: title, year, DOI and source
+    #[default]
+    Compact,
+    /// One list item per paper with relevance, authors, venue, links and a
+    /// short abstract
+    Detailed,
+}
+
+/// Options for [`MetaSearchResult::to_markdown`]
+#[derive(Debug, Clone)]
+pub struct MarkdownOptions {
+    /// Table or list layout
+    pub layout: MarkdownLayout,
+    /// Authors listed before "et al." in the detailed layout
+    pub max_authors: usize,
+    /// Abstract characters shown in the detailed layout (0 hides the abstract)
+    pub abstract_chars: usize,
+}
+
+impl Default for MarkdownOptions {
+    fn default() -> Self {
+        Self {
+            layout: MarkdownLayout::Compact,
+            max_authors: 3,
+            abstract_chars: 300,
+        }
+    }
+}
+
+impl MarkdownOptions {
+    /// Options for the detailed layout with default limits
+    #[must_use]
+    pub fn detailed() -> Self {
+        Self {
+            layout: MarkdownLayout::Detailed,
+            ..Self::default()
+        }
+    }
+}
+
+impl MetaSearchResult {
+    /// Render the papers as markdown, with DOIs linked to doi.org
+    #[must_use]
+    pub fn to_markdown(&self, opts: &MarkdownOptions) -> String {
+        let mut sources: Vec<&String> = self.by_source.keys().collect();
+        sources.sort();
+
+        render_papers_markdown(
+            self.papers.iter().map(|paper| {
+                let source = sources
+                    .iter()
+                    .find(|source| {
+                        self.by_source[source.as_str()]
+                            .iter()
+                            .any(|p| p.doi == paper.doi && p.title == paper.title)
+                    })
+                    .map(|source| source.as_str());
+                (paper, source, None)
+            }),
+            opts,
+        )
+    }
+}
+
+/// Render papers, each with the provider it came from and its relevance score
+/// (0.0 to 1.0, shown as a percentage when known), as markdown
+pub fn render_papers_markdown<'a>(
+    papers: impl IntoIterator<Item = (&'a PaperMetadata, Option<&'a str>, Option<f64>)>,
+    opts: &MarkdownOptions,
+) -> String {
+    let papers: Vec<_> = papers.into_iter().collect();
+    if papers.is_empty() {
+        return "_No papers found._".to_string();
+    }
+
+    match opts.layout {
+        MarkdownLayout::Compact => render_table(&papers),
+        MarkdownLayout::Detailed => render_list(&papers, opts),
+    }
+}
+
+/// Render papers, each with the provider it came from and its relevance score,
+/// by filling in `template` once per paper.
+///
+/// `{index}`, `{title}`, `{authors}`, `{year}`, `{journal}`, `{doi}`,
+/// `{doi_url}`, `{pdf_url}`, `{license}`, `{open_access}` ("yes" or "no"),
+/// `{source}`, `{relevance}` (a percentage) and `{abstract}` are replaced with
+/// the paper's value, or "—" when it has none; unknown placeholders are left
+/// as written.
+pub fn render_papers_template<'a>(
+    papers: impl IntoIterator<Item = (&'a PaperMetadata, Option<&'a str>, Option<f64>)>,
+    template: &str,
+) -> String {
+    let lines: Vec<String> = papers
+        .into_iter()
+        .enumerate()
+        .map(|(i, (paper, source, relevance))| {
+            fill_template(template, i + 1, paper, source, relevance)
+        })
+        .collect();
+
+    if lines.is_empty() {
+        return "No papers found.".to_string();
+    }
+    lines.join("\n")
+}
+
+fn fill_template(
+    template: &str,
+    index: usize,
+    paper: &PaperMetadata,
+    source: Option<&str>,
+    relevance: Option<f64>,
+) -> String {
+    let doi = Some(paper.doi.trim()).filter(|doi| !doi.is_empty());
+    let field = |name: &str| -> Option<Option<String>> {
+        Some(match name {
+            "index" => Some(index.to_string()),
+            "title" => paper.title.clone(),
+            "authors" => Some(paper.authors.join(", ")),
+            "year" => paper.year.filter(|y| *y > 0).map(|y| y.to_string()),
+            "journal" => paper.journal.clone(),
+            "doi" => doi.map(str::to_string),
+            "doi_url" => doi.map(doi_url),
+            "pdf_url" => paper.pdf_url.clone(),
+            "license" => paper.license.clone(),
+            "open_access" => paper
+                .is_open_access
+                .map(|open| if open { "yes" } else { "no" }.to_string()),
+            "source" => source.map(str::to_string),
+            "relevance" => relevance.map(format_relevance),
+            "abstract" => paper
+                .abstract_text
+                .as_deref()
+                .map(|text| text.split_whitespace().collect::<Vec<_>>().join(" ")),
+            _ => return None,
+        })
+    };
+
+    // Values are inserted as-is, so a title containing "{doi}" stays literal
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(open) = rest.find('{') {
+        out.push_str(&rest[..open]);
+        let after = &rest[open + 1..];
+        match after
+            .find('}')
+            .and_then(|close| Some((close, field(&after[..close])?)))
+        {
+            Some((close, value)) => {
+                let value = value.filter(|v| !v.trim().is_empty());
+                out.push_str(value.as_deref().unwrap_or("—"));
+                rest = &after[close + 1..];
+            }
+            None => {
+                out.push('{');
+                rest = after;
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// doi.org URL for a DOI, with characters that break markdown links escaped
+#[must_use]
+pub fn doi_url(doi: &str) -> String {
+    let mut url = String::from("https://doi.org/");
+    for c in doi.trim().chars() {
+        match c {
+            '(' => url.push_str("%28"),
+            ')' => url.push_str("%29"),
+            ' ' => url.push_str("%20"),
+            '<' => url.push_str("%3C"),
+            '>' => url.push_str("%3E"),
+            _ => url.push(c),
+        }
+    }
+    url
+}
+
+/// Relevance score (0.0 to 1.0) as a whole percentage
+fn format_relevance(score: f64) -> String {
+    format!("{:.0}%", score * 100.0)
+}
+
+fn doi_link(doi: &str) -> Option<String> {
+    let doi = doi.trim();
+    (!doi.is_empty()).then(|| format!("[{}]({})", escape_text(doi), doi_url(doi)))
+}
+
+fn render_table(papers: &[(&PaperMetadata, Option<&str>, Option<f64>)]) -> String {
+    let show_relevance = papers.iter().any(|(_, _, relevance)| relevance.is_some());
+    let mut out = if show_relevance {
+        String::from("| # | Title | Year | DOI | Source | Relevance |\n|---|---|---|---|---|---|\n")
+    } else {
+        String::from("| # | Title | Year | DOI | Source |\n|---|---|---|---|---|\n")
+    };
+
+    for (i, (paper, source, relevance)) in papers.iter().enumerate() {
+        let year = paper
+            .year
+            .filter(|y| *y > 0)
+            .map(|y| y.to_string())
+            .unwrap_or_else(|| "—".to_string());
+        out.push_str(&format!(
+            "| {} | {} | {} | {} | {} |",
+            i + 1,
+            escape_cell(paper.title.as_deref().unwrap_or("No title")),
+            year,
+            doi_link(&paper.doi).unwrap_or_else(|| "—".to_string()),
+            source.map_or_else(|| "—".to_string(), escape_cell),
+        ));
+        if show_relevance {
+            let relevance = relevance.map_or_else(|| "—".to_string(), format_relevance);
+            out.push_str(&format!(" {relevance} |"));
+        }
+        out.push('\n');
+    }
+
+    out.trim_end().to_string()
+}
+
+fn render_list(
+    papers: &[(&PaperMetadata, Option<&str>, Option<f64>)],
+    opts: &MarkdownOptions,
+) -> String {
+    papers
+        .iter()
+        .enumerate()
+        .map(|(i, (paper, source, relevance))| {
+            let mut item = format!(
+                "{}. **{}**",
+                i + 1,
+                escape_text(paper.title.as_deref().unwrap_or("No title"))
+            );
+            if let Some(relevance) = relevance {
+                item.push_str(&format!(" (Relevance: {})", format_relevance(*relevance)));
+            }
+
+            if !paper.authors.is_empty() {
+                let mut authors = paper
+                    .authors
+                    .iter()
+                    .take(opts.max_authors.max(1))
+                    .map(String::as_str)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                if paper.authors.len() > opts.max_authors.max(1) {
+                    authors.push_str(" et al.");
+                }
+                item.push_str(&format!("\n   - Authors: {}", escape_text(&authors)));
+            }
+
+            let year = paper.year.filter(|y| *y > 0);
+            match (paper.journal.as_deref(), year) {
+                (Some(journal), Some(year)) => {
+                    item.push_str(&format!(
+                        "\n   - Published: {} ({year})",
+                        escape_text(journal)
+                    ));
+                }
+                (Some(journal), None) => {
+                    item.push_str(&format!("\n   - Published: {}", escape_text(journal)));
+                }
+                (None, Some(year)) => item.push_str(&format!("\n   - Year: {year}")),
+                (None, None) => {}
+            }
+
+            match doi_link(&paper.doi) {
+                Some(link) => item.push_str(&format!("\n   - DOI: {link}")),
+                None => item.push_str("\n   - DOI: not available (cannot download)"),
+            }
+            if let Some(pdf_url) = paper.pdf_url.as_deref().filter(|u| !u.is_empty()) {
+                item.push_str(&format!("\n   - PDF: <{pdf_url}>"));
+            }
+            if let Some(license) = paper.license.as_deref() {
+                item.push_str(&format!("\n   - License: {}", escape_text(license)));
+            }
+            match paper.is_open_access {
+                Some(true) => item.push_str("\n   - Access: open"),
+                Some(false) => item.push_str("\n   - Access: closed"),
+                None => {}
+            }
+            if let Some(source) = source {
+                item.push_str(&format!("\n   - Source: {source}"));
+            }
+
+            if opts.abstract_chars > 0 {
+                if let Some(abstract_text) = paper.abstract_text.as_deref() {
+                    let abstract_text = abstract_text
+                        .split_whitespace()
+                        .collect::<Vec<_>>()
+                        .join(" ");
+                    if !abstract_text.is_empty() {
+                        item.push_str(&format!(
+                            "\n\n   > {}",
+                            escape_text(&truncate(&abstract_text, opts.abstract_chars))
+                        ));
+                    }
+                }
+            }
+
+            item
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+fn truncate(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        return text.to_string();
+    }
+    let mut truncated: String = text.chars().take(max_chars).collect();
+    truncated.push('…');
+    truncated
+}
+
+/// Escape characters that would otherwise be read as markdown emphasis or links
+fn escape_text(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '*' | '_' | '[' | ']' | '`') {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// Escape text for a table cell, where pipes and newlines end the cell
+fn escape_cell(text: &str) -> String {
+    escape_text(text)
+        .replace('|', "\\|")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::time::Duration;
+
+    fn create_test_result() -> MetaSearchResult {
+        let mut first = PaperMetadata::new("10.1038/nature14539".to_string());
+        first.title = Some("Deep learning".to_string());
+        first.authors = vec![
+            "Yann LeCun".to_string(),
+            "Yoshua Bengio".to_string(),
+            "Geoffrey Hinton".to_string(),
+            "Someone Else".to_string(),
+        ];
+        first.journal = Some("Nature".to_string());
+        first.year = Some(2015);
+        first.abstract_text = Some("Deep learning allows computational models...".to_string());
+
+        let mut second = PaperMetadata::new("10.1002/(SICI)1097-0258(19980815)17:15".to_string());
+        second.title = Some("Pipes | and *stars*".to_string());
+
+        MetaSearchResult {
+            papers: vec![first.clone(), second.clone()],
+            by_source: HashMap::from([
+                ("crossref".to_string(), vec![first]),
+                ("openalex".to_string(), vec![second]),
+            ]),
+            total_search_time: Duration::from_millis(120),
+            successful_providers: 2,
+            failed_providers: 0,
+            provider_errors: HashMap::new(),
+            provider_metadata: HashMap::new(),
+            timings: HashMap::new(),
+            score_breakdowns: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_to_markdown_links_every_doi() {
+        let result = create_test_result();
+
+        for opts in [MarkdownOptions::default(), MarkdownOptions::detailed()] {
+            let markdown = result.to_markdown(&opts);
+            assert!(markdown.contains("[10.1038/nature14539](https://doi.org/10.1038/nature14539)"));
+            assert!(markdown
+                .contains("(https://doi.org/10.1002/%28SICI%291097-0258%2819980815%2917:15)"));
+        }
+    }
+
+    #[test]
+    fn test_to_markdown_compact_table() {
+        let markdown = create_test_result().to_markdown(&MarkdownOptions::default());
+        let lines: Vec<&str> = markdown.lines().collect();
+
+        assert_eq!(lines[0], "| # | Title | Year | DOI | Source |");
+        assert_eq!(lines.len(), 4);
+        assert!(lines[2].contains("| 2015 |") && lines[2].ends_with("| crossref |"));
+        assert!(lines[3].contains(r"Pipes \| and \*stars\*"));
+    }
+
+    #[test]
+    fn test_to_markdown_detailed_list() {
+        let markdown = create_test_result().to_markdown(&MarkdownOptions::detailed());
+
+        assert!(markdown.starts_with("1. **Deep learning**"));
+        assert!(markdown.contains("Authors: Yann LeCun, Yoshua Bengio, Geoffrey Hinton et al."));
+        assert!(markdown.contains("Published: Nature (2015)"));
+        assert!(markdown.contains("> Deep learning allows"));
+        assert!(markdown.contains("Source: openalex"));
+    }
+
+    #[test]
+    fn test_render_papers_template_fills_placeholders() {
+        let result = create_test_result();
+        let rendered = render_papers_template(
+            result
+                .papers
+                .iter()
+                .map(|p| (p, Some("crossref"), Some(0.874))),
+            "{index}. {title} ({year}) {doi_url} [{source}] {relevance} {unknown}",
+        );
+        let lines: Vec<&str> = rendered.lines().collect();
+
+        assert_eq!(
+            lines[0],
+            "1. Deep learning (2015) https://doi.org/10.1038/nature14539 [crossref] 87% {unknown}"
+        );
+        assert!(lines[1].starts_with("2. Pipes | and *stars* (—) "));
+    }
+
+    #[test]
+    fn test_render_papers_markdown_relevance_shown_as_percentage() {
+        let result = create_test_result();
+        let papers = || {
+            result
+                .papers
+                .iter()
+                .zip([Some(0.92), None])
+                .map(|(p, relevance)| (p, Some("crossref"), relevance))
+        };
+
+        let list = render_papers_markdown(papers(), &MarkdownOptions::detailed());
+        assert!(list.starts_with("1. **Deep learning** (Relevance: 92%)"));
+        assert!(list.contains("2. **Pipes | and \\*stars\\***\n"));
+
+        let table = render_papers_markdown(papers(), &MarkdownOptions::default());
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(lines[0], "| # | Title | Year | DOI | Source | Relevance |");
+        assert!(lines[2].ends_with("| crossref | 92% |"));
+        assert!(lines[3].ends_with("| crossref | — |"));
+        assert!(!create_test_result()
+            .to_markdown(&MarkdownOptions::default())
+            .contains("Relevance"));
+    }
+
+    #[test]
+    fn test_render_papers_markdown_empty() {
+        assert_eq!(
+            render_papers_markdown(Vec::new(), &MarkdownOptions::default()),
+            "_No papers found._"
+        );
+    }
+}
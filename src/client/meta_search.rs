@@ -1,16 +1,27 @@
 use crate::client::providers::{
-    ArxivProvider, BiorxivProvider, CoreProvider, CrossRefProvider, MdpiProvider, OpenAlexProvider,
-    OpenReviewProvider, ProviderError, ProviderResult, PubMedCentralProvider, ResearchGateProvider,
-    SciHubProvider, SearchContext, SearchQuery, SearchType, SemanticScholarProvider,
-    SourceProvider, SsrnProvider, UnpaywallProvider,
+    provider_order, unpaywall, ArxivProvider, BiorxivProvider, CoreProvider, CrossRefProvider,
+    MdpiProvider, OpenAlexProvider, OpenReviewProvider, ProviderError, ProviderResult,
+    PubMedCentralProvider, ResearchGateProvider, SciHubProvider, SearchContext, SearchQuery,
+    SearchType, SemanticScholarProvider, SourceProvider, SsrnProvider, UnpaywallProvider,
+    ZenodoProvider,
 };
-use crate::client::PaperMetadata;
+use crate::client::{
+    clean_abstract, normalize_author_name, normalize_medline_author_name, relevance,
+    AbstractSignature, HostConcurrencyLimiter, PaperMetadata, RateLimitState, RelevanceScorer,
+    SearchResultCache,
+};
+use crate::config::AuthorNameFormat;
 use crate::Config;
-use std::collections::{HashMap, HashSet};
+use chrono::Datelike;
+use futures::StreamExt;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
-use tokio::sync::RwLock;
+use tokio::sync::{OwnedSemaphorePermit, RwLock, Semaphore};
+use tokio::task::JoinSet;
 use tokio::time::timeout;
+use tokio_util::sync::CancellationToken;
 use tracing::{debug, error, info, warn};
 
 /// Configuration for meta-search behavior
@@ -24,10 +35,64 @@ pub struct MetaSearchConfig {
     pub continue_on_failure: bool,
     /// Whether to deduplicate results
     pub deduplicate_results: bool,
-    /// Minimum relevance score to include results
+    /// Score results against the query and order them best first
+    pub relevance_scoring: bool,
+    /// Minimum relevance score to include results, with `relevance_scoring`
     pub min_relevance_score: f64,
+    /// Weight of the latest outcome in a provider's rolling failure rate (0.0-1.0).
+    /// Higher values make the penalty react, and recover, faster.
+    pub failure_decay: f64,
+    /// Priority points subtracted from a provider whose recent requests all
+    /// failed. Values up to [`MAX_FAILURE_PENALTY`] only reorder a failing
+    /// provider among those of similar priority; larger ones can drop it
+    /// behind a whole tier.
+    pub max_failure_penalty: u8,
+    /// Form author names are normalized to when results are aggregated
+    pub author_format: AuthorNameFormat,
+    /// Papers published before this year are dropped during aggregation,
+    /// unless the query overrides it with the [`MIN_YEAR_PARAM`] parameter
+    pub min_year: Option<u32>,
+    /// Once the providers that have finished cover `max_results` unique papers,
+    /// abort the rest instead of waiting for them. Only applies with
+    /// `continue_on_failure`, since the aborted providers are not reported.
+    pub greedy_stop: bool,
+    /// Maximum number of provider health checks run at once
+    pub health_check_concurrency: usize,
+    /// Time a provider health check may take before it counts as unhealthy
+    pub health_check_timeout: Duration,
+    /// Strip "Abstract:" labels and trailing copyright lines from abstracts
+    /// during aggregation
+    pub clean_abstracts: bool,
+    /// Also treat papers as duplicates when their abstracts are at least
+    /// this similar (0.0-1.0), catching preprint and published versions
+    /// with different DOIs and titles. `None` skips the comparison.
+    pub abstract_dedup_threshold: Option<f64>,
+    /// Largest provider response body read before the request is abandoned,
+    /// applied to every search context that sets none (`None` = unlimited)
+    pub max_response_bytes: Option<u64>,
+    /// Search a provider once more, after this delay, when it returns no
+    /// results. `None` takes an empty answer as final.
+    pub retry_on_empty: Option<Duration>,
 }
 
+/// Largest failure penalty accepted from the configuration, kept below the
+/// 10 points between the default priorities of adjacent tiers
+pub const MAX_FAILURE_PENALTY: u8 = 9;
+
+/// Priority boost for providers that yield PDFs when a query asks for them
+const FULLTEXT_PRIORITY_BOOST: i32 = 10;
+
+/// `SearchQuery::params` key overriding [`MetaSearchConfig::min_year`] for one
+/// search. `"0"` disables the cutoff.
+pub const MIN_YEAR_PARAM: &str = "min_year";
+
+/// `SearchQuery::params` key set to `"true"` when the caller only wants papers
+/// with abstracts, so providers that never return any are queried last
+pub const ABSTRACT_REQUIRED_PARAM: &str = "abstract_required";
+
+/// Priority penalty for providers without abstracts when a search requires them
+const NO_ABSTRACT_PRIORITY_PENALTY: i32 = 100;
+
 impl Default for MetaSearchConfig {
     fn default() -> Self {
         Self {
@@ -35,7 +100,19 @@ impl Default for MetaSearchConfig {
             provider_timeout: Duration::from_secs(30),
             continue_on_failure: true,
             deduplicate_results: true,
+            relevance_scoring: false,
             min_relevance_score: 0.0,
+            failure_decay: 0.3,
+            max_failure_penalty: MAX_FAILURE_PENALTY,
+            author_format: AuthorNameFormat::FirstLast,
+            min_year: None,
+            greedy_stop: false,
+            health_check_concurrency: 8,
+            health_check_timeout: Duration::from_secs(5),
+            clean_abstracts: true,
+            abstract_dedup_threshold: None,
+            max_response_bytes: Some(50 * 1024 * 1024),
+            retry_on_empty: None,
         }
     }
 }
@@ -49,21 +126,68 @@ impl MetaSearchConfig {
             provider_timeout,
             continue_on_failure: true,
             deduplicate_results: true,
+            relevance_scoring: false,
             min_relevance_score: 0.0,
+            failure_decay: 0.3,
+            max_failure_penalty: MAX_FAILURE_PENALTY,
+            author_format: AuthorNameFormat::FirstLast,
+            min_year: None,
+            greedy_stop: false,
+            health_check_concurrency: 8,
+            health_check_timeout: Duration::from_secs(5),
+            clean_abstracts: true,
+            abstract_dedup_threshold: None,
+            max_response_bytes: Some(50 * 1024 * 1024),
+            retry_on_empty: None,
         }
     }
 
     /// Create `MetaSearchConfig` from app config
     #[must_use]
     pub const fn from_config(config: &Config) -> Self {
-        Self::with_provider_timeout(Duration::from_secs(
-            config.research_source.provider_timeout_secs,
-        ))
+        Self {
+            max_parallel_providers: config.concurrency.search_provider_workers,
+            author_format: config.research_source.author_format,
+            min_year: config.meta_search.min_year,
+            greedy_stop: config.meta_search.greedy_stop,
+            health_check_concurrency: config.meta_search.health_check_concurrency,
+            health_check_timeout: Duration::from_secs(config.meta_search.health_check_timeout_secs),
+            clean_abstracts: config.meta_search.clean_abstracts,
+            abstract_dedup_threshold: config.meta_search.abstract_dedup_threshold,
+            relevance_scoring: config.meta_search.relevance_scoring,
+            failure_decay: config.meta_search.failure_decay,
+            max_failure_penalty: config.meta_search.max_failure_penalty,
+            max_response_bytes: if config.http.max_response_bytes == 0 {
+                None
+            } else {
+                Some(config.http.max_response_bytes)
+            },
+            retry_on_empty: if config.meta_search.retry_on_empty {
+                Some(Duration::from_millis(
+                    config.meta_search.retry_on_empty_delay_ms,
+                ))
+            } else {
+                None
+            },
+            ..Self::with_provider_timeout(Duration::from_secs(
+                config.research_source.provider_timeout_secs,
+            ))
+        }
     }
 }
 
-/// Result from meta-search across multiple providers
+/// Capabilities of one provider, as reported by
+/// [`MetaSearchClient::provider_capabilities`]
 #[derive(Debug, Clone)]
+pub struct ProviderCapabilities {
+    pub name: String,
+    pub search_types: Vec<SearchType>,
+    /// Whether the provider yields PDFs rather than metadata only
+    pub provides_fulltext: bool,
+}
+
+/// Result from meta-search across multiple providers
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct MetaSearchResult {
     /// All papers found across providers
     pub papers: Vec<PaperMetadata>,
@@ -77,8 +201,77 @@ pub struct MetaSearchResult {
     pub failed_providers: usize,
     /// Errors from failed providers
     pub provider_errors: HashMap<String, String>,
-    /// Metadata from all providers
+    /// Metadata from all providers. Besides provider-specific keys, each
+    /// entry has `truncated` ("true" when the provider has more results than
+    /// it returned) and, when known, `total_available`.
     pub provider_metadata: HashMap<String, HashMap<String, String>>,
+    /// Time spent per provider, successful or not, plus the whole search
+    /// under `total`
+    pub timings: HashMap<String, Duration>,
+    /// Relevance score components per paper, keyed by [`Self::paper_key`].
+    /// Empty unless relevance scoring is enabled; a paper's components sum
+    /// to its score.
+    pub score_breakdowns: HashMap<String, HashMap<String, f64>>,
+}
+
+impl MetaSearchResult {
+    /// Fold in the results of another search. Papers already present (by DOI,
+    /// or by title when there is no DOI) are skipped.
+    pub fn merge(&mut self, other: Self) {
+        let key = Self::paper_key;
+
+        let mut seen: HashSet<String> = self.papers.iter().map(key).collect();
+        self.papers.extend(
+            other
+                .papers
+                .into_iter()
+                .filter(|paper| seen.insert(key(paper))),
+        );
+
+        for (source, papers) in other.by_source {
+            let existing = self.by_source.entry(source).or_default();
+            let mut seen: HashSet<String> = existing.iter().map(key).collect();
+            existing.extend(papers.into_iter().filter(|paper| seen.insert(key(paper))));
+        }
+        for (source, error) in other.provider_errors {
+            if !self.by_source.contains_key(&source) {
+                self.provider_errors.entry(source).or_insert(error);
+            }
+        }
+        self.provider_errors
+            .retain(|source, _| !self.by_source.contains_key(source));
+        self.provider_metadata.extend(other.provider_metadata);
+
+        for (name, duration) in other.timings {
+            *self.timings.entry(name).or_default() += duration;
+        }
+        for (paper, breakdown) in other.score_breakdowns {
+            self.score_breakdowns.entry(paper).or_insert(breakdown);
+        }
+        self.total_search_time += other.total_search_time;
+        self.successful_providers = self.by_source.len();
+        self.failed_providers = self.provider_errors.len();
+    }
+
+    /// Identity of a paper across result sets: its DOI, or its title when it
+    /// has no DOI
+    #[must_use]
+    pub fn paper_key(paper: &PaperMetadata) -> String {
+        let doi = paper.doi.trim();
+        if doi.is_empty() {
+            let title = paper.title.as_deref().unwrap_or_default();
+            format!(
+                "title:{}",
+                title
+                    .split_whitespace()
+                    .collect::<Vec<_>>()
+                    .join(" ")
+                    .to_lowercase()
+            )
+        } else {
+            format!("doi:{}", doi.to_lowercase())
+        }
+    }
 }
 
 /// Client that performs meta-search across multiple academic sources
@@ -89,74 +282,281 @@ struct ProviderStats {
     avg_response_time: f64,
     /// Number of requests made
     request_count: u64,
+    /// Exponentially weighted share of recent requests that failed (0.0-1.0)
+    failure_rate: f64,
     /// Last update timestamp
     last_updated: Instant,
 }
 
+/// How long a failed health check keeps a provider out of searches
+const UNHEALTHY_PROVIDER_TTL: Duration = Duration::from_secs(300);
+
+/// Result of the latest health check of a provider
+#[derive(Debug, Clone, Copy)]
+struct ProviderHealth {
+    healthy: bool,
+    checked_at: Instant,
+}
+
 impl Default for ProviderStats {
     fn default() -> Self {
         Self {
             avg_response_time: 1000.0, // Start with 1 second assumption
             request_count: 0,
+            failure_rate: 0.0,
             last_updated: Instant::now(),
         }
     }
 }
 
+/// Slots a provider task holds for its whole run: its host's (when capped)
+/// and one of the shared provider slots
+struct DispatchPermits {
+    _host: Option<OwnedSemaphorePermit>,
+    _shared: OwnedSemaphorePermit,
+}
+
+/// Successful provider results, provider errors and per-provider durations
+type ParallelSearchOutcome = (
+    Vec<(String, ProviderResult)>,
+    HashMap<String, String>,
+    HashMap<String, Duration>,
+);
+
 pub struct MetaSearchClient {
     providers: Vec<Arc<dyn SourceProvider>>,
     config: MetaSearchConfig,
-    #[allow(dead_code)]
-    rate_limiters: Arc<RwLock<HashMap<String, Instant>>>,
+    /// Last request time per host, spacing requests by each provider's base delay
+    rate_limit_state: Arc<RateLimitState>,
+    /// Where `rate_limit_state` is saved on shutdown, if it is persisted
+    rate_limit_state_file: Option<PathBuf>,
     /// Provider performance statistics for adaptive semaphore sizing
     provider_stats: Arc<RwLock<HashMap<String, ProviderStats>>>,
+    /// Per-host caps on in-flight provider requests
+    host_limiter: Arc<HostConcurrencyLimiter>,
+    /// Extra request headers per provider name, merged into its search context
+    provider_headers: HashMap<String, HashMap<String, String>>,
+    /// Search timeouts per provider name, overriding `config.provider_timeout`
+    provider_timeouts: HashMap<String, Duration>,
+    /// Providers whose empty results are retried with `config.retry_on_empty`;
+    /// empty means all of them
+    retry_on_empty_providers: HashSet<String>,
+    /// Latest health check outcome per provider
+    provider_health: Arc<RwLock<HashMap<String, ProviderHealth>>>,
+    /// Charset for provider responses that declare none and aren't UTF-8
+    default_charset: Option<&'static encoding_rs::Encoding>,
+    /// Results of recent searches kept on disk, if enabled
+    result_cache: Option<SearchResultCache>,
 }
 
 impl MetaSearchClient {
     /// Create a new meta-search client
-    pub fn new(_app_config: Config, meta_config: MetaSearchConfig) -> Result<Self, ProviderError> {
-        let providers: Vec<Arc<dyn SourceProvider>> = vec![
+    pub fn new(app_config: Config, meta_config: MetaSearchConfig) -> Result<Self, ProviderError> {
+        let http = &app_config.http;
+        let arxiv_version = app_config
+            .research_source
+            .arxiv_version_pin()
+            .map_err(|e| ProviderError::Other(e.to_string()))?;
+        let host_limiter = HostConcurrencyLimiter::new(&app_config.http.per_host_max_concurrency);
+        let provider_timeouts = app_config
+            .meta_search
+            .provider_timeouts
+            .iter()
+            .map(|(provider, secs)| (provider.clone(), Duration::from_secs(*secs)))
+            .collect();
+        let rate_limit_state_file = app_config
+            .rate_limiting
+            .persist_state
+            .then(|| app_config.rate_limiting.state_file.clone());
+        let rate_limit_state = match &rate_limit_state_file {
+            Some(path) => RateLimitState::load(path).unwrap_or_else(|e| {
+                warn!(
+                    "Ignoring unreadable rate limit state {}: {}",
+                    path.display(),
+                    e
+                );
+                RateLimitState::default()
+            }),
+            None => RateLimitState::default(),
+        };
+        let mut providers: Vec<Arc<dyn SourceProvider>> = vec![
             // CrossRef provider (highest priority for authoritative metadata)
-            Arc::new(CrossRefProvider::new(None)?), // TODO: Get email from config
+            Arc::new(CrossRefProvider::with_http_config(None, http)?), // TODO: Get email from config
             // Semantic Scholar provider (very high priority for PDF access + metadata)
-            Arc::new(SemanticScholarProvider::new(None)?), // TODO: Get API key from config
+            Arc::new(SemanticScholarProvider::with_http_config(None, http)?), // TODO: Get API key from config
             // OpenAlex provider (high priority for comprehensive academic coverage)
-            Arc::new(OpenAlexProvider::new()?),
+            Arc::new(OpenAlexProvider::with_http_config(http)?),
             // Unpaywall provider (high priority for legal free PDF discovery)
-            Arc::new(UnpaywallProvider::new_with_default_email()?), // TODO: Get email from config
+            Arc::new(UnpaywallProvider::with_http_config(
+                unpaywall::DEFAULT_EMAIL.to_string(),
+                http,
+            )?), // TODO: Get email from config
             // PubMed Central provider (very high priority for biomedical papers)
-            Arc::new(PubMedCentralProvider::new(None)?), // TODO: Get API key from config
+            Arc::new(PubMedCentralProvider::with_http_config(None, http)?), // TODO: Get API key from config
             // CORE provider (high priority for open access collection)
-            Arc::new(CoreProvider::new(None)?), // TODO: Get API key from config
+            Arc::new(CoreProvider::with_http_config(None, http)?), // TODO: Get API key from config
+            // Zenodo provider (open access datasets and preprints)
+            Arc::new(ZenodoProvider::with_http_config(http)?),
             // SSRN provider (high priority for recent papers and preprints)
-            Arc::new(SsrnProvider::new()?),
+            Arc::new(SsrnProvider::with_http_config(http)?),
             // arXiv provider (high priority for CS/physics/math)
-            Arc::new(ArxivProvider::new()?),
+            Arc::new(ArxivProvider::with_http_config(http)?.with_version_pin(arxiv_version)),
             // bioRxiv provider (biology preprints)
-            Arc::new(BiorxivProvider::new()?),
+            Arc::new(BiorxivProvider::with_http_config(http)?),
             // OpenReview provider (high priority for ML conference papers)
-            Arc::new(OpenReviewProvider::new()?),
+            Arc::new(OpenReviewProvider::with_http_config(http)?),
             // MDPI provider (good priority for open access journals)
-            Arc::new(MdpiProvider::new()?),
+            Arc::new(MdpiProvider::with_http_config(http)?),
+        ];
+
+        if app_config.safe_mode {
+            info!("Safe mode enabled: Sci-Hub and ResearchGate providers are disabled");
+        } else {
             // ResearchGate provider (lower priority due to access limitations)
-            Arc::new(ResearchGateProvider::new()?),
+            providers.push(Arc::new(ResearchGateProvider::with_http_config(http)?));
             // Sci-Hub provider (lowest priority, for full-text access)
-            Arc::new(SciHubProvider::new()?),
-        ];
+            providers.push(Arc::new(SciHubProvider::with_http_config(http)?));
+        }
 
         info!(
-            "Initialized meta-search client with {} providers",
-            providers.len()
+            "Initialized meta-search client with {} providers: {}",
+            providers.len(),
+            providers
+                .iter()
+                .map(|p| p.name())
+                .collect::<Vec<_>>()
+                .join(", ")
         );
 
         Ok(Self {
             providers,
             config: meta_config,
-            rate_limiters: Arc::new(RwLock::new(HashMap::new())),
+            rate_limit_state: Arc::new(rate_limit_state),
+            rate_limit_state_file,
+            provider_stats: Arc::new(RwLock::new(HashMap::new())),
+            host_limiter: Arc::new(host_limiter),
+            provider_health: Arc::new(RwLock::new(HashMap::new())),
+            default_charset: app_config.http.default_encoding(),
+            provider_headers: app_config.http.provider_headers,
+            provider_timeouts,
+            retry_on_empty_providers: app_config
+                .meta_search
+                .retry_on_empty_providers
+                .into_iter()
+                .collect(),
+            result_cache: app_config
+                .search_cache
+                .enabled
+                .then(|| SearchResultCache::from_settings(&app_config.search_cache)),
+        })
+    }
+
+    /// Create a meta-search client over a custom set of providers
+    #[must_use]
+    pub fn with_providers(
+        providers: Vec<Arc<dyn SourceProvider>>,
+        meta_config: MetaSearchConfig,
+    ) -> Self {
+        Self {
+            providers,
+            config: meta_config,
+            rate_limit_state: Arc::new(RateLimitState::default()),
+            rate_limit_state_file: None,
             provider_stats: Arc::new(RwLock::new(HashMap::new())),
+            host_limiter: Arc::new(HostConcurrencyLimiter::default()),
+            provider_health: Arc::new(RwLock::new(HashMap::new())),
+            provider_headers: HashMap::new(),
+            provider_timeouts: HashMap::new(),
+            retry_on_empty_providers: HashSet::new(),
+            default_charset: None,
+            result_cache: None,
+        }
+    }
+
+    /// Cap in-flight requests per host, replacing any caps set from config
+    #[must_use]
+    pub fn with_per_host_concurrency(mut self, limits: &HashMap<String, usize>) -> Self {
+        self.host_limiter = Arc::new(HostConcurrencyLimiter::new(limits));
+        self
+    }
+
+    /// Send extra headers with every request to the named providers,
+    /// replacing any headers set from config
+    #[must_use]
+    pub fn with_provider_headers(
+        mut self,
+        headers: HashMap<String, HashMap<String, String>>,
+    ) -> Self {
+        self.provider_headers = headers;
+        self
+    }
+
+    /// Give the named providers their own search timeout instead of
+    /// `provider_timeout`, replacing any timeouts set from config
+    #[must_use]
+    pub fn with_provider_timeouts(mut self, timeouts: HashMap<String, Duration>) -> Self {
+        self.provider_timeouts = timeouts;
+        self
+    }
+
+    /// Only retry empty results from the named providers, replacing any
+    /// set from config
+    #[must_use]
+    pub fn with_retry_on_empty_providers(mut self, providers: HashSet<String>) -> Self {
+        self.retry_on_empty_providers = providers;
+        self
+    }
+
+    /// Answer repeated queries from `cache` instead of the providers, and
+    /// store new results in it
+    #[must_use]
+    pub fn with_result_cache(mut self, cache: SearchResultCache) -> Self {
+        self.result_cache = Some(cache);
+        self
+    }
+
+    /// Search timeout for `provider`: its configured override, or the
+    /// global provider timeout
+    fn provider_timeout(&self, provider: &dyn SourceProvider) -> Duration {
+        self.provider_timeouts
+            .get(provider.name())
+            .copied()
+            .unwrap_or(self.config.provider_timeout)
+    }
+
+    /// Delay before searching `provider` again after it returned nothing, or
+    /// `None` when its empty results aren't retried
+    fn retry_on_empty_delay(&self, provider: &dyn SourceProvider) -> Option<Duration> {
+        self.config.retry_on_empty.filter(|_| {
+            self.retry_on_empty_providers.is_empty()
+                || self.retry_on_empty_providers.contains(provider.name())
         })
     }
 
+    /// `context` with the headers and timeout override configured for
+    /// `provider` applied, and the configured response size limit and default
+    /// charset if it has none
+    fn provider_context(
+        &self,
+        context: &SearchContext,
+        provider: &dyn SourceProvider,
+    ) -> SearchContext {
+        let mut context = context.clone();
+        context.max_response_bytes = context
+            .max_response_bytes
+            .or(self.config.max_response_bytes);
+        context.default_charset = context.default_charset.or(self.default_charset);
+        if let Some(timeout) = self.provider_timeouts.get(provider.name()) {
+            context.timeout = *timeout;
+        }
+        if let Some(headers) = self.provider_headers.get(provider.name()) {
+            context
+                .headers
+                .extend(headers.iter().map(|(k, v)| (k.clone(), v.clone())));
+        }
+        context
+    }
+
     /// Calculate adaptive semaphore size based on provider response times
     async fn calculate_adaptive_semaphore_size(&self, provider_count: usize) -> usize {
         let stats = self.provider_stats.read().await;
@@ -195,16 +595,24 @@ impl MetaSearchClient {
         adaptive_size
     }
 
-    /// Static version of `update_provider_stats` for use in spawned tasks
+    /// Record a provider request's response time and outcome
     async fn update_provider_stats_static(
         provider_stats: &Arc<RwLock<HashMap<String, ProviderStats>>>,
         provider_name: &str,
         response_time_ms: f64,
+        success: bool,
+        failure_decay: f64,
     ) {
-        let (avg_response_time, request_count) = {
+        let (avg_response_time, request_count, failure_rate) = {
             let mut stats = provider_stats.write().await;
             let provider_stats = stats.entry(provider_name.to_string()).or_default();
 
+            // Failures push the rate towards 1.0, successes decay it towards 0.0
+            let outcome = if success { 0.0 } else { 1.0 };
+            let decay = failure_decay.clamp(0.0, 1.0);
+            provider_stats.failure_rate =
+                decay.mul_add(outcome, (1.0 - decay) * provider_stats.failure_rate);
+
             // Update running average using exponential moving average
             let alpha: f64 = 0.2; // Weighting factor for new measurements
             if provider_stats.request_count == 0 {
@@ -222,15 +630,29 @@ impl MetaSearchClient {
             (
                 provider_stats.avg_response_time,
                 provider_stats.request_count,
+                provider_stats.failure_rate,
             )
         };
 
         debug!(
-            "Updated provider stats for {}: avg_time={:.1}ms, requests={}",
-            provider_name, avg_response_time, request_count
+            "Updated provider stats for {}: avg_time={:.1}ms, requests={}, failure_rate={:.2}",
+            provider_name, avg_response_time, request_count, failure_rate
         );
     }
 
+    /// Priority penalties for providers that have been failing recently
+    async fn failure_penalties(&self) -> HashMap<String, i32> {
+        let stats = self.provider_stats.read().await;
+        stats
+            .iter()
+            .filter_map(|(name, stats)| {
+                let penalty = (stats.failure_rate * f64::from(self.config.max_failure_penalty))
+                    .round() as i32;
+                (penalty > 0).then(|| (name.clone(), penalty))
+            })
+            .collect()
+    }
+
     /// Get list of available providers
     #[must_use]
     pub fn providers(&self) -> Vec<String> {
@@ -240,38 +662,207 @@ impl MetaSearchClient {
             .collect()
     }
 
-    /// Perform health checks on all providers
+    /// Priority of the provider named `name`, if it is registered
+    #[must_use]
+    pub fn provider_priority(&self, name: &str) -> Option<u8> {
+        self.providers
+            .iter()
+            .find(|p| p.name() == name)
+            .map(|p| p.priority())
+    }
+
+    /// What each provider supports, including whether it yields PDFs or
+    /// metadata only
+    #[must_use]
+    pub fn provider_capabilities(&self) -> Vec<ProviderCapabilities> {
+        self.providers
+            .iter()
+            .map(|p| ProviderCapabilities {
+                name: p.name().to_string(),
+                search_types: p.supported_search_types(),
+                provides_fulltext: p.provides_fulltext(),
+            })
+            .collect()
+    }
+
+    /// Perform health checks on all providers. Up to
+    /// `health_check_concurrency` probes run at once, each cut off after
+    /// `health_check_timeout`, so one slow provider doesn't hold up the rest.
     pub async fn health_check(&self) -> HashMap<String, bool> {
-        let context = self.create_search_context();
-        let mut results = HashMap::new();
+        let context = self.default_search_context();
+        let semaphore = Arc::new(Semaphore::new(self.config.health_check_concurrency.max(1)));
 
+        let mut tasks = JoinSet::new();
         for provider in &self.providers {
-            let health = provider.health_check(&context).await.unwrap_or(false);
-            results.insert(provider.name().to_string(), health);
+            let provider = provider.clone();
+            let context = self.provider_context(&context, provider.as_ref());
+            let host_limiter = self.host_limiter.clone();
+            let semaphore = semaphore.clone();
+            let timeout_duration = self.config.health_check_timeout;
+
+            tasks.spawn(async move {
+                // Host slot first, so a provider waiting on a capped host
+                // doesn't hold one of the shared slots meanwhile
+                let _host_permit = host_limiter.acquire(provider.host().as_deref()).await;
+                let _permit = semaphore.acquire_owned().await;
+                let healthy = matches!(
+                    timeout(timeout_duration, provider.health_check(&context)).await,
+                    Ok(Ok(true))
+                );
+                (provider.name().to_string(), healthy)
+            });
+        }
 
-            if health {
-                info!("Provider {} is healthy", provider.name());
-            } else {
-                warn!("Provider {} is unhealthy", provider.name());
+        let mut results = HashMap::new();
+        while let Some(joined) = tasks.join_next().await {
+            match joined {
+                Ok((name, health)) => {
+                    if health {
+                        info!("Provider {} is healthy", name);
+                    } else {
+                        warn!("Provider {} is unhealthy", name);
+                    }
+                    self.record_health(&name, health).await;
+                    results.insert(name, health);
+                }
+                Err(e) => error!("Health check task failed: {}", e),
             }
         }
 
         results
     }
 
+    /// Health-check every provider concurrently so the first search doesn't
+    /// pay for it. The checks leave keep-alive connections to each provider
+    /// host in the HTTP pools, and providers found down are skipped by
+    /// searches until their result expires. Returns the number of healthy
+    /// providers.
+    pub async fn warm_up(&self) -> usize {
+        let start_time = Instant::now();
+        let context = self.default_search_context();
+
+        let mut tasks = JoinSet::new();
+        for provider in &self.providers {
+            let provider = provider.clone();
+            let context = self.provider_context(&context, provider.as_ref());
+            let host_limiter = self.host_limiter.clone();
+            let timeout_duration = self.provider_timeout(provider.as_ref());
+
+            tasks.spawn(async move {
+                let _host_permit = host_limiter.acquire(provider.host().as_deref()).await;
+                let healthy = matches!(
+                    timeout(timeout_duration, provider.health_check(&context)).await,
+                    Ok(Ok(true))
+                );
+                (provider.name().to_string(), healthy)
+            });
+        }
+
+        let mut healthy_count = 0;
+        while let Some(joined) = tasks.join_next().await {
+            match joined {
+                Ok((name, healthy)) => {
+                    if healthy {
+                        healthy_count += 1;
+                    } else {
+                        warn!("Provider {} failed its warm-up health check", name);
+                    }
+                    self.record_health(&name, healthy).await;
+                }
+                Err(e) => error!("Warm-up task failed: {}", e),
+            }
+        }
+
+        info!(
+            "Warmed up {}/{} providers in {:?}",
+            healthy_count,
+            self.providers.len(),
+            start_time.elapsed()
+        );
+        healthy_count
+    }
+
+    /// Remember the outcome of a provider health check
+    async fn record_health(&self, provider_name: &str, healthy: bool) {
+        self.provider_health.write().await.insert(
+            provider_name.to_string(),
+            ProviderHealth {
+                healthy,
+                checked_at: Instant::now(),
+            },
+        );
+    }
+
+    /// Providers whose latest health check failed and hasn't expired yet
+    async fn known_unhealthy_providers(&self) -> HashSet<String> {
+        self.provider_health
+            .read()
+            .await
+            .iter()
+            .filter(|(_, health)| {
+                !health.healthy && health.checked_at.elapsed() < UNHEALTHY_PROVIDER_TTL
+            })
+            .map(|(name, _)| name.clone())
+            .collect()
+    }
+
     /// Search across multiple providers
     pub async fn search(&self, query: &SearchQuery) -> Result<MetaSearchResult, ProviderError> {
+        self.search_with_context(query, self.default_search_context())
+            .await
+    }
+
+    /// Search across multiple providers, passing `context` to each of them
+    /// instead of the default one, e.g. to send a different user agent or
+    /// extra headers for this search only
+    pub async fn search_with_context(
+        &self,
+        query: &SearchQuery,
+        context: SearchContext,
+    ) -> Result<MetaSearchResult, ProviderError> {
+        self.run_search(query, &context, &CancellationToken::new())
+            .await
+    }
+
+    /// Search across multiple providers until `cancel` fires. On cancellation
+    /// the outstanding provider tasks are aborted and `ProviderError::Cancelled`
+    /// is returned.
+    pub async fn search_with_cancel(
+        &self,
+        query: &SearchQuery,
+        cancel: &CancellationToken,
+    ) -> Result<MetaSearchResult, ProviderError> {
+        self.run_search(query, &self.default_search_context(), cancel)
+            .await
+    }
+
+    async fn run_search(
+        &self,
+        query: &SearchQuery,
+        context: &SearchContext,
+        cancel: &CancellationToken,
+    ) -> Result<MetaSearchResult, ProviderError> {
+        if cancel.is_cancelled() {
+            return Err(ProviderError::Cancelled);
+        }
+        if let Some(cache) = &self.result_cache {
+            if let Some(cached) = cache.get(query).await {
+                info!(
+                    "Returning cached meta-search result for: {} ({} papers)",
+                    query.query,
+                    cached.papers.len()
+                );
+                return Ok(cached);
+            }
+        }
         let start_time = Instant::now();
         info!(
             "Starting meta-search for: {} (type: {:?})",
             query.query, query.search_type
         );
 
-        // Create search context
-        let context = self.create_search_context();
-
         // Filter providers based on query type and supported features
-        let suitable_providers = self.filter_providers_for_query(query);
+        let suitable_providers = self.filter_providers_for_query(query).await;
         info!(
             "Using {} providers for search: {:?}",
             suitable_providers.len(),
@@ -282,12 +873,22 @@ impl MetaSearchClient {
         );
 
         // Search providers in parallel
-        let (provider_results, provider_errors) = self
-            .execute_parallel_search(suitable_providers, query, &context)
-            .await;
+        let (provider_results, provider_errors, timings) = self
+            .execute_parallel_search(suitable_providers, query, context, cancel)
+            .await?;
 
         // Aggregate results
-        let meta_result = self.aggregate_results(&provider_results, provider_errors, start_time);
+        let mut meta_result = self.aggregate_results(
+            &provider_results,
+            provider_errors,
+            start_time,
+            self.min_year_for(query),
+            query.offset,
+        );
+        meta_result.timings.extend(timings);
+        if self.config.relevance_scoring {
+            self.score_papers(&mut meta_result, &query.query);
+        }
 
         info!(
             "Meta-search completed: {} total papers from {} providers in {:?}",
@@ -296,6 +897,14 @@ impl MetaSearchClient {
             meta_result.total_search_time
         );
 
+        if let Some(cache) = &self.result_cache {
+            if meta_result.successful_providers > 0 {
+                if let Err(e) = cache.insert(query, &meta_result).await {
+                    warn!("Failed to cache search result for {}: {}", query.query, e);
+                }
+            }
+        }
+
         Ok(meta_result)
     }
 
@@ -304,7 +913,7 @@ impl MetaSearchClient {
         let normalized_doi = Self::normalize_doi(doi);
         info!("Searching for DOI: {}", normalized_doi);
 
-        let context = self.create_search_context();
+        let context = self.default_search_context();
         let doi_providers = self.select_doi_providers();
 
         for provider in doi_providers {
@@ -320,6 +929,78 @@ impl MetaSearchClient {
         Ok(None)
     }
 
+    /// Look up a DOI with only the highest-priority DOI-capable provider not
+    /// known to be unhealthy, returning the provider's name with the paper.
+    /// Cheaper than [`Self::get_by_doi`] or a DOI search when that provider
+    /// knows the paper; `None` when it doesn't or none is healthy.
+    pub async fn get_by_doi_from_top_provider(
+        &self,
+        doi: &str,
+    ) -> Result<Option<(String, PaperMetadata)>, ProviderError> {
+        let normalized_doi = Self::normalize_doi(doi);
+        let unhealthy = self.known_unhealthy_providers().await;
+        let Some(provider) = self
+            .select_doi_providers()
+            .into_iter()
+            .find(|provider| !unhealthy.contains(provider.name()))
+        else {
+            debug!("No healthy DOI-capable provider for {}", normalized_doi);
+            return Ok(None);
+        };
+
+        let context = self.default_search_context();
+        let paper = self
+            .try_provider_for_doi(&provider, &normalized_doi, &context)
+            .await?;
+        Ok(paper.map(|paper| (provider.name().to_string(), paper)))
+    }
+
+    /// Resolve several DOIs concurrently.
+    ///
+    /// Each DOI walks the DOI-capable providers in priority order, exactly like
+    /// [`Self::get_by_doi`]. At most `max_parallel_providers` DOIs are resolved at
+    /// once, and DOIs that normalize to the same value are only looked up once.
+    /// The returned map has an entry for every input DOI; lookups that fail or
+    /// find nothing map to `None`.
+    pub async fn get_by_dois(&self, dois: &[String]) -> HashMap<String, Option<PaperMetadata>> {
+        let mut seen = HashSet::new();
+        let unique_dois: Vec<String> = dois
+            .iter()
+            .map(|doi| Self::normalize_doi(doi))
+            .filter(|doi| seen.insert(doi.clone()))
+            .collect();
+
+        let concurrency = self.config.max_parallel_providers.max(1);
+        info!(
+            "Resolving {} DOIs ({} unique) with concurrency {}",
+            dois.len(),
+            unique_dois.len(),
+            concurrency
+        );
+
+        let resolved: HashMap<String, Option<PaperMetadata>> = futures::stream::iter(unique_dois)
+            .map(|doi| async move {
+                let paper = match self.get_by_doi(&doi).await {
+                    Ok(paper) => paper,
+                    Err(e) => {
+                        warn!("Failed to resolve DOI {}: {}", doi, e);
+                        None
+                    }
+                };
+                (doi, paper)
+            })
+            .buffer_unordered(concurrency)
+            .collect()
+            .await;
+
+        dois.iter()
+            .map(|doi| {
+                let paper = resolved.get(&Self::normalize_doi(doi)).cloned().flatten();
+                (doi.clone(), paper)
+            })
+            .collect()
+    }
+
     /// Normalize DOI format for consistent processing
     fn normalize_doi(doi: &str) -> String {
         // Remove common prefixes and normalize format
@@ -355,8 +1036,8 @@ impl MetaSearchClient {
             .filter(|p| p.supported_search_types().contains(&SearchType::Doi))
             .collect();
 
-        // Sort by priority (highest first)
-        providers.sort_by_key(|p| std::cmp::Reverse(p.priority()));
+        // Sort by priority (highest first), then tier and name
+        providers.sort_by(|a, b| provider_order(a.as_ref(), b.as_ref()));
 
         debug!(
             "Selected {} DOI-capable providers: {:?}",
@@ -375,7 +1056,7 @@ impl MetaSearchClient {
         context: &SearchContext,
     ) -> Result<Option<PaperMetadata>, ProviderError> {
         // Apply rate limiting
-        if let Err(e) = Self::apply_rate_limit(provider).await {
+        if let Err(e) = Self::apply_rate_limit(&self.rate_limit_state, provider).await {
             warn!("Rate limit hit for {}: {}", provider.name(), e);
             return Ok(None);
         }
@@ -390,7 +1071,9 @@ impl MetaSearchClient {
         doi: &str,
         context: &SearchContext,
     ) -> Result<Option<PaperMetadata>, ProviderError> {
-        match provider.get_by_doi(doi, context).await {
+        let _host_permit = self.host_limiter.acquire(provider.host().as_deref()).await;
+        let context = self.provider_context(context, provider.as_ref());
+        match provider.get_by_doi(doi, &context).await {
             Ok(Some(paper)) => {
                 info!("Found paper for DOI {} from {}", doi, provider.name());
                 Ok(Some(paper))
@@ -408,35 +1091,57 @@ impl MetaSearchClient {
         }
     }
 
-    /// Create search context with common settings
-    fn create_search_context(&self) -> SearchContext {
+    /// Context passed to providers when the caller supplies none: the
+    /// configured provider timeout and the server's user agent
+    #[must_use]
+    pub fn default_search_context(&self) -> SearchContext {
         SearchContext {
             timeout: self.config.provider_timeout,
             user_agent: "knowledge_accumulator_mcp/0.2.1 (Academic Research Tool)".to_string(),
             rate_limit: Some(Duration::from_millis(1000)),
             headers: HashMap::new(),
+            max_response_bytes: self.config.max_response_bytes,
+            default_charset: self.default_charset,
         }
     }
 
     /// Filter providers based on query characteristics
-    fn filter_providers_for_query(&self, query: &SearchQuery) -> Vec<Arc<dyn SourceProvider>> {
+    async fn filter_providers_for_query(
+        &self,
+        query: &SearchQuery,
+    ) -> Vec<Arc<dyn SourceProvider>> {
         let mut suitable = Vec::new();
 
         for provider in &self.providers {
-            // Check if provider supports the search type
-            if provider
-                .supported_search_types()
-                .contains(&query.search_type)
-                || provider
-                    .supported_search_types()
-                    .contains(&SearchType::Auto)
+            // Check if provider supports the search type. Full-text search
+            // needs a dedicated endpoint, so a catch-all `Auto` doesn't count.
+            let supported = provider.supported_search_types();
+            if supported.contains(&query.search_type)
+                || (query.search_type != SearchType::FullText
+                    && supported.contains(&SearchType::Auto))
             {
                 suitable.push(provider.clone());
             }
         }
 
+        // Skip providers known to be down, unless that would leave none
+        let unhealthy = self.known_unhealthy_providers().await;
+        if suitable.iter().any(|p| !unhealthy.contains(p.name())) {
+            suitable.retain(|provider| {
+                let healthy = !unhealthy.contains(provider.name());
+                if !healthy {
+                    debug!(
+                        "Skipping provider {} (failed health check)",
+                        provider.name()
+                    );
+                }
+                healthy
+            });
+        }
+
         // Apply intelligent priority ordering based on query characteristics
-        Self::apply_intelligent_priority_ordering(&mut suitable, query);
+        let penalties = self.failure_penalties().await;
+        Self::apply_intelligent_priority_ordering(&mut suitable, query, &penalties);
 
         suitable
     }
@@ -447,54 +1152,146 @@ impl MetaSearchClient {
         providers: Vec<Arc<dyn SourceProvider>>,
         query: &SearchQuery,
         context: &SearchContext,
-    ) -> (Vec<(String, ProviderResult)>, HashMap<String, String>) {
+        cancel: &CancellationToken,
+    ) -> Result<ParallelSearchOutcome, ProviderError> {
         let mut provider_results = Vec::new();
         let mut provider_errors = HashMap::new();
+        let mut timings = HashMap::new();
 
         // Use adaptive semaphore sizing based on provider performance
         let adaptive_size = self
             .calculate_adaptive_semaphore_size(providers.len())
             .await;
-        let semaphore = Arc::new(tokio::sync::Semaphore::new(adaptive_size));
-
-        let mut tasks = Vec::new();
-        for provider in providers {
-            let provider = provider.clone();
+        let semaphore = Arc::new(Semaphore::new(adaptive_size.max(1)));
+
+        // `providers` is in priority order. A provider's task is only spawned
+        // once a permit is free, so lower-priority providers can't take a slot
+        // ahead of higher-priority ones. Its host slot is taken before the
+        // shared permit, so waiting on a capped host doesn't hold a shared slot.
+        let mut pending: VecDeque<(usize, Arc<dyn SourceProvider>)> =
+            providers.into_iter().enumerate().collect();
+        let dispatch = |tasks: &mut JoinSet<_>,
+                        permits: DispatchPermits,
+                        index: usize,
+                        provider: Arc<dyn SourceProvider>| {
             let query = query.clone();
-            let context = context.clone();
-            let semaphore = semaphore.clone();
-            let timeout_duration = self.config.provider_timeout;
+            let context = self.provider_context(context, provider.as_ref());
+            let rate_limit_state = self.rate_limit_state.clone();
+            let timeout_duration = self.provider_timeout(provider.as_ref());
+            let mut retry_delay = self.retry_on_empty_delay(provider.as_ref());
 
-            let task = tokio::spawn(async move {
-                let _permit = semaphore.acquire().await.unwrap();
+            tasks.spawn(async move {
+                let _permits = permits;
                 let start_time = Instant::now();
 
-                // Apply rate limiting
-                if let Err(e) = Self::apply_rate_limit(&provider).await {
-                    return (provider.name().to_string(), Err(e), start_time.elapsed());
-                }
-
-                // Search with timeout
-                let result = timeout(timeout_duration, provider.search(&query, &context)).await;
-                let elapsed = start_time.elapsed();
+                let result = loop {
+                    // Apply rate limiting
+                    if let Err(e) = Self::apply_rate_limit(&rate_limit_state, &provider).await {
+                        break Err(e);
+                    }
 
-                let provider_name = provider.name().to_string();
-                let result = match result {
-                    Ok(Ok(provider_result)) => Ok(provider_result),
-                    Ok(Err(e)) => Err(e),
-                    Err(_) => Err(ProviderError::Timeout),
+                    // Search with timeout
+                    let result = timeout(timeout_duration, provider.search(&query, &context)).await;
+
+                    let result = match result {
+                        Ok(Ok(provider_result)) => Ok(provider_result),
+                        Ok(Err(e)) => Err(e),
+                        Err(_) => Err(ProviderError::Timeout),
+                    };
+                    // An empty answer gets one more try, if configured
+                    match (result, retry_delay.take()) {
+                        (Ok(found), Some(delay)) if found.papers.is_empty() => {
+                            debug!(
+                                "Provider {} returned no results, retrying in {:?}",
+                                provider.name(),
+                                delay
+                            );
+                            tokio::time::sleep(delay).await;
+                        }
+                        (result, _) => break result,
+                    }
                 };
 
-                (provider_name, result, elapsed)
+                (
+                    index,
+                    provider.name().to_string(),
+                    result,
+                    start_time.elapsed(),
+                )
             });
+        };
 
-            tasks.push(task);
+        let greedy_target =
+            (self.config.greedy_stop && self.config.continue_on_failure).then(|| {
+                usize::try_from(query.max_results)
+                    .unwrap_or(usize::MAX)
+                    .max(1)
+            });
+        let min_year = self.min_year_for(query);
+        let mut unique_keys = HashSet::new();
+
+        // Dropping the set (or cancelling) aborts any provider still running
+        let mut tasks = JoinSet::new();
+        let mut outcomes = Vec::new();
+        while !(pending.is_empty() && tasks.is_empty()) {
+            let next_host = pending.front().and_then(|(_, provider)| provider.host());
+            tokio::select! {
+                biased;
+                () = cancel.cancelled() => {
+                    info!("Meta-search cancelled, aborting {} provider tasks", tasks.len());
+                    tasks.abort_all();
+                    return Err(ProviderError::Cancelled);
+                }
+                permits = Self::acquire_dispatch_permits(
+                    self.host_limiter.clone(),
+                    semaphore.clone(),
+                    next_host,
+                ), if !pending.is_empty() => {
+                    if let Some((index, provider)) = pending.pop_front() {
+                        dispatch(&mut tasks, permits, index, provider);
+                    }
+                }
+                joined = tasks.join_next(), if !tasks.is_empty() => match joined {
+                    Some(Ok(outcome)) => {
+                        if let (Some(_), Ok(result)) = (greedy_target, &outcome.2) {
+                            unique_keys.extend(
+                                result
+                                    .papers
+                                    .iter()
+                                    .filter(|paper| {
+                                        min_year.map_or(true, |min_year| {
+                                            paper.year.map_or(true, |year| year >= min_year)
+                                        })
+                                    })
+                                    .map(MetaSearchResult::paper_key),
+                            );
+                        }
+                        outcomes.push(outcome);
+                        if greedy_target.is_some_and(|target| unique_keys.len() >= target)
+                            && !(tasks.is_empty() && pending.is_empty())
+                        {
+                            info!(
+                                "Gathered {} unique results, not waiting for {} remaining providers",
+                                unique_keys.len(),
+                                tasks.len() + pending.len()
+                            );
+                            tasks.abort_all();
+                            break;
+                        }
+                    }
+                    Some(Err(e)) => error!("Task failed: {}", e),
+                    None => break,
+                },
+            }
         }
+        // Keep provider order regardless of completion order
+        outcomes.sort_by_key(|(index, ..)| *index);
 
         // Collect results and update statistics
-        for task in tasks {
-            match task.await {
-                Ok((provider_name, Ok(result), elapsed)) => {
+        for (_, provider_name, result, elapsed) in outcomes {
+            timings.insert(provider_name.clone(), elapsed);
+            match result {
+                Ok(result) => {
                     #[allow(clippy::cast_precision_loss)]
                     let response_time_ms = elapsed.as_millis().min(u128::from(u64::MAX)) as f64;
                     info!(
@@ -504,21 +1301,19 @@ impl MetaSearchClient {
                         response_time_ms
                     );
 
-                    // Update provider statistics asynchronously
-                    let provider_stats_clone = self.provider_stats.clone();
-                    let provider_name_clone = provider_name.clone();
-                    tokio::spawn(async move {
-                        Self::update_provider_stats_static(
-                            &provider_stats_clone,
-                            &provider_name_clone,
-                            response_time_ms,
-                        )
-                        .await;
-                    });
+                    // Update provider statistics
+                    Self::update_provider_stats_static(
+                        &self.provider_stats,
+                        &provider_name,
+                        response_time_ms,
+                        true,
+                        self.config.failure_decay,
+                    )
+                    .await;
 
                     provider_results.push((provider_name, result));
                 }
-                Ok((provider_name, Err(error), elapsed)) => {
+                Err(error) => {
                     #[allow(clippy::cast_precision_loss)]
                     let response_time_ms = elapsed.as_millis().min(u128::from(u64::MAX)) as f64;
                     warn!(
@@ -527,34 +1322,35 @@ impl MetaSearchClient {
                     );
 
                     // Update stats even for failed requests to track response times
-                    let provider_stats_clone = self.provider_stats.clone();
-                    let provider_name_clone = provider_name.clone();
-                    tokio::spawn(async move {
-                        Self::update_provider_stats_static(
-                            &provider_stats_clone,
-                            &provider_name_clone,
-                            response_time_ms,
-                        )
-                        .await;
-                    });
+                    // and the rolling failure rate
+                    Self::update_provider_stats_static(
+                        &self.provider_stats,
+                        &provider_name,
+                        response_time_ms,
+                        false,
+                        self.config.failure_decay,
+                    )
+                    .await;
 
                     provider_errors.insert(provider_name, error.to_string());
                 }
-                Err(e) => {
-                    error!("Task failed: {}", e);
-                }
             }
         }
 
-        (provider_results, provider_errors)
+        Ok((provider_results, provider_errors, timings))
     }
 
     /// Apply intelligent priority ordering based on query characteristics
     fn apply_intelligent_priority_ordering(
         providers: &mut Vec<Arc<dyn SourceProvider>>,
         query: &SearchQuery,
+        failure_penalties: &HashMap<String, i32>,
     ) {
         let query_lower = query.query.to_lowercase();
+        let abstract_required = query
+            .params
+            .get(ABSTRACT_REQUIRED_PARAM)
+            .is_some_and(|value| value == "true");
 
         // Create priority adjustments based on query analysis
         let mut provider_scores: Vec<(Arc<dyn SourceProvider>, i32)> = providers
@@ -576,12 +1372,24 @@ impl MetaSearchClient {
                 adjusted_priority +=
                     Self::calculate_temporal_priority_boost(provider, &query_lower);
 
+                // Recently flaky providers sink gradually instead of being skipped
+                adjusted_priority -= failure_penalties.get(provider.name()).copied().unwrap_or(0);
+
+                if abstract_required && !provider.provides_abstracts() {
+                    adjusted_priority -= NO_ABSTRACT_PRIORITY_PENALTY;
+                }
+
                 (provider.clone(), adjusted_priority)
             })
             .collect();
 
-        // Sort by adjusted priority (highest first)
-        provider_scores.sort_by_key(|(_, score)| std::cmp::Reverse(*score));
+        // Sort by adjusted priority (highest first); equal scores fall back
+        // to the tier and name order
+        provider_scores.sort_by(|(a, a_score), (b, b_score)| {
+            b_score
+                .cmp(a_score)
+                .then_with(|| provider_order(a.as_ref(), b.as_ref()))
+        });
 
         // Update the providers vector with the new ordering
         *providers = provider_scores
@@ -643,6 +1451,7 @@ impl MetaSearchClient {
                 "unpaywall" => 12,        // Specialized in open access
                 "core" => 10,             // Large open access collection
                 "mdpi" => 8,              // Open access publisher
+                "zenodo" => 6,            // Open repository with DOIs
                 "biorxiv" | "arxiv" => 5, // Open preprints
                 _ => 0,
             }
@@ -700,6 +1509,14 @@ impl MetaSearchClient {
                     _ => 0,
                 }
             }
+            SearchType::FullText => {
+                // Providers with the largest full-text indexes first
+                match provider_name {
+                    "core" => 8,
+                    "semantic_scholar" => 6,
+                    _ => 0,
+                }
+            }
             SearchType::Auto => {
                 0 // No specific boost for auto searches
             }
@@ -708,17 +1525,12 @@ impl MetaSearchClient {
 
     /// Calculate content availability priority boost
     fn calculate_content_priority_boost(provider: &Arc<dyn SourceProvider>, query: &str) -> i32 {
-        let provider_name = provider.name();
-
         // If query suggests need for full-text/PDF access
         if query.contains("pdf") || query.contains("full text") || query.contains("download") {
-            match provider_name {
-                "arxiv" | "biorxiv" => 12,                           // Always has PDFs
-                "unpaywall" => 10,                                   // Specialized in free PDFs
-                "semantic_scholar" | "pubmed_central" | "mdpi" => 8, // Often has PDF links/full text
-                "ssrn" | "core" => 6,                                // Often has PDFs/full text
-                "sci_hub" => 15, // Always tries for PDFs (but lowest base priority)
-                _ => 0,
+            if provider.provides_fulltext() {
+                FULLTEXT_PRIORITY_BOOST
+            } else {
+                0
             }
         }
         // If query suggests need for recent/preprint content
@@ -727,7 +1539,7 @@ impl MetaSearchClient {
             || query.contains("2024")
             || query.contains("2023")
         {
-            match provider_name {
+            match provider.name() {
                 "arxiv" | "biorxiv" => 10, // Latest preprints
                 "ssrn" => 8,               // Recent working papers
                 "openreview" => 6,         // Recent ML papers
@@ -953,24 +1765,77 @@ impl MetaSearchClient {
         oa_keywords.iter().any(|&keyword| query.contains(keyword))
     }
 
-    /// Apply rate limiting for a provider
-    async fn apply_rate_limit(provider: &Arc<dyn SourceProvider>) -> Result<(), ProviderError> {
-        // Simple rate limiting - wait for base delay since last request
-        let base_delay = provider.base_delay();
+    /// Save the per-host request times so the next start keeps spacing
+    /// requests. Does nothing when the state is not persisted.
+    pub fn save_rate_limit_state(&self) -> crate::Result<()> {
+        if let Some(path) = &self.rate_limit_state_file {
+            self.rate_limit_state.save(path)?;
+            debug!("Saved rate limit state to {}", path.display());
+        }
+        Ok(())
+    }
+
+    /// Wait for the next provider's host slot (if its host is capped), then
+    /// for a shared provider slot
+    async fn acquire_dispatch_permits(
+        host_limiter: Arc<HostConcurrencyLimiter>,
+        semaphore: Arc<Semaphore>,
+        host: Option<String>,
+    ) -> DispatchPermits {
+        let host = host_limiter.acquire(host.as_deref()).await;
+        let shared = semaphore
+            .acquire_owned()
+            .await
+            .expect("provider semaphore is never closed");
+        DispatchPermits {
+            _host: host,
+            _shared: shared,
+        }
+    }
 
-        // For now, just wait the base delay
-        // In a more sophisticated implementation, we'd track per-provider timing
-        tokio::time::sleep(base_delay).await;
+    /// Apply rate limiting for a provider: wait until its base delay has
+    /// passed since the previous request to the same host (or provider, if
+    /// it has no fixed host)
+    async fn apply_rate_limit(
+        state: &RateLimitState,
+        provider: &Arc<dyn SourceProvider>,
+    ) -> Result<(), ProviderError> {
+        let key = provider
+            .host()
+            .unwrap_or_else(|| provider.name().to_string());
+        let wait = state.reserve(&key, provider.base_delay());
+        if !wait.is_zero() {
+            tokio::time::sleep(wait).await;
+        }
 
         Ok(())
     }
 
-    /// Aggregate results from multiple providers
+    /// Year cutoff for a search: the query's [`MIN_YEAR_PARAM`] when set,
+    /// otherwise the configured default
+    fn min_year_for(&self, query: &SearchQuery) -> Option<u32> {
+        let Some(value) = query.params.get(MIN_YEAR_PARAM) else {
+            return self.config.min_year;
+        };
+        match value.trim().parse::<u32>() {
+            Ok(0) => None,
+            Ok(year) => Some(year),
+            Err(_) => {
+                warn!("Ignoring invalid {} parameter: {}", MIN_YEAR_PARAM, value);
+                self.config.min_year
+            }
+        }
+    }
+
+    /// Aggregate results from multiple providers, dropping papers published
+    /// before `min_year` (undated papers are kept)
     fn aggregate_results(
         &self,
         provider_results: &[(String, ProviderResult)],
         provider_errors: HashMap<String, String>,
         start_time: Instant,
+        min_year: Option<u32>,
+        offset: u32,
     ) -> MetaSearchResult {
         let mut all_papers = Vec::new();
         let mut by_source = HashMap::new();
@@ -978,68 +1843,198 @@ impl MetaSearchClient {
 
         // Collect all papers and organize by source
         for (source, result) in provider_results {
-            by_source.insert(source.clone(), result.papers.clone());
-            provider_metadata.insert(source.clone(), result.metadata.clone());
-            all_papers.extend(result.papers.clone());
+            let mut papers = result.papers.clone();
+            if let Some(min_year) = min_year {
+                papers.retain(|paper| paper.year.map_or(true, |year| year >= min_year));
+            }
+            let medline = self
+                .providers
+                .iter()
+                .any(|provider| provider.name() == source && provider.medline_author_names());
+            self.normalize_authors(&mut papers, medline);
+            if self.config.clean_abstracts {
+                Self::clean_abstracts(&mut papers);
+            }
+            by_source.insert(source.clone(), papers.clone());
+            provider_metadata.insert(source.clone(), Self::provider_metadata(result, offset));
+            all_papers.extend(papers);
         }
 
         // Deduplicate if requested
         if self.config.deduplicate_results {
-            all_papers = Self::deduplicate_papers(all_papers);
-        }
-
-        // Filter by relevance score if needed
-        if self.config.min_relevance_score > 0.0 {
-            // Note: We'd need to add relevance scoring to PaperMetadata
-            // For now, include all papers
+            all_papers = Self::deduplicate_papers(all_papers, self.config.abstract_dedup_threshold);
         }
 
-        // Sort by source priority and then by some relevance metric
-        // For now, just keep the order
-
+        let total_search_time = start_time.elapsed();
         MetaSearchResult {
             papers: all_papers,
             by_source,
-            total_search_time: start_time.elapsed(),
+            total_search_time,
             successful_providers: provider_results.len(),
             failed_providers: provider_errors.len(),
             provider_errors,
             provider_metadata,
+            timings: HashMap::from([("total".to_string(), total_search_time)]),
+            score_breakdowns: HashMap::new(),
         }
     }
 
-    /// Deduplicate papers based on DOI and title similarity
-    fn deduplicate_papers(papers: Vec<PaperMetadata>) -> Vec<PaperMetadata> {
+    /// Score the papers against `query`, drop those under
+    /// `min_relevance_score` and order the rest best first. A paper found by
+    /// several providers is credited with the highest of their priorities.
+    fn score_papers(&self, result: &mut MetaSearchResult, query: &str) {
+        let current_year = u32::try_from(chrono::Utc::now().year()).unwrap_or_default();
+        let scorer = RelevanceScorer::new(query, current_year);
+        let priorities: HashMap<&str, u8> = self
+            .providers
+            .iter()
+            .map(|provider| (provider.name(), provider.priority()))
+            .collect();
+
+        let mut paper_priorities: HashMap<String, u8> = HashMap::new();
+        for (source, papers) in &result.by_source {
+            let priority = priorities.get(source.as_str()).copied().unwrap_or_default();
+            for paper in papers {
+                let entry = paper_priorities
+                    .entry(MetaSearchResult::paper_key(paper))
+                    .or_default();
+                *entry = (*entry).max(priority);
+            }
+        }
+
+        let mut ranked: Vec<(f64, PaperMetadata)> = std::mem::take(&mut result.papers)
+            .into_iter()
+            .filter_map(|paper| {
+                let key = MetaSearchResult::paper_key(&paper);
+                let priority = paper_priorities.get(&key).copied().unwrap_or_default();
+                let breakdown = scorer.breakdown(&paper, priority);
+                let score = relevance::total_score(&breakdown);
+                if score < self.config.min_relevance_score {
+                    return None;
+                }
+                result.score_breakdowns.insert(key, breakdown);
+                Some((score, paper))
+            })
+            .collect();
+        ranked.sort_by(|a, b| b.0.total_cmp(&a.0));
+        result.papers = ranked.into_iter().map(|(_, paper)| paper).collect();
+    }
+
+    /// The provider's own metadata plus `truncated` (whether more results
+    /// exist past this page, which started at `offset`) and
+    /// `total_available` when the provider reported a total count
+    fn provider_metadata(result: &ProviderResult, offset: u32) -> HashMap<String, String> {
+        let mut metadata = result.metadata.clone();
+        let returned = u32::try_from(result.papers.len()).unwrap_or(u32::MAX);
+        let truncated = result.has_more
+            || result
+                .total_available
+                .is_some_and(|total| offset.saturating_add(returned) < total);
+        metadata.insert("truncated".to_string(), truncated.to_string());
+        if let Some(total) = result.total_available {
+            metadata.insert("total_available".to_string(), total.to_string());
+        }
+        metadata
+    }
+
+    /// Rewrite author names into the configured canonical form, reading them
+    /// as MEDLINE names when `medline` is set. The names as reported by the
+    /// provider are kept in `raw_authors`.
+    fn normalize_authors(&self, papers: &mut [PaperMetadata], medline: bool) {
+        let normalize = if medline {
+            normalize_medline_author_name
+        } else {
+            normalize_author_name
+        };
+        for paper in papers {
+            if paper.raw_authors.is_empty() {
+                paper.raw_authors = std::mem::take(&mut paper.authors);
+            }
+            paper.authors = paper
+                .raw_authors
+                .iter()
+                .map(|name| normalize(name, self.config.author_format))
+                .filter(|name| !name.is_empty())
+                .collect();
+        }
+    }
+
+    /// Strip labels and copyright boilerplate from the papers' abstracts
+    fn clean_abstracts(papers: &mut [PaperMetadata]) {
+        for paper in papers {
+            if let Some(abstract_text) = &paper.abstract_text {
+                paper.abstract_text = Some(clean_abstract(abstract_text));
+            }
+        }
+    }
+
+    /// Deduplicate papers based on DOI and title similarity, and with
+    /// `abstract_threshold` on abstract similarity. The first copy of a paper
+    /// is kept, taking the license from a later copy if it has none. It is
+    /// open access if any copy says so.
+    fn deduplicate_papers(
+        papers: Vec<PaperMetadata>,
+        abstract_threshold: Option<f64>,
+    ) -> Vec<PaperMetadata> {
         let original_count = papers.len();
-        let mut unique_papers = Vec::new();
-        let mut seen_dois = HashSet::new();
-        let mut seen_titles = HashSet::new();
+        let mut unique_papers: Vec<PaperMetadata> = Vec::new();
+        let mut seen_dois: HashMap<String, usize> = HashMap::new();
+        let mut seen_titles: HashMap<String, usize> = HashMap::new();
+        let mut seen_abstracts: Vec<(AbstractSignature, usize)> = Vec::new();
 
         for paper in papers {
-            let mut is_duplicate = false;
-
-            // Check DOI duplicates
-            if !paper.doi.is_empty() {
-                if seen_dois.contains(&paper.doi) {
-                    is_duplicate = true;
-                } else {
-                    seen_dois.insert(paper.doi.clone());
-                }
+            let normalized_title = paper
+                .title
+                .as_ref()
+                .map(|title| title.to_lowercase().replace([' ', '\t', '\n'], ""));
+
+            // Check DOI duplicates, then title duplicates (case-insensitive, normalized)
+            let doi_match = (!paper.doi.is_empty())
+                .then(|| seen_dois.get(&paper.doi).copied())
+                .flatten();
+            let title_match = if doi_match.is_none() {
+                normalized_title
+                    .as_ref()
+                    .and_then(|title| seen_titles.get(title).copied())
+            } else {
+                None
+            };
+            // Comparing abstracts is the costly part, so it only runs when
+            // neither the DOI nor the title matched
+            let signature = abstract_threshold
+                .filter(|_| doi_match.is_none() && title_match.is_none())
+                .and(paper.abstract_text.as_deref())
+                .and_then(AbstractSignature::new);
+            let abstract_match = signature.as_ref().and_then(|signature| {
+                seen_abstracts
+                    .iter()
+                    .find(|(seen, _)| {
+                        abstract_threshold.is_some_and(|t| signature.similarity(seen) >= t)
+                    })
+                    .map(|(_, index)| *index)
+            });
+            let index = doi_match
+                .or(title_match)
+                .or(abstract_match)
+                .unwrap_or(unique_papers.len());
+            if let Some(signature) = signature.filter(|_| abstract_match.is_none()) {
+                seen_abstracts.push((signature, index));
             }
 
-            // Check title duplicates (case-insensitive, normalized)
-            if !is_duplicate {
-                if let Some(title) = &paper.title {
-                    let normalized_title = title.to_lowercase().replace([' ', '\t', '\n'], "");
-                    if seen_titles.contains(&normalized_title) {
-                        is_duplicate = true;
-                    } else {
-                        seen_titles.insert(normalized_title);
-                    }
-                }
+            if !paper.doi.is_empty() && doi_match.is_none() {
+                seen_dois.insert(paper.doi.clone(), index);
+            }
+            if let Some(title) = normalized_title.filter(|_| doi_match.is_none()) {
+                seen_titles.entry(title).or_insert(index);
             }
 
-            if !is_duplicate {
+            if let Some(kept) = unique_papers.get_mut(index) {
+                if kept.license.is_none() {
+                    kept.license = paper.license;
+                }
+                // `Some(true)` outranks `Some(false)`, which outranks unknown
+                kept.is_open_access = kept.is_open_access.max(paper.is_open_access);
+            } else {
                 unique_papers.push(paper);
             }
         }
@@ -1054,41 +2049,80 @@ impl MetaSearchClient {
     }
 
     /// Try to get a PDF URL from any provider, cascading through them by priority
-    #[allow(clippy::cognitive_complexity)]
     pub async fn get_pdf_url_cascade(&self, doi: &str) -> Result<Option<String>, ProviderError> {
+        Ok(self
+            .get_pdf_source_cascade(doi)
+            .await?
+            .map(|(_, pdf_url)| pdf_url))
+    }
+
+    /// Like [`Self::get_pdf_url_cascade`], also returning the name of the
+    /// provider that supplied the PDF URL as `(provider, url)`
+    pub async fn get_pdf_source_cascade(
+        &self,
+        doi: &str,
+    ) -> Result<Option<(String, String)>, ProviderError> {
+        Ok(self
+            .get_pdf_sources_cascade(doi, 1)
+            .await?
+            .into_iter()
+            .next())
+    }
+
+    /// Ask providers for a PDF URL in priority order, collecting up to `limit`
+    /// distinct `(provider, url)` pairs (0 = ask every provider). Fails only
+    /// when no URL was found and a provider returned an error.
+    #[allow(clippy::cognitive_complexity)]
+    pub async fn get_pdf_sources_cascade(
+        &self,
+        doi: &str,
+        limit: usize,
+    ) -> Result<Vec<(String, String)>, ProviderError> {
         info!("Attempting cascade PDF retrieval for DOI: {}", doi);
 
-        let context = self.create_search_context();
+        let context = self.default_search_context();
 
-        // Sort providers by priority (highest first)
+        // Sort providers by priority (highest first), then tier and name
         let mut providers: Vec<_> = self.providers.iter().collect();
-        providers.sort_by_key(|p| std::cmp::Reverse(p.priority()));
+        providers.sort_by(|a, b| provider_order(a.as_ref(), b.as_ref()));
 
         let mut last_error = None;
+        let mut sources: Vec<(String, String)> = Vec::new();
 
         for provider in providers {
+            if limit > 0 && sources.len() >= limit {
+                break;
+            }
             info!(
-                "Trying PDF retrieval from provider: {} (priority: {})",
+                "Trying PDF retrieval from provider: {} (priority: {}, tier: {:?})",
                 provider.name(),
-                provider.priority()
+                provider.priority(),
+                provider.tier()
             );
 
             // Apply rate limiting
-            if let Err(e) = Self::apply_rate_limit(provider).await {
+            if let Err(e) = Self::apply_rate_limit(&self.rate_limit_state, provider).await {
                 warn!("Rate limit hit for {}: {}", provider.name(), e);
                 last_error = Some(e);
                 continue;
             }
 
             // Try to get PDF URL from this provider
-            match provider.get_pdf_url(doi, &context).await {
+            let host_permit = self.host_limiter.acquire(provider.host().as_deref()).await;
+            let pdf_result = provider
+                .get_pdf_url(doi, &self.provider_context(&context, provider.as_ref()))
+                .await;
+            drop(host_permit);
+            match pdf_result {
                 Ok(Some(pdf_url)) if !pdf_url.is_empty() => {
                     info!(
                         "Successfully found PDF URL from {}: {}",
                         provider.name(),
                         pdf_url
                     );
-                    return Ok(Some(pdf_url));
+                    if !sources.iter().any(|(_, url)| *url == pdf_url) {
+                        sources.push((provider.name().to_string(), pdf_url));
+                    }
                 }
                 Ok(Some(empty_url)) => {
                     warn!(
@@ -1114,11 +2148,15 @@ impl MetaSearchClient {
             }
         }
 
+        if !sources.is_empty() {
+            return Ok(sources);
+        }
+
         // If we get here, no provider could provide a PDF
         last_error.map_or_else(
             || {
                 info!("No provider could find a PDF for DOI: {}", doi);
-                Ok(None)
+                Ok(Vec::new())
             },
             |error| Err(error),
         )
@@ -1128,6 +2166,7 @@ impl MetaSearchClient {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::client::providers::traits::tests::{paper, MockProvider};
 
     #[tokio::test]
     async fn test_meta_search_client_creation() {
@@ -1147,6 +2186,7 @@ mod tests {
         assert!(providers.contains(&"arxiv".to_string()));
         assert!(providers.contains(&"biorxiv".to_string()));
         assert!(providers.contains(&"core".to_string()));
+        assert!(providers.contains(&"zenodo".to_string()));
         assert!(providers.contains(&"crossref".to_string()));
         assert!(providers.contains(&"semantic_scholar".to_string()));
         assert!(providers.contains(&"unpaywall".to_string()));
@@ -1154,6 +2194,21 @@ mod tests {
         assert!(providers.contains(&"sci_hub".to_string()));
     }
 
+    #[tokio::test]
+    async fn test_provider_listing_safe_mode_excludes_restricted_sources() {
+        let config = Config {
+            safe_mode: true,
+            ..Config::default()
+        };
+        let meta_config = MetaSearchConfig::from_config(&config);
+        let client = MetaSearchClient::new(config, meta_config).unwrap();
+
+        let providers = client.providers();
+        assert!(!providers.contains(&"sci_hub".to_string()));
+        assert!(!providers.contains(&"researchgate".to_string()));
+        assert!(providers.contains(&"arxiv".to_string()));
+    }
+
     #[tokio::test]
     async fn test_deduplication() {
         let config = Config::default();
@@ -1165,25 +2220,1137 @@ mod tests {
                 doi: "10.1038/nature12373".to_string(),
                 title: Some("Test Paper".to_string()),
                 authors: vec!["Author 1".to_string()],
+                raw_authors: Vec::new(),
                 journal: Some("Nature".to_string()),
                 year: Some(2023),
                 abstract_text: None,
                 pdf_url: None,
                 file_size: None,
+                license: None,
+                is_open_access: None,
             },
             PaperMetadata {
                 doi: "10.1038/nature12373".to_string(), // Same DOI
                 title: Some("Test Paper".to_string()),
                 authors: vec!["Author 1".to_string()],
+                raw_authors: Vec::new(),
                 journal: Some("Nature".to_string()),
                 year: Some(2023),
                 abstract_text: None,
                 pdf_url: None,
                 file_size: None,
+                license: None,
+                is_open_access: None,
             },
         ];
 
-        let deduplicated = MetaSearchClient::deduplicate_papers(papers);
+        let deduplicated = MetaSearchClient::deduplicate_papers(papers, None);
         assert_eq!(deduplicated.len(), 1);
     }
+
+    #[test]
+    fn test_deduplicate_papers_collapses_similar_abstracts() {
+        let abstract_text = "We introduce a transformer architecture for protein structure \
+                             prediction that combines evolutionary couplings with geometric \
+                             attention, reaching atomic accuracy on most targets of the \
+                             fourteenth critical assessment of structure prediction.";
+        let mut preprint = PaperMetadata::new("10.1101/2021.01.01.000001".to_string());
+        preprint.title = Some("Highly accurate protein structure prediction".to_string());
+        preprint.abstract_text = Some(abstract_text.to_string());
+        let mut published = PaperMetadata::new("10.1038/s41586-021-03819-2".to_string());
+        published.title =
+            Some("Highly accurate protein structure prediction with AlphaFold".to_string());
+        published.abstract_text = Some(format!("Abstract: {abstract_text}"));
+        let mut unrelated = PaperMetadata::new("10.1000/unrelated".to_string());
+        unrelated.abstract_text = Some(
+            "Graph neural networks are applied to traffic forecasting in dense urban road \
+             networks, where sensor readings are missing for long stretches of time and \
+             must be imputed before prediction."
+                .to_string(),
+        );
+        let papers = vec![preprint, published, unrelated];
+
+        let deduplicated = MetaSearchClient::deduplicate_papers(papers.clone(), Some(0.8));
+        let dois: Vec<&str> = deduplicated.iter().map(|p| p.doi.as_str()).collect();
+        assert_eq!(dois, vec!["10.1101/2021.01.01.000001", "10.1000/unrelated"]);
+
+        assert_eq!(MetaSearchClient::deduplicate_papers(papers, None).len(), 3);
+    }
+
+    #[test]
+    fn test_aggregate_results_normalizes_author_names() {
+        let client = MetaSearchClient::with_providers(
+            Vec::new(),
+            MetaSearchConfig {
+                author_format: AuthorNameFormat::LastFirst,
+                ..MetaSearchConfig::default()
+            },
+        );
+        let mut paper = PaperMetadata::new("10.1000/authors".to_string());
+        paper.authors = vec![
+            "John Smith".to_string(),
+            "Doe, Jane".to_string(),
+            "Curie M".to_string(),
+            " ".to_string(),
+        ];
+        let provider_results = vec![(
+            "mock".to_string(),
+            ProviderResult {
+                papers: vec![paper.clone()],
+                source: "mock".to_string(),
+                total_available: None,
+                search_time: Duration::ZERO,
+                has_more: false,
+                metadata: HashMap::new(),
+            },
+        )];
+
+        let result =
+            client.aggregate_results(&provider_results, HashMap::new(), Instant::now(), None, 0);
+
+        let expected = vec!["Smith, John", "Doe, Jane", "Curie, M."];
+        assert_eq!(result.papers[0].authors, expected);
+        assert_eq!(result.papers[0].raw_authors, paper.authors);
+        assert_eq!(result.by_source["mock"][0].authors, expected);
+    }
+
+    #[test]
+    fn test_aggregate_results_cleans_abstracts_when_enabled() {
+        let mut paper = PaperMetadata::new("10.1000/abstract".to_string());
+        paper.abstract_text = Some(
+            "Abstract: We study protein folding kinetics. © 2022 Elsevier Inc. All rights reserved."
+                .to_string(),
+        );
+        let provider_results = vec![(
+            "mock".to_string(),
+            ProviderResult {
+                papers: vec![paper.clone()],
+                source: "mock".to_string(),
+                total_available: None,
+                search_time: Duration::ZERO,
+                has_more: false,
+                metadata: HashMap::new(),
+            },
+        )];
+        let aggregate = |clean_abstracts| {
+            MetaSearchClient::with_providers(
+                Vec::new(),
+                MetaSearchConfig {
+                    clean_abstracts,
+                    ..MetaSearchConfig::default()
+                },
+            )
+            .aggregate_results(
+                &provider_results,
+                HashMap::new(),
+                Instant::now(),
+                None,
+                0,
+            )
+        };
+
+        let result = aggregate(true);
+        assert_eq!(
+            result.papers[0].abstract_text.as_deref(),
+            Some("We study protein folding kinetics.")
+        );
+
+        let result = aggregate(false);
+        assert_eq!(result.papers[0].abstract_text, paper.abstract_text);
+    }
+
+    #[test]
+    fn test_score_papers_orders_best_first_with_breakdowns() {
+        let mut unrelated = PaperMetadata::new("10.1000/unrelated".to_string());
+        unrelated.title = Some("Soil microbiome survey".to_string());
+        let mut matching = PaperMetadata::new("10.1000/matching".to_string());
+        matching.title = Some("Protein folding kinetics".to_string());
+        let provider_results = vec![(
+            "mock".to_string(),
+            ProviderResult {
+                papers: vec![unrelated, matching],
+                source: "mock".to_string(),
+                total_available: None,
+                search_time: Duration::ZERO,
+                has_more: false,
+                metadata: HashMap::new(),
+            },
+        )];
+        let client = MetaSearchClient::with_providers(
+            Vec::new(),
+            MetaSearchConfig {
+                relevance_scoring: true,
+                min_relevance_score: 0.1,
+                ..MetaSearchConfig::default()
+            },
+        );
+
+        let mut result =
+            client.aggregate_results(&provider_results, HashMap::new(), Instant::now(), None, 0);
+        client.score_papers(&mut result, "protein folding");
+
+        assert_eq!(result.papers.len(), 1);
+        assert_eq!(result.papers[0].doi, "10.1000/matching");
+        let breakdown = &result.score_breakdowns["doi:10.1000/matching"];
+        assert!((relevance::total_score(breakdown) - 0.4).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_aggregate_results_reports_truncated_providers() {
+        let client = MetaSearchClient::with_providers(Vec::new(), MetaSearchConfig::default());
+        let result_with = |total_available| ProviderResult {
+            papers: vec![
+                PaperMetadata::new("10.1000/one".to_string()),
+                PaperMetadata::new("10.1000/two".to_string()),
+            ],
+            source: "mock".to_string(),
+            total_available,
+            search_time: Duration::ZERO,
+            has_more: false,
+            metadata: HashMap::new(),
+        };
+        let provider_results = vec![
+            ("partial".to_string(), result_with(Some(1234))),
+            ("complete".to_string(), result_with(Some(2))),
+            ("unknown".to_string(), result_with(None)),
+        ];
+
+        let result =
+            client.aggregate_results(&provider_results, HashMap::new(), Instant::now(), None, 0);
+
+        let partial = &result.provider_metadata["partial"];
+        assert_eq!(partial["truncated"], "true");
+        assert_eq!(partial["total_available"], "1234");
+        assert_eq!(result.provider_metadata["complete"]["truncated"], "false");
+        assert_eq!(result.provider_metadata["unknown"]["truncated"], "false");
+        assert!(!result.provider_metadata["unknown"].contains_key("total_available"));
+    }
+
+    #[test]
+    fn test_aggregate_results_last_page_not_truncated() {
+        let client = MetaSearchClient::with_providers(Vec::new(), MetaSearchConfig::default());
+        let provider_results = vec![(
+            "mock".to_string(),
+            ProviderResult {
+                papers: vec![
+                    PaperMetadata::new("10.1000/eleven".to_string()),
+                    PaperMetadata::new("10.1000/twelve".to_string()),
+                ],
+                source: "mock".to_string(),
+                total_available: Some(12),
+                search_time: Duration::ZERO,
+                has_more: false,
+                metadata: HashMap::new(),
+            },
+        )];
+
+        // Results 11-12 of 12: nothing left after this page
+        let last_page =
+            client.aggregate_results(&provider_results, HashMap::new(), Instant::now(), None, 10);
+        assert_eq!(last_page.provider_metadata["mock"]["truncated"], "false");
+
+        let middle_page =
+            client.aggregate_results(&provider_results, HashMap::new(), Instant::now(), None, 5);
+        assert_eq!(middle_page.provider_metadata["mock"]["truncated"], "true");
+    }
+
+    #[test]
+    fn test_aggregate_results_min_year_drops_older_papers_keeps_undated() {
+        let client = MetaSearchClient::with_providers(
+            Vec::new(),
+            MetaSearchConfig {
+                min_year: Some(2015),
+                ..MetaSearchConfig::default()
+            },
+        );
+        let papers = [
+            ("10.1000/old", Some(2009)),
+            ("10.1000/new", Some(2021)),
+            ("10.1000/undated", None),
+        ]
+        .into_iter()
+        .map(|(doi, year)| {
+            let mut paper = PaperMetadata::new(doi.to_string());
+            paper.year = year;
+            paper
+        })
+        .collect();
+        let provider_results = vec![(
+            "mock".to_string(),
+            ProviderResult {
+                papers,
+                source: "mock".to_string(),
+                total_available: None,
+                search_time: Duration::ZERO,
+                has_more: false,
+                metadata: HashMap::new(),
+            },
+        )];
+
+        let mut query = SearchQuery {
+            query: "crispr".to_string(),
+            search_type: SearchType::Keywords,
+            max_results: 10,
+            offset: 0,
+            params: HashMap::new(),
+        };
+        let result = client.aggregate_results(
+            &provider_results,
+            HashMap::new(),
+            Instant::now(),
+            client.min_year_for(&query),
+            query.offset,
+        );
+        let dois: Vec<&str> = result.papers.iter().map(|p| p.doi.as_str()).collect();
+        assert_eq!(dois, vec!["10.1000/new", "10.1000/undated"]);
+        assert_eq!(result.by_source["mock"].len(), 2);
+
+        // Per-query override, and "0" to disable the cutoff
+        query
+            .params
+            .insert(MIN_YEAR_PARAM.to_string(), "2022".to_string());
+        assert_eq!(client.min_year_for(&query), Some(2022));
+        query
+            .params
+            .insert(MIN_YEAR_PARAM.to_string(), "0".to_string());
+        assert_eq!(client.min_year_for(&query), None);
+    }
+
+    /// Provider that answers DOI searches and lookups from a fixed table
+    fn mock_doi_provider(
+        name: &'static str,
+        priority: u8,
+        papers: &[(&str, &str)],
+    ) -> MockProvider {
+        let papers: HashMap<String, PaperMetadata> = papers
+            .iter()
+            .map(|(doi, title)| ((*doi).to_string(), paper(doi, title)))
+            .collect();
+        MockProvider::new(name)
+            .with_priority(priority)
+            .with_search_types(vec![SearchType::Doi])
+            .answering(move |query, _| Ok(papers.get(&query.query).cloned().into_iter().collect()))
+    }
+
+    fn create_mock_doi_client() -> MetaSearchClient {
+        let providers: Vec<Arc<dyn SourceProvider>> = vec![
+            Arc::new(mock_doi_provider(
+                "mock_primary",
+                90,
+                &[("10.1000/a", "Paper A"), ("10.1000/b", "Paper B")],
+            )),
+            Arc::new(mock_doi_provider(
+                "mock_fallback",
+                10,
+                &[("10.1000/b", "Fallback B"), ("10.1000/c", "Paper C")],
+            )),
+        ];
+        MetaSearchClient::with_providers(providers, MetaSearchConfig::default())
+    }
+
+    #[tokio::test]
+    async fn test_search_records_timing_for_each_provider() {
+        let client = create_mock_doi_client();
+        let query = SearchQuery {
+            query: "10.1000/b".to_string(),
+            search_type: SearchType::Doi,
+            max_results: 10,
+            offset: 0,
+            params: HashMap::new(),
+        };
+
+        let result = client.search(&query).await.unwrap();
+
+        assert_eq!(result.successful_providers, 2);
+        for provider in result.by_source.keys() {
+            assert!(
+                result.timings.contains_key(provider),
+                "{provider} should have a recorded duration"
+            );
+        }
+        assert_eq!(result.timings["total"], result.total_search_time);
+    }
+
+    #[tokio::test]
+    async fn test_get_by_dois_resolves_all() {
+        let client = create_mock_doi_client();
+        let dois = vec![
+            "10.1000/a".to_string(),
+            "doi:10.1000/b".to_string(),
+            "https://doi.org/10.1000/c".to_string(),
+        ];
+
+        let results = client.get_by_dois(&dois).await;
+
+        assert_eq!(results.len(), 3);
+        for doi in &dois {
+            assert!(
+                results.get(doi).is_some_and(Option::is_some),
+                "DOI {doi} should be resolved"
+            );
+        }
+        // Higher-priority provider wins when both know the DOI
+        assert_eq!(
+            results["doi:10.1000/b"].as_ref().unwrap().title.as_deref(),
+            Some("Paper B")
+        );
+        assert_eq!(
+            results["https://doi.org/10.1000/c"].as_ref().unwrap().doi,
+            "10.1000/c"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_by_dois_missing_doi_none() {
+        let client = create_mock_doi_client();
+        let dois = vec!["10.1000/a".to_string(), "10.1000/missing".to_string()];
+
+        let results = client.get_by_dois(&dois).await;
+
+        assert_eq!(results.len(), 2);
+        assert!(results["10.1000/a"].is_some());
+        assert!(results["10.1000/missing"].is_none());
+    }
+
+    #[tokio::test]
+    async fn test_failure_penalty_lowers_and_restores_ordering() {
+        let mut client = create_mock_doi_client();
+        client.config.max_failure_penalty = 100;
+        let query = SearchQuery {
+            query: "10.1000/a".to_string(),
+            search_type: SearchType::Doi,
+            max_results: 10,
+            offset: 0,
+            params: HashMap::new(),
+        };
+        let ordering = |providers: Vec<Arc<dyn SourceProvider>>| {
+            providers.iter().map(|p| p.name()).collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            ordering(client.filter_providers_for_query(&query).await),
+            vec!["mock_primary", "mock_fallback"]
+        );
+
+        for _ in 0..5 {
+            MetaSearchClient::update_provider_stats_static(
+                &client.provider_stats,
+                "mock_primary",
+                100.0,
+                false,
+                client.config.failure_decay,
+            )
+            .await;
+        }
+        assert_eq!(
+            ordering(client.filter_providers_for_query(&query).await),
+            vec!["mock_fallback", "mock_primary"]
+        );
+
+        for _ in 0..5 {
+            MetaSearchClient::update_provider_stats_static(
+                &client.provider_stats,
+                "mock_primary",
+                100.0,
+                true,
+                client.config.failure_decay,
+            )
+            .await;
+        }
+        assert_eq!(
+            ordering(client.filter_providers_for_query(&query).await),
+            vec!["mock_primary", "mock_fallback"]
+        );
+    }
+
+    /// Provider that records how many requests to its host are in flight
+    struct SharedHostProvider {
+        name: &'static str,
+        in_flight: Arc<std::sync::atomic::AtomicUsize>,
+        max_in_flight: Arc<std::sync::atomic::AtomicUsize>,
+    }
+
+    #[async_trait::async_trait]
+    impl SourceProvider for SharedHostProvider {
+        fn name(&self) -> &'static str {
+            self.name
+        }
+
+        fn base_delay(&self) -> Duration {
+            Duration::ZERO
+        }
+
+        fn host(&self) -> Option<String> {
+            Some("eutils.ncbi.nlm.nih.gov".to_string())
+        }
+
+        fn supported_search_types(&self) -> Vec<SearchType> {
+            vec![SearchType::Keywords]
+        }
+
+        async fn search(
+            &self,
+            _query: &SearchQuery,
+            _context: &SearchContext,
+        ) -> Result<ProviderResult, ProviderError> {
+            use std::sync::atomic::Ordering;
+
+            let current = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max_in_flight.fetch_max(current, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+
+            Ok(ProviderResult {
+                papers: Vec::new(),
+                source: self.name.to_string(),
+                total_available: None,
+                search_time: Duration::ZERO,
+                has_more: false,
+                metadata: HashMap::new(),
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_search_per_host_cap_serializes_shared_host() {
+        let in_flight = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let max_in_flight = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let providers: Vec<Arc<dyn SourceProvider>> = ["pubmed_a", "pubmed_b"]
+            .into_iter()
+            .map(|name| {
+                Arc::new(SharedHostProvider {
+                    name,
+                    in_flight: in_flight.clone(),
+                    max_in_flight: max_in_flight.clone(),
+                }) as Arc<dyn SourceProvider>
+            })
+            .collect();
+        let limits = HashMap::from([("eutils.ncbi.nlm.nih.gov".to_string(), 1)]);
+        let client = MetaSearchClient::with_providers(providers, MetaSearchConfig::default())
+            .with_per_host_concurrency(&limits);
+
+        let query = SearchQuery {
+            query: "crispr".to_string(),
+            search_type: SearchType::Keywords,
+            max_results: 10,
+            offset: 0,
+            params: HashMap::new(),
+        };
+        let result = client.search(&query).await.unwrap();
+
+        assert_eq!(result.successful_providers, 2);
+        assert_eq!(
+            max_in_flight.load(std::sync::atomic::Ordering::SeqCst),
+            1,
+            "only one request to the shared host may be in flight"
+        );
+    }
+
+    /// Provider whose searches never finish; counts searches started and
+    /// search futures dropped before completing
+    struct HangingProvider {
+        name: &'static str,
+        started: Arc<std::sync::atomic::AtomicUsize>,
+        dropped: Arc<std::sync::atomic::AtomicUsize>,
+    }
+
+    struct DropCounter(Arc<std::sync::atomic::AtomicUsize>);
+
+    impl Drop for DropCounter {
+        fn drop(&mut self) {
+            self.0.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl SourceProvider for HangingProvider {
+        fn name(&self) -> &'static str {
+            self.name
+        }
+
+        fn base_delay(&self) -> Duration {
+            Duration::ZERO
+        }
+
+        fn supported_search_types(&self) -> Vec<SearchType> {
+            vec![SearchType::Keywords]
+        }
+
+        async fn search(
+            &self,
+            _query: &SearchQuery,
+            _context: &SearchContext,
+        ) -> Result<ProviderResult, ProviderError> {
+            let _guard = DropCounter(self.dropped.clone());
+            self.started
+                .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            std::future::pending().await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_search_with_cancel_aborts_provider_tasks() {
+        use std::sync::atomic::Ordering;
+
+        let started = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let dropped = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let providers: Vec<Arc<dyn SourceProvider>> = ["hang_a", "hang_b"]
+            .into_iter()
+            .map(|name| {
+                Arc::new(HangingProvider {
+                    name,
+                    started: started.clone(),
+                    dropped: dropped.clone(),
+                }) as Arc<dyn SourceProvider>
+            })
+            .collect();
+        let client = Arc::new(MetaSearchClient::with_providers(
+            providers,
+            MetaSearchConfig::default(),
+        ));
+        let cancel = CancellationToken::new();
+
+        let search = tokio::spawn({
+            let client = client.clone();
+            let cancel = cancel.clone();
+            async move {
+                let query = SearchQuery {
+                    query: "crispr".to_string(),
+                    search_type: SearchType::Keywords,
+                    max_results: 10,
+                    offset: 0,
+                    params: HashMap::new(),
+                };
+                client.search_with_cancel(&query, &cancel).await
+            }
+        });
+
+        // Wait until both providers are mid-search, then cancel
+        while started.load(Ordering::SeqCst) < 2 {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+        cancel.cancel();
+
+        let result = timeout(Duration::from_secs(1), search)
+            .await
+            .expect("search should return promptly once cancelled")
+            .unwrap();
+        assert!(matches!(result, Err(ProviderError::Cancelled)));
+
+        timeout(Duration::from_secs(1), async {
+            while dropped.load(Ordering::SeqCst) < 2 {
+                tokio::time::sleep(Duration::from_millis(5)).await;
+            }
+        })
+        .await
+        .expect("outstanding provider searches should be aborted");
+    }
+
+    /// Provider that returns `count` distinct papers after `delay`
+    fn delayed_provider(
+        name: &'static str,
+        count: usize,
+        delay: Duration,
+    ) -> Arc<dyn SourceProvider> {
+        Arc::new(
+            MockProvider::new(name)
+                .with_search_types(vec![SearchType::Keywords])
+                .with_delay(delay)
+                .with_papers(
+                    (0..count)
+                        .map(|i| PaperMetadata::new(format!("10.1000/{name}.{i}")))
+                        .collect(),
+                ),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_greedy_stop_skips_slow_providers_once_limit_reached() {
+        let search_with = |greedy_stop| async move {
+            let providers: Vec<Arc<dyn SourceProvider>> = vec![
+                delayed_provider("fast", 3, Duration::ZERO),
+                delayed_provider("slow_a", 3, Duration::from_millis(200)),
+                delayed_provider("slow_b", 3, Duration::from_millis(200)),
+            ];
+            let client = MetaSearchClient::with_providers(
+                providers,
+                MetaSearchConfig {
+                    greedy_stop,
+                    ..MetaSearchConfig::default()
+                },
+            );
+            let query = SearchQuery {
+                query: "sparse attention".to_string(),
+                search_type: SearchType::Keywords,
+                max_results: 2,
+                offset: 0,
+                params: HashMap::new(),
+            };
+            client.search(&query).await.unwrap()
+        };
+
+        let greedy = search_with(true).await;
+        assert_eq!(greedy.successful_providers, 1);
+        assert_eq!(greedy.by_source.keys().collect::<Vec<_>>(), vec!["fast"]);
+        assert_eq!(greedy.papers.len(), 3);
+        assert_eq!(greedy.failed_providers, 0);
+
+        let exhaustive = search_with(false).await;
+        assert_eq!(exhaustive.successful_providers, 3);
+        assert_eq!(exhaustive.papers.len(), 9);
+    }
+
+    #[tokio::test]
+    async fn test_search_provider_timeout_override_applies_to_that_provider_only() {
+        let providers: Vec<Arc<dyn SourceProvider>> = vec![
+            delayed_provider("mirror", 1, Duration::from_millis(300)),
+            delayed_provider("index", 1, Duration::from_millis(300)),
+        ];
+        let client = MetaSearchClient::with_providers(
+            providers,
+            MetaSearchConfig::with_provider_timeout(Duration::from_secs(5)),
+        )
+        .with_provider_timeouts(HashMap::from([(
+            "mirror".to_string(),
+            Duration::from_millis(50),
+        )]));
+        let query = SearchQuery {
+            query: "sparse attention".to_string(),
+            search_type: SearchType::Keywords,
+            max_results: 10,
+            offset: 0,
+            params: HashMap::new(),
+        };
+
+        let result = client.search(&query).await.unwrap();
+        assert_eq!(result.successful_providers, 1);
+        assert_eq!(result.failed_providers, 1);
+        assert_eq!(result.by_source.keys().collect::<Vec<_>>(), vec!["index"]);
+        assert!(result.provider_errors.contains_key("mirror"));
+    }
+
+    /// Provider that answers its first search with nothing and later ones
+    /// with a paper
+    fn flaky_provider() -> Arc<MockProvider> {
+        let calls = std::sync::atomic::AtomicUsize::new(0);
+        Arc::new(
+            MockProvider::new("flaky")
+                .with_search_types(vec![SearchType::Keywords])
+                .answering(move |_, _| {
+                    if calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst) == 0 {
+                        Ok(Vec::new())
+                    } else {
+                        Ok(vec![PaperMetadata::new("10.1000/flaky".to_string())])
+                    }
+                }),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_search_retry_on_empty_returns_results_of_second_attempt() {
+        let query = SearchQuery {
+            query: "graph neural networks".to_string(),
+            search_type: SearchType::Keywords,
+            max_results: 10,
+            offset: 0,
+            params: HashMap::new(),
+        };
+
+        // Without the retry the empty answer stands
+        let flaky = flaky_provider();
+        let client =
+            MetaSearchClient::with_providers(vec![flaky.clone()], MetaSearchConfig::default());
+        assert!(client.search(&query).await.unwrap().papers.is_empty());
+        assert_eq!(flaky.searches.load(std::sync::atomic::Ordering::SeqCst), 1);
+
+        let flaky = flaky_provider();
+        let client = MetaSearchClient::with_providers(
+            vec![flaky.clone()],
+            MetaSearchConfig {
+                retry_on_empty: Some(Duration::from_millis(10)),
+                ..MetaSearchConfig::default()
+            },
+        )
+        .with_retry_on_empty_providers(HashSet::from(["flaky".to_string()]));
+        let result = client.search(&query).await.unwrap();
+        assert_eq!(result.papers.len(), 1);
+        assert_eq!(result.papers[0].doi, "10.1000/flaky");
+        assert_eq!(flaky.searches.load(std::sync::atomic::Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_search_concurrency_one_runs_providers_in_priority_order() {
+        let started = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let providers: Vec<Arc<dyn SourceProvider>> = [
+            ("low", 10),
+            ("high", 200),
+            ("mid", 100),
+            ("lowest", 1),
+            ("higher", 150),
+        ]
+        .into_iter()
+        .map(|(name, priority)| {
+            let started = started.clone();
+            Arc::new(
+                MockProvider::new(name)
+                    .with_priority(priority)
+                    .with_search_types(vec![SearchType::Keywords])
+                    .with_delay(Duration::from_millis(20))
+                    .answering(move |_, _| {
+                        started.lock().unwrap().push(name);
+                        Ok(Vec::new())
+                    }),
+            ) as Arc<dyn SourceProvider>
+        })
+        .collect();
+        let client = MetaSearchClient::with_providers(
+            providers,
+            MetaSearchConfig {
+                max_parallel_providers: 1,
+                ..MetaSearchConfig::default()
+            },
+        );
+        let query = SearchQuery {
+            query: "graph neural networks".to_string(),
+            search_type: SearchType::Keywords,
+            max_results: 10,
+            offset: 0,
+            params: HashMap::new(),
+        };
+
+        let result = client.search(&query).await.unwrap();
+
+        assert_eq!(result.successful_providers, 5);
+        assert_eq!(
+            *started.lock().unwrap(),
+            vec!["high", "higher", "mid", "low", "lowest"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_health_check_runs_probes_in_parallel_with_timeout() {
+        let mut providers: Vec<Arc<dyn SourceProvider>> = ["a", "b", "c", "d"]
+            .into_iter()
+            .map(|name| delayed_provider(name, 1, Duration::from_millis(300)))
+            .collect();
+        providers.push(delayed_provider("hung", 1, Duration::from_secs(30)));
+        let client = MetaSearchClient::with_providers(
+            providers,
+            MetaSearchConfig {
+                health_check_concurrency: 5,
+                health_check_timeout: Duration::from_millis(500),
+                ..MetaSearchConfig::default()
+            },
+        );
+
+        let started = Instant::now();
+        let health = client.health_check().await;
+        let elapsed = started.elapsed();
+
+        // Sequential probes would take 4 x 300ms plus the 500ms timeout
+        assert!(
+            elapsed < Duration::from_millis(1000),
+            "health check took {elapsed:?}"
+        );
+        assert_eq!(health.len(), 5);
+        assert!(["a", "b", "c", "d"].iter().all(|name| health[*name]));
+        assert!(!health["hung"]);
+    }
+
+    #[tokio::test]
+    async fn test_warm_up_excludes_unhealthy_provider() {
+        use std::sync::atomic::Ordering;
+
+        let up = Arc::new(
+            MockProvider::new("up")
+                .with_health(true)
+                .with_papers(vec![paper("10.1000/up", "Paper from up")]),
+        );
+        let down = Arc::new(
+            MockProvider::new("down")
+                .with_health(false)
+                .with_papers(vec![paper("10.1000/down", "Paper from down")]),
+        );
+        let providers: Vec<Arc<dyn SourceProvider>> = vec![up.clone(), down.clone()];
+        let client = MetaSearchClient::with_providers(providers, MetaSearchConfig::default());
+
+        assert_eq!(client.warm_up().await, 1);
+
+        let query = SearchQuery {
+            query: "crispr".to_string(),
+            search_type: SearchType::Keywords,
+            max_results: 10,
+            offset: 0,
+            params: HashMap::new(),
+        };
+        let result = client.search(&query).await.unwrap();
+
+        assert_eq!(up.searches.load(Ordering::SeqCst), 1);
+        assert_eq!(down.searches.load(Ordering::SeqCst), 0);
+        assert!(result.by_source.contains_key("up"));
+        assert!(!result.by_source.contains_key("down"));
+    }
+
+    #[tokio::test]
+    async fn test_full_text_query_only_reaches_capable_providers() {
+        use std::sync::atomic::Ordering;
+
+        let typed = |name, search_types| {
+            Arc::new(
+                MockProvider::new(name)
+                    .with_search_types(search_types)
+                    .with_papers(vec![PaperMetadata::new(format!("10.1000/{name}"))]),
+            )
+        };
+        let full_text = typed(
+            "full_text",
+            vec![SearchType::Keywords, SearchType::FullText],
+        );
+        let catch_all = typed("catch_all", vec![SearchType::Auto]);
+        let metadata_only = typed("metadata_only", vec![SearchType::Keywords]);
+        let providers: Vec<Arc<dyn SourceProvider>> =
+            vec![full_text.clone(), catch_all.clone(), metadata_only.clone()];
+        let client = MetaSearchClient::with_providers(providers, MetaSearchConfig::default());
+
+        let query = SearchQuery {
+            query: "we fine-tune on the held-out split".to_string(),
+            search_type: SearchType::FullText,
+            max_results: 10,
+            offset: 0,
+            params: HashMap::new(),
+        };
+        let result = client.search(&query).await.unwrap();
+
+        assert_eq!(full_text.searches.load(Ordering::SeqCst), 1);
+        assert_eq!(catch_all.searches.load(Ordering::SeqCst), 0);
+        assert_eq!(metadata_only.searches.load(Ordering::SeqCst), 0);
+        assert_eq!(
+            result.by_source.keys().collect::<Vec<_>>(),
+            vec!["full_text"]
+        );
+
+        // A keyword search still goes to every provider that can take it
+        let query = SearchQuery {
+            search_type: SearchType::Keywords,
+            ..query
+        };
+        client.search(&query).await.unwrap();
+        assert_eq!(catch_all.searches.load(Ordering::SeqCst), 1);
+        assert_eq!(metadata_only.searches.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_search_result_cache_survives_restart_without_provider_calls() {
+        use std::sync::atomic::Ordering;
+
+        let cache_dir = tempfile::TempDir::new().unwrap();
+        let cache =
+            || SearchResultCache::new(cache_dir.path().to_path_buf(), Duration::from_secs(60), 10);
+        let start = || {
+            let provider = Arc::new(
+                MockProvider::new("index")
+                    .with_search_types(vec![SearchType::Keywords])
+                    .with_papers(vec![PaperMetadata::new("10.1000/index".to_string())]),
+            );
+            let providers: Vec<Arc<dyn SourceProvider>> = vec![provider.clone()];
+            let client = MetaSearchClient::with_providers(providers, MetaSearchConfig::default())
+                .with_result_cache(cache());
+            (client, provider)
+        };
+        let query = SearchQuery {
+            query: "Sparse  Attention".to_string(),
+            search_type: SearchType::Keywords,
+            max_results: 10,
+            offset: 0,
+            params: HashMap::new(),
+        };
+
+        let (client, provider) = start();
+        let first = client.search(&query).await.unwrap();
+        assert_eq!(provider.searches.load(Ordering::SeqCst), 1);
+        drop(client);
+
+        // Same query, normalized differently, from a fresh client
+        let (client, provider) = start();
+        let query = SearchQuery {
+            query: "sparse attention".to_string(),
+            ..query
+        };
+        let cached = client.search(&query).await.unwrap();
+        assert_eq!(provider.searches.load(Ordering::SeqCst), 0);
+        assert_eq!(
+            cached.papers.iter().map(|p| &p.doi).collect::<Vec<_>>(),
+            first.papers.iter().map(|p| &p.doi).collect::<Vec<_>>()
+        );
+        assert_eq!(cached.successful_providers, first.successful_providers);
+    }
+
+    #[test]
+    fn test_content_priority_boost_uses_fulltext_capability() {
+        let pdf_capable = |name, provides_fulltext| -> Arc<dyn SourceProvider> {
+            Arc::new(
+                MockProvider::new(name)
+                    .with_priority(50)
+                    .with_search_types(vec![SearchType::Keywords])
+                    .with_fulltext(provides_fulltext),
+            )
+        };
+        let metadata_only = pdf_capable("metadata_index", false);
+        let pdf_source = pdf_capable("new_pdf_source", true);
+
+        let boost = MetaSearchClient::calculate_content_priority_boost;
+        assert_eq!(
+            boost(&pdf_source, "download pdf of attention"),
+            FULLTEXT_PRIORITY_BOOST
+        );
+        assert_eq!(boost(&metadata_only, "download pdf of attention"), 0);
+        assert_eq!(boost(&pdf_source, "attention mechanisms"), 0);
+
+        // A PDF query moves the capable provider ahead of an equal-priority one
+        let mut providers = vec![metadata_only, pdf_source];
+        let query = SearchQuery {
+            query: "graph networks pdf".to_string(),
+            search_type: SearchType::Keywords,
+            max_results: 10,
+            offset: 0,
+            params: HashMap::new(),
+        };
+        MetaSearchClient::apply_intelligent_priority_ordering(
+            &mut providers,
+            &query,
+            &HashMap::new(),
+        );
+        assert_eq!(providers[0].name(), "new_pdf_source");
+
+        let client = MetaSearchClient::with_providers(providers, MetaSearchConfig::default());
+        let capabilities = client.provider_capabilities();
+        assert!(capabilities[0].provides_fulltext);
+        assert!(!capabilities[1].provides_fulltext);
+    }
+
+    #[test]
+    fn test_provider_order_keeps_priorities_within_tier() {
+        let mut providers: Vec<Arc<dyn SourceProvider>> = vec![
+            Arc::new(MdpiProvider::new().unwrap()),
+            Arc::new(BiorxivProvider::new().unwrap()),
+            Arc::new(CoreProvider::new(None).unwrap()),
+            Arc::new(UnpaywallProvider::new_with_default_email().unwrap()),
+            Arc::new(SemanticScholarProvider::new(None).unwrap()),
+            Arc::new(PubMedCentralProvider::new(None).unwrap()),
+        ];
+        providers.sort_by(|a, b| provider_order(a.as_ref(), b.as_ref()));
+
+        let names: Vec<_> = providers.iter().map(|p| p.name()).collect();
+        assert_eq!(
+            names,
+            [
+                "pubmed_central",
+                "semantic_scholar",
+                "unpaywall",
+                "core",
+                "biorxiv",
+                "mdpi"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_provider_order_sorts_by_tier_then_name() {
+        use crate::client::providers::ProviderTier;
+
+        let providers: Vec<Arc<dyn SourceProvider>> = [
+            ("shadow_library", ProviderTier::FullTextGray),
+            ("preprints_b", ProviderTier::FullTextOA),
+            ("index", ProviderTier::Metadata),
+            ("registry", ProviderTier::Authoritative),
+            ("preprints_a", ProviderTier::FullTextOA),
+        ]
+        .into_iter()
+        .map(|(name, tier)| {
+            Arc::new(
+                MockProvider::new(name)
+                    .with_tier(tier)
+                    .with_search_types(vec![SearchType::Doi, SearchType::Keywords]),
+            ) as Arc<dyn SourceProvider>
+        })
+        .collect();
+        let expected = vec![
+            "registry",
+            "preprints_a",
+            "preprints_b",
+            "index",
+            "shadow_library",
+        ];
+
+        let client =
+            MetaSearchClient::with_providers(providers.clone(), MetaSearchConfig::default());
+        let names: Vec<_> = client
+            .select_doi_providers()
+            .iter()
+            .map(|p| p.name())
+            .collect();
+        assert_eq!(names, expected);
+        assert_eq!(
+            client.provider_priority("preprints_a"),
+            Some(ProviderTier::FullTextOA.priority())
+        );
+
+        // Without query-specific boosts, search ordering agrees
+        let mut ordered = providers;
+        let query = SearchQuery {
+            query: "attention".to_string(),
+            search_type: SearchType::Keywords,
+            max_results: 10,
+            offset: 0,
+            params: HashMap::new(),
+        };
+        MetaSearchClient::apply_intelligent_priority_ordering(
+            &mut ordered,
+            &query,
+            &HashMap::new(),
+        );
+        let names: Vec<_> = ordered.iter().map(|p| p.name()).collect();
+        assert_eq!(names, expected);
+    }
+
+    #[tokio::test]
+    async fn test_search_with_context_passes_context_to_providers() {
+        let seen = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let provider = MockProvider::new("context_recording").answering({
+            let seen = seen.clone();
+            move |_, context| {
+                seen.lock()
+                    .unwrap()
+                    .push((context.user_agent.clone(), context.headers.clone()));
+                Ok(Vec::new())
+            }
+        });
+        let client =
+            MetaSearchClient::with_providers(vec![Arc::new(provider)], MetaSearchConfig::default());
+        let query = SearchQuery {
+            query: "attention".to_string(),
+            search_type: SearchType::Keywords,
+            max_results: 10,
+            offset: 0,
+            params: HashMap::new(),
+        };
+
+        let mut context = client.default_search_context();
+        context.user_agent = "my-lab-crawler/1.0".to_string();
+        context
+            .headers
+            .insert("x-request-id".to_string(), "abc123".to_string());
+        client.search_with_context(&query, context).await.unwrap();
+        client.search(&query).await.unwrap();
+
+        let seen = seen.lock().unwrap();
+        assert_eq!(seen.len(), 2);
+        assert_eq!(seen[0].0, "my-lab-crawler/1.0");
+        assert_eq!(
+            seen[0].1.get("x-request-id").map(String::as_str),
+            Some("abc123")
+        );
+        assert_eq!(seen[1].0, client.default_search_context().user_agent);
+        assert!(seen[1].1.is_empty());
+    }
 }
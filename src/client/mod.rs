@@ -48,20 +48,43 @@
 //! - Request timeouts and connection limits
 //! - Rate limiting to respect external services
 
+pub mod abstracts;
+pub mod authors;
 pub mod circuit_breaker_service;
+pub mod host_limiter;
+pub mod markdown;
 pub mod meta_search;
 pub mod mirror;
 pub mod providers;
 pub mod rate_limiter;
+pub mod relevance;
+pub mod result_cache;
 
+pub use abstracts::{clean_abstract, AbstractSignature};
+pub use authors::{normalize_author_name, normalize_medline_author_name};
 pub use circuit_breaker_service::CircuitBreakerService;
-pub use meta_search::{MetaSearchClient, MetaSearchConfig, MetaSearchResult};
+pub use host_limiter::HostConcurrencyLimiter;
+pub use markdown::{MarkdownLayout, MarkdownOptions};
+pub use meta_search::{
+    MetaSearchClient, MetaSearchConfig, MetaSearchResult, ProviderCapabilities,
+    ABSTRACT_REQUIRED_PARAM, MIN_YEAR_PARAM,
+};
 pub use mirror::{Mirror, MirrorHealth, MirrorManager};
-pub use rate_limiter::RateLimiter;
+pub use rate_limiter::{RateLimitState, RateLimiter};
+pub use relevance::RelevanceScorer;
+pub use result_cache::SearchResultCache;
 
+use crate::config::HttpConfig;
 use crate::Result;
 use std::time::Duration;
 
+/// Starting point for every outbound HTTP client: a builder carrying the
+/// `[http]` settings that apply to all connections (currently the IP family).
+/// Callers layer their own timeouts and user agent on top.
+pub fn http_client_builder(http: &HttpConfig) -> reqwest::ClientBuilder {
+    http.ip_family.apply(reqwest::Client::builder())
+}
+
 /// HTTP client configuration for research source integration
 #[derive(Debug, Clone)]
 pub struct HttpClientConfig {
@@ -75,6 +98,8 @@ pub struct HttpClientConfig {
     pub user_agent: String,
     /// Proxy URL (optional)
     pub proxy: Option<String>,
+    /// Shared `[http]` settings (IP family) applied to the client
+    pub http: HttpConfig,
 }
 
 impl Default for HttpClientConfig {
@@ -85,6 +110,7 @@ impl Default for HttpClientConfig {
             max_redirects: 10,
             user_agent: "knowledge_accumulator_mcp/0.2.1 (Academic Research Tool)".to_string(),
             proxy: None,
+            http: HttpConfig::default(),
         }
     }
 }
@@ -106,6 +132,7 @@ impl Default for HttpClientConfig {
 ///
 /// ```no_run
 /// use knowledge_accumulator_mcp::client::{SecureHttpClientFactory, HttpClientConfig};
+/// use knowledge_accumulator_mcp::config::HttpConfig;
 /// use std::time::Duration;
 ///
 /// let config = HttpClientConfig {
@@ -114,6 +141,7 @@ impl Default for HttpClientConfig {
 ///     max_redirects: 5,
 ///     user_agent: "MyApp/1.0".to_string(),
 ///     proxy: None,
+///     http: HttpConfig::default(),
 /// };
 ///
 /// let client = SecureHttpClientFactory::create_client(&config)?;
@@ -147,7 +175,7 @@ impl SecureHttpClientFactory {
     /// - Apply connection and request timeouts
     /// - Follow redirects up to the configured limit
     pub fn create_client(config: &HttpClientConfig) -> Result<reqwest::Client> {
-        let mut client_builder = reqwest::Client::builder()
+        let mut client_builder = http_client_builder(&config.http)
             .timeout(config.timeout)
             .connect_timeout(config.connect_timeout)
             .redirect(reqwest::redirect::Policy::limited(
@@ -261,8 +289,11 @@ pub struct PaperMetadata {
     pub doi: String,
     /// Paper title
     pub title: Option<String>,
-    /// Authors
+    /// Authors, normalized to the configured name format
     pub authors: Vec<String>,
+    /// Authors exactly as the provider reported them
+    #[serde(default)]
+    pub raw_authors: Vec<String>,
     /// Journal name
     pub journal: Option<String>,
     /// Publication year
@@ -273,6 +304,13 @@ pub struct PaperMetadata {
     pub pdf_url: Option<String>,
     /// File size in bytes (if available)
     pub file_size: Option<u64>,
+    /// License the paper is published under, e.g. `cc-by`, or the license
+    /// URL where the provider reports no short name
+    #[serde(default)]
+    pub license: Option<String>,
+    /// Whether the paper is free to read, when the provider knows
+    #[serde(default)]
+    pub is_open_access: Option<bool>,
 }
 
 impl PaperMetadata {
@@ -283,11 +321,14 @@ impl PaperMetadata {
             doi,
             title: None,
             authors: Vec::new(),
+            raw_authors: Vec::new(),
             journal: None,
             year: None,
             abstract_text: None,
             pdf_url: None,
             file_size: None,
+            license: None,
+            is_open_access: None,
         }
     }
 
@@ -302,6 +343,7 @@ impl PaperMetadata {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::IpFamily;
 
     #[test]
     fn test_secure_http_client_factory_default() {
@@ -333,6 +375,27 @@ mod tests {
         assert_eq!(config.max_redirects, 10);
         assert!(config.user_agent.contains("knowledge_accumulator_mcp"));
         assert!(config.proxy.is_none());
+        assert_eq!(config.http.ip_family, IpFamily::Auto);
+    }
+
+    #[test]
+    fn test_secure_http_client_factory_with_ipv4_only() {
+        let mut config = HttpClientConfig::default();
+        config.http.ip_family = IpFamily::V4;
+        assert_eq!(
+            config.http.ip_family.local_address(),
+            Some(std::net::IpAddr::V4(std::net::Ipv4Addr::UNSPECIFIED))
+        );
+        let client = SecureHttpClientFactory::create_client(&config);
+        assert!(client.is_ok(), "Should create IPv4-only client");
+
+        let mut app_config = crate::Config::default();
+        app_config.http.ip_family = IpFamily::V4;
+        let meta_client = MetaSearchClient::new(app_config, MetaSearchConfig::default());
+        assert!(
+            meta_client.is_ok(),
+            "Providers should build IPv4-only clients"
+        );
     }
 
     #[test]
@@ -1,9 +1,11 @@
 use super::traits::{
-    ProviderError, ProviderResult, SearchContext, SearchQuery, SearchType, SourceProvider,
+    provider_http_client, read_response_text, with_context_headers, ProviderError, ProviderResult,
+    ProviderTier, SearchContext, SearchQuery, SearchType, SourceProvider,
 };
 use crate::client::circuit_breaker_service::CircuitBreakerService;
 use crate::client::rate_limiter::ProviderRateLimiter;
 use crate::client::PaperMetadata;
+use crate::config::HttpConfig;
 use async_trait::async_trait;
 use reqwest::Client;
 use std::collections::HashMap;
@@ -19,25 +21,90 @@ pub struct ArxivProvider {
     base_url: String,
     rate_limiter: Arc<Mutex<Option<ProviderRateLimiter>>>,
     circuit_breaker_service: Arc<CircuitBreakerService>,
+    /// Version PDF URLs are pinned to (`None` resolves the latest version)
+    version_pin: Option<u32>,
+}
+
+/// DOI prefix arXiv registers for its own e-prints (`10.48550/arXiv.<id>`)
+const ARXIV_DOI_PREFIX: &str = "10.48550/arxiv.";
+
+/// arXiv identifier for an arXiv-registered DOI, e.g. `2409.10516` for
+/// `10.48550/arXiv.2409.10516`
+#[must_use]
+pub fn arxiv_id_from_doi(doi: &str) -> Option<&str> {
+    let doi = doi.trim();
+    let prefix = doi.get(..ARXIV_DOI_PREFIX.len())?;
+    let id = &doi[ARXIV_DOI_PREFIX.len()..];
+    (prefix.eq_ignore_ascii_case(ARXIV_DOI_PREFIX) && !id.is_empty()).then_some(id)
+}
+
+/// Strip a trailing version suffix (`v2`) from an arXiv identifier
+fn strip_version(id: &str) -> &str {
+    match id.rfind('v') {
+        Some(pos) if pos + 1 < id.len() && id[pos + 1..].bytes().all(|b| b.is_ascii_digit()) => {
+            &id[..pos]
+        }
+        _ => id,
+    }
 }
 
 impl ArxivProvider {
     /// Create a new arXiv provider
     pub fn new() -> Result<Self, ProviderError> {
-        let client = Client::builder()
-            .timeout(Duration::from_secs(30))
-            .user_agent("knowledge_accumulator_mcp/0.2.1 (Academic Research Tool)")
-            .build()
-            .map_err(|e| ProviderError::Other(format!("Failed to create HTTP client: {e}")))?;
+        Self::with_http_config(&HttpConfig::default())
+    }
+
+    /// Create a new arXiv provider whose client follows the `[http]` settings
+    pub fn with_http_config(http: &HttpConfig) -> Result<Self, ProviderError> {
+        let client = provider_http_client(
+            http,
+            Some("knowledge_accumulator_mcp/0.2.1 (Academic Research Tool)"),
+        )?;
 
         Ok(Self {
             client,
             base_url: "https://export.arxiv.org/api/query".to_string(),
             rate_limiter: Arc::new(Mutex::new(None)),
             circuit_breaker_service: Arc::new(CircuitBreakerService::new()),
+            version_pin: None,
         })
     }
 
+    /// Pin resolved PDF URLs to a specific arXiv version instead of the latest
+    #[must_use]
+    pub const fn with_version_pin(mut self, version: Option<u32>) -> Self {
+        self.version_pin = version;
+        self
+    }
+
+    /// PDF URL for an arXiv identifier, honouring the version pin
+    #[must_use]
+    pub fn pdf_url_for_id(&self, arxiv_id: &str) -> String {
+        self.pin_pdf_url(&format!("https://arxiv.org/pdf/{arxiv_id}.pdf"))
+    }
+
+    /// Rewrite an arXiv PDF URL to the pinned version, replacing any version
+    /// suffix it already carries. URLs are returned unchanged without a pin.
+    fn pin_pdf_url(&self, url: &str) -> String {
+        let Some(version) = self.version_pin else {
+            return url.to_string();
+        };
+        let Some(start) = url.find("/pdf/") else {
+            return url.to_string();
+        };
+
+        let id_start = start + "/pdf/".len();
+        let (id, suffix) = match url[id_start..].strip_suffix(".pdf") {
+            Some(id) => (id, ".pdf"),
+            None => (&url[id_start..], ""),
+        };
+        format!(
+            "{}{}v{version}{suffix}",
+            &url[..id_start],
+            strip_version(id)
+        )
+    }
+
     /// Initialize rate limiter with configuration
     pub async fn init_rate_limiter(&self, config: &crate::config::RateLimitingConfig) {
         let limiter = ProviderRateLimiter::new("arxiv".to_string(), config);
@@ -73,16 +140,44 @@ impl ArxivProvider {
         let mut url = Url::parse(&self.base_url)
             .map_err(|e| ProviderError::Other(format!("Invalid base URL: {e}")))?;
 
+        // arXiv-registered DOIs map straight to an arXiv identifier
+        if query.search_type == SearchType::Doi {
+            if let Some(arxiv_id) = arxiv_id_from_doi(&query.query) {
+                url.query_pairs_mut()
+                    .append_pair("id_list", arxiv_id)
+                    .append_pair("max_results", &query.max_results.to_string());
+                return Ok(url.to_string());
+            }
+        }
+
         // Build search terms based on query type
-        let search_query = match query.search_type {
-            SearchType::Doi => format!("doi:{query}", query = query.query),
-            SearchType::Title => format!("ti:\"{query}\"", query = query.query),
-            SearchType::Author => format!("au:\"{query}\"", query = query.query),
-            SearchType::Keywords | SearchType::Auto => {
-                // For auto/keywords, search in title, abstract, and comments
-                format!("all:\"{query}\"", query = query.query)
+        let all_terms = query
+            .all_terms()
+            .filter(|_| query.search_type != SearchType::Doi);
+        let search_query = if let Some(terms) = all_terms {
+            // Parsed boolean query: every term must match in the searched field
+            let field = match query.search_type {
+                SearchType::Title => "ti",
+                SearchType::Author => "au",
+                SearchType::Subject => "cat",
+                _ => "all",
+            };
+            terms
+                .iter()
+                .map(|term| format!("{field}:\"{term}\""))
+                .collect::<Vec<_>>()
+                .join(" AND ")
+        } else {
+            match query.search_type {
+                SearchType::Doi => format!("doi:{query}", query = query.query),
+                SearchType::Title => format!("ti:\"{query}\"", query = query.query),
+                SearchType::Author => format!("au:\"{query}\"", query = query.query),
+                SearchType::Keywords | SearchType::Auto | SearchType::FullText => {
+                    // For auto/keywords, search in title, abstract, and comments
+                    format!("all:\"{query}\"", query = query.query)
+                }
+                SearchType::Subject => format!("cat:{query}", query = query.query),
             }
-            SearchType::Subject => format!("cat:{query}", query = query.query),
         };
 
         url.query_pairs_mut()
@@ -99,8 +194,13 @@ impl ArxivProvider {
     fn parse_response(&self, response_text: &str) -> Result<Vec<PaperMetadata>, ProviderError> {
         use roxmltree::Document;
 
-        let doc = Document::parse(response_text)
-            .map_err(|e| ProviderError::Parse(format!("Failed to parse XML: {e}")))?;
+        let doc = Document::parse(response_text).map_err(|e| {
+            ProviderError::parse_error(
+                self.name(),
+                format!("Failed to parse XML: {e}"),
+                response_text,
+            )
+        })?;
 
         let mut papers = Vec::new();
 
@@ -110,11 +210,14 @@ impl ArxivProvider {
                 doi: String::new(),
                 title: None,
                 authors: Vec::new(),
+                raw_authors: Vec::new(),
                 journal: Some("arXiv".to_string()),
                 year: None,
                 abstract_text: None,
                 pdf_url: None,
                 file_size: None,
+                license: None,
+                is_open_access: Some(true), // arXiv preprints are free to read
             };
 
             // Extract metadata from entry
@@ -164,7 +267,7 @@ impl ArxivProvider {
                                 if link_type == "application/pdf" {
                                     // Resolve relative URLs to absolute URLs
                                     let pdf_url = Self::resolve_pdf_url(href)?;
-                                    paper.pdf_url = Some(pdf_url);
+                                    paper.pdf_url = Some(self.pin_pdf_url(&pdf_url));
                                 }
                             }
                         }
@@ -196,6 +299,7 @@ impl Default for ArxivProvider {
                     base_url: "https://export.arxiv.org/api/query".to_string(),
                     rate_limiter: Arc::new(Mutex::new(None)),
                     circuit_breaker_service: Arc::new(CircuitBreakerService::new()),
+                    version_pin: None,
                 }
             }
         }
@@ -208,6 +312,10 @@ impl SourceProvider for ArxivProvider {
         "arxiv"
     }
 
+    fn base_url(&self) -> Option<&str> {
+        Some(self.base_url.as_str())
+    }
+
     fn description(&self) -> &'static str {
         "arXiv.org - Open access e-prints in physics, mathematics, computer science, and more"
     }
@@ -223,10 +331,14 @@ impl SourceProvider for ArxivProvider {
         ]
     }
 
-    fn supports_full_text(&self) -> bool {
+    fn provides_fulltext(&self) -> bool {
         true // arXiv provides free PDF access
     }
 
+    fn tier(&self) -> ProviderTier {
+        ProviderTier::FullTextOA // Free PDFs of every preprint
+    }
+
     fn priority(&self) -> u8 {
         80 // High priority for CS/physics/math
     }
@@ -275,14 +387,10 @@ impl SourceProvider for ArxivProvider {
         let response = self
             .circuit_breaker_service
             .call_http("arxiv", || async {
-                let mut request = self.client.get(&url);
-
-                // Add custom headers
-                for (key, value) in &context.headers {
-                    request = request.header(key, value);
-                }
-
-                request.timeout(context.timeout).send().await
+                with_context_headers(self.client.get(&url), context)
+                    .timeout(context.timeout)
+                    .send()
+                    .await
             })
             .await
             .map_err(|e| {
@@ -313,7 +421,9 @@ impl SourceProvider for ArxivProvider {
         // Check response status
         if !response.status().is_success() {
             let status = response.status();
-            let error_text = response.text().await.unwrap_or_default();
+            let error_text = read_response_text(response, context)
+                .await
+                .unwrap_or_default();
 
             return Err(match status.as_u16() {
                 429 => ProviderError::RateLimit,
@@ -325,10 +435,7 @@ impl SourceProvider for ArxivProvider {
         }
 
         // Parse the response
-        let response_text = response
-            .text()
-            .await
-            .map_err(|e| ProviderError::Network(format!("Failed to read response: {e}")))?;
+        let response_text = read_response_text(response, context).await?;
 
         let papers = self.parse_response(&response_text)?;
         let search_time = start_time.elapsed();
@@ -364,15 +471,27 @@ impl SourceProvider for ArxivProvider {
         })
     }
 
-    async fn health_check(&self, _context: &SearchContext) -> Result<bool, ProviderError> {
+    async fn get_pdf_url(
+        &self,
+        doi: &str,
+        context: &SearchContext,
+    ) -> Result<Option<String>, ProviderError> {
+        if let Some(arxiv_id) = arxiv_id_from_doi(doi) {
+            return Ok(Some(self.pdf_url_for_id(arxiv_id)));
+        }
+
+        let paper = self.get_by_doi(doi, context).await?;
+        Ok(paper.and_then(|paper| paper.pdf_url))
+    }
+
+    async fn health_check(&self, context: &SearchContext) -> Result<bool, ProviderError> {
         debug!("Performing arXiv health check");
 
         // Simplified health check that just tries to connect to the API
         let response = self
             .circuit_breaker_service
             .call_http("arxiv", || async {
-                self.client
-                    .get(&self.base_url)
+                with_context_headers(self.client.get(&self.base_url), context)
                     .timeout(Duration::from_secs(10))
                     .send()
                     .await
@@ -433,6 +552,32 @@ mod tests {
         assert!(url.contains("start=0"));
     }
 
+    #[test]
+    fn test_arxiv_search_url_and_terms() {
+        let provider = ArxivProvider::new().unwrap();
+
+        let query = SearchQuery {
+            query: "Smith \"Jones\"".to_string(),
+            search_type: SearchType::Author,
+            max_results: 10,
+            offset: 0,
+            params: HashMap::from([(
+                crate::client::providers::ALL_TERMS_PARAM.to_string(),
+                r#"["Smith","Jones"]"#.to_string(),
+            )]),
+        };
+
+        let url = Url::parse(&provider.build_search_url(&query).unwrap()).unwrap();
+        let search_query = url
+            .query_pairs()
+            .find(|(key, _)| key == "search_query")
+            .map(|(_, value)| value.into_owned());
+        assert_eq!(
+            search_query.as_deref(),
+            Some("au:\"Smith\" AND au:\"Jones\"")
+        );
+    }
+
     #[test]
     fn test_arxiv_doi_search_url() {
         let provider = ArxivProvider::new().unwrap();
@@ -479,4 +624,81 @@ mod tests {
         let result = ArxivProvider::resolve_pdf_url(invalid_url);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_pdf_url_pinned_version() {
+        let provider = ArxivProvider::new().unwrap().with_version_pin(Some(1));
+
+        assert_eq!(
+            provider.pdf_url_for_id("2409.10516"),
+            "https://arxiv.org/pdf/2409.10516v1.pdf"
+        );
+        assert_eq!(
+            provider.pin_pdf_url("http://arxiv.org/pdf/2409.10516v3"),
+            "http://arxiv.org/pdf/2409.10516v1"
+        );
+        assert_eq!(
+            provider.pdf_url_for_id("hep-th/9901001v2"),
+            "https://arxiv.org/pdf/hep-th/9901001v1.pdf"
+        );
+
+        let latest = ArxivProvider::new().unwrap();
+        assert_eq!(
+            latest.pdf_url_for_id("2409.10516"),
+            "https://arxiv.org/pdf/2409.10516.pdf"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_pdf_url_maps_arxiv_doi_to_pinned_version() {
+        let provider = ArxivProvider::new().unwrap().with_version_pin(Some(2));
+        let context = SearchContext {
+            timeout: Duration::from_secs(5),
+            user_agent: "test".to_string(),
+            rate_limit: None,
+            headers: HashMap::new(),
+            max_response_bytes: None,
+            default_charset: None,
+        };
+
+        let url = provider
+            .get_pdf_url("10.48550/arXiv.2409.10516", &context)
+            .await
+            .unwrap();
+        assert_eq!(
+            url.as_deref(),
+            Some("https://arxiv.org/pdf/2409.10516v2.pdf")
+        );
+
+        let query = SearchQuery {
+            query: "10.48550/arXiv.2409.10516".to_string(),
+            search_type: SearchType::Doi,
+            max_results: 1,
+            offset: 0,
+            params: HashMap::new(),
+        };
+        let search_url = provider.build_search_url(&query).unwrap();
+        assert!(search_url.contains("id_list=2409.10516"));
+        assert!(!search_url.contains("search_query"));
+    }
+
+    #[test]
+    fn test_parse_response_marks_papers_open_access() {
+        let provider = ArxivProvider::new().unwrap();
+        let feed = r#"<?xml version="1.0" encoding="UTF-8"?>
+<feed xmlns="http://www.w3.org/2005/Atom">
+  <entry>
+    <id>http://arxiv.org/abs/1706.03762v7</id>
+    <published>2017-06-12T17:57:34Z</published>
+    <title>Attention Is All You Need</title>
+    <author><name>Ashish Vaswani</name></author>
+    <link title="pdf" href="http://arxiv.org/pdf/1706.03762v7" rel="related" type="application/pdf"/>
+  </entry>
+</feed>"#;
+
+        let papers = provider.parse_response(feed).unwrap();
+        assert_eq!(papers.len(), 1);
+        assert_eq!(papers[0].doi, "arXiv:1706.03762v7");
+        assert_eq!(papers[0].is_open_access, Some(true));
+    }
 }
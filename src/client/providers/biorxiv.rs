@@ -1,7 +1,9 @@
 use super::traits::{
-    ProviderError, ProviderResult, SearchContext, SearchQuery, SearchType, SourceProvider,
+    provider_http_client, read_response_text, with_context_headers, ProviderError, ProviderResult,
+    ProviderTier, SearchContext, SearchQuery, SearchType, SourceProvider,
 };
 use crate::client::PaperMetadata;
+use crate::config::HttpConfig;
 use async_trait::async_trait;
 use reqwest::Client;
 use serde::Deserialize;
@@ -59,11 +61,15 @@ pub struct BiorxivProvider {
 impl BiorxivProvider {
     /// Create a new bioRxiv provider
     pub fn new() -> Result<Self, ProviderError> {
-        let client = Client::builder()
-            .timeout(Duration::from_secs(30))
-            .user_agent("knowledge_accumulator_mcp/0.2.1 (Academic Research Tool)")
-            .build()
-            .map_err(|e| ProviderError::Network(format!("Failed to create HTTP client: {e}")))?;
+        Self::with_http_config(&HttpConfig::default())
+    }
+
+    /// Create a new bioRxiv provider whose client follows the `[http]` settings
+    pub fn with_http_config(http: &HttpConfig) -> Result<Self, ProviderError> {
+        let client = provider_http_client(
+            http,
+            Some("knowledge_accumulator_mcp/0.2.1 (Academic Research Tool)"),
+        )?;
 
         Ok(Self {
             client,
@@ -136,22 +142,27 @@ impl BiorxivProvider {
             doi: paper.doi,
             title: Some(paper.title),
             authors,
+            raw_authors: Vec::new(),
             journal: Some(format!("{} preprint", paper.server)), // "biorxiv preprint" or "medrxiv preprint"
             year,
             abstract_text: paper.abstract_text,
             pdf_url,
             file_size: None,
+            license: None,
+            is_open_access: Some(true), // Preprints are free to read
         }
     }
 
     /// Get paper by DOI from bioRxiv
-    async fn get_paper_by_doi(&self, doi: &str) -> Result<Option<PaperMetadata>, ProviderError> {
+    async fn get_paper_by_doi(
+        &self,
+        doi: &str,
+        context: &SearchContext,
+    ) -> Result<Option<PaperMetadata>, ProviderError> {
         let url = self.build_doi_url(doi);
         debug!("Getting paper by DOI from bioRxiv: {}", url);
 
-        let response = self
-            .client
-            .get(&url)
+        let response = with_context_headers(self.client.get(&url), context)
             .send()
             .await
             .map_err(|e| ProviderError::Network(format!("Request failed: {e}")))?;
@@ -168,17 +179,18 @@ impl BiorxivProvider {
             )));
         }
 
-        let response_text = response
-            .text()
-            .await
-            .map_err(|e| ProviderError::Network(format!("Failed to read response: {e}")))?;
+        let response_text = read_response_text(response, context).await?;
 
         debug!("bioRxiv response: {}", response_text);
 
         let biorxiv_response: BiorxivResponse =
             serde_json::from_str(&response_text).map_err(|e| {
                 warn!("Failed to parse bioRxiv response: {}", response_text);
-                ProviderError::Parse(format!("Failed to parse JSON: {e}"))
+                ProviderError::parse_error(
+                    self.name(),
+                    format!("Failed to parse JSON: {e}"),
+                    &response_text,
+                )
             })?;
 
         // Check for error messages
@@ -201,6 +213,7 @@ impl BiorxivProvider {
         &self,
         days_back: u32,
         limit: u32,
+        context: &SearchContext,
     ) -> Result<Vec<PaperMetadata>, ProviderError> {
         use chrono::{Duration as ChronoDuration, Utc};
 
@@ -214,9 +227,7 @@ impl BiorxivProvider {
         let url = self.build_date_search_url(&start_date_str, &end_date_str);
         debug!("Searching bioRxiv by date range: {}", url);
 
-        let response = self
-            .client
-            .get(&url)
+        let response = with_context_headers(self.client.get(&url), context)
             .send()
             .await
             .map_err(|e| ProviderError::Network(format!("Request failed: {e}")))?;
@@ -228,17 +239,18 @@ impl BiorxivProvider {
             )));
         }
 
-        let response_text = response
-            .text()
-            .await
-            .map_err(|e| ProviderError::Network(format!("Failed to read response: {e}")))?;
+        let response_text = read_response_text(response, context).await?;
 
         debug!("bioRxiv search response: {}", response_text);
 
         let biorxiv_response: BiorxivResponse =
             serde_json::from_str(&response_text).map_err(|e| {
                 warn!("Failed to parse bioRxiv search response: {}", response_text);
-                ProviderError::Parse(format!("Failed to parse JSON: {e}"))
+                ProviderError::parse_error(
+                    self.name(),
+                    format!("Failed to parse JSON: {e}"),
+                    &response_text,
+                )
             })?;
 
         // Check for error messages
@@ -266,6 +278,10 @@ impl SourceProvider for BiorxivProvider {
         "biorxiv"
     }
 
+    fn base_url(&self) -> Option<&str> {
+        Some(self.base_url.as_str())
+    }
+
     fn description(&self) -> &'static str {
         "bioRxiv - Biology preprint server"
     }
@@ -274,10 +290,14 @@ impl SourceProvider for BiorxivProvider {
         vec![SearchType::Doi, SearchType::Keywords] // Limited search capabilities
     }
 
-    fn supports_full_text(&self) -> bool {
+    fn provides_fulltext(&self) -> bool {
         true // bioRxiv provides PDF access for all preprints
     }
 
+    fn tier(&self) -> ProviderTier {
+        ProviderTier::FullTextOA // Preprints with free PDFs
+    }
+
     fn priority(&self) -> u8 {
         75 // Lower priority - more specialized for biology preprints
     }
@@ -289,7 +309,7 @@ impl SourceProvider for BiorxivProvider {
     async fn search(
         &self,
         query: &SearchQuery,
-        _context: &SearchContext,
+        context: &SearchContext,
     ) -> Result<ProviderResult, ProviderError> {
         let start_time = Instant::now();
 
@@ -302,7 +322,7 @@ impl SourceProvider for BiorxivProvider {
             SearchType::Doi => {
                 // Check if this is a bioRxiv DOI
                 if let Some(biorxiv_doi) = Self::extract_biorxiv_doi(&query.query) {
-                    if let Some(paper) = self.get_paper_by_doi(&biorxiv_doi).await? {
+                    if let Some(paper) = self.get_paper_by_doi(&biorxiv_doi, context).await? {
                         vec![paper]
                     } else {
                         Vec::new()
@@ -316,7 +336,8 @@ impl SourceProvider for BiorxivProvider {
                 // bioRxiv doesn't support text search, so we search recent papers
                 // This is a limitation of the bioRxiv API
                 warn!("bioRxiv doesn't support keyword search, returning recent papers");
-                self.search_recent_papers(30, query.max_results).await?
+                self.search_recent_papers(30, query.max_results, context)
+                    .await?
             }
             _ => {
                 // bioRxiv doesn't support other search types
@@ -352,26 +373,29 @@ impl SourceProvider for BiorxivProvider {
     async fn get_by_doi(
         &self,
         doi: &str,
-        _context: &SearchContext,
+        context: &SearchContext,
     ) -> Result<Option<PaperMetadata>, ProviderError> {
         info!("Getting paper by DOI from bioRxiv: {}", doi);
 
         // Check if this is a bioRxiv DOI first
         if let Some(biorxiv_doi) = Self::extract_biorxiv_doi(doi) {
-            self.get_paper_by_doi(&biorxiv_doi).await
+            self.get_paper_by_doi(&biorxiv_doi, context).await
         } else {
             // Not a bioRxiv DOI
             Ok(None)
         }
     }
 
-    async fn health_check(&self, _context: &SearchContext) -> Result<bool, ProviderError> {
+    async fn health_check(&self, context: &SearchContext) -> Result<bool, ProviderError> {
         debug!("Performing bioRxiv health check");
 
         // Use a known bioRxiv DOI for health check
         let test_url = self.build_doi_url("10.1101/2020.01.01.000001");
 
-        match self.client.get(&test_url).send().await {
+        match with_context_headers(self.client.get(&test_url), context)
+            .send()
+            .await
+        {
             Ok(response) if response.status().is_success() || response.status().as_u16() == 404 => {
                 info!("bioRxiv health check: OK");
                 Ok(true)
@@ -425,8 +449,9 @@ mod tests {
         let provider = BiorxivProvider::new().unwrap();
 
         assert_eq!(provider.name(), "biorxiv");
-        assert!(provider.supports_full_text());
+        assert!(provider.provides_fulltext());
         assert_eq!(provider.priority(), 75);
+        assert_eq!(provider.tier(), ProviderTier::FullTextOA);
         assert!(provider.supported_search_types().contains(&SearchType::Doi));
     }
 
@@ -1,7 +1,9 @@
 use super::traits::{
-    ProviderError, ProviderResult, SearchContext, SearchQuery, SearchType, SourceProvider,
+    provider_http_client, read_response_text, with_context_headers, ProviderError, ProviderResult,
+    ProviderTier, SearchContext, SearchQuery, SearchType, SourceProvider,
 };
 use crate::client::PaperMetadata;
+use crate::config::HttpConfig;
 use async_trait::async_trait;
 use reqwest::Client;
 use serde::Deserialize;
@@ -74,11 +76,18 @@ pub struct CoreProvider {
 impl CoreProvider {
     /// Create a new CORE provider
     pub fn new(api_key: Option<String>) -> Result<Self, ProviderError> {
-        let client = Client::builder()
-            .timeout(Duration::from_secs(30))
-            .user_agent("knowledge_accumulator_mcp/0.2.1 (Academic Research Tool)")
-            .build()
-            .map_err(|e| ProviderError::Network(format!("Failed to create HTTP client: {e}")))?;
+        Self::with_http_config(api_key, &HttpConfig::default())
+    }
+
+    /// Create a new CORE provider whose client follows the `[http]` settings
+    pub fn with_http_config(
+        api_key: Option<String>,
+        http: &HttpConfig,
+    ) -> Result<Self, ProviderError> {
+        let client = provider_http_client(
+            http,
+            Some("knowledge_accumulator_mcp/0.2.1 (Academic Research Tool)"),
+        )?;
 
         Ok(Self {
             client,
@@ -103,6 +112,20 @@ impl CoreProvider {
         format!("{}/works?doi={}", self.base_url, urlencoding::encode(doi))
     }
 
+    /// Query requiring every term, in CORE's boolean syntax
+    fn all_terms_query(terms: &[String]) -> String {
+        terms
+            .iter()
+            .map(|term| format!("\"{term}\""))
+            .collect::<Vec<_>>()
+            .join(" AND ")
+    }
+
+    /// Restrict a query to the extracted full text of each work
+    fn full_text_query(query: &str) -> String {
+        format!("fullText:({query})")
+    }
+
     /// Get request headers including API key if available
     fn get_headers(&self) -> HashMap<String, String> {
         let mut headers = HashMap::new();
@@ -150,11 +173,14 @@ impl CoreProvider {
             doi,
             title: article.title,
             authors,
+            raw_authors: Vec::new(),
             journal,
             year: article.year_published,
             abstract_text: article.abstract_text,
             pdf_url,
             file_size: None,
+            license: None,
+            is_open_access: Some(true), // CORE only aggregates open access content
         }
     }
 
@@ -164,11 +190,12 @@ impl CoreProvider {
         query: &str,
         limit: u32,
         offset: u32,
+        context: &SearchContext,
     ) -> Result<Vec<PaperMetadata>, ProviderError> {
         let url = self.build_search_url(query, limit, offset);
         debug!("Searching CORE: {}", url);
 
-        let mut request = self.client.get(&url);
+        let mut request = with_context_headers(self.client.get(&url), context);
 
         // Add API key header if available
         for (key, value) in self.get_headers() {
@@ -187,10 +214,7 @@ impl CoreProvider {
             )));
         }
 
-        let response_text = response
-            .text()
-            .await
-            .map_err(|e| ProviderError::Network(format!("Failed to read response: {e}")))?;
+        let response_text = read_response_text(response, context).await?;
 
         debug!("CORE response: {}", response_text);
 
@@ -229,11 +253,15 @@ impl CoreProvider {
     }
 
     /// Get paper by DOI
-    async fn get_paper_by_doi(&self, doi: &str) -> Result<Option<PaperMetadata>, ProviderError> {
+    async fn get_paper_by_doi(
+        &self,
+        doi: &str,
+        context: &SearchContext,
+    ) -> Result<Option<PaperMetadata>, ProviderError> {
         let url = self.build_doi_url(doi);
         debug!("Getting paper by DOI from CORE: {}", url);
 
-        let mut request = self.client.get(&url);
+        let mut request = with_context_headers(self.client.get(&url), context);
 
         // Add API key header if available
         for (key, value) in self.get_headers() {
@@ -257,15 +285,18 @@ impl CoreProvider {
             )));
         }
 
-        let response_text = response
-            .text()
-            .await
-            .map_err(|e| ProviderError::Network(format!("Failed to read response: {e}")))?;
+        let response_text = read_response_text(response, context).await?;
 
         debug!("CORE DOI response: {}", response_text);
 
-        let article_response: CoreArticleResponse = serde_json::from_str(&response_text)
-            .map_err(|e| ProviderError::Parse(format!("Failed to parse JSON: {e}")))?;
+        let article_response: CoreArticleResponse =
+            serde_json::from_str(&response_text).map_err(|e| {
+                ProviderError::parse_error(
+                    self.name(),
+                    format!("Failed to parse JSON: {e}"),
+                    &response_text,
+                )
+            })?;
 
         if article_response.status == "OK" {
             if let Some(article) = article_response.data {
@@ -286,6 +317,10 @@ impl SourceProvider for CoreProvider {
         "core"
     }
 
+    fn base_url(&self) -> Option<&str> {
+        Some(self.base_url.as_str())
+    }
+
     fn description(&self) -> &'static str {
         "CORE - World's largest collection of open access research papers"
     }
@@ -297,13 +332,18 @@ impl SourceProvider for CoreProvider {
             SearchType::Author,
             SearchType::Keywords,
             SearchType::Auto,
+            SearchType::FullText,
         ]
     }
 
-    fn supports_full_text(&self) -> bool {
+    fn provides_fulltext(&self) -> bool {
         true // CORE specifically focuses on open access full-text papers
     }
 
+    fn tier(&self) -> ProviderTier {
+        ProviderTier::FullTextOA // Aggregated open access repositories
+    }
+
     fn priority(&self) -> u8 {
         86 // High priority for open access content, between Unpaywall and SSRN
     }
@@ -319,7 +359,7 @@ impl SourceProvider for CoreProvider {
     async fn search(
         &self,
         query: &SearchQuery,
-        _context: &SearchContext,
+        context: &SearchContext,
     ) -> Result<ProviderResult, ProviderError> {
         let start_time = Instant::now();
 
@@ -331,17 +371,30 @@ impl SourceProvider for CoreProvider {
         let papers = match query.search_type {
             SearchType::Doi => {
                 // Try DOI lookup first
-                if let Some(paper) = self.get_paper_by_doi(&query.query).await? {
+                if let Some(paper) = self.get_paper_by_doi(&query.query, context).await? {
                     vec![paper]
                 } else {
                     // Fallback to search if DOI lookup fails
-                    self.search_papers(&query.query, query.max_results, query.offset)
+                    self.search_papers(&query.query, query.max_results, query.offset, context)
                         .await?
                 }
             }
+            SearchType::FullText => {
+                self.search_papers(
+                    &Self::full_text_query(&query.query),
+                    query.max_results,
+                    query.offset,
+                    context,
+                )
+                .await?
+            }
             _ => {
                 // Use general search for all other types
-                self.search_papers(&query.query, query.max_results, query.offset)
+                let q = query.all_terms().map_or_else(
+                    || query.query.clone(),
+                    |terms| Self::all_terms_query(&terms),
+                );
+                self.search_papers(&q, query.max_results, query.offset, context)
                     .await?
             }
         };
@@ -370,18 +423,18 @@ impl SourceProvider for CoreProvider {
     async fn get_by_doi(
         &self,
         doi: &str,
-        _context: &SearchContext,
+        context: &SearchContext,
     ) -> Result<Option<PaperMetadata>, ProviderError> {
         info!("Getting paper by DOI from CORE: {}", doi);
-        self.get_paper_by_doi(doi).await
+        self.get_paper_by_doi(doi, context).await
     }
 
-    async fn health_check(&self, _context: &SearchContext) -> Result<bool, ProviderError> {
+    async fn health_check(&self, context: &SearchContext) -> Result<bool, ProviderError> {
         debug!("Performing CORE health check");
 
         let url = format!("{}/search/works?q=test&limit=1", self.base_url);
 
-        let mut request = self.client.get(&url);
+        let mut request = with_context_headers(self.client.get(&url), context);
 
         // Add API key header if available
         for (key, value) in self.get_headers() {
@@ -442,8 +495,9 @@ mod tests {
         let provider = CoreProvider::new(None).unwrap();
 
         assert_eq!(provider.name(), "core");
-        assert!(provider.supports_full_text());
+        assert!(provider.provides_fulltext());
         assert_eq!(provider.priority(), 86);
+        assert_eq!(provider.tier(), ProviderTier::FullTextOA);
         assert!(provider.supported_search_types().contains(&SearchType::Doi));
         assert!(provider
             .supported_search_types()
@@ -461,6 +515,10 @@ mod tests {
 
         let doi_url = provider.build_doi_url("10.1038/nature12373");
         assert!(doi_url.contains("doi=10.1038%2Fnature12373"));
+
+        let full_text_url =
+            provider.build_search_url(&CoreProvider::full_text_query("held-out split"), 10, 0);
+        assert!(full_text_url.contains("q=fullText%3A%28held-out%20split%29"));
     }
 
     #[test]
@@ -1,8 +1,10 @@
 use super::traits::{
-    ProviderError, ProviderResult, SearchContext, SearchQuery, SearchType, SourceProvider,
+    provider_http_client, read_response_text, with_context_headers, ProviderError, ProviderResult,
+    ProviderTier, SearchContext, SearchQuery, SearchType, SourceProvider,
 };
 use crate::client::circuit_breaker_service::CircuitBreakerService;
 use crate::client::PaperMetadata;
+use crate::config::HttpConfig;
 use async_trait::async_trait;
 use reqwest::Client;
 use serde::Deserialize;
@@ -45,6 +47,39 @@ struct CrossRefWork {
     #[serde(rename = "type")]
     #[allow(dead_code)]
     work_type: Option<String>,
+    #[serde(default)]
+    license: Vec<CrossRefLicense>,
+}
+
+/// Response of the `works/{doi}` endpoint, reduced to the reference list
+#[derive(Debug, Deserialize)]
+struct CrossRefWorkResponse {
+    status: String,
+    message: CrossRefWorkReferences,
+}
+
+#[derive(Debug, Deserialize)]
+struct CrossRefWorkReferences {
+    /// Absent when the publisher deposits no references or keeps them closed
+    #[serde(default)]
+    reference: Vec<CrossRefReference>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CrossRefReference {
+    /// Only set for references `CrossRef` could match to a DOI
+    #[serde(rename = "DOI")]
+    doi: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CrossRefLicense {
+    #[serde(rename = "URL")]
+    url: String,
+    /// Which version the license covers: `vor` (published), `am` (accepted
+    /// manuscript), `tdm` (text mining) or `unspecified`
+    #[serde(rename = "content-version")]
+    content_version: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -70,11 +105,18 @@ pub struct CrossRefProvider {
 impl CrossRefProvider {
     /// Create a new `CrossRef` provider
     pub fn new(email: Option<String>) -> Result<Self, ProviderError> {
-        let client = Client::builder()
-            .timeout(Duration::from_secs(30))
-            .user_agent("knowledge_accumulator_mcp/0.2.1 (Academic Research Tool)")
-            .build()
-            .map_err(|e| ProviderError::Other(format!("Failed to create HTTP client: {e}")))?;
+        Self::with_http_config(email, &HttpConfig::default())
+    }
+
+    /// Create a new `CrossRef` provider whose client follows the `[http]` settings
+    pub fn with_http_config(
+        email: Option<String>,
+        http: &HttpConfig,
+    ) -> Result<Self, ProviderError> {
+        let client = provider_http_client(
+            http,
+            Some("knowledge_accumulator_mcp/0.2.1 (Academic Research Tool)"),
+        )?;
 
         Ok(Self {
             client,
@@ -84,6 +126,64 @@ impl CrossRefProvider {
         })
     }
 
+    /// DOIs of the works cited by `doi`, from the `reference` list `CrossRef`
+    /// holds for it. Works without deposited references, references without
+    /// a DOI and unknown DOIs yield nothing rather than an error.
+    pub async fn get_references(
+        &self,
+        doi: &str,
+        context: &SearchContext,
+    ) -> Result<Vec<String>, ProviderError> {
+        let clean_doi = doi
+            .trim()
+            .trim_start_matches("doi:")
+            .trim_start_matches("https://doi.org/");
+        let mut url = Url::parse(&format!("{}/{}", self.base_url, clean_doi))
+            .map_err(|e| ProviderError::InvalidQuery(format!("Invalid DOI {doi}: {e}")))?;
+        if let Some(email) = &self.email {
+            url.query_pairs_mut().append_pair("mailto", email);
+        }
+        debug!("CrossRef references URL: {}", url);
+
+        let response = self
+            .circuit_breaker_service
+            .call_http("crossref", || async {
+                with_context_headers(self.client.get(url.as_str()), context)
+                    .timeout(context.timeout)
+                    .send()
+                    .await
+            })
+            .await
+            .map_err(|e| {
+                error!("CrossRef references request failed: {}", e);
+                request_error(e)
+            })?;
+
+        let status = response.status();
+        if status.as_u16() == 404 {
+            debug!("CrossRef has no work for DOI {}", clean_doi);
+            return Ok(Vec::new());
+        }
+        if !status.is_success() {
+            return Err(match status.as_u16() {
+                429 => ProviderError::RateLimit,
+                503 => ProviderError::ServiceUnavailable(
+                    "CrossRef service temporarily unavailable".to_string(),
+                ),
+                _ => ProviderError::Network(format!("HTTP {status}")),
+            });
+        }
+
+        let response_text = read_response_text(response, context).await?;
+        let references = reference_dois(&response_text)?;
+        info!(
+            "CrossRef lists {} referenced DOIs for {}",
+            references.len(),
+            clean_doi
+        );
+        Ok(references)
+    }
+
     /// Build `CrossRef` API URL for search
     fn build_search_url(&self, query: &SearchQuery) -> Result<String, ProviderError> {
         let mut url = Url::parse(&self.base_url)
@@ -116,7 +216,7 @@ impl CrossRefProvider {
             SearchType::Author => {
                 params.push(("query.author", query.query.clone()));
             }
-            SearchType::Keywords | SearchType::Auto => {
+            SearchType::Keywords | SearchType::Auto | SearchType::FullText => {
                 params.push(("query", query.query.clone()));
             }
             SearchType::Subject => {
@@ -168,17 +268,102 @@ impl CrossRefProvider {
             .url
             .filter(|url| !url.is_empty() && url.contains(".pdf"));
 
+        // Prefer the license of the published version over text-mining ones
+        let license = work
+            .license
+            .iter()
+            .find(|license| license.content_version.as_deref() == Some("vor"))
+            .or_else(|| work.license.first())
+            .map(|license| license_name(&license.url));
+
         PaperMetadata {
             doi,
             title,
             authors,
+            raw_authors: Vec::new(),
             journal,
             year,
             abstract_text: work.abstract_text,
             pdf_url,
             file_size: None,
+            license,
+            is_open_access: None,
+        }
+    }
+}
+
+/// Provider error for a failed `CrossRef` request
+fn request_error(e: crate::Error) -> ProviderError {
+    match e {
+        crate::Error::CircuitBreakerOpen { service } => {
+            ProviderError::ServiceUnavailable(format!("Circuit breaker open for {service}"))
+        }
+        crate::Error::NetworkTimeout { .. } => ProviderError::Timeout,
+        crate::Error::ConnectionRefused { .. } => {
+            ProviderError::Network("Connection failed".to_string())
+        }
+        crate::Error::Http(http_err) => {
+            if http_err.is_timeout() {
+                ProviderError::Timeout
+            } else if http_err.is_connect() {
+                ProviderError::Network(format!("Connection failed: {http_err}"))
+            } else {
+                ProviderError::Network(format!("Request failed: {http_err}"))
+            }
+        }
+        _ => ProviderError::Network(format!("Request failed: {e}")),
+    }
+}
+
+/// Distinct DOIs in the `reference` list of a `works/{doi}` response, in
+/// citation order
+fn reference_dois(response_text: &str) -> Result<Vec<String>, ProviderError> {
+    let response: CrossRefWorkResponse = serde_json::from_str(response_text).map_err(|e| {
+        ProviderError::parse_error(
+            "crossref",
+            format!("Failed to parse JSON: {e}"),
+            response_text,
+        )
+    })?;
+    if response.status != "ok" {
+        return Err(ProviderError::Other(format!(
+            "CrossRef API error: {}",
+            response.status
+        )));
+    }
+
+    let mut dois: Vec<String> = Vec::new();
+    for doi in response
+        .message
+        .reference
+        .into_iter()
+        .filter_map(|reference| reference.doi)
+    {
+        let doi = doi.trim().to_lowercase();
+        if !doi.is_empty() && !dois.contains(&doi) {
+            dois.push(doi);
         }
     }
+    Ok(dois)
+}
+
+/// Short name for a Creative Commons license URL (`cc-by`, `cc-by-nc-nd`,
+/// `cc0`), or the URL itself for any other license
+fn license_name(url: &str) -> String {
+    let path = url
+        .trim()
+        .trim_start_matches("https://")
+        .trim_start_matches("http://")
+        .trim_start_matches("www.");
+    if let Some(kind) = path.strip_prefix("creativecommons.org/licenses/") {
+        if let Some(kind) = kind.split('/').next().filter(|kind| !kind.is_empty()) {
+            return format!("cc-{}", kind.to_ascii_lowercase());
+        }
+    }
+    if path.starts_with("creativecommons.org/publicdomain/zero/") {
+        return "cc0".to_string();
+    }
+    url.trim().to_string()
 }
 
 impl Default for CrossRefProvider {
@@ -206,6 +391,10 @@ impl SourceProvider for CrossRefProvider {
         "crossref"
     }
 
+    fn base_url(&self) -> Option<&str> {
+        Some(self.base_url.as_str())
+    }
+
     fn description(&self) -> &'static str {
         "CrossRef API - Comprehensive metadata for academic publications with DOI"
     }
@@ -220,10 +409,14 @@ impl SourceProvider for CrossRefProvider {
         ]
     }
 
-    fn supports_full_text(&self) -> bool {
+    fn provides_fulltext(&self) -> bool {
         false // CrossRef provides metadata, not full text
     }
 
+    fn tier(&self) -> ProviderTier {
+        ProviderTier::Authoritative // DOI registration agency, the reference for metadata
+    }
+
     fn priority(&self) -> u8 {
         90 // Very high priority for metadata
     }
@@ -256,45 +449,23 @@ impl SourceProvider for CrossRefProvider {
         let response = self
             .circuit_breaker_service
             .call_http("crossref", || async {
-                let mut request = self.client.get(&url);
-
-                // Add custom headers
-                for (key, value) in &context.headers {
-                    request = request.header(key, value);
-                }
-
-                request.timeout(context.timeout).send().await
+                with_context_headers(self.client.get(&url), context)
+                    .timeout(context.timeout)
+                    .send()
+                    .await
             })
             .await
             .map_err(|e| {
                 error!("CrossRef request failed: {}", e);
-                match e {
-                    crate::Error::CircuitBreakerOpen { service } => {
-                        ProviderError::ServiceUnavailable(format!(
-                            "Circuit breaker open for {service}"
-                        ))
-                    }
-                    crate::Error::NetworkTimeout { .. } => ProviderError::Timeout,
-                    crate::Error::ConnectionRefused { .. } => {
-                        ProviderError::Network("Connection failed".to_string())
-                    }
-                    crate::Error::Http(http_err) => {
-                        if http_err.is_timeout() {
-                            ProviderError::Timeout
-                        } else if http_err.is_connect() {
-                            ProviderError::Network(format!("Connection failed: {http_err}"))
-                        } else {
-                            ProviderError::Network(format!("Request failed: {http_err}"))
-                        }
-                    }
-                    _ => ProviderError::Network(format!("Request failed: {e}")),
-                }
+                request_error(e)
             })?;
 
         // Check response status
         if !response.status().is_success() {
             let status = response.status();
-            let error_text = response.text().await.unwrap_or_default();
+            let error_text = read_response_text(response, context)
+                .await
+                .unwrap_or_default();
 
             return Err(match status.as_u16() {
                 404 if query.search_type == SearchType::Doi => {
@@ -318,13 +489,16 @@ impl SourceProvider for CrossRefProvider {
         }
 
         // Parse the response
-        let response_text = response
-            .text()
-            .await
-            .map_err(|e| ProviderError::Network(format!("Failed to read response: {e}")))?;
-
-        let crossref_response: CrossRefResponse = serde_json::from_str(&response_text)
-            .map_err(|e| ProviderError::Parse(format!("Failed to parse JSON: {e}")))?;
+        let response_text = read_response_text(response, context).await?;
+
+        let crossref_response: CrossRefResponse =
+            serde_json::from_str(&response_text).map_err(|e| {
+                ProviderError::parse_error(
+                    self.name(),
+                    format!("Failed to parse JSON: {e}"),
+                    &response_text,
+                )
+            })?;
 
         if crossref_response.status != "ok" {
             return Err(ProviderError::Other(format!(
@@ -465,4 +639,60 @@ mod tests {
         let url = provider.build_search_url(&query).unwrap();
         assert!(url.contains("10.1038/nature12373"));
     }
+
+    #[test]
+    fn test_convert_work_reads_license() {
+        let provider = CrossRefProvider::new(None).unwrap();
+        let work: CrossRefWork = serde_json::from_value(serde_json::json!({
+            "DOI": "10.3390/s21010001",
+            "title": ["Sensor fusion"],
+            "license": [
+                {
+                    "URL": "https://www.elsevier.com/tdm/userlicense/1.0/",
+                    "content-version": "tdm"
+                },
+                {
+                    "URL": "https://creativecommons.org/licenses/by/4.0/",
+                    "content-version": "vor"
+                }
+            ]
+        }))
+        .unwrap();
+
+        let paper = provider.convert_work(work);
+        assert_eq!(paper.license.as_deref(), Some("cc-by"));
+        assert_eq!(
+            license_name("http://creativecommons.org/publicdomain/zero/1.0/"),
+            "cc0"
+        );
+    }
+
+    #[test]
+    fn test_reference_dois_extracts_cited_dois() {
+        let response = serde_json::json!({
+            "status": "ok",
+            "message": {
+                "DOI": "10.1038/nature12373",
+                "reference": [
+                    {"key": "ref1", "DOI": "10.1126/science.1254806", "doi-asserted-by": "crossref"},
+                    {"key": "ref2", "unstructured": "Smith J. An uncited report. 1999."},
+                    {"key": "ref3", "DOI": "10.1103/PhysRevLett.116.061102"},
+                    {"key": "ref4", "DOI": "10.1126/SCIENCE.1254806"}
+                ]
+            }
+        })
+        .to_string();
+
+        assert_eq!(
+            reference_dois(&response).unwrap(),
+            vec![
+                "10.1126/science.1254806".to_string(),
+                "10.1103/physrevlett.116.061102".to_string(),
+            ]
+        );
+
+        // Works without deposited references have no `reference` field
+        let no_references = r#"{"status": "ok", "message": {"DOI": "10.1000/bare"}}"#;
+        assert!(reference_dois(no_references).unwrap().is_empty());
+    }
 }
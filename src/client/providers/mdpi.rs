@@ -1,7 +1,9 @@
 use crate::client::providers::{
-    ProviderError, ProviderResult, SearchContext, SearchQuery, SearchType, SourceProvider,
+    provider_http_client, read_response_text, with_context_headers, ProviderError, ProviderResult,
+    ProviderTier, SearchContext, SearchQuery, SearchType, SourceProvider,
 };
 use crate::client::PaperMetadata;
+use crate::config::HttpConfig;
 use async_trait::async_trait;
 use regex::Regex;
 use reqwest::Client;
@@ -97,11 +99,15 @@ struct MdpiJournal {
 impl MdpiProvider {
     /// Create a new MDPI provider
     pub fn new() -> Result<Self, ProviderError> {
-        let client = Client::builder()
-            .user_agent("knowledge_accumulator_mcp/0.3.0 (Academic Research Tool)")
-            .timeout(Duration::from_secs(30))
-            .build()
-            .map_err(|e| ProviderError::Network(format!("Failed to create HTTP client: {e}")))?;
+        Self::with_http_config(&HttpConfig::default())
+    }
+
+    /// Create a new MDPI provider whose client follows the `[http]` settings
+    pub fn with_http_config(http: &HttpConfig) -> Result<Self, ProviderError> {
+        let client = provider_http_client(
+            http,
+            Some("knowledge_accumulator_mcp/0.3.0 (Academic Research Tool)"),
+        )?;
 
         Ok(Self {
             client,
@@ -129,9 +135,7 @@ impl MdpiProvider {
             ("format", "json"), // Try JSON first, fallback to HTML if needed
         ];
 
-        let response = self
-            .client
-            .get(&search_url)
+        let response = with_context_headers(self.client.get(&search_url), context)
             .query(&params)
             .timeout(context.timeout)
             .send()
@@ -144,10 +148,7 @@ impl MdpiProvider {
         }
 
         // Try to parse as JSON first
-        let response_text = response
-            .text()
-            .await
-            .map_err(|e| ProviderError::Network(format!("Failed to read MDPI response: {e}")))?;
+        let response_text = read_response_text(response, context).await?;
 
         // Since MDPI likely doesn't have a JSON API, we'll parse HTML
         if response_text.contains("<!DOCTYPE html") || response_text.contains("<html") {
@@ -184,9 +185,7 @@ impl MdpiProvider {
             urlencoding::encode(query)
         );
 
-        let response = self
-            .client
-            .get(&search_url)
+        let response = with_context_headers(self.client.get(&search_url), context)
             .timeout(context.timeout)
             .send()
             .await
@@ -199,10 +198,7 @@ impl MdpiProvider {
             )));
         }
 
-        let html_content = response
-            .text()
-            .await
-            .map_err(|e| ProviderError::Network(format!("Failed to read MDPI HTML: {e}")))?;
+        let html_content = read_response_text(response, context).await?;
 
         self.parse_html_results(&html_content)
     }
@@ -396,7 +392,10 @@ impl MdpiProvider {
                 // Direct DOI search
                 query.query.clone()
             }
-            SearchType::Keywords | SearchType::Auto | SearchType::Subject => {
+            SearchType::Keywords
+            | SearchType::Auto
+            | SearchType::Subject
+            | SearchType::FullText => {
                 // General search
                 query.query.clone()
             }
@@ -448,11 +447,14 @@ impl MdpiProvider {
             doi: article.doi.clone().unwrap_or_default(),
             title: article.title.clone(),
             authors,
+            raw_authors: Vec::new(),
             abstract_text: article.abstract_text.clone(),
             journal: journal_name,
             year,
             pdf_url: article.pdf_url.clone(),
             file_size: None, // File size not available from MDPI search
+            license: None,
+            is_open_access: Some(true), // MDPI publishes everything open access
         }
     }
 
@@ -469,15 +471,23 @@ impl SourceProvider for MdpiProvider {
         "mdpi"
     }
 
+    fn base_url(&self) -> Option<&str> {
+        Some(self.base_url.as_str())
+    }
+
     fn description(&self) -> &'static str {
         "MDPI provider for open access scientific journals"
     }
 
+    fn tier(&self) -> ProviderTier {
+        ProviderTier::FullTextOA // Open access publisher
+    }
+
     fn priority(&self) -> u8 {
         75 // Good priority for open access papers
     }
 
-    fn supports_full_text(&self) -> bool {
+    fn provides_fulltext(&self) -> bool {
         true
     }
 
@@ -568,9 +578,7 @@ impl SourceProvider for MdpiProvider {
     async fn health_check(&self, context: &SearchContext) -> Result<bool, ProviderError> {
         let health_url = format!("{}/search?q=test", self.base_url);
 
-        let response = self
-            .client
-            .get(&health_url)
+        let response = with_context_headers(self.client.get(&health_url), context)
             .timeout(context.timeout)
             .send()
             .await
@@ -601,6 +609,8 @@ mod tests {
             user_agent: "test".to_string(),
             rate_limit: None,
             headers: HashMap::new(),
+            max_response_bytes: None,
+            default_charset: None,
         }
     }
 
@@ -705,7 +715,8 @@ mod tests {
 
         assert_eq!(provider.name(), "mdpi");
         assert_eq!(provider.priority(), 75);
-        assert!(provider.supports_full_text());
+        assert_eq!(provider.tier(), ProviderTier::FullTextOA);
+        assert!(provider.provides_fulltext());
 
         let supported_types = provider.supported_search_types();
         assert!(supported_types.contains(&SearchType::Title));
@@ -12,6 +12,7 @@ pub mod semantic_scholar;
 pub mod ssrn;
 pub mod traits;
 pub mod unpaywall;
+pub mod zenodo;
 
 pub use arxiv::ArxivProvider;
 pub use biorxiv::BiorxivProvider;
@@ -26,6 +27,9 @@ pub use sci_hub::SciHubProvider;
 pub use semantic_scholar::SemanticScholarProvider;
 pub use ssrn::SsrnProvider;
 pub use traits::{
-    ProviderError, ProviderResult, SearchContext, SearchQuery, SearchType, SourceProvider,
+    provider_http_client, provider_order, read_response_text, with_context_headers, ProviderError,
+    ProviderResult, ProviderSignals, ProviderTier, SearchContext, SearchQuery, SearchType,
+    SourceProvider, ALL_TERMS_PARAM,
 };
 pub use unpaywall::UnpaywallProvider;
+pub use zenodo::ZenodoProvider;
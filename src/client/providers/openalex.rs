@@ -1,8 +1,10 @@
 use super::traits::{
-    ProviderError, ProviderResult, SearchContext, SearchQuery, SearchType, SourceProvider,
+    provider_http_client, read_response_text, with_context_headers, ProviderError, ProviderResult,
+    ProviderSignals, ProviderTier, SearchContext, SearchQuery, SearchType, SourceProvider,
 };
 use crate::client::rate_limiter::ProviderRateLimiter;
 use crate::client::PaperMetadata;
+use crate::config::HttpConfig;
 use async_trait::async_trait;
 use reqwest::Client;
 use serde::Deserialize;
@@ -54,6 +56,8 @@ struct AuthorInfo {
 struct Location {
     source: Option<SourceInfo>,
     pdf_url: Option<String>,
+    #[serde(default)]
+    license: Option<String>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -79,11 +83,12 @@ struct MetaInfo {
 impl OpenAlexProvider {
     /// Create a new OpenAlex provider
     pub fn new() -> Result<Self, ProviderError> {
-        let client = Client::builder()
-            .timeout(Duration::from_secs(30))
-            .user_agent("knowledge_accumulator_mcp/0.6.6 (Academic Research Tool; mailto:cthomasbrittain@hotmail.com)")
-            .build()
-            .map_err(|e| ProviderError::Other(format!("Failed to create HTTP client: {e}")))?;
+        Self::with_http_config(&HttpConfig::default())
+    }
+
+    /// Create a new OpenAlex provider whose client follows the `[http]` settings
+    pub fn with_http_config(http: &HttpConfig) -> Result<Self, ProviderError> {
+        let client = provider_http_client(http, Some("knowledge_accumulator_mcp/0.6.6 (Academic Research Tool; mailto:cthomasbrittain@hotmail.com)"))?;
 
         Ok(Self {
             client,
@@ -92,6 +97,13 @@ impl OpenAlexProvider {
         })
     }
 
+    /// Query `base_url` (the works endpoint) instead of the public API
+    #[must_use]
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
     /// Initialize rate limiter with configuration
     pub async fn init_rate_limiter(&self, config: &crate::config::RateLimitingConfig) {
         let limiter = ProviderRateLimiter::new("openalex".to_string(), config);
@@ -133,7 +145,7 @@ impl OpenAlexProvider {
                     return Err(ProviderError::InvalidQuery("Empty query".to_string()));
                 }
             }
-            SearchType::Keywords | SearchType::Auto => {
+            SearchType::Keywords | SearchType::Auto | SearchType::FullText => {
                 // For keywords/auto, search in abstract or use default search
                 if !query.query.trim().is_empty() {
                     format!("default.search:{}", query.query)
@@ -209,6 +221,13 @@ impl OpenAlexProvider {
                 .and_then(|loc| loc.pdf_url.clone())
                 .filter(|url| !url.is_empty());
 
+            // License of the open access copy, else of the published version
+            let license = [&work.best_oa_location, &work.primary_location]
+                .into_iter()
+                .flatten()
+                .find_map(|loc| loc.license.clone())
+                .filter(|license| !license.is_empty());
+
             // Reconstruct abstract from inverted index
             let abstract_text = Self::reconstruct_abstract(work.abstract_inverted_index.as_ref());
 
@@ -216,11 +235,14 @@ impl OpenAlexProvider {
                 doi,
                 title: work.title,
                 authors,
+                raw_authors: Vec::new(),
                 journal,
                 year: work.publication_year,
                 abstract_text,
                 pdf_url,
                 file_size: None, // OpenAlex doesn't provide file size
+                license,
+                is_open_access: Some(work.best_oa_location.is_some()),
             };
 
             papers.push(paper);
@@ -266,6 +288,67 @@ impl OpenAlexProvider {
         }
     }
 
+    /// Citation and reference counts and open access status for a DOI, or
+    /// `None` when OpenAlex does not know the work
+    pub async fn get_signals(
+        &self,
+        doi: &str,
+        context: &SearchContext,
+    ) -> Result<Option<ProviderSignals>, ProviderError> {
+        #[derive(Debug, Deserialize)]
+        struct SignalsResponse {
+            cited_by_count: Option<u64>,
+            referenced_works_count: Option<u64>,
+            open_access: Option<OpenAccessSignals>,
+        }
+
+        #[derive(Debug, Deserialize)]
+        struct OpenAccessSignals {
+            is_oa: Option<bool>,
+            oa_status: Option<String>,
+        }
+
+        self.apply_rate_limit().await?;
+
+        let url = format!("{}/doi:{}", self.base_url, urlencoding::encode(doi));
+        debug!("Getting signals from OpenAlex: {}", url);
+
+        let response = with_context_headers(self.client.get(&url), context)
+            .timeout(context.timeout)
+            .send()
+            .await
+            .map_err(|e| ProviderError::Network(format!("HTTP request failed: {e}")))?;
+
+        match response.status().as_u16() {
+            404 => return Ok(None),
+            429 => return Err(ProviderError::RateLimit),
+            status if !response.status().is_success() => {
+                return Err(ProviderError::ServiceUnavailable(format!("HTTP {status}")))
+            }
+            _ => {}
+        }
+
+        let response_text = read_response_text(response, context).await?;
+        let work: SignalsResponse = serde_json::from_str(&response_text).map_err(|e| {
+            ProviderError::parse_error(
+                self.name(),
+                format!("Failed to parse JSON response: {e}"),
+                &response_text,
+            )
+        })?;
+
+        let (is_open_access, oa_status) = work
+            .open_access
+            .map_or((None, None), |oa| (oa.is_oa, oa.oa_status));
+        Ok(Some(ProviderSignals {
+            citation_count: work.cited_by_count,
+            reference_count: work.referenced_works_count,
+            is_open_access,
+            oa_status,
+            ..ProviderSignals::default()
+        }))
+    }
+
     /// Apply rate limiting before making requests
     async fn apply_rate_limit(&self) -> Result<(), ProviderError> {
         let mut rate_limiter = self.rate_limiter.lock().await;
@@ -285,6 +368,14 @@ impl SourceProvider for OpenAlexProvider {
         "openalex"
     }
 
+    fn base_url(&self) -> Option<&str> {
+        Some(self.base_url.as_str())
+    }
+
+    fn tier(&self) -> ProviderTier {
+        ProviderTier::Authoritative // Open index of scholarly works
+    }
+
     fn priority(&self) -> u8 {
         180 // High priority - authoritative source with good coverage
     }
@@ -308,7 +399,7 @@ impl SourceProvider for OpenAlexProvider {
         "OpenAlex: Open catalog of scholarly papers, authors, venues, and institutions with 240M+ works"
     }
 
-    fn supports_full_text(&self) -> bool {
+    fn provides_fulltext(&self) -> bool {
         true // Many papers have open access PDFs
     }
 
@@ -342,29 +433,15 @@ impl SourceProvider for OpenAlexProvider {
             let url = search_url.clone();
 
             async move {
-                client
-                    .get(&url)
-                    .headers({
-                        let mut headers = reqwest::header::HeaderMap::new();
-                        headers.insert(
-                            reqwest::header::USER_AGENT,
-                            reqwest::header::HeaderValue::from_static(
-                                "knowledge_accumulator_mcp/0.6.6 (Academic Research Tool)",
-                            ),
-                        );
-                        // Add custom headers from context if any
-                        for (key, value) in &context.headers {
-                            if let (Ok(header_name), Ok(header_value)) = (
-                                reqwest::header::HeaderName::from_bytes(key.as_bytes()),
-                                reqwest::header::HeaderValue::from_str(value),
-                            ) {
-                                headers.insert(header_name, header_value);
-                            }
-                        }
-                        headers
-                    })
-                    .send()
-                    .await
+                with_context_headers(
+                    client.get(&url).header(
+                        reqwest::header::USER_AGENT,
+                        "knowledge_accumulator_mcp/0.6.6 (Academic Research Tool)",
+                    ),
+                    context,
+                )
+                .send()
+                .await
             }
         })
         .await
@@ -374,8 +451,7 @@ impl SourceProvider for OpenAlexProvider {
         // Check response status
         if !response.status().is_success() {
             let status = response.status();
-            let error_text = response
-                .text()
+            let error_text = read_response_text(response, context)
                 .await
                 .unwrap_or_else(|_| "Unknown error".to_string());
 
@@ -394,13 +470,16 @@ impl SourceProvider for OpenAlexProvider {
         }
 
         // Parse response
-        let response_text = response
-            .text()
-            .await
-            .map_err(|e| ProviderError::Network(format!("Failed to read response: {e}")))?;
+        let response_text = read_response_text(response, context).await?;
 
-        let openalex_response: OpenAlexResponse = serde_json::from_str(&response_text)
-            .map_err(|e| ProviderError::Parse(format!("Failed to parse JSON response: {e}")))?;
+        let openalex_response: OpenAlexResponse =
+            serde_json::from_str(&response_text).map_err(|e| {
+                ProviderError::parse_error(
+                    self.name(),
+                    format!("Failed to parse JSON response: {e}"),
+                    &response_text,
+                )
+            })?;
 
         // Convert to papers
         let papers = self.parse_response(openalex_response.clone())?;
@@ -441,10 +520,13 @@ impl SourceProvider for OpenAlexProvider {
 
         let health_url = "https://api.openalex.org/works?filter=title.search:test&per-page=1";
 
-        let response = tokio::time::timeout(context.timeout, self.client.get(health_url).send())
-            .await
-            .map_err(|_| ProviderError::Timeout)?
-            .map_err(|e| ProviderError::Network(format!("Health check failed: {e}")))?;
+        let response = tokio::time::timeout(
+            context.timeout,
+            with_context_headers(self.client.get(health_url), context).send(),
+        )
+        .await
+        .map_err(|_| ProviderError::Timeout)?
+        .map_err(|e| ProviderError::Network(format!("Health check failed: {e}")))?;
 
         let is_healthy = response.status().is_success();
 
@@ -636,8 +718,10 @@ mod tests {
 
         assert_eq!(provider.name(), "openalex");
         assert_eq!(provider.priority(), 180);
+        assert_eq!(provider.tier(), ProviderTier::Authoritative);
+        assert!(provider.priority() > ProviderTier::Authoritative.priority());
         assert_eq!(provider.base_delay(), Duration::from_millis(870));
-        assert!(provider.supports_full_text());
+        assert!(provider.provides_fulltext());
 
         let supported_types = provider.supported_search_types();
         assert!(supported_types.contains(&SearchType::Title));
@@ -676,10 +760,12 @@ mod tests {
                         display_name: Some("Nature".to_string()),
                     }),
                     pdf_url: None,
+                    license: None,
                 }),
                 best_oa_location: Some(Location {
                     source: None,
                     pdf_url: Some("https://arxiv.org/pdf/1706.03762.pdf".to_string()),
+                    license: Some("cc-by".to_string()),
                 }),
                 abstract_inverted_index: {
                     let mut index = HashMap::new();
@@ -715,6 +801,7 @@ mod tests {
             paper.abstract_text,
             Some("The dominant sequence models".to_string())
         );
+        assert_eq!(paper.license.as_deref(), Some("cc-by"));
     }
 
     #[test]
@@ -860,6 +947,7 @@ mod tests {
                 best_oa_location: Some(Location {
                     source: None,
                     pdf_url: Some("".to_string()), // Empty URL should be filtered
+                    license: None,
                 }),
                 abstract_inverted_index: None,
             }],
@@ -1,20 +1,48 @@
 use crate::client::providers::{
-    ProviderError, ProviderResult, SearchContext, SearchQuery, SearchType, SourceProvider,
+    provider_http_client, read_response_text, with_context_headers, ProviderError, ProviderResult,
+    ProviderTier, SearchContext, SearchQuery, SearchType, SourceProvider,
 };
 use crate::client::PaperMetadata;
+use crate::config::HttpConfig;
 use async_trait::async_trait;
 use regex::Regex;
 use reqwest::Client;
 use serde::Deserialize;
 use std::collections::HashMap;
+use std::sync::OnceLock;
 use std::time::Duration;
 use tracing::{debug, info};
 
+/// Search param holding an `OpenReview` venue, e.g. `NeurIPS.cc` or `ICLR.cc/2024/Conference`
+pub const VENUE_PARAM: &str = "venue";
+/// Search param holding a four-digit publication year
+pub const YEAR_PARAM: &str = "year";
+
+/// Short conference names accepted in place of their `OpenReview` group IDs
+const VENUE_ALIASES: &[(&str, &str)] = &[
+    ("neurips", "NeurIPS.cc"),
+    ("nips", "NeurIPS.cc"),
+    ("iclr", "ICLR.cc"),
+    ("icml", "ICML.cc"),
+];
+
+/// Pattern each `/`-separated segment of a venue group ID must match
+fn venue_segment_regex() -> &'static Regex {
+    static SEGMENT: OnceLock<Regex> = OnceLock::new();
+    SEGMENT.get_or_init(|| {
+        Regex::new(r"^[A-Za-z0-9][A-Za-z0-9_.\-]*$").expect("venue segment pattern is valid")
+    })
+}
+
 /// `OpenReview` provider for machine learning conference papers
 ///
 /// `OpenReview` is a venue for ML conference papers, reviews, and conference proceedings.
 /// This provider searches `OpenReview` submissions and provides access to papers
 /// from venues like `NeurIPS`, `ICLR`, `ICML`, etc.
+///
+/// Searches can be scoped with the [`VENUE_PARAM`] and [`YEAR_PARAM`] query
+/// params. A venue plus year becomes the venue ID
+/// `<venue>/<year>/Conference`; a year alone filters results by publication year.
 pub struct OpenReviewProvider {
     client: Client,
     base_url: String,
@@ -87,11 +115,15 @@ struct OpenReviewDetails {
 impl OpenReviewProvider {
     /// Create a new `OpenReview` provider
     pub fn new() -> Result<Self, ProviderError> {
-        let client = Client::builder()
-            .user_agent("knowledge_accumulator_mcp/0.3.0 (Academic Research Tool)")
-            .timeout(Duration::from_secs(30))
-            .build()
-            .map_err(|e| ProviderError::Network(format!("Failed to create HTTP client: {e}")))?;
+        Self::with_http_config(&HttpConfig::default())
+    }
+
+    /// Create a new `OpenReview` provider whose client follows the `[http]` settings
+    pub fn with_http_config(http: &HttpConfig) -> Result<Self, ProviderError> {
+        let client = provider_http_client(
+            http,
+            Some("knowledge_accumulator_mcp/0.3.0 (Academic Research Tool)"),
+        )?;
 
         Ok(Self {
             client,
@@ -104,26 +136,13 @@ impl OpenReviewProvider {
         &self,
         query: &str,
         max_results: usize,
+        venue_id: Option<&str>,
         context: &SearchContext,
     ) -> Result<Vec<OpenReviewNote>, ProviderError> {
         let search_url = format!("{}/notes", self.base_url);
+        let params = Self::build_request_params(query, max_results, venue_id);
 
-        let max_results_str = max_results.to_string();
-        let offset_str = "0".to_string();
-        let details_str = "directPdfLink,originalPdf".to_string();
-        let mut params = vec![
-            ("limit", &max_results_str),
-            ("offset", &offset_str),
-            ("details", &details_str),
-        ];
-
-        // Build content search query
-        let content_query = format!("content.title:{query} OR content.abstract:{query}");
-        params.push(("content", &content_query));
-
-        let response = self
-            .client
-            .get(&search_url)
+        let response = with_context_headers(self.client.get(&search_url), context)
             .query(&params)
             .timeout(context.timeout)
             .send()
@@ -139,9 +158,15 @@ impl OpenReviewProvider {
             )));
         }
 
-        let search_result: OpenReviewResponse = response.json().await.map_err(|e| {
-            ProviderError::Parse(format!("Failed to parse OpenReview response: {e}"))
-        })?;
+        let response_text = read_response_text(response, context).await?;
+        let search_result: OpenReviewResponse =
+            serde_json::from_str(&response_text).map_err(|e| {
+                ProviderError::parse_error(
+                    self.name(),
+                    format!("Failed to parse OpenReview response: {e}"),
+                    &response_text,
+                )
+            })?;
 
         debug!(
             "OpenReview search found {} results for query: '{}'",
@@ -152,6 +177,101 @@ impl OpenReviewProvider {
         Ok(search_result.notes)
     }
 
+    /// Query-string parameters for a `/notes` search
+    fn build_request_params(
+        query: &str,
+        max_results: usize,
+        venue_id: Option<&str>,
+    ) -> Vec<(&'static str, String)> {
+        let mut params = vec![
+            ("limit", max_results.to_string()),
+            ("offset", "0".to_string()),
+            ("details", "directPdfLink,originalPdf".to_string()),
+            (
+                "content",
+                format!("content.title:{query} OR content.abstract:{query}"),
+            ),
+        ];
+        if let Some(venue_id) = venue_id {
+            params.push(("content.venueid", venue_id.to_string()));
+        }
+        params
+    }
+
+    /// Resolve the venue and year params into an `OpenReview` venue ID and a
+    /// year to filter on. Venues must be group IDs (`NeurIPS.cc`,
+    /// `ICLR.cc/2024/Conference`) or one of the known short names.
+    fn build_scope(
+        params: &HashMap<String, String>,
+    ) -> Result<(Option<String>, Option<u32>), ProviderError> {
+        let year = params
+            .get(YEAR_PARAM)
+            .map(|year| year.trim())
+            .filter(|year| !year.is_empty())
+            .map(|year| {
+                year.parse::<u32>()
+                    .ok()
+                    .filter(|y| (1900..=2100).contains(y) && year.len() == 4)
+                    .ok_or_else(|| {
+                        ProviderError::InvalidQuery(format!(
+                            "OpenReview year must be a four-digit year, got '{year}'"
+                        ))
+                    })
+            })
+            .transpose()?;
+
+        let Some(venue) = params
+            .get(VENUE_PARAM)
+            .map(|venue| venue.trim().trim_end_matches('/'))
+            .filter(|venue| !venue.is_empty())
+        else {
+            return Ok((None, year));
+        };
+
+        let venue = VENUE_ALIASES
+            .iter()
+            .find(|(alias, _)| alias.eq_ignore_ascii_case(venue))
+            .map_or(venue, |(_, group)| *group);
+
+        let segment = venue_segment_regex();
+        let mut segments = venue.split('/');
+        let domain = segments.next().unwrap_or_default();
+        let segments: Vec<&str> = segments.collect();
+        if !domain.contains('.')
+            || !segment.is_match(domain)
+            || !segments.iter().all(|s| segment.is_match(s))
+        {
+            return Err(ProviderError::InvalidQuery(format!(
+                "OpenReview venue must be a group ID like 'NeurIPS.cc' or \
+                 'ICLR.cc/2024/Conference', got '{venue}'"
+            )));
+        }
+
+        let venue_year = segments
+            .iter()
+            .find(|s| s.len() == 4 && s.chars().all(|c| c.is_ascii_digit()))
+            .and_then(|s| s.parse::<u32>().ok());
+        let venue_id = match (venue_year, year) {
+            (Some(venue_year), Some(year)) if venue_year != year => {
+                return Err(ProviderError::InvalidQuery(format!(
+                    "OpenReview venue '{venue}' does not match year {year}"
+                )));
+            }
+            (Some(_), _) => venue.to_string(),
+            (None, Some(year)) if segments.is_empty() => format!("{venue}/{year}/Conference"),
+            (None, _) => venue.to_string(),
+        };
+
+        // The venue ID already scopes the year, so only year-only searches
+        // need filtering
+        let year_filter = if venue_year.is_some() || venue_id != venue {
+            None
+        } else {
+            year
+        };
+        Ok((Some(venue_id), year_filter))
+    }
+
     /// Build query string for different search types
     fn build_query(query: &SearchQuery) -> String {
         match query.search_type {
@@ -163,7 +283,10 @@ impl OpenReviewProvider {
                 // Search in authors field
                 query.query.clone()
             }
-            SearchType::Keywords | SearchType::Auto | SearchType::Subject => {
+            SearchType::Keywords
+            | SearchType::Auto
+            | SearchType::Subject
+            | SearchType::FullText => {
                 // General search across title and abstract
                 query.query.clone()
             }
@@ -236,11 +359,14 @@ impl OpenReviewProvider {
             doi: String::new(), // OpenReview papers typically don't have DOIs
             title: note.content.title.clone(),
             authors,
+            raw_authors: Vec::new(),
             abstract_text: note.content.abstract_text.clone(),
             journal,
             year,
             pdf_url,
             file_size: None, // File size not available from OpenReview API
+            license: None,
+            is_open_access: Some(true), // Submissions are public
         }
     }
 
@@ -340,15 +466,23 @@ impl SourceProvider for OpenReviewProvider {
         "openreview"
     }
 
+    fn base_url(&self) -> Option<&str> {
+        Some(self.base_url.as_str())
+    }
+
     fn description(&self) -> &'static str {
         "OpenReview provider for ML conference papers and proceedings"
     }
 
+    fn tier(&self) -> ProviderTier {
+        ProviderTier::FullTextOA // Conference submissions with free PDFs
+    }
+
     fn priority(&self) -> u8 {
         85 // High priority for ML papers
     }
 
-    fn supports_full_text(&self) -> bool {
+    fn provides_fulltext(&self) -> bool {
         true
     }
 
@@ -370,8 +504,17 @@ impl SourceProvider for OpenReviewProvider {
         info!("Searching OpenReview for: '{}'", query.query);
 
         let search_query = Self::build_query(query);
+        let (venue_id, year_filter) = Self::build_scope(&query.params)?;
+        if let Some(venue_id) = &venue_id {
+            debug!("Scoping OpenReview search to venue {}", venue_id);
+        }
         let notes = self
-            .search_openreview(&search_query, query.max_results as usize, context)
+            .search_openreview(
+                &search_query,
+                query.max_results as usize,
+                venue_id.as_deref(),
+                context,
+            )
             .await?;
 
         if notes.is_empty() {
@@ -401,6 +544,7 @@ impl SourceProvider for OpenReviewProvider {
                 let venue = paper.journal.as_deref().unwrap_or("");
                 self.is_ml_paper(title, abstract_text, venue)
             })
+            .filter(|paper| year_filter.map_or(true, |year| paper.year == Some(year)))
             .collect();
 
         let search_time = start_time.elapsed();
@@ -438,9 +582,7 @@ impl SourceProvider for OpenReviewProvider {
     async fn health_check(&self, context: &SearchContext) -> Result<bool, ProviderError> {
         let health_url = format!("{}/notes?limit=1", self.base_url);
 
-        let response = self
-            .client
-            .get(&health_url)
+        let response = with_context_headers(self.client.get(&health_url), context)
             .timeout(context.timeout)
             .send()
             .await
@@ -471,6 +613,8 @@ mod tests {
             user_agent: "test".to_string(),
             rate_limit: None,
             headers: HashMap::new(),
+            max_response_bytes: None,
+            default_charset: None,
         }
     }
 
@@ -536,13 +680,75 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_build_scope_venue_and_year_builds_venue_id() {
+        let params = HashMap::from([
+            (VENUE_PARAM.to_string(), "NeurIPS".to_string()),
+            (YEAR_PARAM.to_string(), "2023".to_string()),
+        ]);
+
+        let (venue_id, year_filter) = OpenReviewProvider::build_scope(&params).unwrap();
+        assert_eq!(venue_id.as_deref(), Some("NeurIPS.cc/2023/Conference"));
+        assert_eq!(year_filter, None);
+
+        let request =
+            OpenReviewProvider::build_request_params("diffusion", 10, venue_id.as_deref());
+        assert!(request.contains(&("content.venueid", "NeurIPS.cc/2023/Conference".to_string())));
+        assert!(request.contains(&(
+            "content",
+            "content.title:diffusion OR content.abstract:diffusion".to_string()
+        )));
+    }
+
+    #[test]
+    fn test_build_scope_full_venue_id_and_year_only() {
+        let params = HashMap::from([(
+            VENUE_PARAM.to_string(),
+            "ICLR.cc/2024/Conference".to_string(),
+        )]);
+        let (venue_id, year_filter) = OpenReviewProvider::build_scope(&params).unwrap();
+        assert_eq!(venue_id.as_deref(), Some("ICLR.cc/2024/Conference"));
+        assert_eq!(year_filter, None);
+
+        let params = HashMap::from([(YEAR_PARAM.to_string(), "2022".to_string())]);
+        let (venue_id, year_filter) = OpenReviewProvider::build_scope(&params).unwrap();
+        assert_eq!(venue_id, None);
+        assert_eq!(year_filter, Some(2022));
+
+        let request = OpenReviewProvider::build_request_params("diffusion", 10, None);
+        assert!(request.iter().all(|(key, _)| *key != "content.venueid"));
+    }
+
+    #[test]
+    fn test_build_scope_invalid_venue_or_year_rejected() {
+        for (venue, year) in [
+            ("NeurIPS 2023", ""),
+            ("neurips.cc/2023/<script>", ""),
+            ("ICLR.cc/2024/Conference", "2023"),
+            ("ICML.cc", "23"),
+        ] {
+            let params = HashMap::from([
+                (VENUE_PARAM.to_string(), venue.to_string()),
+                (YEAR_PARAM.to_string(), year.to_string()),
+            ]);
+            assert!(
+                matches!(
+                    OpenReviewProvider::build_scope(&params),
+                    Err(ProviderError::InvalidQuery(_))
+                ),
+                "expected '{venue}' / '{year}' to be rejected"
+            );
+        }
+    }
+
     #[test]
     fn test_provider_metadata() {
         let provider = OpenReviewProvider::new().unwrap();
 
         assert_eq!(provider.name(), "openreview");
         assert_eq!(provider.priority(), 85);
-        assert!(provider.supports_full_text());
+        assert_eq!(provider.tier(), ProviderTier::FullTextOA);
+        assert!(provider.provides_fulltext());
 
         let supported_types = provider.supported_search_types();
         assert!(supported_types.contains(&SearchType::Title));
@@ -1,7 +1,9 @@
 use crate::client::providers::{
-    ProviderError, ProviderResult, SearchContext, SearchQuery, SearchType, SourceProvider,
+    provider_http_client, read_response_text, with_context_headers, ProviderError, ProviderResult,
+    ProviderTier, SearchContext, SearchQuery, SearchType, SourceProvider,
 };
 use crate::client::PaperMetadata;
+use crate::config::HttpConfig;
 use async_trait::async_trait;
 use regex::Regex;
 use reqwest::Client;
@@ -100,13 +102,18 @@ struct ArticleId {
 impl PubMedCentralProvider {
     /// Create a new `PubMed Central` provider
     pub fn new(api_key: Option<String>) -> Result<Self, ProviderError> {
-        let client = Client::builder()
-            .user_agent(
-                "knowledge_accumulator_mcp/0.3.0 (https://github.com/Ladvien/research_hub_mcp)",
-            )
-            .timeout(std::time::Duration::from_secs(30))
-            .build()
-            .map_err(|e| ProviderError::Network(format!("Failed to create HTTP client: {e}")))?;
+        Self::with_http_config(api_key, &HttpConfig::default())
+    }
+
+    /// Create a new `PubMed Central` provider whose client follows the `[http]` settings
+    pub fn with_http_config(
+        api_key: Option<String>,
+        http: &HttpConfig,
+    ) -> Result<Self, ProviderError> {
+        let client = provider_http_client(
+            http,
+            Some("knowledge_accumulator_mcp/0.3.0 (https://github.com/Ladvien/research_hub_mcp)"),
+        )?;
 
         Ok(Self {
             client,
@@ -138,9 +145,7 @@ impl PubMedCentralProvider {
             params.push(("api_key", api_key));
         }
 
-        let response = self
-            .client
-            .get(&search_url)
+        let response = with_context_headers(self.client.get(&search_url), context)
             .query(&params)
             .timeout(context.timeout)
             .send()
@@ -154,9 +159,15 @@ impl PubMedCentralProvider {
             )));
         }
 
-        let search_result: PmcSearchResponse = response.json().await.map_err(|e| {
-            ProviderError::Parse(format!("Failed to parse PMC search response: {e}"))
-        })?;
+        let response_text = read_response_text(response, context).await?;
+        let search_result: PmcSearchResponse =
+            serde_json::from_str(&response_text).map_err(|e| {
+                ProviderError::parse_error(
+                    self.name(),
+                    format!("Failed to parse PMC search response: {e}"),
+                    &response_text,
+                )
+            })?;
 
         // Check for errors in the response
         if let Some(ref error_list) = search_result.esearchresult.errorlist {
@@ -192,9 +203,7 @@ impl PubMedCentralProvider {
             params.push(("api_key", api_key));
         }
 
-        let response = self
-            .client
-            .get(&fetch_url)
+        let response = with_context_headers(self.client.get(&fetch_url), context)
             .query(&params)
             .timeout(context.timeout)
             .send()
@@ -208,8 +217,13 @@ impl PubMedCentralProvider {
             )));
         }
 
-        let fetch_result: PmcFetchResponse = response.json().await.map_err(|e| {
-            ProviderError::Parse(format!("Failed to parse PMC fetch response: {e}"))
+        let response_text = read_response_text(response, context).await?;
+        let fetch_result: PmcFetchResponse = serde_json::from_str(&response_text).map_err(|e| {
+            ProviderError::parse_error(
+                self.name(),
+                format!("Failed to parse PMC fetch response: {e}"),
+                &response_text,
+            )
         })?;
 
         Ok(fetch_result.result.into_values().collect())
@@ -230,7 +244,10 @@ impl PubMedCentralProvider {
                 // Search in author field
                 format!("{}[author]", query.query)
             }
-            SearchType::Keywords | SearchType::Auto | SearchType::Subject => {
+            SearchType::Keywords
+            | SearchType::Auto
+            | SearchType::Subject
+            | SearchType::FullText => {
                 // General search across all fields
                 query.query.clone()
             }
@@ -267,6 +284,7 @@ impl PubMedCentralProvider {
             doi,
             title: article.title.clone(),
             authors,
+            raw_authors: Vec::new(),
             abstract_text: article.abstract_text.clone(),
             journal: article.journal_name.clone(),
             year: article.pub_date.as_ref().and_then(|date| {
@@ -281,6 +299,8 @@ impl PubMedCentralProvider {
             }),
             pdf_url,
             file_size: None, // File size not available from PMC API
+            license: None,
+            is_open_access: Some(true), // PMC articles are free to read
         }
     }
 
@@ -314,11 +334,23 @@ impl SourceProvider for PubMedCentralProvider {
         "pubmed_central"
     }
 
+    fn medline_author_names(&self) -> bool {
+        true
+    }
+
+    fn base_url(&self) -> Option<&str> {
+        Some(self.base_url.as_str())
+    }
+
+    fn tier(&self) -> ProviderTier {
+        ProviderTier::FullTextOA // Free full-text archive of biomedical papers
+    }
+
     fn priority(&self) -> u8 {
         89 // High priority for biomedical papers
     }
 
-    fn supports_full_text(&self) -> bool {
+    fn provides_fulltext(&self) -> bool {
         true
     }
 
@@ -412,9 +444,7 @@ impl SourceProvider for PubMedCentralProvider {
             self.base_url
         );
 
-        let response = self
-            .client
-            .get(&health_url)
+        let response = with_context_headers(self.client.get(&health_url), context)
             .timeout(context.timeout)
             .send()
             .await
@@ -442,6 +472,8 @@ mod tests {
             user_agent: "test".to_string(),
             rate_limit: None,
             headers: HashMap::new(),
+            max_response_bytes: None,
+            default_charset: None,
         }
     }
 
@@ -497,7 +529,8 @@ mod tests {
 
         assert_eq!(provider.name(), "pubmed_central");
         assert_eq!(provider.priority(), 89);
-        assert!(provider.supports_full_text());
+        assert_eq!(provider.tier(), ProviderTier::FullTextOA);
+        assert!(provider.provides_fulltext());
 
         let supported_types = provider.supported_search_types();
         assert!(supported_types.contains(&SearchType::Doi));
@@ -1,7 +1,9 @@
 use crate::client::providers::{
-    ProviderError, ProviderResult, SearchContext, SearchQuery, SearchType, SourceProvider,
+    provider_http_client, read_response_text, with_context_headers, ProviderError, ProviderResult,
+    ProviderTier, SearchContext, SearchQuery, SearchType, SourceProvider,
 };
 use crate::client::PaperMetadata;
+use crate::config::HttpConfig;
 use async_trait::async_trait;
 use regex::Regex;
 use reqwest::Client;
@@ -24,11 +26,15 @@ pub struct ResearchGateProvider {
 impl ResearchGateProvider {
     /// Create a new `ResearchGate` provider with ethical scraping settings
     pub fn new() -> Result<Self, ProviderError> {
-        let client = Client::builder()
-            .user_agent("knowledge_accumulator_mcp/0.3.0 (Academic Research Tool)")
-            .timeout(Duration::from_secs(30))
-            .build()
-            .map_err(|e| ProviderError::Network(format!("Failed to create HTTP client: {e}")))?;
+        Self::with_http_config(&HttpConfig::default())
+    }
+
+    /// Create a new `ResearchGate` provider whose client follows the `[http]` settings
+    pub fn with_http_config(http: &HttpConfig) -> Result<Self, ProviderError> {
+        let client = provider_http_client(
+            http,
+            Some("knowledge_accumulator_mcp/0.3.0 (Academic Research Tool)"),
+        )?;
 
         Ok(Self {
             client,
@@ -55,9 +61,7 @@ impl ResearchGateProvider {
         // Add rate limiting to be respectful
         tokio::time::sleep(self.rate_limit).await;
 
-        let response = self
-            .client
-            .get(url)
+        let response = with_context_headers(self.client.get(url), context)
             .timeout(context.timeout)
             .send()
             .await
@@ -67,9 +71,7 @@ impl ResearchGateProvider {
             return Ok(None);
         }
 
-        let html = response.text().await.map_err(|e| {
-            ProviderError::Parse(format!("Failed to read ResearchGate response: {e}"))
-        })?;
+        let html = read_response_text(response, context).await?;
 
         self.parse_publication_page(&html)
     }
@@ -120,11 +122,14 @@ impl ResearchGateProvider {
                 doi,
                 title,
                 authors,
+                raw_authors: Vec::new(),
                 journal: None,       // Not easily extractable from RG
                 year: None,          // Would need more complex parsing
                 abstract_text: None, // Requires login for full access
                 pdf_url: None,       // ResearchGate PDFs require authentication
                 file_size: None,
+                license: None,
+                is_open_access: None,
             }))
         } else {
             Ok(None)
@@ -138,18 +143,30 @@ impl SourceProvider for ResearchGateProvider {
         "researchgate"
     }
 
+    fn base_url(&self) -> Option<&str> {
+        Some(self.base_url.as_str())
+    }
+
     fn description(&self) -> &'static str {
         "ResearchGate provider with ethical scraping (limited functionality)"
     }
 
+    fn tier(&self) -> ProviderTier {
+        ProviderTier::FullTextGray // PDFs are uploaded by authors and often require a login
+    }
+
     fn priority(&self) -> u8 {
         70 // Lower priority due to access limitations
     }
 
-    fn supports_full_text(&self) -> bool {
+    fn provides_fulltext(&self) -> bool {
         false // ResearchGate PDFs require authentication
     }
 
+    fn provides_abstracts(&self) -> bool {
+        false // Abstracts require login
+    }
+
     fn supported_search_types(&self) -> Vec<SearchType> {
         vec![SearchType::Auto, SearchType::Title, SearchType::Author]
     }
@@ -208,9 +225,12 @@ impl SourceProvider for ResearchGateProvider {
         Ok(None)
     }
 
-    async fn health_check(&self, _context: &SearchContext) -> Result<bool, ProviderError> {
+    async fn health_check(&self, context: &SearchContext) -> Result<bool, ProviderError> {
         // Simple check to see if ResearchGate is accessible
-        match self.client.head(&self.base_url).send().await {
+        match with_context_headers(self.client.head(&self.base_url), context)
+            .send()
+            .await
+        {
             Ok(response) => Ok(response.status().is_success()),
             Err(_) => Ok(false),
         }
@@ -228,6 +248,8 @@ mod tests {
             user_agent: "test".to_string(),
             rate_limit: None,
             headers: HashMap::new(),
+            max_response_bytes: None,
+            default_charset: None,
         }
     }
 
@@ -252,7 +274,8 @@ mod tests {
 
         assert_eq!(provider.name(), "researchgate");
         assert_eq!(provider.priority(), 70);
-        assert!(!provider.supports_full_text());
+        assert_eq!(provider.tier(), ProviderTier::FullTextGray);
+        assert!(!provider.provides_fulltext());
 
         let supported_types = provider.supported_search_types();
         assert!(supported_types.contains(&SearchType::Auto));
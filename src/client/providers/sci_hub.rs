@@ -1,8 +1,10 @@
 use super::traits::{
-    ProviderError, ProviderResult, SearchContext, SearchQuery, SearchType, SourceProvider,
+    provider_http_client, read_response_text, with_context_headers, ProviderError, ProviderResult,
+    ProviderTier, SearchContext, SearchQuery, SearchType, SourceProvider,
 };
 use crate::client::circuit_breaker_service::CircuitBreakerService;
 use crate::client::PaperMetadata;
+use crate::config::HttpConfig;
 use async_trait::async_trait;
 use reqwest::Client;
 use scraper::{Html, Selector};
@@ -24,11 +26,12 @@ pub struct SciHubProvider {
 impl SciHubProvider {
     /// Create a new Sci-Hub provider with known mirrors
     pub fn new() -> Result<Self, ProviderError> {
-        let client = Client::builder()
-            .timeout(Duration::from_secs(30))
-            // Don't set user agent here - we'll rotate them per request
-            .build()
-            .map_err(|e| ProviderError::Network(format!("Failed to create HTTP client: {e}")))?;
+        Self::with_http_config(&HttpConfig::default())
+    }
+
+    /// Create a new Sci-Hub provider whose client follows the `[http]` settings
+    pub fn with_http_config(http: &HttpConfig) -> Result<Self, ProviderError> {
+        let client = provider_http_client(http, None)?;
 
         // Updated Sci-Hub mirrors (as of 2024)
         let mirrors = vec![
@@ -91,6 +94,7 @@ impl SciHubProvider {
         &self,
         identifier: &str,
         search_type: &SearchType,
+        context: &SearchContext,
     ) -> Result<Option<PaperMetadata>, ProviderError> {
         let query = match search_type {
             SearchType::Doi => Self::clean_doi(identifier),
@@ -101,7 +105,7 @@ impl SciHubProvider {
         for _ in 0..self.mirrors.len() {
             let mirror = self.get_next_mirror();
 
-            match self.try_mirror(&mirror, &query).await {
+            match self.try_mirror(&mirror, &query, context).await {
                 Ok(Some(metadata)) => {
                     info!("Successfully found paper on Sci-Hub mirror: {}", mirror);
                     return Ok(Some(metadata));
@@ -123,6 +127,7 @@ impl SciHubProvider {
         &self,
         mirror: &str,
         query: &str,
+        context: &SearchContext,
     ) -> Result<Option<PaperMetadata>, ProviderError> {
         let url = format!("{}/{}", mirror, urlencoding::encode(query));
         let user_agent = self.get_next_user_agent();
@@ -135,21 +140,19 @@ impl SciHubProvider {
         let response = self
             .circuit_breaker_service
             .call_http("sci_hub", || async {
-                self
-                .client
-                .get(&url)
-                .header("User-Agent", user_agent)
-                .header(
-                    "Accept",
-                    "text/html,application/xhtml+xml,application/xml;q=0.9,image/webp,*/*;q=0.8",
-                )
-                .header("Accept-Language", "en-US,en;q=0.5")
-                .header("Accept-Encoding", "gzip, deflate")
-                .header("DNT", "1")
-                .header("Connection", "keep-alive")
-                .header("Upgrade-Insecure-Requests", "1")
-                .send()
-                .await
+                with_context_headers(self.client.get(&url), context)
+                    .header("User-Agent", user_agent.as_str())
+                    .header(
+                        "Accept",
+                        "text/html,application/xhtml+xml,application/xml;q=0.9,image/webp,*/*;q=0.8",
+                    )
+                    .header("Accept-Language", "en-US,en;q=0.5")
+                    .header("Accept-Encoding", "gzip, deflate")
+                    .header("DNT", "1")
+                    .header("Connection", "keep-alive")
+                    .header("Upgrade-Insecure-Requests", "1")
+                    .send()
+                    .await
             })
             .await
             .map_err(|e| match e {
@@ -180,10 +183,7 @@ impl SciHubProvider {
             )));
         }
 
-        let html_content = response
-            .text()
-            .await
-            .map_err(|e| ProviderError::Network(format!("Failed to read response: {e}")))?;
+        let html_content = read_response_text(response, context).await?;
 
         self.parse_scihub_response(&html_content, query)
     }
@@ -273,12 +273,15 @@ impl SciHubProvider {
             let metadata = PaperMetadata {
                 doi: original_query.to_string(),
                 title,
-                authors: Vec::new(), // Sci-Hub doesn't provide detailed metadata
+                authors: Vec::new(),
+                raw_authors: Vec::new(), // Sci-Hub doesn't provide detailed metadata
                 journal: None,
                 year: None,
                 abstract_text: None,
                 pdf_url,
                 file_size: None,
+                license: None,
+                is_open_access: None,
             };
 
             Ok(Some(metadata))
@@ -302,10 +305,18 @@ impl SourceProvider for SciHubProvider {
         vec![SearchType::Doi, SearchType::Title, SearchType::Auto]
     }
 
-    fn supports_full_text(&self) -> bool {
+    fn provides_fulltext(&self) -> bool {
         true
     }
 
+    fn provides_abstracts(&self) -> bool {
+        false
+    }
+
+    fn tier(&self) -> ProviderTier {
+        ProviderTier::FullTextGray // Fallback for full-text access
+    }
+
     fn priority(&self) -> u8 {
         10 // Lower priority, use as fallback for full-text access
     }
@@ -317,7 +328,7 @@ impl SourceProvider for SciHubProvider {
     async fn search(
         &self,
         query: &SearchQuery,
-        _context: &SearchContext,
+        context: &SearchContext,
     ) -> Result<ProviderResult, ProviderError> {
         let start_time = Instant::now();
 
@@ -327,7 +338,7 @@ impl SourceProvider for SciHubProvider {
         );
 
         let paper = self
-            .fetch_from_scihub(&query.query, &query.search_type)
+            .fetch_from_scihub(&query.query, &query.search_type, context)
             .await?;
 
         let papers = if let Some(metadata) = paper {
@@ -362,13 +373,13 @@ impl SourceProvider for SciHubProvider {
     async fn get_by_doi(
         &self,
         doi: &str,
-        _context: &SearchContext,
+        context: &SearchContext,
     ) -> Result<Option<PaperMetadata>, ProviderError> {
         info!("Getting paper by DOI from Sci-Hub: {}", doi);
-        self.fetch_from_scihub(doi, &SearchType::Doi).await
+        self.fetch_from_scihub(doi, &SearchType::Doi, context).await
     }
 
-    async fn health_check(&self, _context: &SearchContext) -> Result<bool, ProviderError> {
+    async fn health_check(&self, context: &SearchContext) -> Result<bool, ProviderError> {
         debug!("Performing Sci-Hub health check");
 
         // Try to access the main page of the first mirror
@@ -376,7 +387,11 @@ impl SourceProvider for SciHubProvider {
 
         let response = self
             .circuit_breaker_service
-            .call_http("sci_hub", || async { self.client.get(mirror).send().await })
+            .call_http("sci_hub", || async {
+                with_context_headers(self.client.get(mirror), context)
+                    .send()
+                    .await
+            })
             .await;
 
         match response {
@@ -437,8 +452,9 @@ mod tests {
         let provider = SciHubProvider::new().unwrap();
 
         assert_eq!(provider.name(), "sci_hub");
-        assert!(provider.supports_full_text());
+        assert!(provider.provides_fulltext());
         assert_eq!(provider.priority(), 10);
+        assert_eq!(provider.tier(), ProviderTier::FullTextGray);
         assert!(provider.supported_search_types().contains(&SearchType::Doi));
     }
 }
@@ -1,7 +1,9 @@
 use super::traits::{
-    ProviderError, ProviderResult, SearchContext, SearchQuery, SearchType, SourceProvider,
+    provider_http_client, read_response_text, with_context_headers, ProviderError, ProviderResult,
+    ProviderSignals, ProviderTier, SearchContext, SearchQuery, SearchType, SourceProvider,
 };
 use crate::client::PaperMetadata;
+use crate::config::HttpConfig;
 use async_trait::async_trait;
 use reqwest::Client;
 use serde::Deserialize;
@@ -13,7 +15,6 @@ use tracing::{debug, info, warn};
 #[derive(Debug, Deserialize)]
 struct SemanticScholarResponse {
     data: Vec<SemanticScholarPaper>,
-    #[allow(dead_code)]
     total: Option<u32>,
     #[allow(dead_code)]
     offset: Option<u32>,
@@ -79,6 +80,38 @@ struct Journal {
     name: Option<String>,
 }
 
+/// Semantic Scholar snippet search response, used for full-text queries
+#[derive(Debug, Deserialize)]
+struct SnippetSearchResponse {
+    data: Vec<SnippetMatch>,
+}
+
+/// A passage of a paper's body text matching the query
+#[derive(Debug, Deserialize)]
+struct SnippetMatch {
+    paper: SnippetPaper,
+}
+
+#[derive(Debug, Deserialize)]
+struct SnippetPaper {
+    #[serde(rename = "corpusId")]
+    corpus_id: Option<serde_json::Value>,
+    title: Option<String>,
+    #[serde(default)]
+    authors: Vec<SnippetAuthor>,
+}
+
+/// Snippet results list authors either by name or as author objects
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum SnippetAuthor {
+    Name(String),
+    Author(Author),
+}
+
+/// Most snippets the snippet search endpoint returns per request
+const MAX_SNIPPET_RESULTS: u32 = 1000;
+
 /// Semantic Scholar provider for academic papers
 pub struct SemanticScholarProvider {
     client: Client,
@@ -89,11 +122,18 @@ pub struct SemanticScholarProvider {
 impl SemanticScholarProvider {
     /// Create a new Semantic Scholar provider
     pub fn new(api_key: Option<String>) -> Result<Self, ProviderError> {
-        let client = Client::builder()
-            .timeout(Duration::from_secs(30))
-            .user_agent("knowledge_accumulator_mcp/0.2.1 (Academic Research Tool)")
-            .build()
-            .map_err(|e| ProviderError::Network(format!("Failed to create HTTP client: {e}")))?;
+        Self::with_http_config(api_key, &HttpConfig::default())
+    }
+
+    /// Create a new Semantic Scholar provider whose client follows the `[http]` settings
+    pub fn with_http_config(
+        api_key: Option<String>,
+        http: &HttpConfig,
+    ) -> Result<Self, ProviderError> {
+        let client = provider_http_client(
+            http,
+            Some("knowledge_accumulator_mcp/0.2.1 (Academic Research Tool)"),
+        )?;
 
         Ok(Self {
             client,
@@ -102,6 +142,13 @@ impl SemanticScholarProvider {
         })
     }
 
+    /// Query `base_url` instead of the public Graph API
+    #[must_use]
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
     /// Build search URL for Semantic Scholar API
     fn build_search_url(&self, query: &str, fields: &[&str], limit: u32, offset: u32) -> String {
         let fields_param = fields.join(",");
@@ -115,6 +162,16 @@ impl SemanticScholarProvider {
         )
     }
 
+    /// Build snippet search URL, which matches the body text of papers
+    fn build_snippet_search_url(&self, query: &str, limit: u32) -> String {
+        format!(
+            "{}/snippet/search?query={}&limit={}",
+            self.base_url,
+            urlencoding::encode(query),
+            limit
+        )
+    }
+
     /// Build DOI lookup URL
     fn build_doi_url(&self, doi: &str, fields: &[&str]) -> String {
         let fields_param = fields.join(",");
@@ -159,26 +216,33 @@ impl SemanticScholarProvider {
             .open_access_pdf
             .and_then(|pdf| pdf.url)
             .filter(|url| !url.is_empty());
+        // Without an open access PDF the paper may still be free elsewhere
+        let is_open_access = pdf_url.is_some().then_some(true);
 
         PaperMetadata {
             doi,
             title: paper.title,
             authors,
+            raw_authors: Vec::new(),
             journal,
             year: paper.year,
             abstract_text: paper.abstract_text,
             pdf_url,
             file_size: None,
+            license: None,
+            is_open_access,
         }
     }
 
-    /// Search papers by query
+    /// Search papers by query, returning the papers and the total number of
+    /// matches reported by the API
     async fn search_papers(
         &self,
         query: &str,
         limit: u32,
         offset: u32,
-    ) -> Result<Vec<PaperMetadata>, ProviderError> {
+        context: &SearchContext,
+    ) -> Result<(Vec<PaperMetadata>, Option<u32>), ProviderError> {
         let fields = [
             "paperId",
             "externalIds",
@@ -196,7 +260,7 @@ impl SemanticScholarProvider {
         let url = self.build_search_url(query, &fields, limit, offset);
         debug!("Searching Semantic Scholar: {}", url);
 
-        let mut request = self.client.get(&url);
+        let mut request = with_context_headers(self.client.get(&url), context);
 
         // Add API key header if available
         for (key, value) in self.get_headers() {
@@ -215,20 +279,29 @@ impl SemanticScholarProvider {
             )));
         }
 
-        let response_text = response
-            .text()
-            .await
-            .map_err(|e| ProviderError::Network(format!("Failed to read response: {e}")))?;
+        let response_text = read_response_text(response, context).await?;
 
         debug!("Semantic Scholar response: {}", response_text);
 
+        self.parse_search_response(&response_text)
+    }
+
+    /// Parse a paper search response into papers and the reported total
+    fn parse_search_response(
+        &self,
+        response_text: &str,
+    ) -> Result<(Vec<PaperMetadata>, Option<u32>), ProviderError> {
         let api_response: SemanticScholarResponse =
-            serde_json::from_str(&response_text).map_err(|e| {
+            serde_json::from_str(response_text).map_err(|e| {
                 warn!(
                     "Failed to parse Semantic Scholar response: {}",
                     response_text
                 );
-                ProviderError::Parse(format!("Failed to parse JSON: {e}"))
+                ProviderError::parse_error(
+                    self.name(),
+                    format!("Failed to parse JSON: {e}"),
+                    response_text,
+                )
             })?;
 
         let papers = api_response
@@ -237,11 +310,100 @@ impl SemanticScholarProvider {
             .map(|paper| self.convert_paper(paper))
             .collect();
 
+        Ok((papers, api_response.total))
+    }
+
+    /// Search the body text of papers. The snippet endpoint has no offset,
+    /// so enough snippets are fetched to cover it and the first ones skipped.
+    async fn search_full_text(
+        &self,
+        query: &str,
+        limit: u32,
+        offset: u32,
+        context: &SearchContext,
+    ) -> Result<(Vec<PaperMetadata>, Option<u32>), ProviderError> {
+        let url = self
+            .build_snippet_search_url(query, offset.saturating_add(limit).min(MAX_SNIPPET_RESULTS));
+        debug!("Searching Semantic Scholar full text: {}", url);
+
+        let mut request = with_context_headers(self.client.get(&url), context);
+        for (key, value) in self.get_headers() {
+            request = request.header(&key, &value);
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| ProviderError::Network(format!("Request failed: {e}")))?;
+
+        if !response.status().is_success() {
+            return Err(ProviderError::Network(format!(
+                "API request failed with status: {}",
+                response.status()
+            )));
+        }
+
+        let response_text = read_response_text(response, context).await?;
+
+        let papers = self
+            .parse_snippet_response(&response_text)?
+            .into_iter()
+            .skip(offset as usize)
+            .take(limit as usize)
+            .collect();
+        Ok((papers, None))
+    }
+
+    /// Parse a snippet search response into one paper per matching work
+    fn parse_snippet_response(
+        &self,
+        response_text: &str,
+    ) -> Result<Vec<PaperMetadata>, ProviderError> {
+        let api_response: SnippetSearchResponse =
+            serde_json::from_str(response_text).map_err(|e| {
+                ProviderError::parse_error(
+                    self.name(),
+                    format!("Failed to parse snippet JSON: {e}"),
+                    response_text,
+                )
+            })?;
+
+        // Several snippets can come from the same paper
+        let mut seen = std::collections::HashSet::new();
+        let mut papers = Vec::new();
+        for snippet in api_response.data {
+            let paper = snippet.paper;
+            let corpus_id = match paper.corpus_id {
+                Some(serde_json::Value::String(id)) => id,
+                Some(serde_json::Value::Number(id)) => id.to_string(),
+                _ => continue,
+            };
+            if !seen.insert(corpus_id.clone()) {
+                continue;
+            }
+
+            let mut metadata = PaperMetadata::new(format!("semantic_scholar:CorpusId:{corpus_id}"));
+            metadata.title = paper.title;
+            metadata.authors = paper
+                .authors
+                .into_iter()
+                .filter_map(|author| match author {
+                    SnippetAuthor::Name(name) => Some(name),
+                    SnippetAuthor::Author(author) => author.name,
+                })
+                .collect();
+            papers.push(metadata);
+        }
+
         Ok(papers)
     }
 
     /// Get paper by DOI
-    async fn get_paper_by_doi(&self, doi: &str) -> Result<Option<PaperMetadata>, ProviderError> {
+    async fn get_paper_by_doi(
+        &self,
+        doi: &str,
+        context: &SearchContext,
+    ) -> Result<Option<PaperMetadata>, ProviderError> {
         let fields = [
             "paperId",
             "externalIds",
@@ -259,7 +421,7 @@ impl SemanticScholarProvider {
         let url = self.build_doi_url(doi, &fields);
         debug!("Getting paper by DOI from Semantic Scholar: {}", url);
 
-        let mut request = self.client.get(&url);
+        let mut request = with_context_headers(self.client.get(&url), context);
 
         // Add API key header if available
         for (key, value) in self.get_headers() {
@@ -283,18 +445,83 @@ impl SemanticScholarProvider {
             )));
         }
 
-        let response_text = response
-            .text()
-            .await
-            .map_err(|e| ProviderError::Network(format!("Failed to read response: {e}")))?;
+        let response_text = read_response_text(response, context).await?;
 
         debug!("Semantic Scholar DOI response: {}", response_text);
 
-        let paper: SemanticScholarPaper = serde_json::from_str(&response_text)
-            .map_err(|e| ProviderError::Parse(format!("Failed to parse JSON: {e}")))?;
+        let paper: SemanticScholarPaper = serde_json::from_str(&response_text).map_err(|e| {
+            ProviderError::parse_error(
+                self.name(),
+                format!("Failed to parse JSON: {e}"),
+                &response_text,
+            )
+        })?;
 
         Ok(Some(self.convert_paper(paper)))
     }
+
+    /// Citation, influential-citation and reference counts for a DOI, or
+    /// `None` when Semantic Scholar does not know the paper
+    pub async fn get_signals(
+        &self,
+        doi: &str,
+        context: &SearchContext,
+    ) -> Result<Option<ProviderSignals>, ProviderError> {
+        #[derive(Debug, Deserialize)]
+        struct SignalsResponse {
+            #[serde(rename = "citationCount")]
+            citation_count: Option<u64>,
+            #[serde(rename = "influentialCitationCount")]
+            influential_citation_count: Option<u64>,
+            #[serde(rename = "referenceCount")]
+            reference_count: Option<u64>,
+        }
+
+        let url = self.build_doi_url(
+            doi,
+            &[
+                "citationCount",
+                "influentialCitationCount",
+                "referenceCount",
+            ],
+        );
+        debug!("Getting signals from Semantic Scholar: {}", url);
+
+        let mut request =
+            with_context_headers(self.client.get(&url), context).timeout(context.timeout);
+        for (key, value) in self.get_headers() {
+            request = request.header(&key, &value);
+        }
+        let response = request
+            .send()
+            .await
+            .map_err(|e| ProviderError::Network(format!("Request failed: {e}")))?;
+
+        match response.status().as_u16() {
+            404 => return Ok(None),
+            429 => return Err(ProviderError::RateLimit),
+            status if !response.status().is_success() => {
+                return Err(ProviderError::ServiceUnavailable(format!("HTTP {status}")))
+            }
+            _ => {}
+        }
+
+        let response_text = read_response_text(response, context).await?;
+        let signals: SignalsResponse = serde_json::from_str(&response_text).map_err(|e| {
+            ProviderError::parse_error(
+                self.name(),
+                format!("Failed to parse JSON: {e}"),
+                &response_text,
+            )
+        })?;
+
+        Ok(Some(ProviderSignals {
+            citation_count: signals.citation_count,
+            influential_citation_count: signals.influential_citation_count,
+            reference_count: signals.reference_count,
+            ..ProviderSignals::default()
+        }))
+    }
 }
 
 #[async_trait]
@@ -303,6 +530,10 @@ impl SourceProvider for SemanticScholarProvider {
         "semantic_scholar"
     }
 
+    fn base_url(&self) -> Option<&str> {
+        Some(self.base_url.as_str())
+    }
+
     fn description(&self) -> &'static str {
         "Semantic Scholar - AI-powered research tool with free PDF access"
     }
@@ -314,13 +545,18 @@ impl SourceProvider for SemanticScholarProvider {
             SearchType::Author,
             SearchType::Keywords,
             SearchType::Auto,
+            SearchType::FullText,
         ]
     }
 
-    fn supports_full_text(&self) -> bool {
+    fn provides_fulltext(&self) -> bool {
         true // Semantic Scholar provides open access PDFs when available
     }
 
+    fn tier(&self) -> ProviderTier {
+        ProviderTier::FullTextOA // Open access PDFs when available
+    }
+
     fn priority(&self) -> u8 {
         88 // High priority - between SSRN and CrossRef
     }
@@ -336,7 +572,7 @@ impl SourceProvider for SemanticScholarProvider {
     async fn search(
         &self,
         query: &SearchQuery,
-        _context: &SearchContext,
+        context: &SearchContext,
     ) -> Result<ProviderResult, ProviderError> {
         let start_time = Instant::now();
 
@@ -345,33 +581,39 @@ impl SourceProvider for SemanticScholarProvider {
             query.query, query.search_type
         );
 
-        let papers = match query.search_type {
+        let (papers, total) = match query.search_type {
             SearchType::Doi => {
                 // Try DOI lookup first
-                if let Some(paper) = self.get_paper_by_doi(&query.query).await? {
-                    vec![paper]
+                if let Some(paper) = self.get_paper_by_doi(&query.query, context).await? {
+                    (vec![paper], Some(1))
                 } else {
                     // Fallback to search if DOI lookup fails
-                    self.search_papers(&query.query, query.max_results, query.offset)
+                    self.search_papers(&query.query, query.max_results, query.offset, context)
                         .await?
                 }
             }
+            SearchType::FullText => {
+                self.search_full_text(&query.query, query.max_results, query.offset, context)
+                    .await?
+            }
             _ => {
                 // Use general search for all other types
-                self.search_papers(&query.query, query.max_results, query.offset)
+                self.search_papers(&query.query, query.max_results, query.offset, context)
                     .await?
             }
         };
 
         let search_time = start_time.elapsed();
-        let papers_count = papers.len();
+        let papers_count = u32::try_from(papers.len()).unwrap_or(u32::MAX);
 
         let result = ProviderResult {
             papers,
             source: "Semantic Scholar".to_string(),
-            total_available: Some(u32::try_from(papers_count).unwrap_or(u32::MAX)),
+            total_available: Some(total.unwrap_or(papers_count)),
             search_time,
-            has_more: papers_count >= query.max_results as usize,
+            has_more: total.map_or(papers_count >= query.max_results, |total| {
+                query.offset.saturating_add(papers_count) < total
+            }),
             metadata: HashMap::new(),
         };
 
@@ -387,18 +629,18 @@ impl SourceProvider for SemanticScholarProvider {
     async fn get_by_doi(
         &self,
         doi: &str,
-        _context: &SearchContext,
+        context: &SearchContext,
     ) -> Result<Option<PaperMetadata>, ProviderError> {
         info!("Getting paper by DOI from Semantic Scholar: {}", doi);
-        self.get_paper_by_doi(doi).await
+        self.get_paper_by_doi(doi, context).await
     }
 
-    async fn health_check(&self, _context: &SearchContext) -> Result<bool, ProviderError> {
+    async fn health_check(&self, context: &SearchContext) -> Result<bool, ProviderError> {
         debug!("Performing Semantic Scholar health check");
 
         let url = format!("{}/paper/search?query=test&limit=1", self.base_url);
 
-        let mut request = self.client.get(&url);
+        let mut request = with_context_headers(self.client.get(&url), context);
 
         // Add API key header if available
         for (key, value) in self.get_headers() {
@@ -458,8 +700,9 @@ mod tests {
         let provider = SemanticScholarProvider::new(None).unwrap();
 
         assert_eq!(provider.name(), "semantic_scholar");
-        assert!(provider.supports_full_text());
+        assert!(provider.provides_fulltext());
         assert_eq!(provider.priority(), 88);
+        assert_eq!(provider.tier(), ProviderTier::FullTextOA);
         assert!(provider.supported_search_types().contains(&SearchType::Doi));
     }
 
@@ -477,4 +720,86 @@ mod tests {
         assert!(doi_url.contains("DOI:10.1038%2Fnature12373"));
         assert!(doi_url.contains("fields=title"));
     }
+
+    #[test]
+    fn test_parse_search_response_reports_total() {
+        let provider = SemanticScholarProvider::new(None).unwrap();
+        let response = r#"{
+            "total": 1234,
+            "offset": 0,
+            "next": 2,
+            "data": [
+                {"paperId": "a", "title": "First", "externalIds": {"DOI": "10.1000/a"}},
+                {"paperId": "b", "title": "Second", "externalIds": {"DOI": "10.1000/b"}}
+            ]
+        }"#;
+
+        let (papers, total) = provider.parse_search_response(response).unwrap();
+        assert_eq!(papers.len(), 2);
+        assert_eq!(total, Some(1234));
+    }
+
+    #[tokio::test]
+    async fn test_configured_provider_headers_reach_server() {
+        let mock_server = wiremock::MockServer::start().await;
+        wiremock::Mock::given(wiremock::matchers::path("/paper/search"))
+            .and(wiremock::matchers::header("x-proxy-token", "secret-token"))
+            .respond_with(wiremock::ResponseTemplate::new(200).set_body_string(
+                r#"{"total": 1, "data": [{"paperId": "a", "title": "Benchmark", "externalIds": {"DOI": "10.1000/a"}}]}"#,
+            ))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let provider = SemanticScholarProvider::new(None)
+            .unwrap()
+            .with_base_url(mock_server.uri());
+        let client = crate::client::MetaSearchClient::with_providers(
+            vec![std::sync::Arc::new(provider)],
+            crate::client::MetaSearchConfig::default(),
+        )
+        .with_provider_headers(HashMap::from([(
+            "semantic_scholar".to_string(),
+            HashMap::from([("x-proxy-token".to_string(), "secret-token".to_string())]),
+        )]));
+        let query = SearchQuery {
+            query: "benchmark".to_string(),
+            search_type: SearchType::Keywords,
+            max_results: 5,
+            offset: 0,
+            params: HashMap::new(),
+        };
+
+        let result = client.search(&query).await.unwrap();
+
+        assert!(
+            result.provider_errors.is_empty(),
+            "{:?}",
+            result.provider_errors
+        );
+        assert_eq!(result.papers.len(), 1);
+        assert_eq!(result.papers[0].doi, "10.1000/a");
+    }
+
+    #[test]
+    fn test_parse_snippet_response_one_paper_per_work() {
+        let provider = SemanticScholarProvider::new(None).unwrap();
+        let response = r#"{
+            "data": [
+                {"score": 0.9, "snippet": {"text": "first match"},
+                 "paper": {"corpusId": "42", "title": "Body Text", "authors": ["Ada Lovelace"]}},
+                {"score": 0.8, "snippet": {"text": "second match"},
+                 "paper": {"corpusId": "42", "title": "Body Text", "authors": ["Ada Lovelace"]}},
+                {"score": 0.7, "snippet": {"text": "other paper"},
+                 "paper": {"corpusId": 7, "title": "Other", "authors": [{"name": "Alan Turing"}]}}
+            ]
+        }"#;
+
+        let papers = provider.parse_snippet_response(response).unwrap();
+        assert_eq!(papers.len(), 2);
+        assert_eq!(papers[0].doi, "semantic_scholar:CorpusId:42");
+        assert_eq!(papers[0].authors, vec!["Ada Lovelace".to_string()]);
+        assert_eq!(papers[1].doi, "semantic_scholar:CorpusId:7");
+        assert_eq!(papers[1].authors, vec!["Alan Turing".to_string()]);
+    }
 }
@@ -1,7 +1,9 @@
 use super::traits::{
-    ProviderError, ProviderResult, SearchContext, SearchQuery, SearchType, SourceProvider,
+    provider_http_client, read_response_text, with_context_headers, ProviderError, ProviderResult,
+    ProviderTier, SearchContext, SearchQuery, SearchType, SourceProvider,
 };
 use crate::client::PaperMetadata;
+use crate::config::HttpConfig;
 use async_trait::async_trait;
 use reqwest::Client;
 use scraper::{Html, Selector};
@@ -18,11 +20,15 @@ pub struct SsrnProvider {
 impl SsrnProvider {
     /// Create a new SSRN provider
     pub fn new() -> Result<Self, ProviderError> {
-        let client = Client::builder()
-            .timeout(Duration::from_secs(30))
-            .user_agent("knowledge_accumulator_mcp/0.2.1 (Academic Research Tool)")
-            .build()
-            .map_err(|e| ProviderError::Network(format!("Failed to create HTTP client: {e}")))?;
+        Self::with_http_config(&HttpConfig::default())
+    }
+
+    /// Create a new SSRN provider whose client follows the `[http]` settings
+    pub fn with_http_config(http: &HttpConfig) -> Result<Self, ProviderError> {
+        let client = provider_http_client(
+            http,
+            Some("knowledge_accumulator_mcp/0.2.1 (Academic Research Tool)"),
+        )?;
 
         Ok(Self {
             client,
@@ -55,13 +61,15 @@ impl SsrnProvider {
     }
 
     /// Fetch paper by SSRN ID
-    async fn fetch_by_id(&self, ssrn_id: &str) -> Result<Option<PaperMetadata>, ProviderError> {
+    async fn fetch_by_id(
+        &self,
+        ssrn_id: &str,
+        context: &SearchContext,
+    ) -> Result<Option<PaperMetadata>, ProviderError> {
         let url = self.build_paper_url(ssrn_id);
         debug!("Fetching SSRN paper from: {}", url);
 
-        let response = self
-            .client
-            .get(&url)
+        let response = with_context_headers(self.client.get(&url), context)
             .send()
             .await
             .map_err(|e| ProviderError::Network(format!("Request failed: {e}")))?;
@@ -70,10 +78,7 @@ impl SsrnProvider {
             return Ok(None);
         }
 
-        let html_content = response
-            .text()
-            .await
-            .map_err(|e| ProviderError::Network(format!("Failed to read response: {e}")))?;
+        let html_content = read_response_text(response, context).await?;
 
         self.parse_paper_page(&html_content, ssrn_id)
     }
@@ -173,11 +178,14 @@ impl SsrnProvider {
                 doi: format!("10.2139/ssrn.{ssrn_id}"),
                 title,
                 authors,
+                raw_authors: Vec::new(),
                 journal: Some("SSRN Electronic Journal".to_string()),
                 year,
                 abstract_text,
                 pdf_url,
                 file_size: None,
+                license: None,
+                is_open_access: None,
             }))
         } else {
             Ok(None)
@@ -189,13 +197,12 @@ impl SsrnProvider {
         &self,
         query: &str,
         limit: u32,
+        context: &SearchContext,
     ) -> Result<Vec<PaperMetadata>, ProviderError> {
         let url = self.build_search_url(query);
         debug!("Searching SSRN: {}", url);
 
-        let response = self
-            .client
-            .get(&url)
+        let response = with_context_headers(self.client.get(&url), context)
             .send()
             .await
             .map_err(|e| ProviderError::Network(format!("Search request failed: {e}")))?;
@@ -204,12 +211,9 @@ impl SsrnProvider {
             return Ok(Vec::new());
         }
 
-        let html_content = response
-            .text()
-            .await
-            .map_err(|e| ProviderError::Network(format!("Failed to read response: {e}")))?;
+        let html_content = read_response_text(response, context).await?;
 
-        self.parse_search_results(&html_content, limit as usize)
+        self.parse_search_results(&html_content, limit as usize, context)
             .await
     }
 
@@ -218,6 +222,7 @@ impl SsrnProvider {
         &self,
         html: &str,
         limit: usize,
+        context: &SearchContext,
     ) -> Result<Vec<PaperMetadata>, ProviderError> {
         // Parse HTML in a separate scope to ensure it's dropped before await
         let ssrn_ids = {
@@ -244,7 +249,7 @@ impl SsrnProvider {
         // Fetch details for each paper
         let mut papers = Vec::new();
         for ssrn_id in ssrn_ids.iter().take(limit) {
-            if let Ok(Some(paper)) = self.fetch_by_id(ssrn_id).await {
+            if let Ok(Some(paper)) = self.fetch_by_id(ssrn_id, context).await {
                 papers.push(paper);
             }
         }
@@ -259,6 +264,10 @@ impl SourceProvider for SsrnProvider {
         "ssrn"
     }
 
+    fn base_url(&self) -> Option<&str> {
+        Some(self.base_url.as_str())
+    }
+
     fn description(&self) -> &'static str {
         "SSRN - Social Science Research Network (Free preprints)"
     }
@@ -273,10 +282,14 @@ impl SourceProvider for SsrnProvider {
         ]
     }
 
-    fn supports_full_text(&self) -> bool {
+    fn provides_fulltext(&self) -> bool {
         true // SSRN usually provides free PDFs
     }
 
+    fn tier(&self) -> ProviderTier {
+        ProviderTier::FullTextOA // Free working papers and preprints
+    }
+
     fn priority(&self) -> u8 {
         85 // High priority for recent papers and preprints
     }
@@ -288,7 +301,7 @@ impl SourceProvider for SsrnProvider {
     async fn search(
         &self,
         query: &SearchQuery,
-        _context: &SearchContext,
+        context: &SearchContext,
     ) -> Result<ProviderResult, ProviderError> {
         let start_time = Instant::now();
 
@@ -301,19 +314,21 @@ impl SourceProvider for SsrnProvider {
             SearchType::Doi => {
                 // Check if it's an SSRN DOI
                 if let Some(ssrn_id) = self.extract_ssrn_id(&query.query) {
-                    if let Some(paper) = self.fetch_by_id(&ssrn_id).await? {
+                    if let Some(paper) = self.fetch_by_id(&ssrn_id, context).await? {
                         vec![paper]
                     } else {
                         Vec::new()
                     }
                 } else {
                     // Not an SSRN DOI, search by title
-                    self.search_papers(&query.query, query.max_results).await?
+                    self.search_papers(&query.query, query.max_results, context)
+                        .await?
                 }
             }
             _ => {
                 // For all other search types, use the general search
-                self.search_papers(&query.query, query.max_results).await?
+                self.search_papers(&query.query, query.max_results, context)
+                    .await?
             }
         };
 
@@ -341,21 +356,24 @@ impl SourceProvider for SsrnProvider {
     async fn get_by_doi(
         &self,
         doi: &str,
-        _context: &SearchContext,
+        context: &SearchContext,
     ) -> Result<Option<PaperMetadata>, ProviderError> {
         info!("Getting paper by DOI from SSRN: {}", doi);
 
         if let Some(ssrn_id) = self.extract_ssrn_id(doi) {
-            self.fetch_by_id(&ssrn_id).await
+            self.fetch_by_id(&ssrn_id, context).await
         } else {
             Ok(None)
         }
     }
 
-    async fn health_check(&self, _context: &SearchContext) -> Result<bool, ProviderError> {
+    async fn health_check(&self, context: &SearchContext) -> Result<bool, ProviderError> {
         debug!("Performing SSRN health check");
 
-        match self.client.get(&self.base_url).send().await {
+        match with_context_headers(self.client.get(&self.base_url), context)
+            .send()
+            .await
+        {
             Ok(response) if response.status().is_success() => {
                 info!("SSRN health check: OK");
                 Ok(true)
@@ -408,8 +426,9 @@ mod tests {
         let provider = SsrnProvider::new().unwrap();
 
         assert_eq!(provider.name(), "ssrn");
-        assert!(provider.supports_full_text());
+        assert!(provider.provides_fulltext());
         assert_eq!(provider.priority(), 85);
+        assert_eq!(provider.tier(), ProviderTier::FullTextOA);
         assert!(provider.supported_search_types().contains(&SearchType::Doi));
     }
 }
@@ -17,7 +17,7 @@
 //!
 //! ```no_run
 //! use async_trait::async_trait;
-//! use knowledge_accumulator_mcp::client::providers::{SourceProvider, SearchQuery, SearchContext, ProviderResult, ProviderError, SearchType};
+//! use knowledge_accumulator_mcp::client::providers::{SourceProvider, SearchQuery, SearchContext, ProviderResult, ProviderError, ProviderTier, SearchType};
 //! use std::time::Duration;
 //!
 //! struct MyProvider {
@@ -27,7 +27,7 @@
 //! #[async_trait]
 //! impl SourceProvider for MyProvider {
 //!     fn name(&self) -> &'static str { "my_provider" }
-//!     fn priority(&self) -> u8 { 50 }
+//!     fn tier(&self) -> ProviderTier { ProviderTier::Metadata }
 //!     fn base_delay(&self) -> Duration { Duration::from_millis(500) }
 //!
 //!     fn supported_search_types(&self) -> Vec<SearchType> {
@@ -46,7 +46,8 @@
 //! }
 //! ```
 
-use crate::client::PaperMetadata;
+use crate::client::{http_client_builder, PaperMetadata};
+use crate::config::HttpConfig;
 use async_trait::async_trait;
 use std::collections::HashMap;
 use std::time::Duration;
@@ -71,6 +72,23 @@ pub struct SearchQuery {
     pub params: HashMap<String, String>,
 }
 
+/// `SearchQuery::params` key listing terms that must all match, as a JSON
+/// array.
+///
+/// Set for parsed boolean queries so providers with their own boolean syntax
+/// can use it; other providers search the plain `query`.
+pub const ALL_TERMS_PARAM: &str = "all_terms";
+
+impl SearchQuery {
+    /// Terms from the [`ALL_TERMS_PARAM`] parameter, if it is set
+    #[must_use]
+    pub fn all_terms(&self) -> Option<Vec<String>> {
+        self.params
+            .get(ALL_TERMS_PARAM)
+            .and_then(|terms| serde_json::from_str(terms).ok())
+    }
+}
+
 /// Specifies the type of search to perform across academic sources.
 ///
 /// Different providers may support different search types. The meta-search client
@@ -83,7 +101,8 @@ pub struct SearchQuery {
 /// - **ArXiv**: Title, Author, Keywords, Subject
 /// - **CrossRef**: DOI, Title, Author
 /// - **PubMed**: Title, Author, Keywords, Subject
-/// - **Semantic Scholar**: Title, Author, Keywords
+/// - **Semantic Scholar**: Title, Author, Keywords, FullText
+/// - **CORE**: Title, Author, Keywords, FullText
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum SearchType {
     /// Automatic detection based on query characteristics.
@@ -121,6 +140,55 @@ pub enum SearchType {
     /// Example: "cs.AI" (Computer Science - Artificial Intelligence)
     /// Uses provider-specific subject taxonomies.
     Subject,
+
+    /// Search within the body text of papers rather than their metadata.
+    ///
+    /// Example: "we fine-tune on the held-out split"
+    /// Only sent to providers that list it in their supported search types;
+    /// declaring `Auto` is not enough.
+    FullText,
+}
+
+/// How far a provider's metadata and PDFs can be relied on.
+///
+/// A provider's default priority comes from its tier. Providers override
+/// [`SourceProvider::priority`] to order themselves within their tier, and
+/// equal priorities fall back to tier and name; see [`provider_order`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ProviderTier {
+    /// Registries and indexes of record, e.g. CrossRef and OpenAlex
+    Authoritative,
+    /// Legal open access full text: preprint servers, repositories and
+    /// open access publishers
+    FullTextOA,
+    /// Metadata without dependable full text
+    Metadata,
+    /// Full text of uncertain legality or availability, used as a last
+    /// resort (ResearchGate, Sci-Hub)
+    FullTextGray,
+}
+
+impl ProviderTier {
+    /// Default priority of providers in this tier
+    #[must_use]
+    pub const fn priority(self) -> u8 {
+        match self {
+            Self::Authoritative => 90,
+            Self::FullTextOA => 80,
+            Self::Metadata => 50,
+            Self::FullTextGray => 10,
+        }
+    }
+}
+
+/// Order in which providers are asked: highest priority first, then by tier,
+/// then by name. Providers that keep their tier's priority therefore sort
+/// by tier and name.
+pub fn provider_order(a: &dyn SourceProvider, b: &dyn SourceProvider) -> std::cmp::Ordering {
+    b.priority()
+        .cmp(&a.priority())
+        .then_with(|| a.tier().cmp(&b.tier()))
+        .then_with(|| a.name().cmp(b.name()))
 }
 
 /// Context for search operations
@@ -134,6 +202,12 @@ pub struct SearchContext {
     pub rate_limit: Option<Duration>,
     /// Additional headers
     pub headers: HashMap<String, String>,
+    /// Largest response body a provider reads before giving up
+    /// (`None` = unlimited)
+    pub max_response_bytes: Option<u64>,
+    /// Charset for response bodies that declare none and aren't valid UTF-8
+    /// (`None` = replace undecodable bytes)
+    pub default_charset: Option<&'static encoding_rs::Encoding>,
 }
 
 /// Result from a source provider
@@ -153,6 +227,22 @@ pub struct ProviderResult {
     pub metadata: HashMap<String, String>,
 }
 
+/// Citation and open access signals a provider reports for one paper.
+/// Signals the provider does not know are `None`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProviderSignals {
+    /// Number of papers citing this one
+    pub citation_count: Option<u64>,
+    /// Citations the provider classifies as influential
+    pub influential_citation_count: Option<u64>,
+    /// Number of works this paper references
+    pub reference_count: Option<u64>,
+    /// Whether a free, legal copy exists
+    pub is_open_access: Option<bool>,
+    /// Open access route (gold, green, hybrid, bronze, closed)
+    pub oa_status: Option<String>,
+}
+
 /// Errors that can occur during provider operations
 #[derive(Debug, Error)]
 pub enum ProviderError {
@@ -162,6 +252,15 @@ pub enum ProviderError {
     #[error("Parse error: {0}")]
     Parse(String),
 
+    /// A response that could not be parsed, with the start of its body so a
+    /// change in the provider's response format can be spotted
+    #[error("Parse error from {provider}: {context} (response: {snippet})")]
+    ParseError {
+        provider: String,
+        context: String,
+        snippet: String,
+    },
+
     #[error("Rate limit exceeded")]
     RateLimit,
 
@@ -177,10 +276,125 @@ pub enum ProviderError {
     #[error("Timeout occurred")]
     Timeout,
 
+    #[error("Search cancelled")]
+    Cancelled,
+
+    #[error("Response exceeded the {limit}-byte size limit")]
+    ResponseTooLarge { limit: u64 },
+
     #[error("Provider error: {0}")]
     Other(String),
 }
 
+/// Characters of a response body kept in [`ProviderError::ParseError`]
+const PARSE_ERROR_SNIPPET_CHARS: usize = 200;
+
+impl ProviderError {
+    /// Parse failure for `provider`'s response `body`, keeping a truncated snippet of it
+    pub fn parse_error(provider: &str, context: impl Into<String>, body: &str) -> Self {
+        let body = body.trim();
+        let mut snippet: String = body.chars().take(PARSE_ERROR_SNIPPET_CHARS).collect();
+        if snippet.len() < body.len() {
+            snippet.push('…');
+        }
+        Self::ParseError {
+            provider: provider.to_string(),
+            context: context.into(),
+            snippet,
+        }
+    }
+}
+
+/// Build a provider's HTTP client from the `[http]` settings, with the
+/// standard 30 second request timeout and the provider's user agent (`None`
+/// for providers that set one per request).
+pub fn provider_http_client(
+    http: &HttpConfig,
+    user_agent: Option<&str>,
+) -> Result<reqwest::Client, ProviderError> {
+    let mut builder = http_client_builder(http).timeout(Duration::from_secs(30));
+    if let Some(user_agent) = user_agent {
+        builder = builder.user_agent(user_agent);
+    }
+    builder
+        .build()
+        .map_err(|e| ProviderError::Network(format!("Failed to create HTTP client: {e}")))
+}
+
+/// Add the per-request headers of `context`, such as the provider's
+/// `http.provider_headers`, to `request`
+pub fn with_context_headers(
+    request: reqwest::RequestBuilder,
+    context: &SearchContext,
+) -> reqwest::RequestBuilder {
+    context
+        .headers
+        .iter()
+        .fold(request, |request, (key, value)| request.header(key, value))
+}
+
+/// Read a provider response body as text, aborting with
+/// [`ProviderError::ResponseTooLarge`] as soon as it grows past
+/// `context.max_response_bytes`.
+///
+/// A declared `Content-Length` over the limit is refused before anything is
+/// read.
+///
+/// The body is decoded with the charset named in its `Content-Type`; without
+/// one it is read as UTF-8, falling back to `context.default_charset` when it
+/// isn't valid UTF-8.
+pub async fn read_response_text(
+    mut response: reqwest::Response,
+    context: &SearchContext,
+) -> Result<String, ProviderError> {
+    let limit = context.max_response_bytes;
+    if let (Some(limit), Some(length)) = (limit, response.content_length()) {
+        if length > limit {
+            return Err(ProviderError::ResponseTooLarge { limit });
+        }
+    }
+    let declared_charset = response
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(content_type_charset);
+
+    let mut body = Vec::new();
+    while let Some(chunk) = response
+        .chunk()
+        .await
+        .map_err(|e| ProviderError::Network(format!("Failed to read response: {e}")))?
+    {
+        body.extend_from_slice(&chunk);
+        if let Some(limit) = limit.filter(|&limit| body.len() as u64 > limit) {
+            return Err(ProviderError::ResponseTooLarge { limit });
+        }
+    }
+
+    let encoding = match declared_charset {
+        Some(encoding) => encoding,
+        None => match String::from_utf8(body) {
+            Ok(text) => return Ok(text),
+            Err(e) => {
+                body = e.into_bytes();
+                context.default_charset.unwrap_or(encoding_rs::UTF_8)
+            }
+        },
+    };
+    Ok(encoding.decode(&body).0.into_owned())
+}
+
+/// Encoding named by the `charset` parameter of a `Content-Type` value
+fn content_type_charset(content_type: &str) -> Option<&'static encoding_rs::Encoding> {
+    content_type.split(';').skip(1).find_map(|param| {
+        let (name, value) = param.split_once('=')?;
+        if !name.trim().eq_ignore_ascii_case("charset") {
+            return None;
+        }
+        encoding_rs::Encoding::for_label(value.trim().trim_matches('"').as_bytes())
+    })
+}
+
 /// Core trait for academic source providers.
 ///
 /// This trait defines the interface that all academic source providers must implement
@@ -208,7 +422,7 @@ pub enum ProviderError {
 ///
 /// ```no_run
 /// use async_trait::async_trait;
-/// use knowledge_accumulator_mcp::client::providers::{SourceProvider, SearchQuery, SearchContext, ProviderResult, ProviderError, SearchType};
+/// use knowledge_accumulator_mcp::client::providers::{SourceProvider, SearchQuery, SearchContext, ProviderResult, ProviderError, ProviderTier, SearchType};
 /// use std::time::Duration;
 ///
 /// struct ExampleProvider {
@@ -219,7 +433,7 @@ pub enum ProviderError {
 /// #[async_trait]
 /// impl SourceProvider for ExampleProvider {
 ///     fn name(&self) -> &'static str { "example" }
-///     fn priority(&self) -> u8 { 50 }
+///     fn tier(&self) -> ProviderTier { ProviderTier::Metadata }
 ///     fn base_delay(&self) -> Duration { Duration::from_millis(500) }
 ///
 ///     fn supported_search_types(&self) -> Vec<SearchType> {
@@ -276,20 +490,22 @@ pub trait SourceProvider: Send + Sync {
     /// - "pubmed" for PubMed Central
     fn name(&self) -> &'static str;
 
+    /// Returns the tier this provider belongs to.
+    ///
+    /// The tier sets the provider's default priority and orders it among
+    /// providers of equal priority.
+    fn tier(&self) -> ProviderTier {
+        ProviderTier::Metadata
+    }
+
     /// Returns the priority level for this provider (0-255, higher = more priority).
     ///
     /// The meta-search client uses this to determine the order in which providers
     /// are queried. Higher priority providers are queried first and may influence
-    /// result ranking.
-    ///
-    /// # Priority Guidelines
-    /// - 200+: Authoritative sources (CrossRef, official databases)
-    /// - 150-199: High-quality aggregators (Semantic Scholar)
-    /// - 100-149: Specialized repositories (ArXiv, PubMed)
-    /// - 50-99: General search engines
-    /// - 0-49: Fallback sources
+    /// result ranking. Defaults to the priority of the provider's tier; override
+    /// it to order the provider among the others in its tier.
     fn priority(&self) -> u8 {
-        50 // Default medium priority
+        self.tier().priority()
     }
 
     /// Returns the base delay between requests to this provider.
@@ -300,6 +516,25 @@ pub trait SourceProvider: Send + Sync {
         Duration::from_millis(1000) // Default 1 second
     }
 
+    /// Returns the fixed API base URL this provider sends requests to, if any.
+    ///
+    /// Providers that rotate between mirrors return `None`.
+    fn base_url(&self) -> Option<&str> {
+        None
+    }
+
+    /// Returns the host this provider sends requests to, if it has a fixed one.
+    ///
+    /// The meta-search client uses this to apply per-host concurrency caps, so
+    /// providers that share a host also share its limit. Derived from
+    /// [`SourceProvider::base_url`]; providers without one are not limited per host.
+    fn host(&self) -> Option<String> {
+        url::Url::parse(self.base_url()?)
+            .ok()?
+            .host_str()
+            .map(str::to_string)
+    }
+
     /// Returns the search types supported by this provider.
     ///
     /// The meta-search client uses this information to route queries to appropriate
@@ -314,11 +549,33 @@ pub trait SourceProvider: Send + Sync {
         "Academic source provider"
     }
 
-    /// Returns whether this provider supports full-text PDF access.
+    /// Returns whether this provider yields PDFs, as opposed to metadata only.
     ///
-    /// Used by the meta-search client to prioritize providers when PDF access
-    /// is specifically requested or needed.
+    /// Unrelated to [`SearchType::FullText`], which searches inside paper
+    /// text. The meta-search client boosts these providers when a query asks
+    /// for PDFs, and reports the flag in
+    /// [`MetaSearchClient::provider_capabilities`](crate::client::MetaSearchClient::provider_capabilities).
+    fn provides_fulltext(&self) -> bool {
+        false
+    }
+
+    /// Returns whether this provider supports full-text PDF access.
+    #[deprecated(note = "use `provides_fulltext`")]
     fn supports_full_text(&self) -> bool {
+        self.provides_fulltext()
+    }
+
+    /// Returns whether this provider's results can include abstracts.
+    ///
+    /// Providers that never return abstracts are queried last by searches
+    /// that require them.
+    fn provides_abstracts(&self) -> bool {
+        true
+    }
+
+    /// Returns whether this provider reports author names in MEDLINE style
+    /// ("Smith JA"), so a trailing run of capitals is always initials.
+    fn medline_author_names(&self) -> bool {
         false
     }
 
@@ -406,3 +663,240 @@ pub trait SourceProvider: Send + Sync {
         Ok(result.and_then(|paper| paper.pdf_url))
     }
 }
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    /// How a [`MockProvider`] answers a search
+    type Answer = dyn Fn(&SearchQuery, &SearchContext) -> Result<Vec<PaperMetadata>, ProviderError>
+        + Send
+        + Sync;
+
+    /// Configurable provider for tests across the crate. Takes any search
+    /// type, answers without delay and counts its searches and DOI lookups.
+    pub struct MockProvider {
+        name: &'static str,
+        tier: ProviderTier,
+        priority: Option<u8>,
+        search_types: Vec<SearchType>,
+        provides_fulltext: bool,
+        healthy: Option<bool>,
+        delay: Duration,
+        answer: Box<Answer>,
+        /// Searches started, including the ones DOI lookups don't make
+        pub searches: AtomicUsize,
+        /// Calls to `get_by_doi`
+        pub lookups: AtomicUsize,
+    }
+
+    impl MockProvider {
+        /// Provider named `name` answering every search with no papers
+        pub fn new(name: &'static str) -> Self {
+            Self {
+                name,
+                tier: ProviderTier::Metadata,
+                priority: None,
+                search_types: vec![SearchType::Auto],
+                provides_fulltext: false,
+                healthy: None,
+                delay: Duration::ZERO,
+                answer: Box::new(|_, _| Ok(Vec::new())),
+                searches: AtomicUsize::new(0),
+                lookups: AtomicUsize::new(0),
+            }
+        }
+
+        /// Answer every search with `papers`
+        pub fn with_papers(self, papers: Vec<PaperMetadata>) -> Self {
+            self.answering(move |_, _| Ok(papers.clone()))
+        }
+
+        /// Answer each search, and DOI lookup, with what `answer` returns
+        pub fn answering(
+            mut self,
+            answer: impl Fn(&SearchQuery, &SearchContext) -> Result<Vec<PaperMetadata>, ProviderError>
+                + Send
+                + Sync
+                + 'static,
+        ) -> Self {
+            self.answer = Box::new(answer);
+            self
+        }
+
+        /// Fail every search as unavailable
+        pub fn failing(self) -> Self {
+            let name = self.name;
+            self.answering(move |_, _| {
+                Err(ProviderError::ServiceUnavailable(format!("{name} is down")))
+            })
+        }
+
+        pub const fn with_tier(mut self, tier: ProviderTier) -> Self {
+            self.tier = tier;
+            self
+        }
+
+        pub const fn with_priority(mut self, priority: u8) -> Self {
+            self.priority = Some(priority);
+            self
+        }
+
+        pub fn with_search_types(mut self, search_types: Vec<SearchType>) -> Self {
+            self.search_types = search_types;
+            self
+        }
+
+        pub const fn with_fulltext(mut self, provides_fulltext: bool) -> Self {
+            self.provides_fulltext = provides_fulltext;
+            self
+        }
+
+        /// Report this health check outcome instead of probing with a search
+        pub const fn with_health(mut self, healthy: bool) -> Self {
+            self.healthy = Some(healthy);
+            self
+        }
+
+        /// Wait this long before answering each search
+        pub const fn with_delay(mut self, delay: Duration) -> Self {
+            self.delay = delay;
+            self
+        }
+
+        async fn answer(
+            &self,
+            query: &SearchQuery,
+            context: &SearchContext,
+        ) -> Result<Vec<PaperMetadata>, ProviderError> {
+            tokio::time::sleep(self.delay).await;
+            (self.answer)(query, context)
+        }
+    }
+
+    /// A paper with a DOI and title
+    pub fn paper(doi: &str, title: &str) -> PaperMetadata {
+        let mut paper = PaperMetadata::new(doi.to_string());
+        paper.title = Some(title.to_string());
+        paper
+    }
+
+    #[async_trait]
+    impl SourceProvider for MockProvider {
+        fn name(&self) -> &'static str {
+            self.name
+        }
+
+        fn tier(&self) -> ProviderTier {
+            self.tier
+        }
+
+        fn priority(&self) -> u8 {
+            self.priority.unwrap_or_else(|| self.tier.priority())
+        }
+
+        fn base_delay(&self) -> Duration {
+            Duration::ZERO
+        }
+
+        fn supported_search_types(&self) -> Vec<SearchType> {
+            self.search_types.clone()
+        }
+
+        fn provides_fulltext(&self) -> bool {
+            self.provides_fulltext
+        }
+
+        async fn search(
+            &self,
+            query: &SearchQuery,
+            context: &SearchContext,
+        ) -> Result<ProviderResult, ProviderError> {
+            self.searches.fetch_add(1, Ordering::SeqCst);
+            Ok(ProviderResult {
+                papers: self.answer(query, context).await?,
+                source: self.name.to_string(),
+                total_available: None,
+                search_time: self.delay,
+                has_more: false,
+                metadata: HashMap::new(),
+            })
+        }
+
+        async fn get_by_doi(
+            &self,
+            doi: &str,
+            context: &SearchContext,
+        ) -> Result<Option<PaperMetadata>, ProviderError> {
+            self.lookups.fetch_add(1, Ordering::SeqCst);
+            let query = SearchQuery {
+                query: doi.to_string(),
+                search_type: SearchType::Doi,
+                max_results: 1,
+                offset: 0,
+                params: HashMap::new(),
+            };
+            Ok(self.answer(&query, context).await?.into_iter().next())
+        }
+
+        async fn health_check(&self, context: &SearchContext) -> Result<bool, ProviderError> {
+            if let Some(healthy) = self.healthy {
+                return Ok(healthy);
+            }
+            let query = SearchQuery {
+                query: "test".to_string(),
+                search_type: SearchType::Keywords,
+                max_results: 1,
+                offset: 0,
+                params: HashMap::new(),
+            };
+            Ok(self.answer(&query, context).await.is_ok())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_read_response_text_aborts_stream_over_limit() {
+        // Streams 1 KB chunks without a Content-Length until the client hangs up
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut request = [0u8; 1024];
+            let _ = socket.read(&mut request).await;
+            socket
+                .write_all(b"HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nTransfer-Encoding: chunked\r\n\r\n")
+                .await
+                .unwrap();
+            let chunk = format!("400\r\n{}\r\n", "x".repeat(0x400));
+            while socket.write_all(chunk.as_bytes()).await.is_ok() {
+                tokio::time::sleep(Duration::from_millis(1)).await;
+            }
+        });
+
+        let context = SearchContext {
+            timeout: Duration::from_secs(10),
+            user_agent: "test".to_string(),
+            rate_limit: None,
+            headers: HashMap::new(),
+            max_response_bytes: Some(64 * 1024),
+            default_charset: None,
+        };
+        let response = reqwest::get(format!("http://{address}/works"))
+            .await
+            .unwrap();
+        let result = tokio::time::timeout(
+            Duration::from_secs(10),
+            read_response_text(response, &context),
+        )
+        .await
+        .expect("read should be aborted, not run forever");
+
+        assert!(matches!(
+            result,
+            Err(ProviderError::ResponseTooLarge { limit }) if limit == 64 * 1024
+        ));
+        server.abort();
+    }
+}
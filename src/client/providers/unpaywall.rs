@@ -1,7 +1,9 @@
 use super::traits::{
-    ProviderError, ProviderResult, SearchContext, SearchQuery, SearchType, SourceProvider,
+    provider_http_client, read_response_text, with_context_headers, ProviderError, ProviderResult,
+    ProviderSignals, ProviderTier, SearchContext, SearchQuery, SearchType, SourceProvider,
 };
 use crate::client::PaperMetadata;
+use crate::config::HttpConfig;
 use async_trait::async_trait;
 use reqwest::Client;
 use serde::Deserialize;
@@ -47,7 +49,6 @@ struct OALocation {
     has_repository_copy: Option<bool>,
     #[allow(dead_code)]
     is_best: Option<bool>,
-    #[allow(dead_code)]
     license: Option<String>,
     #[allow(dead_code)]
     oa_date: Option<String>,
@@ -73,21 +74,28 @@ pub struct UnpaywallProvider {
     email: String,
 }
 
+/// Contact address sent to Unpaywall when none is configured
+pub const DEFAULT_EMAIL: &str = "knowledge_accumulator_mcp@academic-tool.org";
+
 impl UnpaywallProvider {
     /// Create a new Unpaywall provider
     /// Requires an email address as per Unpaywall API terms
     pub fn new(email: String) -> Result<Self, ProviderError> {
+        Self::with_http_config(email, &HttpConfig::default())
+    }
+
+    /// Create a new Unpaywall provider whose client follows the `[http]` settings
+    pub fn with_http_config(email: String, http: &HttpConfig) -> Result<Self, ProviderError> {
         if email.is_empty() || !email.contains('@') {
             return Err(ProviderError::Auth(
                 "Valid email address required for Unpaywall API".to_string(),
             ));
         }
 
-        let client = Client::builder()
-            .timeout(Duration::from_secs(30))
-            .user_agent("knowledge_accumulator_mcp/0.2.1 (Academic Research Tool)")
-            .build()
-            .map_err(|e| ProviderError::Network(format!("Failed to create HTTP client: {e}")))?;
+        let client = provider_http_client(
+            http,
+            Some("knowledge_accumulator_mcp/0.2.1 (Academic Research Tool)"),
+        )?;
 
         Ok(Self {
             client,
@@ -99,7 +107,14 @@ impl UnpaywallProvider {
     /// Create a new Unpaywall provider with default email
     pub fn new_with_default_email() -> Result<Self, ProviderError> {
         // Use a default email for testing - in production this should come from config
-        Self::new("knowledge_accumulator_mcp@academic-tool.org".to_string())
+        Self::new(DEFAULT_EMAIL.to_string())
+    }
+
+    /// Query `base_url` instead of the public API
+    #[must_use]
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
     }
 
     /// Build Unpaywall DOI lookup URL
@@ -148,26 +163,43 @@ impl UnpaywallProvider {
             })
             .filter(|url| !url.is_empty());
 
+        let license = response
+            .best_oa_location
+            .as_ref()
+            .and_then(|loc| loc.license.clone())
+            .or_else(|| {
+                response
+                    .oa_locations
+                    .as_ref()
+                    .and_then(|locations| locations.iter().find_map(|loc| loc.license.clone()))
+            })
+            .filter(|license| !license.is_empty());
+
         PaperMetadata {
             doi: response.doi,
             title: response.title,
             authors,
+            raw_authors: Vec::new(),
             journal: response.journal_name,
             year: response.year,
             abstract_text: None, // Unpaywall doesn't provide abstracts
             pdf_url,
             file_size: None,
+            license,
+            is_open_access: Some(response.is_open_access),
         }
     }
 
     /// Get paper by DOI from Unpaywall
-    async fn get_paper_by_doi(&self, doi: &str) -> Result<Option<PaperMetadata>, ProviderError> {
+    async fn get_paper_by_doi(
+        &self,
+        doi: &str,
+        context: &SearchContext,
+    ) -> Result<Option<PaperMetadata>, ProviderError> {
         let url = self.build_doi_url(doi);
         debug!("Getting paper by DOI from Unpaywall: {}", url);
 
-        let response = self
-            .client
-            .get(&url)
+        let response = with_context_headers(self.client.get(&url), context)
             .send()
             .await
             .map_err(|e| ProviderError::Network(format!("Request failed: {e}")))?;
@@ -184,17 +216,18 @@ impl UnpaywallProvider {
             )));
         }
 
-        let response_text = response
-            .text()
-            .await
-            .map_err(|e| ProviderError::Network(format!("Failed to read response: {e}")))?;
+        let response_text = read_response_text(response, context).await?;
 
         debug!("Unpaywall response: {}", response_text);
 
         let unpaywall_response: UnpaywallResponse =
             serde_json::from_str(&response_text).map_err(|e| {
                 warn!("Failed to parse Unpaywall response: {}", response_text);
-                ProviderError::Parse(format!("Failed to parse JSON: {e}"))
+                ProviderError::parse_error(
+                    self.name(),
+                    format!("Failed to parse JSON: {e}"),
+                    &response_text,
+                )
             })?;
 
         // Only return papers that have open access
@@ -205,6 +238,53 @@ impl UnpaywallProvider {
             Ok(None)
         }
     }
+
+    /// Open access status for a DOI, or `None` when Unpaywall does not know
+    /// the paper
+    pub async fn get_signals(
+        &self,
+        doi: &str,
+        context: &SearchContext,
+    ) -> Result<Option<ProviderSignals>, ProviderError> {
+        #[derive(Debug, Deserialize)]
+        struct SignalsResponse {
+            is_oa: Option<bool>,
+            oa_status: Option<String>,
+        }
+
+        let url = self.build_doi_url(doi);
+        debug!("Getting signals from Unpaywall: {}", url);
+
+        let response = with_context_headers(self.client.get(&url), context)
+            .timeout(context.timeout)
+            .send()
+            .await
+            .map_err(|e| ProviderError::Network(format!("Request failed: {e}")))?;
+
+        match response.status().as_u16() {
+            404 => return Ok(None),
+            429 => return Err(ProviderError::RateLimit),
+            status if !response.status().is_success() => {
+                return Err(ProviderError::ServiceUnavailable(format!("HTTP {status}")))
+            }
+            _ => {}
+        }
+
+        let response_text = read_response_text(response, context).await?;
+        let signals: SignalsResponse = serde_json::from_str(&response_text).map_err(|e| {
+            ProviderError::parse_error(
+                self.name(),
+                format!("Failed to parse JSON: {e}"),
+                &response_text,
+            )
+        })?;
+
+        Ok(Some(ProviderSignals {
+            is_open_access: signals.is_oa,
+            oa_status: signals.oa_status,
+            ..ProviderSignals::default()
+        }))
+    }
 }
 
 #[async_trait]
@@ -213,6 +293,10 @@ impl SourceProvider for UnpaywallProvider {
         "unpaywall"
     }
 
+    fn base_url(&self) -> Option<&str> {
+        Some(self.base_url.as_str())
+    }
+
     fn description(&self) -> &'static str {
         "Unpaywall - Database of open access research papers"
     }
@@ -221,10 +305,18 @@ impl SourceProvider for UnpaywallProvider {
         vec![SearchType::Doi] // Unpaywall only supports DOI lookups
     }
 
-    fn supports_full_text(&self) -> bool {
+    fn provides_fulltext(&self) -> bool {
         true // Unpaywall specifically finds open access PDFs
     }
 
+    fn provides_abstracts(&self) -> bool {
+        false
+    }
+
+    fn tier(&self) -> ProviderTier {
+        ProviderTier::FullTextOA // Legal free versions of paywalled papers
+    }
+
     fn priority(&self) -> u8 {
         87 // High priority for finding legal free versions
     }
@@ -236,7 +328,7 @@ impl SourceProvider for UnpaywallProvider {
     async fn search(
         &self,
         query: &SearchQuery,
-        _context: &SearchContext,
+        context: &SearchContext,
     ) -> Result<ProviderResult, ProviderError> {
         let start_time = Instant::now();
 
@@ -247,7 +339,7 @@ impl SourceProvider for UnpaywallProvider {
 
         let papers = if query.search_type == SearchType::Doi {
             // Unpaywall only supports DOI lookups
-            if let Some(paper) = self.get_paper_by_doi(&query.query).await? {
+            if let Some(paper) = self.get_paper_by_doi(&query.query, context).await? {
                 vec![paper]
             } else {
                 Vec::new()
@@ -285,19 +377,22 @@ impl SourceProvider for UnpaywallProvider {
     async fn get_by_doi(
         &self,
         doi: &str,
-        _context: &SearchContext,
+        context: &SearchContext,
     ) -> Result<Option<PaperMetadata>, ProviderError> {
         info!("Getting paper by DOI from Unpaywall: {}", doi);
-        self.get_paper_by_doi(doi).await
+        self.get_paper_by_doi(doi, context).await
     }
 
-    async fn health_check(&self, _context: &SearchContext) -> Result<bool, ProviderError> {
+    async fn health_check(&self, context: &SearchContext) -> Result<bool, ProviderError> {
         debug!("Performing Unpaywall health check");
 
         // Use a known DOI for health check
         let test_url = self.build_doi_url("10.1038/nature12373");
 
-        match self.client.get(&test_url).send().await {
+        match with_context_headers(self.client.get(&test_url), context)
+            .send()
+            .await
+        {
             Ok(response) if response.status().is_success() || response.status().as_u16() == 404 => {
                 info!("Unpaywall health check: OK");
                 Ok(true)
@@ -344,8 +439,9 @@ mod tests {
         let provider = UnpaywallProvider::new("test@example.com".to_string()).unwrap();
 
         assert_eq!(provider.name(), "unpaywall");
-        assert!(provider.supports_full_text());
+        assert!(provider.provides_fulltext());
         assert_eq!(provider.priority(), 87);
+        assert_eq!(provider.tier(), ProviderTier::FullTextOA);
         assert!(provider.supported_search_types().contains(&SearchType::Doi));
         assert!(!provider
             .supported_search_types()
@@ -367,4 +463,22 @@ mod tests {
         let provider = UnpaywallProvider::new_with_default_email();
         assert!(provider.is_ok());
     }
+
+    #[test]
+    fn test_convert_response_reads_license() {
+        let provider = UnpaywallProvider::new("test@example.com".to_string()).unwrap();
+        let response: UnpaywallResponse = serde_json::from_value(serde_json::json!({
+            "doi": "10.1371/journal.pone.0000001",
+            "title": "An open paper",
+            "is_oa": true,
+            "best_oa_location": {
+                "license": "cc-by",
+                "url_for_pdf": "https://journals.plos.org/paper.pdf"
+            }
+        }))
+        .unwrap();
+
+        let paper = provider.convert_response(response);
+        assert_eq!(paper.license.as_deref(), Some("cc-by"));
+    }
 }
@@ -0,0 +1,640 @@
+use super::traits::{
+    provider_http_client, read_response_text, with_context_headers, ProviderError, ProviderResult,
+    ProviderTier, SearchContext, SearchQuery, SearchType, SourceProvider,
+};
+use crate::client::PaperMetadata;
+use crate::config::HttpConfig;
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+use tracing::{debug, info, warn};
+
+/// Largest page size Zenodo serves to anonymous clients
+const MAX_PAGE_SIZE: u32 = 25;
+
+/// Zenodo API response for record searches
+#[derive(Debug, Deserialize)]
+struct ZenodoSearchResponse {
+    hits: ZenodoHits,
+}
+
+#[derive(Debug, Deserialize)]
+struct ZenodoHits {
+    #[serde(default)]
+    hits: Vec<ZenodoRecord>,
+    total: Option<u32>,
+}
+
+/// Individual record from the Zenodo API
+#[derive(Debug, Deserialize)]
+struct ZenodoRecord {
+    id: Option<u64>,
+    doi: Option<String>,
+    metadata: ZenodoRecordMetadata,
+    #[serde(default)]
+    files: Vec<ZenodoFile>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ZenodoRecordMetadata {
+    title: Option<String>,
+    #[serde(default)]
+    creators: Vec<ZenodoCreator>,
+    description: Option<String>,
+    publication_date: Option<String>,
+    journal: Option<ZenodoJournal>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ZenodoCreator {
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ZenodoJournal {
+    title: Option<String>,
+}
+
+/// File attached to a Zenodo record
+#[derive(Debug, Deserialize)]
+struct ZenodoFile {
+    key: String,
+    #[serde(rename = "type")]
+    file_type: Option<String>,
+    size: Option<u64>,
+    #[serde(default)]
+    links: ZenodoFileLinks,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct ZenodoFileLinks {
+    /// Direct download link (legacy API)
+    download: Option<String>,
+    /// File content link (current API)
+    #[serde(rename = "self")]
+    self_link: Option<String>,
+}
+
+impl ZenodoFile {
+    fn is_pdf(&self) -> bool {
+        self.file_type
+            .as_deref()
+            .is_some_and(|t| t.eq_ignore_ascii_case("pdf"))
+            || self.key.to_ascii_lowercase().ends_with(".pdf")
+    }
+}
+
+/// Zenodo provider for datasets, preprints and other research outputs
+pub struct ZenodoProvider {
+    client: Client,
+    base_url: String,
+}
+
+impl ZenodoProvider {
+    /// Create a new Zenodo provider
+    pub fn new() -> Result<Self, ProviderError> {
+        Self::with_http_config(&HttpConfig::default())
+    }
+
+    /// Create a new Zenodo provider whose client follows the `[http]` settings
+    pub fn with_http_config(http: &HttpConfig) -> Result<Self, ProviderError> {
+        let client = provider_http_client(
+            http,
+            Some("knowledge_accumulator_mcp/0.2.1 (Academic Research Tool)"),
+        )?;
+
+        Ok(Self {
+            client,
+            base_url: "https://zenodo.org".to_string(),
+        })
+    }
+
+    /// Build the Elasticsearch-style query string for a search type
+    fn build_query(query: &str, search_type: &SearchType) -> String {
+        let phrase = query.replace('"', "");
+        match search_type {
+            SearchType::Doi => format!("doi:\"{phrase}\""),
+            SearchType::Title => format!("title:\"{phrase}\""),
+            SearchType::Author => format!("creators.name:\"{phrase}\""),
+            _ => query.to_string(),
+        }
+    }
+
+    /// Build search URL for the Zenodo records API
+    fn build_search_url(&self, query: &str, limit: u32, offset: u32) -> String {
+        let size = limit.clamp(1, MAX_PAGE_SIZE);
+        format!(
+            "{}/api/records?q={}&size={}&page={}",
+            self.base_url,
+            urlencoding::encode(query),
+            size,
+            offset / size + 1
+        )
+    }
+
+    /// Download URL for a record: its PDF when there is one, otherwise its
+    /// only file
+    fn select_file_url(
+        &self,
+        record_id: Option<u64>,
+        files: &[ZenodoFile],
+    ) -> Option<(String, Option<u64>)> {
+        let file = files.iter().find(|file| file.is_pdf()).or(match files {
+            [only] => Some(only),
+            _ => None,
+        })?;
+
+        let url = file
+            .links
+            .download
+            .clone()
+            .or_else(|| file.links.self_link.clone())
+            .or_else(|| {
+                record_id.map(|id| {
+                    format!(
+                        "{}/records/{}/files/{}?download=1",
+                        self.base_url,
+                        id,
+                        urlencoding::encode(&file.key)
+                    )
+                })
+            })?;
+
+        Some((url, file.size))
+    }
+
+    /// Convert a Zenodo record to `PaperMetadata`
+    fn convert_record(&self, record: ZenodoRecord) -> PaperMetadata {
+        // Use the DOI if available, otherwise a Zenodo identifier
+        let doi = record.doi.filter(|doi| !doi.is_empty()).unwrap_or_else(|| {
+            record
+                .id
+                .map_or_else(|| "zenodo:unknown".to_string(), |id| format!("zenodo:{id}"))
+        });
+
+        let (pdf_url, file_size) = self
+            .select_file_url(record.id, &record.files)
+            .map_or((None, None), |(url, size)| (Some(url), size));
+
+        let year = record
+            .metadata
+            .publication_date
+            .as_deref()
+            .and_then(|date| date.get(..4))
+            .and_then(|year| year.parse().ok());
+
+        PaperMetadata {
+            doi,
+            title: record.metadata.title,
+            authors: record
+                .metadata
+                .creators
+                .into_iter()
+                .map(|creator| creator.name)
+                .collect(),
+            raw_authors: Vec::new(),
+            journal: record.metadata.journal.and_then(|journal| journal.title),
+            year,
+            abstract_text: record
+                .metadata
+                .description
+                .map(|description| strip_html_tags(&description))
+                .filter(|text| !text.is_empty()),
+            pdf_url,
+            file_size,
+            license: None,
+            is_open_access: None,
+        }
+    }
+
+    /// Parse a record search response into papers and the reported total
+    fn parse_search_response(
+        &self,
+        response_text: &str,
+    ) -> Result<(Vec<PaperMetadata>, Option<u32>), ProviderError> {
+        let api_response: ZenodoSearchResponse =
+            serde_json::from_str(response_text).map_err(|e| {
+                warn!("Failed to parse Zenodo response: {}", response_text);
+                ProviderError::parse_error(
+                    self.name(),
+                    format!("Failed to parse JSON: {e}"),
+                    response_text,
+                )
+            })?;
+
+        let papers = api_response
+            .hits
+            .hits
+            .into_iter()
+            .map(|record| self.convert_record(record))
+            .collect();
+
+        Ok((papers, api_response.hits.total))
+    }
+
+    /// Search records by query
+    async fn search_records(
+        &self,
+        query: &str,
+        limit: u32,
+        offset: u32,
+        context: &SearchContext,
+    ) -> Result<(Vec<PaperMetadata>, Option<u32>), ProviderError> {
+        let url = self.build_search_url(query, limit, offset);
+        debug!("Searching Zenodo: {}", url);
+
+        let response = with_context_headers(self.client.get(&url), context)
+            .header("Accept", "application/json")
+            .send()
+            .await
+            .map_err(|e| ProviderError::Network(format!("Request failed: {e}")))?;
+
+        if response.status().as_u16() == 429 {
+            return Err(ProviderError::RateLimit);
+        }
+
+        if !response.status().is_success() {
+            return Err(ProviderError::Network(format!(
+                "API request failed with status: {}",
+                response.status()
+            )));
+        }
+
+        let response_text = read_response_text(response, context).await?;
+
+        debug!("Zenodo response: {}", response_text);
+
+        self.parse_search_response(&response_text)
+    }
+
+    /// Get a record by DOI
+    async fn get_record_by_doi(
+        &self,
+        doi: &str,
+        context: &SearchContext,
+    ) -> Result<Option<PaperMetadata>, ProviderError> {
+        let query = Self::build_query(doi, &SearchType::Doi);
+        let (papers, _) = self.search_records(&query, 1, 0, context).await?;
+
+        Ok(papers
+            .into_iter()
+            .find(|paper| paper.doi.eq_ignore_ascii_case(doi.trim())))
+    }
+}
+
+/// Plain text of an HTML description
+fn strip_html_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => {
+                in_tag = true;
+                text.push(' ');
+            }
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[async_trait]
+impl SourceProvider for ZenodoProvider {
+    fn name(&self) -> &'static str {
+        "zenodo"
+    }
+
+    fn base_url(&self) -> Option<&str> {
+        Some(self.base_url.as_str())
+    }
+
+    fn description(&self) -> &'static str {
+        "Zenodo - Open repository for datasets, preprints and software with DOIs"
+    }
+
+    fn supported_search_types(&self) -> Vec<SearchType> {
+        vec![
+            SearchType::Doi,
+            SearchType::Title,
+            SearchType::Author,
+            SearchType::Keywords,
+            SearchType::Auto,
+        ]
+    }
+
+    fn provides_fulltext(&self) -> bool {
+        true // Records link their deposited files directly
+    }
+
+    fn tier(&self) -> ProviderTier {
+        ProviderTier::FullTextOA // Open repository with deposited files
+    }
+
+    fn priority(&self) -> u8 {
+        84 // Open access tier, just below CORE and OpenReview
+    }
+
+    fn base_delay(&self) -> Duration {
+        Duration::from_millis(500) // Anonymous clients are rate limited
+    }
+
+    async fn search(
+        &self,
+        query: &SearchQuery,
+        context: &SearchContext,
+    ) -> Result<ProviderResult, ProviderError> {
+        let start_time = Instant::now();
+
+        info!(
+            "Searching Zenodo for: {} (type: {:?})",
+            query.query, query.search_type
+        );
+
+        let (papers, total) = self
+            .search_records(
+                &Self::build_query(&query.query, &query.search_type),
+                query.max_results,
+                query.offset,
+                context,
+            )
+            .await?;
+
+        let search_time = start_time.elapsed();
+        let papers_count = u32::try_from(papers.len()).unwrap_or(u32::MAX);
+
+        let result = ProviderResult {
+            papers,
+            source: "Zenodo".to_string(),
+            total_available: Some(total.unwrap_or(papers_count)),
+            search_time,
+            has_more: total.map_or(papers_count >= query.max_results, |total| {
+                query.offset.saturating_add(papers_count) < total
+            }),
+            metadata: HashMap::new(),
+        };
+
+        info!(
+            "Zenodo search completed: {} papers found in {:?}",
+            result.papers.len(),
+            search_time
+        );
+
+        Ok(result)
+    }
+
+    async fn get_by_doi(
+        &self,
+        doi: &str,
+        context: &SearchContext,
+    ) -> Result<Option<PaperMetadata>, ProviderError> {
+        info!("Getting paper by DOI from Zenodo: {}", doi);
+        self.get_record_by_doi(doi, context).await
+    }
+
+    async fn health_check(&self, context: &SearchContext) -> Result<bool, ProviderError> {
+        debug!("Performing Zenodo health check");
+
+        let url = self.build_search_url("test", 1, 0);
+
+        match with_context_headers(self.client.get(&url), context)
+            .send()
+            .await
+        {
+            Ok(response) if response.status().is_success() => {
+                info!("Zenodo health check: OK");
+                Ok(true)
+            }
+            Ok(response) => {
+                warn!(
+                    "Zenodo health check failed with status: {}",
+                    response.status()
+                );
+                Ok(false)
+            }
+            Err(e) => {
+                warn!("Zenodo health check failed: {}", e);
+                Ok(false)
+            }
+        }
+    }
+
+    async fn get_pdf_url(
+        &self,
+        doi: &str,
+        context: &SearchContext,
+    ) -> Result<Option<String>, ProviderError> {
+        if let Some(paper) = self.get_by_doi(doi, context).await? {
+            Ok(paper.pdf_url)
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+impl Default for ZenodoProvider {
+    fn default() -> Self {
+        Self::new().expect("Failed to create ZenodoProvider")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_provider_interface() {
+        let provider = ZenodoProvider::new().unwrap();
+
+        assert_eq!(provider.name(), "zenodo");
+        assert_eq!(provider.host().as_deref(), Some("zenodo.org"));
+        assert!(provider.provides_fulltext());
+        assert!(provider.supported_search_types().contains(&SearchType::Doi));
+    }
+
+    #[test]
+    fn test_url_building() {
+        let provider = ZenodoProvider::new().unwrap();
+
+        let query = ZenodoProvider::build_query("10.5281/zenodo.123", &SearchType::Doi);
+        assert_eq!(query, "doi:\"10.5281/zenodo.123\"");
+
+        let search_url = provider.build_search_url("protein folding", 10, 20);
+        assert!(search_url.contains("q=protein%20folding"));
+        assert!(search_url.contains("size=10"));
+        assert!(search_url.contains("page=3"));
+    }
+
+    #[test]
+    fn test_parse_search_response_selects_pdf_file() {
+        let provider = ZenodoProvider::new().unwrap();
+        let response = r#"{
+            "hits": {
+                "total": 1,
+                "hits": [{
+                    "id": 7654321,
+                    "doi": "10.5281/zenodo.7654321",
+                    "metadata": {
+                        "title": "Benchmark Dataset for Protein Folding",
+                        "creators": [{"name": "Doe, Jane"}, {"name": "Roe, Richard"}],
+                        "description": "<p>A <b>curated</b> benchmark.</p>",
+                        "publication_date": "2023-05-17"
+                    },
+                    "files": [
+                        {
+                            "key": "data.csv",
+                            "size": 2048,
+                            "links": {"self": "https://zenodo.org/api/records/7654321/files/data.csv/content"}
+                        },
+                        {
+                            "key": "paper.PDF",
+                            "size": 524288,
+                            "links": {"self": "https://zenodo.org/api/records/7654321/files/paper.PDF/content"}
+                        }
+                    ]
+                }]
+            }
+        }"#;
+
+        let (papers, total) = provider.parse_search_response(response).unwrap();
+
+        assert_eq!(total, Some(1));
+        let paper = &papers[0];
+        assert_eq!(paper.doi, "10.5281/zenodo.7654321");
+        assert_eq!(paper.authors, vec!["Doe, Jane", "Roe, Richard"]);
+        assert_eq!(paper.year, Some(2023));
+        assert_eq!(paper.abstract_text.as_deref(), Some("A curated benchmark."));
+        assert_eq!(
+            paper.pdf_url.as_deref(),
+            Some("https://zenodo.org/api/records/7654321/files/paper.PDF/content")
+        );
+        assert_eq!(paper.file_size, Some(524_288));
+    }
+
+    #[tokio::test]
+    async fn test_malformed_response_reported_as_parse_error() {
+        let body = "<html><body>Zenodo is down for maintenance</body></html>";
+        let mock_server = wiremock::MockServer::start().await;
+        wiremock::Mock::given(wiremock::matchers::path("/api/records"))
+            .respond_with(wiremock::ResponseTemplate::new(200).set_body_string(body))
+            .mount(&mock_server)
+            .await;
+
+        let mut provider = ZenodoProvider::new().unwrap();
+        provider.base_url = mock_server.uri();
+
+        match provider.parse_search_response(body) {
+            Err(ProviderError::ParseError {
+                provider, snippet, ..
+            }) => {
+                assert_eq!(provider, "zenodo");
+                assert_eq!(snippet, body);
+            }
+            other => panic!("expected ParseError, got {other:?}"),
+        }
+
+        let client = crate::client::MetaSearchClient::with_providers(
+            vec![std::sync::Arc::new(provider)],
+            crate::client::MetaSearchConfig::default(),
+        );
+        let query = SearchQuery {
+            query: "benchmark".to_string(),
+            search_type: SearchType::Keywords,
+            max_results: 5,
+            offset: 0,
+            params: HashMap::new(),
+        };
+        let result = client.search(&query).await.unwrap();
+
+        let error = &result.provider_errors["zenodo"];
+        assert!(error.contains("zenodo"), "{error}");
+        assert!(error.contains("down for maintenance"), "{error}");
+    }
+
+    #[tokio::test]
+    async fn test_configured_provider_headers_reach_server() {
+        let mock_server = wiremock::MockServer::start().await;
+        wiremock::Mock::given(wiremock::matchers::path("/api/records"))
+            .and(wiremock::matchers::header("x-api-key", "secret-key"))
+            .respond_with(wiremock::ResponseTemplate::new(200).set_body_string(
+                r#"{"hits": {"total": 1, "hits": [{"id": 1, "doi": "10.5281/zenodo.1", "metadata": {"title": "Benchmark"}}]}}"#,
+            ))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let mut provider = ZenodoProvider::new().unwrap();
+        provider.base_url = mock_server.uri();
+        let client = crate::client::MetaSearchClient::with_providers(
+            vec![std::sync::Arc::new(provider)],
+            crate::client::MetaSearchConfig::default(),
+        )
+        .with_provider_headers(HashMap::from([(
+            "zenodo".to_string(),
+            HashMap::from([("x-api-key".to_string(), "secret-key".to_string())]),
+        )]));
+        let query = SearchQuery {
+            query: "benchmark".to_string(),
+            search_type: SearchType::Keywords,
+            max_results: 5,
+            offset: 0,
+            params: HashMap::new(),
+        };
+
+        let result = client.search(&query).await.unwrap();
+
+        assert!(
+            result.provider_errors.is_empty(),
+            "{:?}",
+            result.provider_errors
+        );
+        assert_eq!(result.papers.len(), 1);
+        assert_eq!(result.papers[0].doi, "10.5281/zenodo.1");
+    }
+
+    #[tokio::test]
+    async fn test_search_latin1_response_decodes_author_names() {
+        let body = r#"{"hits": {"total": 1, "hits": [{"id": 1, "doi": "10.5281/zenodo.1", "metadata": {"title": "Étude", "creators": [{"name": "Müller, Jürgen"}, {"name": "Núñez, José"}]}}]}}"#;
+        let (latin1, _, _) = encoding_rs::WINDOWS_1252.encode(body);
+        let query = SearchQuery {
+            query: "etude".to_string(),
+            search_type: SearchType::Keywords,
+            max_results: 5,
+            offset: 0,
+            params: HashMap::new(),
+        };
+        let context = SearchContext {
+            timeout: Duration::from_secs(10),
+            user_agent: "test".to_string(),
+            rate_limit: None,
+            headers: HashMap::new(),
+            max_response_bytes: None,
+            default_charset: Some(encoding_rs::WINDOWS_1252),
+        };
+
+        // Charset declared by the server, then left to the configured default
+        for content_type in ["application/json; charset=ISO-8859-1", "application/json"] {
+            let mock_server = wiremock::MockServer::start().await;
+            wiremock::Mock::given(wiremock::matchers::path("/api/records"))
+                .respond_with(
+                    wiremock::ResponseTemplate::new(200)
+                        .set_body_raw(latin1.to_vec(), content_type),
+                )
+                .mount(&mock_server)
+                .await;
+            let mut provider = ZenodoProvider::new().unwrap();
+            provider.base_url = mock_server.uri();
+
+            let result = provider.search(&query, &context).await.unwrap();
+
+            assert_eq!(result.papers[0].title.as_deref(), Some("Étude"));
+            assert_eq!(
+                result.papers[0].authors,
+                vec!["Müller, Jürgen", "Núñez, José"]
+            );
+        }
+    }
+}
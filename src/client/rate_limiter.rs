@@ -1,4 +1,7 @@
-use std::time::{Duration, Instant};
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::{Duration, Instant, SystemTime};
 use tokio::time::sleep;
 use tracing::{debug, info};
 
@@ -373,6 +376,62 @@ impl ProviderRateLimiter {
     }
 }
 
+/// Last request time per host, saved on shutdown and loaded at startup so a
+/// restarted server keeps spacing its requests instead of bursting at hosts
+/// it has only just used
+#[derive(Debug, Default)]
+pub struct RateLimitState {
+    last_requests: Mutex<HashMap<String, SystemTime>>,
+}
+
+impl RateLimitState {
+    /// Load state saved at `path`. A missing file is an empty state.
+    pub fn load(path: &Path) -> crate::Result<Self> {
+        let last_requests = match std::fs::read_to_string(path) {
+            Ok(contents) => serde_json::from_str(&contents)?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => HashMap::new(),
+            Err(e) => return Err(e.into()),
+        };
+        Ok(Self {
+            last_requests: Mutex::new(last_requests),
+        })
+    }
+
+    /// Save the state to `path`, creating its directory if needed
+    pub fn save(&self, path: &Path) -> crate::Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let contents = serde_json::to_vec_pretty(&*self.lock())?;
+        std::fs::write(path, contents)?;
+        Ok(())
+    }
+
+    /// Time of the latest request reserved for `host`
+    #[must_use]
+    pub fn last_request(&self, host: &str) -> Option<SystemTime> {
+        self.lock().get(&host.to_ascii_lowercase()).copied()
+    }
+
+    /// Reserve the next request slot for `host`, at least `min_interval`
+    /// after the previous one, and return how long to wait for it
+    pub fn reserve(&self, host: &str, min_interval: Duration) -> Duration {
+        let now = SystemTime::now();
+        let mut last_requests = self.lock();
+        let slot = last_requests
+            .get(&host.to_ascii_lowercase())
+            .map_or(now, |last| (*last + min_interval).max(now));
+        last_requests.insert(host.to_ascii_lowercase(), slot);
+        slot.duration_since(now).unwrap_or_default()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, SystemTime>> {
+        self.last_requests
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -451,4 +510,31 @@ mod tests {
         // Rate should increase due to fast responses
         assert!(limiter.current_rate() > 2.0);
     }
+
+    #[test]
+    fn test_rate_limit_state_restored_state_delays_next_request() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("rate_limits.json");
+        let interval = Duration::from_secs(10);
+
+        let state = RateLimitState::default();
+        assert_eq!(state.reserve("export.arxiv.org", interval), Duration::ZERO);
+        state.save(&path).unwrap();
+
+        // A restarted limiter still waits out the interval since the saved request
+        let restored = RateLimitState::load(&path).unwrap();
+        assert_eq!(
+            restored.last_request("export.arxiv.org"),
+            state.last_request("export.arxiv.org")
+        );
+        let wait = restored.reserve("export.arxiv.org", interval);
+        assert!(wait > Duration::from_secs(9) && wait <= interval);
+        assert_eq!(
+            restored.reserve("api.crossref.org", interval),
+            Duration::ZERO
+        );
+
+        let missing = RateLimitState::load(&temp_dir.path().join("missing.json")).unwrap();
+        assert!(missing.last_request("export.arxiv.org").is_none());
+    }
 }
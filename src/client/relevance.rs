@@ -0,0 +1,124 @@
+//! Relevance scoring of merged search results.
+//!
+//! A paper's score is the sum of weighted components: how many query terms
+//! its title and abstract contain, the priority of the provider that returned
+//! it, and how recently it was published. The components are kept per paper
+//! so a ranking can be explained. Scores range from 0.0 to 1.0.
+
+use crate::client::PaperMetadata;
+use std::collections::{HashMap, HashSet};
+use std::hash::BuildHasher;
+
+/// Share of query terms found in the title
+pub const TITLE_MATCH: &str = "title_match";
+/// Share of query terms found in the abstract
+pub const ABSTRACT_MATCH: &str = "abstract_match";
+/// Priority of the provider that returned the paper
+pub const PROVIDER_PRIORITY: &str = "provider_priority";
+/// How recently the paper was published
+pub const RECENCY: &str = "recency";
+
+const TITLE_WEIGHT: f64 = 0.4;
+const ABSTRACT_WEIGHT: f64 = 0.3;
+const PROVIDER_PRIORITY_WEIGHT: f64 = 0.1;
+const RECENCY_WEIGHT: f64 = 0.2;
+
+/// Papers this many years old or older get no recency credit
+const RECENCY_HORIZON_YEARS: u32 = 20;
+
+/// Scores papers against one query
+#[derive(Debug, Clone)]
+pub struct RelevanceScorer {
+    terms: HashSet<String>,
+    current_year: u32,
+}
+
+impl RelevanceScorer {
+    /// Scorer for `query`, measuring recency from `current_year`
+    #[must_use]
+    pub fn new(query: &str, current_year: u32) -> Self {
+        Self {
+            terms: words(query),
+            current_year,
+        }
+    }
+
+    /// Weighted score components of `paper`, keyed by [`TITLE_MATCH`],
+    /// [`ABSTRACT_MATCH`], [`PROVIDER_PRIORITY`] and [`RECENCY`]. They sum
+    /// to the paper's score.
+    #[must_use]
+    pub fn breakdown(&self, paper: &PaperMetadata, provider_priority: u8) -> HashMap<String, f64> {
+        let recency = paper.year.map_or(0.0, |year| {
+            let age = self.current_year.saturating_sub(year);
+            f64::from(RECENCY_HORIZON_YEARS.saturating_sub(age)) / f64::from(RECENCY_HORIZON_YEARS)
+        });
+
+        HashMap::from([
+            (
+                TITLE_MATCH.to_string(),
+                TITLE_WEIGHT * self.term_coverage(paper.title.as_deref()),
+            ),
+            (
+                ABSTRACT_MATCH.to_string(),
+                ABSTRACT_WEIGHT * self.term_coverage(paper.abstract_text.as_deref()),
+            ),
+            (
+                PROVIDER_PRIORITY.to_string(),
+                PROVIDER_PRIORITY_WEIGHT * f64::from(provider_priority) / f64::from(u8::MAX),
+            ),
+            (RECENCY.to_string(), RECENCY_WEIGHT * recency),
+        ])
+    }
+
+    /// Share of the query terms that occur in `text` (0.0-1.0)
+    fn term_coverage(&self, text: Option<&str>) -> f64 {
+        let Some(text) = text.filter(|_| !self.terms.is_empty()) else {
+            return 0.0;
+        };
+        let found = words(text);
+        let matched = self
+            .terms
+            .iter()
+            .filter(|term| found.contains(*term))
+            .count();
+        #[allow(clippy::cast_precision_loss)]
+        let coverage = matched as f64 / self.terms.len() as f64;
+        coverage
+    }
+}
+
+/// Total score of a breakdown returned by [`RelevanceScorer::breakdown`]
+#[must_use]
+pub fn total_score<S: BuildHasher>(breakdown: &HashMap<String, f64, S>) -> f64 {
+    breakdown.values().sum()
+}
+
+/// Lower-cased words of `text`, ignoring punctuation
+fn words(text: &str) -> HashSet<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_breakdown_components_sum_to_score() {
+        let mut paper = PaperMetadata::new("10.1000/folding".to_string());
+        paper.title = Some("Protein folding with deep networks".to_string());
+        paper.abstract_text = Some("We predict how a protein folds.".to_string());
+        paper.year = Some(2014);
+
+        let scorer = RelevanceScorer::new("protein folding", 2024);
+        let breakdown = scorer.breakdown(&paper, 255);
+
+        assert!((breakdown[TITLE_MATCH] - 0.4).abs() < 1e-9);
+        assert!((breakdown[ABSTRACT_MATCH] - 0.15).abs() < 1e-9);
+        assert!((breakdown[PROVIDER_PRIORITY] - 0.1).abs() < 1e-9);
+        assert!((breakdown[RECENCY] - 0.1).abs() < 1e-9);
+        assert!((total_score(&breakdown) - 0.75).abs() < 1e-9);
+    }
+}
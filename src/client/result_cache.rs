@@ -0,0 +1,166 @@
+//! On-disk cache of meta-search results.
+//!
+//! Each query is stored as one JSON file named by the SHA-256 of its
+//! normalized form, so a new client pointed at the same directory, e.g.
+//! after a restart, answers a recent query without asking the providers.
+//! Entries older than the TTL are ignored and removed when read; once the
+//! directory holds more than `max_entries` files the oldest are evicted.
+
+use crate::client::providers::SearchQuery;
+use crate::client::MetaSearchResult;
+use crate::config::SearchCacheSettings;
+use crate::Result;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tracing::{debug, warn};
+
+/// A cached result and the query it answers
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedSearch {
+    /// Normalized query, guarding against hash collisions
+    key: String,
+    /// Seconds since the Unix epoch when the result was stored
+    stored_at: u64,
+    result: MetaSearchResult,
+}
+
+/// Meta-search results kept on disk across restarts
+#[derive(Debug, Clone)]
+pub struct SearchResultCache {
+    directory: PathBuf,
+    ttl: Duration,
+    max_entries: usize,
+}
+
+impl SearchResultCache {
+    /// Cache in `directory`, serving entries for `ttl` and keeping at most
+    /// `max_entries` of them
+    #[must_use]
+    pub const fn new(directory: PathBuf, ttl: Duration, max_entries: usize) -> Self {
+        Self {
+            directory,
+            ttl,
+            max_entries,
+        }
+    }
+
+    /// Cache configured by the `search_cache` settings
+    #[must_use]
+    pub fn from_settings(settings: &SearchCacheSettings) -> Self {
+        Self::new(
+            settings.directory.clone(),
+            Duration::from_secs(settings.ttl_secs),
+            settings.max_entries,
+        )
+    }
+
+    /// Normalized form of `query`: the query text lower-cased with its
+    /// whitespace collapsed, plus the search type, paging and parameters
+    #[must_use]
+    pub fn key(query: &SearchQuery) -> String {
+        let text = query
+            .query
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .to_lowercase();
+        let mut params: Vec<String> = query
+            .params
+            .iter()
+            .map(|(name, value)| format!("{name}={value}"))
+            .collect();
+        params.sort();
+        format!(
+            "{text}|{:?}|{}|{}|{}",
+            query.search_type,
+            query.max_results,
+            query.offset,
+            params.join("&")
+        )
+    }
+
+    /// The stored result for `query`, unless there is none or it has expired
+    pub async fn get(&self, query: &SearchQuery) -> Option<MetaSearchResult> {
+        let key = Self::key(query);
+        let path = self.entry_path(&key);
+        let contents = tokio::fs::read(&path).await.ok()?;
+        let entry: CachedSearch = match serde_json::from_slice(&contents) {
+            Ok(entry) => entry,
+            Err(e) => {
+                warn!("Removing unreadable search cache entry {:?}: {}", path, e);
+                let _ = tokio::fs::remove_file(&path).await;
+                return None;
+            }
+        };
+        if entry.key != key {
+            return None;
+        }
+        if now_secs().saturating_sub(entry.stored_at) >= self.ttl.as_secs() {
+            debug!("Search cache entry for '{}' expired", query.query);
+            let _ = tokio::fs::remove_file(&path).await;
+            return None;
+        }
+        debug!("Search cache hit for '{}'", query.query);
+        Some(entry.result)
+    }
+
+    /// Store `result` as the answer to `query`, evicting the oldest entries
+    /// beyond `max_entries`
+    pub async fn insert(&self, query: &SearchQuery, result: &MetaSearchResult) -> Result<()> {
+        tokio::fs::create_dir_all(&self.directory).await?;
+        let key = Self::key(query);
+        let path = self.entry_path(&key);
+        let entry = CachedSearch {
+            key,
+            stored_at: now_secs(),
+            result: result.clone(),
+        };
+        let contents = serde_json::to_vec(&entry)?;
+
+        // Write then rename, so a concurrent reader never sees half a file
+        let partial = path.with_extension("json.partial");
+        tokio::fs::write(&partial, contents).await?;
+        tokio::fs::rename(&partial, &path).await?;
+
+        self.evict().await
+    }
+
+    /// Remove the least recently stored entries beyond `max_entries`
+    async fn evict(&self) -> Result<()> {
+        let mut entries = Vec::new();
+        let mut dir = tokio::fs::read_dir(&self.directory).await?;
+        while let Some(entry) = dir.next_entry().await? {
+            let path = entry.path();
+            if path.extension().is_some_and(|e| e == "json") {
+                let modified = entry.metadata().await?.modified()?;
+                entries.push((modified, path));
+            }
+        }
+        if entries.len() <= self.max_entries {
+            return Ok(());
+        }
+
+        entries.sort();
+        let excess = entries.len() - self.max_entries;
+        for (_, path) in entries.into_iter().take(excess) {
+            debug!("Evicting search cache entry {:?}", path);
+            if let Err(e) = tokio::fs::remove_file(&path).await {
+                warn!("Failed to evict search cache entry {:?}: {}", path, e);
+            }
+        }
+        Ok(())
+    }
+
+    fn entry_path(&self, key: &str) -> PathBuf {
+        self.directory
+            .join(format!("{:x}.json", Sha256::digest(key.as_bytes())))
+    }
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
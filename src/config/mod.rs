@@ -1,7 +1,7 @@
 use config;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::path::PathBuf;
 use tracing::{debug, info, warn};
 
@@ -23,12 +23,42 @@ pub struct Config {
     pub rate_limiting: RateLimitingConfig,
     /// Categorization configuration
     pub categorization: crate::services::CategorizationConfig,
+    /// Abstract translation configuration (opt-in)
+    pub translation: crate::services::TranslationConfig,
+    /// Synonym-based query expansion configuration (opt-in)
+    pub query_expansion: crate::services::QueryExpansionConfig,
+    /// Limits on search query terms and abstract keywords
+    pub search_terms: crate::services::SearchTermLimits,
+    /// Corrected-DOI suggestions for failed DOI lookups
+    pub doi_suggestions: crate::services::DoiSuggestionConfig,
+    /// Outbound HTTP connection configuration
+    pub http: HttpConfig,
+    /// Bibliography generation configuration
+    pub bibliography: BibliographyConfig,
+    /// Result filtering applied to every meta-search
+    pub meta_search: MetaSearchSettings,
+    /// Storage for result sets compared by `search_diff`
+    pub search_diff: SearchDiffSettings,
+    /// On-disk cache of meta-search results that survives restarts
+    pub search_cache: SearchCacheSettings,
+    /// Formatting of tool output
+    pub output: OutputSettings,
+    /// Language of download and search messages
+    pub locale: LocaleSettings,
+    /// Worker limits for batch downloads, metadata extraction and searches
+    pub concurrency: ConcurrencyConfig,
     /// Environment profile (development, production)
     #[serde(default = "default_profile")]
     pub profile: String,
     /// Configuration schema version
     #[serde(default = "default_schema_version")]
     pub schema_version: String,
+    /// Refuse to start when the config file is invalid. When false, invalid
+    /// fields are logged and fall back to their defaults.
+    pub strict: bool,
+    /// Never construct the Sci-Hub and ResearchGate providers. Takes effect
+    /// at startup.
+    pub safe_mode: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -59,17 +89,355 @@ pub struct ResearchSourceConfig {
     pub provider_timeout_secs: u64,
     /// Maximum retry attempts
     pub max_retries: u32,
+    /// arXiv PDF version to resolve: "latest" or a pinned version such as "v1"
+    pub arxiv_version: String,
+    /// How author names from providers are written in results
+    pub author_format: AuthorNameFormat,
+}
+
+impl ResearchSourceConfig {
+    /// Pinned arXiv version number, or `None` when resolving the latest version
+    pub fn arxiv_version_pin(&self) -> crate::Result<Option<u32>> {
+        let version = self.arxiv_version.trim();
+        if version.eq_ignore_ascii_case("latest") {
+            return Ok(None);
+        }
+
+        version
+            .strip_prefix(['v', 'V'])
+            .and_then(|n| n.parse::<u32>().ok())
+            .filter(|n| *n > 0)
+            .map(Some)
+            .ok_or_else(|| crate::Error::InvalidInput {
+                field: "research_source.arxiv_version".to_string(),
+                reason: format!("Expected \"latest\" or a version like \"v1\", got \"{version}\""),
+            })
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(default)]
 pub struct DownloadsConfig {
     /// Download directory path
+    #[serde(deserialize_with = "deserialize_expanded_path")]
     pub directory: PathBuf,
     /// Maximum concurrent downloads
     pub max_concurrent: usize,
+    /// Maximum number of downloads waiting or running; further downloads
+    /// are rejected until the queue drains
+    pub max_queue_len: usize,
     /// Maximum file size in MB
     pub max_file_size_mb: u64,
+    /// Number of files hashed concurrently by maintenance operations
+    pub hash_workers: usize,
+    /// Directory for downloads that fail content validation. When unset, such files are deleted.
+    #[serde(deserialize_with = "deserialize_expanded_opt_path")]
+    pub quarantine_dir: Option<PathBuf>,
+    /// Bandwidth cap per download in bytes per second (0 = unlimited)
+    pub max_bytes_per_sec: u64,
+    /// Fail a download when no data arrives for this many seconds (0 = never)
+    pub stall_timeout_secs: u64,
+    /// Try the next candidate PDF URL when a source serves an HTML page instead of a PDF
+    pub html_failover: bool,
+    /// Try the next candidate PDF URL, in provider priority order, when a
+    /// download fails or yields something other than a valid PDF
+    pub url_failover: bool,
+    /// Hash each download with SHA-256 unless the request says otherwise.
+    /// Turning it off saves reading large files twice; their results then
+    /// carry no hash.
+    pub verify_integrity_default: bool,
+    /// Linearize and compress each downloaded PDF with `pdf_optimizer`. The
+    /// original is kept when the tool is missing, fails, or produces a file
+    /// that doesn't parse as a PDF.
+    pub optimize_pdf: bool,
+    /// Command that writes an optimized copy of `{input}` to `{output}`;
+    /// placeholders are substituted per word and no shell is involved. Words
+    /// are split on whitespace with no quoting, so the program path and
+    /// literal arguments cannot contain spaces.
+    pub pdf_optimizer: String,
+    /// Command run after each successful download. `{path}` and `{doi}` are
+    /// substituted per word and the command is executed without a shell.
+    /// As with `pdf_optimizer`, words cannot be quoted to contain spaces.
+    pub post_download_hook: Option<String>,
+    /// Filename for downloads without an explicit filename. Supports
+    /// `{title}`, `{author}`, `{year}`, `{doi}` and `{source}`; when unset the
+    /// sanitized title is used.
+    pub filename_template: Option<String>,
+    /// Name files after the server's `Content-Disposition` filename when
+    /// neither a filename, a template nor a paper title is available
+    pub honor_content_disposition: bool,
+    /// Resolve a DOI with the highest-priority healthy DOI-capable provider
+    /// first, and only run the full meta-search when it has no PDF URL or
+    /// the download from it fails
+    pub doi_fast_path: bool,
+    /// When a DOI lookup finds no PDF, search the configured providers by the
+    /// paper's title as a last resort
+    pub title_search_fallback: bool,
+    /// Save each downloaded paper's metadata as JSON next to the PDF, in a
+    /// file with the same name and a `.json` extension
+    pub write_sidecar: bool,
+    /// Append a JSON line for each completed download, with the provider the
+    /// PDF came from, to `.downloads.jsonl` in `directory`
+    pub write_manifest: bool,
+    /// How manifest writes are kept from interleaving when downloads finish
+    /// at the same time
+    pub manifest_lock: ManifestLock,
+    /// `cleanup_downloads` deletes files not modified for this many days
+    pub retention_days: Option<u64>,
+    /// `cleanup_downloads` deletes the least recently accessed files until
+    /// the download directory fits in this many MB
+    pub retention_max_size_mb: Option<u64>,
+    /// Largest file in MB that `download_paper` returns inline as base64
+    /// when asked to (0 = never)
+    pub inline_max_size_mb: u64,
+    /// Show downloaded files in tool output relative to `directory` instead
+    /// of by absolute path; the absolute path stays in the structured result
+    pub report_relative_paths: bool,
+}
+
+/// Locking around appends to the downloads manifest
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ManifestLock {
+    /// Serialize writes within this server
+    #[default]
+    Process,
+    /// Also hold an exclusive advisory lock (`flock`) on the manifest while
+    /// writing, for servers sharing one download directory. Unix only;
+    /// elsewhere it behaves like `Process`
+    File,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct HttpConfig {
+    /// IP family used for outbound connections (auto, v4, v6)
+    pub ip_family: IpFamily,
+    /// Maximum in-flight requests per host, keyed by host name. Hosts not listed are uncapped.
+    pub per_host_max_concurrency: HashMap<String, usize>,
+    /// Extra headers sent with every request to a provider, keyed by provider
+    /// name (e.g. `x-api-key`). Values are never logged.
+    pub provider_headers: HashMap<String, HashMap<String, String>>,
+    /// Largest provider response body, in bytes, read before the request is
+    /// aborted (0 = unlimited)
+    pub max_response_bytes: u64,
+    /// Charset for provider responses whose `Content-Type` names none and
+    /// that aren't valid UTF-8, e.g. `iso-8859-1` (empty = replace invalid bytes)
+    pub default_charset: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct BibliographyConfig {
+    /// Identifiers resolved concurrently when generating a bibliography
+    pub max_concurrent: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct MetaSearchSettings {
+    /// Drop papers published before this year. Papers without a year are kept.
+    pub min_year: Option<u32>,
+    /// Stop waiting for slower providers once a search has gathered as many
+    /// unique results as it asked for
+    pub greedy_stop: bool,
+    /// Search output only shows abstracts for results with at least this
+    /// relevance score (0.0-1.0); the rest are listed without one
+    pub abstract_min_relevance: f64,
+    /// Provider health checks run at the same time
+    pub health_check_concurrency: usize,
+    /// Seconds a provider health check may take before the provider is
+    /// reported unhealthy
+    pub health_check_timeout_secs: u64,
+    /// Treat upper-case `AND`/`OR` and quoted phrases in queries as boolean
+    /// syntax instead of literal text
+    pub parse_boolean: bool,
+    /// Most lookups (PDF verification, abstract translation) made to enrich
+    /// one search's results; the rest are returned un-enriched
+    pub max_enrichment_requests: Option<usize>,
+    /// Seconds one search may spend enriching its results before the rest
+    /// are returned un-enriched
+    pub max_enrichment_duration_secs: Option<u64>,
+    /// Strip "Abstract:" labels and trailing copyright/license lines from
+    /// abstracts when results are merged
+    pub clean_abstracts: bool,
+    /// Merge results whose abstracts are at least this similar (0.0-1.0)
+    /// even when DOI and title differ. Unset by default, since every new
+    /// abstract is compared with all the ones kept so far.
+    pub abstract_dedup_threshold: Option<f64>,
+    /// Score results by query terms in title and abstract, provider priority
+    /// and recency, and order them by that score instead of provider order
+    pub relevance_scoring: bool,
+    /// Seconds a named provider may take to answer a search, overriding
+    /// `research_source.provider_timeout_secs` for that provider
+    pub provider_timeouts: HashMap<String, u64>,
+    /// Providers that must answer a search for its results to be trusted;
+    /// with fewer, `search_papers` warns that results may be incomplete
+    pub min_successful_providers: usize,
+    /// Search a provider once more when it returns no results, for providers
+    /// that intermittently come back empty. Off by default, since a query
+    /// with no matches then waits for a second answer.
+    pub retry_on_empty: bool,
+    /// Providers `retry_on_empty` applies to; empty means every provider
+    pub retry_on_empty_providers: Vec<String>,
+    /// Milliseconds to wait before searching again after an empty result
+    pub retry_on_empty_delay_ms: u64,
+    /// Weight of the latest outcome in a provider's rolling failure rate
+    /// (0.0-1.0); higher values make the penalty react, and recover, faster
+    pub failure_decay: f64,
+    /// Priority points taken from a provider whose recent searches all
+    /// failed, at most 9 so failures never push it behind a whole tier
+    pub max_failure_penalty: u8,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct SearchDiffSettings {
+    /// Directory where `search_diff` result sets are saved
+    pub directory: PathBuf,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct SearchCacheSettings {
+    /// Keep meta-search results on disk and answer repeated queries from
+    /// them, including after a restart. Takes effect at startup.
+    pub enabled: bool,
+    /// Directory holding one file per cached query
+    pub directory: PathBuf,
+    /// Seconds a cached result is served before the query is searched again
+    pub ttl_secs: u64,
+    /// Most queries kept; the oldest are evicted first
+    pub max_entries: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct OutputSettings {
+    /// How `search_papers` results are rendered: one of the presets `emoji`,
+    /// `plain` or `minimal`, or a per-paper template using `{index}`,
+    /// `{title}`, `{authors}`, `{year}`, `{journal}`, `{doi}`, `{doi_url}`,
+    /// `{pdf_url}`, `{license}`, `{open_access}`, `{source}`, `{relevance}`
+    /// and `{abstract}`
+    pub search_template: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct LocaleSettings {
+    /// Language of download and search messages, e.g. `en` or `es`. Takes
+    /// effect at startup.
+    pub language: String,
+    /// Directory holding message catalogs named `<language>.toml`. Messages
+    /// a catalog leaves out, and every message when there is no catalog for
+    /// the language, are shown in English.
+    pub catalog_dir: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct ConcurrencyConfig {
+    /// Downloads run at once by `download_papers_batch` when the request
+    /// doesn't set `max_concurrent`
+    pub download_workers: usize,
+    /// PDFs processed at once by batch metadata extraction
+    pub metadata_workers: usize,
+    /// Providers queried at once by each search. Takes effect at startup.
+    pub search_provider_workers: usize,
+}
+
+/// IP family used when connecting to remote hosts
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum IpFamily {
+    /// Use whatever the resolver returns (dual-stack)
+    #[default]
+    Auto,
+    /// Only connect over IPv4
+    V4,
+    /// Only connect over IPv6
+    V6,
+}
+
+impl IpFamily {
+    /// Local address the HTTP client binds to, if the family is restricted
+    #[must_use]
+    pub const fn local_address(self) -> Option<std::net::IpAddr> {
+        match self {
+            Self::Auto => None,
+            Self::V4 => Some(std::net::IpAddr::V4(std::net::Ipv4Addr::UNSPECIFIED)),
+            Self::V6 => Some(std::net::IpAddr::V6(std::net::Ipv6Addr::UNSPECIFIED)),
+        }
+    }
+
+    /// Restrict a `reqwest` client builder to this IP family. Binding to the
+    /// unspecified address of one family makes the connector skip resolved
+    /// addresses of the other family.
+    pub fn apply(self, builder: reqwest::ClientBuilder) -> reqwest::ClientBuilder {
+        match self.local_address() {
+            Some(address) => builder.local_address(address),
+            None => builder,
+        }
+    }
+}
+
+/// Canonical form author names are normalized to
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AuthorNameFormat {
+    /// "John Smith"
+    #[default]
+    FirstLast,
+    /// "Smith, John"
+    LastFirst,
+}
+
+/// A single problem found by [`Config::validation_errors`]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ConfigValidationError {
+    /// The server cannot listen on this port
+    #[error("server.port: {port} is not a valid listen port")]
+    InvalidPort { port: u16 },
+    /// A timeout is not a positive number of seconds
+    #[error("{field}: timeout must be greater than 0 seconds")]
+    InvalidTimeout { field: String },
+    /// The download directory's parent does not exist and cannot be created
+    /// because `ancestor` is not a directory
+    #[error(
+        "downloads.directory: parent of {} cannot be created, {} is not a directory",
+        .path.display(),
+        .ancestor.display()
+    )]
+    DownloadDirParentMissing { path: PathBuf, ancestor: PathBuf },
+    /// The log level is not one `tracing` understands
+    #[error("logging.level: invalid log level '{level}', expected one of trace, debug, info, warn, error")]
+    InvalidLogLevel { level: String },
+    /// Any other out-of-range or malformed value
+    #[error("{field}: {reason}")]
+    InvalidValue { field: String, reason: String },
+}
+
+impl ConfigValidationError {
+    /// Dotted path of the offending config field
+    #[must_use]
+    pub fn field(&self) -> String {
+        match self {
+            Self::InvalidPort { .. } => "server.port".to_string(),
+            Self::InvalidTimeout { field } | Self::InvalidValue { field, .. } => field.clone(),
+            Self::DownloadDirParentMissing { .. } => "downloads.directory".to_string(),
+            Self::InvalidLogLevel { .. } => "logging.level".to_string(),
+        }
+    }
+}
+
+/// The nearest existing ancestor of `path`'s parent, if it is something
+/// other than a directory (so the parent can never be created)
+fn blocking_ancestor(path: &std::path::Path) -> Option<PathBuf> {
+    path.parent()?
+        .ancestors()
+        .find(|ancestor| ancestor.exists())
+        .filter(|ancestor| !ancestor.is_dir())
+        .map(std::path::Path::to_path_buf)
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -102,6 +470,10 @@ pub struct RateLimitingConfig {
     pub min_rate: f64,
     /// Maximum rate when adaptive limiting increases rate
     pub max_rate: f64,
+    /// Save per-host request times on shutdown and restore them at startup
+    pub persist_state: bool,
+    /// File the per-host request times are saved to
+    pub state_file: PathBuf,
 }
 
 fn default_profile() -> String {
@@ -112,6 +484,25 @@ fn default_schema_version() -> String {
     "1.0".to_string()
 }
 
+/// A path read from the configuration, with `~` and a leading `$VAR`
+/// expanded
+fn deserialize_expanded_path<'de, D>(deserializer: D) -> Result<PathBuf, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let path = String::deserialize(deserializer)?;
+    Ok(expand_path(&path))
+}
+
+/// Like [`deserialize_expanded_path`], for optional paths
+fn deserialize_expanded_opt_path<'de, D>(deserializer: D) -> Result<Option<PathBuf>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let path = Option::<String>::deserialize(deserializer)?;
+    Ok(path.as_deref().map(expand_path))
+}
+
 /// Expand tilde and environment variables in paths
 #[allow(clippy::option_if_let_else)]
 fn expand_path(path: &str) -> PathBuf {
@@ -134,6 +525,173 @@ fn expand_path(path: &str) -> PathBuf {
     }
 }
 
+/// Whether the top-level `strict = false` is set. Only the lines before the
+/// first table header are read, so the setting is found even when a later
+/// section has a syntax error.
+fn declares_non_strict(config_str: &str) -> bool {
+    let preamble = config_str
+        .lines()
+        .take_while(|line| !line.trim_start().starts_with('['))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    toml::from_str::<toml::Table>(&preamble)
+        .ok()
+        .and_then(|table| table.get("strict").and_then(toml::Value::as_bool))
+        == Some(false)
+}
+
+/// Parse TOML one section at a time, logging and skipping sections with
+/// syntax errors so the rest of the file can still be used
+fn parse_toml_sections(config_str: &str) -> toml::Table {
+    if let Ok(table) = toml::from_str::<toml::Table>(config_str) {
+        return table;
+    }
+
+    let mut sections = vec![String::new()];
+    for line in config_str.lines() {
+        if line.trim_start().starts_with('[') {
+            sections.push(String::new());
+        }
+        if let Some(section) = sections.last_mut() {
+            section.push_str(line);
+            section.push('\n');
+        }
+    }
+
+    let mut document = toml::Table::new();
+    for section in sections {
+        match toml::from_str::<toml::Table>(&section) {
+            Ok(table) => merge_toml_tables(&mut document, table),
+            Err(e) => warn!(
+                "Ignoring config section with invalid TOML near '{}': {}",
+                section
+                    .lines()
+                    .find(|l| !l.trim().is_empty())
+                    .unwrap_or_default()
+                    .trim(),
+                e.message()
+            ),
+        }
+    }
+    document
+}
+
+fn merge_toml_tables(into: &mut toml::Table, from: toml::Table) {
+    for (key, value) in from {
+        match (into.get_mut(&key), value) {
+            (Some(toml::Value::Table(existing)), toml::Value::Table(table)) => {
+                merge_toml_tables(existing, table);
+            }
+            (_, value) => {
+                into.insert(key, value);
+            }
+        }
+    }
+}
+
+/// Add `value` at `path` in `accepted` if the result still deserializes as
+/// a [`Config`]. Tables that do not are retried entry by entry; anything
+/// else is logged and left out.
+fn accept_toml_value(accepted: &mut toml::Table, path: &[String], value: toml::Value) {
+    fn deserializes(table: &toml::Table) -> Result<Config, String> {
+        toml::Value::Table(table.clone())
+            .try_into::<Config>()
+            .map_err(|e| e.message().to_string())
+    }
+
+    set_toml_path(accepted, path, value.clone());
+    let Err(error) = deserializes(accepted) else {
+        return;
+    };
+    remove_toml_path(accepted, path);
+
+    if let toml::Value::Table(entries) = value {
+        set_toml_path(accepted, path, toml::Value::Table(toml::Table::new()));
+        if deserializes(accepted).is_ok() {
+            for (key, entry) in entries {
+                let mut entry_path = path.to_vec();
+                entry_path.push(key);
+                accept_toml_value(accepted, &entry_path, entry);
+            }
+            return;
+        }
+        remove_toml_path(accepted, path);
+    }
+
+    warn!(
+        "Ignoring config value {}: {}; using the default",
+        path.join("."),
+        error
+    );
+}
+
+fn set_toml_path(table: &mut toml::Table, path: &[String], value: toml::Value) {
+    let Some((last, parents)) = path.split_last() else {
+        return;
+    };
+    let mut table = table;
+    for key in parents {
+        table = match table
+            .entry(key.clone())
+            .or_insert_with(|| toml::Value::Table(toml::Table::new()))
+        {
+            toml::Value::Table(next) => next,
+            _ => return,
+        };
+    }
+    table.insert(last.clone(), value);
+}
+
+fn remove_toml_path(table: &mut toml::Table, path: &[String]) -> Option<toml::Value> {
+    let (last, parents) = path.split_last()?;
+    let mut table = table;
+    for key in parents {
+        table = table.get_mut(key)?.as_table_mut()?;
+    }
+    table.remove(last)
+}
+
+/// Paths of every non-table value in `table`
+fn toml_leaf_paths(table: &toml::Table, prefix: &[String]) -> Vec<Vec<String>> {
+    let mut paths = Vec::new();
+    for (key, value) in table {
+        let mut path = prefix.to_vec();
+        path.push(key.clone());
+        match value {
+            toml::Value::Table(inner) => paths.extend(toml_leaf_paths(inner, &path)),
+            _ => paths.push(path),
+        }
+    }
+    paths
+}
+
+/// Every non-object value in `value`, rendered as JSON and keyed by its
+/// dotted path. API keys and provider header values are redacted.
+fn json_leaves(value: &serde_json::Value, prefix: &str, leaves: &mut BTreeMap<String, String>) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, inner) in map {
+                let path = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{prefix}.{key}")
+                };
+                json_leaves(inner, &path, leaves);
+            }
+        }
+        serde_json::Value::Null => {
+            leaves.insert(prefix.to_string(), "null".to_string());
+        }
+        _ if prefix.ends_with("api_key") || prefix.starts_with("http.provider_headers.") => {
+            leaves.insert(prefix.to_string(), "\"[REDACTED]\"".to_string());
+        }
+        _ => {
+            leaves.insert(prefix.to_string(), value.to_string());
+        }
+    }
+}
+
 /// CLI argument overrides for configuration
 #[derive(Debug, Default, Clone)]
 pub struct ConfigOverrides {
@@ -168,8 +726,22 @@ impl Default for Config {
             logging: LoggingConfig::default(),
             rate_limiting: RateLimitingConfig::default(),
             categorization: crate::services::CategorizationConfig::default(),
+            translation: crate::services::TranslationConfig::default(),
+            query_expansion: crate::services::QueryExpansionConfig::default(),
+            search_terms: crate::services::SearchTermLimits::default(),
+            doi_suggestions: crate::services::DoiSuggestionConfig::default(),
+            http: HttpConfig::default(),
+            bibliography: BibliographyConfig::default(),
+            meta_search: MetaSearchSettings::default(),
+            search_diff: SearchDiffSettings::default(),
+            search_cache: SearchCacheSettings::default(),
+            output: OutputSettings::default(),
+            locale: LocaleSettings::default(),
+            concurrency: ConcurrencyConfig::default(),
             profile: default_profile(),
             schema_version: default_schema_version(),
+            strict: true,
+            safe_mode: false,
         }
     }
 }
@@ -198,6 +770,8 @@ impl Default for ResearchSourceConfig {
             timeout_secs: 30,
             provider_timeout_secs: 30,
             max_retries: 3,
+            arxiv_version: "latest".to_string(),
+            author_format: AuthorNameFormat::FirstLast,
         }
     }
 }
@@ -207,7 +781,135 @@ impl Default for DownloadsConfig {
         Self {
             directory: expand_path("~/downloads/papers"),
             max_concurrent: 3,
+            max_queue_len: 1000,
             max_file_size_mb: 100,
+            hash_workers: 4,
+            quarantine_dir: None,
+            max_bytes_per_sec: 0,
+            stall_timeout_secs: 60,
+            html_failover: true,
+            url_failover: true,
+            verify_integrity_default: true,
+            optimize_pdf: false,
+            pdf_optimizer: "qpdf --linearize --object-streams=generate --compress-streams=y \
+                            --recompress-flate {input} {output}"
+                .to_string(),
+            post_download_hook: None,
+            filename_template: None,
+            honor_content_disposition: true,
+            doi_fast_path: true,
+            title_search_fallback: false,
+            write_sidecar: false,
+            write_manifest: false,
+            manifest_lock: ManifestLock::Process,
+            retention_days: None,
+            retention_max_size_mb: None,
+            inline_max_size_mb: 10,
+            report_relative_paths: false,
+        }
+    }
+}
+
+impl Default for HttpConfig {
+    fn default() -> Self {
+        Self {
+            ip_family: IpFamily::Auto,
+            per_host_max_concurrency: HashMap::new(),
+            provider_headers: HashMap::new(),
+            max_response_bytes: 50 * 1024 * 1024,
+            default_charset: "windows-1252".to_string(),
+        }
+    }
+}
+
+impl HttpConfig {
+    /// Encoding named by `default_charset`; `None` when it is empty or not a
+    /// known charset label
+    #[must_use]
+    pub fn default_encoding(&self) -> Option<&'static encoding_rs::Encoding> {
+        encoding_rs::Encoding::for_label(self.default_charset.trim().as_bytes())
+    }
+}
+
+impl Default for BibliographyConfig {
+    fn default() -> Self {
+        Self { max_concurrent: 30 }
+    }
+}
+
+impl Default for MetaSearchSettings {
+    fn default() -> Self {
+        Self {
+            min_year: None,
+            greedy_stop: false,
+            abstract_min_relevance: 0.0,
+            health_check_concurrency: 8,
+            health_check_timeout_secs: 5,
+            parse_boolean: false,
+            max_enrichment_requests: None,
+            max_enrichment_duration_secs: None,
+            clean_abstracts: true,
+            abstract_dedup_threshold: None,
+            relevance_scoring: false,
+            provider_timeouts: HashMap::new(),
+            min_successful_providers: 1,
+            retry_on_empty: false,
+            retry_on_empty_providers: Vec::new(),
+            retry_on_empty_delay_ms: 500,
+            failure_decay: 0.3,
+            max_failure_penalty: crate::client::meta_search::MAX_FAILURE_PENALTY,
+        }
+    }
+}
+
+impl Default for SearchDiffSettings {
+    fn default() -> Self {
+        Self {
+            directory: dirs::data_dir()
+                .unwrap_or_else(|| expand_path("~/.local/share"))
+                .join("knowledge_accumulator_mcp")
+                .join("result_sets"),
+        }
+    }
+}
+
+impl Default for SearchCacheSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            directory: dirs::data_dir()
+                .unwrap_or_else(|| expand_path("~/.local/share"))
+                .join("knowledge_accumulator_mcp")
+                .join("search_cache"),
+            ttl_secs: 86400,
+            max_entries: 1000,
+        }
+    }
+}
+
+impl Default for OutputSettings {
+    fn default() -> Self {
+        Self {
+            search_template: "emoji".to_string(),
+        }
+    }
+}
+
+impl Default for LocaleSettings {
+    fn default() -> Self {
+        Self {
+            language: crate::server::messages::DEFAULT_LANGUAGE.to_string(),
+            catalog_dir: None,
+        }
+    }
+}
+
+impl Default for ConcurrencyConfig {
+    fn default() -> Self {
+        Self {
+            download_workers: 9,
+            metadata_workers: 12,
+            search_provider_workers: 3,
         }
     }
 }
@@ -237,6 +939,7 @@ impl Default for RateLimitingConfig {
         providers.insert("semantic_scholar".to_string(), 1.5);
         providers.insert("unpaywall".to_string(), 1.5);
         providers.insert("core".to_string(), 1.5);
+        providers.insert("zenodo".to_string(), 1.0);
         providers.insert("ssrn".to_string(), 1.0);
 
         // Sci-Hub and similar - conservative rates
@@ -254,6 +957,11 @@ impl Default for RateLimitingConfig {
             default_rate: 1.0,
             min_rate: 0.25,
             max_rate: 5.0,
+            persist_state: true,
+            state_file: dirs::data_dir()
+                .unwrap_or_else(|| expand_path("~/.local/share"))
+                .join("knowledge_accumulator_mcp")
+                .join("rate_limits.json"),
         }
     }
 }
@@ -321,8 +1029,71 @@ impl Config {
         Self::validate_config_file_security(path)?;
 
         let config_str = std::fs::read_to_string(path)?;
-        toml::from_str(&config_str)
-            .map_err(|e| crate::Error::Config(config::ConfigError::Foreign(Box::new(e))))
+        Self::from_toml_str(&config_str)
+    }
+
+    /// Parse a TOML configuration. With `strict = false`, fields that fail to
+    /// parse or validate are logged and keep their defaults instead of
+    /// failing the whole load.
+    pub fn from_toml_str(config_str: &str) -> crate::Result<Self> {
+        match toml::from_str::<Self>(config_str) {
+            Ok(config) if config.strict || config.validation_errors().is_empty() => Ok(config),
+            Ok(_) => Ok(Self::from_toml_str_lenient(config_str)),
+            Err(e) if declares_non_strict(config_str) => {
+                warn!(
+                    "Configuration file has errors, falling back to defaults for invalid fields: {}",
+                    e.message()
+                );
+                Ok(Self::from_toml_str_lenient(config_str))
+            }
+            Err(e) => Err(crate::Error::Config(config::ConfigError::Foreign(
+                Box::new(e),
+            ))),
+        }
+    }
+
+    /// Build a configuration from every field of `config_str` that parses and
+    /// validates, leaving the rest at their defaults
+    fn from_toml_str_lenient(config_str: &str) -> Self {
+        let parse = |table: &toml::Table| -> Self {
+            toml::Value::Table(table.clone())
+                .try_into()
+                .unwrap_or_default()
+        };
+
+        let mut accepted = toml::Table::new();
+        for (key, value) in parse_toml_sections(config_str) {
+            accept_toml_value(&mut accepted, &[key], value);
+        }
+
+        // Drop each value whose removal clears a validation error
+        let mut config = parse(&accepted);
+        let mut errors = config.validation_errors();
+        for path in toml_leaf_paths(&accepted, &[]) {
+            if errors.is_empty() {
+                break;
+            }
+            let Some(value) = remove_toml_path(&mut accepted, &path) else {
+                continue;
+            };
+            let candidate = parse(&accepted);
+            let remaining = candidate.validation_errors();
+            if remaining.len() < errors.len() {
+                for error in errors.iter().filter(|e| !remaining.contains(e)) {
+                    warn!(
+                        "Ignoring config value {}: {}; using the default",
+                        path.join("."),
+                        error
+                    );
+                }
+                config = candidate;
+                errors = remaining;
+            } else {
+                set_toml_path(&mut accepted, &path, value);
+            }
+        }
+
+        config
     }
 
     /// Try to load from standard configuration locations
@@ -474,9 +1245,10 @@ impl Config {
         config
     }
 
-    /// Generate JSON schema for configuration
+    /// JSON schema of the configuration, with each setting's description
+    /// and default value
     #[must_use]
-    pub fn generate_schema() -> serde_json::Value {
+    pub fn json_schema() -> serde_json::Value {
         let schema = schemars::schema_for!(Self);
         serde_json::to_value(schema).unwrap_or_default()
     }
@@ -525,61 +1297,282 @@ impl Config {
             );
         }
 
-        if self.research_source.timeout_secs != new_config.research_source.timeout_secs {
-            self.research_source.timeout_secs = new_config.research_source.timeout_secs;
+        if self.research_source.timeout_secs != new_config.research_source.timeout_secs {
+            self.research_source.timeout_secs = new_config.research_source.timeout_secs;
+            changed = true;
+            debug!(
+                "Hot reloaded sci-hub timeout: {}",
+                new_config.research_source.timeout_secs
+            );
+        }
+        if self.research_source.provider_timeout_secs
+            != new_config.research_source.provider_timeout_secs
+        {
+            self.research_source.provider_timeout_secs =
+                new_config.research_source.provider_timeout_secs;
+            changed = true;
+            debug!(
+                "Hot reloaded provider timeout: {}",
+                new_config.research_source.provider_timeout_secs
+            );
+        }
+
+        if self.research_source.max_retries != new_config.research_source.max_retries {
+            self.research_source.max_retries = new_config.research_source.max_retries;
+            changed = true;
+            debug!(
+                "Hot reloaded max retries: {}",
+                new_config.research_source.max_retries
+            );
+        }
+
+        // Hot-reloadable: Health check intervals
+        if self.server.health_check_interval_secs != new_config.server.health_check_interval_secs {
+            self.server.health_check_interval_secs = new_config.server.health_check_interval_secs;
+            changed = true;
+            debug!(
+                "Hot reloaded health check interval: {}",
+                new_config.server.health_check_interval_secs
+            );
+        }
+
+        // Hot-reloadable: Download configuration
+        if self.downloads.max_concurrent != new_config.downloads.max_concurrent {
+            self.downloads.max_concurrent = new_config.downloads.max_concurrent;
+            changed = true;
+            debug!(
+                "Hot reloaded max concurrent downloads: {}",
+                new_config.downloads.max_concurrent
+            );
+        }
+
+        if self.downloads.max_queue_len != new_config.downloads.max_queue_len {
+            self.downloads.max_queue_len = new_config.downloads.max_queue_len;
+            changed = true;
+            debug!(
+                "Hot reloaded max download queue length: {}",
+                new_config.downloads.max_queue_len
+            );
+        }
+
+        if self.downloads.max_file_size_mb != new_config.downloads.max_file_size_mb {
+            self.downloads.max_file_size_mb = new_config.downloads.max_file_size_mb;
+            changed = true;
+            debug!(
+                "Hot reloaded max file size: {}MB",
+                new_config.downloads.max_file_size_mb
+            );
+        }
+
+        if self.downloads.hash_workers != new_config.downloads.hash_workers {
+            self.downloads.hash_workers = new_config.downloads.hash_workers;
+            changed = true;
+            debug!(
+                "Hot reloaded hash workers: {}",
+                new_config.downloads.hash_workers
+            );
+        }
+
+        if self.downloads.quarantine_dir != new_config.downloads.quarantine_dir {
+            self.downloads.quarantine_dir = new_config.downloads.quarantine_dir.clone();
+            changed = true;
+            debug!(
+                "Hot reloaded quarantine directory: {:?}",
+                new_config.downloads.quarantine_dir
+            );
+        }
+
+        if self.downloads.max_bytes_per_sec != new_config.downloads.max_bytes_per_sec {
+            self.downloads.max_bytes_per_sec = new_config.downloads.max_bytes_per_sec;
+            changed = true;
+            debug!(
+                "Hot reloaded download bandwidth cap: {} bytes/s",
+                new_config.downloads.max_bytes_per_sec
+            );
+        }
+
+        if self.downloads.stall_timeout_secs != new_config.downloads.stall_timeout_secs {
+            self.downloads.stall_timeout_secs = new_config.downloads.stall_timeout_secs;
+            changed = true;
+            debug!(
+                "Hot reloaded download stall timeout: {}s",
+                new_config.downloads.stall_timeout_secs
+            );
+        }
+
+        if self.downloads.html_failover != new_config.downloads.html_failover {
+            self.downloads.html_failover = new_config.downloads.html_failover;
+            changed = true;
+            debug!(
+                "Hot reloaded HTML failover: {}",
+                new_config.downloads.html_failover
+            );
+        }
+
+        if self.downloads.url_failover != new_config.downloads.url_failover {
+            self.downloads.url_failover = new_config.downloads.url_failover;
+            changed = true;
+            debug!(
+                "Hot reloaded URL failover: {}",
+                new_config.downloads.url_failover
+            );
+        }
+
+        if self.downloads.verify_integrity_default != new_config.downloads.verify_integrity_default
+        {
+            self.downloads.verify_integrity_default = new_config.downloads.verify_integrity_default;
+            changed = true;
+            debug!(
+                "Hot reloaded default integrity verification: {}",
+                new_config.downloads.verify_integrity_default
+            );
+        }
+
+        if self.downloads.optimize_pdf != new_config.downloads.optimize_pdf
+            || self.downloads.pdf_optimizer != new_config.downloads.pdf_optimizer
+        {
+            self.downloads.optimize_pdf = new_config.downloads.optimize_pdf;
+            self.downloads
+                .pdf_optimizer
+                .clone_from(&new_config.downloads.pdf_optimizer);
+            changed = true;
+            debug!(
+                "Hot reloaded PDF optimization: {} ({})",
+                new_config.downloads.optimize_pdf, new_config.downloads.pdf_optimizer
+            );
+        }
+
+        if self.downloads.post_download_hook != new_config.downloads.post_download_hook {
+            self.downloads
+                .post_download_hook
+                .clone_from(&new_config.downloads.post_download_hook);
+            changed = true;
+            debug!(
+                "Hot reloaded post-download hook: {:?}",
+                new_config.downloads.post_download_hook
+            );
+        }
+
+        if self.downloads.filename_template != new_config.downloads.filename_template {
+            self.downloads
+                .filename_template
+                .clone_from(&new_config.downloads.filename_template);
+            changed = true;
+            debug!(
+                "Hot reloaded download filename template: {:?}",
+                new_config.downloads.filename_template
+            );
+        }
+
+        if self.downloads.honor_content_disposition
+            != new_config.downloads.honor_content_disposition
+        {
+            self.downloads.honor_content_disposition =
+                new_config.downloads.honor_content_disposition;
+            changed = true;
+            debug!(
+                "Hot reloaded Content-Disposition filename honoring: {}",
+                new_config.downloads.honor_content_disposition
+            );
+        }
+
+        if self.downloads.doi_fast_path != new_config.downloads.doi_fast_path {
+            self.downloads.doi_fast_path = new_config.downloads.doi_fast_path;
+            changed = true;
+            debug!(
+                "Hot reloaded DOI fast path: {}",
+                new_config.downloads.doi_fast_path
+            );
+        }
+
+        if self.downloads.title_search_fallback != new_config.downloads.title_search_fallback {
+            self.downloads.title_search_fallback = new_config.downloads.title_search_fallback;
+            changed = true;
+            debug!(
+                "Hot reloaded title search fallback: {}",
+                new_config.downloads.title_search_fallback
+            );
+        }
+
+        if self.downloads.write_sidecar != new_config.downloads.write_sidecar {
+            self.downloads.write_sidecar = new_config.downloads.write_sidecar;
+            changed = true;
+            debug!(
+                "Hot reloaded metadata sidecars: {}",
+                new_config.downloads.write_sidecar
+            );
+        }
+
+        if self.downloads.write_manifest != new_config.downloads.write_manifest {
+            self.downloads.write_manifest = new_config.downloads.write_manifest;
+            changed = true;
+            debug!(
+                "Hot reloaded downloads manifest: {}",
+                new_config.downloads.write_manifest
+            );
+        }
+
+        if self.downloads.manifest_lock != new_config.downloads.manifest_lock {
+            self.downloads.manifest_lock = new_config.downloads.manifest_lock;
+            changed = true;
+            debug!(
+                "Hot reloaded manifest lock: {:?}",
+                new_config.downloads.manifest_lock
+            );
+        }
+
+        if self.downloads.retention_days != new_config.downloads.retention_days {
+            self.downloads.retention_days = new_config.downloads.retention_days;
             changed = true;
             debug!(
-                "Hot reloaded sci-hub timeout: {}",
-                new_config.research_source.timeout_secs
+                "Hot reloaded download retention: {:?} days",
+                new_config.downloads.retention_days
             );
         }
-        if self.research_source.provider_timeout_secs
-            != new_config.research_source.provider_timeout_secs
-        {
-            self.research_source.provider_timeout_secs =
-                new_config.research_source.provider_timeout_secs;
+
+        if self.downloads.retention_max_size_mb != new_config.downloads.retention_max_size_mb {
+            self.downloads.retention_max_size_mb = new_config.downloads.retention_max_size_mb;
             changed = true;
             debug!(
-                "Hot reloaded provider timeout: {}",
-                new_config.research_source.provider_timeout_secs
+                "Hot reloaded download size budget: {:?} MB",
+                new_config.downloads.retention_max_size_mb
             );
         }
 
-        if self.research_source.max_retries != new_config.research_source.max_retries {
-            self.research_source.max_retries = new_config.research_source.max_retries;
+        if self.downloads.inline_max_size_mb != new_config.downloads.inline_max_size_mb {
+            self.downloads.inline_max_size_mb = new_config.downloads.inline_max_size_mb;
             changed = true;
             debug!(
-                "Hot reloaded max retries: {}",
-                new_config.research_source.max_retries
+                "Hot reloaded inline download limit: {} MB",
+                new_config.downloads.inline_max_size_mb
             );
         }
 
-        // Hot-reloadable: Health check intervals
-        if self.server.health_check_interval_secs != new_config.server.health_check_interval_secs {
-            self.server.health_check_interval_secs = new_config.server.health_check_interval_secs;
+        if self.downloads.report_relative_paths != new_config.downloads.report_relative_paths {
+            self.downloads.report_relative_paths = new_config.downloads.report_relative_paths;
             changed = true;
             debug!(
-                "Hot reloaded health check interval: {}",
-                new_config.server.health_check_interval_secs
+                "Hot reloaded relative download paths: {}",
+                new_config.downloads.report_relative_paths
             );
         }
 
-        // Hot-reloadable: Download configuration
-        if self.downloads.max_concurrent != new_config.downloads.max_concurrent {
-            self.downloads.max_concurrent = new_config.downloads.max_concurrent;
+        // Hot-reloadable: Batch worker limits
+        if self.concurrency.download_workers != new_config.concurrency.download_workers {
+            self.concurrency.download_workers = new_config.concurrency.download_workers;
             changed = true;
             debug!(
-                "Hot reloaded max concurrent downloads: {}",
-                new_config.downloads.max_concurrent
+                "Hot reloaded download workers: {}",
+                new_config.concurrency.download_workers
             );
         }
 
-        if self.downloads.max_file_size_mb != new_config.downloads.max_file_size_mb {
-            self.downloads.max_file_size_mb = new_config.downloads.max_file_size_mb;
+        if self.concurrency.metadata_workers != new_config.concurrency.metadata_workers {
+            self.concurrency.metadata_workers = new_config.concurrency.metadata_workers;
             changed = true;
             debug!(
-                "Hot reloaded max file size: {}MB",
-                new_config.downloads.max_file_size_mb
+                "Hot reloaded metadata workers: {}",
+                new_config.concurrency.metadata_workers
             );
         }
 
@@ -647,7 +1640,40 @@ impl Config {
         Ok(changed)
     }
 
+    /// Validate the configuration, reporting every problem at once as a
+    /// single `InvalidInput` error. See [`Self::validation_errors`] for the
+    /// individual failures.
     pub fn validate(&self) -> crate::Result<()> {
+        let errors = self.validation_errors();
+        if errors.is_empty() {
+            return Ok(());
+        }
+
+        Err(crate::Error::InvalidInput {
+            field: errors
+                .iter()
+                .map(ConfigValidationError::field)
+                .collect::<Vec<_>>()
+                .join(", "),
+            reason: errors
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join("; "),
+        })
+    }
+
+    /// Every validation failure in the configuration
+    #[must_use]
+    pub fn validation_errors(&self) -> Vec<ConfigValidationError> {
+        let mut errors = Vec::new();
+        let mut invalid = |field: &str, reason: String| {
+            errors.push(ConfigValidationError::InvalidValue {
+                field: field.to_string(),
+                reason,
+            });
+        };
+
         // Validate schema version (allow forward compatibility)
         let supported_versions = ["1.0"];
         if !supported_versions.contains(&self.schema_version.as_str()) {
@@ -657,112 +1683,347 @@ impl Config {
             );
         }
 
-        // Validate server configuration
-        if self.server.port == 0 {
-            return Err(crate::Error::InvalidInput {
-                field: "server.port".to_string(),
-                reason: "Server port cannot be 0".to_string(),
-            });
-        }
-        if self.server.port < 1024 {
-            warn!(
-                "Server port {} is in the privileged range (<1024)",
-                self.server.port
-            );
-        }
-
         // Validate Sci-Hub configuration
         if self.research_source.endpoints.is_empty() {
-            return Err(crate::Error::InvalidInput {
-                field: "sci_hub.mirrors".to_string(),
-                reason: "At least one Sci-Hub mirror must be configured".to_string(),
-            });
+            invalid(
+                "sci_hub.mirrors",
+                "At least one Sci-Hub mirror must be configured".to_string(),
+            );
         }
-
         for mirror in &self.research_source.endpoints {
             if !mirror.starts_with("https://") {
-                return Err(crate::Error::InvalidInput {
-                    field: "sci_hub.mirrors".to_string(),
-                    reason: format!("Sci-Hub mirror must use HTTPS: {mirror}"),
-                });
+                invalid(
+                    "sci_hub.mirrors",
+                    format!("Sci-Hub mirror must use HTTPS: {mirror}"),
+                );
             }
         }
-
         if self.research_source.rate_limit_per_sec == 0 {
-            return Err(crate::Error::InvalidInput {
-                field: "sci_hub.rate_limit_per_sec".to_string(),
-                reason: "Rate limit must be greater than 0".to_string(),
-            });
+            invalid(
+                "sci_hub.rate_limit_per_sec",
+                "Rate limit must be greater than 0".to_string(),
+            );
+        }
+        if let Err(crate::Error::InvalidInput { field, reason }) =
+            self.research_source.arxiv_version_pin()
+        {
+            invalid(&field, reason);
         }
 
         // Validate downloads configuration
         if self.downloads.max_concurrent == 0 {
-            return Err(crate::Error::InvalidInput {
-                field: "downloads.max_concurrent".to_string(),
-                reason: "Max concurrent downloads must be greater than 0".to_string(),
-            });
+            invalid(
+                "downloads.max_concurrent",
+                "Max concurrent downloads must be greater than 0".to_string(),
+            );
+        }
+        if self.downloads.max_queue_len == 0 {
+            invalid(
+                "downloads.max_queue_len",
+                "Max download queue length must be greater than 0".to_string(),
+            );
         }
         if self.downloads.max_file_size_mb == 0 {
-            return Err(crate::Error::InvalidInput {
-                field: "downloads.max_file_size_mb".to_string(),
-                reason: "Max file size must be greater than 0".to_string(),
-            });
+            invalid(
+                "downloads.max_file_size_mb",
+                "Max file size must be greater than 0".to_string(),
+            );
+        }
+        if self.downloads.hash_workers == 0 {
+            invalid(
+                "downloads.hash_workers",
+                "Hash workers must be greater than 0".to_string(),
+            );
+        }
+        if self.downloads.optimize_pdf
+            && !["{input}", "{output}"]
+                .iter()
+                .all(|placeholder| self.downloads.pdf_optimizer.contains(placeholder))
+        {
+            invalid(
+                "downloads.pdf_optimizer",
+                "PDF optimizer command must contain {input} and {output}".to_string(),
+            );
+        }
+        if self
+            .downloads
+            .post_download_hook
+            .as_deref()
+            .is_some_and(|hook| hook.trim().is_empty())
+        {
+            invalid(
+                "downloads.post_download_hook",
+                "Post-download hook command cannot be empty".to_string(),
+            );
+        }
+        if let Some(template) = &self.downloads.filename_template {
+            if template.trim().is_empty() {
+                invalid(
+                    "downloads.filename_template",
+                    "Filename template cannot be empty".to_string(),
+                );
+            } else if template.contains(['/', '\\']) {
+                invalid(
+                    "downloads.filename_template",
+                    format!("Filename template must not contain path separators: {template}"),
+                );
+            }
         }
 
-        // Validate logging configuration
-        let valid_log_levels = ["trace", "debug", "info", "warn", "error"];
-        if !valid_log_levels.contains(&self.logging.level.as_str()) {
-            return Err(crate::Error::InvalidInput {
-                field: "logging.level".to_string(),
-                reason: format!(
-                    "Invalid log level '{}'. Valid levels: {:?}",
-                    self.logging.level, valid_log_levels
+        // Validate search term limits
+        if self.search_terms.max_terms == 0 {
+            invalid(
+                "search_terms.max_terms",
+                "Maximum search terms must be greater than 0".to_string(),
+            );
+        }
+        if self.search_terms.max_term_length == 0 {
+            invalid(
+                "search_terms.max_term_length",
+                "Maximum search term length must be greater than 0".to_string(),
+            );
+        }
+
+        // Validate HTTP configuration
+        let mut zero_hosts: Vec<&String> = self
+            .http
+            .per_host_max_concurrency
+            .iter()
+            .filter(|(_, max)| **max == 0)
+            .map(|(host, _)| host)
+            .collect();
+        zero_hosts.sort();
+        for host in zero_hosts {
+            invalid(
+                &format!("http.per_host_max_concurrency.{host}"),
+                "Per-host concurrency must be greater than 0".to_string(),
+            );
+        }
+        let mut provider_headers: Vec<_> = self.http.provider_headers.iter().collect();
+        provider_headers.sort_by_key(|(provider, _)| *provider);
+        for (provider, headers) in provider_headers {
+            let mut names: Vec<_> = headers.iter().collect();
+            names.sort_by_key(|(name, _)| *name);
+            for (name, value) in names {
+                let field = format!("http.provider_headers.{provider}.{name}");
+                if reqwest::header::HeaderName::from_bytes(name.as_bytes()).is_err() {
+                    invalid(&field, "Not a valid HTTP header name".to_string());
+                } else if reqwest::header::HeaderValue::from_str(value).is_err() {
+                    invalid(&field, "Not a valid HTTP header value".to_string());
+                }
+            }
+        }
+        if !self.http.default_charset.trim().is_empty() && self.http.default_encoding().is_none() {
+            invalid(
+                "http.default_charset",
+                format!("Unknown charset '{}'", self.http.default_charset),
+            );
+        }
+
+        if !(0.0..=1.0).contains(&self.meta_search.abstract_min_relevance) {
+            invalid(
+                "meta_search.abstract_min_relevance",
+                "Abstract relevance threshold must be between 0.0 and 1.0".to_string(),
+            );
+        }
+
+        if let Some(threshold) = self.meta_search.abstract_dedup_threshold {
+            if !(0.0..=1.0).contains(&threshold) {
+                invalid(
+                    "meta_search.abstract_dedup_threshold",
+                    "Abstract similarity threshold must be between 0.0 and 1.0".to_string(),
+                );
+            }
+        }
+
+        let mut zero_timeouts: Vec<&String> = self
+            .meta_search
+            .provider_timeouts
+            .iter()
+            .filter(|(_, secs)| **secs == 0)
+            .map(|(provider, _)| provider)
+            .collect();
+        zero_timeouts.sort();
+        for provider in zero_timeouts {
+            invalid(
+                &format!("meta_search.provider_timeouts.{provider}"),
+                "Provider timeout must be greater than 0".to_string(),
+            );
+        }
+
+        if !(0.0..=1.0).contains(&self.meta_search.failure_decay) {
+            invalid(
+                "meta_search.failure_decay",
+                "Failure decay must be between 0.0 and 1.0".to_string(),
+            );
+        }
+        if self.meta_search.max_failure_penalty > crate::client::meta_search::MAX_FAILURE_PENALTY {
+            invalid(
+                "meta_search.max_failure_penalty",
+                format!(
+                    "Failure penalty must be at most {}, below the spacing between provider tiers",
+                    crate::client::meta_search::MAX_FAILURE_PENALTY
                 ),
-            });
+            );
+        }
+
+        if self.meta_search.health_check_concurrency == 0 {
+            invalid(
+                "meta_search.health_check_concurrency",
+                "Health check concurrency must be greater than 0".to_string(),
+            );
+        }
+        if self.meta_search.health_check_timeout_secs == 0 {
+            invalid(
+                "meta_search.health_check_timeout_secs",
+                "Health check timeout must be greater than 0".to_string(),
+            );
+        }
+
+        // The language names the catalog file
+        if self.locale.language.is_empty()
+            || !self
+                .locale
+                .language
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        {
+            invalid(
+                "locale.language",
+                "Language must be a tag such as en or pt-BR".to_string(),
+            );
+        }
+
+        // Validate search cache configuration
+        if self.search_cache.ttl_secs == 0 {
+            invalid(
+                "search_cache.ttl_secs",
+                "Search cache TTL must be greater than 0".to_string(),
+            );
+        }
+        if self.search_cache.max_entries == 0 {
+            invalid(
+                "search_cache.max_entries",
+                "Search cache must hold at least one entry".to_string(),
+            );
+        }
+
+        // Validate bibliography configuration
+        if self.bibliography.max_concurrent == 0 {
+            invalid(
+                "bibliography.max_concurrent",
+                "Bibliography concurrency must be greater than 0".to_string(),
+            );
+        }
+
+        // Validate concurrency configuration
+        for (field, workers) in [
+            (
+                "concurrency.download_workers",
+                self.concurrency.download_workers,
+            ),
+            (
+                "concurrency.metadata_workers",
+                self.concurrency.metadata_workers,
+            ),
+            (
+                "concurrency.search_provider_workers",
+                self.concurrency.search_provider_workers,
+            ),
+        ] {
+            if workers == 0 {
+                invalid(field, "Worker count must be greater than 0".to_string());
+            }
         }
 
         // Validate rate limiting configuration
         if self.rate_limiting.default_rate <= 0.0 {
-            return Err(crate::Error::InvalidInput {
-                field: "rate_limiting.default_rate".to_string(),
-                reason: "Default rate limit must be greater than 0".to_string(),
-            });
+            invalid(
+                "rate_limiting.default_rate",
+                "Default rate limit must be greater than 0".to_string(),
+            );
         }
-
         if self.rate_limiting.min_rate <= 0.0
             || self.rate_limiting.min_rate > self.rate_limiting.max_rate
         {
-            return Err(crate::Error::InvalidInput {
-                field: "rate_limiting.min_rate".to_string(),
-                reason: "Minimum rate must be greater than 0 and less than maximum rate"
-                    .to_string(),
-            });
+            invalid(
+                "rate_limiting.min_rate",
+                "Minimum rate must be greater than 0 and less than maximum rate".to_string(),
+            );
         }
-
         if self.rate_limiting.burst_size == 0 {
-            return Err(crate::Error::InvalidInput {
-                field: "rate_limiting.burst_size".to_string(),
-                reason: "Burst size must be greater than 0".to_string(),
-            });
+            invalid(
+                "rate_limiting.burst_size",
+                "Burst size must be greater than 0".to_string(),
+            );
         }
-
-        for (provider, rate) in &self.rate_limiting.providers {
+        let mut providers: Vec<(&String, &f64)> = self.rate_limiting.providers.iter().collect();
+        providers.sort_by(|a, b| a.0.cmp(b.0));
+        for (provider, rate) in providers {
             if *rate <= 0.0 {
-                return Err(crate::Error::InvalidInput {
-                    field: format!("rate_limiting.providers.{provider}"),
-                    reason: format!("Rate limit for provider '{provider}' must be greater than 0"),
-                });
+                invalid(
+                    &format!("rate_limiting.providers.{provider}"),
+                    format!("Rate limit for provider '{provider}' must be greater than 0"),
+                );
             }
         }
 
+        // Validate logging configuration
         let valid_log_formats = ["json", "text"];
         if !valid_log_formats.contains(&self.logging.format.as_str()) {
-            return Err(crate::Error::InvalidInput {
-                field: "logging.format".to_string(),
-                reason: format!(
+            invalid(
+                "logging.format",
+                format!(
                     "Invalid log format '{}'. Valid formats: {:?}",
                     self.logging.format, valid_log_formats
                 ),
+            );
+        }
+
+        // Validate server configuration
+        if self.server.port == 0 {
+            errors.push(ConfigValidationError::InvalidPort {
+                port: self.server.port,
+            });
+        }
+        if self.server.port > 0 && self.server.port < 1024 {
+            warn!(
+                "Server port {} is in the privileged range (<1024)",
+                self.server.port
+            );
+        }
+
+        let timeouts = [
+            ("server.timeout_secs", self.server.timeout_secs),
+            (
+                "research_source.timeout_secs",
+                self.research_source.timeout_secs,
+            ),
+            (
+                "research_source.provider_timeout_secs",
+                self.research_source.provider_timeout_secs,
+            ),
+            ("translation.timeout_secs", self.translation.timeout_secs),
+        ];
+        for (field, secs) in timeouts {
+            if secs == 0 {
+                errors.push(ConfigValidationError::InvalidTimeout {
+                    field: field.to_string(),
+                });
+            }
+        }
+
+        if let Some(ancestor) = blocking_ancestor(&self.downloads.directory) {
+            errors.push(ConfigValidationError::DownloadDirParentMissing {
+                path: self.downloads.directory.clone(),
+                ancestor,
+            });
+        }
+
+        let valid_log_levels = ["trace", "debug", "info", "warn", "error"];
+        if !valid_log_levels.contains(&self.logging.level.as_str()) {
+            errors.push(ConfigValidationError::InvalidLogLevel {
+                level: self.logging.level.clone(),
             });
         }
 
@@ -775,7 +2036,42 @@ impl Config {
             );
         }
 
-        Ok(())
+        errors
+    }
+
+    /// Settings that differ from `base` as `(field, base value, current value)`,
+    /// sorted by field. Fields are dotted paths such as `downloads.directory`
+    /// and values are rendered as JSON; a field missing on one side (e.g. a
+    /// map entry) shows as `unset`.
+    #[must_use]
+    pub fn diff(&self, base: &Self) -> Vec<(String, String, String)> {
+        let leaves = |config: &Self| {
+            let mut leaves = BTreeMap::new();
+            if let Ok(value) = serde_json::to_value(config) {
+                json_leaves(&value, "", &mut leaves);
+            }
+            leaves
+        };
+        let current = leaves(self);
+        let mut base = leaves(base);
+
+        let mut changes = Vec::new();
+        for (field, value) in current {
+            match base.remove(&field) {
+                Some(base_value) if base_value == value => {}
+                base_value => changes.push((
+                    field,
+                    base_value.unwrap_or_else(|| "unset".to_string()),
+                    value,
+                )),
+            }
+        }
+        changes.extend(
+            base.into_iter()
+                .map(|(field, base_value)| (field, base_value, "unset".to_string())),
+        );
+        changes.sort();
+        changes
     }
 
     /// Get a safe version of the config for logging (with sensitive values redacted)
@@ -788,6 +2084,13 @@ impl Config {
             // Log file paths might contain sensitive info, but probably okay to log
         }
 
+        // Provider headers typically carry API keys
+        for headers in safe_config.http.provider_headers.values_mut() {
+            for value in headers.values_mut() {
+                *value = "[REDACTED]".to_string();
+            }
+        }
+
         // Could redact download directory if it contains user info
         if safe_config
             .downloads
@@ -813,6 +2116,14 @@ schema_version = "1.0"
 # Environment profile: "development" or "production"
 profile = "development"
 
+# Refuse to start when this file is invalid (default: true). When false,
+# invalid fields are logged and fall back to their defaults.
+strict = true
+
+# Never query Sci-Hub or ResearchGate, e.g. for institutional deployments
+# (default: false). Requires a restart.
+safe_mode = false
+
 [server]
 # Server listen port (default: 8080)
 port = 8080
@@ -849,6 +2160,13 @@ provider_timeout_secs = 30
 # Maximum retry attempts (default: 3)
 max_retries = 3
 
+# arXiv PDF version to resolve: "latest" or a pinned version like "v1" (default: "latest")
+arxiv_version = "latest"
+
+# How author names are written in results: "first_last" ("John Smith") or
+# "last_first" ("Smith, John") (default: "first_last")
+author_format = "first_last"
+
 [downloads]
 # Download directory path (supports ~ expansion)
 directory = "~/downloads/papers"
@@ -856,9 +2174,315 @@ directory = "~/downloads/papers"
 # Maximum concurrent downloads (default: 3)
 max_concurrent = 3
 
+# Maximum downloads queued or running at once; batch downloads past this are
+# rejected with a "queue full" error (default: 1000)
+max_queue_len = 1000
+
 # Maximum file size in MB (default: 100)
 max_file_size_mb = 100
 
+# Files hashed in parallel when scanning the download directory (default: 4)
+hash_workers = 4
+
+# Move downloads that are not valid PDFs here (with a .reason.txt file)
+# instead of deleting them (default: none, invalid files are deleted)
+# quarantine_dir = "~/downloads/papers_quarantine"
+
+# Bandwidth cap per download in bytes per second (default: 0, unlimited)
+max_bytes_per_sec = 0
+
+# Abort a download, deleting the partial file, when no data arrives for this
+# many seconds (default: 60, 0 waits until the overall request timeout)
+stall_timeout_secs = 60
+
+# When a PDF link serves an HTML page (login wall, interstitial), abort it and
+# try the next candidate URL from the search results (default: true)
+html_failover = true
+
+# When a download from one PDF URL fails (HTTP error, stalled transfer, not a
+# valid PDF), try the URL offered by the next provider in priority order
+# (default: true)
+url_failover = true
+
+# Hash every download with SHA-256 to verify its integrity, unless a request
+# sets verify_integrity itself. Turn off to skip reading large files a second
+# time; their results then have no sha256_hash (default: true)
+verify_integrity_default = true
+
+# Linearize and compress each downloaded PDF for archiving and fast web
+# viewing. Needs the optimizer installed (qpdf by default); when it is missing
+# or its output doesn't parse as a PDF, the download is kept as is
+# (default: false)
+optimize_pdf = false
+
+# Command writing an optimized copy of {input} to {output}, run without a
+# shell. The command is split on whitespace and quotes are not interpreted, so
+# the program and its fixed arguments cannot contain spaces (put the program on
+# PATH or symlink it instead); substituted paths may. With Ghostscript instead
+# of qpdf:
+# pdf_optimizer = "gs -sDEVICE=pdfwrite -dPDFSETTINGS=/ebook -dFastWebView=true -dNOPAUSE -dBATCH -dQUIET -sOutputFile={output} {input}"
+pdf_optimizer = "qpdf --linearize --object-streams=generate --compress-streams=y --recompress-flate {input} {output}"
+
+# Command to run after each successful download, e.g. to import into a
+# reference manager. {path} and {doi} are replaced in each argument; the
+# command runs without a shell and is split on whitespace, so pipes and quotes
+# are not interpreted and only the substituted {path} may contain spaces.
+# post_download_hook = "zotero-cli import {path}"
+
+# Filename for downloads (default: the sanitized paper title). Placeholders:
+# {title}, {author} (first author's family name), {year}, {doi} and {source}
+# (the provider the PDF came from); ".pdf" is added if missing.
+# filename_template = "{author}_{year}_{source}"
+
+# For direct URL downloads without a title, use the filename the server sends
+# in its Content-Disposition header instead of one derived from the URL
+# (default: true)
+honor_content_disposition = true
+
+# Ask only the highest-priority healthy DOI-capable provider for a DOI's PDF
+# before running the full meta-search, which is used when that provider has no
+# PDF URL or its download fails (default: true)
+doi_fast_path = true
+
+# When a DOI lookup finds no PDF, search the providers by the paper's title and
+# use a PDF from a result with the same title. Only the enabled providers are
+# queried, so safe_mode still applies (default: false)
+title_search_fallback = false
+
+# Save each downloaded paper's metadata as JSON next to the PDF
+# (paper.pdf -> paper.json). download_paper's write_sidecar argument overrides
+# it per call (default: false)
+write_sidecar = false
+
+# Append one JSON line per completed download (DOI, file, hash, and the
+# provider the PDF came from) to .downloads.jsonl in the download directory
+# (default: false)
+write_manifest = false
+
+# Downloads finishing together never interleave their manifest lines: "process"
+# serializes the writes within this server, "file" also takes an advisory lock
+# on the manifest, for several servers sharing one download directory
+# (default: "process")
+manifest_lock = "process"
+
+# Retention policy applied by the cleanup_downloads tool and, with
+# --cleanup-interval, periodically by the daemon. Files not modified for
+# retention_days are deleted first, then the least recently accessed files
+# until the directory fits in retention_max_size_mb. The quarantine directory
+# is never touched (default: none, nothing is deleted)
+# retention_days = 180
+# retention_max_size_mb = 10240
+
+# Largest PDF download_paper returns base64-encoded in the tool result when
+# called with inline = true, for clients that can't read the server's disk.
+# Larger files are still saved but not returned (default: 10, 0 disables)
+inline_max_size_mb = 10
+
+# Show downloaded files in tool output relative to the download directory, so
+# the server's home directory isn't revealed to the client. The absolute path
+# is still part of the structured result (default: false)
+report_relative_paths = false
+
+[http]
+# IP family for outbound connections: "auto", "v4" or "v6" (default: "auto")
+# Use "v4" if publisher hosts hang over IPv6 on dual-stack networks
+ip_family = "auto"
+
+# Largest provider response body in bytes; bigger responses are aborted while
+# streaming instead of being buffered (default: 52428800 = 50 MB, 0 = unlimited)
+max_response_bytes = 52428800
+
+# Charset for responses that name none in their Content-Type and aren't valid
+# UTF-8, as some older endpoints send Latin-1 (default: "windows-1252", a
+# superset of ISO-8859-1; "" replaces undecodable bytes instead)
+default_charset = "windows-1252"
+
+# Maximum in-flight requests per host, shared by all providers on that host
+# (default: none, requests are only limited per provider)
+# [http.per_host_max_concurrency]
+# "eutils.ncbi.nlm.nih.gov" = 3
+
+# Extra headers sent with every request to a provider, e.g. API keys or a
+# contact address. Values are never logged.
+# [http.provider_headers.zenodo]
+# "Authorization" = "Bearer your-token"
+
+[translation]
+# Translate non-English abstracts in search results (default: false)
+enabled = false
+
+# LibreTranslate-compatible endpoint, required when enabled
+# endpoint = "https://libretranslate.example.org/translate"
+# api_key = "your-key"
+
+# Language abstracts are translated into (default: "en")
+target_language = "en"
+
+[query_expansion]
+# Also search with synonyms substituted into keyword queries and merge the
+# results (default: false)
+enabled = false
+
+# Extra searches per query (default: 3)
+max_expansions = 3
+
+# Phrase -> alternative phrasings, matched case-insensitively on whole words.
+# Setting this replaces the built-in medical examples.
+# [query_expansion.synonyms]
+# "heart attack" = ["myocardial infarction"]
+# "myocardial infarction" = ["heart attack"]
+
+[search_terms]
+# Queries and abstract keyword lists with more terms than this are rejected
+# (default: 50)
+max_terms = 50
+
+# Longest accepted single term, in characters (default: 200)
+max_term_length = 200
+
+[doi_suggestions]
+# When a DOI is not found, ask CrossRef for registered DOIs with the same
+# prefix and suggest the closest ones in the error. This sends the requested
+# DOI, or the title if one was given, to CrossRef (default: false)
+enabled = false
+
+# Suggestions listed per failed lookup (default: 3)
+max_suggestions = 3
+
+# Without a title, only suggest DOIs within this many edits of the requested
+# one; look-alike characters such as O/0 are free (default: 3)
+max_distance = 3
+
+[bibliography]
+# Identifiers resolved concurrently per bibliography request (default: 30)
+max_concurrent = 30
+
+[meta_search]
+# Drop papers published before this year from every search; papers with no
+# known year are kept. Requests can override it with their own min_year
+# (default: none)
+# min_year = 2015
+
+# Return as soon as the providers that have answered cover the requested number
+# of unique results, cancelling the rest; the slowest providers' results are
+# never seen (default: false)
+greedy_stop = false
+
+# Only show abstracts in search results for papers with at least this
+# relevance score (0.0-1.0); lower-ranked papers are listed with title and DOI
+# (default: 0.0, every abstract is shown)
+abstract_min_relevance = 0.0
+
+# Provider health checks run in parallel, this many at a time, and a provider
+# that doesn't answer within the timeout is reported unhealthy
+# (defaults: 8, 5)
+health_check_concurrency = 8
+health_check_timeout_secs = 5
+
+# Read upper-case AND/OR and "quoted phrases" in queries as boolean syntax:
+# AND-ed terms must all match (arXiv and CORE get their native syntax) and
+# each OR alternative is searched separately with the results merged. Off by
+# default so queries are searched exactly as typed (default: false)
+parse_boolean = false
+
+# Budget for enriching one search's results (PDF verification for
+# require_pdf + verify, abstract translation). Once either cap is reached the
+# remaining papers are returned as the providers gave them (default: no caps)
+# max_enrichment_requests = 20
+# max_enrichment_duration_secs = 10
+
+# Strip leading "Abstract:"/"Summary" labels and trailing copyright or license
+# sentences ("© 2023 Elsevier Ltd. All rights reserved.") from abstracts
+# (default: true)
+clean_abstracts = true
+
+# Also merge results whose abstracts are at least this similar (0.0-1.0), for
+# preprint and journal versions of a paper that share neither DOI nor title.
+# Compares every abstract with those already kept, so it is off by default
+# abstract_dedup_threshold = 0.9
+
+# Rank results by a relevance score built from query terms in the title and
+# abstract, provider priority and publication year, instead of provider order.
+# search_papers with explain_relevance shows each paper's score components
+# (default: false)
+relevance_scoring = false
+
+# search_papers warns that results may be incomplete when fewer providers than
+# this answer, e.g. when only a weak fallback provider responded (default: 1)
+min_successful_providers = 1
+
+# Search a provider a second time, after a short delay, when it returns no
+# results, for providers that occasionally answer empty for queries that do
+# match. Only one retry is made. Limit it to the flaky providers, or leave the
+# list empty to retry any of them (defaults: false, [], 500)
+retry_on_empty = false
+# retry_on_empty_providers = ["core", "ssrn"]
+retry_on_empty_delay_ms = 500
+
+# Providers that keep failing are asked later. Each search outcome moves a
+# provider's rolling failure rate towards 1.0 (failure) or 0.0 (success) by
+# failure_decay, and the rate times max_failure_penalty is subtracted from its
+# priority. The penalty is at most 9, below the 10 points between tiers, so a
+# failing provider is reordered among its neighbours rather than dropped
+# behind a whole tier (defaults: 0.3, 9)
+failure_decay = 0.3
+max_failure_penalty = 9
+
+# Search timeout in seconds for individual providers, overriding
+# research_source.provider_timeout_secs, e.g. more patience for a slow mirror
+# and less for a fast metadata API (default: none)
+# [meta_search.provider_timeouts]
+# sci_hub = 60
+# crossref = 10
+
+[search_diff]
+# Where search_diff saves result sets between runs
+# (default: the platform data directory)
+# directory = "~/.local/share/knowledge_accumulator_mcp/result_sets"
+
+[search_cache]
+# Keep search results on disk so repeated queries, even after a restart,
+# are answered without asking the providers again (default: false)
+enabled = false
+# Where cached results are stored (default: the platform data directory)
+# directory = "~/.local/share/knowledge_accumulator_mcp/search_cache"
+# Seconds before a cached result is searched again (default: 86400)
+ttl_secs = 86400
+# Most queries kept; the oldest are evicted first (default: 1000)
+max_entries = 1000
+
+[output]
+# How search_papers results are shown (default: "emoji"):
+#   "emoji"   - the default, with emoji headings and a download tip
+#   "plain"   - the same paper list with plain-text headings
+#   "minimal" - one line per paper: number, title, year and DOI
+# Anything else is used as a template for each paper, with {index}, {title},
+# {authors}, {year}, {journal}, {doi}, {doi_url}, {pdf_url}, {license},
+# {open_access}, {source}, {relevance} and {abstract} filled in, e.g. "{index}. {title} ({year}) https://doi.org/{doi}"
+search_template = "emoji"
+
+[locale]
+# Language of download and search messages (default: "en"). Takes effect at
+# startup.
+language = "en"
+# Directory with message catalogs named <language>.toml. Each table groups
+# message templates, e.g. [download] not_found = "...{doi}..."; messages a
+# catalog leaves out stay in English.
+# catalog_dir = "/home/me/.config/knowledge_accumulator_mcp/messages"
+
+[concurrency]
+# Downloads run at once by download_papers_batch, unless the request sets its
+# own max_concurrent (default: 9)
+download_workers = 9
+
+# PDFs processed at once by batch metadata extraction; CPU-bound, so around
+# the number of cores is a good ceiling (default: 12)
+metadata_workers = 12
+
+# Providers queried at once by each search, doubled while providers answer
+# quickly and halved while they are slow. Takes effect at startup (default: 3)
+search_provider_workers = 3
+
 [logging]
 # Log level: "trace", "debug", "info", "warn", "error" (default: "info")
 level = "info"
@@ -891,6 +2515,14 @@ min_rate = 0.25
 # Maximum rate when adaptive limiting increases rate (default: 5.0)
 max_rate = 5.0
 
+# Save per-host request times on shutdown and restore them at startup, so a
+# restart does not burst requests at recently used hosts (default: true)
+persist_state = true
+
+# File the request times are saved to
+# (default: <data dir>/knowledge_accumulator_mcp/rate_limits.json)
+# state_file = "~/.local/share/knowledge_accumulator_mcp/rate_limits.json"
+
 # Provider-specific rate limits (requests per second)
 [rate_limiting.providers]
 arxiv = 2.0
@@ -1125,6 +2757,25 @@ mod tests {
         assert!(config.validate().is_ok());
     }
 
+    #[test]
+    fn test_diff_reports_changed_download_directory() {
+        let base = Config::default();
+        assert!(base.diff(&base).is_empty());
+
+        let mut config = Config::default();
+        config.downloads.directory = PathBuf::from("/srv/papers");
+        let diff = config.diff(&base);
+
+        assert_eq!(diff.len(), 1);
+        let (field, default, current) = &diff[0];
+        assert_eq!(field, "downloads.directory");
+        assert_eq!(
+            default,
+            &serde_json::to_string(&base.downloads.directory).unwrap()
+        );
+        assert_eq!(current, "\"/srv/papers\"");
+    }
+
     #[test]
     fn test_server_config_defaults() {
         let config = ServerConfig {
@@ -1149,12 +2800,156 @@ mod tests {
             timeout_secs: 30,
             provider_timeout_secs: 60,
             max_retries: 3,
+            arxiv_version: "latest".to_string(),
+            author_format: AuthorNameFormat::FirstLast,
         };
         assert!(!config.endpoints.is_empty());
         assert_eq!(config.rate_limit_per_sec, 1);
         assert_eq!(config.max_retries, 3);
     }
 
+    #[test]
+    fn test_http_ip_family_parsing() {
+        assert_eq!(Config::default().http.ip_family, IpFamily::Auto);
+        assert_eq!(IpFamily::Auto.local_address(), None);
+
+        let config: Config = toml::from_str("[http]\nip_family = \"v4\"\n").unwrap();
+        assert_eq!(config.http.ip_family, IpFamily::V4);
+        assert!(config.http.ip_family.local_address().unwrap().is_ipv4());
+
+        let config: Config = toml::from_str("[http]\nip_family = \"v6\"\n").unwrap();
+        assert!(config.http.ip_family.local_address().unwrap().is_ipv6());
+    }
+
+    #[test]
+    fn test_http_per_host_max_concurrency_validation() {
+        let config: Config =
+            toml::from_str("[http.per_host_max_concurrency]\n\"eutils.ncbi.nlm.nih.gov\" = 3\n")
+                .unwrap();
+        assert_eq!(
+            config
+                .http
+                .per_host_max_concurrency
+                .get("eutils.ncbi.nlm.nih.gov"),
+            Some(&3)
+        );
+        assert!(config.validate().is_ok());
+
+        let mut config = Config::default();
+        config
+            .http
+            .per_host_max_concurrency
+            .insert("api.crossref.org".to_string(), 0);
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_meta_search_failure_penalty_validation() {
+        let config: Config =
+            toml::from_str("[meta_search]\nfailure_decay = 0.5\nmax_failure_penalty = 5\n")
+                .unwrap();
+        assert!(config.validate().is_ok());
+        let meta = crate::client::MetaSearchConfig::from_config(&config);
+        assert!((meta.failure_decay - 0.5).abs() < f64::EPSILON);
+        assert_eq!(meta.max_failure_penalty, 5);
+
+        let mut config = Config::default();
+        config.meta_search.max_failure_penalty = 50;
+        assert!(config.validate().is_err());
+
+        let mut config = Config::default();
+        config.meta_search.failure_decay = 1.5;
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_arxiv_version_pin_parsing() {
+        let mut config = Config::default();
+        assert_eq!(config.research_source.arxiv_version_pin().unwrap(), None);
+
+        config.research_source.arxiv_version = "v2".to_string();
+        assert_eq!(config.research_source.arxiv_version_pin().unwrap(), Some(2));
+        assert!(config.validate().is_ok());
+
+        for invalid in ["v0", "2", "newest"] {
+            config.research_source.arxiv_version = invalid.to_string();
+            assert!(config.validate().is_err());
+        }
+    }
+
+    #[test]
+    fn test_validation_errors_reports_every_problem() {
+        let blocker = tempfile::NamedTempFile::new().unwrap();
+        let mut config = Config::default();
+        config.server.port = 0;
+        config.research_source.timeout_secs = 0;
+        config.logging.level = "loud".to_string();
+        config.downloads.directory = blocker.path().join("papers").join("inbox");
+
+        let errors = config.validation_errors();
+        assert_eq!(errors.len(), 4, "{errors:?}");
+        assert!(errors.contains(&ConfigValidationError::InvalidPort { port: 0 }));
+        assert!(errors.contains(&ConfigValidationError::InvalidTimeout {
+            field: "research_source.timeout_secs".to_string()
+        }));
+        assert!(errors.contains(&ConfigValidationError::InvalidLogLevel {
+            level: "loud".to_string()
+        }));
+        assert!(
+            errors.contains(&ConfigValidationError::DownloadDirParentMissing {
+                path: config.downloads.directory.clone(),
+                ancestor: blocker.path().to_path_buf(),
+            })
+        );
+
+        match config.validate() {
+            Err(crate::Error::InvalidInput { field, reason }) => {
+                for expected in [
+                    "server.port",
+                    "research_source.timeout_secs",
+                    "logging.level",
+                    "downloads.directory",
+                ] {
+                    assert!(field.contains(expected), "{field}");
+                    assert!(reason.contains(expected), "{reason}");
+                }
+            }
+            other => panic!("expected InvalidInput, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_from_toml_str_non_strict_bad_field_uses_default() {
+        let toml = "strict = false\n\n[server]\nport = \"not a port\"\nhost = \"0.0.0.0\"\n\n[downloads]\nmax_concurrent = 0\n";
+
+        let config = Config::from_toml_str(toml).unwrap();
+        assert_eq!(config.server.port, ServerConfig::default().port);
+        assert_eq!(config.server.host, "0.0.0.0");
+        assert_eq!(
+            config.downloads.max_concurrent,
+            DownloadsConfig::default().max_concurrent
+        );
+        assert!(config.validate().is_ok());
+
+        let strict = toml.replace("strict = false", "strict = true");
+        assert!(Config::from_toml_str(&strict).is_err());
+    }
+
+    #[test]
+    fn test_from_toml_str_non_strict_syntax_error_keeps_other_sections() {
+        let toml = "strict = false\n[server]\nport = 9000\n[logging\nlevel = \"debug\"\n";
+
+        let config = Config::from_toml_str(toml).unwrap();
+        assert_eq!(config.server.port, 9000);
+        assert_eq!(config.logging.level, LoggingConfig::default().level);
+        assert!(Config::from_toml_str(&toml.replace("strict = false", "")).is_err());
+    }
+
+    #[test]
+    fn test_validation_errors_empty_for_default_config() {
+        assert!(Config::default().validation_errors().is_empty());
+    }
+
     #[test]
     fn test_config_validation() {
         let mut config = Config::default();
@@ -1198,6 +2993,22 @@ mod tests {
         assert_eq!(expanded, PathBuf::from("/absolute/path"));
     }
 
+    #[test]
+    fn test_from_toml_str_quarantine_dir_tilde_expanded() {
+        let config = Config::from_toml_str(
+            "[downloads]\ndirectory = \"~/papers\"\nquarantine_dir = \"~/quarantine\"\n",
+        )
+        .unwrap();
+        let Some(home_dir) = dirs::home_dir() else {
+            return;
+        };
+        assert_eq!(config.downloads.directory, home_dir.join("papers"));
+        assert_eq!(
+            config.downloads.quarantine_dir,
+            Some(home_dir.join("quarantine"))
+        );
+    }
+
     #[test]
     fn test_config_overrides() {
         let overrides = ConfigOverrides {
@@ -1244,7 +3055,7 @@ mod tests {
 
     #[test]
     fn test_schema_generation() {
-        let schema = Config::generate_schema();
+        let schema = Config::json_schema();
         assert!(schema.is_object());
 
         // Verify schema contains expected properties
@@ -1252,6 +3063,16 @@ mod tests {
         assert!(schema_obj.contains_key("$schema"));
     }
 
+    #[test]
+    fn test_json_schema_includes_downloads_directory() {
+        let schema = Config::json_schema();
+        assert!(schema["properties"]["downloads"].is_object());
+
+        let directory = &schema["$defs"]["DownloadsConfig"]["properties"]["directory"];
+        assert_eq!(directory["description"], "Download directory path");
+        assert!(directory.get("default").is_some(), "{directory}");
+    }
+
     #[test]
     fn test_example_config_generation() {
         let example = Config::generate_example_config();
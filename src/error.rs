@@ -16,9 +16,9 @@ pub enum Error {
     #[error("Serialization error: {0}")]
     Serde(#[from] serde_json::Error),
 
-    // Network errors (transient - should retry)
+    // Network errors (transient unless the server answered with a client error)
     #[error("HTTP error: {0}")]
-    Http(#[from] reqwest::Error),
+    Http(#[source] reqwest::Error),
 
     #[error("Network timeout after {timeout:?}: {message}")]
     NetworkTimeout { timeout: Duration, message: String },
@@ -49,6 +49,12 @@ pub enum Error {
     #[error("Authorization denied: {resource}")]
     AuthorizationDenied { resource: String },
 
+    #[error("Paper found in {providers_checked} provider(s) but no PDF available: {}", .metadata.doi)]
+    PaywalledPaper {
+        metadata: Box<crate::client::PaperMetadata>,
+        providers_checked: usize,
+    },
+
     // Server errors (transient - should retry)
     #[error("Service temporarily unavailable: {service} - {reason}")]
     ServiceUnavailable { service: String, reason: String },
@@ -110,13 +116,14 @@ pub enum ErrorCategory {
 impl Error {
     /// Categorize error for retry logic
     #[must_use]
-    pub const fn category(&self) -> ErrorCategory {
+    pub fn category(&self) -> ErrorCategory {
         match self {
             // Permanent errors - don't retry
             Self::Config(_)
             | Self::InvalidInput { .. }
             | Self::AuthenticationFailed(_)
             | Self::AuthorizationDenied { .. }
+            | Self::PaywalledPaper { .. }
             | Self::Parse { .. }
             | Self::Serde(_) => ErrorCategory::Permanent,
 
@@ -128,9 +135,18 @@ impl Error {
                 ErrorCategory::CircuitBreaker
             }
 
+            // HTTP errors carrying a 4xx status are the caller's fault, except
+            // request timeouts and rate limiting
+            Self::Http(err) => match err.status().map(|status| status.as_u16()) {
+                Some(429) => ErrorCategory::RateLimited,
+                Some(408) => ErrorCategory::Transient,
+                Some(400..=499) => ErrorCategory::Permanent,
+                _ if err.is_builder() => ErrorCategory::Permanent,
+                _ => ErrorCategory::Transient,
+            },
+
             // Transient errors - retry with exponential backoff
-            Self::Http(_)
-            | Self::NetworkTimeout { .. }
+            Self::NetworkTimeout { .. }
             | Self::ConnectionRefused { .. }
             | Self::DnsFailure { .. }
             | Self::ServiceUnavailable { .. }
@@ -165,13 +181,65 @@ impl Error {
 
     /// Check if error is retryable
     #[must_use]
-    pub const fn is_retryable(&self) -> bool {
+    pub fn is_retryable(&self) -> bool {
         matches!(
             self.category(),
             ErrorCategory::Transient | ErrorCategory::RateLimited
         )
     }
 
+    /// Check if error means the paper exists but no provider offers a PDF of it
+    #[must_use]
+    pub const fn is_paywall(&self) -> bool {
+        matches!(self, Self::PaywalledPaper { .. })
+    }
+
+    /// Check if error means the requested resource doesn't exist
+    #[must_use]
+    pub fn is_not_found(&self) -> bool {
+        match self {
+            Self::SciHub { code, .. } => *code == 404,
+            Self::Http(err) => err
+                .status()
+                .is_some_and(|status| matches!(status.as_u16(), 404 | 410)),
+            _ => false,
+        }
+    }
+
+    /// Check if error is a network failure: the server was unreachable, or the
+    /// connection broke or timed out before a response arrived
+    #[must_use]
+    pub fn is_network(&self) -> bool {
+        match self {
+            Self::Http(err) => err.status().is_none() && !err.is_builder(),
+            Self::NetworkTimeout { .. }
+            | Self::ConnectionRefused { .. }
+            | Self::DnsFailure { .. }
+            | Self::Timeout { .. } => true,
+            _ => false,
+        }
+    }
+
+    /// Check if error is a file system permission failure
+    #[must_use]
+    pub fn is_permission_denied(&self) -> bool {
+        match self {
+            Self::Io(err) => err.kind() == std::io::ErrorKind::PermissionDenied,
+            Self::InvalidInput { field, .. } => field == "permissions",
+            _ => false,
+        }
+    }
+
+    /// Check if error is a DNS or connection failure, i.e. the request never
+    /// reached the server
+    #[must_use]
+    pub const fn is_connection_failure(&self) -> bool {
+        matches!(
+            self,
+            Self::DnsFailure { .. } | Self::ConnectionRefused { .. }
+        )
+    }
+
     /// Get suggested retry delay for rate limited errors
     #[must_use]
     pub const fn retry_after(&self) -> Option<Duration> {
@@ -197,6 +265,64 @@ impl Error {
 
 pub type Result<T> = std::result::Result<T, Error>;
 
+// HTTP error conversion. DNS and connection failures get their own variants so
+// the retry policy treats them as fast-retry network blips.
+impl From<reqwest::Error> for Error {
+    fn from(err: reqwest::Error) -> Self {
+        if !err.is_connect() {
+            return Self::Http(err);
+        }
+
+        let host = err
+            .url()
+            .and_then(|url| url.host_str())
+            .unwrap_or("unknown")
+            .to_string();
+
+        if is_dns_error(&err) {
+            Self::DnsFailure { hostname: host }
+        } else if has_io_error_kind(&err, std::io::ErrorKind::ConnectionRefused) {
+            let endpoint = match err
+                .url()
+                .map(|url| url.port_or_known_default().unwrap_or(0))
+            {
+                Some(port) => format!("{host}:{port}"),
+                None => host,
+            };
+            Self::ConnectionRefused { endpoint }
+        } else {
+            Self::Http(err)
+        }
+    }
+}
+
+/// Whether any error in the source chain is a name resolution failure
+fn is_dns_error(err: &(dyn std::error::Error + 'static)) -> bool {
+    let mut current = Some(err);
+    while let Some(e) = current {
+        let message = e.to_string().to_lowercase();
+        if message.contains("dns error") || message.contains("failed to lookup address") {
+            return true;
+        }
+        current = e.source();
+    }
+    false
+}
+
+/// Whether any error in the source chain is an I/O error of the given kind
+fn has_io_error_kind(err: &(dyn std::error::Error + 'static), kind: std::io::ErrorKind) -> bool {
+    let mut current = Some(err);
+    while let Some(e) = current {
+        if e.downcast_ref::<std::io::Error>()
+            .is_some_and(|io_err| io_err.kind() == kind)
+        {
+            return true;
+        }
+        current = e.source();
+    }
+    false
+}
+
 // Provider error conversion
 impl From<crate::client::providers::ProviderError> for Error {
     fn from(err: crate::client::providers::ProviderError) -> Self {
@@ -208,6 +334,14 @@ impl From<crate::client::providers::ProviderError> for Error {
                 context: "provider".to_string(),
                 message: msg,
             },
+            crate::client::providers::ProviderError::ParseError {
+                provider,
+                context,
+                snippet,
+            } => Self::Parse {
+                context: provider,
+                message: format!("{context} (response: {snippet})"),
+            },
             crate::client::providers::ProviderError::RateLimit => Self::RateLimitExceeded {
                 retry_after: Duration::from_secs(60),
             },
@@ -225,6 +359,12 @@ impl From<crate::client::providers::ProviderError> for Error {
             crate::client::providers::ProviderError::Timeout => Self::Timeout {
                 timeout: Duration::from_secs(30),
             },
+            crate::client::providers::ProviderError::Cancelled => {
+                Self::Provider("Search cancelled".to_string())
+            }
+            crate::client::providers::ProviderError::ResponseTooLarge { limit } => {
+                Self::Provider(format!("Response exceeded the {limit}-byte size limit"))
+            }
             crate::client::providers::ProviderError::Other(msg) => Self::Provider(msg),
         }
     }
@@ -334,4 +474,53 @@ mod tests {
         };
         assert!(scihub_403_error.is_retryable());
     }
+
+    #[test]
+    fn test_error_classifiers_match_representative_errors() {
+        let paywalled = Error::PaywalledPaper {
+            metadata: Box::new(crate::client::PaperMetadata::new(
+                "10.1000/paywalled".to_string(),
+            )),
+            providers_checked: 3,
+        };
+        assert!(paywalled.is_paywall());
+        assert!(!paywalled.is_retryable());
+        assert!(!paywalled.is_network());
+        assert!(!paywalled.is_not_found());
+
+        let not_found = Error::SciHub {
+            code: 404,
+            message: "Not Found".to_string(),
+        };
+        assert!(not_found.is_not_found());
+        assert!(!not_found.is_retryable());
+        assert!(!not_found.is_paywall());
+
+        let refused = Error::ConnectionRefused {
+            endpoint: "sci-hub.se:443".to_string(),
+        };
+        assert!(refused.is_network());
+        assert!(refused.is_retryable());
+        assert!(!refused.is_not_found());
+
+        let timeout = Error::Timeout {
+            timeout: Duration::from_secs(30),
+        };
+        assert!(timeout.is_network());
+        assert!(timeout.is_retryable());
+
+        let permission = Error::Io(std::io::Error::from(std::io::ErrorKind::PermissionDenied));
+        assert!(permission.is_permission_denied());
+        assert!(!permission.is_network());
+
+        let invalid = Error::InvalidInput {
+            field: "doi".to_string(),
+            reason: "No PDF available".to_string(),
+        };
+        assert!(!invalid.is_paywall());
+        assert!(!invalid.is_not_found());
+        assert!(!invalid.is_network());
+        assert!(!invalid.is_permission_denied());
+        assert!(!invalid.is_retryable());
+    }
 }
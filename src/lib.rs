@@ -60,7 +60,7 @@ pub mod tools;
 //     MetaSearchAdapter, MultiProviderAdapter, PaperDownloadAdapter, PdfMetadataAdapter,
 // };
 pub use client::{Doi, MetaSearchClient, MetaSearchConfig, MetaSearchResult, PaperMetadata};
-pub use config::{Config, ConfigOverrides};
+pub use config::{Config, ConfigOverrides, ConfigValidationError};
 // pub use di::{ServiceContainer, ServiceScope};
 pub use error::{Error, Result};
 // pub use ports::{DownloadServicePort, MetadataServicePort, ProviderServicePort, SearchServicePort};
@@ -74,5 +74,8 @@ pub use resilience::{CircuitBreaker, RetryConfig, RetryPolicy, TimeoutConfig, Ti
 pub use server::Server;
 pub use service::{DaemonConfig, DaemonService, HealthCheck, PidFile, SignalHandler};
 pub use tools::{
-    BibliographyTool, CategorizeTool, CodeSearchTool, DownloadTool, MetadataExtractor, SearchTool,
+    BibliographyTool, BibtexValidationTool, CategorizeTool, CleanupTool, CodeSearchTool,
+    DownloadTool, ExportLibraryTool, LibraryTool, MetadataExtractor, PaperSignalsTool,
+    PaperTextTool, RecategorizeTool, ReferenceExtractionTool, ResolveDoiTool, SearchDiffTool,
+    SearchTool,
 };
@@ -31,6 +31,19 @@ struct Cli {
     #[arg(long, default_value = "8090")]
     health_port: u16,
 
+    /// Query to rerun periodically in daemon mode (repeatable)
+    #[arg(long = "watch-query")]
+    watch_queries: Vec<String>,
+
+    /// Seconds between runs of watched queries
+    #[arg(long, default_value = "3600")]
+    watch_interval: u64,
+
+    /// Seconds between applying the download retention policy in daemon
+    /// mode (0 disables it)
+    #[arg(long, default_value = "0")]
+    cleanup_interval: u64,
+
     /// Override server port
     #[arg(long)]
     port: Option<u16>,
@@ -76,7 +89,7 @@ async fn main() -> Result<()> {
 
     // Handle schema generation request
     if cli.generate_schema {
-        let schema = Config::generate_schema();
+        let schema = Config::json_schema();
         println!("{}", serde_json::to_string_pretty(&schema)?);
         return Ok(());
     }
@@ -121,10 +134,15 @@ async fn main() -> Result<()> {
         daemon_config.daemon = true;
         daemon_config.health_port = cli.health_port;
         daemon_config.pid_file = cli.pid_file.or_else(|| Some(PidFile::standard_path()));
+        daemon_config.watch_interval_secs = cli.watch_interval;
+        daemon_config.cleanup_interval_secs = cli.cleanup_interval;
 
         // Create and start daemon service
         let config = Arc::new(config);
         let mut daemon = DaemonService::new(config, daemon_config)?;
+        for query in &cli.watch_queries {
+            daemon.watch_query(query, 10).await?;
+        }
 
         match daemon.start().await {
             Ok(()) => {
@@ -4,7 +4,7 @@
 //! It handles storage, retrieval, and validation of application configuration.
 
 use super::{Repository, RepositoryError, RepositoryResult, RepositoryStats};
-use crate::config::{Config, ConfigOverrides};
+use crate::config::{Config, ConfigOverrides, ConfigValidationError};
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -477,10 +477,24 @@ impl ConfigRepository for InMemoryConfigRepository {
         let start_time = Instant::now();
 
         // Validate config before storing
-        config.validate().map_err(|e| RepositoryError::Validation {
-            field: "config".to_string(),
-            message: format!("Configuration validation failed: {}", e),
-        })?;
+        let errors = config.validation_errors();
+        if !errors.is_empty() {
+            return Err(RepositoryError::Validation {
+                field: errors
+                    .iter()
+                    .map(ConfigValidationError::field)
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                message: format!(
+                    "Configuration validation failed: {}",
+                    errors
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>()
+                        .join("; ")
+                ),
+            });
+        }
 
         {
             let mut app_config = self.app_config.write().await;
@@ -43,6 +43,7 @@ pub mod paper;
 // Re-export the main traits and types
 pub use cache::{CacheEntry, CacheRepository, InMemoryCacheRepository};
 pub use config::{ConfigRepository, InMemoryConfigRepository};
+pub use crate::services::DuplicateStrategy;
 pub use paper::{InMemoryPaperRepository, PaperFilter, PaperQuery, PaperRepository};
 
 use crate::Result;
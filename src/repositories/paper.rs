@@ -5,6 +5,7 @@
 
 use super::{Repository, RepositoryError, RepositoryResult, RepositoryStats};
 use crate::client::PaperMetadata;
+use crate::services::{duplicates, sanitize_search_terms, DuplicateStrategy, SearchTermLimits};
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -76,6 +77,23 @@ impl PaperQuery {
         self
     }
 
+    /// Copy of the query with control characters stripped from its abstract
+    /// keywords, failing if the keywords exceed `limits`
+    pub fn sanitized(&self, limits: &SearchTermLimits) -> RepositoryResult<Self> {
+        let abstract_keywords =
+            sanitize_search_terms("abstract_keywords", &self.abstract_keywords, limits).map_err(
+                |e| RepositoryError::Validation {
+                    field: "abstract_keywords".to_string(),
+                    message: e.to_string(),
+                },
+            )?;
+
+        Ok(Self {
+            abstract_keywords,
+            ..self.clone()
+        })
+    }
+
     /// Set result limit
     pub fn limit(mut self, limit: usize) -> Self {
         self.limit = Some(limit);
@@ -162,6 +180,13 @@ pub trait PaperRepository: Repository {
 
     /// Check if a paper exists by DOI
     async fn exists(&self, doi: &str) -> RepositoryResult<bool>;
+
+    /// Find groups of records that likely describe the same paper. Only
+    /// groups with at least two records are returned.
+    async fn find_duplicates(
+        &self,
+        strategy: DuplicateStrategy,
+    ) -> RepositoryResult<Vec<Vec<PaperMetadata>>>;
 }
 
 /// In-memory implementation of PaperRepository for testing and caching
@@ -173,6 +198,8 @@ pub struct InMemoryPaperRepository {
     stats: Arc<RwLock<RepositoryStats>>,
     /// Creation timestamp for ordering
     creation_timestamps: Arc<RwLock<HashMap<String, Instant>>>,
+    /// Limits applied to query abstract keywords
+    term_limits: SearchTermLimits,
 }
 
 impl InMemoryPaperRepository {
@@ -182,9 +209,16 @@ impl InMemoryPaperRepository {
             papers: Arc::new(RwLock::new(HashMap::new())),
             stats: Arc::new(RwLock::new(RepositoryStats::new())),
             creation_timestamps: Arc::new(RwLock::new(HashMap::new())),
+            term_limits: SearchTermLimits::default(),
         }
     }
 
+    /// Use `limits` for abstract keywords instead of the defaults
+    pub fn with_term_limits(mut self, limits: SearchTermLimits) -> Self {
+        self.term_limits = limits;
+        self
+    }
+
     /// Normalize DOI for consistent storage and retrieval
     fn normalize_doi(&self, doi: &str) -> String {
         doi.trim().to_lowercase()
@@ -486,6 +520,7 @@ impl PaperRepository for InMemoryPaperRepository {
         filter: &PaperFilter,
     ) -> RepositoryResult<Vec<PaperMetadata>> {
         let start_time = Instant::now();
+        let query = &query.sanitized(&self.term_limits)?;
 
         let papers = self.papers.read().await;
 
@@ -655,6 +690,30 @@ impl PaperRepository for InMemoryPaperRepository {
         let papers = self.papers.read().await;
         Ok(papers.contains_key(&normalized_doi))
     }
+
+    async fn find_duplicates(
+        &self,
+        strategy: DuplicateStrategy,
+    ) -> RepositoryResult<Vec<Vec<PaperMetadata>>> {
+        let start_time = Instant::now();
+
+        let papers = self.papers.read().await;
+        let mut records: Vec<(&String, &PaperMetadata)> = papers.iter().collect();
+        // Sort by key so groups come back in a stable order
+        records.sort_by(|a, b| a.0.cmp(b.0));
+
+        let records: Vec<PaperMetadata> =
+            records.into_iter().map(|(_, paper)| paper.clone()).collect();
+        let groups = duplicates::find_duplicates(&records, strategy);
+
+        self.record_operation(start_time, true).await;
+        debug!(
+            "Found {} duplicate groups using {:?}",
+            groups.len(),
+            strategy
+        );
+        Ok(groups)
+    }
 }
 
 #[cfg(test)]
@@ -666,11 +725,14 @@ mod tests {
             doi: doi.to_string(),
             title: Some(format!("Test Paper {}", doi)),
             authors: vec!["Test Author".to_string()],
+            raw_authors: Vec::new(),
             journal: Some("Test Journal".to_string()),
             year: Some(2023),
             abstract_text: Some("This is a test abstract".to_string()),
             pdf_url: Some("https://example.com/paper.pdf".to_string()),
             file_size: Some(1024),
+            license: None,
+            is_open_access: None,
         }
     }
 
@@ -768,6 +830,29 @@ mod tests {
         assert_eq!(count, 3);
     }
 
+    #[tokio::test]
+    async fn test_search_abstract_keywords_sanitized_and_bounded() {
+        let repo = InMemoryPaperRepository::new().with_term_limits(SearchTermLimits {
+            max_terms: 2,
+            max_term_length: 50,
+        });
+        repo.store(&create_test_paper("10.1000/keywords"))
+            .await
+            .unwrap();
+
+        let query = PaperQuery::new().with_abstract_keyword("te\u{0}st\u{7}");
+        assert_eq!(repo.search(&query).await.unwrap().len(), 1);
+
+        let query = PaperQuery::new()
+            .with_abstract_keyword("one")
+            .with_abstract_keyword("two")
+            .with_abstract_keyword("three");
+        assert!(matches!(
+            repo.search(&query).await,
+            Err(RepositoryError::Validation { field, .. }) if field == "abstract_keywords"
+        ));
+    }
+
     #[tokio::test]
     async fn test_repository_stats() {
         let repo = InMemoryPaperRepository::new();
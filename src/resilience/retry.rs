@@ -83,6 +83,8 @@ pub struct RetryPolicy {
     fast_config: RetryConfig,
     slow_config: RetryConfig,
     rate_limited_config: RetryConfig,
+    /// Only retry DNS and connection failures
+    connection_failures_only: bool,
 }
 
 impl Default for RetryPolicy {
@@ -92,14 +94,30 @@ impl Default for RetryPolicy {
             fast_config: RetryConfig::fast(),
             slow_config: RetryConfig::slow(),
             rate_limited_config: RetryConfig::rate_limited(),
+            connection_failures_only: false,
         }
     }
 }
 
 impl RetryPolicy {
+    /// Policy that retries only requests which never reached the server (DNS
+    /// and connection failures), using fast backoff. Safe to wrap around
+    /// non-idempotent requests and calls that already have their own timeout.
+    #[must_use]
+    pub fn connection_failures() -> Self {
+        Self {
+            connection_failures_only: true,
+            ..Self::default()
+        }
+    }
+
     /// Get retry config based on error
     #[must_use]
-    pub const fn config_for_error(&self, error: &Error) -> Option<&RetryConfig> {
+    pub fn config_for_error(&self, error: &Error) -> Option<&RetryConfig> {
+        if self.connection_failures_only && !error.is_connection_failure() {
+            return None;
+        }
+
         match error.category() {
             ErrorCategory::Permanent => None, // Don't retry permanent errors
             ErrorCategory::CircuitBreaker => None, // Don't retry circuit breaker errors
@@ -255,6 +273,7 @@ where
         fast_config: config.clone(),
         slow_config: config.clone(),
         rate_limited_config: config,
+        connection_failures_only: false,
     };
 
     retry_with_policy(operation, &policy, operation_name).await
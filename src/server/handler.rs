@@ -1,22 +1,39 @@
+use crate::client::markdown::{render_papers_markdown, render_papers_template, MarkdownOptions};
+use crate::client::ProviderCapabilities;
+use crate::server::messages::MessageCatalog;
 use crate::tools::{
     bibliography::BibliographyInput,
+    bibtex_validation::{BibtexValidationInput, EntryValidationStatus},
+    cleanup::{CleanupDownloadsInput, DedupeDownloadsInput},
     code_search::CodeSearchInput,
     download::{
         BatchDownloadInput as ActualBatchDownloadInput, DownloadInput as ActualDownloadInput,
+        RepairDownloadInput, VerifyFileInput, VerifyFileResult,
     },
-    metadata::MetadataInput as ActualMetadataInput,
-    search::{SearchInput as ActualSearchInput, SearchResult},
+    export::ExportLibraryInput,
+    library::FindDuplicatesInput,
+    metadata::{MetadataInput as ActualMetadataInput, MetadataOutputFormat, MetadataResult},
+    paper_text::PaperTextInput,
+    recategorize::RecategorizeDownloadsInput,
+    references::ReferenceExtractionInput,
+    resolve_doi::ResolveDoiInput,
+    search::{PaperResult, SearchInput as ActualSearchInput, SearchResult},
+    search_diff::SearchDiffInput,
+    signals::PaperSignalsInput,
 };
 use crate::{
-    BibliographyTool, CodeSearchTool, Config, DownloadTool, MetaSearchClient, MetadataExtractor,
-    Result, SearchTool,
+    BibliographyTool, BibtexValidationTool, CleanupTool, CodeSearchTool, Config, DownloadTool,
+    ExportLibraryTool, LibraryTool, MetaSearchClient, MetadataExtractor, PaperMetadata,
+    PaperSignalsTool, PaperTextTool, RecategorizeTool, ReferenceExtractionTool, ResolveDoiTool,
+    Result, SearchDiffTool, SearchTool,
 };
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
 use chrono::Utc;
 use rmcp::{
     model::{
         CallToolRequestParam, CallToolResult, Content, Implementation, InitializeRequestParam,
         InitializeResult, ListToolsResult, PaginatedRequestParam, ProtocolVersion,
-        ServerCapabilities, ServerInfo, Tool,
+        ResourceContents, ServerCapabilities, ServerInfo, Tool,
     },
     service::{RequestContext, RoleServer},
     ErrorData, ServerHandler,
@@ -24,13 +41,16 @@ use rmcp::{
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::{
-    collections::HashMap,
+    cmp::Reverse,
+    collections::{BTreeMap, HashMap},
     future::Future,
+    path::Path,
     sync::Arc,
     time::{Duration, SystemTime},
 };
 use tokio::sync::RwLock;
-use tracing::{debug, info, instrument};
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info, instrument, warn};
 
 // Tool input structures
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -67,49 +87,146 @@ struct CategoryCacheEntry {
 }
 
 /// Main MCP server handler implementing rmcp
-#[derive(Debug)]
 pub struct ResearchServerHandler {
     #[allow(dead_code)]
     config: Arc<Config>,
     search_tool: Arc<SearchTool>,
     download_tool: Arc<DownloadTool>,
     metadata_extractor: Arc<MetadataExtractor>,
+    reference_extraction_tool: Arc<ReferenceExtractionTool>,
     code_search_tool: Arc<CodeSearchTool>,
     bibliography_tool: Arc<BibliographyTool>,
+    bibtex_validation_tool: Arc<BibtexValidationTool>,
+    paper_signals_tool: Arc<PaperSignalsTool>,
+    search_diff_tool: Arc<SearchDiffTool>,
+    resolve_doi_tool: Arc<ResolveDoiTool>,
+    paper_text_tool: Arc<PaperTextTool>,
+    cleanup_tool: Arc<CleanupTool>,
+    recategorize_tool: Arc<RecategorizeTool>,
+    library_tool: Arc<LibraryTool>,
+    export_library_tool: Arc<ExportLibraryTool>,
+    /// Download and search messages in the configured language
+    messages: Arc<MessageCatalog>,
+    /// Meta-search client shared by the search, download and validation tools
+    meta_client: Arc<MetaSearchClient>,
     /// Cache of DOI -> Category mappings from recent searches
     category_cache: Arc<RwLock<HashMap<String, CategoryCacheEntry>>>,
 }
 
+impl std::fmt::Debug for ResearchServerHandler {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ResearchServerHandler")
+            .field("config", &self.config)
+            .field("search_tool", &self.search_tool)
+            .field("download_tool", &self.download_tool)
+            .field("metadata_extractor", &self.metadata_extractor)
+            .field("reference_extraction_tool", &self.reference_extraction_tool)
+            .field("code_search_tool", &self.code_search_tool)
+            .field("bibliography_tool", &self.bibliography_tool)
+            .field("bibtex_validation_tool", &self.bibtex_validation_tool)
+            .field("paper_signals_tool", &self.paper_signals_tool)
+            .field("search_diff_tool", &self.search_diff_tool)
+            .field("resolve_doi_tool", &self.resolve_doi_tool)
+            .field("paper_text_tool", &self.paper_text_tool)
+            .field("cleanup_tool", &self.cleanup_tool)
+            .field("recategorize_tool", &self.recategorize_tool)
+            .field("library_tool", &self.library_tool)
+            .field("export_library_tool", &self.export_library_tool)
+            .field("messages", &self.messages)
+            .field("meta_client", &"MetaSearchClient")
+            .field("category_cache", &self.category_cache)
+            .finish()
+    }
+}
+
 impl ResearchServerHandler {
     pub fn new(config: Arc<Config>) -> Result<Self> {
-        info!("Initializing Research MCP server handler");
-
         // Initialize MetaSearch client with config
         let meta_config = crate::client::MetaSearchConfig::from_config(&config);
         let client = Arc::new(MetaSearchClient::new((*config).clone(), meta_config)?);
+        Self::with_meta_client(config, client)
+    }
+
+    /// Create a handler whose tools search through `client` instead of a
+    /// client built from config
+    pub fn with_meta_client(config: Arc<Config>, client: Arc<MetaSearchClient>) -> Result<Self> {
+        info!("Initializing Research MCP server handler");
+
+        // Initialize search tool (shares the meta-search client)
+        let search_tool =
+            Arc::new(SearchTool::new(config.clone())?.with_meta_client(client.clone()));
 
-        // Initialize search tool
-        let search_tool = SearchTool::new(config.clone())?;
+        // Initialize search diff tool (shares the search tool)
+        let search_diff_tool =
+            SearchDiffTool::new(search_tool.clone(), config.search_diff.directory.clone());
+
+        // Initialize BibTeX validation tool (shares the meta-search client)
+        let bibtex_validation_tool = BibtexValidationTool::new(client.clone());
+
+        // Initialize DOI lookup tool (shares the meta-search client)
+        let resolve_doi_tool = ResolveDoiTool::new(client.clone());
 
         // Initialize download tool
-        let download_tool = DownloadTool::new(client, config.clone())?;
+        let download_tool = Arc::new(DownloadTool::new(client.clone(), config.clone())?);
+
+        // Initialize paper text tool (shares the download tool)
+        let paper_text_tool = PaperTextTool::new(download_tool.clone());
 
         // Initialize metadata extractor
         let metadata_extractor = MetadataExtractor::new(config.clone())?;
 
+        // Initialize reference extraction tool
+        let reference_extraction_tool = ReferenceExtractionTool::new(config.clone());
+
         // Initialize code search tool
         let code_search_tool = CodeSearchTool::new(config.clone())?;
 
         // Initialize bibliography tool
         let bibliography_tool = BibliographyTool::new(config.clone())?;
 
+        // Initialize paper signals tool
+        let paper_signals_tool = PaperSignalsTool::new(config.clone())?;
+
+        // Initialize download cleanup tool
+        let cleanup_tool = CleanupTool::new(config.clone());
+
+        // Initialize download re-categorization tool
+        let recategorize_tool = RecategorizeTool::new(config.clone())?;
+
+        // Initialize library query tool
+        let library_tool = LibraryTool::new(config.clone());
+
+        // Initialize library export tool
+        let export_library_tool = ExportLibraryTool::new(config.clone());
+
+        // A broken catalog shouldn't keep the server from starting
+        let messages = MessageCatalog::load(&config.locale).unwrap_or_else(|e| {
+            warn!(
+                "Failed to load messages for locale '{}': {}",
+                config.locale.language, e
+            );
+            MessageCatalog::default()
+        });
+
         Ok(Self {
             config,
-            search_tool: Arc::new(search_tool),
-            download_tool: Arc::new(download_tool),
+            search_tool,
+            download_tool,
             metadata_extractor: Arc::new(metadata_extractor),
+            reference_extraction_tool: Arc::new(reference_extraction_tool),
             code_search_tool: Arc::new(code_search_tool),
             bibliography_tool: Arc::new(bibliography_tool),
+            bibtex_validation_tool: Arc::new(bibtex_validation_tool),
+            paper_signals_tool: Arc::new(paper_signals_tool),
+            search_diff_tool: Arc::new(search_diff_tool),
+            resolve_doi_tool: Arc::new(resolve_doi_tool),
+            paper_text_tool: Arc::new(paper_text_tool),
+            cleanup_tool: Arc::new(cleanup_tool),
+            recategorize_tool: Arc::new(recategorize_tool),
+            library_tool: Arc::new(library_tool),
+            export_library_tool: Arc::new(export_library_tool),
+            messages: Arc::new(messages),
+            meta_client: client,
             category_cache: Arc::new(RwLock::new(HashMap::new())),
         })
     }
@@ -121,6 +238,529 @@ impl ResearchServerHandler {
         Ok(())
     }
 
+    /// The meta-search client shared by the tools
+    #[must_use]
+    pub fn meta_client(&self) -> Arc<MetaSearchClient> {
+        Arc::clone(&self.meta_client)
+    }
+
+    /// Register the hooks that persist this handler's state on shutdown
+    pub fn register_flush_hooks(&self, hooks: &super::FlushHooks) {
+        // Keep the per-host request times so a restart doesn't burst
+        let client = Arc::clone(&self.meta_client);
+        hooks.register("rate limit state", move || async move {
+            client.save_rate_limit_state()
+        });
+    }
+
+    /// Health-check the search providers in the background so the first
+    /// search skips providers that are down
+    pub fn spawn_provider_warm_up(&self) {
+        let client = Arc::clone(&self.meta_client);
+        tokio::spawn(async move {
+            client.warm_up().await;
+        });
+    }
+
+    /// Run the `search_papers` tool with its call arguments
+    async fn search_papers(
+        &self,
+        args: serde_json::Map<String, serde_json::Value>,
+        cancel: &CancellationToken,
+    ) -> std::result::Result<CallToolResult, ErrorData> {
+        // Simple parsing for simplified schema
+        let query = args.get("query").and_then(|v| v.as_str()).ok_or_else(|| {
+            ErrorData::invalid_params("Missing required 'query' parameter".to_string(), None)
+        })?;
+        let limit = args
+            .get("limit")
+            .and_then(serde_json::Value::as_u64)
+            .unwrap_or(10) as u32;
+        let require_pdf = args
+            .get("require_pdf")
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+        let verify = args
+            .get("verify")
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+        let min_year = args
+            .get("min_year")
+            .and_then(serde_json::Value::as_u64)
+            .and_then(|year| u32::try_from(year).ok());
+        let venue = args
+            .get("venue")
+            .and_then(serde_json::Value::as_str)
+            .map(str::to_string);
+        let year = args
+            .get("year")
+            .and_then(serde_json::Value::as_u64)
+            .and_then(|year| u32::try_from(year).ok());
+        let include_timings = args
+            .get("include_timings")
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+        let explain_relevance = args
+            .get("explain_relevance")
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+        let abstract_required = args
+            .get("abstract_required")
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+        let abstract_keywords = args
+            .get("abstract_keywords")
+            .map(|v| serde_json::from_value(v.clone()))
+            .transpose()
+            .map_err(|e| {
+                ErrorData::invalid_params(format!("Invalid abstract_keywords: {e}"), None)
+            })?
+            .unwrap_or_default();
+        let group_by_source = args
+            .get("group_by_source")
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+        let search_type = args
+            .get("search_type")
+            .map(|v| serde_json::from_value(v.clone()))
+            .transpose()
+            .map_err(|e| ErrorData::invalid_params(format!("Invalid search_type: {e}"), None))?
+            .unwrap_or_default();
+
+        let input = ActualSearchInput {
+            query: query.to_string(),
+            search_type,
+            limit,
+            offset: 0,
+            require_pdf,
+            verify,
+            min_year,
+            abstract_required,
+            venue,
+            year,
+            abstract_keywords,
+        };
+
+        let results = self
+            .search_tool
+            .search_papers_with_cancel(input, cancel)
+            .await
+            .map_err(|e| ErrorData::internal_error(format!("Search failed: {e}"), None))?;
+
+        // Cache the category information for each paper
+        self.cache_paper_categories(&results).await;
+
+        let sections = if group_by_source {
+            render_source_sections(
+                &results.papers,
+                self.config.meta_search.abstract_min_relevance,
+            )
+        } else {
+            Vec::new()
+        };
+        let mut text = if group_by_source {
+            let mut summary = self.messages.format(
+                "search.found_by_source",
+                &[
+                    ("count", &results.returned_count.to_string()),
+                    ("query", &results.query),
+                    ("source_count", &sections.len().to_string()),
+                ],
+            );
+            if include_timings {
+                summary.push_str(&self.messages.format(
+                    "search.timings_plain",
+                    &[("timings", &format_search_timings(&results.timings_ms))],
+                ));
+            }
+            summary
+        } else {
+            render_search_output(
+                &results,
+                &self.config.output.search_template,
+                self.config.meta_search.abstract_min_relevance,
+                include_timings,
+                &self.messages,
+            )
+        };
+        if let Some(warning) =
+            incomplete_results_warning(&results, self.config.meta_search.min_successful_providers)
+        {
+            text.push_str(&format!("\n\n{warning}"));
+        }
+        if explain_relevance {
+            text.push_str(&format_score_breakdowns(&results.papers).map_or_else(
+                || "\n\nRelevance scoring is disabled (meta_search.relevance_scoring)".to_string(),
+                |breakdowns| format!("\n\nRelevance:\n{breakdowns}"),
+            ));
+        }
+
+        let mut content = vec![Content::text(text)];
+        content.extend(sections.into_iter().map(Content::text));
+        Ok(CallToolResult {
+            content: Some(content),
+            structured_content: None,
+            is_error: Some(false),
+        })
+    }
+
+    /// How a downloaded file's path appears in tool output: relative to the
+    /// download directory with `downloads.report_relative_paths`, otherwise
+    /// in full
+    fn report_path(&self, path: &Path) -> String {
+        if !self.config.downloads.report_relative_paths {
+            return path.display().to_string();
+        }
+        // Files outside the download directory are shown by name only
+        path.strip_prefix(&self.config.downloads.directory)
+            .ok()
+            .or_else(|| path.file_name().map(Path::new))
+            .unwrap_or(path)
+            .display()
+            .to_string()
+    }
+
+    /// Run the `download_paper` tool with its call arguments
+    async fn download_paper(
+        &self,
+        args: serde_json::Map<String, serde_json::Value>,
+    ) -> std::result::Result<CallToolResult, ErrorData> {
+        // Simple parsing for simplified schema
+        let doi = args.get("doi").and_then(|v| v.as_str()).ok_or_else(|| {
+            ErrorData::invalid_params("Missing required 'doi' parameter".to_string(), None)
+        })?;
+        let filename = args
+            .get("filename")
+            .and_then(|v| v.as_str())
+            .map(ToString::to_string);
+        let title = args
+            .get("title")
+            .and_then(|v| v.as_str())
+            .map(ToString::to_string);
+        let write_sidecar = args
+            .get("write_sidecar")
+            .and_then(serde_json::Value::as_bool);
+        let verify_integrity = args
+            .get("verify_integrity")
+            .and_then(serde_json::Value::as_bool);
+        let inline = args
+            .get("inline")
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+
+        // Look up category from recent search results
+        let category = self.get_cached_category(doi).await;
+
+        let input = ActualDownloadInput {
+            doi: Some(doi.to_string()),
+            url: None,
+            filename,
+            directory: None,
+            category,
+            overwrite: false,
+            verify_integrity,
+            title,
+            write_sidecar,
+            headers: None,
+        };
+
+        debug!("Attempting download with input: {:?}", input);
+        match self.download_tool.download_paper(input).await {
+            Ok(result) => {
+                debug!("Download result received: {:?}", result.status);
+                debug!(
+                    "File size: {:?}, file path: {:?}",
+                    result.file_size, result.file_path
+                );
+
+                // Validate that the file actually has content
+                let file_size = result.file_size.unwrap_or(0);
+                if file_size == 0 {
+                    debug!("Download succeeded but file size is 0 - cleaning up");
+                    // Clean up zero-byte file if it exists
+                    if let Some(file_path) = &result.file_path {
+                        if file_path.exists() {
+                            debug!("Removing zero-byte file: {:?}", file_path);
+                            let _ = std::fs::remove_file(file_path);
+                        }
+                    }
+                    Ok(CallToolResult {
+                        content: Some(vec![Content::text(self.messages.format(
+                            "download.empty",
+                            &[
+                                ("doi", doi),
+                                ("download_id", &result.download_id),
+                                ("seconds", &format!("{:.2}", result.duration_seconds)),
+                                ("status", &format!("{:?}", result.status)),
+                            ],
+                        ))]),
+                        structured_content: None,
+                        is_error: Some(true),
+                    })
+                } else {
+                    debug!("Download successful - file size: {} bytes", file_size);
+                    let duration_info = if result.duration_seconds > 0.0 {
+                        self.messages.format(
+                            "download.timing",
+                            &[
+                                ("seconds", &format!("{:.1}", result.duration_seconds)),
+                                (
+                                    "speed_kbps",
+                                    &format!("{:.1}", result.average_speed as f64 / 1024.0),
+                                ),
+                            ],
+                        )
+                    } else {
+                        String::new()
+                    };
+
+                    let hash_info = result.sha256_hash.as_ref().map_or_else(
+                        || self.messages.format("download.hash_skipped", &[]),
+                        |h| self.messages.format("download.hash", &[("hash", &h[..16])]),
+                    );
+
+                    let source_info = result
+                        .source_provider
+                        .as_ref()
+                        .map(|source| {
+                            self.messages
+                                .format("download.source", &[("source", source)])
+                        })
+                        .unwrap_or_default();
+
+                    let file = result
+                        .file_path
+                        .as_deref()
+                        .map_or("Unknown".to_string(), |p| self.report_path(p));
+                    let mut content = vec![Content::text(format!(
+                        "{}{}{}{}",
+                        self.messages.format(
+                            "download.success",
+                            &[
+                                ("file", &file),
+                                ("size_kb", &(file_size / 1024).to_string())
+                            ],
+                        ),
+                        source_info,
+                        duration_info,
+                        hash_info
+                    ))];
+                    if inline {
+                        if let Some(file_path) = &result.file_path {
+                            content.push(
+                                inline_pdf_content(
+                                    file_path,
+                                    &file,
+                                    file_size,
+                                    self.config.downloads.inline_max_size_mb,
+                                    &self.messages,
+                                )
+                                .await,
+                            );
+                        }
+                    }
+
+                    // The text may show a shortened path; the full one stays here
+                    Ok(CallToolResult {
+                        content: Some(content),
+                        structured_content: serde_json::to_value(&result).ok(),
+                        is_error: Some(false),
+                    })
+                }
+            }
+            Err(e) => {
+                debug!("Download failed with error: {}", e);
+                debug!("Error type: {:?}", std::any::type_name_of_val(&e));
+
+                // Generate timestamp for debugging
+                let timestamp = Utc::now().format("%Y-%m-%d %H:%M:%S UTC").to_string();
+
+                // Return a helpful error message with debug information
+                let error_msg = if let crate::Error::PaywalledPaper {
+                    metadata,
+                    providers_checked,
+                } = &e
+                {
+                    DownloadTool::paywalled_message(metadata, *providers_checked)
+                } else {
+                    let msg = e.to_string();
+                    let key = if e.is_not_found() {
+                        "download.not_found"
+                    } else if e.is_network() {
+                        "download.network_error"
+                    } else if e.is_permission_denied() {
+                        "download.permission_denied"
+                    } else {
+                        "download.failed"
+                    };
+                    self.messages.format(
+                        key,
+                        &[
+                            ("doi", doi),
+                            ("time", &timestamp),
+                            ("error_type", std::any::type_name_of_val(&e)),
+                            ("error", &msg),
+                        ],
+                    )
+                };
+                Ok(CallToolResult {
+                    content: Some(vec![Content::text(error_msg)]),
+                    structured_content: None,
+                    is_error: Some(true),
+                })
+            }
+        }
+    }
+
+    /// Run the `extract_metadata` tool with its call arguments. The full
+    /// result is returned as structured content; the text follows the
+    /// requested `output_format`.
+    async fn extract_metadata(
+        &self,
+        args: serde_json::Map<String, serde_json::Value>,
+    ) -> std::result::Result<CallToolResult, ErrorData> {
+        let input: ActualMetadataInput = serde_json::from_value(serde_json::Value::Object(args))
+            .map_err(|e| ErrorData::invalid_params(format!("Invalid metadata input: {e}"), None))?;
+        let output_format = input.output_format;
+
+        let result = self
+            .metadata_extractor
+            .extract_metadata(input)
+            .await
+            .map_err(|e| {
+                ErrorData::internal_error(format!("Metadata extraction failed: {e}"), None)
+            })?;
+        let structured = serde_json::to_value(&result)
+            .map_err(|e| ErrorData::internal_error(format!("Serialization failed: {e}"), None))?;
+
+        let (text, is_error) = match output_format {
+            MetadataOutputFormat::Json => (
+                serde_json::to_string_pretty(&result).map_err(|e| {
+                    ErrorData::internal_error(format!("Serialization failed: {e}"), None)
+                })?,
+                false,
+            ),
+            MetadataOutputFormat::Bibtex => result.metadata.as_ref().map_or_else(
+                || {
+                    (
+                        format!(
+                            "⚠️ No metadata to format as BibTeX for {}: {}",
+                            result.file_path,
+                            result.error.as_deref().unwrap_or("extraction failed")
+                        ),
+                        true,
+                    )
+                },
+                |metadata| (metadata.to_bibtex(), false),
+            ),
+            MetadataOutputFormat::Summary => (render_metadata_summary(&result), false),
+        };
+
+        Ok(CallToolResult {
+            content: Some(vec![Content::text(text)]),
+            structured_content: Some(structured),
+            is_error: Some(is_error),
+        })
+    }
+
+    /// Run the `resolve_doi` tool with its call arguments. The text is just
+    /// the DOI when one paper matches, so it can be pasted into a citation.
+    async fn resolve_doi(
+        &self,
+        args: serde_json::Map<String, serde_json::Value>,
+    ) -> std::result::Result<CallToolResult, ErrorData> {
+        let input: ResolveDoiInput = serde_json::from_value(serde_json::Value::Object(args))
+            .map_err(|e| {
+                ErrorData::invalid_params(format!("Invalid resolve DOI input: {e}"), None)
+            })?;
+
+        let resolved = self
+            .resolve_doi_tool
+            .resolve(input)
+            .await
+            .map_err(|e| match e {
+                crate::Error::InvalidInput { .. } => ErrorData::invalid_params(e.to_string(), None),
+                _ => ErrorData::internal_error(format!("DOI lookup failed: {e}"), None),
+            })?;
+
+        let output = if let Some(doi) = &resolved.doi {
+            doi.clone()
+        } else if resolved.candidates.is_empty() {
+            format!("No DOI found for '{}'", resolved.title)
+        } else {
+            let mut output = format!(
+                "No single match for '{}'; closest candidates:\n",
+                resolved.title
+            );
+            for (i, candidate) in resolved.candidates.iter().enumerate() {
+                output.push_str(&format!(
+                    "{}. {} - {}{} (score {:.2})\n",
+                    i + 1,
+                    candidate.doi,
+                    candidate.title,
+                    candidate
+                        .year
+                        .map_or_else(String::new, |year| format!(", {year}")),
+                    candidate.score
+                ));
+            }
+            output
+        };
+
+        Ok(CallToolResult {
+            content: Some(vec![Content::text(output)]),
+            structured_content: serde_json::to_value(&resolved).ok(),
+            is_error: Some(false),
+        })
+    }
+
+    /// Run the `get_paper_text` tool with its call arguments
+    async fn get_paper_text(
+        &self,
+        args: serde_json::Map<String, serde_json::Value>,
+    ) -> std::result::Result<CallToolResult, ErrorData> {
+        let input: PaperTextInput = serde_json::from_value(serde_json::Value::Object(args))
+            .map_err(|e| {
+                ErrorData::invalid_params(format!("Invalid paper text input: {e}"), None)
+            })?;
+
+        let result = self
+            .paper_text_tool
+            .get_text(input)
+            .await
+            .map_err(|e| match e {
+                crate::Error::InvalidInput { .. } => ErrorData::invalid_params(e.to_string(), None),
+                _ => ErrorData::internal_error(format!("Failed to get paper text: {e}"), None),
+            })?;
+
+        let mut output = format!(
+            "📄 {}\n",
+            result
+                .title
+                .as_deref()
+                .or(result.doi.as_deref())
+                .unwrap_or("Paper text")
+        );
+        output.push_str(&format!(
+            "Characters {}-{} of {}\n\n",
+            result.offset,
+            result.offset + result.text.chars().count(),
+            result.total_chars
+        ));
+        output.push_str(&result.text);
+        if let Some(next_offset) = result.next_offset {
+            output.push_str(&format!(
+                "\n\n[Text continues; call again with offset {next_offset} for more]"
+            ));
+        }
+
+        Ok(CallToolResult {
+            content: Some(vec![Content::text(output)]),
+            structured_content: serde_json::to_value(&result).ok(),
+            is_error: Some(false),
+        })
+    }
+
     /// Cache category information from search results
     async fn cache_paper_categories(&self, results: &SearchResult) {
         let mut cache = self.category_cache.write().await;
@@ -244,12 +884,66 @@ impl ServerHandler for ResearchServerHandler {
                                 "type": "string",
                                 "description": "Query string - can be DOI, title, or author name"
                             },
+                            "search_type": {
+                                "type": "string",
+                                "enum": ["auto", "doi", "title", "author", "author_year", "full_text"],
+                                "description": "How to interpret the query; full_text searches the body text of papers",
+                                "default": "auto"
+                            },
                             "limit": {
                                 "type": "integer",
                                 "description": "Maximum number of results to return",
                                 "default": 10,
                                 "minimum": 1,
                                 "maximum": 100
+                            },
+                            "require_pdf": {
+                                "type": "boolean",
+                                "description": "Only return papers with a downloadable PDF",
+                                "default": false
+                            },
+                            "verify": {
+                                "type": "boolean",
+                                "description": "With require_pdf, look up PDFs across all providers for papers without a PDF link (slower)",
+                                "default": false
+                            },
+                            "abstract_required": {
+                                "type": "boolean",
+                                "description": "Only return papers with an abstract; providers that never return abstracts are queried last",
+                                "default": false
+                            },
+                            "abstract_keywords": {
+                                "type": "array",
+                                "items": { "type": "string" },
+                                "description": "Only return papers whose abstract mentions at least one of these keywords (case-insensitive)"
+                            },
+                            "min_year": {
+                                "type": "integer",
+                                "description": "Drop papers published before this year (overrides the server default; 0 disables the cutoff). Papers without a year are kept.",
+                                "minimum": 0
+                            },
+                            "venue": {
+                                "type": "string",
+                                "description": "Scope OpenReview results to a venue: a group ID like 'ICLR.cc/2024/Conference' or a short name (NeurIPS, ICLR, ICML)"
+                            },
+                            "year": {
+                                "type": "integer",
+                                "description": "Conference year for OpenReview results; with venue it selects that year's conference"
+                            },
+                            "include_timings": {
+                                "type": "boolean",
+                                "description": "Append how long each provider took",
+                                "default": false
+                            },
+                            "explain_relevance": {
+                                "type": "boolean",
+                                "description": "Append each paper's relevance score components (requires meta_search.relevance_scoring)",
+                                "default": false
+                            },
+                            "group_by_source": {
+                                "type": "boolean",
+                                "description": "List papers in one section per provider that returned them instead of a single ranked list",
+                                "default": false
                             }
                         },
                         "required": ["query"]
@@ -270,6 +964,23 @@ impl ServerHandler for ResearchServerHandler {
                             "filename": {
                                 "type": "string", 
                                 "description": "Optional custom filename for the downloaded PDF"
+                            },
+                            "title": {
+                                "type": "string",
+                                "description": "Optional paper title, used to suggest the correct DOI if this one is not found"
+                            },
+                            "write_sidecar": {
+                                "type": "boolean",
+                                "description": "Write the paper's metadata to a .json file next to the PDF (default: downloads.write_sidecar from the config)"
+                            },
+                            "verify_integrity": {
+                                "type": "boolean",
+                                "description": "Compute the SHA256 of the downloaded file (default: downloads.verify_integrity_default from the config)"
+                            },
+                            "inline": {
+                                "type": "boolean",
+                                "description": "Also return the PDF base64-encoded in the result, for clients that can't read the server's download directory. Only files up to downloads.inline_max_size_mb are returned",
+                                "default": false
                             }
                         },
                         "required": ["doi"]
@@ -284,13 +995,69 @@ impl ServerHandler for ResearchServerHandler {
                     output_schema: None,
                     annotations: None,
                 },
+                Tool {
+                    name: "repair_download".into(),
+                    description: Some("Check a downloaded PDF (by DOI or file path) for corruption such as a missing %PDF header or %%EOF marker, and re-download it if needed. The file is only replaced once the new copy verifies.".into()),
+                    input_schema: Arc::new(serde_json::to_value(schemars::schema_for!(RepairDownloadInput)).unwrap().as_object().unwrap().clone()),
+                    output_schema: None,
+                    annotations: None,
+                },
+                Tool {
+                    name: "verify_file".into(),
+                    description: Some("Check a file on disk without re-downloading it: computes its SHA-256 (compared with expected_sha256 if given) and checks for the %PDF header and %%EOF marker.".into()),
+                    input_schema: Arc::new(serde_json::to_value(schemars::schema_for!(VerifyFileInput)).unwrap().as_object().unwrap().clone()),
+                    output_schema: None,
+                    annotations: None,
+                },
                 Tool {
                     name: "extract_metadata".into(),
-                    description: Some("Extract metadata from PDF files. Single file or batch processing (12 concurrent for batch_files array). Returns title, authors, DOI, abstract, etc.".into()),
+                    description: Some("Extract metadata from PDF files. Single file or batch processing (12 concurrent for batch_files array). Returns title, authors, DOI, abstract, etc. as structured content, with text rendered as JSON, BibTeX or a summary (output_format)".into()),
                     input_schema: Arc::new(serde_json::to_value(schemars::schema_for!(ActualMetadataInput)).unwrap().as_object().unwrap().clone()),
                     output_schema: None,
                     annotations: None,
                 },
+                Tool {
+                    name: "cleanup_downloads".into(),
+                    description: Some("Delete downloaded files older than max_age_days, then the least recently accessed files until the download directory fits in max_total_size_mb. Limits default to downloads.retention_days and downloads.retention_max_size_mb; quarantined files are never touched. Use dry_run to preview.".into()),
+                    input_schema: Arc::new(serde_json::to_value(schemars::schema_for!(CleanupDownloadsInput)).unwrap().as_object().unwrap().clone()),
+                    output_schema: None,
+                    annotations: None,
+                },
+                Tool {
+                    name: "dedupe_downloads".into(),
+                    description: Some("Find PDFs in the download directory with identical content, hashing with downloads.hash_workers workers, and delete all but the first copy of each (by path). Sidecars and quarantined files are never touched. Use dry_run to preview.".into()),
+                    input_schema: Arc::new(serde_json::to_value(schemars::schema_for!(DedupeDownloadsInput)).unwrap().as_object().unwrap().clone()),
+                    output_schema: None,
+                    annotations: None,
+                },
+                Tool {
+                    name: "recategorize_downloads".into(),
+                    description: Some("Re-run automatic categorization over downloaded papers using their metadata sidecars (.json next to each PDF) and move each PDF and sidecar into its new category directory. Name collisions get a numbered suffix; papers without a sidecar stay where they are. Use dry_run to preview.".into()),
+                    input_schema: Arc::new(serde_json::to_value(schemars::schema_for!(RecategorizeDownloadsInput)).unwrap().as_object().unwrap().clone()),
+                    output_schema: None,
+                    annotations: None,
+                },
+                Tool {
+                    name: "find_duplicates_in_repository".into(),
+                    description: Some("Find downloaded papers that likely describe the same paper, using their metadata sidecars (.json next to each PDF). Match by exact DOI (default) or by fuzzy title (min_similarity, default 0.9). Nothing is deleted.".into()),
+                    input_schema: Arc::new(serde_json::to_value(schemars::schema_for!(FindDuplicatesInput)).unwrap().as_object().unwrap().clone()),
+                    output_schema: None,
+                    annotations: None,
+                },
+                Tool {
+                    name: "export_library".into(),
+                    description: Some("Write every downloaded paper with a metadata sidecar (.json next to the PDF) to one BibTeX, RIS or CSL-JSON file for a reference manager. Papers missing fields the format requires are skipped and listed.".into()),
+                    input_schema: Arc::new(serde_json::to_value(schemars::schema_for!(ExportLibraryInput)).unwrap().as_object().unwrap().clone()),
+                    output_schema: None,
+                    annotations: None,
+                },
+                Tool {
+                    name: "extract_references".into(),
+                    description: Some("Extract the reference list (bibliography) from a downloaded PDF. Returns each reference's raw text plus DOI, year and authors where they can be detected.".into()),
+                    input_schema: Arc::new(serde_json::to_value(schemars::schema_for!(ReferenceExtractionInput)).unwrap().as_object().unwrap().clone()),
+                    output_schema: None,
+                    annotations: None,
+                },
                 Tool {
                     name: "search_code".into(),
                     description: Some("Search for code patterns within downloaded research papers using regex".into()),
@@ -305,6 +1072,61 @@ impl ServerHandler for ResearchServerHandler {
                     output_schema: None,
                     annotations: None,
                 },
+                Tool {
+                    name: "validate_bibliography".into(),
+                    description: Some("Validate a BibTeX file against academic sources. Looks up each entry's DOI, corrects mismatched titles/years, fills missing fields, and returns corrected BibTeX with a diff report.".into()),
+                    input_schema: Arc::new(serde_json::to_value(schemars::schema_for!(BibtexValidationInput)).unwrap().as_object().unwrap().clone()),
+                    output_schema: None,
+                    annotations: None,
+                },
+                Tool {
+                    name: "paper_signals".into(),
+                    description: Some("Summarize triage signals for a DOI: citation and reference counts (Semantic Scholar, OpenAlex) and open access status (Unpaywall)".into()),
+                    input_schema: Arc::new(serde_json::to_value(schemars::schema_for!(PaperSignalsInput)).unwrap().as_object().unwrap().clone()),
+                    output_schema: None,
+                    annotations: None,
+                },
+                Tool {
+                    name: "search_diff".into(),
+                    description: Some("Run a search and return only papers that are new since a previous run. Pass the result_set_id from the last run as `since`; omit it to start tracking a query.".into()),
+                    input_schema: Arc::new(serde_json::to_value(schemars::schema_for!(SearchDiffInput)).unwrap().as_object().unwrap().clone()),
+                    output_schema: None,
+                    annotations: None,
+                },
+                Tool {
+                    name: "resolve_doi".into(),
+                    description: Some("Look up the DOI of a paper from its title, optionally narrowed by an author and year. Returns the single matching DOI, or a short ranked list when the title is ambiguous; no abstracts or PDFs.".into()),
+                    input_schema: Arc::new(serde_json::to_value(schemars::schema_for!(ResolveDoiInput)).unwrap().as_object().unwrap().clone()),
+                    output_schema: None,
+                    annotations: None,
+                },
+                Tool {
+                    name: "get_paper_text".into(),
+                    description: Some("Get the text of a paper by DOI or PDF URL without saving the PDF. The PDF is fetched to a temporary file, its text extracted and the file deleted. Long texts are returned in pages; pass the returned next_offset to read on.".into()),
+                    input_schema: Arc::new(serde_json::to_value(schemars::schema_for!(PaperTextInput)).unwrap().as_object().unwrap().clone()),
+                    output_schema: None,
+                    annotations: None,
+                },
+                Tool {
+                    name: "config_info".into(),
+                    description: Some("Show which settings of the running server differ from the built-in defaults, with the default and effective value of each, and which providers yield PDFs rather than metadata only".into()),
+                    input_schema: Arc::new(serde_json::json!({
+                        "type": "object",
+                        "properties": {}
+                    }).as_object().unwrap().clone()),
+                    output_schema: None,
+                    annotations: None,
+                },
+                Tool {
+                    name: "config_schema".into(),
+                    description: Some("Return the JSON schema of the server configuration: every available setting with its description and default value".into()),
+                    input_schema: Arc::new(serde_json::json!({
+                        "type": "object",
+                        "properties": {}
+                    }).as_object().unwrap().clone()),
+                    output_schema: None,
+                    annotations: None,
+                },
             ];
 
             Ok(ListToolsResult {
@@ -314,19 +1136,25 @@ impl ServerHandler for ResearchServerHandler {
         }
     }
 
-    #[instrument(skip(self, request, _context))]
+    #[instrument(skip(self, request, context))]
     fn call_tool(
         &self,
         request: CallToolRequestParam,
-        _context: RequestContext<RoleServer>,
+        context: RequestContext<RoleServer>,
     ) -> impl Future<Output = std::result::Result<CallToolResult, ErrorData>> + Send + '_ {
         info!("Tool called: {}", request.name);
 
-        let search_tool = Arc::clone(&self.search_tool);
         let download_tool = Arc::clone(&self.download_tool);
-        let metadata_extractor = Arc::clone(&self.metadata_extractor);
+        let reference_extraction_tool = Arc::clone(&self.reference_extraction_tool);
         let code_search_tool = Arc::clone(&self.code_search_tool);
         let bibliography_tool = Arc::clone(&self.bibliography_tool);
+        let bibtex_validation_tool = Arc::clone(&self.bibtex_validation_tool);
+        let paper_signals_tool = Arc::clone(&self.paper_signals_tool);
+        let search_diff_tool = Arc::clone(&self.search_diff_tool);
+        let cleanup_tool = Arc::clone(&self.cleanup_tool);
+        let recategorize_tool = Arc::clone(&self.recategorize_tool);
+        let library_tool = Arc::clone(&self.library_tool);
+        let export_library_tool = Arc::clone(&self.export_library_tool);
 
         async move {
             match request.name.as_ref() {
@@ -348,223 +1176,287 @@ impl ServerHandler for ResearchServerHandler {
                     })
                 }
                 "search_papers" => {
-                    // Simple parsing for simplified schema
-                    let args = request.arguments.unwrap_or_default();
-                    let query = args.get("query").and_then(|v| v.as_str()).ok_or_else(|| {
+                    self.search_papers(request.arguments.unwrap_or_default(), &context.ct)
+                        .await
+                }
+                "download_paper" => {
+                    self.download_paper(request.arguments.unwrap_or_default())
+                        .await
+                }
+                "cleanup_downloads" => {
+                    let input: CleanupDownloadsInput = serde_json::from_value(
+                        serde_json::Value::Object(request.arguments.unwrap_or_default()),
+                    )
+                    .map_err(|e| {
+                        ErrorData::invalid_params(format!("Invalid cleanup input: {e}"), None)
+                    })?;
+
+                    let result = cleanup_tool.cleanup(input).await.map_err(|e| match e {
+                        crate::Error::InvalidInput { .. } => {
+                            ErrorData::invalid_params(e.to_string(), None)
+                        }
+                        _ => ErrorData::internal_error(format!("Cleanup failed: {e}"), None),
+                    })?;
+
+                    let mut output = format!(
+                        "🧹 {} {} file(s), freeing {} KB ({} KB remain)\n",
+                        if result.dry_run {
+                            "Would delete"
+                        } else {
+                            "Deleted"
+                        },
+                        result.deleted.len(),
+                        result.freed_bytes / 1024,
+                        result.remaining_bytes / 1024
+                    );
+                    for path in &result.deleted {
+                        output.push_str(&format!("\n• {}", path.display()));
+                    }
+
+                    Ok(CallToolResult {
+                        content: Some(vec![Content::text(output)]),
+                        structured_content: None,
+                        is_error: Some(false),
+                    })
+                }
+                "dedupe_downloads" => {
+                    let input: DedupeDownloadsInput = serde_json::from_value(
+                        serde_json::Value::Object(request.arguments.unwrap_or_default()),
+                    )
+                    .map_err(|e| {
+                        ErrorData::invalid_params(format!("Invalid dedupe input: {e}"), None)
+                    })?;
+
+                    let result = cleanup_tool.dedupe(input).await.map_err(|e| {
+                        ErrorData::internal_error(format!("Dedupe failed: {e}"), None)
+                    })?;
+
+                    let removed: usize = result.groups.iter().map(|g| g.removed.len()).sum();
+                    let mut output = format!(
+                        "🧹 {} {} duplicate PDF(s), freeing {} KB\n",
+                        if result.dry_run {
+                            "Would delete"
+                        } else {
+                            "Deleted"
+                        },
+                        removed,
+                        result.freed_bytes / 1024
+                    );
+                    for group in &result.groups {
+                        output.push_str(&format!("\n• Kept {}", group.kept.display()));
+                        for path in &group.removed {
+                            output.push_str(&format!("\n  - {}", path.display()));
+                        }
+                    }
+
+                    Ok(CallToolResult {
+                        content: Some(vec![Content::text(output)]),
+                        structured_content: None,
+                        is_error: Some(false),
+                    })
+                }
+                "find_duplicates_in_repository" => {
+                    let input: FindDuplicatesInput = serde_json::from_value(
+                        serde_json::Value::Object(request.arguments.unwrap_or_default()),
+                    )
+                    .map_err(|e| {
                         ErrorData::invalid_params(
-                            "Missing required 'query' parameter".to_string(),
+                            format!("Invalid duplicate search input: {e}"),
                             None,
                         )
                     })?;
-                    let limit = args
-                        .get("limit")
-                        .and_then(serde_json::Value::as_u64)
-                        .unwrap_or(10) as u32;
-
-                    let input = ActualSearchInput {
-                        query: query.to_string(),
-                        search_type: crate::tools::search::SearchType::Auto,
-                        limit,
-                        offset: 0,
-                    };
 
-                    let results = search_tool.search_papers(input).await.map_err(|e| {
-                        ErrorData::internal_error(format!("Search failed: {e}"), None)
+                    let result =
+                        library_tool
+                            .find_duplicates(input)
+                            .await
+                            .map_err(|e| match e {
+                                crate::Error::InvalidInput { .. } => {
+                                    ErrorData::invalid_params(e.to_string(), None)
+                                }
+                                _ => ErrorData::internal_error(
+                                    format!("Duplicate search failed: {e}"),
+                                    None,
+                                ),
+                            })?;
+
+                    let mut output = format!(
+                        "🔁 Found {} duplicate group(s) among {} paper(s)\n",
+                        result.groups.len(),
+                        result.scanned
+                    );
+                    for (i, group) in result.groups.iter().enumerate() {
+                        output.push_str(&format!("\n{}.", i + 1));
+                        for paper in group {
+                            output.push_str(&format!(
+                                "\n  - {} ({}) {}",
+                                paper.metadata.title.as_deref().unwrap_or("Untitled"),
+                                paper.metadata.doi,
+                                paper.path.display()
+                            ));
+                        }
+                    }
+
+                    Ok(CallToolResult {
+                        content: Some(vec![Content::text(output)]),
+                        structured_content: None,
+                        is_error: Some(false),
+                    })
+                }
+                "export_library" => {
+                    let input: ExportLibraryInput = serde_json::from_value(
+                        serde_json::Value::Object(request.arguments.unwrap_or_default()),
+                    )
+                    .map_err(|e| {
+                        ErrorData::invalid_params(format!("Invalid export input: {e}"), None)
                     })?;
 
-                    // Cache the category information for each paper
-                    self.cache_paper_categories(&results).await;
+                    let result = export_library_tool
+                        .export(input)
+                        .await
+                        .map_err(|e| match e {
+                            crate::Error::InvalidInput { .. } => {
+                                ErrorData::invalid_params(e.to_string(), None)
+                            }
+                            _ => ErrorData::internal_error(format!("Export failed: {e}"), None),
+                        })?;
+
+                    let mut output = format!(
+                        "📚 Exported {} paper(s) to {}\n",
+                        result.exported,
+                        result.path.display()
+                    );
+                    if !result.skipped.is_empty() {
+                        output.push_str(&format!(
+                            "\n⚠️ Skipped {} paper(s) missing required fields:",
+                            result.skipped.len()
+                        ));
+                        for entry in &result.skipped {
+                            output.push_str(&format!(
+                                "\n  - {} ({})",
+                                entry.doi,
+                                entry.missing.join(", ")
+                            ));
+                        }
+                    }
+
+                    Ok(CallToolResult {
+                        content: Some(vec![Content::text(output)]),
+                        structured_content: None,
+                        is_error: Some(false),
+                    })
+                }
+                "recategorize_downloads" => {
+                    let input: RecategorizeDownloadsInput = serde_json::from_value(
+                        serde_json::Value::Object(request.arguments.unwrap_or_default()),
+                    )
+                    .map_err(|e| {
+                        ErrorData::invalid_params(format!("Invalid recategorize input: {e}"), None)
+                    })?;
+
+                    let result =
+                        recategorize_tool
+                            .recategorize(input)
+                            .await
+                            .map_err(|e| match e {
+                                crate::Error::InvalidInput { .. } => {
+                                    ErrorData::invalid_params(e.to_string(), None)
+                                }
+                                _ => ErrorData::internal_error(
+                                    format!("Re-categorization failed: {e}"),
+                                    None,
+                                ),
+                            })?;
+
+                    let mut output = format!(
+                        "🗂️ {} {} paper(s) into new categories ({} already in place, {} without a category)\n",
+                        if result.dry_run { "Would move" } else { "Moved" },
+                        result.moved.len(),
+                        result.unchanged,
+                        result.skipped.len()
+                    );
+                    for file in &result.moved {
+                        output.push_str(&format!(
+                            "\n• {} → {}",
+                            file.from.display(),
+                            file.to.display()
+                        ));
+                    }
 
                     Ok(CallToolResult {
-                        content: Some(vec![Content::text(format!("📚 Found {} papers for '{}'\n\n{}\n\n💡 Tip: Papers from {} may be available for download. Very recent papers (2024-2025) might not be available yet.", 
-                            results.returned_count,
-                            results.query,
-                            results.papers.iter().enumerate().map(|(i, p)| {
-                                let doi_info = if p.metadata.doi.is_empty() {
-                                    "\n  ⚠️ No DOI available (cannot download)".to_string()
-                                } else {
-                                    format!("\n  📖 DOI: {doi}", doi = p.metadata.doi)
-                                };
-                                let source_info = format!("\n  🔍 Source: {source}", source = p.source);
-                                let year = p.metadata.year.filter(|y| *y > 0)
-                                    .map(|y| format!("\n  📅 Year: {y}"))
-                                    .unwrap_or_default();
-                                format!("{}. {} (Relevance: {:.0}%){}{}{}",
-                                    i + 1,
-                                    p.metadata.title.as_deref().unwrap_or("No title"),
-                                    p.relevance_score * 100.0,
-                                    doi_info,
-                                    source_info,
-                                    year
-                                )
-                            }).collect::<Vec<_>>().join("\n\n"),
-                            results.papers.iter().filter(|p| !p.metadata.doi.is_empty()).count()
-                        ))]),
+                        content: Some(vec![Content::text(output)]),
                         structured_content: None,
                         is_error: Some(false),
                     })
                 }
-                "download_paper" => {
-                    // Simple parsing for simplified schema
-                    let args = request.arguments.unwrap_or_default();
-                    let doi = args.get("doi").and_then(|v| v.as_str()).ok_or_else(|| {
-                        ErrorData::invalid_params(
-                            "Missing required 'doi' parameter".to_string(),
-                            None,
-                        )
+                "repair_download" => {
+                    let input: RepairDownloadInput = serde_json::from_value(
+                        serde_json::Value::Object(request.arguments.unwrap_or_default()),
+                    )
+                    .map_err(|e| {
+                        ErrorData::invalid_params(format!("Invalid repair input: {e}"), None)
                     })?;
-                    let filename = args
-                        .get("filename")
-                        .and_then(|v| v.as_str())
-                        .map(ToString::to_string);
-
-                    // Look up category from recent search results
-                    let category = self.get_cached_category(doi).await;
-
-                    let input = ActualDownloadInput {
-                        doi: Some(doi.to_string()),
-                        url: None,
-                        filename,
-                        directory: None,
-                        category,
-                        overwrite: false,
-                        verify_integrity: true,
-                    };
 
-                    debug!("Attempting download with input: {:?}", input);
-                    match download_tool.download_paper(input).await {
+                    match download_tool.repair_download(input).await {
                         Ok(result) => {
-                            debug!("Download result received: {:?}", result.status);
-                            debug!(
-                                "File size: {:?}, file path: {:?}",
-                                result.file_size, result.file_path
-                            );
-
-                            // Validate that the file actually has content
-                            let file_size = result.file_size.unwrap_or(0);
-                            if file_size == 0 {
-                                debug!("Download succeeded but file size is 0 - cleaning up");
-                                // Clean up zero-byte file if it exists
-                                if let Some(file_path) = &result.file_path {
-                                    if file_path.exists() {
-                                        debug!("Removing zero-byte file: {:?}", file_path);
-                                        let _ = std::fs::remove_file(file_path);
-                                    }
-                                }
-                                Ok(CallToolResult {
-                                    content: Some(vec![Content::text(format!("⚠️ Download failed - no content received\n\nDOI: {doi}\n\n🔍 Debug Info:\n• Download ID: {}\n• Duration: {:.2}s\n• Status: {:?}\n• File created but empty\n\nThe paper was found but no downloadable content is available. This could be because:\n• The paper is too new or recently published\n• It's behind a paywall not covered by available sources\n• The DOI might be incorrect\n• Network issues during download\n\nTry checking the publisher's website or your institutional access.",
-                                        result.download_id, result.duration_seconds, result.status))]),
-                                    structured_content: None,
-                                    is_error: Some(true),
-                                })
-                            } else {
-                                debug!("Download successful - file size: {} bytes", file_size);
-                                let duration_info = if result.duration_seconds > 0.0 {
-                                    format!(
-                                        "\n⏱️ Time: {:.1}s\n🚀 Speed: {:.1} KB/s",
-                                        result.duration_seconds,
-                                        result.average_speed as f64 / 1024.0
-                                    )
-                                } else {
-                                    String::new()
-                                };
-
-                                let hash_info = result
-                                    .sha256_hash
-                                    .map(|h| format!("\n🔐 SHA256: {}...", &h[..16]))
-                                    .unwrap_or_default();
-
-                                Ok(CallToolResult {
-                                    content: Some(vec![Content::text(format!("✅ Download successful!\n\n📄 File: {}\n📦 Size: {} KB{}{}",
-                                        result.file_path.as_ref().map_or("Unknown".to_string(), |p| p.display().to_string()),
-                                        file_size / 1024, duration_info, hash_info))]),
-                                    structured_content: None,
-                                    is_error: Some(false),
-                                })
-                            }
-                        }
-                        Err(e) => {
-                            debug!("Download failed with error: {}", e);
-                            debug!("Error type: {:?}", std::any::type_name_of_val(&e));
-
-                            // Generate timestamp for debugging
-                            let timestamp = Utc::now().format("%Y-%m-%d %H:%M:%S UTC");
-
-                            // Return a helpful error message with debug information
-                            let error_msg = match e.to_string().as_str() {
-                                msg if msg.contains("No PDF available")
-                                    || msg.contains("not found in any provider") =>
-                                {
-                                    format!("⚠️ Paper not available for download\n\n\
-                                            DOI: {doi}\n\n\
-                                            🔍 Debug Info:\n\
-                                            • Time: {}\n\
-                                            • Error: {}\n\
-                                            • All available sources checked\n\n\
-                                            This paper is not currently available through any source. This could be because:\n\
-                                            • The paper is too new (published recently)\n\
-                                            • It's from a publisher not covered by available sources\n\
-                                            • The DOI might be incorrect or malformed\n\
-                                            • Temporary service unavailability\n\n\
-                                            💡 Alternatives:\n\
-                                            • Try searching for the paper on Google Scholar\n\
-                                            • Check if your institution has access\n\
-                                            • Try arXiv or other preprint servers\n\
-                                            • Contact the authors directly\n\
-                                            • Verify the DOI is correct", timestamp, msg)
-                                }
-                                msg if msg.contains("Network")
-                                    || msg.contains("timeout")
-                                    || msg.contains("Connection") =>
-                                {
-                                    format!(
-                                        "⚠️ Network error while downloading\n\n\
-                                            DOI: {doi}\n\n\
-                                            🔍 Debug Info:\n\
-                                            • Time: {}\n\
-                                            • Error: {}\n\
-                                            • Network connectivity issue detected\n\n\
-                                            Please check your internet connection and try again.\n\
-                                            If the problem persists, the source servers may be temporarily unavailable.",
-                                        timestamp, msg
-                                    )
-                                }
-                                msg if msg.contains("Permission")
-                                    || msg.contains("Claude Desktop") =>
-                                {
-                                    format!(
-                                        "⚠️ File system permission error\n\n\
-                                            DOI: {doi}\n\n\
-                                            🔍 Debug Info:\n\
-                                            • Time: {}\n\
-                                            • Error: {}\n\n\
-                                            This appears to be a permission issue with accessing the download directory.\n\
-                                            Please check the error message for specific instructions to resolve.",
-                                        timestamp, msg
-                                    )
-                                }
-                                _ => {
-                                    format!(
-                                        "⚠️ Download failed\n\n\
-                                            DOI: {doi}\n\n\
-                                            🔍 Debug Info:\n\
-                                            • Time: {}\n\
-                                            • Error Type: {}\n\
-                                            • Error: {}\n\n\
-                                            Please try again or use a different DOI. If this error persists,\n\
-                                            it may indicate an issue with the paper source or network connectivity.",
-                                        timestamp, std::any::type_name_of_val(&e), e
-                                    )
-                                }
+                            let text = match (&result.corruption, &result.download) {
+                                (Some(corruption), Some(download)) => format!(
+                                    "🔧 Repaired {}\n\n• Problem: {}\n• New size: {} KB{}",
+                                    self.report_path(&result.file_path),
+                                    corruption,
+                                    download.file_size.unwrap_or(0) / 1024,
+                                    download
+                                        .source_provider
+                                        .as_ref()
+                                        .map(|source| format!("\n• Source: {source}"))
+                                        .unwrap_or_default()
+                                ),
+                                _ => format!(
+                                    "✅ {} looks intact - nothing to repair",
+                                    self.report_path(&result.file_path)
+                                ),
                             };
                             Ok(CallToolResult {
-                                content: Some(vec![Content::text(error_msg)]),
+                                content: Some(vec![Content::text(text)]),
                                 structured_content: None,
-                                is_error: Some(true),
+                                is_error: Some(false),
                             })
                         }
+                        Err(e) => Ok(CallToolResult {
+                            content: Some(vec![Content::text(format!(
+                                "⚠️ Repair failed: {e}\n\nThe existing file was left unchanged."
+                            ))]),
+                            structured_content: None,
+                            is_error: Some(true),
+                        }),
                     }
                 }
+                "verify_file" => {
+                    let input: VerifyFileInput = serde_json::from_value(serde_json::Value::Object(
+                        request.arguments.unwrap_or_default(),
+                    ))
+                    .map_err(|e| {
+                        ErrorData::invalid_params(format!("Invalid verify input: {e}"), None)
+                    })?;
+
+                    let result = download_tool
+                        .verify_file(input)
+                        .await
+                        .map_err(|e| match e {
+                            crate::Error::InvalidInput { .. } => {
+                                ErrorData::invalid_params(e.to_string(), None)
+                            }
+                            _ => {
+                                ErrorData::internal_error(format!("Verification failed: {e}"), None)
+                            }
+                        })?;
+
+                    Ok(CallToolResult {
+                        content: Some(vec![Content::text(render_verify_file(&result))]),
+                        structured_content: None,
+                        is_error: Some(false),
+                    })
+                }
                 "download_papers_batch" => {
                     let input: ActualBatchDownloadInput = serde_json::from_value(
                         serde_json::Value::Object(request.arguments.unwrap_or_default()),
@@ -692,32 +1584,56 @@ impl ServerHandler for ResearchServerHandler {
                     }
                 }
                 "extract_metadata" => {
-                    let input: ActualMetadataInput = serde_json::from_value(
+                    self.extract_metadata(request.arguments.unwrap_or_default())
+                        .await
+                }
+                "extract_references" => {
+                    let input: ReferenceExtractionInput = serde_json::from_value(
                         serde_json::Value::Object(request.arguments.unwrap_or_default()),
                     )
                     .map_err(|e| {
-                        ErrorData::invalid_params(format!("Invalid metadata input: {e}"), None)
+                        ErrorData::invalid_params(
+                            format!("Invalid reference extraction input: {e}"),
+                            None,
+                        )
                     })?;
 
-                    let result = metadata_extractor
-                        .extract_metadata(input)
+                    let result = reference_extraction_tool
+                        .extract_references(input)
                         .await
                         .map_err(|e| {
                             ErrorData::internal_error(
-                                format!("Metadata extraction failed: {e}"),
+                                format!("Reference extraction failed: {e}"),
                                 None,
                             )
                         })?;
 
+                    let text = if result.section_found {
+                        let references = result
+                            .references
+                            .iter()
+                            .map(|reference| {
+                                let doi = reference
+                                    .doi
+                                    .as_deref()
+                                    .map_or_else(String::new, |doi| format!("\n   🔗 DOI: {doi}"));
+                                format!("[{}] {}{}", reference.id, reference.raw_text, doi)
+                            })
+                            .collect::<Vec<_>>()
+                            .join("\n\n");
+                        format!(
+                            "📚 Found {} references ({} with DOI) in {}\n\n{}",
+                            result.references.len(),
+                            result.doi_count,
+                            result.file_path,
+                            references
+                        )
+                    } else {
+                        format!("⚠️ No references section found in {}", result.file_path)
+                    };
+
                     Ok(CallToolResult {
-                        content: Some(vec![Content::text(
-                            serde_json::to_string_pretty(&result).map_err(|e| {
-                                ErrorData::internal_error(
-                                    format!("Serialization failed: {e}"),
-                                    None,
-                                )
-                            })?,
-                        )]),
+                        content: Some(vec![Content::text(text)]),
                         structured_content: None,
                         is_error: Some(false),
                     })
@@ -847,6 +1763,153 @@ impl ServerHandler for ResearchServerHandler {
                         is_error: Some(false),
                     })
                 }
+                "validate_bibliography" => {
+                    let input: BibtexValidationInput = serde_json::from_value(
+                        serde_json::Value::Object(request.arguments.unwrap_or_default()),
+                    )
+                    .map_err(|e| {
+                        ErrorData::invalid_params(
+                            format!("Invalid bibliography validation input: {e}"),
+                            None,
+                        )
+                    })?;
+
+                    let result = bibtex_validation_tool.validate(input).await.map_err(|e| {
+                        ErrorData::internal_error(
+                            format!("Bibliography validation failed: {e}"),
+                            None,
+                        )
+                    })?;
+
+                    let count = |status: EntryValidationStatus| {
+                        result.entries.iter().filter(|e| e.status == status).count()
+                    };
+                    let output = format!(
+                        "📚 Validated {} BibTeX entries\n\
+                        • Verified: {}\n\
+                        • Corrected: {}\n\
+                        • No DOI: {}\n\
+                        • DOI not found: {}\n\n\
+                        📝 Changes:\n{}\n\
+                        ✅ Corrected BibTeX:\n\n{}",
+                        result.entries.len(),
+                        count(EntryValidationStatus::Verified),
+                        result.corrected_count,
+                        count(EntryValidationStatus::NoDoi),
+                        count(EntryValidationStatus::NotFound),
+                        result.report,
+                        result.corrected_bibtex
+                    );
+
+                    Ok(CallToolResult {
+                        content: Some(vec![Content::text(output)]),
+                        structured_content: None,
+                        is_error: Some(false),
+                    })
+                }
+                "paper_signals" => {
+                    let input: PaperSignalsInput = serde_json::from_value(
+                        serde_json::Value::Object(request.arguments.unwrap_or_default()),
+                    )
+                    .map_err(|e| {
+                        ErrorData::invalid_params(format!("Invalid paper signals input: {e}"), None)
+                    })?;
+
+                    let signals = paper_signals_tool
+                        .gather(input)
+                        .await
+                        .map_err(|e| match e {
+                            crate::Error::InvalidInput { .. } => {
+                                ErrorData::invalid_params(e.to_string(), None)
+                            }
+                            _ => ErrorData::internal_error(
+                                format!("Gathering paper signals failed: {e}"),
+                                None,
+                            ),
+                        })?;
+
+                    Ok(CallToolResult {
+                        content: Some(vec![Content::text(signals.summary())]),
+                        structured_content: None,
+                        is_error: Some(false),
+                    })
+                }
+                "search_diff" => {
+                    let input: SearchDiffInput = serde_json::from_value(serde_json::Value::Object(
+                        request.arguments.unwrap_or_default(),
+                    ))
+                    .map_err(|e| {
+                        ErrorData::invalid_params(format!("Invalid search diff input: {e}"), None)
+                    })?;
+
+                    let diff = search_diff_tool.diff(input).await.map_err(|e| match e {
+                        crate::Error::InvalidInput { .. } => {
+                            ErrorData::invalid_params(e.to_string(), None)
+                        }
+                        _ => ErrorData::internal_error(format!("Search diff failed: {e}"), None),
+                    })?;
+
+                    let since = diff.since_created_at.map_or_else(
+                        || "first run".to_string(),
+                        |created| format!("since {}", created.format("%Y-%m-%d %H:%M UTC")),
+                    );
+                    let mut output = format!(
+                        "🆕 {} new paper(s) for '{}' ({since}, {} seen before)\n\n",
+                        diff.new_papers.len(),
+                        diff.query,
+                        diff.previously_seen
+                    );
+                    for (i, paper) in diff.new_papers.iter().enumerate() {
+                        output.push_str(&format!(
+                            "{}. {}\n   DOI: {}\n",
+                            i + 1,
+                            paper.metadata.title.as_deref().unwrap_or("Untitled"),
+                            if paper.metadata.doi.is_empty() {
+                                "N/A"
+                            } else {
+                                &paper.metadata.doi
+                            }
+                        ));
+                    }
+                    output.push_str(&format!(
+                        "\n🔖 Next time pass since = \"{}\"",
+                        diff.result_set_id
+                    ));
+
+                    Ok(CallToolResult {
+                        content: Some(vec![Content::text(output)]),
+                        structured_content: None,
+                        is_error: Some(false),
+                    })
+                }
+                "resolve_doi" => {
+                    self.resolve_doi(request.arguments.unwrap_or_default())
+                        .await
+                }
+                "get_paper_text" => {
+                    self.get_paper_text(request.arguments.unwrap_or_default())
+                        .await
+                }
+                "config_info" => Ok(CallToolResult {
+                    content: Some(vec![Content::text(format!(
+                        "{}\n\n{}",
+                        format_config_diff(&self.config.diff(&Config::default())),
+                        format_provider_capabilities(&self.meta_client.provider_capabilities()),
+                    ))]),
+                    structured_content: None,
+                    is_error: Some(false),
+                }),
+                "config_schema" => {
+                    let schema =
+                        serde_json::to_string_pretty(&Config::json_schema()).map_err(|e| {
+                            ErrorData::internal_error(format!("Failed to render schema: {e}"), None)
+                        })?;
+                    Ok(CallToolResult {
+                        content: Some(vec![Content::text(schema)]),
+                        structured_content: None,
+                        is_error: Some(false),
+                    })
+                }
                 _ => Err(ErrorData::invalid_request(
                     format!("Unknown tool: {}", request.name),
                     None,
@@ -861,9 +1924,363 @@ const fn default_limit() -> u32 {
     10
 }
 
+/// Search results as a detailed markdown list with relevance percentages.
+/// Abstracts are only shown for papers whose relevance score reaches
+/// `abstract_min_relevance`.
+fn render_search_results(papers: &[PaperResult], abstract_min_relevance: f64) -> String {
+    let papers = displayed_papers(papers, abstract_min_relevance);
+    render_papers_markdown(
+        papers
+            .iter()
+            .map(|(metadata, source, relevance)| (metadata, Some(*source), Some(*relevance))),
+        &MarkdownOptions::detailed(),
+    )
+}
+
+/// Papers paired with their source and relevance score, dropping the
+/// abstract of papers whose relevance score is below `abstract_min_relevance`
+fn displayed_papers(
+    papers: &[PaperResult],
+    abstract_min_relevance: f64,
+) -> Vec<(PaperMetadata, &str, f64)> {
+    papers
+        .iter()
+        .map(|p| {
+            let mut metadata = p.metadata.clone();
+            if p.relevance_score < abstract_min_relevance {
+                metadata.abstract_text = None;
+            }
+            (metadata, p.source.as_str(), p.relevance_score)
+        })
+        .collect()
+}
+
+/// One section per provider that returned any of `papers`, largest first,
+/// each listing the papers that provider found. A paper several providers
+/// returned appears in each of their sections.
+fn render_source_sections(papers: &[PaperResult], abstract_min_relevance: f64) -> Vec<String> {
+    let mut by_source: BTreeMap<&str, Vec<PaperResult>> = BTreeMap::new();
+    for paper in papers {
+        let sources: Vec<&str> = if paper.found_by.is_empty() {
+            vec![paper.source.as_str()]
+        } else {
+            paper.found_by.iter().map(String::as_str).collect()
+        };
+        for source in sources {
+            by_source.entry(source).or_default().push(paper.clone());
+        }
+    }
+
+    let mut groups: Vec<(&str, Vec<PaperResult>)> = by_source.into_iter().collect();
+    groups.sort_by_key(|(_, papers)| Reverse(papers.len()));
+    groups
+        .into_iter()
+        .map(|(source, papers)| {
+            format!(
+                "## {source} ({} papers)\n\n{}",
+                papers.len(),
+                render_search_results(&papers, abstract_min_relevance)
+            )
+        })
+        .collect()
+}
+
+/// Template used by the `minimal` output preset
+const MINIMAL_SEARCH_TEMPLATE: &str = "{index}. {title} ({year}) {doi}";
+
+/// The `search_papers` response in the format chosen by
+/// `output.search_template`: the `emoji` preset (also used when unset),
+/// `plain`, `minimal`, or a custom per-paper template.
+fn render_search_output(
+    results: &SearchResult,
+    template: &str,
+    abstract_min_relevance: f64,
+    include_timings: bool,
+    messages: &MessageCatalog,
+) -> String {
+    let timings = include_timings.then(|| format_search_timings(&results.timings_ms));
+    let count = results.returned_count.to_string();
+
+    match template.trim() {
+        "" | "emoji" => {
+            let doi_count = results
+                .papers
+                .iter()
+                .filter(|p| !p.metadata.doi.is_empty())
+                .count()
+                .to_string();
+            let mut text = messages.format(
+                "search.found",
+                &[
+                    ("count", &count),
+                    ("query", &results.query),
+                    (
+                        "results",
+                        &render_search_results(&results.papers, abstract_min_relevance),
+                    ),
+                    ("doi_count", &doi_count),
+                ],
+            );
+            if let Some(timings) = timings {
+                text.push_str(&messages.format("search.timings", &[("timings", &timings)]));
+            }
+            text
+        }
+        preset => {
+            let rendered = match preset {
+                "plain" => render_search_results(&results.papers, abstract_min_relevance),
+                _ => {
+                    let template = if preset == "minimal" {
+                        MINIMAL_SEARCH_TEMPLATE
+                    } else {
+                        template
+                    };
+                    let papers = displayed_papers(&results.papers, abstract_min_relevance);
+                    render_papers_template(
+                        papers.iter().map(|(metadata, source, relevance)| {
+                            (metadata, Some(*source), Some(*relevance))
+                        }),
+                        template,
+                    )
+                }
+            };
+            let mut text = messages.format(
+                "search.found_plain",
+                &[
+                    ("count", &count),
+                    ("query", &results.query),
+                    ("results", &rendered),
+                ],
+            );
+            if let Some(timings) = timings {
+                text.push_str(&messages.format("search.timings_plain", &[("timings", &timings)]));
+            }
+            text
+        }
+    }
+}
+
+/// Warning for a search answered by fewer than `min_successful_providers`
+/// providers. A search no provider answered gets none; it has no results to
+/// qualify.
+fn incomplete_results_warning(
+    results: &SearchResult,
+    min_successful_providers: usize,
+) -> Option<String> {
+    let succeeded = results.successful_providers.len();
+    if succeeded == 0 || succeeded >= min_successful_providers {
+        return None;
+    }
+
+    let mut failed = results.failed_providers.clone();
+    failed.sort();
+    let failed = if failed.is_empty() {
+        String::new()
+    } else {
+        format!(" Failed: {}.", failed.join(", "))
+    };
+    Some(format!(
+        "⚠️ Results may be incomplete: {succeeded} provider(s) answered ({}), fewer than the {min_successful_providers} required by meta_search.min_successful_providers.{failed}",
+        results.successful_providers.join(", ")
+    ))
+}
+
+/// A downloaded PDF as an embedded base64 resource, or a note saying why it
+/// isn't returned when it is over `max_size_mb` (0 disables inline files).
+/// The notes and the resource URI show `reported`, the path as tool output
+/// shows it; `path` is only read.
+async fn inline_pdf_content(
+    path: &Path,
+    reported: &str,
+    file_size: u64,
+    max_size_mb: u64,
+    messages: &MessageCatalog,
+) -> Content {
+    if max_size_mb == 0 {
+        return Content::text(messages.format("download.inline_disabled", &[]));
+    }
+    if file_size > max_size_mb.saturating_mul(1024 * 1024) {
+        return Content::text(messages.format(
+            "download.inline_too_large",
+            &[
+                (
+                    "size_mb",
+                    &format!("{:.1}", file_size as f64 / (1024.0 * 1024.0)),
+                ),
+                ("limit_mb", &max_size_mb.to_string()),
+                ("file", reported),
+            ],
+        ));
+    }
+
+    match tokio::fs::read(path).await {
+        Ok(bytes) => Content::resource(ResourceContents::BlobResourceContents {
+            // A relative path has no file:// form and is given as it is shown
+            uri: url::Url::from_file_path(reported)
+                .map_or_else(|()| reported.to_string(), |url| url.to_string()),
+            mime_type: Some("application/pdf".to_string()),
+            blob: BASE64.encode(bytes),
+        }),
+        Err(e) => {
+            warn!("Failed to read {:?} for inline download: {}", path, e);
+            Content::text(messages.format(
+                "download.inline_read_failed",
+                &[("file", reported), ("error", &e.to_string())],
+            ))
+        }
+    }
+}
+
+/// Settings that differ from the defaults as a markdown table
+fn format_config_diff(diff: &[(String, String, String)]) -> String {
+    if diff.is_empty() {
+        return "⚙️ All settings are at their defaults.".to_string();
+    }
+
+    let cell = |value: &str| value.replace('|', "\\|");
+    let mut output = format!(
+        "⚙️ {} settings differ from the defaults:\n\n| Setting | Default | Effective |\n|---|---|---|\n",
+        diff.len()
+    );
+    for (field, default, current) in diff {
+        output.push_str(&format!(
+            "| `{}` | {} | {} |\n",
+            field,
+            cell(default),
+            cell(current)
+        ));
+    }
+    output.trim_end().to_string()
+}
+
+/// Enabled providers as a markdown table, marking which ones yield PDFs
+fn format_provider_capabilities(capabilities: &[ProviderCapabilities]) -> String {
+    let mut output =
+        "🔌 Providers:\n\n| Provider | PDFs | Search types |\n|---|---|---|\n".to_string();
+    for capability in capabilities {
+        let search_types: Vec<String> = capability
+            .search_types
+            .iter()
+            .map(|t| format!("{t:?}"))
+            .collect();
+        output.push_str(&format!(
+            "| {} | {} | {} |\n",
+            capability.name,
+            if capability.provides_fulltext {
+                "yes"
+            } else {
+                "metadata only"
+            },
+            search_types.join(", ")
+        ));
+    }
+    output.trim_end().to_string()
+}
+
+/// Search timings, the total first and then the slowest providers first
+fn format_search_timings(timings_ms: &HashMap<String, u64>) -> String {
+    let mut providers: Vec<(&String, &u64)> = timings_ms
+        .iter()
+        .filter(|(name, _)| name.as_str() != "total")
+        .collect();
+    providers.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+
+    let mut parts: Vec<String> = timings_ms
+        .get("total")
+        .map(|total| format!("total {total}ms"))
+        .into_iter()
+        .collect();
+    parts.extend(providers.iter().map(|(name, ms)| format!("{name} {ms}ms")));
+    parts.join(" · ")
+}
+
+/// Summary of a `verify_file` check, leading with ✅ when every check passed
+fn render_verify_file(result: &VerifyFileResult) -> String {
+    let passed = |ok: bool| if ok { "yes" } else { "no" };
+    let intact =
+        result.valid_pdf_header && result.has_eof_marker && result.hash_matches != Some(false);
+    format!(
+        "{} {} ({} bytes)\n\n• SHA-256: {}{}\n• PDF header: {}\n• %%EOF marker: {}",
+        if intact { "✅" } else { "⚠️" },
+        result.file_path.display(),
+        result.file_size,
+        result.sha256,
+        result
+            .hash_matches
+            .map(|matches| format!("\n• Matches expected hash: {}", passed(matches)))
+            .unwrap_or_default(),
+        passed(result.valid_pdf_header),
+        passed(result.has_eof_marker)
+    )
+}
+
+/// Short readable overview of a metadata extraction result
+fn render_metadata_summary(result: &MetadataResult) -> String {
+    let Some(metadata) = &result.metadata else {
+        return format!(
+            "⚠️ No metadata extracted from {}: {}",
+            result.file_path,
+            result.error.as_deref().unwrap_or("extraction failed")
+        );
+    };
+
+    let mut lines = vec![format!(
+        "📄 {}",
+        metadata.title.as_deref().unwrap_or("Untitled")
+    )];
+    if !metadata.authors.is_empty() {
+        let authors: Vec<&str> = metadata.authors.iter().map(|a| a.name.as_str()).collect();
+        lines.push(format!("👥 {}", authors.join(", ")));
+    }
+    if let Some(journal) = &metadata.journal {
+        lines.push(format!("📰 {journal}"));
+    }
+    if let Some(date) = &metadata.publication_date {
+        lines.push(format!("📅 {date}"));
+    }
+    if let Some(doi) = &metadata.doi {
+        lines.push(format!("🔗 DOI: {doi}"));
+    }
+    if let Some(pages) = metadata.page_count {
+        lines.push(format!("📑 {pages} pages"));
+    }
+    lines.push(format!(
+        "🎯 Confidence: {:.2} ({})",
+        metadata.confidence_score, metadata.metadata_source
+    ));
+    lines.join("\n")
+}
+
+/// One line per scored paper with its relevance score and the components it
+/// is made of, or `None` when no paper was scored
+fn format_score_breakdowns(papers: &[PaperResult]) -> Option<String> {
+    let lines: Vec<String> = papers
+        .iter()
+        .enumerate()
+        .filter(|(_, paper)| !paper.score_breakdown.is_empty())
+        .map(|(index, paper)| {
+            let mut components: Vec<(&String, &f64)> = paper.score_breakdown.iter().collect();
+            components.sort_by(|a, b| a.0.cmp(b.0));
+            format!(
+                "{}. {:.2} ({})",
+                index + 1,
+                paper.relevance_score,
+                components
+                    .iter()
+                    .map(|(name, value)| format!("{name} {value:.2}"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+        })
+        .collect();
+    (!lines.is_empty()).then(|| lines.join("\n"))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::client::providers::traits::tests::{paper, MockProvider};
+    use crate::client::providers::SearchType;
 
     fn create_test_handler() -> ResearchServerHandler {
         let config = Config::default();
@@ -894,4 +2311,441 @@ mod tests {
         assert_eq!(input.limit, 10);
         assert_eq!(input.offset, 0);
     }
+
+    #[test]
+    fn test_render_search_results_abstract_only_above_threshold() {
+        let paper = |doi: &str, abstract_text: &str, relevance_score: f64| {
+            let mut metadata = PaperMetadata::new(doi.to_string());
+            metadata.title = Some(format!("Paper {doi}"));
+            metadata.abstract_text = Some(abstract_text.to_string());
+            PaperResult {
+                metadata,
+                relevance_score,
+                score_breakdown: HashMap::new(),
+                available: true,
+                source: "mock".to_string(),
+                found_by: vec!["mock".to_string()],
+                category: None,
+                abstract_translated: None,
+            }
+        };
+        let papers = vec![
+            paper("10.1000/strong", "A close match for the query.", 0.99),
+            paper("10.1000/weak", "A loosely related paper.", 0.5),
+        ];
+
+        let rendered = render_search_results(&papers, 0.9);
+        assert!(rendered.contains("**Paper 10.1000/strong** (Relevance: 99%)"));
+        assert!(rendered.contains("A close match for the query."));
+        assert!(!rendered.contains("A loosely related paper."));
+        assert!(rendered.contains("10.1000/weak"));
+
+        let rendered = render_search_results(&papers, 0.0);
+        assert!(rendered.contains("A loosely related paper."));
+    }
+
+    #[test]
+    fn test_render_search_output_plain_preset_has_no_emoji() {
+        let mut metadata = PaperMetadata::new("10.1038/nature14539".to_string());
+        metadata.title = Some("Deep learning".to_string());
+        metadata.authors = vec!["Yann LeCun".to_string()];
+        metadata.year = Some(2015);
+        let results = SearchResult {
+            query: "deep learning".to_string(),
+            search_type: crate::tools::search::SearchType::Auto,
+            papers: vec![PaperResult {
+                metadata,
+                relevance_score: 1.0,
+                score_breakdown: HashMap::new(),
+                available: true,
+                source: "crossref".to_string(),
+                found_by: vec!["crossref".to_string()],
+                category: None,
+                abstract_translated: None,
+            }],
+            total_count: 1,
+            returned_count: 1,
+            filtered_count: 0,
+            offset: 0,
+            has_more: false,
+            search_time_ms: 42,
+            source_mirror: None,
+            category: None,
+            successful_providers: vec!["crossref".to_string()],
+            failed_providers: vec![],
+            provider_errors: HashMap::new(),
+            papers_per_provider: HashMap::new(),
+            timings_ms: HashMap::from([("total".to_string(), 42)]),
+        };
+
+        let rendered =
+            render_search_output(&results, "plain", 0.0, true, &MessageCatalog::default());
+        assert!(rendered.starts_with("Found 1 papers for 'deep learning'"));
+        assert!(rendered.contains("Deep learning"));
+        assert!(rendered.contains("Yann LeCun"));
+        assert!(rendered.contains("10.1038/nature14539"));
+        assert!(rendered.contains("Timings: total 42ms"));
+        assert!(
+            !rendered.chars().any(|c| c as u32 >= 0x2300),
+            "unexpected emoji in: {rendered}"
+        );
+
+        let minimal =
+            render_search_output(&results, "minimal", 0.0, false, &MessageCatalog::default());
+        assert!(minimal.ends_with("1. Deep learning (2015) 10.1038/nature14539"));
+    }
+
+    #[tokio::test]
+    async fn test_verify_file_known_file_matches_expected_hash() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("paper.pdf");
+        std::fs::write(&path, b"%PDF-1.4\n%%EOF\n").unwrap();
+        let expected = "14bcd090baf31edba64e9cbd8cdfc15f943344aa72cb3675ad8e91bfcbce03ad";
+        let handler = create_test_handler();
+
+        let result = handler
+            .download_tool
+            .verify_file(VerifyFileInput {
+                file_path: path.to_string_lossy().to_string(),
+                expected_sha256: Some(expected.to_uppercase()),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(result.sha256, expected);
+        assert_eq!(result.hash_matches, Some(true));
+        assert!(result.valid_pdf_header);
+        assert!(result.has_eof_marker);
+        assert!(render_verify_file(&result).starts_with("✅"));
+    }
+
+    #[tokio::test]
+    async fn test_inline_pdf_content_returns_small_pdf_as_base64() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("paper.pdf");
+        let pdf = b"%PDF-1.4\n1 0 obj << /Type /Catalog >> endobj\n%%EOF\n";
+        std::fs::write(&path, pdf).unwrap();
+
+        let messages = MessageCatalog::default();
+        let reported = path.display().to_string();
+        let content = inline_pdf_content(&path, &reported, pdf.len() as u64, 1, &messages).await;
+        let json = serde_json::to_value(&content).unwrap();
+        assert_eq!(json["type"], "resource");
+        assert_eq!(json["resource"]["mimeType"], "application/pdf");
+        assert_eq!(json["resource"]["blob"], BASE64.encode(pdf));
+        assert!(json["resource"]["uri"]
+            .as_str()
+            .unwrap()
+            .starts_with("file://"));
+
+        let refused = inline_pdf_content(&path, &reported, 2 * 1024 * 1024, 1, &messages).await;
+        let json = serde_json::to_value(&refused).unwrap();
+        assert_eq!(json["type"], "text");
+        assert!(json["text"]
+            .as_str()
+            .unwrap()
+            .contains("over the 1 MB limit"));
+    }
+
+    #[tokio::test]
+    async fn test_inline_pdf_content_relative_path_keeps_root_out_of_output() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("paper.pdf");
+        std::fs::write(&path, b"%PDF-1.4\n%%EOF\n").unwrap();
+        let messages = MessageCatalog::default();
+        let root = temp_dir.path().display().to_string();
+
+        let content = inline_pdf_content(&path, "paper.pdf", 15, 1, &messages).await;
+        let json = serde_json::to_value(&content).unwrap();
+        assert_eq!(json["resource"]["uri"], "paper.pdf");
+
+        let refused = inline_pdf_content(&path, "paper.pdf", 2 * 1024 * 1024, 1, &messages).await;
+        let text = serde_json::to_value(&refused).unwrap()["text"]
+            .as_str()
+            .unwrap()
+            .to_string();
+        assert!(text.ends_with("It is still saved at paper.pdf"));
+        assert!(!text.contains(&root));
+    }
+
+    /// Provider that answers every search, or fails every one
+    fn fixed_provider(name: &'static str, answers: bool) -> MockProvider {
+        let provider = MockProvider::new(name);
+        if answers {
+            provider.with_papers(vec![paper(
+                &format!("10.1000/{name}"),
+                &format!("Sparse attention in {name}"),
+            )])
+        } else {
+            provider.failing()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_search_papers_too_few_providers_warns_incomplete() {
+        let providers: Vec<Arc<dyn crate::client::providers::SourceProvider>> = vec![
+            Arc::new(fixed_provider("weak", true)),
+            Arc::new(fixed_provider("index_a", false)),
+            Arc::new(fixed_provider("index_b", false)),
+        ];
+        let client = Arc::new(MetaSearchClient::with_providers(
+            providers,
+            crate::client::MetaSearchConfig::default(),
+        ));
+        let search = |min_successful_providers| {
+            let mut config = Config::default();
+            config.meta_search.min_successful_providers = min_successful_providers;
+            let handler =
+                ResearchServerHandler::with_meta_client(Arc::new(config), Arc::clone(&client))
+                    .unwrap();
+            async move {
+                let args = serde_json::json!({ "query": "sparse attention" });
+                let result = handler
+                    .search_papers(args.as_object().unwrap().clone(), &CancellationToken::new())
+                    .await
+                    .unwrap();
+                let json = serde_json::to_value(&result.content.unwrap()[0]).unwrap();
+                json["text"].as_str().unwrap().to_string()
+            }
+        };
+
+        let text = search(2).await;
+        assert!(text.contains("10.1000/weak"));
+        assert!(text.contains("Results may be incomplete: 1 provider(s) answered (weak)"));
+        assert!(text.contains("Failed: index_a, index_b."));
+
+        let text = search(1).await;
+        assert!(!text.contains("Results may be incomplete"));
+    }
+
+    /// Provider returning one paper with a PDF link and one without
+    fn partial_pdf_provider() -> MockProvider {
+        let mut open = paper("10.1000/with-pdf", "Sparse attention with a PDF");
+        open.pdf_url = Some("https://example.com/with-pdf.pdf".to_string());
+        let closed = paper("10.1000/without-pdf", "Sparse attention without a PDF");
+        MockProvider::new("partial_pdf").with_papers(vec![open, closed])
+    }
+
+    #[tokio::test]
+    async fn test_search_papers_require_pdf_drops_results_without_pdf_url() {
+        let client = Arc::new(MetaSearchClient::with_providers(
+            vec![Arc::new(partial_pdf_provider())],
+            crate::client::MetaSearchConfig::default(),
+        ));
+        let handler =
+            ResearchServerHandler::with_meta_client(Arc::new(Config::default()), client).unwrap();
+        let search = |require_pdf: bool| {
+            let handler = &handler;
+            async move {
+                let args =
+                    serde_json::json!({ "query": "sparse attention", "require_pdf": require_pdf });
+                let result = handler
+                    .search_papers(args.as_object().unwrap().clone(), &CancellationToken::new())
+                    .await
+                    .unwrap();
+                let json = serde_json::to_value(&result.content.unwrap()[0]).unwrap();
+                json["text"].as_str().unwrap().to_string()
+            }
+        };
+
+        let text = search(false).await;
+        assert!(text.contains("10.1000/with-pdf"));
+        assert!(text.contains("10.1000/without-pdf"));
+
+        let text = search(true).await;
+        assert!(text.contains("10.1000/with-pdf"));
+        assert!(!text.contains("10.1000/without-pdf"));
+    }
+
+    #[tokio::test]
+    async fn test_search_papers_group_by_source_has_section_per_source() {
+        let providers: Vec<Arc<dyn crate::client::providers::SourceProvider>> = vec![
+            Arc::new(fixed_provider("alpha", true)),
+            Arc::new(fixed_provider("beta", true)),
+            Arc::new(fixed_provider("down", false)),
+        ];
+        let client = Arc::new(MetaSearchClient::with_providers(
+            providers,
+            crate::client::MetaSearchConfig::default(),
+        ));
+        let handler =
+            ResearchServerHandler::with_meta_client(Arc::new(Config::default()), client).unwrap();
+
+        let args = serde_json::json!({ "query": "sparse attention", "group_by_source": true });
+        let result = handler
+            .search_papers(args.as_object().unwrap().clone(), &CancellationToken::new())
+            .await
+            .unwrap();
+        let texts: Vec<String> = result
+            .content
+            .unwrap()
+            .iter()
+            .map(|content| {
+                serde_json::to_value(content).unwrap()["text"]
+                    .as_str()
+                    .unwrap()
+                    .to_string()
+            })
+            .collect();
+
+        assert_eq!(texts.len(), 3);
+        assert!(texts[0].starts_with("Found 2 papers for 'sparse attention' from 2 sources"));
+        let mut sections = texts[1..].to_vec();
+        sections.sort();
+        assert!(sections[0].starts_with("## alpha (1 papers)"));
+        assert!(sections[0].contains("10.1000/alpha"));
+        assert!(!sections[0].contains("10.1000/beta"));
+        assert!(sections[1].starts_with("## beta (1 papers)"));
+        assert!(sections[1].contains("10.1000/beta"));
+        assert!(texts.iter().all(|text| !text.contains("## down")));
+    }
+
+    #[tokio::test]
+    async fn test_extract_metadata_output_formats_bibtex_and_structured_json() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("sparse.pdf");
+        crate::tools::metadata::tests::create_sample_pdf(
+            &path,
+            &[
+                "Scaling Laws for Sparse Attention",
+                "Published March 2023",
+                "Sparse attention reduces the quadratic cost of transformers.",
+            ],
+        );
+        let handler = create_test_handler();
+        let extract = |format: &str| {
+            let args = serde_json::json!({
+                "file_path": path.to_string_lossy(),
+                "use_cache": false,
+                "output_format": format,
+            });
+            handler.extract_metadata(args.as_object().unwrap().clone())
+        };
+
+        let result = extract("bibtex").await.unwrap();
+        assert_eq!(result.is_error, Some(false));
+        let json = serde_json::to_value(&result.content.unwrap()[0]).unwrap();
+        let entries =
+            crate::tools::bibtex_validation::parse_bibtex(json["text"].as_str().unwrap()).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].entry_type, "article");
+        assert!(!entries[0].key.is_empty());
+        assert!(entries[0].field("title").is_some());
+        assert_eq!(entries[0].field("year"), Some("2023"));
+
+        let result = extract("json").await.unwrap();
+        let structured = result.structured_content.unwrap();
+        assert_ne!(structured["status"], "failed");
+        assert_eq!(structured["metadata"]["page_count"], 1);
+        let json = serde_json::to_value(&result.content.unwrap()[0]).unwrap();
+        let text: serde_json::Value = serde_json::from_str(json["text"].as_str().unwrap()).unwrap();
+        assert_eq!(text, structured);
+    }
+
+    /// Provider that answers DOI lookups with a fixed PDF URL
+    fn pdf_url_provider(pdf_url: String) -> MockProvider {
+        MockProvider::new("pdf_host")
+            .with_search_types(vec![SearchType::Doi])
+            .answering(move |query, _| {
+                let mut paper = paper(&query.query, "Relative Paths");
+                paper.pdf_url = Some(pdf_url.clone());
+                Ok(vec![paper])
+            })
+    }
+
+    #[tokio::test]
+    async fn test_download_paper_report_relative_paths_hides_download_root() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, ResponseTemplate};
+
+        let mock_server = wiremock::MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/paper.pdf"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_bytes(b"%PDF-1.4\n% relative\n%%EOF\n".to_vec())
+                    .insert_header("content-type", "application/pdf"),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let download_dir = tempfile::TempDir::new().unwrap();
+        let mut config = Config::default();
+        config.downloads.directory = download_dir.path().to_path_buf();
+        config.downloads.report_relative_paths = true;
+        let client = Arc::new(MetaSearchClient::with_providers(
+            vec![Arc::new(pdf_url_provider(format!(
+                "{}/paper.pdf",
+                mock_server.uri()
+            )))],
+            crate::client::MetaSearchConfig::default(),
+        ));
+        let handler = ResearchServerHandler::with_meta_client(Arc::new(config), client).unwrap();
+
+        let args = serde_json::json!({ "doi": "10.1000/relative", "filename": "relative.pdf" });
+        let result = handler
+            .download_paper(args.as_object().unwrap().clone())
+            .await
+            .unwrap();
+        assert_eq!(result.is_error, Some(false));
+
+        let absolute = std::path::PathBuf::from(
+            result.structured_content.unwrap()["file_path"]
+                .as_str()
+                .unwrap(),
+        );
+        assert!(absolute.is_absolute());
+        let relative = absolute.strip_prefix(download_dir.path()).unwrap();
+        assert!(relative.ends_with("relative.pdf"));
+
+        let json = serde_json::to_value(&result.content.unwrap()[0]).unwrap();
+        let text = json["text"].as_str().unwrap();
+        assert!(text.contains(&format!("📄 File: {}\n", relative.display())));
+        assert!(!text.contains(&download_dir.path().display().to_string()));
+    }
+
+    /// Answers title searches with papers whose titles resemble the query
+    fn title_provider() -> MockProvider {
+        MockProvider::new("titles")
+            .with_search_types(vec![SearchType::Title])
+            .with_papers(
+                [
+                    ("10.1000/review", "Attention mechanisms: a review", 2021),
+                    ("10.5555/3295222.3295349", "Attention Is All You Need", 2017),
+                    ("10.1000/reply", "Attention is not all you need", 2021),
+                ]
+                .into_iter()
+                .map(|(doi, title, year)| {
+                    let mut paper = paper(doi, title);
+                    paper.abstract_text = Some("An abstract the caller didn't ask for".to_string());
+                    paper.year = Some(year);
+                    paper
+                })
+                .collect(),
+            )
+    }
+
+    #[tokio::test]
+    async fn test_resolve_doi_title_returns_expected_doi() {
+        let providers: Vec<Arc<dyn crate::client::providers::SourceProvider>> =
+            vec![Arc::new(title_provider())];
+        let client = Arc::new(MetaSearchClient::with_providers(
+            providers,
+            crate::client::MetaSearchConfig::default(),
+        ));
+        let handler =
+            ResearchServerHandler::with_meta_client(Arc::new(Config::default()), client).unwrap();
+
+        let args = serde_json::json!({ "title": "attention is all you need." });
+        let result = handler
+            .resolve_doi(args.as_object().unwrap().clone())
+            .await
+            .unwrap();
+
+        let json = serde_json::to_value(&result.content.unwrap()[0]).unwrap();
+        assert_eq!(json["text"].as_str().unwrap(), "10.5555/3295222.3295349");
+        let structured = result.structured_content.unwrap();
+        assert_eq!(structured["candidates"].as_array().unwrap().len(), 1);
+        assert_eq!(structured["candidates"][0]["source"], "titles");
+    }
 }
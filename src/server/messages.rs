@@ -0,0 +1,325 @@
+//! Localized templates for the messages tools show to users.
+//!
+//! Every message has a key (`download.not_found`, `search.found`, ...) and a
+//! template with `{name}` placeholders. The English templates are built in;
+//! another language is loaded from `<locale.catalog_dir>/<language>.toml`,
+//! where each table holds the keys of one group:
+//!
+//! ```toml
+//! [download]
+//! not_found = "⚠️ Artículo no disponible\n\nDOI: {doi}"
+//! ```
+//!
+//! Keys a catalog leaves out keep their English template.
+
+use crate::config::LocaleSettings;
+use crate::Result;
+use std::collections::HashMap;
+use std::path::Path;
+use tracing::{debug, info, warn};
+
+/// Language of the built-in templates
+pub const DEFAULT_LANGUAGE: &str = "en";
+
+/// Built-in English templates, by key
+const ENGLISH: &[(&str, &str)] = &[
+    (
+        "download.success",
+        "✅ Download successful!\n\n📄 File: {file}\n📦 Size: {size_kb} KB",
+    ),
+    ("download.source", "\n🌐 Source: {source}"),
+    (
+        "download.timing",
+        "\n⏱️ Time: {seconds}s\n🚀 Speed: {speed_kbps} KB/s",
+    ),
+    ("download.hash", "\n🔐 SHA256: {hash}..."),
+    (
+        "download.hash_skipped",
+        "\n🔐 SHA256: not computed (integrity verification skipped)",
+    ),
+    (
+        "download.inline_disabled",
+        "ℹ️ Not returned inline: inline downloads are disabled (downloads.inline_max_size_mb = 0)",
+    ),
+    (
+        "download.inline_too_large",
+        "ℹ️ Not returned inline: the file is {size_mb} MB, over the {limit_mb} MB limit \
+         (downloads.inline_max_size_mb). It is still saved at {file}",
+    ),
+    (
+        "download.inline_read_failed",
+        "⚠️ Not returned inline: failed to read {file}: {error}",
+    ),
+    (
+        "download.empty",
+        "⚠️ Download failed - no content received\n\n\
+         DOI: {doi}\n\n\
+         🔍 Debug Info:\n\
+         • Download ID: {download_id}\n\
+         • Duration: {seconds}s\n\
+         • Status: {status}\n\
+         • File created but empty\n\n\
+         The paper was found but no downloadable content is available. This could be because:\n\
+         • The paper is too new or recently published\n\
+         • It's behind a paywall not covered by available sources\n\
+         • The DOI might be incorrect\n\
+         • Network issues during download\n\n\
+         Try checking the publisher's website or your institutional access.",
+    ),
+    (
+        "download.not_found",
+        "⚠️ Paper not available for download\n\n\
+         DOI: {doi}\n\n\
+         🔍 Debug Info:\n\
+         • Time: {time}\n\
+         • Error: {error}\n\
+         • All available sources checked\n\n\
+         This paper is not currently available through any source. This could be because:\n\
+         • The paper is too new (published recently)\n\
+         • It's from a publisher not covered by available sources\n\
+         • The DOI might be incorrect or malformed\n\
+         • Temporary service unavailability\n\n\
+         💡 Alternatives:\n\
+         • Try searching for the paper on Google Scholar\n\
+         • Check if your institution has access\n\
+         • Try arXiv or other preprint servers\n\
+         • Contact the authors directly\n\
+         • Verify the DOI is correct",
+    ),
+    (
+        "download.network_error",
+        "⚠️ Network error while downloading\n\n\
+         DOI: {doi}\n\n\
+         🔍 Debug Info:\n\
+         • Time: {time}\n\
+         • Error: {error}\n\
+         • Network connectivity issue detected\n\n\
+         Please check your internet connection and try again.\n\
+         If the problem persists, the source servers may be temporarily unavailable.",
+    ),
+    (
+        "download.permission_denied",
+        "⚠️ File system permission error\n\n\
+         DOI: {doi}\n\n\
+         🔍 Debug Info:\n\
+         • Time: {time}\n\
+         • Error: {error}\n\n\
+         This appears to be a permission issue with accessing the download directory.\n\
+         Please check the error message for specific instructions to resolve.",
+    ),
+    (
+        "download.failed",
+        "⚠️ Download failed\n\n\
+         DOI: {doi}\n\n\
+         🔍 Debug Info:\n\
+         • Time: {time}\n\
+         • Error Type: {error_type}\n\
+         • Error: {error}\n\n\
+         Please try again or use a different DOI. If this error persists,\n\
+         it may indicate an issue with the paper source or network connectivity.",
+    ),
+    (
+        "search.found",
+        "📚 Found {count} papers for '{query}'\n\n{results}\n\n\
+         💡 Tip: Papers from {doi_count} may be available for download. \
+         Very recent papers (2024-2025) might not be available yet.",
+    ),
+    (
+        "search.found_plain",
+        "Found {count} papers for '{query}'\n\n{results}",
+    ),
+    (
+        "search.found_by_source",
+        "Found {count} papers for '{query}' from {source_count} sources",
+    ),
+    ("search.timings", "\n\n⏱️ Timings: {timings}"),
+    ("search.timings_plain", "\n\nTimings: {timings}"),
+];
+
+/// Message templates for one language
+#[derive(Debug, Clone)]
+pub struct MessageCatalog {
+    language: String,
+    templates: HashMap<String, String>,
+}
+
+impl Default for MessageCatalog {
+    fn default() -> Self {
+        Self {
+            language: DEFAULT_LANGUAGE.to_string(),
+            templates: ENGLISH
+                .iter()
+                .map(|(key, template)| ((*key).to_string(), (*template).to_string()))
+                .collect(),
+        }
+    }
+}
+
+impl MessageCatalog {
+    /// Catalog for `settings.language`: its templates from the catalog
+    /// directory over the English ones. English is used alone when the
+    /// language has no catalog file.
+    pub fn load(settings: &LocaleSettings) -> Result<Self> {
+        let mut catalog = Self::default();
+        let Some(dir) = settings.catalog_dir.as_deref() else {
+            if settings.language != DEFAULT_LANGUAGE {
+                warn!(
+                    "No locale.catalog_dir set; showing messages in English instead of '{}'",
+                    settings.language
+                );
+            }
+            return Ok(catalog);
+        };
+
+        let path = dir.join(format!("{}.toml", settings.language));
+        if !path.is_file() {
+            if settings.language != DEFAULT_LANGUAGE {
+                warn!(
+                    "No message catalog at {:?}; showing messages in English",
+                    path
+                );
+            }
+            return Ok(catalog);
+        }
+
+        let mut translated = 0;
+        for (key, template) in read_catalog(&path)? {
+            if let Some(slot) = catalog.templates.get_mut(&key) {
+                *slot = template;
+                translated += 1;
+            } else {
+                warn!("Ignoring unknown message key '{}' in {:?}", key, path);
+            }
+        }
+        info!(
+            "Loaded {} of {} messages for locale '{}' from {:?}",
+            translated,
+            catalog.templates.len(),
+            settings.language,
+            path
+        );
+        catalog.language.clone_from(&settings.language);
+        Ok(catalog)
+    }
+
+    /// Language the templates were loaded for
+    #[must_use]
+    pub fn language(&self) -> &str {
+        &self.language
+    }
+
+    /// The message for `key` with each `{name}` replaced by its value in
+    /// `args`. Placeholders without a value are left as written, and so are
+    /// braces inside the values.
+    #[must_use]
+    pub fn format(&self, key: &str, args: &[(&str, &str)]) -> String {
+        let Some(template) = self.templates.get(key) else {
+            debug!("No message template for key '{}'", key);
+            return key.to_string();
+        };
+
+        let mut out = String::with_capacity(template.len());
+        let mut rest = template.as_str();
+        while let Some(open) = rest.find('{') {
+            out.push_str(&rest[..open]);
+            let after = &rest[open + 1..];
+            let value = after.find('}').and_then(|close| {
+                let name = &after[..close];
+                args.iter()
+                    .find(|(arg, _)| *arg == name)
+                    .map(|(_, value)| (close, *value))
+            });
+            match value {
+                Some((close, value)) => {
+                    out.push_str(value);
+                    rest = &after[close + 1..];
+                }
+                None => {
+                    out.push('{');
+                    rest = after;
+                }
+            }
+        }
+        out.push_str(rest);
+        out
+    }
+}
+
+/// Templates in a catalog file, keyed by `<table>.<key>`
+fn read_catalog(path: &Path) -> Result<HashMap<String, String>> {
+    let parse_error = |message: String| crate::Error::Parse {
+        context: format!("message catalog {}", path.display()),
+        message,
+    };
+    let contents = std::fs::read_to_string(path)?;
+    let table: toml::Table = contents.parse().map_err(|e| parse_error(format!("{e}")))?;
+
+    let mut templates = HashMap::new();
+    flatten_catalog("", &table, &mut templates).map_err(parse_error)?;
+    Ok(templates)
+}
+
+fn flatten_catalog(
+    prefix: &str,
+    table: &toml::Table,
+    templates: &mut HashMap<String, String>,
+) -> std::result::Result<(), String> {
+    for (name, value) in table {
+        let key = if prefix.is_empty() {
+            name.clone()
+        } else {
+            format!("{prefix}.{name}")
+        };
+        match value {
+            toml::Value::String(template) => {
+                templates.insert(key, template.clone());
+            }
+            toml::Value::Table(inner) => flatten_catalog(&key, inner, templates)?,
+            _ => return Err(format!("'{key}' must be a string")),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_load_second_locale_translates_not_found_message() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(
+            temp_dir.path().join("es.toml"),
+            r#"
+[download]
+not_found = "⚠️ Artículo no disponible\n\nDOI: {doi}\nError: {error}"
+"#,
+        )
+        .unwrap();
+
+        let settings = LocaleSettings {
+            language: "es".to_string(),
+            catalog_dir: Some(temp_dir.path().to_path_buf()),
+        };
+        let catalog = MessageCatalog::load(&settings).unwrap();
+        assert_eq!(catalog.language(), "es");
+
+        let message = catalog.format(
+            "download.not_found",
+            &[
+                ("doi", "10.1000/missing"),
+                ("error", "not found"),
+                ("time", "now"),
+            ],
+        );
+        assert_eq!(
+            message,
+            "⚠️ Artículo no disponible\n\nDOI: 10.1000/missing\nError: not found"
+        );
+
+        // Keys the catalog leaves out stay in English
+        let network = catalog.format("download.network_error", &[("doi", "10.1000/x")]);
+        assert!(network.starts_with("⚠️ Network error while downloading\n\nDOI: 10.1000/x"));
+    }
+}
@@ -1,5 +1,7 @@
 // pub mod command_integration;
 pub mod handler;
+pub mod messages;
+pub mod shutdown;
 pub mod transport;
 
 use crate::{Config, Error, Result};
@@ -11,10 +13,14 @@ use tracing::{info, warn};
 
 // pub use command_integration::CommandIntegratedHandler;
 pub use handler::ResearchServerHandler;
+pub use messages::MessageCatalog;
+pub use shutdown::FlushHooks;
 
 pub struct Server {
     config: Arc<Config>,
     cancellation_token: CancellationToken,
+    /// State persisted during graceful shutdown
+    flush_hooks: FlushHooks,
 }
 
 impl Server {
@@ -23,6 +29,7 @@ impl Server {
         Self {
             config: Arc::new(config),
             cancellation_token: CancellationToken::new(),
+            flush_hooks: FlushHooks::new(),
         }
     }
 
@@ -31,6 +38,7 @@ impl Server {
         Self {
             config,
             cancellation_token: CancellationToken::new(),
+            flush_hooks: FlushHooks::new(),
         }
     }
 
@@ -46,6 +54,10 @@ impl Server {
 
         info!("MCP server handler initialized successfully");
 
+        // Probe providers while the transport comes up
+        handler.spawn_provider_warm_up();
+        handler.register_flush_hooks(&self.flush_hooks);
+
         // Setup signal handlers
         let shutdown_token = self.cancellation_token.clone();
         tokio::spawn(async move {
@@ -118,19 +130,22 @@ impl Server {
     async fn graceful_shutdown(&self) -> Result<()> {
         info!("Performing graceful shutdown");
 
-        // Here we would:
-        // 1. Stop accepting new requests
-        // 2. Wait for existing requests to complete
-        // 3. Close connections cleanly
-        // 4. Save any necessary state
-
-        // For now, just a placeholder
-        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        // Persist in-memory state, each hook bounded by the shutdown timeout
+        let flush_timeout =
+            std::time::Duration::from_secs(self.config.server.graceful_shutdown_timeout_secs);
+        self.flush_hooks.run(flush_timeout).await;
 
         info!("Graceful shutdown completed");
         Ok(())
     }
 
+    /// Hooks run during graceful shutdown; register one to persist state
+    /// that would otherwise be lost
+    #[must_use]
+    pub const fn flush_hooks(&self) -> &FlushHooks {
+        &self.flush_hooks
+    }
+
     pub async fn shutdown(&self) {
         warn!("Initiating server shutdown");
         self.cancellation_token.cancel();
@@ -171,4 +186,28 @@ mod tests {
         server.shutdown().await;
         assert!(server.cancellation_token.is_cancelled());
     }
+
+    #[tokio::test]
+    async fn test_graceful_shutdown_runs_registered_flush_hook() {
+        let mut config = Config::default();
+        config.server.graceful_shutdown_timeout_secs = 1;
+        let server = Server::new(config);
+        let flushed = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let flag = Arc::clone(&flushed);
+        server
+            .flush_hooks()
+            .register("test cache", move || async move {
+                flag.store(true, std::sync::atomic::Ordering::SeqCst);
+                Ok(())
+            });
+        // A hung hook must not keep the others from completing
+        server
+            .flush_hooks()
+            .register("hung", || std::future::pending::<Result<()>>());
+
+        server.graceful_shutdown().await.unwrap();
+
+        assert!(flushed.load(std::sync::atomic::Ordering::SeqCst));
+        assert!(server.flush_hooks().names().is_empty());
+    }
 }
@@ -0,0 +1,98 @@
+//! Flush hooks run during graceful shutdown.
+//!
+//! Tools and the handler register a hook for each piece of in-memory state
+//! that should outlive the process (rate-limit history, caches). On shutdown
+//! every hook runs once, concurrently, and a hook that fails or overruns its
+//! time budget is logged without holding up the others.
+
+use crate::Result;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::task::JoinSet;
+use tracing::{debug, info, warn};
+
+/// Future returned by a flush hook
+pub type FlushFuture = Pin<Box<dyn Future<Output = Result<()>> + Send>>;
+
+type FlushHook = Box<dyn FnOnce() -> FlushFuture + Send>;
+
+/// Hooks that persist in-memory state when the server shuts down
+#[derive(Clone, Default)]
+pub struct FlushHooks {
+    hooks: Arc<Mutex<Vec<(String, FlushHook)>>>,
+}
+
+impl std::fmt::Debug for FlushHooks {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FlushHooks")
+            .field("hooks", &self.names())
+            .finish()
+    }
+}
+
+impl FlushHooks {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Run `hook` at shutdown; `name` identifies it in the logs
+    pub fn register<F, Fut>(&self, name: impl Into<String>, hook: F)
+    where
+        F: FnOnce() -> Fut + Send + 'static,
+        Fut: Future<Output = Result<()>> + Send + 'static,
+    {
+        let name = name.into();
+        debug!("Registered shutdown flush hook: {}", name);
+        self.lock()
+            .push((name, Box::new(move || Box::pin(hook()) as FlushFuture)));
+    }
+
+    /// Names of the hooks not yet run, in registration order
+    #[must_use]
+    pub fn names(&self) -> Vec<String> {
+        self.lock().iter().map(|(name, _)| name.clone()).collect()
+    }
+
+    /// Run every registered hook concurrently, each allowed `timeout`.
+    /// Hooks run at most once. Returns the number that completed successfully.
+    pub async fn run(&self, timeout: Duration) -> usize {
+        let hooks = std::mem::take(&mut *self.lock());
+        if hooks.is_empty() {
+            return 0;
+        }
+        info!("Running {} shutdown flush hooks", hooks.len());
+
+        let mut tasks = JoinSet::new();
+        for (name, hook) in hooks {
+            tasks.spawn(async move {
+                let outcome = tokio::time::timeout(timeout, hook()).await;
+                (name, outcome)
+            });
+        }
+
+        let mut flushed = 0;
+        while let Some(joined) = tasks.join_next().await {
+            match joined {
+                Ok((name, Ok(Ok(())))) => {
+                    debug!("Flush hook {} completed", name);
+                    flushed += 1;
+                }
+                Ok((name, Ok(Err(e)))) => warn!("Flush hook {} failed: {}", name, e),
+                Ok((name, Err(_))) => {
+                    warn!("Flush hook {} did not finish within {:?}", name, timeout);
+                }
+                Err(e) => warn!("Flush hook task failed: {}", e),
+            }
+        }
+        flushed
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Vec<(String, FlushHook)>> {
+        self.hooks
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
@@ -1,3 +1,6 @@
+use crate::tools::cleanup::CleanupDownloadsInput;
+use crate::tools::search::SearchType;
+use crate::tools::{CleanupTool, SearchDiffTool, SearchTool};
 use crate::{Config, Result, Server};
 use daemonize::Daemonize;
 use serde::{Deserialize, Serialize};
@@ -14,6 +17,7 @@ use tracing::{error, info, instrument, warn};
 use super::health::HealthCheck;
 use super::pid::PidFile;
 use super::signals::SignalHandler;
+use super::watch::{QueryWatch, QueryWatcher};
 
 /// Daemon configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -46,6 +50,11 @@ pub struct DaemonConfig {
     pub max_restart_attempts: u32,
     /// Restart delay in seconds
     pub restart_delay_secs: u64,
+    /// How often watched queries are rerun, in seconds
+    pub watch_interval_secs: u64,
+    /// How often the download retention policy is applied, in seconds
+    /// (0 = never)
+    pub cleanup_interval_secs: u64,
 }
 
 impl Default for DaemonConfig {
@@ -65,6 +74,8 @@ impl Default for DaemonConfig {
             auto_restart: true,
             max_restart_attempts: 3,
             restart_delay_secs: 5,
+            watch_interval_secs: 3600,
+            cleanup_interval_secs: 0,
         }
     }
 }
@@ -92,6 +103,7 @@ pub struct DaemonService {
     shutdown_tx: watch::Sender<bool>,
     shutdown_rx: watch::Receiver<bool>,
     task_monitor: TaskMonitor,
+    query_watcher: Arc<QueryWatcher>,
 }
 
 impl DaemonService {
@@ -99,7 +111,22 @@ impl DaemonService {
     pub fn new(config: Arc<Config>, daemon_config: DaemonConfig) -> Result<Self> {
         info!("Initializing daemon service");
 
-        let health_check = Arc::new(HealthCheck::new(daemon_config.health_port));
+        // Watched queries are diffed through search_diff result sets, next to
+        // which the watch list itself is kept
+        let search_tool = Arc::new(SearchTool::new(config.clone())?);
+        let search_diff = Arc::new(SearchDiffTool::new(
+            search_tool,
+            config.search_diff.directory.clone(),
+        ));
+        let query_watcher = Arc::new(QueryWatcher::new(
+            search_diff,
+            config.search_diff.directory.join("watches.json"),
+            Duration::from_secs(daemon_config.watch_interval_secs),
+        )?);
+
+        let health_check = Arc::new(
+            HealthCheck::new(daemon_config.health_port).with_query_watcher(query_watcher.clone()),
+        );
         let signal_handler = SignalHandler::new()?;
         let (shutdown_tx, shutdown_rx) = watch::channel(false);
         let task_monitor = TaskMonitor::new();
@@ -115,6 +142,7 @@ impl DaemonService {
             shutdown_tx,
             shutdown_rx,
             task_monitor,
+            query_watcher,
         })
     }
 
@@ -153,6 +181,12 @@ impl DaemonService {
         // Start resource monitoring
         let monitor_handle = self.start_resource_monitor();
 
+        // Rerun watched queries in the background
+        let watch_handle = self.start_query_watcher();
+
+        // Prune old downloads in the background
+        let cleanup_handle = self.start_download_cleanup();
+
         // Initialize and start the MCP server
         self.server = Some(Arc::new(Server::new_with_arc(self.config.clone())));
 
@@ -207,6 +241,10 @@ impl DaemonService {
         // Cleanup
         health_handle.abort();
         monitor_handle.abort();
+        watch_handle.abort();
+        if let Some(handle) = cleanup_handle {
+            handle.abort();
+        }
 
         if let Some(mut pid_file) = self.pid_file.take() {
             pid_file.remove()?;
@@ -366,6 +404,78 @@ impl DaemonService {
         })
     }
 
+    /// Rerun due watched queries every minute, or every watch interval if
+    /// that is shorter
+    fn start_query_watcher(&self) -> tokio::task::JoinHandle<()> {
+        let watcher = self.query_watcher.clone();
+        let period = Duration::from_secs(self.daemon_config.watch_interval_secs.clamp(1, 60));
+        let mut shutdown_rx = self.shutdown_rx.clone();
+
+        tokio::spawn(async move {
+            let mut interval = interval(period);
+
+            loop {
+                tokio::select! {
+                    _ = interval.tick() => {
+                        if let Err(e) = watcher.run_due(SystemTime::now()).await {
+                            error!("Failed to run watched queries: {}", e);
+                        }
+                    }
+                    _ = shutdown_rx.changed() => {
+                        info!("Query watcher shutting down");
+                        break;
+                    }
+                }
+            }
+        })
+    }
+
+    /// Apply the download retention policy every cleanup interval. Returns
+    /// `None` when the interval is 0 or no retention limit is configured.
+    fn start_download_cleanup(&self) -> Option<tokio::task::JoinHandle<()>> {
+        let downloads = &self.config.downloads;
+        if self.daemon_config.cleanup_interval_secs == 0
+            || (downloads.retention_days.is_none() && downloads.retention_max_size_mb.is_none())
+        {
+            return None;
+        }
+
+        let cleanup_tool = CleanupTool::new(self.config.clone());
+        let period = Duration::from_secs(self.daemon_config.cleanup_interval_secs);
+        let mut shutdown_rx = self.shutdown_rx.clone();
+
+        Some(tokio::spawn(async move {
+            let mut interval = interval(period);
+
+            loop {
+                tokio::select! {
+                    _ = interval.tick() => {
+                        if let Err(e) = cleanup_tool.cleanup(CleanupDownloadsInput::default()).await {
+                            error!("Failed to clean up downloads: {}", e);
+                        }
+                    }
+                    _ = shutdown_rx.changed() => {
+                        info!("Download cleanup shutting down");
+                        break;
+                    }
+                }
+            }
+        }))
+    }
+
+    /// Start rerunning `query` every watch interval, reporting papers that
+    /// were not returned before
+    pub async fn watch_query(&self, query: &str, limit: u32) -> Result<QueryWatch> {
+        self.query_watcher
+            .watch_query(query, SearchType::Auto, limit)
+            .await
+    }
+
+    /// Watched queries with the new DOIs found by their latest run
+    pub async fn watched_queries(&self) -> Vec<QueryWatch> {
+        self.query_watcher.watches().await
+    }
+
     /// Get current memory usage in MB
     fn get_memory_usage() -> u64 {
         // Platform-specific memory usage
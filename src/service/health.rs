@@ -1,3 +1,4 @@
+use super::watch::QueryWatcher;
 use axum::{extract::State, http::StatusCode, response::Json, routing::get, Router};
 use serde::{Deserialize, Serialize};
 use std::net::SocketAddr;
@@ -42,6 +43,7 @@ impl Default for HealthChecks {
 pub struct HealthCheck {
     port: u16,
     status: Arc<RwLock<HealthStatus>>,
+    query_watcher: Option<Arc<QueryWatcher>>,
 }
 
 impl HealthCheck {
@@ -58,9 +60,17 @@ impl HealthCheck {
         Self {
             port,
             status: Arc::new(RwLock::new(status)),
+            query_watcher: None,
         }
     }
 
+    /// Also serve the daemon's watched queries and their new papers at `/watches`
+    #[must_use]
+    pub fn with_query_watcher(mut self, query_watcher: Arc<QueryWatcher>) -> Self {
+        self.query_watcher = Some(query_watcher);
+        self
+    }
+
     /// Start the health check HTTP endpoint
     #[instrument(skip(self))]
     pub async fn start(&self) -> crate::Result<()> {
@@ -84,11 +94,19 @@ impl HealthCheck {
 
     /// Create the axum application
     fn create_app(&self) -> Router {
-        Router::new()
+        let mut router = Router::new()
             .route("/health", get(health_handler))
             .route("/health/live", get(liveness_handler))
             .route("/health/ready", get(readiness_handler))
-            .route("/health/startup", get(startup_handler))
+            .route("/health/startup", get(startup_handler));
+        if let Some(query_watcher) = &self.query_watcher {
+            let query_watcher = query_watcher.clone();
+            router = router.route(
+                "/watches",
+                get(move || async move { Json(query_watcher.watches().await) }),
+            );
+        }
+        router
             .with_state(self.status.clone())
             .layer(TraceLayer::new_for_http())
     }
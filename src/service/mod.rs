@@ -2,8 +2,10 @@ pub mod daemon;
 pub mod health;
 pub mod pid;
 pub mod signals;
+pub mod watch;
 
 pub use daemon::{DaemonConfig, DaemonService};
 pub use health::{HealthCheck, HealthStatus};
 pub use pid::PidFile;
 pub use signals::SignalHandler;
+pub use watch::{QueryWatch, QueryWatcher};
@@ -0,0 +1,254 @@
+use crate::tools::search::SearchType;
+use crate::tools::search_diff::{SearchDiffInput, SearchDiffTool};
+use crate::Result;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+use tokio::sync::RwLock;
+use tracing::{info, warn};
+
+/// A saved query the daemon reruns on a schedule
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueryWatch {
+    pub id: String,
+    pub query: String,
+    pub search_type: SearchType,
+    pub limit: u32,
+    /// `search_diff` result set covering every paper seen so far
+    pub result_set_id: Option<String>,
+    pub last_run: Option<SystemTime>,
+    /// DOIs that first appeared in the latest run. The first run only records
+    /// what the query already returns, so it reports none.
+    pub new_dois: Vec<String>,
+}
+
+/// Reruns watched queries once their interval has passed and records the
+/// papers that are new since the previous run. Watches are saved to
+/// `state_path` after every change so they survive restarts.
+pub struct QueryWatcher {
+    search_diff: Arc<SearchDiffTool>,
+    state_path: PathBuf,
+    interval: Duration,
+    watches: RwLock<Vec<QueryWatch>>,
+}
+
+impl QueryWatcher {
+    /// Create a watcher, restoring any watches saved in `state_path`
+    pub fn new(
+        search_diff: Arc<SearchDiffTool>,
+        state_path: PathBuf,
+        interval: Duration,
+    ) -> Result<Self> {
+        let watches = match std::fs::read_to_string(&state_path) {
+            Ok(contents) => serde_json::from_str(&contents)?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+            Err(e) => return Err(e.into()),
+        };
+
+        Ok(Self {
+            search_diff,
+            state_path,
+            interval,
+            watches: RwLock::new(watches),
+        })
+    }
+
+    /// Start watching `query`. Watching a query that is already watched
+    /// returns the existing watch.
+    pub async fn watch_query(
+        &self,
+        query: &str,
+        search_type: SearchType,
+        limit: u32,
+    ) -> Result<QueryWatch> {
+        let query = query.trim();
+        if query.is_empty() {
+            return Err(crate::Error::InvalidInput {
+                field: "query".to_string(),
+                reason: "Query cannot be empty".to_string(),
+            });
+        }
+
+        let mut watches = self.watches.write().await;
+        if let Some(existing) = watches.iter().find(|w| w.query.eq_ignore_ascii_case(query)) {
+            return Ok(existing.clone());
+        }
+
+        let watch = QueryWatch {
+            id: uuid::Uuid::new_v4().to_string(),
+            query: query.to_string(),
+            search_type,
+            limit,
+            result_set_id: None,
+            last_run: None,
+            new_dois: Vec::new(),
+        };
+        watches.push(watch.clone());
+        self.save(&watches).await?;
+
+        info!("Watching query '{}' as {}", watch.query, watch.id);
+        Ok(watch)
+    }
+
+    /// Stop watching the query with `id`. Returns whether it was watched.
+    pub async fn unwatch(&self, id: &str) -> Result<bool> {
+        let mut watches = self.watches.write().await;
+        let before = watches.len();
+        watches.retain(|w| w.id != id);
+        if watches.len() == before {
+            return Ok(false);
+        }
+        self.save(&watches).await?;
+        Ok(true)
+    }
+
+    /// All watches with the new DOIs found by their latest run
+    pub async fn watches(&self) -> Vec<QueryWatch> {
+        self.watches.read().await.clone()
+    }
+
+    /// Rerun every watch that has not run within the interval as of `now`.
+    /// Returns the watches that ran; a watch whose search fails is retried on
+    /// the next call.
+    pub async fn run_due(&self, now: SystemTime) -> Result<Vec<QueryWatch>> {
+        // Searches run without the lock so `watches()` stays responsive
+        let interval = self.interval;
+        let due: Vec<QueryWatch> = {
+            let watches = self.watches.read().await;
+            watches
+                .iter()
+                .filter(|watch| {
+                    watch.last_run.map_or(true, |last_run| {
+                        now.duration_since(last_run)
+                            .is_ok_and(|elapsed| elapsed >= interval)
+                    })
+                })
+                .cloned()
+                .collect()
+        };
+
+        let mut ran = Vec::new();
+        for mut watch in due {
+            let diff = match self
+                .search_diff
+                .diff(SearchDiffInput {
+                    query: watch.query.clone(),
+                    search_type: watch.search_type.clone(),
+                    limit: watch.limit,
+                    since: watch.result_set_id.clone(),
+                })
+                .await
+            {
+                Ok(diff) => diff,
+                Err(e) => {
+                    warn!("Watched query '{}' failed: {}", watch.query, e);
+                    continue;
+                }
+            };
+
+            watch.new_dois = if watch.result_set_id.is_some() {
+                diff.new_papers
+                    .iter()
+                    .map(|paper| paper.metadata.doi.clone())
+                    .filter(|doi| !doi.is_empty())
+                    .collect()
+            } else {
+                Vec::new()
+            };
+            watch.result_set_id = Some(diff.result_set_id);
+            watch.last_run = Some(now);
+
+            if !watch.new_dois.is_empty() {
+                info!(
+                    "Watched query '{}' has {} new papers: {:?}",
+                    watch.query,
+                    watch.new_dois.len(),
+                    watch.new_dois
+                );
+            }
+            ran.push(watch);
+        }
+
+        if ran.is_empty() {
+            return Ok(ran);
+        }
+        let mut watches = self.watches.write().await;
+        // Watches removed while their search ran stay removed
+        for updated in &ran {
+            if let Some(watch) = watches.iter_mut().find(|watch| watch.id == updated.id) {
+                *watch = updated.clone();
+            }
+        }
+        self.save(&watches).await?;
+        Ok(ran)
+    }
+
+    async fn save(&self, watches: &[QueryWatch]) -> Result<()> {
+        if let Some(parent) = self.state_path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        tokio::fs::write(&self.state_path, serde_json::to_vec_pretty(watches)?).await?;
+        Ok(())
+    }
+}
+
+impl std::fmt::Debug for QueryWatcher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("QueryWatcher")
+            .field("state_path", &self.state_path)
+            .field("interval", &self.interval)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tools::search_diff::tests::rounds_search_tool;
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn test_run_due_reports_new_paper_on_second_run() {
+        let search_tool = rounds_search_tool(vec![
+            vec!["10.1000/a", "10.1000/b"],
+            vec!["10.1000/b", "10.1000/c", "10.1000/a"],
+        ]);
+        let temp_dir = TempDir::new().unwrap();
+        let search_diff = Arc::new(SearchDiffTool::new(
+            Arc::new(search_tool),
+            temp_dir.path().to_path_buf(),
+        ));
+        let state_path = temp_dir.path().join("watches.json");
+        let interval = Duration::from_secs(3600);
+        let watcher = QueryWatcher::new(search_diff.clone(), state_path.clone(), interval).unwrap();
+        let watch = watcher
+            .watch_query("protein folding", SearchType::Auto, 10)
+            .await
+            .unwrap();
+
+        let start = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let first = watcher.run_due(start).await.unwrap();
+        assert_eq!(first.len(), 1);
+        assert!(first[0].new_dois.is_empty());
+
+        // Not due yet: the provider's second round must not be consumed
+        let early = watcher
+            .run_due(start + Duration::from_secs(60))
+            .await
+            .unwrap();
+        assert!(early.is_empty());
+
+        let second = watcher.run_due(start + interval).await.unwrap();
+        assert_eq!(second.len(), 1);
+        assert_eq!(second[0].new_dois, vec!["10.1000/c".to_string()]);
+
+        // The state survives a restart
+        let restored = QueryWatcher::new(search_diff, state_path, interval).unwrap();
+        let saved = restored.watches().await;
+        assert_eq!(saved.len(), 1);
+        assert_eq!(saved[0].id, watch.id);
+        assert_eq!(saved[0].new_dois, vec!["10.1000/c".to_string()]);
+        assert_eq!(saved[0].last_run, Some(start + interval));
+    }
+}
@@ -0,0 +1,179 @@
+use crate::client::providers::{SearchQuery, ALL_TERMS_PARAM};
+
+/// One term of a boolean query: a run of plain words or a quoted phrase
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryTerm {
+    pub text: String,
+    pub quoted: bool,
+}
+
+impl QueryTerm {
+    fn render(&self) -> String {
+        if self.quoted {
+            format!("\"{}\"", self.text)
+        } else {
+            self.text.clone()
+        }
+    }
+}
+
+/// A query using upper-case `AND`/`OR` operators and quoted phrases. `AND`
+/// binds tighter than `OR`, so the query is a list of alternatives, each a
+/// list of terms that must all match.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BooleanQuery {
+    pub alternatives: Vec<Vec<QueryTerm>>,
+}
+
+enum Token {
+    Word(String),
+    Phrase(String),
+    And,
+    Or,
+}
+
+impl BooleanQuery {
+    /// Parse `query`, returning `None` when it has no operators (or nothing
+    /// left to combine), so plain queries are searched exactly as typed.
+    /// Lower-case "and"/"or" are ordinary words.
+    #[must_use]
+    pub fn parse(query: &str) -> Option<Self> {
+        let tokens = Self::tokenize(query);
+        if !tokens.iter().any(|t| matches!(t, Token::And | Token::Or)) {
+            return None;
+        }
+
+        let mut alternatives = Vec::new();
+        let mut group: Vec<QueryTerm> = Vec::new();
+        let mut words: Vec<String> = Vec::new();
+        let flush = |words: &mut Vec<String>, group: &mut Vec<QueryTerm>| {
+            if !words.is_empty() {
+                group.push(QueryTerm {
+                    text: words.join(" "),
+                    quoted: false,
+                });
+                words.clear();
+            }
+        };
+
+        for token in tokens {
+            match token {
+                Token::Word(word) => words.push(word),
+                Token::Phrase(text) => {
+                    flush(&mut words, &mut group);
+                    group.push(QueryTerm { text, quoted: true });
+                }
+                Token::And => flush(&mut words, &mut group),
+                Token::Or => {
+                    flush(&mut words, &mut group);
+                    if !group.is_empty() {
+                        alternatives.push(std::mem::take(&mut group));
+                    }
+                }
+            }
+        }
+        flush(&mut words, &mut group);
+        if !group.is_empty() {
+            alternatives.push(group);
+        }
+
+        let combines_terms = alternatives.len() > 1 || alternatives.iter().any(|a| a.len() > 1);
+        combines_terms.then_some(Self { alternatives })
+    }
+
+    fn tokenize(query: &str) -> Vec<Token> {
+        let mut tokens = Vec::new();
+        let mut chars = query.chars().peekable();
+        while let Some(c) = chars.next() {
+            if c.is_whitespace() {
+                continue;
+            }
+            if c == '"' {
+                // An unterminated quote runs to the end of the query
+                let phrase: String = chars.by_ref().take_while(|&c| c != '"').collect();
+                let phrase = phrase.split_whitespace().collect::<Vec<_>>().join(" ");
+                if !phrase.is_empty() {
+                    tokens.push(Token::Phrase(phrase));
+                }
+                continue;
+            }
+
+            let mut word = c.to_string();
+            while let Some(next) = chars.next_if(|&c| !c.is_whitespace() && c != '"') {
+                word.push(next);
+            }
+            tokens.push(match word.as_str() {
+                "AND" => Token::And,
+                "OR" => Token::Or,
+                _ => Token::Word(word),
+            });
+        }
+        tokens
+    }
+
+    /// Provider queries to run, one per alternative; their results are
+    /// merged. Each carries its terms under [`ALL_TERMS_PARAM`] for
+    /// providers with a native `AND` syntax, while `query` holds the terms
+    /// space-separated for the rest.
+    #[must_use]
+    pub fn search_queries(&self, base: &SearchQuery) -> Vec<SearchQuery> {
+        self.alternatives
+            .iter()
+            .map(|terms| {
+                let mut params = base.params.clone();
+                let texts: Vec<&str> = terms.iter().map(|t| t.text.as_str()).collect();
+                params.insert(
+                    ALL_TERMS_PARAM.to_string(),
+                    serde_json::to_string(&texts).unwrap_or_default(),
+                );
+                SearchQuery {
+                    query: terms
+                        .iter()
+                        .map(QueryTerm::render)
+                        .collect::<Vec<_>>()
+                        .join(" "),
+                    params,
+                    ..base.clone()
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn word(text: &str) -> QueryTerm {
+        QueryTerm {
+            text: text.to_string(),
+            quoted: false,
+        }
+    }
+
+    fn phrase(text: &str) -> QueryTerm {
+        QueryTerm {
+            text: text.to_string(),
+            quoted: true,
+        }
+    }
+
+    #[test]
+    fn test_parse_and_or_with_phrases() {
+        let query = BooleanQuery::parse("\"deep learning\" AND protein folding OR Smith").unwrap();
+        assert_eq!(
+            query.alternatives,
+            vec![
+                vec![phrase("deep learning"), word("protein folding")],
+                vec![word("Smith")],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_plain_query_is_none() {
+        assert!(BooleanQuery::parse("salt and pepper").is_none());
+        assert!(BooleanQuery::parse("\"exact phrase\" search").is_none());
+        assert!(BooleanQuery::parse("AND Smith").is_none());
+    }
+}
@@ -18,6 +18,8 @@ pub struct CategorizationConfig {
     pub default_category: String,
     /// Maximum number of abstracts to include in categorization
     pub max_abstracts: usize,
+    /// Infer a category from paper metadata when a download has none
+    pub auto_categorize: bool,
 }
 
 impl Default for CategorizationConfig {
@@ -27,10 +29,82 @@ impl Default for CategorizationConfig {
             max_prompt_length: 4000,
             default_category: "research_papers".to_string(),
             max_abstracts: 5,
+            auto_categorize: false,
         }
     }
 }
 
+/// Research domains used for automatic categorization, with the lowercase
+/// keywords that indicate them, matched at the start of a word. Earlier
+/// domains win ties.
+const DOMAIN_KEYWORDS: &[(&str, &[&str])] = &[
+    (
+        "Physics",
+        &[
+            "physics",
+            "physical review",
+            "quantum",
+            "particle",
+            "astrophysic",
+            "cosmolog",
+            "condensed matter",
+            "relativity",
+            "optics",
+            "thermodynamic",
+        ],
+    ),
+    (
+        "Mathematics",
+        &[
+            "mathemat",
+            "algebra",
+            "topology",
+            "theorem",
+            "combinatoric",
+            "number theory",
+            "geometry",
+        ],
+    ),
+    (
+        "Computer_Science",
+        &[
+            "computer science",
+            "computing",
+            "machine learning",
+            "neural network",
+            "algorithm",
+            "software",
+            "artificial intelligence",
+        ],
+    ),
+    (
+        "Chemistry",
+        &["chemistry", "chemical", "molecule", "catalys", "synthesis"],
+    ),
+    (
+        "Biology",
+        &["biology", "cell", "genom", "protein", "gene ", "ecology"],
+    ),
+    (
+        "Medicine",
+        &[
+            "medicine", "medical", "clinical", "patient", "lancet", "disease",
+        ],
+    ),
+    ("Economics", &["economic", "finance", "market", "monetary"]),
+];
+
+/// Whether `keyword` occurs in `text` at the start of a word, so that
+/// stems like "genom" match "genomics" but "cell" doesn't match "miscellany"
+fn starts_word_in(text: &str, keyword: &str) -> bool {
+    text.match_indices(keyword).any(|(start, _)| {
+        text[..start]
+            .chars()
+            .next_back()
+            .map_or(true, |c| !c.is_alphanumeric())
+    })
+}
+
 /// Service for categorizing papers using LLM
 #[derive(Clone)]
 pub struct CategorizationService {
@@ -212,6 +286,48 @@ impl CategorizationService {
         current_category
     }
 
+    /// Infer a research-domain category from a paper's journal, title and
+    /// abstract. Journal matches weigh most; returns `None` when no domain
+    /// keyword matches.
+    #[must_use]
+    pub fn infer_category(&self, paper: &PaperMetadata) -> Option<String> {
+        let fields = [
+            (paper.journal.as_deref(), 3),
+            (paper.title.as_deref(), 2),
+            (paper.abstract_text.as_deref(), 1),
+        ];
+        let fields: Vec<(String, usize)> = fields
+            .iter()
+            .filter_map(|(text, weight)| text.map(|t| (t.to_lowercase(), *weight)))
+            .collect();
+
+        let mut best: Option<(&str, usize)> = None;
+        for (category, keywords) in DOMAIN_KEYWORDS {
+            let score: usize = fields
+                .iter()
+                .map(|(text, weight)| {
+                    keywords.iter().filter(|k| starts_word_in(text, k)).count() * weight
+                })
+                .sum();
+            if score > 0 && best.map_or(true, |(_, best_score)| score > best_score) {
+                best = Some((category, score));
+            }
+        }
+
+        let (category, score) = best?;
+        debug!(
+            "Inferred category '{}' (score {}) for {}",
+            category, score, paper.doi
+        );
+        Some(category.to_string())
+    }
+
+    /// Check whether categories should be inferred for uncategorized downloads
+    #[must_use]
+    pub const fn auto_categorize_enabled(&self) -> bool {
+        self.config.enabled && self.config.auto_categorize
+    }
+
     /// Check if categorization is enabled
     #[must_use]
     pub const fn is_enabled(&self) -> bool {
@@ -294,6 +410,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_infer_category_from_metadata() {
+        let service = create_test_service();
+
+        let mut paper = PaperMetadata::new("10.1103/physrevlett.1".to_string());
+        paper.journal = Some("Physical Review Letters".to_string());
+        paper.title = Some("Observation of quantum entanglement".to_string());
+        assert_eq!(service.infer_category(&paper).as_deref(), Some("Physics"));
+
+        let mut paper = PaperMetadata::new("10.1000/unknown".to_string());
+        paper.title = Some("Notes on a miscellany".to_string());
+        assert_eq!(service.infer_category(&paper), None);
+    }
+
     #[test]
     fn test_resolve_category_conflict() {
         let service = create_test_service();
@@ -329,6 +459,7 @@ mod tests {
             doi: "10.1000/test1".to_string(),
             title: Some("Machine Learning in Healthcare".to_string()),
             authors: vec!["Smith, J.".to_string()],
+            raw_authors: Vec::new(),
             journal: None,
             year: Some(2023),
             abstract_text: Some(
@@ -337,6 +468,8 @@ mod tests {
             ),
             pdf_url: None,
             file_size: None,
+            license: None,
+            is_open_access: None,
         }];
 
         let prompt = service.generate_category_prompt("machine learning", &papers);
@@ -358,11 +491,14 @@ mod tests {
             doi: "10.1000/test1".to_string(),
             title: Some("Very Long Title".to_string()),
             authors: vec!["Author".to_string()],
+            raw_authors: Vec::new(),
             journal: None,
             year: Some(2023),
             abstract_text: Some("A".repeat(1000)), // Very long abstract
             pdf_url: None,
             file_size: None,
+            license: None,
+            is_open_access: None,
         }];
 
         let prompt = service.generate_category_prompt("test query", &papers);
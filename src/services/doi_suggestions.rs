@@ -0,0 +1,200 @@
+use crate::client::http_client_builder;
+use crate::config::HttpConfig;
+use crate::Result;
+use reqwest::Client;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use tracing::{debug, warn};
+
+/// Configuration for suggesting corrected DOIs when a DOI is not found
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct DoiSuggestionConfig {
+    /// Whether failed DOI lookups query CrossRef for likely corrections.
+    /// Off by default because each failed lookup then sends the requested
+    /// DOI (or title) to `crossref_url`.
+    pub enabled: bool,
+    /// CrossRef works endpoint
+    pub crossref_url: String,
+    /// Maximum number of suggestions returned
+    pub max_suggestions: usize,
+    /// Largest edit distance from the requested DOI accepted when no title is
+    /// given. Look-alike characters (O/0, l/1) do not count towards it.
+    pub max_distance: usize,
+}
+
+impl Default for DoiSuggestionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            crossref_url: "https://api.crossref.org/works".to_string(),
+            max_suggestions: 3,
+            max_distance: 3,
+        }
+    }
+}
+
+/// A DOI that is likely what the user meant
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct DoiSuggestion {
+    /// Suggested DOI
+    pub doi: String,
+    /// Title registered for the suggested DOI
+    pub title: Option<String>,
+    /// Edit distance from the requested DOI
+    pub distance: usize,
+}
+
+#[derive(Deserialize)]
+struct CrossRefResponse {
+    message: CrossRefMessage,
+}
+
+#[derive(Deserialize)]
+struct CrossRefMessage {
+    #[serde(default)]
+    items: Vec<CrossRefItem>,
+}
+
+#[derive(Deserialize)]
+struct CrossRefItem {
+    #[serde(rename = "DOI")]
+    doi: String,
+    #[serde(default)]
+    title: Vec<String>,
+}
+
+/// Looks up likely corrections for DOIs that could not be resolved
+#[derive(Debug, Clone)]
+pub struct DoiSuggester {
+    client: Client,
+    config: DoiSuggestionConfig,
+}
+
+impl DoiSuggester {
+    /// Create a suggester querying `config.crossref_url`, with a client that
+    /// follows the `[http]` settings
+    pub fn new(config: DoiSuggestionConfig, http: &HttpConfig, timeout: Duration) -> Result<Self> {
+        let client = http_client_builder(http)
+            .timeout(timeout)
+            .user_agent("knowledge_accumulator_mcp/0.2.1 (Academic Research Tool)")
+            .build()
+            .map_err(|e| crate::Error::Service(format!("Failed to create HTTP client: {e}")))?;
+
+        Ok(Self { client, config })
+    }
+
+    /// Build the suggester from config. Returns `None` when suggestions are
+    /// disabled.
+    pub fn from_config(
+        config: &DoiSuggestionConfig,
+        http: &HttpConfig,
+        timeout: Duration,
+    ) -> Result<Option<Self>> {
+        if !config.enabled || config.max_suggestions == 0 {
+            return Ok(None);
+        }
+        Self::new(config.clone(), http, timeout).map(Some)
+    }
+
+    /// Suggest registered DOIs under the same prefix as `doi`, closest first.
+    /// With a `title`, CrossRef is searched by title and every match is kept;
+    /// otherwise the DOI suffix is searched and only near misses are kept.
+    /// Lookup failures yield no suggestions.
+    pub async fn suggest(&self, doi: &str, title: Option<&str>) -> Vec<DoiSuggestion> {
+        let doi = doi
+            .trim()
+            .trim_start_matches("https://doi.org/")
+            .trim_start_matches("doi:");
+        let Some((prefix, suffix)) = doi.split_once('/') else {
+            return Vec::new();
+        };
+        let title = title.map(str::trim).filter(|t| !t.is_empty());
+
+        let items = match self.search(prefix, title.unwrap_or(suffix)).await {
+            Ok(items) => items,
+            Err(e) => {
+                warn!("DOI suggestion lookup for {} failed: {}", doi, e);
+                return Vec::new();
+            }
+        };
+
+        let mut suggestions: Vec<DoiSuggestion> = items
+            .into_iter()
+            .filter(|item| !item.doi.eq_ignore_ascii_case(doi))
+            .map(|item| DoiSuggestion {
+                distance: doi_distance(doi, &item.doi),
+                doi: item.doi,
+                title: item.title.into_iter().next(),
+            })
+            .filter(|s| title.is_some() || s.distance <= self.config.max_distance)
+            .collect();
+        suggestions.sort_by(|a, b| a.distance.cmp(&b.distance).then_with(|| a.doi.cmp(&b.doi)));
+        suggestions.dedup_by(|a, b| a.doi.eq_ignore_ascii_case(&b.doi));
+        suggestions.truncate(self.config.max_suggestions);
+
+        debug!("{} DOI suggestions for {}", suggestions.len(), doi);
+        suggestions
+    }
+
+    async fn search(&self, prefix: &str, query: &str) -> Result<Vec<CrossRefItem>> {
+        let response = self
+            .client
+            .get(&self.config.crossref_url)
+            .query(&[
+                ("filter", format!("prefix:{prefix}")),
+                ("query.bibliographic", query.to_string()),
+                ("select", "DOI,title".to_string()),
+                ("rows", "20".to_string()),
+            ])
+            .send()
+            .await?
+            .error_for_status()?;
+
+        Ok(response.json::<CrossRefResponse>().await?.message.items)
+    }
+}
+
+/// Edit distance between two DOIs, ignoring case and look-alike characters
+fn doi_distance(a: &str, b: &str) -> usize {
+    let normalize = |doi: &str| -> Vec<char> {
+        doi.chars()
+            .map(|c| match c.to_ascii_lowercase() {
+                'o' => '0',
+                'l' | 'i' => '1',
+                c => c,
+            })
+            .collect()
+    };
+    let (a, b) = (normalize(a), normalize(b));
+
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.iter().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != cb);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_doi_distance_ignores_look_alike_characters() {
+        assert_eq!(
+            doi_distance("10.1000/j.jmb.2O20.01.001", "10.1000/J.JMB.2020.01.001"),
+            0
+        );
+        assert_eq!(doi_distance("10.1038/nature1237", "10.1038/nature12373"), 1);
+        assert_eq!(
+            doi_distance("10.1038/nature12373", "10.1038/nature99999"),
+            5
+        );
+    }
+}
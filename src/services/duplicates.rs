@@ -0,0 +1,214 @@
+use crate::client::PaperMetadata;
+use std::collections::HashMap;
+
+/// How [`find_duplicates`] decides that two records describe the same paper
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DuplicateStrategy {
+    /// Same DOI once case and `doi:` / `https://doi.org/` prefixes are ignored
+    ExactDoi,
+    /// Titles whose character-bigram similarity (0.0-1.0) is at least
+    /// `min_similarity`, ignoring case and punctuation
+    FuzzyTitle { min_similarity: f64 },
+}
+
+impl DuplicateStrategy {
+    /// Fuzzy title matching with a threshold that tolerates punctuation,
+    /// casing and small typos but not different papers in a series
+    #[must_use]
+    pub const fn fuzzy_title() -> Self {
+        Self::FuzzyTitle {
+            min_similarity: 0.9,
+        }
+    }
+}
+
+/// Group `papers` that likely describe the same paper. Groups keep the input
+/// order of their records and come back in the order of their first record;
+/// only groups with at least two records are returned.
+#[must_use]
+pub fn find_duplicates(
+    papers: &[PaperMetadata],
+    strategy: DuplicateStrategy,
+) -> Vec<Vec<PaperMetadata>> {
+    duplicate_groups(papers, strategy)
+        .into_iter()
+        .map(|group| group.into_iter().map(|i| papers[i].clone()).collect())
+        .collect()
+}
+
+/// Like [`find_duplicates`], but returns indices into `papers`, for callers
+/// that keep more than the metadata per record
+#[must_use]
+pub fn duplicate_groups(papers: &[PaperMetadata], strategy: DuplicateStrategy) -> Vec<Vec<usize>> {
+    let groups = match strategy {
+        DuplicateStrategy::ExactDoi => {
+            let mut group_of: HashMap<String, usize> = HashMap::new();
+            let mut groups: Vec<Vec<usize>> = Vec::new();
+            for (i, paper) in papers.iter().enumerate() {
+                let doi = canonical_doi(&paper.doi);
+                if doi.is_empty() {
+                    continue;
+                }
+                let group = *group_of.entry(doi).or_insert_with(|| {
+                    groups.push(Vec::new());
+                    groups.len() - 1
+                });
+                groups[group].push(i);
+            }
+            groups
+        }
+        DuplicateStrategy::FuzzyTitle { min_similarity } => {
+            let titles: Vec<Option<String>> = papers
+                .iter()
+                .map(|paper| {
+                    paper
+                        .title
+                        .as_deref()
+                        .map(normalize_title)
+                        .filter(|title| !title.is_empty())
+                })
+                .collect();
+
+            // Greedy single-linkage clustering: each record joins the
+            // first earlier group containing a similar title
+            let mut group_of: Vec<Option<usize>> = vec![None; papers.len()];
+            let mut groups: Vec<Vec<usize>> = Vec::new();
+            for i in 0..papers.len() {
+                let Some(title) = &titles[i] else {
+                    continue;
+                };
+                let existing = (0..i).find(|&j| {
+                    titles[j]
+                        .as_deref()
+                        .is_some_and(|other| title_similarity(title, other) >= min_similarity)
+                });
+                match existing.and_then(|j| group_of[j]) {
+                    Some(group) => {
+                        groups[group].push(i);
+                        group_of[i] = Some(group);
+                    }
+                    None => {
+                        group_of[i] = Some(groups.len());
+                        groups.push(vec![i]);
+                    }
+                }
+            }
+            groups
+        }
+    };
+
+    groups.into_iter().filter(|group| group.len() > 1).collect()
+}
+
+/// DOI without case, whitespace or resolver/`doi:` prefixes
+fn canonical_doi(doi: &str) -> String {
+    let doi = doi.trim().to_lowercase();
+    [
+        "https://doi.org/",
+        "http://doi.org/",
+        "https://dx.doi.org/",
+        "http://dx.doi.org/",
+        "doi:",
+    ]
+    .iter()
+    .find_map(|prefix| doi.strip_prefix(prefix))
+    .map_or_else(|| doi.clone(), |rest| rest.trim().to_string())
+}
+
+/// Lowercase alphanumeric words of a title, single-space separated
+fn normalize_title(title: &str) -> String {
+    title
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                ' '
+            }
+        })
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Sørensen–Dice coefficient over character bigrams of normalized titles
+#[allow(clippy::cast_precision_loss)]
+fn title_similarity(a: &str, b: &str) -> f64 {
+    fn bigrams(s: &str) -> HashMap<(char, char), usize> {
+        let chars: Vec<char> = s.chars().collect();
+        let mut counts = HashMap::new();
+        for pair in chars.windows(2) {
+            *counts.entry((pair[0], pair[1])).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    if a == b {
+        return 1.0;
+    }
+    let (a, b) = (bigrams(a), bigrams(b));
+    let total: usize = a.values().sum::<usize>() + b.values().sum::<usize>();
+    if total == 0 {
+        return 0.0;
+    }
+    let shared: usize = a
+        .iter()
+        .map(|(pair, count)| (*count).min(b.get(pair).copied().unwrap_or(0)))
+        .sum();
+    (2 * shared) as f64 / total as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn paper(doi: &str, title: &str) -> PaperMetadata {
+        let mut paper = PaperMetadata::new(doi.to_string());
+        paper.title = Some(title.to_string());
+        paper
+    }
+
+    fn dois(group: &[PaperMetadata]) -> Vec<&str> {
+        group.iter().map(|p| p.doi.as_str()).collect()
+    }
+
+    #[test]
+    fn test_find_duplicates_groups_near_duplicates() {
+        let papers = vec![
+            paper("10.1000/ABC", "Graph neural networks for citation analysis"),
+            paper("10.1000/attention", "Attention Is All You Need"),
+            paper(
+                "https://doi.org/10.1000/abc",
+                "A survey of protein folding methods",
+            ),
+            paper("10.48550/arxiv.1706.03762", "Attention is all you need."),
+            paper("10.5555/attention-typo", "Attention Is All You Ned"),
+            paper("10.1000/unrelated", "Attention mechanisms in vision"),
+        ];
+
+        let by_doi = find_duplicates(&papers, DuplicateStrategy::ExactDoi);
+        assert_eq!(by_doi.len(), 1);
+        assert_eq!(
+            dois(&by_doi[0]),
+            ["10.1000/ABC", "https://doi.org/10.1000/abc"]
+        );
+
+        let by_title = find_duplicates(&papers, DuplicateStrategy::fuzzy_title());
+        assert_eq!(by_title.len(), 1);
+        assert_eq!(
+            dois(&by_title[0]),
+            [
+                "10.1000/attention",
+                "10.48550/arxiv.1706.03762",
+                "10.5555/attention-typo"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_duplicates_missing_doi_not_grouped() {
+        let papers = vec![paper("", "First"), paper(" ", "Second")];
+        assert!(find_duplicates(&papers, DuplicateStrategy::ExactDoi).is_empty());
+    }
+}
@@ -0,0 +1,244 @@
+use crate::config::DownloadsConfig;
+use crate::Result;
+use futures::StreamExt;
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use tracing::{debug, info, warn};
+
+/// Files grouped by SHA256 digest. Keys and the paths within each group are
+/// sorted, so the same directory always produces the same grouping.
+pub type HashGroups = BTreeMap<String, Vec<PathBuf>>;
+
+/// Parallel file hasher used by maintenance operations over the download
+/// directory (duplicate detection, integrity verification).
+#[derive(Debug, Clone)]
+pub struct FileHasher {
+    workers: usize,
+}
+
+impl FileHasher {
+    /// Create a hasher that runs up to `workers` hashing jobs at once
+    #[must_use]
+    pub fn new(workers: usize) -> Self {
+        Self {
+            workers: workers.max(1),
+        }
+    }
+
+    /// Create a hasher using `downloads.hash_workers`
+    #[must_use]
+    pub fn from_config(config: &DownloadsConfig) -> Self {
+        Self::new(config.hash_workers)
+    }
+
+    /// Number of concurrent hashing jobs
+    #[must_use]
+    pub const fn workers(&self) -> usize {
+        self.workers
+    }
+
+    /// Compute the SHA256 digest of a single file
+    pub async fn hash_file(path: &Path) -> Result<String> {
+        let path = path.to_path_buf();
+        tokio::task::spawn_blocking(move || Self::hash_file_blocking(&path))
+            .await
+            .map_err(|e| crate::Error::Service(format!("Hashing task failed: {e}")))?
+    }
+
+    fn hash_file_blocking(path: &Path) -> Result<String> {
+        let mut file = std::fs::File::open(path)?;
+        let mut hasher = Sha256::new();
+        let mut buffer = [0u8; 8192];
+
+        loop {
+            let bytes_read = file.read(&mut buffer)?;
+            if bytes_read == 0 {
+                break;
+            }
+            hasher.update(&buffer[..bytes_read]);
+        }
+
+        Ok(format!("{:x}", hasher.finalize()))
+    }
+
+    /// Hash files concurrently. Results are returned in input order regardless
+    /// of which job finishes first.
+    pub async fn hash_files(&self, paths: &[PathBuf]) -> Vec<(PathBuf, Result<String>)> {
+        debug!(
+            "Hashing {} files with {} workers",
+            paths.len(),
+            self.workers
+        );
+
+        let mut results: Vec<(usize, PathBuf, Result<String>)> =
+            futures::stream::iter(paths.iter().cloned().enumerate())
+                .map(|(index, path)| async move {
+                    let hash = Self::hash_file(&path).await;
+                    (index, path, hash)
+                })
+                .buffer_unordered(self.workers)
+                .collect()
+                .await;
+
+        results.sort_by_key(|(index, _, _)| *index);
+        results
+            .into_iter()
+            .map(|(_, path, hash)| (path, hash))
+            .collect()
+    }
+
+    /// Hash files one after another. Reference path for the parallel version.
+    pub async fn hash_files_serial(paths: &[PathBuf]) -> Vec<(PathBuf, Result<String>)> {
+        let mut results = Vec::with_capacity(paths.len());
+        for path in paths {
+            let hash = Self::hash_file(path).await;
+            results.push((path.clone(), hash));
+        }
+        results
+    }
+
+    /// Group files by content hash. Files that cannot be read are skipped.
+    #[must_use]
+    pub fn group_hashes(results: Vec<(PathBuf, Result<String>)>) -> HashGroups {
+        let mut groups = HashGroups::new();
+        for (path, hash) in results {
+            match hash {
+                Ok(hash) => groups.entry(hash).or_default().push(path),
+                Err(e) => warn!("Skipping {:?}: failed to hash: {}", path, e),
+            }
+        }
+        for paths in groups.values_mut() {
+            paths.sort();
+        }
+        groups
+    }
+
+    /// Hash every regular file below `dir` and group them by content
+    pub async fn group_directory(&self, dir: &Path) -> Result<HashGroups> {
+        let files = collect_files(dir).await?;
+        info!(
+            "Hashing {} files in {:?} with {} workers",
+            files.len(),
+            dir,
+            self.workers
+        );
+        Ok(Self::group_hashes(self.hash_files(&files).await))
+    }
+
+    /// Find sets of files below `dir` with identical content
+    pub async fn find_duplicates(&self, dir: &Path) -> Result<Vec<Vec<PathBuf>>> {
+        Ok(self
+            .group_directory(dir)
+            .await?
+            .into_values()
+            .filter(|paths| paths.len() > 1)
+            .collect())
+    }
+}
+
+/// Recursively list regular files below `dir` in sorted order, ignoring symlinks
+async fn collect_files(dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    let mut pending = vec![dir.to_path_buf()];
+
+    while let Some(current) = pending.pop() {
+        let mut entries = tokio::fs::read_dir(&current).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            let file_type = entry.file_type().await?;
+            if file_type.is_dir() {
+                pending.push(entry.path());
+            } else if file_type.is_file() {
+                files.push(entry.path());
+            }
+        }
+    }
+
+    files.sort();
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn create_test_tree() -> TempDir {
+        let temp_dir = TempDir::new().unwrap();
+        let nested = temp_dir.path().join("machine_learning");
+        fs::create_dir(&nested).unwrap();
+
+        for i in 0..24 {
+            // Eight distinct contents, each written to three files
+            let content = format!("paper contents {}", i % 8).repeat(512);
+            let dir = if i % 2 == 0 {
+                temp_dir.path()
+            } else {
+                nested.as_path()
+            };
+            fs::write(dir.join(format!("paper_{i}.pdf")), content).unwrap();
+        }
+
+        temp_dir
+    }
+
+    #[test]
+    fn test_new_zero_workers_clamped() {
+        assert_eq!(FileHasher::new(0).workers(), 1);
+        assert_eq!(
+            FileHasher::from_config(&DownloadsConfig::default()).workers(),
+            DownloadsConfig::default().hash_workers
+        );
+    }
+
+    #[tokio::test]
+    async fn test_hash_files_parallel_matches_serial() {
+        let temp_dir = create_test_tree();
+        let files = collect_files(temp_dir.path()).await.unwrap();
+        assert_eq!(files.len(), 24);
+
+        let serial = FileHasher::group_hashes(FileHasher::hash_files_serial(&files).await);
+        for workers in [1, 4, 16] {
+            let parallel = FileHasher::new(workers)
+                .group_directory(temp_dir.path())
+                .await
+                .unwrap();
+            assert_eq!(parallel, serial, "grouping differs with {workers} workers");
+        }
+
+        assert_eq!(serial.len(), 8);
+        assert!(serial.values().all(|paths| paths.len() == 3));
+    }
+
+    #[tokio::test]
+    async fn test_hash_files_preserves_input_order() {
+        let temp_dir = create_test_tree();
+        let files = collect_files(temp_dir.path()).await.unwrap();
+
+        let results = FileHasher::new(8).hash_files(&files).await;
+        let returned: Vec<PathBuf> = results.into_iter().map(|(path, _)| path).collect();
+        assert_eq!(returned, files);
+    }
+
+    #[tokio::test]
+    async fn test_find_duplicates_skips_unique_files() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("a.pdf"), b"same").unwrap();
+        fs::write(temp_dir.path().join("b.pdf"), b"same").unwrap();
+        fs::write(temp_dir.path().join("c.pdf"), b"different").unwrap();
+
+        let duplicates = FileHasher::new(2)
+            .find_duplicates(temp_dir.path())
+            .await
+            .unwrap();
+        assert_eq!(
+            duplicates,
+            vec![vec![
+                temp_dir.path().join("a.pdf"),
+                temp_dir.path().join("b.pdf")
+            ]]
+        );
+    }
+}
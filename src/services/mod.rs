@@ -1,3 +1,19 @@
+pub mod boolean_query;
 pub mod categorization;
+pub mod doi_suggestions;
+pub mod duplicates;
+pub mod hashing;
+pub mod query_expansion;
+pub mod search_terms;
+pub mod translation;
 
+pub use boolean_query::{BooleanQuery, QueryTerm};
 pub use categorization::{CategorizationConfig, CategorizationService};
+pub use doi_suggestions::{DoiSuggester, DoiSuggestion, DoiSuggestionConfig};
+pub use duplicates::{duplicate_groups, find_duplicates, DuplicateStrategy};
+pub use hashing::{FileHasher, HashGroups};
+pub use query_expansion::{QueryExpander, QueryExpansionConfig};
+pub use search_terms::{sanitize_search_terms, SearchTermLimits};
+pub use translation::{
+    HttpTranslationProvider, TranslationConfig, TranslationProvider, TranslationService,
+};
@@ -0,0 +1,140 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Configuration for optional synonym-based query expansion
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct QueryExpansionConfig {
+    /// Whether keyword searches are also run with synonyms substituted
+    pub enabled: bool,
+    /// Phrase to alternative phrasings. Phrases match whole words,
+    /// case-insensitively; add the reverse entry for two-way expansion.
+    pub synonyms: HashMap<String, Vec<String>>,
+    /// Maximum number of additional searches run per query
+    pub max_expansions: usize,
+}
+
+impl Default for QueryExpansionConfig {
+    fn default() -> Self {
+        let synonyms = [
+            ("heart attack", "myocardial infarction"),
+            ("high blood pressure", "hypertension"),
+            ("stroke", "cerebrovascular accident"),
+        ]
+        .into_iter()
+        .map(|(phrase, synonym)| (phrase.to_string(), vec![synonym.to_string()]))
+        .collect();
+
+        Self {
+            enabled: false,
+            synonyms,
+            max_expansions: 3,
+        }
+    }
+}
+
+/// Rewrites queries using a synonym map
+#[derive(Debug, Clone)]
+pub struct QueryExpander {
+    /// Lowercased phrases with their synonyms, longest phrase first
+    synonyms: Vec<(String, Vec<String>)>,
+    max_expansions: usize,
+}
+
+impl QueryExpander {
+    /// Create an expander over `synonyms`
+    #[must_use]
+    pub fn new(synonyms: &HashMap<String, Vec<String>>, max_expansions: usize) -> Self {
+        let mut synonyms: Vec<(String, Vec<String>)> = synonyms
+            .iter()
+            .map(|(phrase, alternatives)| {
+                (phrase.trim().to_ascii_lowercase(), alternatives.clone())
+            })
+            .filter(|(phrase, alternatives)| !phrase.is_empty() && !alternatives.is_empty())
+            .collect();
+        synonyms.sort_by(|a, b| b.0.len().cmp(&a.0.len()).then_with(|| a.0.cmp(&b.0)));
+
+        Self {
+            synonyms,
+            max_expansions,
+        }
+    }
+
+    /// Build the expander from config. Returns `None` unless expansion is
+    /// enabled and at least one synonym is configured.
+    #[must_use]
+    pub fn from_config(config: &QueryExpansionConfig) -> Option<Self> {
+        if !config.enabled {
+            return None;
+        }
+        let expander = Self::new(&config.synonyms, config.max_expansions);
+        (!expander.synonyms.is_empty() && expander.max_expansions > 0).then_some(expander)
+    }
+
+    /// Alternative queries with one known phrase replaced by a synonym. The
+    /// original query is never included.
+    #[must_use]
+    pub fn expand(&self, query: &str) -> Vec<String> {
+        let lowered = query.to_ascii_lowercase();
+        let mut expansions: Vec<String> = Vec::new();
+
+        for (phrase, alternatives) in &self.synonyms {
+            let Some(start) = find_phrase(&lowered, phrase) else {
+                continue;
+            };
+            let end = start + phrase.len();
+            for alternative in alternatives {
+                let expanded =
+                    format!("{}{}{}", &query[..start], alternative.trim(), &query[end..]);
+                if !expanded.eq_ignore_ascii_case(query)
+                    && !expansions.iter().any(|e| e.eq_ignore_ascii_case(&expanded))
+                {
+                    expansions.push(expanded);
+                }
+            }
+        }
+
+        expansions.truncate(self.max_expansions);
+        expansions
+    }
+}
+
+/// Byte offset of `phrase` in `text` where it is not part of a longer word
+fn find_phrase(text: &str, phrase: &str) -> Option<usize> {
+    let is_word = |c: Option<char>| c.is_some_and(char::is_alphanumeric);
+
+    text.match_indices(phrase)
+        .map(|(start, _)| start)
+        .find(|&start| {
+            let before = text[..start].chars().next_back();
+            let after = text[start + phrase.len()..].chars().next();
+            !is_word(before) && !is_word(after)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand_replaces_whole_phrases_only() {
+        let expander = QueryExpander::from_config(&QueryExpansionConfig {
+            enabled: true,
+            ..QueryExpansionConfig::default()
+        })
+        .unwrap();
+
+        assert_eq!(
+            expander.expand("Heart attack risk in athletes"),
+            vec!["myocardial infarction risk in athletes".to_string()]
+        );
+        assert!(expander.expand("heatstroke prevention").is_empty());
+        assert!(expander.expand("transformer architectures").is_empty());
+    }
+
+    #[test]
+    fn test_from_config_disabled_by_default() {
+        assert!(QueryExpander::from_config(&QueryExpansionConfig::default()).is_none());
+    }
+}
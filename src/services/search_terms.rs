@@ -0,0 +1,104 @@
+use crate::Result;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Bounds on the terms a search may contain
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct SearchTermLimits {
+    /// Maximum number of terms (query words or abstract keywords)
+    pub max_terms: usize,
+    /// Maximum length of a single term in characters
+    pub max_term_length: usize,
+}
+
+impl Default for SearchTermLimits {
+    fn default() -> Self {
+        Self {
+            max_terms: 50,
+            max_term_length: 200,
+        }
+    }
+}
+
+/// Strip control characters and surrounding whitespace from each term,
+/// dropping terms left empty.
+///
+/// Fails with `InvalidInput` on `field` when there are more terms than
+/// `limits.max_terms` or one is longer than `limits.max_term_length`.
+pub fn sanitize_search_terms<S: AsRef<str>>(
+    field: &str,
+    terms: &[S],
+    limits: &SearchTermLimits,
+) -> Result<Vec<String>> {
+    let terms: Vec<String> = terms
+        .iter()
+        .map(|term| {
+            term.as_ref()
+                .chars()
+                .filter(|c| !c.is_control())
+                .collect::<String>()
+                .trim()
+                .to_string()
+        })
+        .filter(|term| !term.is_empty())
+        .collect();
+
+    if terms.len() > limits.max_terms {
+        return Err(crate::Error::InvalidInput {
+            field: field.to_string(),
+            reason: format!(
+                "Too many search terms: {} (max {})",
+                terms.len(),
+                limits.max_terms
+            ),
+        });
+    }
+    if let Some(term) = terms
+        .iter()
+        .find(|term| term.chars().count() > limits.max_term_length)
+    {
+        return Err(crate::Error::InvalidInput {
+            field: field.to_string(),
+            reason: format!(
+                "Search term too long: '{}...' (max {} characters)",
+                term.chars().take(20).collect::<String>(),
+                limits.max_term_length
+            ),
+        });
+    }
+
+    Ok(terms)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_search_terms_strips_control_characters() {
+        let terms = ["pro\u{0}tein", "\u{7}", " fold\u{1b}ing\n"];
+
+        let sanitized =
+            sanitize_search_terms("keywords", &terms, &SearchTermLimits::default()).unwrap();
+
+        assert_eq!(sanitized, vec!["protein", "folding"]);
+    }
+
+    #[test]
+    fn test_sanitize_search_terms_over_limits_rejected() {
+        let limits = SearchTermLimits {
+            max_terms: 3,
+            max_term_length: 10,
+        };
+
+        let too_many = ["a", "b", "c", "d"];
+        assert!(matches!(
+            sanitize_search_terms("keywords", &too_many, &limits),
+            Err(crate::Error::InvalidInput { field, .. }) if field == "keywords"
+        ));
+
+        let too_long = ["electroencephalography"];
+        assert!(sanitize_search_terms("keywords", &too_long, &limits).is_err());
+    }
+}
@@ -0,0 +1,269 @@
+use crate::Result;
+use async_trait::async_trait;
+use reqwest::Client;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{debug, info, warn};
+
+/// Configuration for optional abstract translation
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct TranslationConfig {
+    /// Whether non-English abstracts are translated during search
+    pub enabled: bool,
+    /// Translation endpoint accepting LibreTranslate-style `POST` requests
+    pub endpoint: Option<String>,
+    /// API key sent with each request, if the endpoint requires one
+    pub api_key: Option<String>,
+    /// Language abstracts are translated into
+    pub target_language: String,
+    /// Request timeout in seconds
+    pub timeout_secs: u64,
+}
+
+impl Default for TranslationConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            endpoint: None,
+            api_key: None,
+            target_language: "en".to_string(),
+            timeout_secs: 10,
+        }
+    }
+}
+
+/// Backend that translates text into a target language
+#[async_trait]
+pub trait TranslationProvider: Send + Sync {
+    /// Short identifier used in logs
+    fn name(&self) -> &'static str;
+
+    /// Translate `text` into `target_language` (an ISO 639-1 code)
+    async fn translate(&self, text: &str, target_language: &str) -> Result<String>;
+}
+
+/// Translation provider backed by an external HTTP endpoint
+pub struct HttpTranslationProvider {
+    client: Client,
+    endpoint: String,
+    api_key: Option<String>,
+}
+
+#[derive(Serialize)]
+struct TranslateRequest<'a> {
+    q: &'a str,
+    source: &'a str,
+    target: &'a str,
+    format: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    api_key: Option<&'a str>,
+}
+
+#[derive(Deserialize)]
+struct TranslateResponse {
+    #[serde(rename = "translatedText")]
+    translated_text: String,
+}
+
+impl HttpTranslationProvider {
+    /// Create a provider for `endpoint`
+    pub fn new(endpoint: String, api_key: Option<String>, timeout: Duration) -> Result<Self> {
+        let client = Client::builder()
+            .timeout(timeout)
+            .build()
+            .map_err(|e| crate::Error::Service(format!("Failed to create HTTP client: {e}")))?;
+
+        Ok(Self {
+            client,
+            endpoint,
+            api_key,
+        })
+    }
+}
+
+#[async_trait]
+impl TranslationProvider for HttpTranslationProvider {
+    fn name(&self) -> &'static str {
+        "http"
+    }
+
+    async fn translate(&self, text: &str, target_language: &str) -> Result<String> {
+        let request = TranslateRequest {
+            q: text,
+            source: "auto",
+            target: target_language,
+            format: "text",
+            api_key: self.api_key.as_deref(),
+        };
+
+        let response = self
+            .client
+            .post(&self.endpoint)
+            .json(&request)
+            .send()
+            .await?
+            .error_for_status()?;
+
+        let body: TranslateResponse = response.json().await?;
+        Ok(body.translated_text)
+    }
+}
+
+/// Translates paper abstracts that are not already in the target language
+#[derive(Clone)]
+pub struct TranslationService {
+    provider: Arc<dyn TranslationProvider>,
+    target_language: String,
+}
+
+impl std::fmt::Debug for TranslationService {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TranslationService")
+            .field("provider", &self.provider.name())
+            .field("target_language", &self.target_language)
+            .finish()
+    }
+}
+
+impl TranslationService {
+    /// Create a service around `provider`
+    #[must_use]
+    pub fn new(provider: Arc<dyn TranslationProvider>, target_language: String) -> Self {
+        Self {
+            provider,
+            target_language,
+        }
+    }
+
+    /// Build the service from config. Returns `None` unless translation is
+    /// enabled and an endpoint is configured.
+    pub fn from_config(config: &TranslationConfig) -> Result<Option<Self>> {
+        if !config.enabled {
+            return Ok(None);
+        }
+        let Some(endpoint) = config.endpoint.clone() else {
+            warn!("Translation is enabled but translation.endpoint is not set; skipping");
+            return Ok(None);
+        };
+
+        info!("Abstract translation enabled via {}", endpoint);
+        let provider = HttpTranslationProvider::new(
+            endpoint,
+            config.api_key.clone(),
+            Duration::from_secs(config.timeout_secs),
+        )?;
+        Ok(Some(Self::new(
+            Arc::new(provider),
+            config.target_language.clone(),
+        )))
+    }
+
+    /// Whether `abstract_text` would be sent to the provider: it is not
+    /// empty and does not already look like English. Only English detection
+    /// is built in, so other target languages translate every abstract.
+    #[must_use]
+    pub fn needs_translation(&self, abstract_text: &str) -> bool {
+        let abstract_text = abstract_text.trim();
+        if abstract_text.is_empty() {
+            return false;
+        }
+        !(self.target_language == "en" && looks_english(abstract_text))
+    }
+
+    /// Translate an abstract, or return `None` if it needs no translation
+    /// or the provider fails
+    pub async fn translate_abstract(&self, abstract_text: &str) -> Option<String> {
+        if !self.needs_translation(abstract_text) {
+            return None;
+        }
+        let abstract_text = abstract_text.trim();
+
+        match self
+            .provider
+            .translate(abstract_text, &self.target_language)
+            .await
+        {
+            Ok(translated) if !translated.trim().is_empty() => Some(translated),
+            Ok(_) => None,
+            Err(e) => {
+                debug!(
+                    "Translation via {} failed, keeping original abstract: {}",
+                    self.provider.name(),
+                    e
+                );
+                None
+            }
+        }
+    }
+}
+
+/// Cheap English check: share of words that are common English stopwords
+fn looks_english(text: &str) -> bool {
+    const STOPWORDS: &[&str] = &[
+        "the", "of", "and", "to", "in", "is", "that", "for", "we", "this", "with", "are", "on",
+        "by", "as", "an", "be", "from", "our", "which", "these", "it", "at",
+    ];
+
+    let words: Vec<String> = text
+        .split_whitespace()
+        .take(200)
+        .map(|w| w.trim_matches(|c: char| !c.is_alphabetic()).to_lowercase())
+        .filter(|w| !w.is_empty())
+        .collect();
+    if words.is_empty() {
+        return true;
+    }
+
+    let stopwords = words
+        .iter()
+        .filter(|w| STOPWORDS.contains(&w.as_str()))
+        .count();
+    stopwords * 10 >= words.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct UppercaseTranslator;
+
+    #[async_trait]
+    impl TranslationProvider for UppercaseTranslator {
+        fn name(&self) -> &'static str {
+            "uppercase"
+        }
+
+        async fn translate(&self, text: &str, _target_language: &str) -> Result<String> {
+            Ok(text.to_uppercase())
+        }
+    }
+
+    #[test]
+    fn test_from_config_disabled_by_default() {
+        let service = TranslationService::from_config(&TranslationConfig::default()).unwrap();
+        assert!(service.is_none());
+
+        let config = TranslationConfig {
+            enabled: true,
+            ..TranslationConfig::default()
+        };
+        assert!(TranslationService::from_config(&config).unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_translate_abstract_skips_english() {
+        let service = TranslationService::new(Arc::new(UppercaseTranslator), "en".to_string());
+
+        let english = "We study the effect of attention on the performance of language models.";
+        assert_eq!(service.translate_abstract(english).await, None);
+
+        let spanish = "Estudiamos el efecto de la atención en modelos de lenguaje.";
+        assert_eq!(
+            service.translate_abstract(spanish).await.as_deref(),
+            Some("ESTUDIAMOS EL EFECTO DE LA ATENCIÓN EN MODELOS DE LENGUAJE.")
+        );
+    }
+}
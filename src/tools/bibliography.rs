@@ -9,7 +9,7 @@ use tracing::{debug, error, info, instrument, warn};
 pub struct BibliographyInput {
     /// List of DOIs or paper identifiers
     #[schemars(
-        description = "Array of DOIs/identifiers. No limit on quantity. Fetches metadata for 30 papers concurrently."
+        description = "Array of DOIs/identifiers. No limit on quantity. Metadata is fetched concurrently (30 at a time by default) and citations are returned in input order."
     )]
     pub identifiers: Vec<String>,
 
@@ -110,14 +110,14 @@ pub struct PaperMetadata {
 /// Bibliography generation tool
 #[derive(Debug, Clone)]
 pub struct BibliographyTool {
-    _config: Arc<Config>,
+    config: Arc<Config>,
 }
 
 #[allow(dead_code)]
 impl BibliographyTool {
     /// Create a new bibliography tool
     pub const fn new(config: Arc<Config>) -> Result<Self> {
-        Ok(Self { _config: config })
+        Ok(Self { config })
     }
 
     /// Generate bibliography from paper identifiers with parallel metadata fetching
@@ -129,8 +129,10 @@ impl BibliographyTool {
             input.format
         );
 
-        // Use semaphore to limit concurrent metadata fetches (tripled to 30)
-        let semaphore = Arc::new(tokio::sync::Semaphore::new(30));
+        // Use semaphore to limit concurrent metadata fetches
+        let semaphore = Arc::new(tokio::sync::Semaphore::new(
+            self.config.bibliography.max_concurrent.max(1),
+        ));
 
         // Create tasks for parallel metadata fetching
         let mut tasks = Vec::new();
@@ -173,7 +175,7 @@ impl BibliographyTool {
             tasks.push((identifier, task)); // Use original identifier here
         }
 
-        // Wait for all tasks to complete and collect results
+        // Await tasks in spawn order so citations keep the input order
         let mut citations = Vec::new();
         let mut errors = Vec::new();
 
@@ -199,9 +201,6 @@ impl BibliographyTool {
             }
         }
 
-        // Sort citations to maintain order (optional - by identifier)
-        citations.sort_by(|a, b| a.identifier.cmp(&b.identifier));
-
         // Generate combined bibliography
         let bibliography = self.combine_citations(&citations, &input.format);
 
@@ -224,6 +223,7 @@ impl BibliographyTool {
         // In a real implementation, this would query CrossRef, Semantic Scholar, etc.
         // For now, we'll create mock metadata
         debug!("Fetching metadata for: {}", identifier);
+        Self::validate_identifier(identifier)?;
 
         // Simulate some processing time (removed for mock - in real implementation
         // this would be an actual API call)
@@ -245,6 +245,39 @@ impl BibliographyTool {
         })
     }
 
+    /// Reject identifiers that are not a DOI, arXiv ID or URL
+    fn validate_identifier(identifier: &str) -> Result<()> {
+        let trimmed = identifier.trim();
+        let lower = trimmed.to_lowercase();
+        let doi = ["https://doi.org/", "http://doi.org/", "doi:"]
+            .iter()
+            .find_map(|prefix| lower.strip_prefix(prefix))
+            .unwrap_or(&lower)
+            .trim();
+
+        let is_doi = doi
+            .strip_prefix("10.")
+            .and_then(|rest| rest.split_once('/'))
+            .is_some_and(|(registrant, suffix)| {
+                !registrant.is_empty()
+                    && registrant.chars().all(|c| c.is_ascii_digit() || c == '.')
+                    && !suffix.is_empty()
+            });
+        let is_arxiv = lower
+            .strip_prefix("arxiv:")
+            .is_some_and(|id| !id.is_empty());
+        let is_url = lower.starts_with("https://") || lower.starts_with("http://");
+
+        if is_doi || is_arxiv || is_url {
+            Ok(())
+        } else {
+            Err(crate::Error::InvalidInput {
+                field: "identifiers".to_string(),
+                reason: format!("Not a DOI, arXiv ID or URL: {trimmed:?}"),
+            })
+        }
+    }
+
     /// Fetch metadata for a paper (instance method - delegates to static)
     fn fetch_metadata(&self, identifier: &str) -> Result<PaperMetadata> {
         Self::fetch_metadata_sync(identifier)
@@ -609,7 +642,7 @@ impl BibliographyTool {
     // ================================
 
     /// Format as BibTeX (static version)
-    fn format_bibtex_static(
+    pub(crate) fn format_bibtex_static(
         metadata: &PaperMetadata,
         include_abstract: bool,
         include_keywords: bool,
@@ -897,4 +930,52 @@ mod tests {
         let key = tool.generate_bibtex_key(&metadata);
         assert_eq!(key, "Smith2024Test");
     }
+
+    #[tokio::test]
+    async fn test_generate_mixed_identifiers_preserves_order_and_errors() {
+        let mut config = Config::default();
+        config.bibliography.max_concurrent = 2;
+        let tool = BibliographyTool::new(Arc::new(config)).unwrap();
+
+        let identifiers = vec![
+            "10.1038/nature14539".to_string(),
+            "not-a-doi".to_string(),
+            "10.1000/zzz".to_string(),
+            String::new(),
+            "10.1000/aaa".to_string(),
+            "arXiv:1706.03762".to_string(),
+        ];
+        let result = tool
+            .generate(BibliographyInput {
+                identifiers,
+                format: CitationFormat::BibTeX,
+                include_abstract: false,
+                include_keywords: false,
+            })
+            .await
+            .unwrap();
+
+        let cited: Vec<&str> = result
+            .citations
+            .iter()
+            .map(|c| c.identifier.as_str())
+            .collect();
+        assert_eq!(
+            cited,
+            [
+                "10.1038/nature14539",
+                "10.1000/zzz",
+                "10.1000/aaa",
+                "arXiv:1706.03762"
+            ]
+        );
+
+        let failed: Vec<&str> = result
+            .errors
+            .iter()
+            .map(|e| e.identifier.as_str())
+            .collect();
+        assert_eq!(failed, ["not-a-doi", ""]);
+        assert!(result.errors[0].message.contains("Not a DOI"));
+    }
 }
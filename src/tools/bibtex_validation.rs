@@ -0,0 +1,622 @@
+use crate::client::{MetaSearchClient, PaperMetadata};
+use crate::Result;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::ops::Range;
+use std::sync::Arc;
+use tracing::{debug, info, instrument};
+
+/// Input parameters for BibTeX validation
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct BibtexValidationInput {
+    /// Contents of the `.bib` file to validate
+    #[schemars(
+        description = "Contents of a BibTeX (.bib) file. Entries with a doi field are checked against academic sources."
+    )]
+    pub bibtex: String,
+}
+
+/// Outcome of validating a single BibTeX entry
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum EntryValidationStatus {
+    /// Entry matches the source metadata
+    Verified,
+    /// Entry was corrected or enriched from the source metadata
+    Corrected,
+    /// Entry has no DOI and was left unchanged
+    NoDoi,
+    /// DOI could not be resolved by any provider
+    NotFound,
+}
+
+/// A single field change applied to an entry
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct FieldChange {
+    /// BibTeX field name (lowercase)
+    pub field: String,
+    /// Value in the original file, if the field was present
+    pub old_value: Option<String>,
+    /// Value taken from the source metadata
+    pub new_value: String,
+}
+
+/// Validation report for a single entry
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct EntryValidationReport {
+    /// Citation key
+    pub key: String,
+    /// DOI used for the lookup
+    pub doi: Option<String>,
+    /// Validation outcome
+    pub status: EntryValidationStatus,
+    /// Field changes applied to the corrected entry
+    pub changes: Vec<FieldChange>,
+}
+
+/// Result of BibTeX validation
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct BibtexValidationResult {
+    /// The input with corrected entries rewritten in place. Everything else,
+    /// including `@string`/`@preamble`/`@comment` blocks, is kept verbatim.
+    pub corrected_bibtex: String,
+    /// Per-entry reports, in input order
+    pub entries: Vec<EntryValidationReport>,
+    /// Human-readable diff of all changes
+    pub report: String,
+    /// Number of entries that were corrected
+    pub corrected_count: usize,
+}
+
+/// A parsed BibTeX entry
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BibtexEntry {
+    /// Entry type in lowercase (article, inproceedings, ...)
+    pub entry_type: String,
+    /// Citation key
+    pub key: String,
+    /// Fields in file order as (lowercase name, value without delimiters)
+    pub fields: Vec<(String, String)>,
+    /// Field values exactly as written in the file (delimiters and `#`
+    /// concatenations included), keyed by lowercase name. Fields replaced by
+    /// [`BibtexEntry::set_field`] are dropped from here.
+    pub raw_values: HashMap<String, String>,
+    /// Character range of the whole entry in the parsed input
+    pub span: Range<usize>,
+}
+
+impl BibtexEntry {
+    /// Get a field value by (lowercase) name
+    #[must_use]
+    pub fn field(&self, name: &str) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|(field, _)| field == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Set a field, replacing an existing value or appending a new field
+    pub fn set_field(&mut self, name: &str, value: String) {
+        self.raw_values.remove(name);
+        if let Some(existing) = self.fields.iter_mut().find(|(field, _)| field == name) {
+            existing.1 = value;
+        } else {
+            self.fields.push((name.to_string(), value));
+        }
+    }
+
+    /// Render the entry as BibTeX. Fields read from the file keep their
+    /// original value text; fields set since are wrapped in braces.
+    #[must_use]
+    pub fn to_bibtex(&self) -> String {
+        let mut output = format!("@{}{{{}", self.entry_type, self.key);
+        for (name, value) in &self.fields {
+            match self.raw_values.get(name) {
+                Some(raw) => {
+                    let _ = write!(output, ",\n  {name} = {raw}");
+                }
+                None => {
+                    let _ = write!(output, ",\n  {name} = {{{value}}}");
+                }
+            }
+        }
+        output.push_str("\n}");
+        output
+    }
+}
+
+/// Parse BibTeX entries.
+///
+/// `@comment`, `@string` and `@preamble` blocks are skipped, and `@string`
+/// macros are not expanded; use each entry's `span` and `raw_values` to write
+/// the file back without losing them.
+pub fn parse_bibtex(input: &str) -> Result<Vec<BibtexEntry>> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut pos = 0;
+    let mut entries = Vec::new();
+
+    while let Some(offset) = chars[pos..].iter().position(|&c| c == '@') {
+        let start = pos + offset;
+        pos = start + 1;
+        let entry_type =
+            take_while(&chars, &mut pos, |c| c.is_alphanumeric() || c == '_').to_lowercase();
+        skip_whitespace(&chars, &mut pos);
+
+        let close = match chars.get(pos) {
+            Some('{') => '}',
+            Some('(') => ')',
+            // A stray '@' outside of an entry
+            _ => continue,
+        };
+        pos += 1;
+
+        if matches!(entry_type.as_str(), "comment" | "string" | "preamble") {
+            skip_balanced(&chars, &mut pos, close)?;
+            continue;
+        }
+
+        let key = take_while(&chars, &mut pos, |c| c != ',' && c != close)
+            .trim()
+            .to_string();
+        let mut fields = Vec::new();
+        let mut raw_values = HashMap::new();
+
+        loop {
+            while chars
+                .get(pos)
+                .is_some_and(|c| c.is_whitespace() || *c == ',')
+            {
+                pos += 1;
+            }
+            match chars.get(pos) {
+                None => return Err(bibtex_error(format!("Unterminated entry '{key}'"))),
+                Some(&c) if c == close => {
+                    pos += 1;
+                    break;
+                }
+                _ => {}
+            }
+
+            let name = take_while(&chars, &mut pos, |c| c != '=' && c != ',' && c != close)
+                .trim()
+                .to_lowercase();
+            if chars.get(pos) != Some(&'=') {
+                return Err(bibtex_error(format!(
+                    "Field '{name}' in entry '{key}' has no value"
+                )));
+            }
+            pos += 1;
+            let value_start = pos;
+            let value = read_value(&chars, &mut pos, close)?;
+            let raw: String = chars[value_start..pos].iter().collect();
+            raw_values.insert(name.clone(), raw.trim().to_string());
+            fields.push((name, value));
+        }
+
+        entries.push(BibtexEntry {
+            entry_type,
+            key,
+            fields,
+            raw_values,
+            span: start..pos,
+        });
+    }
+
+    Ok(entries)
+}
+
+fn bibtex_error(message: String) -> crate::Error {
+    crate::Error::Parse {
+        context: "BibTeX".to_string(),
+        message,
+    }
+}
+
+fn take_while(chars: &[char], pos: &mut usize, predicate: impl Fn(char) -> bool) -> String {
+    let start = *pos;
+    while chars.get(*pos).is_some_and(|&c| predicate(c)) {
+        *pos += 1;
+    }
+    chars[start..*pos].iter().collect()
+}
+
+fn skip_whitespace(chars: &[char], pos: &mut usize) {
+    while chars.get(*pos).is_some_and(|c| c.is_whitespace()) {
+        *pos += 1;
+    }
+}
+
+/// Skip to just past the delimiter closing a block that has already been opened
+fn skip_balanced(chars: &[char], pos: &mut usize, close: char) -> Result<()> {
+    let open = if close == '}' { '{' } else { '(' };
+    let mut depth = 1;
+    while let Some(&c) = chars.get(*pos) {
+        *pos += 1;
+        if c == open {
+            depth += 1;
+        } else if c == close {
+            depth -= 1;
+            if depth == 0 {
+                return Ok(());
+            }
+        }
+    }
+    Err(bibtex_error("Unterminated block".to_string()))
+}
+
+/// Read a field value: `{...}`, `"..."`, a bare word/number, or `#` concatenations of them
+fn read_value(chars: &[char], pos: &mut usize, close: char) -> Result<String> {
+    let mut value = String::new();
+
+    loop {
+        skip_whitespace(chars, pos);
+        match chars.get(*pos) {
+            Some('{') => {
+                *pos += 1;
+                let start = *pos;
+                skip_balanced(chars, pos, '}')?;
+                value.extend(&chars[start..*pos - 1]);
+            }
+            Some('"') => {
+                *pos += 1;
+                let mut depth = 0;
+                loop {
+                    match chars.get(*pos) {
+                        None => return Err(bibtex_error("Unterminated quoted value".to_string())),
+                        Some('"') if depth == 0 => break,
+                        Some('{') => depth += 1,
+                        Some('}') => depth -= 1,
+                        _ => {}
+                    }
+                    value.push(chars[*pos]);
+                    *pos += 1;
+                }
+                *pos += 1;
+            }
+            _ => {
+                let word = take_while(chars, pos, |c| {
+                    c != ',' && c != close && c != '#' && !c.is_whitespace()
+                });
+                value.push_str(&word);
+            }
+        }
+
+        skip_whitespace(chars, pos);
+        if chars.get(*pos) == Some(&'#') {
+            *pos += 1;
+        } else {
+            return Ok(value);
+        }
+    }
+}
+
+/// Normalize a title for comparison, ignoring case, braces and punctuation
+fn normalize_title(title: &str) -> String {
+    title
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                ' '
+            }
+        })
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Tool that checks BibTeX entries against academic sources and corrects them
+#[derive(Clone)]
+pub struct BibtexValidationTool {
+    client: Arc<MetaSearchClient>,
+}
+
+impl std::fmt::Debug for BibtexValidationTool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BibtexValidationTool")
+            .field("client", &"MetaSearchClient")
+            .finish()
+    }
+}
+
+impl BibtexValidationTool {
+    /// Create a new BibTeX validation tool
+    #[must_use]
+    pub const fn new(client: Arc<MetaSearchClient>) -> Self {
+        Self { client }
+    }
+
+    /// Validate a BibTeX file, returning corrected BibTeX and a diff report
+    #[instrument(skip(self, input), fields(bibtex_len = input.bibtex.len()))]
+    pub async fn validate(&self, input: BibtexValidationInput) -> Result<BibtexValidationResult> {
+        let mut entries = parse_bibtex(&input.bibtex)?;
+        if entries.is_empty() {
+            return Err(crate::Error::InvalidInput {
+                field: "bibtex".to_string(),
+                reason: "No BibTeX entries found".to_string(),
+            });
+        }
+
+        let dois: Vec<String> = entries
+            .iter()
+            .filter_map(|entry| entry.field("doi").map(str::to_string))
+            .collect();
+        info!(
+            "Validating {} BibTeX entries ({} with DOIs)",
+            entries.len(),
+            dois.len()
+        );
+        let resolved = self.client.get_by_dois(&dois).await;
+
+        let mut reports = Vec::with_capacity(entries.len());
+        for entry in &mut entries {
+            let doi = entry.field("doi").map(str::to_string);
+            let (status, changes) = match &doi {
+                None => (EntryValidationStatus::NoDoi, Vec::new()),
+                Some(doi) => match resolved.get(doi).cloned().flatten() {
+                    None => (EntryValidationStatus::NotFound, Vec::new()),
+                    Some(paper) => {
+                        let changes = Self::correct_entry(entry, &paper);
+                        if changes.is_empty() {
+                            (EntryValidationStatus::Verified, changes)
+                        } else {
+                            (EntryValidationStatus::Corrected, changes)
+                        }
+                    }
+                },
+            };
+            debug!("Entry {} validated: {:?}", entry.key, status);
+            reports.push(EntryValidationReport {
+                key: entry.key.clone(),
+                doi,
+                status,
+                changes,
+            });
+        }
+
+        let corrected_bibtex = Self::rewrite_corrected(&input.bibtex, &entries, &reports);
+        let corrected_count = reports
+            .iter()
+            .filter(|report| report.status == EntryValidationStatus::Corrected)
+            .count();
+
+        Ok(BibtexValidationResult {
+            corrected_bibtex,
+            report: Self::format_report(&reports),
+            entries: reports,
+            corrected_count,
+        })
+    }
+
+    /// Splice re-rendered corrected entries into the original text, leaving
+    /// every other byte of the file as it was
+    fn rewrite_corrected(
+        input: &str,
+        entries: &[BibtexEntry],
+        reports: &[EntryValidationReport],
+    ) -> String {
+        let chars: Vec<char> = input.chars().collect();
+        let mut output = String::with_capacity(input.len());
+        let mut pos = 0;
+        for (entry, report) in entries.iter().zip(reports) {
+            if report.status != EntryValidationStatus::Corrected {
+                continue;
+            }
+            output.extend(&chars[pos..entry.span.start]);
+            output.push_str(&entry.to_bibtex());
+            pos = entry.span.end;
+        }
+        output.extend(&chars[pos..]);
+        output
+    }
+
+    /// Apply source metadata to an entry, returning the changes made
+    fn correct_entry(entry: &mut BibtexEntry, paper: &PaperMetadata) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        let mut apply = |entry: &mut BibtexEntry, field: &str, new_value: String| {
+            changes.push(FieldChange {
+                field: field.to_string(),
+                old_value: entry.field(field).map(str::to_string),
+                new_value: new_value.clone(),
+            });
+            entry.set_field(field, new_value);
+        };
+
+        if let Some(title) = &paper.title {
+            let matches = entry
+                .field("title")
+                .is_some_and(|existing| normalize_title(existing) == normalize_title(title));
+            if !matches {
+                apply(entry, "title", title.clone());
+            }
+        }
+
+        if let Some(year) = paper.year {
+            let matches = entry
+                .field("year")
+                .is_some_and(|existing| existing.trim().parse::<u32>().ok() == Some(year));
+            if !matches {
+                apply(entry, "year", year.to_string());
+            }
+        }
+
+        // Only fill in missing author and journal fields; formatting differs too much
+        // between sources to flag existing values as wrong
+        if entry.field("author").is_none() && !paper.authors.is_empty() {
+            apply(entry, "author", paper.authors.join(" and "));
+        }
+        if entry.field("journal").is_none() {
+            if let Some(journal) = &paper.journal {
+                apply(entry, "journal", journal.clone());
+            }
+        }
+
+        changes
+    }
+
+    /// Render the per-entry changes as a diff-style report
+    fn format_report(reports: &[EntryValidationReport]) -> String {
+        let mut report = String::new();
+        for entry in reports {
+            match entry.status {
+                EntryValidationStatus::Verified => {
+                    let _ = writeln!(report, "[{}] verified", entry.key);
+                }
+                EntryValidationStatus::NoDoi => {
+                    let _ = writeln!(report, "[{}] skipped: no DOI", entry.key);
+                }
+                EntryValidationStatus::NotFound => {
+                    let _ = writeln!(
+                        report,
+                        "[{}] DOI not found: {}",
+                        entry.key,
+                        entry.doi.as_deref().unwrap_or_default()
+                    );
+                }
+                EntryValidationStatus::Corrected => {
+                    let _ = writeln!(report, "[{}] corrected", entry.key);
+                    for change in &entry.changes {
+                        if let Some(old) = &change.old_value {
+                            let _ = writeln!(report, "- {} = {{{old}}}", change.field);
+                        }
+                        let _ = writeln!(report, "+ {} = {{{}}}", change.field, change.new_value);
+                    }
+                }
+            }
+        }
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::providers::traits::tests::MockProvider;
+    use crate::client::providers::{SearchType, SourceProvider};
+    use crate::client::MetaSearchConfig;
+
+    fn create_test_tool() -> BibtexValidationTool {
+        let mut paper = PaperMetadata::new("10.1038/nature14539".to_string());
+        paper.title = Some("Deep learning".to_string());
+        paper.year = Some(2015);
+        paper.journal = Some("Nature".to_string());
+        let provider = MockProvider::new("mock")
+            .with_search_types(vec![SearchType::Doi])
+            .answering(move |query, _| {
+                Ok((query.query == paper.doi)
+                    .then(|| paper.clone())
+                    .into_iter()
+                    .collect())
+            });
+        let providers: Vec<Arc<dyn SourceProvider>> = vec![Arc::new(provider)];
+        let client = MetaSearchClient::with_providers(providers, MetaSearchConfig::default());
+        BibtexValidationTool::new(Arc::new(client))
+    }
+
+    #[test]
+    fn test_parse_bibtex_entries() {
+        let input = r#"
+@comment{ignored @article{not, real}}
+@string{nat = "Nature"}
+@Article{lecun2015,
+  title = {Deep {L}earning},
+  author = "LeCun, Yann and Bengio, Yoshua",
+  year = 2015,
+  journal = nat # " Journal",
+}
+@misc(other, note = {no doi})
+"#;
+        let entries = parse_bibtex(input).unwrap();
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].entry_type, "article");
+        assert_eq!(entries[0].key, "lecun2015");
+        assert_eq!(entries[0].field("title"), Some("Deep {L}earning"));
+        assert_eq!(
+            entries[0].field("author"),
+            Some("LeCun, Yann and Bengio, Yoshua")
+        );
+        assert_eq!(entries[0].field("year"), Some("2015"));
+        assert_eq!(entries[0].field("journal"), Some("nat Journal"));
+        assert_eq!(entries[0].raw_values["journal"], "nat # \" Journal\"");
+        assert_eq!(entries[1].key, "other");
+        assert_eq!(entries[1].field("note"), Some("no doi"));
+    }
+
+    #[test]
+    fn test_parse_bibtex_unterminated_entry_error() {
+        let result = parse_bibtex("@article{key, title = {Open");
+        assert!(matches!(result, Err(crate::Error::Parse { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_validate_wrong_year_corrected() {
+        let tool = create_test_tool();
+        let input = BibtexValidationInput {
+            bibtex: "@article{lecun2015,\n  title = {Deep Learning},\n  year = {2014},\n  journal = {Nature},\n  doi = {10.1038/nature14539}\n}\n\n@book{nodoi,\n  title = {Some Book}\n}".to_string(),
+        };
+
+        let result = tool.validate(input).await.unwrap();
+
+        assert_eq!(result.corrected_count, 1);
+        assert_eq!(result.entries[0].status, EntryValidationStatus::Corrected);
+        assert_eq!(
+            result.entries[0].changes,
+            vec![FieldChange {
+                field: "year".to_string(),
+                old_value: Some("2014".to_string()),
+                new_value: "2015".to_string(),
+            }]
+        );
+        assert_eq!(result.entries[1].status, EntryValidationStatus::NoDoi);
+        assert!(result.corrected_bibtex.contains("year = {2015}"));
+        assert!(!result.corrected_bibtex.contains("2014"));
+        assert!(result.corrected_bibtex.contains("@book{nodoi"));
+        assert!(result.report.contains("- year = {2014}"));
+        assert!(result.report.contains("+ year = {2015}"));
+    }
+
+    #[tokio::test]
+    async fn test_validate_round_trip_raw_blocks_preserved() {
+        let tool = create_test_tool();
+        let unchanged = "% Library export\n@preamble{\"\\newcommand{\\noop}[1]{}\"}\n@string{nat = \"Nature\"}\n@comment{keep me}\n\n@book{nodoi,\n  title = \"Some \" # \"Book\",\n  publisher = pub\n}\n";
+        let bibtex = format!(
+            "{unchanged}\n@article{{lecun2015,\n  title = \"Deep \" # \"Learning\",\n  year = 2014,\n  journal = nat,\n  doi = {{10.1038/nature14539}}\n}}\n"
+        );
+
+        let result = tool
+            .validate(BibtexValidationInput {
+                bibtex: bibtex.clone(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(result.corrected_count, 1);
+        assert!(result.corrected_bibtex.starts_with(unchanged));
+        assert_eq!(
+            result.corrected_bibtex,
+            bibtex.replace("year = 2014", "year = {2015}")
+        );
+
+        let untouched = tool
+            .validate(BibtexValidationInput {
+                bibtex: unchanged.to_string(),
+            })
+            .await
+            .unwrap();
+        assert_eq!(untouched.corrected_bibtex, unchanged);
+    }
+
+    #[tokio::test]
+    async fn test_validate_empty_input_error() {
+        let tool = create_test_tool();
+        let input = BibtexValidationInput {
+            bibtex: "no entries here".to_string(),
+        };
+        assert!(tool.validate(input).await.is_err());
+    }
+}
@@ -285,6 +285,7 @@ mod tests {
                 doi: "10.1000/test1".to_string(),
                 title: Some("Machine Learning in Healthcare".to_string()),
                 authors: vec!["Smith, J.".to_string()],
+                raw_authors: Vec::new(),
                 journal: None,
                 year: Some(2023),
                 abstract_text: Some(
@@ -293,11 +294,14 @@ mod tests {
                 ),
                 pdf_url: None,
                 file_size: None,
+                license: None,
+                is_open_access: None,
             },
             PaperMetadata {
                 doi: "10.1000/test2".to_string(),
                 title: Some("Deep Neural Networks for Image Classification".to_string()),
                 authors: vec!["Doe, J.".to_string()],
+                raw_authors: Vec::new(),
                 journal: None,
                 year: Some(2023),
                 abstract_text: Some(
@@ -306,6 +310,8 @@ mod tests {
                 ),
                 pdf_url: None,
                 file_size: None,
+                license: None,
+                is_open_access: None,
             },
         ]
     }
@@ -380,6 +386,7 @@ mod tests {
             doi: "10.1000/quantum1".to_string(),
             title: Some("Quantum Computing Theory".to_string()),
             authors: vec!["Einstein, A.".to_string()],
+            raw_authors: Vec::new(),
             journal: None,
             year: Some(2023),
             abstract_text: Some(
@@ -387,6 +394,8 @@ mod tests {
             ),
             pdf_url: None,
             file_size: None,
+            license: None,
+            is_open_access: None,
         }];
         let quantum_result =
             tool.simple_heuristic_categorization("quantum physics", &quantum_papers);
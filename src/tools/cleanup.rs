@@ -0,0 +1,458 @@
+use crate::services::FileHasher;
+use crate::{Config, Result};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+use tracing::{debug, info, instrument, warn};
+
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+/// Input for pruning the download directory
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct CleanupDownloadsInput {
+    /// Delete files not modified for this many days (default:
+    /// `downloads.retention_days`)
+    #[serde(default)]
+    pub max_age_days: Option<u64>,
+    /// Then delete the least recently accessed files until the directory
+    /// fits in this many MB (default: `downloads.retention_max_size_mb`)
+    #[serde(default)]
+    pub max_total_size_mb: Option<u64>,
+    /// Only report what would be deleted
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+/// Files removed (or, in a dry run, that would be removed) by a cleanup
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CleanupDownloadsResult {
+    /// Deleted files, expired ones first, then in least recently used order
+    pub deleted: Vec<PathBuf>,
+    /// Bytes freed by the deleted files
+    pub freed_bytes: u64,
+    /// Bytes left in the download directory
+    pub remaining_bytes: u64,
+    /// Whether nothing was actually deleted
+    pub dry_run: bool,
+}
+
+/// Input for removing duplicate PDFs from the download directory
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct DedupeDownloadsInput {
+    /// Only report the duplicates
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+/// PDFs with identical content
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DuplicateGroup {
+    /// SHA256 of the shared content
+    pub sha256_hash: String,
+    /// Copy that stays, the first of the group by path
+    pub kept: PathBuf,
+    /// The other copies
+    pub removed: Vec<PathBuf>,
+}
+
+/// Duplicates found (and, unless a dry run, removed) in the download directory
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DedupeDownloadsResult {
+    /// Groups of identical PDFs, ordered by hash
+    pub groups: Vec<DuplicateGroup>,
+    /// Bytes freed by the removed copies
+    pub freed_bytes: u64,
+    /// Whether nothing was actually deleted
+    pub dry_run: bool,
+}
+
+/// A file in the download directory considered for deletion
+#[derive(Debug, Clone)]
+struct StoredFile {
+    path: PathBuf,
+    size: u64,
+    modified: SystemTime,
+    /// Last access, or the modification time where access times aren't kept
+    accessed: SystemTime,
+}
+
+/// Applies the retention policy to the download directory and removes
+/// duplicate PDFs.
+///
+/// Files older than the age limit go first, then the least recently accessed
+/// files until the directory fits the size budget. The quarantine directory
+/// and hidden files (repairs and other downloads in progress) are never
+/// touched.
+#[derive(Debug, Clone)]
+pub struct CleanupTool {
+    config: Arc<Config>,
+}
+
+impl CleanupTool {
+    /// Create a cleanup tool for the configured download directory
+    #[must_use]
+    pub const fn new(config: Arc<Config>) -> Self {
+        Self { config }
+    }
+
+    /// Apply the retention policy as of now
+    pub async fn cleanup(&self, input: CleanupDownloadsInput) -> Result<CleanupDownloadsResult> {
+        self.cleanup_at(input, SystemTime::now()).await
+    }
+
+    /// Apply the retention policy, measuring file ages from `now`
+    #[instrument(skip(self))]
+    pub async fn cleanup_at(
+        &self,
+        input: CleanupDownloadsInput,
+        now: SystemTime,
+    ) -> Result<CleanupDownloadsResult> {
+        let downloads = &self.config.downloads;
+        let max_age = input
+            .max_age_days
+            .or(downloads.retention_days)
+            .map(|days| Duration::from_secs(days.saturating_mul(SECONDS_PER_DAY)));
+        let max_total_bytes = input
+            .max_total_size_mb
+            .or(downloads.retention_max_size_mb)
+            .map(|mb| mb.saturating_mul(1024 * 1024));
+        if max_age.is_none() && max_total_bytes.is_none() {
+            return Err(crate::Error::InvalidInput {
+                field: "max_age_days".to_string(),
+                reason: "No retention policy: pass max_age_days or max_total_size_mb, or set \
+                         downloads.retention_days or downloads.retention_max_size_mb"
+                    .to_string(),
+            });
+        }
+
+        let files = if downloads.directory.is_dir() {
+            collect_stored_files(&downloads.directory, downloads.quarantine_dir.as_deref()).await?
+        } else {
+            Vec::new()
+        };
+        let (expired, kept) = plan_cleanup(files, max_age, max_total_bytes, now);
+
+        let mut deleted = Vec::with_capacity(expired.len());
+        let mut freed_bytes = 0;
+        let mut remaining_bytes: u64 = kept.iter().map(|f| f.size).sum();
+        for file in expired {
+            if !input.dry_run {
+                if let Err(e) = tokio::fs::remove_file(&file.path).await {
+                    warn!("Failed to delete {:?}: {}", file.path, e);
+                    remaining_bytes += file.size;
+                    continue;
+                }
+                debug!("Deleted {:?} ({} bytes)", file.path, file.size);
+            }
+            freed_bytes += file.size;
+            deleted.push(file.path);
+        }
+
+        info!(
+            "{} {} files ({} bytes) from {:?}, {} bytes remain",
+            if input.dry_run {
+                "Would delete"
+            } else {
+                "Deleted"
+            },
+            deleted.len(),
+            freed_bytes,
+            downloads.directory,
+            remaining_bytes
+        );
+
+        Ok(CleanupDownloadsResult {
+            deleted,
+            freed_bytes,
+            remaining_bytes,
+            dry_run: input.dry_run,
+        })
+    }
+
+    /// Hash every PDF in the download directory with `downloads.hash_workers`
+    /// workers and delete all but one copy of each content
+    #[instrument(skip(self))]
+    pub async fn dedupe(&self, input: DedupeDownloadsInput) -> Result<DedupeDownloadsResult> {
+        let downloads = &self.config.downloads;
+        let files = if downloads.directory.is_dir() {
+            collect_stored_files(&downloads.directory, downloads.quarantine_dir.as_deref()).await?
+        } else {
+            Vec::new()
+        };
+        // Sidecars of the same paper can be identical without being spare
+        let pdfs: Vec<StoredFile> = files
+            .into_iter()
+            .filter(|file| {
+                file.path
+                    .extension()
+                    .is_some_and(|ext| ext.eq_ignore_ascii_case("pdf"))
+            })
+            .collect();
+        let paths: Vec<PathBuf> = pdfs.iter().map(|file| file.path.clone()).collect();
+
+        let hasher = FileHasher::from_config(downloads);
+        let hash_groups = FileHasher::group_hashes(hasher.hash_files(&paths).await);
+
+        let mut groups = Vec::new();
+        let mut freed_bytes = 0;
+        for (sha256_hash, paths) in hash_groups {
+            let Some((kept, copies)) = paths.split_first() else {
+                continue;
+            };
+            let mut removed = Vec::with_capacity(copies.len());
+            for copy in copies {
+                if !input.dry_run {
+                    if let Err(e) = tokio::fs::remove_file(copy).await {
+                        warn!("Failed to delete duplicate {:?}: {}", copy, e);
+                        continue;
+                    }
+                    debug!("Deleted {:?}, a copy of {:?}", copy, kept);
+                }
+                freed_bytes += pdfs
+                    .iter()
+                    .find(|file| file.path == *copy)
+                    .map_or(0, |file| file.size);
+                removed.push(copy.clone());
+            }
+            if !removed.is_empty() {
+                groups.push(DuplicateGroup {
+                    sha256_hash,
+                    kept: kept.clone(),
+                    removed,
+                });
+            }
+        }
+
+        info!(
+            "{} {} duplicate PDFs ({} bytes) in {:?}",
+            if input.dry_run {
+                "Would delete"
+            } else {
+                "Deleted"
+            },
+            groups.iter().map(|g| g.removed.len()).sum::<usize>(),
+            freed_bytes,
+            downloads.directory
+        );
+
+        Ok(DedupeDownloadsResult {
+            groups,
+            freed_bytes,
+            dry_run: input.dry_run,
+        })
+    }
+}
+
+/// Split `files` into those to delete, in deletion order, and those to keep
+fn plan_cleanup(
+    files: Vec<StoredFile>,
+    max_age: Option<Duration>,
+    max_total_bytes: Option<u64>,
+    now: SystemTime,
+) -> (Vec<StoredFile>, Vec<StoredFile>) {
+    let (mut expired, mut kept): (Vec<_>, Vec<_>) = files.into_iter().partition(|file| {
+        max_age.is_some_and(|max_age| {
+            now.duration_since(file.modified)
+                .is_ok_and(|age| age > max_age)
+        })
+    });
+    expired.sort_by(|a, b| {
+        a.modified
+            .cmp(&b.modified)
+            .then_with(|| a.path.cmp(&b.path))
+    });
+
+    if let Some(budget) = max_total_bytes {
+        kept.sort_by(|a, b| {
+            a.accessed
+                .cmp(&b.accessed)
+                .then_with(|| a.path.cmp(&b.path))
+        });
+        let mut total: u64 = kept.iter().map(|f| f.size).sum();
+        let over_budget = kept
+            .iter()
+            .take_while(|file| {
+                let over = total > budget;
+                total -= file.size;
+                over
+            })
+            .count();
+        expired.extend(kept.drain(..over_budget));
+    }
+
+    (expired, kept)
+}
+
+/// Regular files below `dir`, skipping hidden files, symlinks and anything
+/// inside `quarantine_dir`
+async fn collect_stored_files(
+    dir: &Path,
+    quarantine_dir: Option<&Path>,
+) -> Result<Vec<StoredFile>> {
+    let mut files = Vec::new();
+    let mut pending = vec![dir.to_path_buf()];
+
+    while let Some(current) = pending.pop() {
+        if quarantine_dir.is_some_and(|quarantine| current.starts_with(quarantine)) {
+            continue;
+        }
+
+        let mut entries = tokio::fs::read_dir(&current).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            if entry.file_name().to_string_lossy().starts_with('.') {
+                continue;
+            }
+            let file_type = entry.file_type().await?;
+            if file_type.is_dir() {
+                pending.push(entry.path());
+            } else if file_type.is_file() {
+                let metadata = entry.metadata().await?;
+                let modified = metadata.modified()?;
+                files.push(StoredFile {
+                    path: entry.path(),
+                    size: metadata.len(),
+                    modified,
+                    accessed: metadata.accessed().unwrap_or(modified),
+                });
+            }
+        }
+    }
+
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(unix)]
+    use nix::sys::stat::utimes;
+    #[cfg(unix)]
+    use nix::sys::time::{TimeVal, TimeValLike};
+    use std::fs;
+    use tempfile::TempDir;
+
+    const NOW: u64 = 1_700_000_000;
+
+    /// Write `size` bytes to `path`, last modified and accessed `age_days` ago
+    #[cfg(unix)]
+    fn write_aged(path: &Path, size: usize, age_days: u64) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, vec![b'x'; size]).unwrap();
+        #[allow(clippy::cast_possible_wrap)]
+        let time = TimeVal::seconds((NOW - age_days * SECONDS_PER_DAY) as i64);
+        utimes(path, &time, &time).unwrap();
+    }
+
+    fn create_test_tool(directory: &Path, quarantine_dir: &Path) -> CleanupTool {
+        let mut config = Config::default();
+        config.downloads.directory = directory.to_path_buf();
+        config.downloads.quarantine_dir = Some(quarantine_dir.to_path_buf());
+        config.downloads.retention_days = Some(30);
+        CleanupTool::new(Arc::new(config))
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_cleanup_removes_only_expired_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let quarantine = root.join("quarantine");
+        write_aged(&root.join("old.pdf"), 100, 90);
+        write_aged(&root.join("ml/older.pdf"), 100, 365);
+        write_aged(&root.join("recent.pdf"), 100, 2);
+        write_aged(&root.join("ml/recent.pdf"), 100, 29);
+        write_aged(&quarantine.join("stale.pdf"), 100, 400);
+        write_aged(&root.join(".paper.pdf.repair"), 100, 400);
+
+        let tool = create_test_tool(root, &quarantine);
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(NOW);
+
+        let dry_run = tool
+            .cleanup_at(
+                CleanupDownloadsInput {
+                    dry_run: true,
+                    ..CleanupDownloadsInput::default()
+                },
+                now,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            dry_run.deleted,
+            vec![root.join("ml/older.pdf"), root.join("old.pdf")]
+        );
+        assert!(root.join("old.pdf").exists());
+
+        let result = tool
+            .cleanup_at(CleanupDownloadsInput::default(), now)
+            .await
+            .unwrap();
+        assert_eq!(result.deleted, dry_run.deleted);
+        assert_eq!(result.freed_bytes, 200);
+        assert_eq!(result.remaining_bytes, 200);
+        assert!(!root.join("old.pdf").exists());
+        assert!(!root.join("ml/older.pdf").exists());
+        assert!(root.join("recent.pdf").exists());
+        assert!(root.join("ml/recent.pdf").exists());
+        assert!(quarantine.join("stale.pdf").exists());
+        assert!(root.join(".paper.pdf.repair").exists());
+    }
+
+    #[tokio::test]
+    async fn test_dedupe_identical_pdfs_keeps_first_copy() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let quarantine = root.join("quarantine");
+        fs::create_dir_all(root.join("ml")).unwrap();
+        fs::create_dir_all(&quarantine).unwrap();
+        fs::write(root.join("a.pdf"), b"%PDF-1.4 same").unwrap();
+        fs::write(root.join("ml/b.pdf"), b"%PDF-1.4 same").unwrap();
+        fs::write(root.join("c.pdf"), b"%PDF-1.4 other").unwrap();
+        fs::write(quarantine.join("d.pdf"), b"%PDF-1.4 same").unwrap();
+        // Identical sidecars are left alone
+        fs::write(root.join("a.json"), b"{}").unwrap();
+        fs::write(root.join("ml/b.json"), b"{}").unwrap();
+
+        let tool = create_test_tool(root, &quarantine);
+        let dry_run = tool
+            .dedupe(DedupeDownloadsInput { dry_run: true })
+            .await
+            .unwrap();
+        assert_eq!(dry_run.groups.len(), 1);
+        assert_eq!(dry_run.groups[0].kept, root.join("a.pdf"));
+        assert_eq!(dry_run.groups[0].removed, vec![root.join("ml/b.pdf")]);
+        assert!(root.join("ml/b.pdf").exists());
+
+        let result = tool.dedupe(DedupeDownloadsInput::default()).await.unwrap();
+        assert_eq!(result.freed_bytes, 13);
+        assert!(root.join("a.pdf").exists());
+        assert!(!root.join("ml/b.pdf").exists());
+        assert!(root.join("c.pdf").exists());
+        assert!(quarantine.join("d.pdf").exists());
+        assert!(root.join("ml/b.json").exists());
+    }
+
+    #[test]
+    fn test_plan_cleanup_size_budget_removes_least_recently_used() {
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(NOW);
+        let file = |name: &str, accessed_days_ago: u64| StoredFile {
+            path: PathBuf::from(name),
+            size: 400,
+            modified: now,
+            accessed: now - Duration::from_secs(accessed_days_ago * SECONDS_PER_DAY),
+        };
+        let files = vec![file("a.pdf", 1), file("b.pdf", 10), file("c.pdf", 5)];
+
+        let (deleted, kept) = plan_cleanup(files, None, Some(1000), now);
+        let names = |files: &[StoredFile]| {
+            files
+                .iter()
+                .map(|f| f.path.to_string_lossy().to_string())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names(&deleted), vec!["b.pdf"]);
+        assert_eq!(names(&kept), vec!["c.pdf", "a.pdf"]);
+    }
+}
@@ -1,5 +1,7 @@
-use crate::client::{Doi, MetaSearchClient, PaperMetadata};
-use crate::services::CategorizationService;
+use crate::client::{http_client_builder, Doi, MetaSearchClient, PaperMetadata};
+use crate::config::ManifestLock;
+use crate::resilience::{retry_with_policy, RetryPolicy};
+use crate::services::{CategorizationService, DoiSuggester, FileHasher};
 // use crate::tools::command::{Command, CommandResult, ExecutionContext};
 use crate::{Config, Result};
 // use async_trait::async_trait;
@@ -7,21 +9,20 @@ use futures::StreamExt;
 use reqwest::Client;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use sha2::{Digest, Sha256};
 // use std::any::Any;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use std::time::{Duration, SystemTime};
+use std::time::{Duration, Instant, SystemTime};
 use tokio::fs::{File, OpenOptions};
-use tokio::io::{AsyncReadExt, AsyncWriteExt};
-use tokio::sync::{mpsc, RwLock};
+use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt};
+use tokio::sync::{mpsc, Mutex, RwLock};
 // use tokio_util::io::ReaderStream; // Not needed currently
 use tracing::{debug, error, info, instrument, warn};
 
 /// Input parameters for the paper download tool
 /// IMPORTANT: Either 'doi' or 'url' must be provided (not both optional!)
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[derive(Clone, Serialize, Deserialize, JsonSchema)]
 pub struct DownloadInput {
     /// DOI of the paper to download (preferred - extract from `search_papers` results)
     #[schemars(
@@ -40,9 +41,50 @@ pub struct DownloadInput {
     /// Whether to overwrite existing files
     #[serde(default)]
     pub overwrite: bool,
-    /// Whether to verify file integrity after download
-    #[serde(default = "default_verify")]
-    pub verify_integrity: bool,
+    /// Hash the file after download to verify its integrity; overrides
+    /// `downloads.verify_integrity_default`
+    #[serde(default)]
+    pub verify_integrity: Option<bool>,
+    /// Paper title, used to suggest corrections when the DOI is not found
+    #[serde(default)]
+    pub title: Option<String>,
+    /// Write the paper's metadata to a `.json` sidecar next to the PDF;
+    /// overrides `downloads.write_sidecar`
+    #[serde(default)]
+    pub write_sidecar: Option<bool>,
+    /// Extra request headers for `url`, e.g. the `Cookie` or `Authorization`
+    /// of an institutional login. Only sent to that URL; values are never
+    /// logged.
+    #[serde(default)]
+    #[schemars(
+        description = "Extra HTTP headers sent with the url download, e.g. an SSO cookie (url downloads only)"
+    )]
+    pub headers: Option<HashMap<String, String>>,
+}
+
+impl std::fmt::Debug for DownloadInput {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DownloadInput")
+            .field("doi", &self.doi)
+            .field("url", &self.url)
+            .field("filename", &self.filename)
+            .field("directory", &self.directory)
+            .field("category", &self.category)
+            .field("overwrite", &self.overwrite)
+            .field("verify_integrity", &self.verify_integrity)
+            .field("title", &self.title)
+            .field("write_sidecar", &self.write_sidecar)
+            .field("headers", &self.headers.as_ref().map(redacted_headers))
+            .finish()
+    }
+}
+
+/// Header names with their values replaced, for logging
+fn redacted_headers(headers: &HashMap<String, String>) -> std::collections::BTreeMap<&str, &str> {
+    headers
+        .keys()
+        .map(|name| (name.as_str(), "[REDACTED]"))
+        .collect()
 }
 
 /// Progress information for a download
@@ -56,8 +98,11 @@ pub struct DownloadProgress {
     pub total_size: Option<u64>,
     /// Downloaded bytes so far
     pub downloaded: u64,
-    /// Download percentage (0-100)
+    /// Download percentage (0-100); stays 0 while `indeterminate`
     pub percentage: f64,
+    /// Whether the total size is unknown, so only `downloaded` tracks progress
+    #[serde(default)]
+    pub indeterminate: bool,
     /// Current download speed in bytes/second
     pub speed_bps: u64,
     /// Estimated time remaining in seconds
@@ -99,8 +144,11 @@ pub struct DownloadResult {
     pub file_path: Option<PathBuf>,
     /// File size in bytes
     pub file_size: Option<u64>,
-    /// SHA256 hash of the file
+    /// SHA256 hash of the file; None when verification was skipped
     pub sha256_hash: Option<String>,
+    /// Whether the file was hashed to verify its integrity
+    #[serde(default)]
+    pub integrity_verified: bool,
     /// Download duration in seconds
     pub duration_seconds: f64,
     /// Average download speed in bytes/second
@@ -109,6 +157,69 @@ pub struct DownloadResult {
     pub metadata: Option<PaperMetadata>,
     /// Error message if failed
     pub error: Option<String>,
+    /// Output of `downloads.post_download_hook`, when one ran
+    #[serde(default)]
+    pub post_download_hook: Option<PostDownloadHookOutput>,
+    /// Provider whose PDF URL the file was downloaded from (None for direct
+    /// URLs)
+    #[serde(default, alias = "source")]
+    pub source_provider: Option<String>,
+    /// URL the file was downloaded from, after any failover to later
+    /// candidates
+    #[serde(default)]
+    pub download_url: Option<String>,
+}
+
+/// Outcome of running the configured post-download hook
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PostDownloadHookOutput {
+    /// Program and arguments the hook was started with
+    pub command: Vec<String>,
+    /// Exit code (None if the hook could not be run or was killed)
+    pub exit_code: Option<i32>,
+    /// Captured standard output
+    pub stdout: String,
+    /// Captured standard error
+    pub stderr: String,
+    /// Why the hook could not be run or did not finish
+    pub error: Option<String>,
+}
+
+/// A URL a paper can be downloaded from, with the provider that offered it
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct DownloadCandidate {
+    url: String,
+    source: Option<String>,
+    /// Headers the caller supplied for this URL
+    headers: HashMap<String, String>,
+}
+
+/// Contents of a metadata sidecar: the paper's metadata, with the provider
+/// the PDF came from alongside its fields
+#[derive(Serialize)]
+struct MetadataSidecar<'a> {
+    #[serde(flatten)]
+    metadata: &'a PaperMetadata,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    source_provider: Option<&'a str>,
+}
+
+/// Name of the downloads manifest in `downloads.directory`. The leading dot
+/// keeps `cleanup_downloads` from treating it as a stored download.
+const MANIFEST_FILE: &str = ".downloads.jsonl";
+
+/// One line of the downloads manifest, written per completed download
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ManifestEntry {
+    download_id: String,
+    completed_at: chrono::DateTime<chrono::Utc>,
+    doi: Option<String>,
+    title: Option<String>,
+    file_path: Option<PathBuf>,
+    file_size: Option<u64>,
+    sha256_hash: Option<String>,
+    source_provider: Option<String>,
+    download_url: Option<String>,
 }
 
 /// Download queue item
@@ -141,12 +252,13 @@ pub struct BatchDownloadInput {
         description = "Array of paper download requests. LIMIT: 1-100 papers per batch. For larger collections, split into multiple batch calls."
     )]
     pub papers: Vec<BatchDownloadRequest>,
-    /// Maximum concurrent downloads (LIMIT: 1-20, default: 9)
+    /// Maximum concurrent downloads (LIMIT: 1-20, default:
+    /// `concurrency.download_workers`)
     #[schemars(
-        description = "Number of concurrent downloads. LIMIT: 1-20 (default: 9). Higher values faster but use more bandwidth."
+        description = "Number of concurrent downloads. LIMIT: 1-20 (default: concurrency.download_workers from the config, 9 unless changed). Higher values faster but use more bandwidth."
     )]
-    #[serde(default = "default_batch_concurrency")]
-    pub max_concurrent: usize,
+    #[serde(default)]
+    pub max_concurrent: Option<usize>,
     /// Continue if some downloads fail (default: true)
     #[schemars(description = "Continue downloading remaining papers if some fail (default: true)")]
     #[serde(default = "default_true")]
@@ -190,9 +302,10 @@ pub struct BatchDownloadSettings {
     /// Whether to overwrite existing files
     #[serde(default)]
     pub overwrite: bool,
-    /// Whether to verify file integrity after download
-    #[serde(default = "default_verify")]
-    pub verify_integrity: bool,
+    /// Hash each file after download to verify its integrity; overrides
+    /// `downloads.verify_integrity_default`
+    #[serde(default)]
+    pub verify_integrity: Option<bool>,
 }
 
 /// Result of a batch download operation
@@ -236,18 +349,167 @@ pub struct BatchDownloadSummary {
     pub failed_items: Vec<String>,
 }
 
-/// Default batch concurrency limit
-const fn default_batch_concurrency() -> usize {
-    9 // Tripled from original 3
+/// Input for re-downloading a corrupt or truncated PDF
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct RepairDownloadInput {
+    /// DOI of the paper to re-download
+    #[serde(default)]
+    pub doi: Option<String>,
+    /// Path of the downloaded file to check (derived from the DOI if omitted)
+    #[serde(default)]
+    pub file_path: Option<String>,
+    /// Direct URL to re-download from instead of resolving the DOI
+    #[serde(default)]
+    pub url: Option<String>,
+}
+
+/// Outcome of checking and repairing a downloaded PDF
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct RepairDownloadResult {
+    /// File that was checked
+    pub file_path: PathBuf,
+    /// Why the file was considered corrupt (None if it was intact)
+    pub corruption: Option<String>,
+    /// Whether the file was replaced by a verified copy
+    pub repaired: bool,
+    /// The re-download that replaced the file
+    pub download: Option<DownloadResult>,
+}
+
+/// Input for checking a file on disk without downloading anything
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct VerifyFileInput {
+    /// Path of the file to check
+    pub file_path: String,
+    /// SHA-256 the file should have, as hex
+    #[serde(default)]
+    pub expected_sha256: Option<String>,
 }
 
-/// Default true value
-const fn default_true() -> bool {
-    true
+/// Integrity of a file on disk
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct VerifyFileResult {
+    /// File that was checked
+    pub file_path: PathBuf,
+    /// Size of the file in bytes
+    pub file_size: u64,
+    /// SHA-256 of the file, as lower-case hex
+    pub sha256: String,
+    /// Whether `sha256` equals the expected hash (None if none was given)
+    pub hash_matches: Option<bool>,
+    /// Whether the file starts with the `%PDF-` magic bytes
+    pub valid_pdf_header: bool,
+    /// Whether a `%%EOF` marker appears near the end of the file
+    pub has_eof_marker: bool,
+}
+
+/// How far from the end of a PDF the `%%EOF` marker may appear
+const PDF_EOF_SEARCH_BYTES: u64 = 1024;
+
+/// `Error::Parse` context for downloads abandoned because the server sent HTML
+const HTML_INTERSTITIAL_CONTEXT: &str = "html interstitial";
+
+/// Whether the start of a response body is an HTML document
+fn looks_like_html(body: &[u8]) -> bool {
+    let body = body.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(body);
+    let start = body
+        .iter()
+        .position(|b| !b.is_ascii_whitespace())
+        .unwrap_or(body.len());
+    let head: Vec<u8> = body[start..]
+        .iter()
+        .take(14)
+        .map(u8::to_ascii_lowercase)
+        .collect();
+    head.starts_with(b"<!doctype html") || head.starts_with(b"<html")
+}
+
+/// The first `max_chars` characters of `text`, never splitting a character
+fn truncate_chars(text: &str, max_chars: usize) -> &str {
+    text.char_indices()
+        .nth(max_chars)
+        .map_or(text, |(end, _)| &text[..end])
+}
+
+/// How long a post-download hook may run before it is killed
+const POST_DOWNLOAD_HOOK_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Longest a PDF optimizer may run before the download is kept as is
+const PDF_OPTIMIZER_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Split a configured command template into argv, replacing each
+/// `(placeholder, value)` within every whitespace-separated word. The split
+/// happens before substitution, so values containing spaces stay one
+/// argument, but the template itself has no quoting: a program or literal
+/// argument with a space in it cannot be written.
+fn command_argv(template: &str, substitutions: &[(&str, &str)]) -> Vec<String> {
+    template
+        .split_whitespace()
+        .map(|word| {
+            substitutions
+                .iter()
+                .fold(word.to_string(), |word, (placeholder, value)| {
+                    word.replace(placeholder, value)
+                })
+        })
+        .collect()
+}
+
+/// Bytes per second for `bytes` transferred in `duration`, rounded to the
+/// nearest byte. Fractional seconds count, so downloads finishing in under
+/// a second still report their rate.
+fn average_speed(bytes: u64, duration: Duration) -> u64 {
+    let secs = duration.as_secs_f64();
+    if secs <= 0.0 {
+        return 0;
+    }
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    let speed = (bytes as f64 / secs).round() as u64;
+    speed
+}
+
+/// Append `line` to the manifest at `path` in a single write, holding an
+/// exclusive advisory lock on the file throughout when `lock_file` is set
+fn append_manifest_line(path: &Path, line: &str, lock_file: bool) -> std::io::Result<()> {
+    use std::io::Write;
+
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?;
+    if lock_file {
+        write_locked(file, line)
+    } else {
+        file.write_all(line.as_bytes())
+    }
+}
+
+/// Write `line` to `file` under an exclusive `flock`
+#[cfg(unix)]
+fn write_locked(file: std::fs::File, line: &str) -> std::io::Result<()> {
+    use nix::fcntl::{Flock, FlockArg};
+    use std::io::Write;
+
+    // Released when the lock is dropped
+    let mut locked =
+        Flock::lock(file, FlockArg::LockExclusive).map_err(|(_, e)| std::io::Error::from(e))?;
+    locked.write_all(line.as_bytes())
 }
 
-/// Default for integrity verification
-const fn default_verify() -> bool {
+/// Without `flock`, appends are only serialized by the in-process mutex
+#[cfg(not(unix))]
+fn write_locked(mut file: std::fs::File, line: &str) -> std::io::Result<()> {
+    use std::io::Write;
+
+    file.write_all(line.as_bytes())
+}
+
+/// Default true value
+const fn default_true() -> bool {
     true
 }
 
@@ -262,6 +524,9 @@ pub struct DownloadTool {
     active_downloads: Arc<RwLock<HashMap<String, DownloadState>>>,
     progress_sender: Option<mpsc::UnboundedSender<DownloadProgress>>,
     categorization_service: CategorizationService,
+    doi_suggester: Option<DoiSuggester>,
+    /// Held while appending to the downloads manifest
+    manifest_lock: Arc<Mutex<()>>,
 }
 
 impl std::fmt::Debug for DownloadTool {
@@ -274,6 +539,8 @@ impl std::fmt::Debug for DownloadTool {
             .field("active_downloads", &"RwLock<HashMap>")
             .field("progress_sender", &"Option<UnboundedSender>")
             .field("categorization_service", &"CategorizationService")
+            .field("doi_suggester", &self.doi_suggester.is_some())
+            .field("manifest_lock", &"Mutex<()>")
             .finish()
     }
 }
@@ -283,7 +550,7 @@ impl DownloadTool {
     pub fn new(client: Arc<MetaSearchClient>, config: Arc<Config>) -> Result<Self> {
         info!("Initializing paper download tool");
 
-        let http_client = Client::builder()
+        let http_client = http_client_builder(&config.http)
             .timeout(Duration::from_secs(config.research_source.timeout_secs * 2)) // Longer timeout for downloads
             .connect_timeout(Duration::from_secs(30))
             .pool_max_idle_per_host(10) // Enable connection pooling with 10 idle connections per host
@@ -301,6 +568,12 @@ impl DownloadTool {
                 crate::Error::Service(format!("Failed to create categorization service: {e}"))
             })?;
 
+        let doi_suggester = DoiSuggester::from_config(
+            &config.doi_suggestions,
+            &config.http,
+            Duration::from_secs(config.research_source.timeout_secs),
+        )?;
+
         Ok(Self {
             client,
             http_client,
@@ -309,6 +582,8 @@ impl DownloadTool {
             active_downloads: Arc::new(RwLock::new(HashMap::new())),
             progress_sender: None,
             categorization_service,
+            doi_suggester,
+            manifest_lock: Arc::new(Mutex::new(())),
         })
     }
 
@@ -324,6 +599,12 @@ impl DownloadTool {
         });
     }
 
+    /// Whether to hash a download: the caller's choice, else
+    /// `downloads.verify_integrity_default`
+    fn verify_integrity(&self, requested: Option<bool>) -> bool {
+        requested.unwrap_or(self.config.downloads.verify_integrity_default)
+    }
+
     /// Download a paper by DOI or URL
     // #[tool] // Will be enabled when rmcp integration is complete
     #[instrument(skip(self), fields(doi = ?input.doi, url = ?input.url))]
@@ -331,9 +612,10 @@ impl DownloadTool {
         debug!("📥 Starting paper download process");
         debug!("🔍 Input validation - DOI: {:?}, URL: {:?}, filename: {:?}, directory: {:?}, category: {:?}",
                input.doi, input.url, input.filename, input.directory, input.category);
+        let verify_integrity = self.verify_integrity(input.verify_integrity);
         debug!(
             "⚙️ Download settings - overwrite: {}, verify_integrity: {}",
-            input.overwrite, input.verify_integrity
+            input.overwrite, verify_integrity
         );
 
         info!(
@@ -350,27 +632,47 @@ impl DownloadTool {
         let download_id = uuid::Uuid::new_v4().to_string();
         debug!("🆔 Generated download ID: {}", download_id);
 
-        // Get download URL and metadata
+        // Get download URL and metadata, sparing the full search when the
+        // best DOI provider has the PDF
         debug!("🔎 Resolving download source for input");
-        let (download_url, metadata) = match self.resolve_download_source(&input).await {
-            Ok((url, meta)) => {
-                debug!("✅ Successfully resolved download source");
-                debug!("📄 Metadata found: {}", meta.is_some());
-                debug!("🔗 Download URL length: {} chars", url.len());
-                debug!(
-                    "🔗 Download URL (truncated): {}...",
-                    if url.len() > 100 { &url[..100] } else { &url }
-                );
-                (url, meta)
-            }
-            Err(e) => {
-                debug!("❌ Failed to resolve download source: {}", e);
-                debug!("🔧 Error type: {:?}", std::any::type_name_of_val(&e));
-                return Err(e);
-            }
+        let fast_path = if self.config.downloads.doi_fast_path {
+            self.resolve_doi_fast_path(&input).await
+        } else {
+            None
+        };
+        let from_fast_path = fast_path.is_some();
+        let (candidates, metadata) = match fast_path {
+            Some(resolved) => resolved,
+            None => match self.resolve_download_source(&input).await {
+                Ok((candidates, meta)) => {
+                    debug!("✅ Successfully resolved download source");
+                    let url = candidates.first().map_or("", |c| c.url.as_str());
+                    debug!(
+                        "📄 Metadata found: {}, candidates: {}",
+                        meta.is_some(),
+                        candidates.len()
+                    );
+                    debug!("🔗 Download URL length: {} chars", url.len());
+                    debug!(
+                        "🔗 Download URL (truncated): {}...",
+                        truncate_chars(url, 100)
+                    );
+                    (candidates, meta)
+                }
+                Err(e) => {
+                    debug!("❌ Failed to resolve download source: {}", e);
+                    debug!("🔧 Error type: {:?}", std::any::type_name_of_val(&e));
+                    return Err(e);
+                }
+            },
         };
 
         // Safety check: ensure we never proceed with an empty URL
+        let download_url = candidates
+            .first()
+            .map(|c| c.url.clone())
+            .unwrap_or_default();
+        let source = candidates.first().and_then(|c| c.source.clone());
         if download_url.is_empty() {
             error!("❌ resolve_download_source returned an empty URL - this is a bug!");
             debug!(
@@ -387,7 +689,7 @@ impl DownloadTool {
         // Determine target file path
         debug!("📁 Determining target file path");
         let file_path = match self
-            .determine_file_path(&input, metadata.as_ref(), &download_url)
+            .determine_file_path(&input, metadata.as_ref(), source.as_deref(), &download_url)
             .await
         {
             Ok(path) => {
@@ -409,7 +711,7 @@ impl DownloadTool {
         debug!("🔍 Checking for existing file at: {:?}", file_path);
         if file_path.exists() && !input.overwrite {
             debug!("📄 File already exists, checking integrity verification setting");
-            if input.verify_integrity {
+            if verify_integrity {
                 debug!("🔐 Calculating hash for existing file verification");
                 if let Ok(hash) = self.calculate_file_hash(&file_path).await {
                     let file_size = tokio::fs::metadata(&file_path).await?.len();
@@ -425,10 +727,14 @@ impl DownloadTool {
                         file_path: Some(file_path),
                         file_size: Some(file_size),
                         sha256_hash: Some(hash),
+                        integrity_verified: true,
                         duration_seconds: 0.0,
                         average_speed: 0,
                         metadata,
                         error: None,
+                        post_download_hook: None,
+                        source_provider: source,
+                        download_url: None,
                     });
                 }
                 debug!("⚠️ Failed to verify existing file hash");
@@ -452,24 +758,89 @@ impl DownloadTool {
         debug!("🚀 Starting download execution");
         debug!(
             "📊 Download parameters - ID: {}, verify: {}, file: {:?}",
-            download_id, input.verify_integrity, file_path
+            download_id, verify_integrity, file_path
         );
 
         // Save a copy for cleanup in case of failure
         let cleanup_path = file_path.clone();
 
-        match self
-            .execute_download(
+        // Without a chosen name or a title, the server's filename beats one
+        // derived from the URL
+        let name_from_server = self.config.downloads.honor_content_disposition
+            && input.filename.is_none()
+            && self.config.downloads.filename_template.is_none()
+            && metadata.as_ref().and_then(|m| m.title.as_ref()).is_none();
+
+        let existed = file_path.exists();
+        let tried: Vec<String> = candidates.iter().map(|c| c.url.clone()).collect();
+        let mut outcome = self
+            .execute_download_with_failover(
                 download_id.clone(),
-                download_url,
-                file_path,
-                metadata,
-                input.verify_integrity,
+                candidates,
+                file_path.clone(),
+                metadata.clone(),
+                verify_integrity,
+                name_from_server,
+                from_fast_path,
             )
-            .await
-        {
-            Ok(result) => {
+            .await;
+        // A failed fast path download falls back to the full resolution
+        if from_fast_path {
+            outcome = match outcome {
+                Err(e) if self.worth_another_url(&e) => {
+                    if !existed && file_path.exists() {
+                        if let Err(e) = tokio::fs::remove_file(&file_path).await {
+                            warn!("Failed to remove partial download {:?}: {}", file_path, e);
+                        }
+                    }
+                    self.download_after_fast_path_failure(
+                        &input,
+                        e,
+                        &tried,
+                        download_id.clone(),
+                        file_path,
+                        metadata,
+                        name_from_server,
+                    )
+                    .await
+                }
+                outcome => outcome,
+            };
+        }
+
+        match outcome {
+            Ok(mut result) => {
                 debug!("✅ Download execution completed successfully");
+                if let Some(file_path) = result.file_path.clone() {
+                    if self.config.downloads.optimize_pdf && self.optimize_pdf(&file_path).await {
+                        result.file_size = tokio::fs::metadata(&file_path)
+                            .await
+                            .map(|m| m.len())
+                            .ok()
+                            .or(result.file_size);
+                        if result.sha256_hash.is_some() {
+                            result.sha256_hash = self.calculate_file_hash(&file_path).await.ok();
+                        }
+                    }
+                }
+                if let Some(file_path) = &result.file_path {
+                    if input
+                        .write_sidecar
+                        .unwrap_or(self.config.downloads.write_sidecar)
+                    {
+                        Self::write_metadata_sidecar(
+                            file_path,
+                            result.metadata.as_ref(),
+                            result.source_provider.as_deref(),
+                        )
+                        .await;
+                    }
+                    if self.config.downloads.write_manifest {
+                        self.append_to_manifest(&result, input.doi.as_deref()).await;
+                    }
+                    let doi = result.metadata.as_ref().map_or("", |m| m.doi.as_str());
+                    result.post_download_hook = self.run_post_download_hook(file_path, doi).await;
+                }
                 debug!(
                     "📊 Final result - status: {:?}, size: {:?} bytes, duration: {:.2}s",
                     result.status, result.file_size, result.duration_seconds
@@ -491,24 +862,263 @@ impl DownloadTool {
         }
     }
 
+    /// Download the PDF for `input`'s DOI or URL to exactly `file_path`,
+    /// with the source resolution, security checks and size limit of
+    /// [`Self::download_paper`] but none of its follow-up steps (sidecar,
+    /// optimization, post-download hook). The caller owns the file; a failed
+    /// download leaves nothing behind.
+    pub async fn download_transient(
+        &self,
+        input: &DownloadInput,
+        file_path: &Path,
+    ) -> Result<DownloadResult> {
+        Self::validate_input(input)?;
+        Self::validate_file_security(file_path).await?;
+
+        let (candidates, metadata) = self.resolve_download_source(input).await?;
+        let result = self
+            .execute_download_with_failover(
+                uuid::Uuid::new_v4().to_string(),
+                candidates,
+                file_path.to_path_buf(),
+                metadata,
+                self.verify_integrity(input.verify_integrity),
+                false,
+                false,
+            )
+            .await;
+        if result.is_err() && file_path.exists() {
+            if let Err(e) = tokio::fs::remove_file(file_path).await {
+                warn!("Failed to remove partial download {:?}: {}", file_path, e);
+            }
+        }
+        result
+    }
+
+    /// Check a downloaded PDF and, if it is corrupt or truncated, download it
+    /// again. The original file is only replaced once the new copy passes the
+    /// same checks, so a failed repair leaves it untouched.
+    #[instrument(skip(self), fields(doi = ?input.doi, file_path = ?input.file_path))]
+    pub async fn repair_download(
+        &self,
+        input: RepairDownloadInput,
+    ) -> Result<RepairDownloadResult> {
+        if input.doi.is_none() && input.file_path.is_none() {
+            return Err(crate::Error::InvalidInput {
+                field: "doi".to_string(),
+                reason: "Either doi or file_path must be provided".to_string(),
+            });
+        }
+
+        let download_input = DownloadInput {
+            doi: input.doi.clone(),
+            url: input.url.clone(),
+            filename: None,
+            directory: None,
+            category: None,
+            overwrite: true,
+            verify_integrity: Some(true),
+            title: None,
+            write_sidecar: None,
+            headers: None,
+        };
+        if input.doi.is_some() || input.url.is_some() {
+            Self::validate_input(&download_input)?;
+        }
+
+        // An intact file given by path needs no network access at all
+        let mut resolved = None;
+        let file_path = if let Some(path) = &input.file_path {
+            PathBuf::from(path)
+        } else {
+            let (candidates, metadata) = self.resolve_download_source(&download_input).await?;
+            let first = candidates.first();
+            let path = self
+                .determine_file_path(
+                    &download_input,
+                    metadata.as_ref(),
+                    first.and_then(|c| c.source.as_deref()),
+                    first.map_or("", |c| c.url.as_str()),
+                )
+                .await?;
+            resolved = Some((candidates, metadata));
+            path
+        };
+
+        if !file_path.is_file() {
+            return Err(crate::Error::InvalidInput {
+                field: "file_path".to_string(),
+                reason: format!("No downloaded file at {}", file_path.display()),
+            });
+        }
+        Self::validate_file_security(&file_path).await?;
+
+        let Some(corruption) = self.pdf_corruption(&file_path).await? else {
+            info!("{:?} is intact, nothing to repair", file_path);
+            return Ok(RepairDownloadResult {
+                file_path,
+                corruption: None,
+                repaired: false,
+                download: None,
+            });
+        };
+        warn!("{:?} is corrupt: {}", file_path, corruption);
+
+        let (candidates, metadata) = match resolved {
+            Some(resolved) => resolved,
+            None if input.doi.is_some() || input.url.is_some() => {
+                self.resolve_download_source(&download_input).await?
+            }
+            None => {
+                return Err(crate::Error::InvalidInput {
+                    field: "doi".to_string(),
+                    reason: format!(
+                    "{} is corrupt ({corruption}); pass the paper's doi or url to re-download it",
+                    file_path.display()
+                ),
+                })
+            }
+        };
+
+        let file_name = file_path.file_name().map_or_else(
+            || "download.pdf".to_string(),
+            |n| n.to_string_lossy().to_string(),
+        );
+        let repair_path = file_path.with_file_name(format!(".{file_name}.repair"));
+        // A leftover from an interrupted repair would otherwise be resumed
+        if repair_path.exists() {
+            tokio::fs::remove_file(&repair_path).await?;
+        }
+
+        let downloaded = self
+            .execute_download_with_failover(
+                uuid::Uuid::new_v4().to_string(),
+                candidates,
+                repair_path.clone(),
+                metadata,
+                true,
+                false,
+                false,
+            )
+            .await;
+        let verified = match downloaded {
+            Ok(result) => self
+                .pdf_corruption(&repair_path)
+                .await
+                .map(|failure| (result, failure)),
+            Err(e) => Err(e),
+        };
+        let mut result = match verified {
+            Ok((result, None)) => result,
+            Ok((_, Some(reason))) => {
+                let _ = tokio::fs::remove_file(&repair_path).await;
+                return Err(crate::Error::Parse {
+                    context: "repaired download".to_string(),
+                    message: format!("Re-downloaded copy is also corrupt: {reason}"),
+                });
+            }
+            Err(e) => {
+                if repair_path.exists() {
+                    let _ = tokio::fs::remove_file(&repair_path).await;
+                }
+                return Err(e);
+            }
+        };
+
+        tokio::fs::rename(&repair_path, &file_path).await?;
+        info!("Repaired {:?}", file_path);
+
+        result.file_path = Some(file_path.clone());
+        let doi = result.metadata.as_ref().map_or("", |m| m.doi.as_str());
+        result.post_download_hook = self.run_post_download_hook(&file_path, doi).await;
+
+        Ok(RepairDownloadResult {
+            file_path,
+            corruption: Some(corruption),
+            repaired: true,
+            download: Some(result),
+        })
+    }
+
+    /// Hash a file on disk and check it for the PDF header and `%%EOF`
+    /// marker, comparing the hash with `expected_sha256` when given
+    #[instrument(skip(self), fields(file_path = %input.file_path))]
+    pub async fn verify_file(&self, input: VerifyFileInput) -> Result<VerifyFileResult> {
+        let expected = input
+            .expected_sha256
+            .as_deref()
+            .map(|hash| hash.trim().to_ascii_lowercase());
+        if let Some(expected) = &expected {
+            if expected.len() != 64 || !expected.chars().all(|c| c.is_ascii_hexdigit()) {
+                return Err(crate::Error::InvalidInput {
+                    field: "expected_sha256".to_string(),
+                    reason: "Expected a SHA-256 hash of 64 hex characters".to_string(),
+                });
+            }
+        }
+
+        let file_path = PathBuf::from(&input.file_path);
+        if !file_path.is_file() {
+            return Err(crate::Error::InvalidInput {
+                field: "file_path".to_string(),
+                reason: format!("No file at {}", file_path.display()),
+            });
+        }
+        Self::validate_file_security(&file_path).await?;
+
+        let sha256 = self.calculate_file_hash(&file_path).await?;
+        let mut file = File::open(&file_path).await?;
+        let file_size = file.metadata().await?.len();
+
+        let mut header = [0u8; 5];
+        let valid_pdf_header = file.read_exact(&mut header).await.is_ok() && &header == b"%PDF-";
+
+        file.seek(std::io::SeekFrom::Start(
+            file_size.saturating_sub(PDF_EOF_SEARCH_BYTES),
+        ))
+        .await?;
+        let mut tail = Vec::new();
+        file.read_to_end(&mut tail).await?;
+        let has_eof_marker = tail.windows(5).any(|window| window == b"%%EOF");
+
+        let hash_matches = expected.map(|expected| expected == sha256);
+        info!(
+            "Verified {:?}: sha256 {}, matches: {:?}, PDF header: {}, %%EOF: {}",
+            file_path, sha256, hash_matches, valid_pdf_header, has_eof_marker
+        );
+        Ok(VerifyFileResult {
+            file_path,
+            file_size,
+            sha256,
+            hash_matches,
+            valid_pdf_header,
+            has_eof_marker,
+        })
+    }
+
     /// Download multiple papers concurrently
-    #[instrument(skip(self), fields(num_papers = input.papers.len(), max_concurrent = input.max_concurrent))]
+    #[instrument(skip(self), fields(num_papers = input.papers.len(), max_concurrent = ?input.max_concurrent))]
     pub async fn download_papers_batch(
         &self,
         input: BatchDownloadInput,
     ) -> Result<BatchDownloadResult> {
         let start_time = SystemTime::now();
+
+        // Validate input
+        Self::validate_batch_input(&input)?;
+
+        let workers = input
+            .max_concurrent
+            .unwrap_or(self.config.concurrency.download_workers)
+            .max(1);
         info!(
             "Starting batch download of {} papers with {} concurrent connections",
             input.papers.len(),
-            input.max_concurrent
+            workers
         );
 
-        // Validate input
-        Self::validate_batch_input(&input)?;
-
         // Create semaphore for concurrency control
-        let semaphore = Arc::new(tokio::sync::Semaphore::new(input.max_concurrent));
+        let semaphore = Arc::new(tokio::sync::Semaphore::new(workers));
 
         // Prepare individual download tasks
         let mut tasks = Vec::new();
@@ -534,16 +1144,40 @@ impl DownloadTool {
                 }
             };
 
+            // Backpressure: refuse work past the queue limit
+            let queue_id = match self.enqueue(download_input.clone()).await {
+                Ok(id) => id,
+                Err(e) => {
+                    let identifier = Self::get_request_identifier(&paper_request);
+                    warn!("Not queueing batch request for {}: {}", identifier, e);
+                    failed_items.push(identifier);
+
+                    if !input.continue_on_error {
+                        return Err(e);
+                    }
+                    continue;
+                }
+            };
+
             let semaphore = semaphore.clone();
             let download_tool = self.clone(); // Clone the tool for the async task
 
             let task = tokio::spawn(async move {
-                let _permit = semaphore.acquire().await.map_err(|e| {
+                let permit = semaphore.acquire().await.map_err(|e| {
                     crate::Error::Service(format!("Failed to acquire download semaphore: {e}"))
-                })?;
+                });
+                let _permit = match permit {
+                    Ok(permit) => permit,
+                    Err(e) => {
+                        download_tool.dequeue(&queue_id).await;
+                        return Err(e);
+                    }
+                };
 
                 debug!("Starting download {} of batch", index + 1);
+                download_tool.mark_queue_item_started(&queue_id).await;
                 let result = download_tool.download_paper(download_input).await;
+                download_tool.dequeue(&queue_id).await;
                 debug!(
                     "Completed download {} of batch: {:?}",
                     index + 1,
@@ -679,12 +1313,11 @@ impl DownloadTool {
             });
         }
 
-        if input.max_concurrent == 0 || input.max_concurrent > 20 {
+        if let Some(max_concurrent) = input.max_concurrent.filter(|&n| n == 0 || n > 20) {
             return Err(crate::Error::InvalidInput {
                 field: "max_concurrent".to_string(),
                 reason: format!(
-                    "Concurrency must be 1-20 (you provided {}). Recommended: 9 for most connections, 3 for slow networks, 15-20 for fast networks.",
-                    input.max_concurrent
+                    "Concurrency must be 1-20 (you provided {max_concurrent}). Recommended: 9 for most connections, 3 for slow networks, 15-20 for fast networks."
                 ),
             });
         }
@@ -763,6 +1396,9 @@ impl DownloadTool {
                 .or_else(|| shared_settings.category.clone()),
             overwrite: shared_settings.overwrite,
             verify_integrity: shared_settings.verify_integrity,
+            title: None,
+            write_sidecar: None,
+            headers: None,
         })
     }
 
@@ -805,6 +1441,26 @@ impl DownloadTool {
             })?;
         }
 
+        // Caller-supplied headers only ever go to the caller's own URL
+        if let Some(headers) = &input.headers {
+            if input.url.is_none() {
+                return Err(crate::Error::InvalidInput {
+                    field: "headers".to_string(),
+                    reason: "Headers can only be sent with a url download".to_string(),
+                });
+            }
+            for (name, value) in headers {
+                if reqwest::header::HeaderName::from_bytes(name.as_bytes()).is_err()
+                    || reqwest::header::HeaderValue::from_str(value).is_err()
+                {
+                    return Err(crate::Error::InvalidInput {
+                        field: "headers".to_string(),
+                        reason: format!("Invalid HTTP header: {name}"),
+                    });
+                }
+            }
+        }
+
         // Validate filename if provided - enhanced security checks
         if let Some(filename) = &input.filename {
             // Check for path traversal attempts
@@ -846,11 +1502,11 @@ impl DownloadTool {
         Ok(())
     }
 
-    /// Resolve download source to URL and metadata
+    /// Resolve download source to candidate URLs, best first, and metadata
     async fn resolve_download_source(
         &self,
         input: &DownloadInput,
-    ) -> Result<(String, Option<PaperMetadata>)> {
+    ) -> Result<(Vec<DownloadCandidate>, Option<PaperMetadata>)> {
         if let Some(doi_str) = &input.doi {
             debug!("🆔 Starting DOI-based resolution for: {}", doi_str);
             info!("Attempting to download paper with DOI: {}", doi_str);
@@ -898,10 +1554,7 @@ impl DownloadTool {
                         debug!(
                             "  [{}] Title: {:?}, PDF URL present: {}",
                             i + 1,
-                            paper
-                                .title
-                                .as_ref()
-                                .map(|t| if t.len() > 50 { &t[..50] } else { t }),
+                            paper.title.as_ref().map(|t| truncate_chars(t, 50)),
                             paper.pdf_url.as_ref().map_or(false, |url| !url.is_empty())
                         );
                     }
@@ -935,7 +1588,9 @@ impl DownloadTool {
                         debug!("✅ Direct PDF URL found - length: {} chars", pdf_url.len());
                         debug!("🔗 URL source: direct provider response");
                         info!("Found PDF URL directly from provider: {}", pdf_url);
-                        return Ok((pdf_url.clone(), Some(paper)));
+                        let candidates = self.pdf_url_candidates(&search_result);
+                        debug!("🔗 {} candidate PDF URL(s) available", candidates.len());
+                        return Ok((candidates, Some(paper)));
                     }
                     debug!("⚠️ Paper has PDF URL field but it's empty - data inconsistency");
                     warn!("Paper has PDF URL but it's empty - this shouldn't happen!");
@@ -963,10 +1618,7 @@ impl DownloadTool {
                     for paper in papers {
                         debug!(
                             "    - Title: {:?}",
-                            paper
-                                .title
-                                .as_ref()
-                                .map(|t| if t.len() > 60 { &t[..60] } else { t })
+                            paper.title.as_ref().map(|t| truncate_chars(t, 60))
                         );
                         debug!(
                             "    - Authors: {:?}",
@@ -981,20 +1633,36 @@ impl DownloadTool {
 
             // Try cascade PDF retrieval through all providers
             debug!("🔄 Executing cascade retrieval for DOI: {}", doi_str);
-            match self.client.get_pdf_url_cascade(doi_str).await {
-                Ok(Some(pdf_url)) => {
-                    debug!("✅ Cascade retrieval SUCCESS! PDF URL obtained");
-                    debug!("🔗 PDF URL length: {} chars", pdf_url.len());
+            // With URL failover every provider is asked, so a failed download
+            // has somewhere to go next
+            let limit = usize::from(!self.config.downloads.url_failover);
+            match self.client.get_pdf_sources_cascade(doi_str, limit).await {
+                Ok(sources) if !sources.is_empty() => {
+                    debug!(
+                        "✅ Cascade retrieval SUCCESS! {} PDF URL(s) obtained",
+                        sources.len()
+                    );
                     debug!(
                         "📄 Using metadata from first search result: {}",
                         search_result.papers.first().is_some()
                     );
-                    info!("Cascade retrieval successful! Found PDF URL: {}", pdf_url);
+                    info!(
+                        "Cascade retrieval successful! Found PDF URL: {}",
+                        sources[0].1
+                    );
                     // Use the first paper's metadata if available
                     let metadata = search_result.papers.first().cloned();
-                    return Ok((pdf_url, metadata));
+                    let candidates = sources
+                        .into_iter()
+                        .map(|(source, url)| DownloadCandidate {
+                            url,
+                            source: Some(source),
+                            headers: HashMap::new(),
+                        })
+                        .collect();
+                    return Ok((candidates, metadata));
                 }
-                Ok(None) => {
+                Ok(_) => {
                     debug!("❌ Cascade retrieval completed but returned None");
                     debug!("📝 This means all providers were checked but no PDF was found");
                     info!("Cascade retrieval completed but no PDF found in any provider");
@@ -1009,6 +1677,31 @@ impl DownloadTool {
                 }
             }
 
+            // Last resort: a broad title search sometimes finds a hosted copy
+            if self.config.downloads.title_search_fallback {
+                let title = search_result
+                    .papers
+                    .first()
+                    .and_then(|paper| paper.title.clone())
+                    .or_else(|| input.title.clone());
+                if let Some(title) = title {
+                    if let Some((pdf_url, paper, source)) =
+                        self.resolve_by_title_search(&title).await
+                    {
+                        // Keep the DOI lookup's metadata, it describes the requested paper
+                        let metadata = search_result.papers.first().cloned().or(Some(paper));
+                        return Ok((
+                            vec![DownloadCandidate {
+                                url: pdf_url,
+                                source,
+                                headers: HashMap::new(),
+                            }],
+                            metadata,
+                        ));
+                    }
+                }
+            }
+
             // If cascade also failed, return detailed error with metadata
             debug!("❌ All retrieval methods exhausted - preparing detailed error response");
             if let Some(paper) = search_result.papers.first() {
@@ -1027,49 +1720,15 @@ impl DownloadTool {
                     );
                 }
 
-                debug!("📋 Preparing detailed error message with paper metadata");
                 debug!(
                     "📄 Paper details - Title: {:?}, Authors: {:?}, Year: {:?}",
                     paper.title, paper.authors, paper.year
                 );
 
-                let error_msg = format!(
-                    "📄 Paper Metadata Found but No PDF Available\n\n\
-                    The paper was successfully located in {} academic database(s), but none provided a downloadable PDF link.\n\n\
-                    📚 Paper Details:\n\
-                    • Title: '{}'\n\
-                    • Authors: {}\n\
-                    • Year: {}\n\
-                    • DOI: {}\n\n\
-                    🔍 Sources Searched: ArXiv, CrossRef, SSRN, Sci-Hub, and others\n\n\
-                    💡 This typically means:\n\
-                    • The paper is behind a paywall\n\
-                    • It's a book or conference proceedings requiring institutional access\n\
-                    • The paper may be available only in print\n\
-                    • Publishers haven't made it freely available\n\n\
-                    🚀 Try These Alternatives:\n\
-                    1. Check your institution's library access\n\
-                    2. Visit the publisher's website directly\n\
-                    3. Search Google Scholar for preprint versions\n\
-                    4. Contact the authors for a copy\n\
-                    5. Check ResearchGate or Academia.edu\n\
-                    6. Look for related open-access papers by the same authors",
-                    search_result.successful_providers,
-                    paper.title.as_ref().unwrap_or(&"Unknown title".to_string()),
-                    if paper.authors.is_empty() { "Unknown authors".to_string() } else { paper.authors.join(", ") },
-                    paper.year.map_or("Unknown year".to_string(), |y| y.to_string()),
-                    doi_str
-                );
-
-                debug!(
-                    "📝 Generated error message length: {} chars",
-                    error_msg.len()
-                );
-
-                debug!("❌ Returning ServiceUnavailable error for PDF Download");
-                Err(crate::Error::ServiceUnavailable {
-                    service: "PDF Download".to_string(),
-                    reason: error_msg,
+                debug!("❌ Returning PaywalledPaper error for PDF Download");
+                Err(crate::Error::PaywalledPaper {
+                    metadata: Box::new(paper.clone()),
+                    providers_checked: search_result.successful_providers,
                 })
             } else {
                 debug!("❌ No paper metadata found in any provider");
@@ -1104,6 +1763,10 @@ impl DownloadTool {
                     search_result.successful_providers,
                     search_result.failed_providers
                 );
+                let error_msg = error_msg
+                    + &self
+                        .doi_suggestions_section(doi_str, input.title.as_deref())
+                        .await;
 
                 debug!(
                     "❌ Returning ServiceUnavailable error for MetaSearch: {}",
@@ -1116,11 +1779,15 @@ impl DownloadTool {
             }
         } else if let Some(url) = &input.url {
             debug!("🔗 Using direct URL for download: {} chars", url.len());
-            debug!(
-                "🔗 URL (truncated): {}...",
-                if url.len() > 100 { &url[..100] } else { url }
-            );
-            Ok((url.clone(), None))
+            debug!("🔗 URL (truncated): {}...", truncate_chars(url, 100));
+            Ok((
+                vec![DownloadCandidate {
+                    url: url.clone(),
+                    source: None,
+                    headers: input.headers.clone().unwrap_or_default(),
+                }],
+                None,
+            ))
         } else {
             debug!("❌ No download source specified in input");
             Err(crate::Error::InvalidInput {
@@ -1130,29 +1797,197 @@ impl DownloadTool {
         }
     }
 
-    /// Determine the target file path for download
-    async fn determine_file_path(
+    /// Search the providers by title and return the first PDF URL belonging to
+    /// a result with the same title, along with that result and the provider it came from
+    async fn resolve_by_title_search(
         &self,
-        input: &DownloadInput,
-        metadata: Option<&PaperMetadata>,
-        download_url: &str,
-    ) -> Result<PathBuf> {
-        // Get base directory
-        let mut base_dir = input
-            .directory
+        title: &str,
+    ) -> Option<(String, PaperMetadata, Option<String>)> {
+        info!("Trying title search fallback for: {}", title);
+        let search_query = crate::client::providers::SearchQuery {
+            query: title.to_string(),
+            search_type: crate::client::providers::SearchType::Title,
+            max_results: 5,
+            offset: 0,
+            params: HashMap::new(),
+        };
+        let search_result = match self.client.search(&search_query).await {
+            Ok(result) => result,
+            Err(e) => {
+                warn!("Title search fallback failed: {}", e);
+                return None;
+            }
+        };
+
+        let wanted = Self::normalize_title(title);
+        let paper = search_result.papers.iter().find(|paper| {
+            paper.pdf_url.as_ref().is_some_and(|url| !url.is_empty())
+                && paper
+                    .title
+                    .as_deref()
+                    .is_some_and(|t| Self::normalize_title(t) == wanted)
+        })?;
+        let pdf_url = paper.pdf_url.clone()?;
+        let source = Self::pdf_url_source(&pdf_url, &search_result);
+        info!("Title search fallback found PDF URL: {}", pdf_url);
+        Some((pdf_url, paper.clone(), source))
+    }
+
+    /// Lowercase alphanumeric words of a title, for comparing titles across providers
+    fn normalize_title(title: &str) -> String {
+        title
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .map(str::to_lowercase)
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// "Did you mean" section listing likely corrections for a DOI that was
+    /// not found, or an empty string when there are none
+    async fn doi_suggestions_section(&self, doi: &str, title: Option<&str>) -> String {
+        let Some(suggester) = &self.doi_suggester else {
+            return String::new();
+        };
+        let suggestions = suggester.suggest(doi, title).await;
+        if suggestions.is_empty() {
+            return String::new();
+        }
+
+        let lines: Vec<String> = suggestions
+            .iter()
+            .map(|s| match &s.title {
+                Some(title) => format!("• {} - {}", s.doi, title),
+                None => format!("• {}", s.doi),
+            })
+            .collect();
+        format!("\n\n🔎 Did you mean:\n{}", lines.join("\n"))
+    }
+
+    /// Distinct PDF URLs in the search results to try in order: those of the
+    /// highest-priority provider first, then URLs only present in the merged
+    /// results
+    fn pdf_url_candidates(
+        &self,
+        search_result: &crate::client::MetaSearchResult,
+    ) -> Vec<DownloadCandidate> {
+        let mut sources: Vec<&String> = search_result.by_source.keys().collect();
+        sources.sort_by_key(|source| {
+            (
+                std::cmp::Reverse(self.client.provider_priority(source).unwrap_or(0)),
+                *source,
+            )
+        });
+
+        let mut candidates: Vec<DownloadCandidate> = Vec::new();
+        let urls = sources
+            .into_iter()
+            .flat_map(|source| {
+                search_result.by_source[source]
+                    .iter()
+                    .map(move |paper| (paper, Some(source)))
+            })
+            .chain(search_result.papers.iter().map(|paper| (paper, None)));
+        for (paper, source) in urls {
+            let Some(url) = paper.pdf_url.as_deref().filter(|url| !url.is_empty()) else {
+                continue;
+            };
+            if !candidates.iter().any(|c| c.url == url) {
+                candidates.push(DownloadCandidate {
+                    url: url.to_string(),
+                    source: source.cloned(),
+                    headers: HashMap::new(),
+                });
+            }
+        }
+        candidates
+    }
+
+    /// Provider whose results include `pdf_url`
+    fn pdf_url_source(
+        pdf_url: &str,
+        search_result: &crate::client::MetaSearchResult,
+    ) -> Option<String> {
+        let mut sources: Vec<&String> = search_result.by_source.keys().collect();
+        sources.sort();
+        sources
+            .into_iter()
+            .find(|source| {
+                search_result.by_source[*source]
+                    .iter()
+                    .any(|paper| paper.pdf_url.as_deref() == Some(pdf_url))
+            })
+            .cloned()
+    }
+
+    /// User-facing explanation for [`crate::Error::PaywalledPaper`]
+    #[must_use]
+    pub fn paywalled_message(metadata: &PaperMetadata, providers_checked: usize) -> String {
+        format!(
+            "📄 Paper Metadata Found but No PDF Available\n\n\
+            The paper was successfully located in {} academic database(s), but none provided a downloadable PDF link.\n\n\
+            📚 Paper Details:\n\
+            • Title: '{}'\n\
+            • Authors: {}\n\
+            • Year: {}\n\
+            • DOI: {}\n\n\
+            🔍 Sources Searched: ArXiv, CrossRef, SSRN, Sci-Hub, and others\n\n\
+            💡 This typically means:\n\
+            • The paper is behind a paywall\n\
+            • It's a book or conference proceedings requiring institutional access\n\
+            • The paper may be available only in print\n\
+            • Publishers haven't made it freely available\n\n\
+            🚀 Try These Alternatives:\n\
+            1. Check your institution's library access\n\
+            2. Visit the publisher's website directly\n\
+            3. Search Google Scholar for preprint versions\n\
+            4. Contact the authors for a copy\n\
+            5. Check ResearchGate or Academia.edu\n\
+            6. Look for related open-access papers by the same authors",
+            providers_checked,
+            metadata.title.as_deref().unwrap_or("Unknown title"),
+            if metadata.authors.is_empty() {
+                "Unknown authors".to_string()
+            } else {
+                metadata.authors.join(", ")
+            },
+            metadata
+                .year
+                .map_or("Unknown year".to_string(), |y| y.to_string()),
+            metadata.doi
+        )
+    }
+
+    /// Determine the target file path for download
+    async fn determine_file_path(
+        &self,
+        input: &DownloadInput,
+        metadata: Option<&PaperMetadata>,
+        source: Option<&str>,
+        download_url: &str,
+    ) -> Result<PathBuf> {
+        // Get base directory
+        let mut base_dir = input
+            .directory
             .as_ref()
             .map_or_else(|| self.get_default_download_directory(), PathBuf::from);
 
-        // Add category subdirectory if provided
-        if let Some(category) = &input.category {
-            if self.categorization_service.is_enabled() {
+        // Add category subdirectory if provided, or inferred from metadata
+        if self.categorization_service.is_enabled() {
+            let category = match &input.category {
                 // Sanitize the category to ensure it's filesystem safe
-                let sanitized_category = self.categorization_service.sanitize_category(category);
+                Some(category) => Some(self.categorization_service.sanitize_category(category)),
+                None if self.categorization_service.auto_categorize_enabled() => {
+                    metadata.and_then(|m| self.categorization_service.infer_category(m))
+                }
+                None => None,
+            };
 
+            if let Some(category) = category {
                 // Resolve any conflicts with existing directories/files
                 let final_category = self
                     .categorization_service
-                    .resolve_category_conflict(&base_dir, &sanitized_category);
+                    .resolve_category_conflict(&base_dir, &category);
 
                 base_dir = base_dir.join(final_category);
                 info!("Using category subdirectory: {:?}", base_dir);
@@ -1223,10 +2058,11 @@ impl DownloadTool {
         }
 
         // Determine filename
-        let filename = input.filename.as_ref().map_or_else(
-            || Self::generate_filename(metadata, download_url),
-            Clone::clone,
-        );
+        let filename = match (&input.filename, &self.config.downloads.filename_template) {
+            (Some(filename), _) => filename.clone(),
+            (None, Some(template)) => Self::render_filename_template(template, metadata, source),
+            (None, None) => Self::generate_filename(metadata, download_url),
+        };
 
         Ok(base_dir.join(filename))
     }
@@ -1236,32 +2072,78 @@ impl DownloadTool {
         self.config.downloads.directory.clone()
     }
 
+    /// Replace characters that are unsafe in filenames with `_` and join
+    /// words with `_`
+    fn sanitize_filename_component(value: &str) -> String {
+        value
+            .chars()
+            .map(|c| {
+                if c.is_alphanumeric() || c == ' ' || c == '-' {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect::<String>()
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join("_")
+    }
+
+    /// Filename from `downloads.filename_template`. Each placeholder value is
+    /// sanitized; unknown values become `unknown`.
+    fn render_filename_template(
+        template: &str,
+        metadata: Option<&PaperMetadata>,
+        source: Option<&str>,
+    ) -> String {
+        let title = metadata
+            .and_then(|m| m.title.as_deref())
+            .map(|title| title.chars().take(50).collect::<String>());
+        // Family name of the first author, in either "First Last" or
+        // "Last, First" form
+        let author = metadata
+            .and_then(|m| m.authors.first())
+            .and_then(|name| match name.split_once(',') {
+                Some((family, _)) => Some(family.trim()),
+                None => name.split_whitespace().last(),
+            })
+            .map(str::to_string);
+        let year = metadata.and_then(|m| m.year).map(|y| y.to_string());
+        let doi = metadata
+            .map(|m| m.doi.clone())
+            .filter(|doi| !doi.is_empty());
+
+        let mut filename = template.trim().to_string();
+        #[allow(clippy::literal_string_with_formatting_args)] // Template placeholders
+        for (placeholder, value) in [
+            ("{title}", title),
+            ("{author}", author),
+            ("{year}", year),
+            ("{doi}", doi),
+            ("{source}", source.map(str::to_string)),
+        ] {
+            let value = value
+                .map(|v| Self::sanitize_filename_component(&v))
+                .filter(|v| !v.is_empty())
+                .unwrap_or_else(|| "unknown".to_string());
+            filename = filename.replace(placeholder, &value);
+        }
+
+        if !filename.to_ascii_lowercase().ends_with(".pdf") {
+            filename.push_str(".pdf");
+        }
+        filename
+    }
+
     /// Generate filename from metadata or URL
     fn generate_filename(metadata: Option<&PaperMetadata>, download_url: &str) -> String {
         if let Some(meta) = metadata {
             if let Some(title) = &meta.title {
                 // Sanitize title for filename
-                let sanitized = title
-                    .chars()
-                    .map(|c| {
-                        if c.is_alphanumeric() || c == ' ' || c == '-' {
-                            c
-                        } else {
-                            '_'
-                        }
-                    })
-                    .collect::<String>()
-                    .split_whitespace()
-                    .collect::<Vec<_>>()
-                    .join("_");
-
-                let truncated = if sanitized.len() > 50 {
-                    sanitized[..50].to_string()
-                } else {
-                    sanitized
-                };
+                let sanitized = Self::sanitize_filename_component(title);
 
-                return format!("{truncated}.pdf");
+                return format!("{}.pdf", truncate_chars(&sanitized, 50));
             }
         }
 
@@ -1286,15 +2168,279 @@ impl DownloadTool {
         )
     }
 
+    /// Sanitized filename from a `Content-Disposition` header. An RFC 5987
+    /// `filename*` takes precedence over `filename`; the result always has a
+    /// `.pdf` extension.
+    fn content_disposition_filename(headers: &reqwest::header::HeaderMap) -> Option<String> {
+        let header = headers
+            .get(reqwest::header::CONTENT_DISPOSITION)?
+            .to_str()
+            .ok()?;
+
+        // Split parameters on `;` outside quoted strings
+        let mut params = Vec::new();
+        let mut current = String::new();
+        let mut quoted = false;
+        for c in header.chars() {
+            match c {
+                '"' => {
+                    quoted = !quoted;
+                    current.push(c);
+                }
+                ';' if !quoted => params.push(std::mem::take(&mut current)),
+                _ => current.push(c),
+            }
+        }
+        params.push(current);
+
+        let mut plain = None;
+        let mut extended = None;
+        for param in &params {
+            let Some((key, value)) = param.split_once('=') else {
+                continue;
+            };
+            let value = value.trim();
+            match key.trim().to_ascii_lowercase().as_str() {
+                "filename" => {
+                    plain = Some(
+                        value
+                            .strip_prefix('"')
+                            .and_then(|v| v.strip_suffix('"'))
+                            .unwrap_or(value)
+                            .to_string(),
+                    );
+                }
+                "filename*" => {
+                    // charset'language'percent-encoded-value
+                    extended = value
+                        .splitn(3, '\'')
+                        .nth(2)
+                        .and_then(|encoded| urlencoding::decode(encoded).ok())
+                        .map(|decoded| decoded.into_owned());
+                }
+                _ => {}
+            }
+        }
+
+        let name = extended.or(plain)?;
+        // Never let the server pick a directory
+        let name = name.rsplit(['/', '\\']).next().unwrap_or_default();
+        let stem = Path::new(name).file_stem()?.to_str()?;
+        let stem: String = Self::sanitize_filename_component(stem)
+            .chars()
+            .take(100)
+            .collect();
+        if stem.is_empty() || stem.chars().all(|c| c == '_') {
+            return None;
+        }
+        Some(format!("{stem}.pdf"))
+    }
+
+    /// Download from each candidate URL in turn. With `downloads.html_failover`,
+    /// a URL that serves HTML is abandoned for the next candidate; with
+    /// `downloads.url_failover`, so is one whose download fails. The last
+    /// candidate's error is returned when none succeeds. `more_to_come` says
+    /// the caller can find further URLs, so even the last one is abandoned
+    /// when it serves HTML.
+    #[allow(clippy::too_many_arguments)]
+    async fn execute_download_with_failover(
+        &self,
+        download_id: String,
+        candidates: Vec<DownloadCandidate>,
+        file_path: PathBuf,
+        metadata: Option<PaperMetadata>,
+        verify_integrity: bool,
+        name_from_server: bool,
+        more_to_come: bool,
+    ) -> Result<DownloadResult> {
+        let attempts = candidates.len();
+        // Only a file this download created may be discarded between
+        // attempts; anything else is a partial download to resume
+        let existed = file_path.exists();
+        let mut html_error = None;
+        for (attempt, candidate) in candidates.into_iter().enumerate() {
+            let is_last = attempt + 1 == attempts;
+            let abort_on_html = self.config.downloads.html_failover && (!is_last || more_to_come);
+            match self
+                .execute_download(
+                    download_id.clone(),
+                    candidate.url.clone(),
+                    &candidate.headers,
+                    file_path.clone(),
+                    metadata.clone(),
+                    verify_integrity,
+                    abort_on_html,
+                    name_from_server,
+                )
+                .await
+            {
+                Ok(mut result) => {
+                    if attempt > 0 {
+                        info!(
+                            "Downloaded from candidate {}/{} ({:?}): {}",
+                            attempt + 1,
+                            attempts,
+                            candidate.source,
+                            candidate.url
+                        );
+                    }
+                    result.source_provider = candidate.source;
+                    result.download_url = Some(candidate.url);
+                    return Ok(result);
+                }
+                Err(crate::Error::Parse { context, message })
+                    if context == HTML_INTERSTITIAL_CONTEXT =>
+                {
+                    warn!(
+                        "{} served HTML instead of a PDF ({}), trying candidate {}/{}",
+                        candidate.url,
+                        message,
+                        attempt + 2,
+                        attempts
+                    );
+                    html_error = Some(crate::Error::Parse { context, message });
+                }
+                Err(e)
+                    if self.config.downloads.url_failover
+                        && !is_last
+                        && Self::is_source_failure(&e) =>
+                {
+                    warn!(
+                        "Download from {} failed ({}), trying candidate {}/{}",
+                        candidate.url,
+                        e,
+                        attempt + 2,
+                        attempts
+                    );
+                    if !existed && file_path.exists() {
+                        if let Err(e) = tokio::fs::remove_file(&file_path).await {
+                            warn!("Failed to remove partial download {:?}: {}", file_path, e);
+                        }
+                    }
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        Err(html_error.unwrap_or_else(|| crate::Error::InvalidInput {
+            field: "download_url".to_string(),
+            reason: "No download URL was found for this paper".to_string(),
+        }))
+    }
+
+    /// Whether another URL for the same paper might succeed where a download
+    /// failed with `error`
+    fn worth_another_url(&self, error: &crate::Error) -> bool {
+        match error {
+            crate::Error::Parse { context, .. } if context == HTML_INTERSTITIAL_CONTEXT => true,
+            error => self.config.downloads.url_failover && Self::is_source_failure(error),
+        }
+    }
+
+    /// PDF URL and metadata of a DOI from the highest-priority healthy
+    /// DOI-capable provider, or `None` when it has no PDF for the paper
+    async fn resolve_doi_fast_path(
+        &self,
+        input: &DownloadInput,
+    ) -> Option<(Vec<DownloadCandidate>, Option<PaperMetadata>)> {
+        let doi = input.doi.as_deref()?;
+        let (source, paper) = match self.client.get_by_doi_from_top_provider(doi).await {
+            Ok(found) => found?,
+            Err(e) => {
+                debug!("DOI fast path lookup failed for {}: {}", doi, e);
+                return None;
+            }
+        };
+        let Some(url) = paper.pdf_url.clone().filter(|url| !url.is_empty()) else {
+            debug!(
+                "{} has no PDF URL for {}, running the full search",
+                source, doi
+            );
+            return None;
+        };
+
+        info!(
+            "Found PDF URL for {} from {} without a full search",
+            doi, source
+        );
+        let candidate = DownloadCandidate {
+            url,
+            source: Some(source),
+            headers: HashMap::new(),
+        };
+        Some((vec![candidate], Some(paper)))
+    }
+
+    /// Run the full resolution after the fast path's download failed with
+    /// `error`, and download from the URLs it finds that weren't `tried`.
+    /// `error` is returned when there are none.
+    #[allow(clippy::too_many_arguments)]
+    async fn download_after_fast_path_failure(
+        &self,
+        input: &DownloadInput,
+        error: crate::Error,
+        tried: &[String],
+        download_id: String,
+        file_path: PathBuf,
+        metadata: Option<PaperMetadata>,
+        name_from_server: bool,
+    ) -> Result<DownloadResult> {
+        warn!(
+            "Download from the top DOI provider failed ({}), falling back to the full search",
+            error
+        );
+        let (candidates, full_metadata) = match self.resolve_download_source(input).await {
+            Ok(resolved) => resolved,
+            Err(e) => {
+                debug!("Full resolution after the DOI fast path failed: {}", e);
+                return Err(error);
+            }
+        };
+        let candidates: Vec<DownloadCandidate> = candidates
+            .into_iter()
+            .filter(|candidate| !tried.contains(&candidate.url))
+            .collect();
+        if candidates.is_empty() {
+            return Err(error);
+        }
+
+        self.execute_download_with_failover(
+            download_id,
+            candidates,
+            file_path,
+            full_metadata.or(metadata),
+            self.verify_integrity(input.verify_integrity),
+            name_from_server,
+            false,
+        )
+        .await
+    }
+
+    /// Whether a failed download is down to the URL it came from, so another
+    /// provider's URL may fare better. Local problems (bad input, disk space,
+    /// file system errors) would fail the same way for every URL.
+    const fn is_source_failure(error: &crate::Error) -> bool {
+        !matches!(
+            error,
+            crate::Error::InvalidInput { .. }
+                | crate::Error::ResourceExhausted { .. }
+                | crate::Error::Io(_)
+        )
+    }
+
     /// Execute the actual download
     #[allow(clippy::too_many_lines)] // Complex download logic needs to be in one place
+    #[allow(clippy::too_many_arguments)]
     async fn execute_download(
         &self,
         download_id: String,
         download_url: String,
-        file_path: PathBuf,
+        headers: &HashMap<String, String>,
+        mut file_path: PathBuf,
         metadata: Option<PaperMetadata>,
         verify_integrity: bool,
+        abort_on_html: bool,
+        name_from_server: bool,
     ) -> Result<DownloadResult> {
         debug!("🚀 Execute download called with ID: {}", download_id);
         debug!("🔗 Download URL validation");
@@ -1335,7 +2481,7 @@ impl DownloadTool {
 
         // Make HEAD request to get file size
         debug!("🔍 Making HEAD request to determine file size");
-        let total_size = match self.get_content_length(&download_url).await {
+        let total_size = match self.get_content_length(&download_url, headers).await {
             Ok(size) => {
                 debug!(
                     "✅ Content-Length determined: {} bytes ({:.2} MB)",
@@ -1351,6 +2497,7 @@ impl DownloadTool {
             }
         };
         progress.total_size = total_size;
+        progress.indeterminate = total_size.is_none();
 
         // Check for partial download (resume capability) but don't create file yet
         debug!("🔄 Checking for resume capability");
@@ -1373,7 +2520,10 @@ impl DownloadTool {
 
         // Make download request first to verify it's valid
         debug!("🌐 Making download request with start_byte: {}", start_byte);
-        let response = match self.make_download_request(&download_url, start_byte).await {
+        let response = match self
+            .make_download_request(&download_url, headers, start_byte)
+            .await
+        {
             Ok(resp) => {
                 debug!("✅ Download request successful");
                 debug!("📊 Response status: {}", resp.status());
@@ -1393,6 +2543,30 @@ impl DownloadTool {
             }
         };
 
+        let content_type = response
+            .headers()
+            .get("content-type")
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+
+        // Prefer the server's filename for a fresh download, unless that
+        // file already exists
+        if name_from_server && start_byte == 0 {
+            if let Some(filename) = Self::content_disposition_filename(response.headers()) {
+                let server_path = file_path.with_file_name(&filename);
+                if server_path.exists() {
+                    debug!(
+                        "📄 Content-Disposition name {} already exists, keeping {:?}",
+                        filename, file_path
+                    );
+                } else {
+                    info!("Using filename from Content-Disposition: {}", filename);
+                    file_path = server_path;
+                    progress.file_path.clone_from(&file_path);
+                }
+            }
+        }
+
         // Update total size from response if not known
         debug!("🔄 Updating total size from response headers");
         let old_total = progress.total_size;
@@ -1410,7 +2584,13 @@ impl DownloadTool {
         // Download with progress tracking - this will create the file only if download succeeds
         debug!("📥 Starting progress-tracked download");
         match self
-            .download_with_progress(response, &file_path, start_byte, &mut progress)
+            .download_with_progress(
+                response,
+                &file_path,
+                start_byte,
+                &mut progress,
+                abort_on_html,
+            )
             .await
         {
             Ok(()) => {
@@ -1426,6 +2606,11 @@ impl DownloadTool {
             }
         };
 
+        // Reject (or quarantine) files that are clearly not PDFs
+        debug!("🔍 Validating downloaded content");
+        self.check_downloaded_content(&file_path, content_type.as_deref())
+            .await?;
+
         // Finalize download
         debug!("🏁 Finalizing download process");
         match self
@@ -1468,6 +2653,7 @@ impl DownloadTool {
             total_size: None,
             downloaded: 0,
             percentage: 0.0,
+            indeterminate: true,
             speed_bps: 0,
             eta_seconds: None,
             status: DownloadStatus::InProgress,
@@ -1480,18 +2666,37 @@ impl DownloadTool {
     async fn make_download_request(
         &self,
         download_url: &str,
+        headers: &HashMap<String, String>,
         start_byte: u64,
     ) -> Result<reqwest::Response> {
-        let response = if start_byte > 0 {
-            self.http_client
-                .get(download_url)
-                .header("Range", format!("bytes={start_byte}-"))
-                .send()
-                .await
-        } else {
-            self.http_client.get(download_url).send().await
+        if !headers.is_empty() {
+            debug!(
+                "🔐 Sending caller-supplied headers: {:?}",
+                redacted_headers(headers)
+            );
         }
-        .map_err(|e| crate::Error::Service(format!("Download request failed: {e}")))?;
+        // Retry DNS and connection failures; anything else is reported as is
+        let response = retry_with_policy(
+            || async {
+                let mut request = self.http_client.get(download_url);
+                for (name, value) in headers {
+                    request = request.header(name.as_str(), value.as_str());
+                }
+                if start_byte > 0 {
+                    request = request.header("Range", format!("bytes={start_byte}-"));
+                }
+                request
+                    .send()
+                    .await
+                    .map_err(|e| match crate::Error::from(e) {
+                        e if e.is_connection_failure() => e,
+                        e => crate::Error::Service(format!("Download request failed: {e}")),
+                    })
+            },
+            &RetryPolicy::connection_failures(),
+            "download request",
+        )
+        .await?;
 
         if !response.status().is_success() && response.status().as_u16() != 206 {
             return Err(crate::Error::SciHub {
@@ -1514,37 +2719,81 @@ impl DownloadTool {
                 if let Ok(length_str) = content_length.to_str() {
                     if let Ok(length) = length_str.parse::<u64>() {
                         progress.total_size = Some(length + start_byte);
+                        progress.indeterminate = false;
                     }
                 }
             }
         }
     }
 
-    /// Download with progress tracking
+    /// Download with progress tracking. With `abort_on_html`, stops before
+    /// writing anything if the response turns out to be an HTML page.
     async fn download_with_progress(
         &self,
         response: reqwest::Response,
         file_path: &PathBuf,
         start_byte: u64,
         progress: &mut DownloadProgress,
+        abort_on_html: bool,
     ) -> Result<()> {
         debug!("📥 Starting progressive download");
         debug!("📁 Target file: {:?}", file_path);
         debug!("🔄 Resume from byte: {}", start_byte);
         debug!("📊 Expected total size: {:?}", progress.total_size);
 
+        if abort_on_html {
+            if let Some(content_type) = response
+                .headers()
+                .get("content-type")
+                .and_then(|value| value.to_str().ok())
+                .filter(|value| value.to_ascii_lowercase().starts_with("text/html"))
+            {
+                return Err(crate::Error::Parse {
+                    context: HTML_INTERSTITIAL_CONTEXT.to_string(),
+                    message: format!("content-type '{content_type}'"),
+                });
+            }
+        }
+
         let mut stream = response.bytes_stream();
         let mut last_progress_time = SystemTime::now();
         let mut bytes_at_last_time = progress.downloaded;
         let mut chunk_count = 0u64;
         let mut total_bytes_received = 0u64;
+        let max_bytes_per_sec = self.config.downloads.max_bytes_per_sec;
+        let stream_started = Instant::now();
+        let stall_timeout = Duration::from_secs(self.config.downloads.stall_timeout_secs);
 
         // Only create/open file when we start receiving data
         let mut file_created = false;
         let mut file: Option<File> = None;
         debug!("🔍 File will be created on first successful chunk");
 
-        while let Some(chunk_result) = stream.next().await {
+        loop {
+            let next = if stall_timeout.is_zero() {
+                stream.next().await
+            } else if let Ok(next) = tokio::time::timeout(stall_timeout, stream.next()).await {
+                next
+            } else {
+                warn!(
+                    "Download stalled: no data for {:?} after {} bytes, removing {:?}",
+                    stall_timeout, progress.downloaded, file_path
+                );
+                drop(file.take());
+                if file_created {
+                    if let Err(e) = tokio::fs::remove_file(file_path).await {
+                        warn!("Failed to remove partial download {:?}: {}", file_path, e);
+                    }
+                }
+                return Err(crate::Error::NetworkTimeout {
+                    timeout: stall_timeout,
+                    message: format!("download stalled after {} bytes", progress.downloaded),
+                });
+            };
+            let Some(chunk_result) = next else {
+                break;
+            };
+
             let chunk = match chunk_result {
                 Ok(chunk) => {
                     chunk_count += 1;
@@ -1566,6 +2815,14 @@ impl DownloadTool {
                 }
             };
 
+            // Some servers label interstitial pages as PDFs, so sniff the body too
+            if abort_on_html && !file_created && looks_like_html(&chunk) {
+                return Err(crate::Error::Parse {
+                    context: HTML_INTERSTITIAL_CONTEXT.to_string(),
+                    message: "response body is an HTML document".to_string(),
+                });
+            }
+
             // Create file on first successful chunk
             if file_created {
                 // File already created, write subsequent chunks
@@ -1627,6 +2884,17 @@ impl DownloadTool {
 
             progress.downloaded += chunk.len() as u64;
 
+            // Pace chunk consumption so the average rate stays under the cap;
+            // the stream is not polled while sleeping, so the server backs off too
+            if max_bytes_per_sec > 0 {
+                #[allow(clippy::cast_precision_loss)]
+                let target =
+                    Duration::from_secs_f64(total_bytes_received as f64 / max_bytes_per_sec as f64);
+                if let Some(ahead) = target.checked_sub(stream_started.elapsed()) {
+                    tokio::time::sleep(ahead).await;
+                }
+            }
+
             // Update progress every 500ms
             let now = SystemTime::now();
             if now
@@ -1694,11 +2962,14 @@ impl DownloadTool {
             progress.speed_bps = speed as u64;
         }
 
-        if let Some(total) = progress.total_size {
+        // Without a total size there is no meaningful percentage or ETA, so
+        // only the byte count is reported
+        progress.indeterminate = progress.total_size.is_none();
+        if let Some(total) = progress.total_size.filter(|total| *total > 0) {
             #[allow(clippy::cast_precision_loss)]
             let percentage = (progress.downloaded as f64 / total as f64) * 100.0;
-            progress.percentage = percentage;
-            let remaining_bytes = total - progress.downloaded;
+            progress.percentage = percentage.min(100.0);
+            let remaining_bytes = total.saturating_sub(progress.downloaded);
             if progress.speed_bps > 0 {
                 progress.eta_seconds = Some(remaining_bytes / progress.speed_bps);
             }
@@ -1719,11 +2990,7 @@ impl DownloadTool {
 
         let duration = start_time.elapsed().unwrap_or(Duration::ZERO);
         let file_size = tokio::fs::metadata(file_path).await?.len();
-        let average_speed = if duration.as_secs() > 0 {
-            file_size / duration.as_secs()
-        } else {
-            0
-        };
+        let average_speed = average_speed(file_size, duration);
 
         // Verify integrity if requested
         let sha256_hash = if verify_integrity {
@@ -1733,7 +3000,11 @@ impl DownloadTool {
         };
 
         progress.status = DownloadStatus::Completed;
+        progress.total_size = Some(file_size);
+        progress.downloaded = file_size;
         progress.percentage = 100.0;
+        progress.indeterminate = false;
+        progress.eta_seconds = Some(0);
         self.send_progress(progress);
 
         info!("Download completed: {:?} ({} bytes)", file_path, file_size);
@@ -1743,71 +3014,455 @@ impl DownloadTool {
             status: DownloadStatus::Completed,
             file_path: Some(file_path.to_path_buf()),
             file_size: Some(file_size),
+            integrity_verified: sha256_hash.is_some(),
             sha256_hash,
             duration_seconds: duration.as_secs_f64(),
             average_speed,
             metadata,
             error: None,
+            post_download_hook: None,
+            source_provider: None,
+            download_url: None,
         })
     }
 
-    /// Get content length from URL
-    async fn get_content_length(&self, url: &str) -> Result<u64> {
-        let response = self
-            .http_client
-            .head(url)
-            .send()
-            .await
-            .map_err(|e| crate::Error::Service(format!("HEAD request failed: {e}")))?;
+    /// Save `metadata` as JSON next to `file_path`, with the same name and a
+    /// `.json` extension, noting the provider the PDF came from. Downloads
+    /// without metadata (direct URLs) get no sidecar, and a failed write is
+    /// logged rather than failing the download.
+    async fn write_metadata_sidecar(
+        file_path: &Path,
+        metadata: Option<&PaperMetadata>,
+        source_provider: Option<&str>,
+    ) {
+        let Some(metadata) = metadata else {
+            debug!("No metadata for {:?}, skipping sidecar", file_path);
+            return;
+        };
 
-        if let Some(content_length) = response.headers().get("content-length") {
-            let length_str = content_length.to_str().map_err(|e| crate::Error::Parse {
-                context: "content-length header".to_string(),
-                message: format!("Invalid content-length header: {e}"),
-            })?;
-            length_str.parse::<u64>().map_err(|e| crate::Error::Parse {
-                context: "content-length value".to_string(),
-                message: format!("Cannot parse content-length: {e}"),
-            })
-        } else {
-            Err(crate::Error::Parse {
-                context: "HTTP headers".to_string(),
-                message: "No content-length header found".to_string(),
-            })
+        let sidecar_path = file_path.with_extension("json");
+        let sidecar = MetadataSidecar {
+            metadata,
+            source_provider,
+        };
+        let written = match serde_json::to_vec_pretty(&sidecar) {
+            Ok(json) => tokio::fs::write(&sidecar_path, json)
+                .await
+                .map_err(|e| e.to_string()),
+            Err(e) => Err(e.to_string()),
+        };
+        match written {
+            Ok(()) => debug!("Wrote metadata sidecar {:?}", sidecar_path),
+            Err(e) => warn!("Failed to write metadata sidecar {:?}: {}", sidecar_path, e),
         }
     }
 
-    /// Calculate SHA256 hash of a file
-    async fn calculate_file_hash(&self, file_path: &Path) -> Result<String> {
-        let mut file = File::open(file_path).await.map_err(crate::Error::Io)?;
-
-        let mut hasher = Sha256::new();
-        let mut buffer = [0u8; 8192];
-
-        loop {
-            let bytes_read = file.read(&mut buffer).await.map_err(crate::Error::Io)?;
-
-            if bytes_read == 0 {
-                break;
+    /// Append a line for the completed download to the manifest in
+    /// `downloads.directory`, under `downloads.manifest_lock` so downloads
+    /// finishing together add whole lines one after the other. As with the
+    /// sidecar, a failed write is logged rather than failing the download.
+    async fn append_to_manifest(&self, result: &DownloadResult, requested_doi: Option<&str>) {
+        let manifest_path = self.config.downloads.directory.join(MANIFEST_FILE);
+        let entry = ManifestEntry {
+            download_id: result.download_id.clone(),
+            completed_at: chrono::Utc::now(),
+            doi: result
+                .metadata
+                .as_ref()
+                .map(|m| m.doi.as_str())
+                .filter(|doi| !doi.is_empty())
+                .or(requested_doi)
+                .map(str::to_string),
+            title: result.metadata.as_ref().and_then(|m| m.title.clone()),
+            file_path: result.file_path.clone(),
+            file_size: result.file_size,
+            sha256_hash: result.sha256_hash.clone(),
+            source_provider: result.source_provider.clone(),
+            download_url: result.download_url.clone(),
+        };
+        let mut line = match serde_json::to_string(&entry) {
+            Ok(line) => line,
+            Err(e) => {
+                warn!("Failed to serialize manifest entry: {}", e);
+                return;
             }
+        };
+        line.push('\n');
 
-            hasher.update(&buffer[..bytes_read]);
+        let lock_file = self.config.downloads.manifest_lock == ManifestLock::File;
+        let _guard = self.manifest_lock.lock().await;
+        let written = match tokio::fs::create_dir_all(&self.config.downloads.directory).await {
+            Ok(()) => {
+                let path = manifest_path.clone();
+                tokio::task::spawn_blocking(move || append_manifest_line(&path, &line, lock_file))
+                    .await
+                    .unwrap_or_else(|e| Err(std::io::Error::new(std::io::ErrorKind::Other, e)))
+            }
+            Err(e) => Err(e),
+        };
+        match written {
+            Ok(()) => debug!("Recorded {} in {:?}", entry.download_id, manifest_path),
+            Err(e) => warn!("Failed to append to manifest {:?}: {}", manifest_path, e),
         }
-
-        Ok(format!("{:x}", hasher.finalize()))
     }
 
-    /// Send progress update
-    fn send_progress(&self, progress: DownloadProgress) {
-        if let Some(sender) = &self.progress_sender {
-            let _ = sender.send(progress);
+    /// Run `downloads.post_download_hook` for a finished download. The
+    /// command is executed directly (no shell), so substituted values can
+    /// never be interpreted as shell syntax. Hook failures are reported in
+    /// the output rather than failing the download.
+    async fn run_post_download_hook(
+        &self,
+        file_path: &Path,
+        doi: &str,
+    ) -> Option<PostDownloadHookOutput> {
+        let template = self.config.downloads.post_download_hook.as_deref()?;
+        #[allow(clippy::literal_string_with_formatting_args)] // Command placeholders
+        let command = command_argv(
+            template,
+            &[("{path}", &file_path.to_string_lossy()), ("{doi}", doi)],
+        );
+        let (program, args) = command.split_first()?;
+
+        debug!("Running post-download hook: {:?}", command);
+        let child = tokio::process::Command::new(program)
+            .args(args)
+            .stdin(std::process::Stdio::null())
+            .kill_on_drop(true)
+            .output();
+
+        let mut output = PostDownloadHookOutput {
+            command: command.clone(),
+            exit_code: None,
+            stdout: String::new(),
+            stderr: String::new(),
+            error: None,
+        };
+        match tokio::time::timeout(POST_DOWNLOAD_HOOK_TIMEOUT, child).await {
+            Ok(Ok(result)) => {
+                output.exit_code = result.status.code();
+                output.stdout = String::from_utf8_lossy(&result.stdout).into_owned();
+                output.stderr = String::from_utf8_lossy(&result.stderr).into_owned();
+                if !result.status.success() {
+                    warn!(
+                        "Post-download hook {:?} exited with {}",
+                        command, result.status
+                    );
+                }
+            }
+            Ok(Err(e)) => {
+                warn!("Failed to run post-download hook {:?}: {}", command, e);
+                output.error = Some(e.to_string());
+            }
+            Err(_) => {
+                warn!(
+                    "Post-download hook {:?} timed out after {:?}",
+                    command, POST_DOWNLOAD_HOOK_TIMEOUT
+                );
+                output.error = Some(format!(
+                    "Timed out after {}s",
+                    POST_DOWNLOAD_HOOK_TIMEOUT.as_secs()
+                ));
+            }
         }
+        Some(output)
     }
 
-    /// Get active downloads
-    pub async fn get_active_downloads(&self) -> Vec<DownloadProgress> {
-        let downloads = self.active_downloads.read().await;
-        downloads
+    /// Linearize and compress a downloaded PDF with `downloads.pdf_optimizer`.
+    /// The optimized copy replaces the file only when the tool succeeds and
+    /// its output still parses as a PDF. Returns whether it was replaced.
+    async fn optimize_pdf(&self, file_path: &Path) -> bool {
+        let file_name = file_path.file_name().map_or_else(
+            || "download.pdf".to_string(),
+            |n| n.to_string_lossy().to_string(),
+        );
+        let optimized = file_path.with_file_name(format!(".{file_name}.optimized"));
+        #[allow(clippy::literal_string_with_formatting_args)] // Command placeholders
+        let command = command_argv(
+            &self.config.downloads.pdf_optimizer,
+            &[
+                ("{input}", &file_path.to_string_lossy()),
+                ("{output}", &optimized.to_string_lossy()),
+            ],
+        );
+        let Some((program, args)) = command.split_first() else {
+            return false;
+        };
+
+        debug!("Optimizing PDF: {:?}", command);
+        let child = tokio::process::Command::new(program)
+            .args(args)
+            .stdin(std::process::Stdio::null())
+            .kill_on_drop(true)
+            .output();
+        let replaced = match tokio::time::timeout(PDF_OPTIMIZER_TIMEOUT, child).await {
+            Ok(Ok(output)) if output.status.success() => {
+                let loaded = tokio::task::spawn_blocking({
+                    let optimized = optimized.clone();
+                    move || lopdf::Document::load(&optimized)
+                })
+                .await;
+                match loaded {
+                    Ok(Ok(_)) => match tokio::fs::rename(&optimized, file_path).await {
+                        Ok(()) => {
+                            info!("Optimized {:?} with {}", file_path, program);
+                            true
+                        }
+                        Err(e) => {
+                            warn!(
+                                "Failed to replace {:?} with its optimized copy: {}",
+                                file_path, e
+                            );
+                            false
+                        }
+                    },
+                    Ok(Err(e)) => {
+                        warn!(
+                            "{} produced a PDF that doesn't parse ({}), keeping {:?} as downloaded",
+                            program, e, file_path
+                        );
+                        false
+                    }
+                    Err(e) => {
+                        warn!(
+                            "Failed to check the optimized copy of {:?}: {}",
+                            file_path, e
+                        );
+                        false
+                    }
+                }
+            }
+            Ok(Ok(output)) => {
+                warn!(
+                    "PDF optimizer {:?} exited with {}: {}",
+                    command,
+                    output.status,
+                    String::from_utf8_lossy(&output.stderr).trim()
+                );
+                false
+            }
+            Ok(Err(e)) if e.kind() == std::io::ErrorKind::NotFound => {
+                warn!(
+                    "PDF optimizer {} is not installed, keeping {:?} as downloaded",
+                    program, file_path
+                );
+                false
+            }
+            Ok(Err(e)) => {
+                warn!("Failed to run PDF optimizer {:?}: {}", command, e);
+                false
+            }
+            Err(_) => {
+                warn!(
+                    "PDF optimizer {:?} timed out after {:?}",
+                    command, PDF_OPTIMIZER_TIMEOUT
+                );
+                false
+            }
+        };
+
+        if !replaced && optimized.exists() {
+            if let Err(e) = tokio::fs::remove_file(&optimized).await {
+                debug!("Failed to remove {:?}: {}", optimized, e);
+            }
+        }
+        replaced
+    }
+
+    /// Check that a finished download looks like a PDF, returning the reason if it does not
+    async fn content_validation_failure(
+        &self,
+        file_path: &Path,
+        content_type: Option<&str>,
+    ) -> Result<Option<String>> {
+        let file_size = tokio::fs::metadata(file_path).await?.len();
+        if file_size == 0 {
+            return Ok(Some("File is empty (0 bytes)".to_string()));
+        }
+
+        let max_bytes = self
+            .config
+            .downloads
+            .max_file_size_mb
+            .saturating_mul(1024 * 1024);
+        if file_size > max_bytes {
+            return Ok(Some(format!(
+                "File size {file_size} bytes exceeds the {} MB limit",
+                self.config.downloads.max_file_size_mb
+            )));
+        }
+
+        if let Some(content_type) = content_type {
+            if content_type.to_ascii_lowercase().starts_with("text/html") {
+                return Ok(Some(format!(
+                    "Server returned '{content_type}' instead of a PDF"
+                )));
+            }
+        }
+
+        let mut header = [0u8; 5];
+        if file_size < header.len() as u64 {
+            return Ok(Some(format!(
+                "File is too small ({file_size} bytes) to be a PDF"
+            )));
+        }
+        let mut file = File::open(file_path).await?;
+        file.read_exact(&mut header).await?;
+        if &header != b"%PDF-" {
+            return Ok(Some(format!(
+                "Missing %PDF header - got {:?}",
+                String::from_utf8_lossy(&header)
+            )));
+        }
+
+        Ok(None)
+    }
+
+    /// Check that a PDF on disk is complete: it must pass the download
+    /// content checks and end with a `%%EOF` marker, which truncated files lack
+    async fn pdf_corruption(&self, file_path: &Path) -> Result<Option<String>> {
+        if let Some(reason) = self.content_validation_failure(file_path, None).await? {
+            return Ok(Some(reason));
+        }
+
+        let mut file = File::open(file_path).await?;
+        let file_size = file.metadata().await?.len();
+        let tail_start = file_size.saturating_sub(PDF_EOF_SEARCH_BYTES);
+        file.seek(std::io::SeekFrom::Start(tail_start)).await?;
+        let mut tail = Vec::new();
+        file.read_to_end(&mut tail).await?;
+        if !tail.windows(5).any(|window| window == b"%%EOF") {
+            return Ok(Some(format!(
+                "Missing %%EOF marker in the last {PDF_EOF_SEARCH_BYTES} bytes - file looks truncated"
+            )));
+        }
+
+        Ok(None)
+    }
+
+    /// Remove a download that failed content validation, or move it to
+    /// `downloads.quarantine_dir` when one is configured
+    async fn check_downloaded_content(
+        &self,
+        file_path: &Path,
+        content_type: Option<&str>,
+    ) -> Result<()> {
+        let Some(reason) = self
+            .content_validation_failure(file_path, content_type)
+            .await?
+        else {
+            return Ok(());
+        };
+
+        warn!(
+            "Downloaded file failed content validation: {:?} - {}",
+            file_path, reason
+        );
+
+        let message = if let Some(quarantine_dir) = &self.config.downloads.quarantine_dir {
+            let quarantined = Self::quarantine_file(file_path, quarantine_dir, &reason).await?;
+            format!("{reason} (file quarantined at {})", quarantined.display())
+        } else {
+            tokio::fs::remove_file(file_path).await?;
+            reason
+        };
+
+        Err(crate::Error::Parse {
+            context: "downloaded content".to_string(),
+            message,
+        })
+    }
+
+    /// Move a file into the quarantine directory next to a `.reason.txt` sidecar
+    async fn quarantine_file(
+        file_path: &Path,
+        quarantine_dir: &Path,
+        reason: &str,
+    ) -> Result<PathBuf> {
+        Self::validate_directory_security(quarantine_dir).await?;
+        tokio::fs::create_dir_all(quarantine_dir).await?;
+
+        let file_name = file_path.file_name().map_or_else(
+            || "download.pdf".to_string(),
+            |n| n.to_string_lossy().to_string(),
+        );
+        let mut target = quarantine_dir.join(&file_name);
+        if target.exists() {
+            target = quarantine_dir.join(format!("{}_{file_name}", chrono::Utc::now().timestamp()));
+        }
+
+        // rename fails across filesystems, so fall back to copy + delete
+        if tokio::fs::rename(file_path, &target).await.is_err() {
+            tokio::fs::copy(file_path, &target).await?;
+            tokio::fs::remove_file(file_path).await?;
+        }
+
+        let mut reason_path = target.clone().into_os_string();
+        reason_path.push(".reason.txt");
+        tokio::fs::write(
+            &reason_path,
+            format!(
+                "original_path: {}\nreason: {reason}\nquarantined_at: {}\n",
+                file_path.display(),
+                chrono::Utc::now().to_rfc3339()
+            ),
+        )
+        .await?;
+
+        info!(
+            "Quarantined invalid download: {:?} -> {:?}",
+            file_path, target
+        );
+        Ok(target)
+    }
+
+    /// Get content length from URL
+    async fn get_content_length(
+        &self,
+        url: &str,
+        headers: &HashMap<String, String>,
+    ) -> Result<u64> {
+        let mut request = self.http_client.head(url);
+        for (name, value) in headers {
+            request = request.header(name.as_str(), value.as_str());
+        }
+        let response = request
+            .send()
+            .await
+            .map_err(|e| crate::Error::Service(format!("HEAD request failed: {e}")))?;
+
+        if let Some(content_length) = response.headers().get("content-length") {
+            let length_str = content_length.to_str().map_err(|e| crate::Error::Parse {
+                context: "content-length header".to_string(),
+                message: format!("Invalid content-length header: {e}"),
+            })?;
+            length_str.parse::<u64>().map_err(|e| crate::Error::Parse {
+                context: "content-length value".to_string(),
+                message: format!("Cannot parse content-length: {e}"),
+            })
+        } else {
+            Err(crate::Error::Parse {
+                context: "HTTP headers".to_string(),
+                message: "No content-length header found".to_string(),
+            })
+        }
+    }
+
+    /// Calculate SHA256 hash of a file
+    async fn calculate_file_hash(&self, file_path: &Path) -> Result<String> {
+        FileHasher::hash_file(file_path).await
+    }
+
+    /// Send progress update
+    fn send_progress(&self, progress: DownloadProgress) {
+        if let Some(sender) = &self.progress_sender {
+            let _ = sender.send(progress);
+        }
+    }
+
+    /// Get active downloads
+    pub async fn get_active_downloads(&self) -> Vec<DownloadProgress> {
+        let downloads = self.active_downloads.read().await;
+        downloads
             .values()
             .map(|state| state.progress.clone())
             .collect()
@@ -1835,6 +3490,52 @@ impl DownloadTool {
         queue.clone()
     }
 
+    /// Number of downloads waiting or running
+    pub async fn queue_len(&self) -> usize {
+        self.download_queue.read().await.len()
+    }
+
+    /// Add a download to the queue, returning its queue id. Fails with
+    /// `ResourceExhausted` once `downloads.max_queue_len` items are queued.
+    pub async fn enqueue(&self, input: DownloadInput) -> Result<String> {
+        let mut queue = self.download_queue.write().await;
+        let limit = self.config.downloads.max_queue_len;
+        if queue.len() >= limit {
+            warn!("Download queue full ({}/{})", queue.len(), limit);
+            return Err(crate::Error::ResourceExhausted {
+                resource: "download queue full".to_string(),
+                current: queue.len() as u64,
+                limit: limit as u64,
+            });
+        }
+
+        let id = uuid::Uuid::new_v4().to_string();
+        queue.push(DownloadQueueItem {
+            id: id.clone(),
+            input,
+            created_at: SystemTime::now(),
+            started_at: None,
+        });
+        debug!("Queued download {} ({}/{})", id, queue.len(), limit);
+        Ok(id)
+    }
+
+    /// Record that a queued download has started
+    async fn mark_queue_item_started(&self, id: &str) {
+        let mut queue = self.download_queue.write().await;
+        if let Some(item) = queue.iter_mut().find(|item| item.id == id) {
+            item.started_at = Some(SystemTime::now());
+        }
+    }
+
+    /// Remove a finished download from the queue
+    async fn dequeue(&self, id: &str) {
+        self.download_queue
+            .write()
+            .await
+            .retain(|item| item.id != id);
+    }
+
     /// Clear completed downloads from tracking
     pub async fn clear_completed(&self) {
         let mut downloads = self.active_downloads.write().await;
@@ -2138,6 +3839,8 @@ impl Command for DownloadTool {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::client::providers::traits::tests::{paper, MockProvider};
+    use crate::client::providers::SearchType as ProviderSearchType;
     use crate::config::{Config, ResearchSourceConfig};
     // use std::path::PathBuf; // Already imported at top level
     use tempfile::TempDir;
@@ -2150,6 +3853,8 @@ mod tests {
             timeout_secs: 30,
             provider_timeout_secs: 60,
             max_retries: 2,
+            arxiv_version: "latest".to_string(),
+            author_format: crate::config::AuthorNameFormat::FirstLast,
         };
         Arc::new(config)
     }
@@ -2171,7 +3876,10 @@ mod tests {
             directory: None,
             category: None,
             overwrite: false,
-            verify_integrity: true,
+            verify_integrity: Some(true),
+            title: None,
+            write_sidecar: None,
+            headers: None,
         };
         assert!(DownloadTool::validate_input(&empty_input).is_err());
 
@@ -2183,7 +3891,10 @@ mod tests {
             directory: None,
             category: None,
             overwrite: false,
-            verify_integrity: true,
+            verify_integrity: Some(true),
+            title: None,
+            write_sidecar: None,
+            headers: None,
         };
         assert!(DownloadTool::validate_input(&both_input).is_err());
 
@@ -2195,7 +3906,10 @@ mod tests {
             directory: None,
             category: None,
             overwrite: false,
-            verify_integrity: true,
+            verify_integrity: Some(true),
+            title: None,
+            write_sidecar: None,
+            headers: None,
         };
         assert!(DownloadTool::validate_input(&valid_doi).is_ok());
 
@@ -2207,7 +3921,10 @@ mod tests {
             directory: None,
             category: None,
             overwrite: false,
-            verify_integrity: true,
+            verify_integrity: Some(true),
+            title: None,
+            write_sidecar: None,
+            headers: None,
         };
         assert!(DownloadTool::validate_input(&valid_url).is_ok());
 
@@ -2219,9 +3936,23 @@ mod tests {
             directory: None,
             category: None,
             overwrite: false,
-            verify_integrity: true,
+            verify_integrity: Some(true),
+            title: None,
+            write_sidecar: None,
+            headers: None,
         };
         assert!(DownloadTool::validate_input(&invalid_filename).is_err());
+
+        // Headers are never sent to URLs resolved from a DOI
+        let doi_with_headers = DownloadInput {
+            filename: None,
+            headers: Some(HashMap::from([(
+                "Cookie".to_string(),
+                "session=1".to_string(),
+            )])),
+            ..invalid_filename
+        };
+        assert!(DownloadTool::validate_input(&doi_with_headers).is_err());
     }
 
     #[test]
@@ -2248,6 +3979,21 @@ mod tests {
         assert!(filename_fallback.ends_with(".pdf"));
     }
 
+    #[test]
+    fn test_generate_filename_multibyte_title_truncates_on_char_boundary() {
+        let mut metadata = PaperMetadata::new("10.1038/test".to_string());
+        // Byte 50 falls inside a two-byte 'é'
+        metadata.title = Some(format!("{}é{}", "a".repeat(49), "é".repeat(30)));
+        let filename =
+            DownloadTool::generate_filename(Some(&metadata), "https://example.com/test.pdf");
+        assert_eq!(filename, format!("{}é.pdf", "a".repeat(49)));
+
+        metadata.title = Some("深層学習による蛋白質構造予測の高精度化に関する研究".repeat(3));
+        let filename =
+            DownloadTool::generate_filename(Some(&metadata), "https://example.com/test.pdf");
+        assert_eq!(filename.trim_end_matches(".pdf").chars().count(), 50);
+    }
+
     #[tokio::test]
     async fn test_default_download_directory() {
         let tool = create_test_download_tool().unwrap();
@@ -2268,12 +4014,20 @@ mod tests {
             directory: Some(temp_dir.path().to_string_lossy().to_string()),
             category: None,
             overwrite: false,
-            verify_integrity: true,
+            verify_integrity: Some(true),
+            title: None,
+            write_sidecar: None,
+            headers: None,
         };
 
         let metadata = Some(PaperMetadata::new("10.1038/test".to_string()));
         let file_path = tool
-            .determine_file_path(&input, metadata.as_ref(), "https://example.com/test.pdf")
+            .determine_file_path(
+                &input,
+                metadata.as_ref(),
+                None,
+                "https://example.com/test.pdf",
+            )
             .await
             .unwrap();
 
@@ -2281,6 +4035,208 @@ mod tests {
         assert!(file_path.starts_with(temp_dir.path()));
     }
 
+    #[tokio::test]
+    async fn test_file_path_auto_categorized_when_no_category() {
+        let mut config = (*create_test_config()).clone();
+        config.categorization.auto_categorize = true;
+        let meta_config = crate::client::MetaSearchConfig::from_config(&config);
+        let client = Arc::new(MetaSearchClient::new(config.clone(), meta_config).unwrap());
+        let tool = DownloadTool::new(client, Arc::new(config)).unwrap();
+        let temp_dir = TempDir::new().unwrap();
+
+        let input = DownloadInput {
+            doi: Some("10.1103/PhysRevLett.116.061102".to_string()),
+            url: None,
+            filename: Some("gw150914.pdf".to_string()),
+            directory: Some(temp_dir.path().to_string_lossy().to_string()),
+            category: None,
+            overwrite: false,
+            verify_integrity: Some(false),
+            title: None,
+            write_sidecar: None,
+            headers: None,
+        };
+
+        let mut metadata = PaperMetadata::new("10.1103/PhysRevLett.116.061102".to_string());
+        metadata.title =
+            Some("Observation of Gravitational Waves from a Binary Black Hole Merger".to_string());
+        metadata.journal = Some("Physical Review Letters".to_string());
+
+        let file_path = tool
+            .determine_file_path(
+                &input,
+                Some(&metadata),
+                None,
+                "https://example.com/gw150914.pdf",
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            file_path,
+            temp_dir.path().join("Physics").join("gw150914.pdf")
+        );
+
+        // An explicit category still wins
+        let input = DownloadInput {
+            category: Some("gravitational_waves".to_string()),
+            ..input
+        };
+        let file_path = tool
+            .determine_file_path(
+                &input,
+                Some(&metadata),
+                None,
+                "https://example.com/gw150914.pdf",
+            )
+            .await
+            .unwrap();
+        assert!(file_path.starts_with(temp_dir.path().join("gravitational_waves")));
+    }
+
+    /// Provider that knows a paper's metadata but has no PDF for it
+    fn metadata_only_provider() -> MockProvider {
+        MockProvider::new("metadata_only")
+            .with_search_types(vec![ProviderSearchType::Doi])
+            .answering(|query, _| {
+                let mut paper = paper(&query.query, "A Paywalled Study");
+                paper.authors = vec!["Jane Doe".to_string()];
+                paper.year = Some(2021);
+                Ok(vec![paper])
+            })
+    }
+
+    #[tokio::test]
+    async fn test_download_paper_paywalled_error_carries_metadata() {
+        let download_dir = TempDir::new().unwrap();
+        let mut config = (*create_test_config()).clone();
+        config.downloads.directory = download_dir.path().to_path_buf();
+        let client = Arc::new(MetaSearchClient::with_providers(
+            vec![Arc::new(metadata_only_provider())],
+            crate::client::MetaSearchConfig::default(),
+        ));
+        let tool = DownloadTool::new(client, Arc::new(config)).unwrap();
+
+        let input = DownloadInput {
+            doi: Some("10.1000/paywalled".to_string()),
+            url: None,
+            filename: None,
+            directory: None,
+            category: None,
+            overwrite: false,
+            verify_integrity: Some(false),
+            title: None,
+            write_sidecar: None,
+            headers: None,
+        };
+
+        match tool.download_paper(input).await {
+            Err(crate::Error::PaywalledPaper {
+                metadata,
+                providers_checked,
+            }) => {
+                assert_eq!(metadata.doi, "10.1000/paywalled");
+                assert_eq!(metadata.title.as_deref(), Some("A Paywalled Study"));
+                assert_eq!(providers_checked, 1);
+
+                let message = DownloadTool::paywalled_message(&metadata, providers_checked);
+                assert!(message.contains("A Paywalled Study"));
+            }
+            other => panic!("expected PaywalledPaper, got {other:?}"),
+        }
+    }
+
+    /// Tool whose only provider finds a DOI without a PDF (unless
+    /// `doi_pdf_url` is set) but has a hosted copy under the paper's title,
+    /// and the count of title searches that provider answers
+    fn create_title_fallback_tool(
+        doi_pdf_url: Option<&str>,
+        title_search_fallback: bool,
+    ) -> (DownloadTool, Arc<std::sync::atomic::AtomicUsize>) {
+        let title_searches = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let doi_pdf_url = doi_pdf_url.map(str::to_string);
+        let provider = MockProvider::new("title_hosted")
+            .with_search_types(vec![ProviderSearchType::Doi, ProviderSearchType::Title])
+            .answering({
+                let title_searches = Arc::clone(&title_searches);
+                move |query, _| {
+                    if query.search_type != ProviderSearchType::Title {
+                        let mut paper = paper(&query.query, "Hosted Preprint");
+                        paper.pdf_url.clone_from(&doi_pdf_url);
+                        return Ok(vec![paper]);
+                    }
+                    title_searches.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                    let mut unrelated = paper("10.1000/other", "Hosted Preprint: A Survey");
+                    unrelated.pdf_url = Some("https://example.org/unrelated.pdf".to_string());
+                    let mut hosted = paper("10.5281/zenodo.1", "Hosted preprint");
+                    hosted.pdf_url = Some("https://example.org/hosted.pdf".to_string());
+                    Ok(vec![unrelated, hosted])
+                }
+            });
+        let client = Arc::new(MetaSearchClient::with_providers(
+            vec![Arc::new(provider)],
+            crate::client::MetaSearchConfig::default(),
+        ));
+        let mut config = (*create_test_config()).clone();
+        config.downloads.title_search_fallback = title_search_fallback;
+        let tool = DownloadTool::new(client, Arc::new(config)).unwrap();
+        (tool, title_searches)
+    }
+
+    #[tokio::test]
+    async fn test_resolve_download_source_title_fallback_only_after_doi_fails() {
+        let input = DownloadInput {
+            doi: Some("10.1000/hosted".to_string()),
+            url: None,
+            filename: None,
+            directory: None,
+            category: None,
+            overwrite: false,
+            verify_integrity: Some(false),
+            title: None,
+            write_sidecar: None,
+            headers: None,
+        };
+        let title_searches = |count: &std::sync::atomic::AtomicUsize| {
+            count.load(std::sync::atomic::Ordering::SeqCst)
+        };
+
+        // The DOI path finds a PDF, so no title search is made
+        let (tool, provider) =
+            create_title_fallback_tool(Some("https://example.org/by-doi.pdf"), true);
+        let (candidates, _) = tool.resolve_download_source(&input).await.unwrap();
+        assert_eq!(
+            candidates,
+            vec![DownloadCandidate {
+                url: "https://example.org/by-doi.pdf".to_string(),
+                source: Some("title_hosted".to_string()),
+                headers: HashMap::new(),
+            }]
+        );
+        assert_eq!(title_searches(&provider), 0);
+
+        // The DOI path fails and the fallback is disabled
+        let (tool, provider) = create_title_fallback_tool(None, false);
+        assert!(matches!(
+            tool.resolve_download_source(&input).await,
+            Err(crate::Error::PaywalledPaper { .. })
+        ));
+        assert_eq!(title_searches(&provider), 0);
+
+        // The DOI path fails and the fallback finds the same-titled copy
+        let (tool, provider) = create_title_fallback_tool(None, true);
+        let (candidates, metadata) = tool.resolve_download_source(&input).await.unwrap();
+        assert_eq!(
+            candidates,
+            vec![DownloadCandidate {
+                url: "https://example.org/hosted.pdf".to_string(),
+                source: Some("title_hosted".to_string()),
+                headers: HashMap::new(),
+            }]
+        );
+        assert_eq!(metadata.unwrap().doi, "10.1000/hosted");
+        assert_eq!(title_searches(&provider), 1);
+    }
+
     #[tokio::test]
     async fn test_file_hash_calculation() {
         let tool = create_test_download_tool().unwrap();
@@ -2310,6 +4266,56 @@ mod tests {
         assert!(queue.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_enqueue_past_max_queue_len_rejected() {
+        let mut config = (*create_test_config()).clone();
+        config.downloads.max_queue_len = 2;
+        let client = Arc::new(MetaSearchClient::with_providers(
+            Vec::new(),
+            crate::client::MetaSearchConfig::default(),
+        ));
+        let tool = DownloadTool::new(client, Arc::new(config)).unwrap();
+        let input = DownloadInput {
+            doi: Some("10.1000/queued".to_string()),
+            url: None,
+            filename: None,
+            directory: None,
+            category: None,
+            overwrite: false,
+            verify_integrity: Some(false),
+            title: None,
+            write_sidecar: None,
+            headers: None,
+        };
+
+        tool.enqueue(input.clone()).await.unwrap();
+        tool.enqueue(input.clone()).await.unwrap();
+        assert_eq!(tool.queue_len().await, 2);
+
+        let err = tool.enqueue(input).await.unwrap_err();
+        assert!(matches!(
+            err,
+            crate::Error::ResourceExhausted {
+                current: 2,
+                limit: 2,
+                ..
+            }
+        ));
+        assert!(err.to_string().contains("queue full"));
+        assert_eq!(tool.queue_len().await, 2);
+    }
+
+    #[tokio::test]
+    async fn test_download_tool_ipv4_only_client() {
+        let mut config = (*create_test_config()).clone();
+        config.http.ip_family = crate::config::IpFamily::V4;
+        let meta_config = crate::client::MetaSearchConfig::from_config(&config);
+        let client = Arc::new(MetaSearchClient::new(config.clone(), meta_config).unwrap());
+
+        let tool = DownloadTool::new(client, Arc::new(config));
+        assert!(tool.is_ok(), "IPv4-only download client should build");
+    }
+
     #[tokio::test]
     async fn test_custom_download_directory() {
         // Create config with custom download directory
@@ -2331,12 +4337,20 @@ mod tests {
             directory: None, // No override, should use config default
             category: None,
             overwrite: false,
-            verify_integrity: false,
+            verify_integrity: Some(false),
+            title: None,
+            write_sidecar: None,
+            headers: None,
         };
 
         let metadata = PaperMetadata::new("10.1038/test".to_string());
         let file_path = tool
-            .determine_file_path(&input, Some(&metadata), "https://example.com/test.pdf")
+            .determine_file_path(
+                &input,
+                Some(&metadata),
+                None,
+                "https://example.com/test.pdf",
+            )
             .await
             .unwrap();
 
@@ -2345,16 +4359,1341 @@ mod tests {
         assert!(file_path.ends_with("test.pdf"));
     }
 
-    // ===========================
-    // Batch Download Tests
-    // ===========================
+    fn create_html_download_tool(
+        download_dir: &Path,
+        quarantine_dir: Option<&Path>,
+    ) -> DownloadTool {
+        let mut config = (*create_test_config()).clone();
+        config.downloads.directory = download_dir.to_path_buf();
+        config.downloads.quarantine_dir = quarantine_dir.map(Path::to_path_buf);
+        let meta_config = crate::client::MetaSearchConfig::from_config(&config);
+        let client = Arc::new(MetaSearchClient::new(config.clone(), meta_config).unwrap());
+        DownloadTool::new(client, Arc::new(config)).unwrap()
+    }
+
+    async fn mount_html_paper(mock_server: &wiremock::MockServer) {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, ResponseTemplate};
+
+        Mock::given(method("GET"))
+            .and(path("/paper.pdf"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(
+                "<html><body>Access denied</body></html>",
+                "text/html; charset=utf-8",
+            ))
+            .mount(mock_server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_download_with_progress_throttled_takes_minimum_duration() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, ResponseTemplate};
+
+        let mock_server = wiremock::MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/paper.pdf"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(vec![b'x'; 20_000]))
+            .mount(&mock_server)
+            .await;
+
+        let download_dir = TempDir::new().unwrap();
+        let mut config = (*create_test_config()).clone();
+        config.downloads.directory = download_dir.path().to_path_buf();
+        config.downloads.max_bytes_per_sec = 10_000;
+        let meta_config = crate::client::MetaSearchConfig::from_config(&config);
+        let client = Arc::new(MetaSearchClient::new(config.clone(), meta_config).unwrap());
+        let tool = DownloadTool::new(client, Arc::new(config)).unwrap();
+
+        let file_path = download_dir.path().join("paper.pdf");
+        let mut progress = DownloadProgress {
+            download_id: "throttle-test".to_string(),
+            source: "test".to_string(),
+            total_size: Some(20_000),
+            downloaded: 0,
+            percentage: 0.0,
+            indeterminate: false,
+            speed_bps: 0,
+            eta_seconds: None,
+            status: DownloadStatus::InProgress,
+            file_path: file_path.clone(),
+            error: None,
+        };
+
+        let started = Instant::now();
+        let response = reqwest::get(format!("{}/paper.pdf", mock_server.uri()))
+            .await
+            .unwrap();
+        tool.download_with_progress(response, &file_path, 0, &mut progress, false)
+            .await
+            .unwrap();
+
+        // 20 KB at 10 KB/s
+        assert!(started.elapsed() >= Duration::from_secs(2));
+        assert_eq!(std::fs::metadata(&file_path).unwrap().len(), 20_000);
+        assert!(
+            progress.speed_bps <= 12_000,
+            "reported speed {} should reflect the cap",
+            progress.speed_bps
+        );
+    }
+
+    #[tokio::test]
+    async fn test_download_with_progress_stalled_stream_times_out() {
+        use tokio::io::AsyncWriteExt;
+
+        // Sends the headers and a first chunk of a larger body, then goes quiet
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut request = [0u8; 1024];
+            let _ = socket.read(&mut request).await;
+            socket
+                .write_all(b"HTTP/1.1 200 OK\r\nContent-Type: application/pdf\r\nContent-Length: 10000\r\n\r\n")
+                .await
+                .unwrap();
+            socket.write_all(&[b'x'; 1000]).await.unwrap();
+            socket.flush().await.unwrap();
+            tokio::time::sleep(Duration::from_secs(30)).await;
+        });
+
+        let download_dir = TempDir::new().unwrap();
+        let mut config = (*create_test_config()).clone();
+        config.downloads.directory = download_dir.path().to_path_buf();
+        config.downloads.stall_timeout_secs = 1;
+        let meta_config = crate::client::MetaSearchConfig::from_config(&config);
+        let client = Arc::new(MetaSearchClient::new(config.clone(), meta_config).unwrap());
+        let tool = DownloadTool::new(client, Arc::new(config)).unwrap();
+
+        let file_path = download_dir.path().join("paper.pdf");
+        let mut progress = DownloadTool::create_initial_progress(
+            "stall-test".to_string(),
+            "test".to_string(),
+            file_path.clone(),
+        );
+        let started = Instant::now();
+        let response = reqwest::get(format!("http://{address}/paper.pdf"))
+            .await
+            .unwrap();
+        let result = tool
+            .download_with_progress(response, &file_path, 0, &mut progress, false)
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(crate::Error::NetworkTimeout { timeout, .. }) if timeout == Duration::from_secs(1)
+        ));
+        assert!(started.elapsed() < Duration::from_secs(10));
+        assert_eq!(progress.downloaded, 1000);
+        assert!(!file_path.exists(), "partial file should be removed");
+        server.abort();
+    }
+
+    #[tokio::test]
+    async fn test_download_with_progress_unknown_size_reports_indeterminate() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, ResponseTemplate};
+
+        let mock_server = wiremock::MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/paper.pdf"))
+            .respond_with(ResponseTemplate::new(200).set_body_bytes(vec![b'x'; 12_000]))
+            .mount(&mock_server)
+            .await;
+
+        let download_dir = TempDir::new().unwrap();
+        let mut config = (*create_test_config()).clone();
+        config.downloads.directory = download_dir.path().to_path_buf();
+        config.downloads.max_bytes_per_sec = 10_000;
+        let meta_config = crate::client::MetaSearchConfig::from_config(&config);
+        let client = Arc::new(MetaSearchClient::new(config.clone(), meta_config).unwrap());
+        let mut tool = DownloadTool::new(client, Arc::new(config)).unwrap();
+        let (sender, mut receiver) = mpsc::unbounded_channel();
+        tool.progress_sender = Some(sender);
+
+        let file_path = download_dir.path().join("paper.pdf");
+        let mut progress = DownloadTool::create_initial_progress(
+            "unknown-size-test".to_string(),
+            "test".to_string(),
+            file_path.clone(),
+        );
+        let response = reqwest::get(format!("{}/paper.pdf", mock_server.uri()))
+            .await
+            .unwrap();
+        tool.download_with_progress(response, &file_path, 0, &mut progress, false)
+            .await
+            .unwrap();
+
+        let mut events = Vec::new();
+        while let Ok(event) = receiver.try_recv() {
+            events.push(event);
+        }
+        assert!(!events.is_empty());
+        for event in &events {
+            assert!(event.indeterminate);
+            assert!(event.total_size.is_none());
+            assert!(event.percentage.abs() < f64::EPSILON);
+            assert!(event.eta_seconds.is_none());
+        }
+        assert!(events
+            .windows(2)
+            .all(|w| w[0].downloaded <= w[1].downloaded));
+        assert_eq!(events.last().unwrap().downloaded, 12_000);
+    }
+
+    #[tokio::test]
+    async fn test_download_paper_invalid_content_quarantined() {
+        let mock_server = wiremock::MockServer::start().await;
+        mount_html_paper(&mock_server).await;
+
+        let download_dir = TempDir::new().unwrap();
+        let quarantine_dir = TempDir::new().unwrap();
+        let tool = create_html_download_tool(download_dir.path(), Some(quarantine_dir.path()));
+
+        let input = DownloadInput {
+            doi: None,
+            url: Some(format!("{}/paper.pdf", mock_server.uri())),
+            filename: None,
+            directory: None,
+            category: None,
+            overwrite: false,
+            verify_integrity: Some(false),
+            title: None,
+            write_sidecar: None,
+            headers: None,
+        };
+        let result = tool.download_paper(input).await;
+
+        assert!(matches!(result, Err(crate::Error::Parse { .. })));
+        assert!(!download_dir.path().join("paper.pdf").exists());
+        assert!(quarantine_dir.path().join("paper.pdf").exists());
+        let reason =
+            std::fs::read_to_string(quarantine_dir.path().join("paper.pdf.reason.txt")).unwrap();
+        assert!(reason.contains("text/html"));
+    }
+
+    #[tokio::test]
+    async fn test_download_paper_invalid_content_deleted_without_quarantine() {
+        let mock_server = wiremock::MockServer::start().await;
+        mount_html_paper(&mock_server).await;
+
+        let download_dir = TempDir::new().unwrap();
+        let tool = create_html_download_tool(download_dir.path(), None);
+
+        let input = DownloadInput {
+            doi: None,
+            url: Some(format!("{}/paper.pdf", mock_server.uri())),
+            filename: None,
+            directory: None,
+            category: None,
+            overwrite: false,
+            verify_integrity: Some(false),
+            title: None,
+            write_sidecar: None,
+            headers: None,
+        };
+        let result = tool.download_paper(input).await;
+
+        assert!(result.is_err());
+        assert!(!download_dir.path().join("paper.pdf").exists());
+    }
+
+    // ===========================
+    // Batch Download Tests
+    // ===========================
+
+    /// Provider that lists the same paper once per mirror URL
+    fn mirror_provider(pdf_urls: Vec<String>) -> MockProvider {
+        MockProvider::new("mirrors")
+            .with_search_types(vec![ProviderSearchType::Doi])
+            .answering(move |query, _| {
+                Ok(pdf_urls
+                    .iter()
+                    .map(|url| {
+                        let mut paper = paper(&query.query, "Mirrored Paper");
+                        paper.pdf_url = Some(url.clone());
+                        paper
+                    })
+                    .collect())
+            })
+    }
+
+    #[tokio::test]
+    async fn test_download_paper_html_failover_uses_next_candidate() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, ResponseTemplate};
+
+        let pdf_body = b"%PDF-1.4\n% mirrored copy\n%%EOF\n".to_vec();
+        let mock_server = wiremock::MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/interstitial.pdf"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string("<html><body>Checking your browser...</body></html>")
+                    .insert_header("content-type", "text/html; charset=utf-8"),
+            )
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/mirror.pdf"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_bytes(pdf_body.clone())
+                    .insert_header("content-type", "application/pdf"),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let download = |html_failover: bool| {
+            let pdf_urls = vec![
+                format!("{}/interstitial.pdf", mock_server.uri()),
+                format!("{}/mirror.pdf", mock_server.uri()),
+            ];
+            async move {
+                let download_dir = TempDir::new().unwrap();
+                let mut config = (*create_test_config()).clone();
+                config.downloads.directory = download_dir.path().to_path_buf();
+                config.downloads.html_failover = html_failover;
+                config.downloads.url_failover = false;
+                let client = Arc::new(MetaSearchClient::with_providers(
+                    vec![Arc::new(mirror_provider(pdf_urls))],
+                    crate::client::MetaSearchConfig::default(),
+                ));
+                let tool = DownloadTool::new(client, Arc::new(config)).unwrap();
+
+                let result = tool
+                    .download_paper(DownloadInput {
+                        doi: Some("10.1000/mirrored".to_string()),
+                        url: None,
+                        filename: Some("mirrored.pdf".to_string()),
+                        directory: None,
+                        category: None,
+                        overwrite: false,
+                        verify_integrity: Some(false),
+                        title: None,
+                        write_sidecar: None,
+                        headers: None,
+                    })
+                    .await;
+                (result, download_dir)
+            }
+        };
+
+        let (result, _download_dir) = download(true).await;
+        let result = result.unwrap();
+        assert!(matches!(result.status, DownloadStatus::Completed));
+        assert_eq!(std::fs::read(result.file_path.unwrap()).unwrap(), pdf_body);
+
+        // Without failover the interstitial is downloaded and rejected
+        let (result, _download_dir) = download(false).await;
+        assert!(matches!(result, Err(crate::Error::Parse { .. })));
+    }
+
+    /// Mirror offering a single PDF URL under its own name and priority
+    fn ranked_mirror_provider(name: &'static str, priority: u8, pdf_url: String) -> MockProvider {
+        MockProvider::new(name)
+            .with_priority(priority)
+            .with_search_types(vec![ProviderSearchType::Doi])
+            .answering(move |query, _| {
+                let mut paper = paper(&query.query, "Mirrored Paper");
+                paper.pdf_url = Some(pdf_url.clone());
+                Ok(vec![paper])
+            })
+    }
+
+    #[tokio::test]
+    async fn test_download_paper_url_failover_after_forbidden_uses_next_provider() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, ResponseTemplate};
+
+        let pdf_body = b"%PDF-1.4\n% open copy\n%%EOF\n".to_vec();
+        let mock_server = wiremock::MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/forbidden.pdf"))
+            .respond_with(ResponseTemplate::new(403))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/open.pdf"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_bytes(pdf_body.clone())
+                    .insert_header("content-type", "application/pdf"),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let download_dir = TempDir::new().unwrap();
+        let mut config = (*create_test_config()).clone();
+        config.downloads.directory = download_dir.path().to_path_buf();
+        // Listed lowest priority first, to show candidates follow priority
+        let client = Arc::new(MetaSearchClient::with_providers(
+            vec![
+                Arc::new(ranked_mirror_provider(
+                    "repository",
+                    50,
+                    format!("{}/open.pdf", mock_server.uri()),
+                )),
+                Arc::new(ranked_mirror_provider(
+                    "publisher",
+                    200,
+                    format!("{}/forbidden.pdf", mock_server.uri()),
+                )),
+            ],
+            crate::client::MetaSearchConfig::default(),
+        ));
+        let tool = DownloadTool::new(client, Arc::new(config)).unwrap();
+
+        let result = tool
+            .download_paper(DownloadInput {
+                doi: Some("10.1000/mirrored".to_string()),
+                url: None,
+                filename: Some("mirrored.pdf".to_string()),
+                directory: None,
+                category: None,
+                overwrite: false,
+                verify_integrity: Some(false),
+                title: None,
+                write_sidecar: None,
+                headers: None,
+            })
+            .await
+            .unwrap();
+
+        assert!(matches!(result.status, DownloadStatus::Completed));
+        assert_eq!(result.source_provider.as_deref(), Some("repository"));
+        assert_eq!(
+            result.download_url,
+            Some(format!("{}/open.pdf", mock_server.uri()))
+        );
+        assert_eq!(std::fs::read(result.file_path.unwrap()).unwrap(), pdf_body);
+    }
+
+    /// DOI provider with a fixed health check outcome; its `searches` and
+    /// `lookups` count searches and DOI lookups
+    fn counting_doi_provider(
+        name: &'static str,
+        priority: u8,
+        healthy: bool,
+        pdf_url: String,
+    ) -> MockProvider {
+        MockProvider::new(name)
+            .with_priority(priority)
+            .with_health(healthy)
+            .with_search_types(vec![ProviderSearchType::Doi])
+            .answering(move |query, _| {
+                let mut paper = paper(&query.query, "Fast Path Paper");
+                paper.pdf_url = Some(pdf_url.clone());
+                Ok(vec![paper])
+            })
+    }
+
+    #[tokio::test]
+    async fn test_download_paper_doi_fast_path_skips_full_search() {
+        use std::sync::atomic::Ordering;
+        use wiremock::matchers::{method, path_regex};
+        use wiremock::{Mock, ResponseTemplate};
+
+        let mock_server = wiremock::MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path_regex(r"^/\w+\.pdf$"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_bytes(b"%PDF-1.4\n% fast path\n%%EOF\n".to_vec())
+                    .insert_header("content-type", "application/pdf"),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let download = |primary_healthy: bool| {
+            let primary = Arc::new(counting_doi_provider(
+                "primary",
+                200,
+                primary_healthy,
+                format!("{}/primary.pdf", mock_server.uri()),
+            ));
+            let secondary = Arc::new(counting_doi_provider(
+                "secondary",
+                100,
+                true,
+                format!("{}/secondary.pdf", mock_server.uri()),
+            ));
+            async move {
+                let download_dir = TempDir::new().unwrap();
+                let mut config = (*create_test_config()).clone();
+                config.downloads.directory = download_dir.path().to_path_buf();
+                let client = Arc::new(MetaSearchClient::with_providers(
+                    vec![primary.clone(), secondary.clone()],
+                    crate::client::MetaSearchConfig::default(),
+                ));
+                client.warm_up().await;
+                let tool = DownloadTool::new(client, Arc::new(config)).unwrap();
+
+                let result = tool
+                    .download_paper(DownloadInput {
+                        doi: Some("10.1000/fast".to_string()),
+                        url: None,
+                        filename: Some("fast.pdf".to_string()),
+                        directory: None,
+                        category: None,
+                        overwrite: false,
+                        verify_integrity: Some(false),
+                        title: None,
+                        write_sidecar: None,
+                        headers: None,
+                    })
+                    .await
+                    .unwrap();
+                assert!(matches!(result.status, DownloadStatus::Completed));
+                (result, primary, secondary)
+            }
+        };
+
+        // The top provider's PDF is downloaded without searching anyone
+        let (result, primary, secondary) = download(true).await;
+        assert_eq!(result.source_provider.as_deref(), Some("primary"));
+        assert_eq!(primary.lookups.load(Ordering::SeqCst), 1);
+        assert_eq!(primary.searches.load(Ordering::SeqCst), 0);
+        assert_eq!(secondary.lookups.load(Ordering::SeqCst), 0);
+        assert_eq!(secondary.searches.load(Ordering::SeqCst), 0);
+
+        // An unhealthy top provider is passed over for the next one
+        let (result, primary, secondary) = download(false).await;
+        assert_eq!(result.source_provider.as_deref(), Some("secondary"));
+        assert_eq!(primary.lookups.load(Ordering::SeqCst), 0);
+        assert_eq!(secondary.lookups.load(Ordering::SeqCst), 1);
+        assert_eq!(primary.searches.load(Ordering::SeqCst), 0);
+        assert_eq!(secondary.searches.load(Ordering::SeqCst), 0);
+    }
+
+    /// A PDF lopdf can parse, with `pages` blank pages
+    fn write_parseable_pdf(path: &Path, pages: i64) {
+        use lopdf::{dictionary, Document, Object};
+
+        let mut doc = Document::with_version("1.5");
+        let pages_id = doc.new_object_id();
+        let kids: Vec<Object> = (0..pages)
+            .map(|_| {
+                doc.add_object(dictionary! {
+                    "Type" => "Page",
+                    "Parent" => pages_id,
+                    "MediaBox" => vec![0.into(), 0.into(), 595.into(), 842.into()],
+                })
+                .into()
+            })
+            .collect();
+        doc.objects.insert(
+            pages_id,
+            Object::Dictionary(dictionary! {
+                "Type" => "Pages",
+                "Kids" => kids,
+                "Count" => pages,
+            }),
+        );
+        let catalog_id = doc.add_object(dictionary! {
+            "Type" => "Catalog",
+            "Pages" => pages_id,
+        });
+        doc.trailer.set("Root", catalog_id);
+        doc.save(path).unwrap();
+    }
+
+    #[test]
+    fn test_command_argv_substituted_value_with_spaces_stays_one_argument() {
+        let argv = command_argv(
+            "qpdf --linearize {input} {output}",
+            &[
+                ("{input}", "/papers/My Paper.pdf"),
+                ("{output}", "/papers/My Paper.opt.pdf"),
+            ],
+        );
+        assert_eq!(
+            argv,
+            vec![
+                "qpdf",
+                "--linearize",
+                "/papers/My Paper.pdf",
+                "/papers/My Paper.opt.pdf"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_optimize_pdf_replaces_file_only_with_parseable_output() {
+        let temp_dir = TempDir::new().unwrap();
+        let paper = temp_dir.path().join("paper.pdf");
+        let optimized = temp_dir.path().join("optimized.pdf");
+        let broken = temp_dir.path().join("broken.pdf");
+        write_parseable_pdf(&paper, 1);
+        write_parseable_pdf(&optimized, 2);
+        std::fs::write(&broken, b"%PDF-1.4\nnot really a pdf\n%%EOF\n").unwrap();
+        let optimize = |pdf_optimizer: String| {
+            let mut config = (*create_test_config()).clone();
+            config.downloads.optimize_pdf = true;
+            config.downloads.pdf_optimizer = pdf_optimizer;
+            let tool = DownloadTool::new(
+                Arc::new(MetaSearchClient::with_providers(
+                    Vec::new(),
+                    crate::client::MetaSearchConfig::default(),
+                )),
+                Arc::new(config),
+            )
+            .unwrap();
+            let paper = paper.clone();
+            async move { tool.optimize_pdf(&paper).await }
+        };
+        let leftovers = || {
+            std::fs::read_dir(temp_dir.path())
+                .unwrap()
+                .filter(|entry| {
+                    entry
+                        .as_ref()
+                        .unwrap()
+                        .file_name()
+                        .to_string_lossy()
+                        .ends_with(".optimized")
+                })
+                .count()
+        };
+
+        // The tool's output parses, so it replaces the download
+        assert!(optimize(format!("cp {} {{output}}", optimized.display())).await);
+        assert_eq!(
+            std::fs::read(&paper).unwrap(),
+            std::fs::read(&optimized).unwrap()
+        );
+        assert!(lopdf::Document::load(&paper).is_ok());
+        assert_eq!(leftovers(), 0);
+
+        // Output that doesn't parse leaves the download untouched
+        let before = std::fs::read(&paper).unwrap();
+        assert!(!optimize(format!("cp {} {{output}}", broken.display())).await);
+        assert_eq!(std::fs::read(&paper).unwrap(), before);
+        assert_eq!(leftovers(), 0);
+
+        // So does a missing tool
+        assert!(!optimize("pdf-optimizer-not-installed {input} {output}".to_string()).await);
+        assert_eq!(std::fs::read(&paper).unwrap(), before);
+    }
+
+    #[tokio::test]
+    async fn test_download_paper_post_download_hook_receives_path_argument() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, ResponseTemplate};
+
+        let mock_server = wiremock::MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/hooked.pdf"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_bytes(b"%PDF-1.4\n% hooked\n%%EOF\n".to_vec())
+                    .insert_header("content-type", "application/pdf"),
+            )
+            .mount(&mock_server)
+            .await;
+
+        // A space in the directory would split the path if a shell were involved
+        let temp_dir = TempDir::new().unwrap();
+        let download_dir = temp_dir.path().join("my papers");
+        std::fs::create_dir_all(&download_dir).unwrap();
+        let mut config = (*create_test_config()).clone();
+        config.downloads.directory = download_dir.clone();
+        config.downloads.post_download_hook = Some("printf [%s|%s] {path} {doi}".to_string());
+        let client = Arc::new(MetaSearchClient::with_providers(
+            vec![Arc::new(mirror_provider(vec![format!(
+                "{}/hooked.pdf",
+                mock_server.uri()
+            )]))],
+            crate::client::MetaSearchConfig::default(),
+        ));
+        let tool = DownloadTool::new(client, Arc::new(config)).unwrap();
+
+        let result = tool
+            .download_paper(DownloadInput {
+                doi: Some("10.1000/hooked".to_string()),
+                url: None,
+                filename: Some("hooked.pdf".to_string()),
+                directory: None,
+                category: None,
+                overwrite: false,
+                verify_integrity: Some(false),
+                title: None,
+                write_sidecar: None,
+                headers: None,
+            })
+            .await
+            .unwrap();
+
+        let file_path = result.file_path.unwrap();
+        assert!(file_path.starts_with(&download_dir));
+        let hook = result.post_download_hook.unwrap();
+        assert_eq!(hook.exit_code, Some(0));
+        assert_eq!(hook.command[2], file_path.to_string_lossy());
+        assert_eq!(
+            hook.stdout,
+            format!("[{}|10.1000/hooked]", file_path.display())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_download_paper_url_headers_reach_server_and_are_redacted() {
+        use wiremock::matchers::{header, method, path};
+        use wiremock::{Mock, ResponseTemplate};
+
+        let mock_server = wiremock::MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/sso.pdf"))
+            .and(header("cookie", "session=s3cret"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_bytes(b"%PDF-1.4\n% behind sso\n%%EOF\n".to_vec())
+                    .insert_header("content-type", "application/pdf"),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let temp_dir = TempDir::new().unwrap();
+        let mut config = (*create_test_config()).clone();
+        config.downloads.directory = temp_dir.path().to_path_buf();
+        let client = Arc::new(MetaSearchClient::with_providers(
+            Vec::new(),
+            crate::client::MetaSearchConfig::default(),
+        ));
+        let tool = DownloadTool::new(client, Arc::new(config)).unwrap();
+
+        let input = DownloadInput {
+            doi: None,
+            url: Some(format!("{}/sso.pdf", mock_server.uri())),
+            filename: Some("sso.pdf".to_string()),
+            directory: None,
+            category: None,
+            overwrite: false,
+            verify_integrity: Some(false),
+            title: None,
+            write_sidecar: None,
+            headers: Some(HashMap::from([(
+                "Cookie".to_string(),
+                "session=s3cret".to_string(),
+            )])),
+        };
+        let logged = format!("{input:?}");
+        assert!(logged.contains("Cookie"));
+        assert!(!logged.contains("s3cret"));
+
+        let result = tool.download_paper(input).await.unwrap();
+        assert!(matches!(result.status, DownloadStatus::Completed));
+        assert!(temp_dir.path().join("sso.pdf").exists());
+    }
+
+    #[tokio::test]
+    async fn test_download_paper_filename_template_includes_source() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, ResponseTemplate};
+
+        let mock_server = wiremock::MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/mirrored.pdf"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_bytes(b"%PDF-1.4\n% mirrored\n%%EOF\n".to_vec())
+                    .insert_header("content-type", "application/pdf"),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let temp_dir = TempDir::new().unwrap();
+        let mut config = (*create_test_config()).clone();
+        config.downloads.directory = temp_dir.path().to_path_buf();
+        config.downloads.filename_template = Some("{title}_{source}".to_string());
+        let client = Arc::new(MetaSearchClient::with_providers(
+            vec![Arc::new(mirror_provider(vec![format!(
+                "{}/mirrored.pdf",
+                mock_server.uri()
+            )]))],
+            crate::client::MetaSearchConfig::default(),
+        ));
+        let tool = DownloadTool::new(client, Arc::new(config)).unwrap();
+
+        let result = tool
+            .download_paper(DownloadInput {
+                doi: Some("10.1000/mirrored".to_string()),
+                url: None,
+                filename: None,
+                directory: None,
+                category: None,
+                overwrite: false,
+                verify_integrity: Some(false),
+                title: None,
+                write_sidecar: None,
+                headers: None,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(result.source_provider.as_deref(), Some("mirrors"));
+        let file_path = result.file_path.unwrap();
+        assert_eq!(
+            file_path.file_name().unwrap().to_string_lossy(),
+            "Mirrored_Paper_mirrors.pdf"
+        );
+        assert!(file_path.exists());
+    }
+
+    #[tokio::test]
+    async fn test_download_paper_direct_url_uses_content_disposition_filename() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, ResponseTemplate};
+
+        let mock_server = wiremock::MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/download"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_bytes(b"%PDF-1.4\n% served\n%%EOF\n".to_vec())
+                    .insert_header("content-type", "application/pdf")
+                    .insert_header(
+                        "content-disposition",
+                        "attachment; filename=\"../Smith 2021; Review.pdf\"",
+                    ),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let temp_dir = TempDir::new().unwrap();
+        let mut config = (*create_test_config()).clone();
+        config.downloads.directory = temp_dir.path().to_path_buf();
+        let tool = DownloadTool::new(
+            Arc::new(MetaSearchClient::with_providers(
+                Vec::new(),
+                crate::client::MetaSearchConfig::default(),
+            )),
+            Arc::new(config),
+        )
+        .unwrap();
+
+        let result = tool
+            .download_paper(DownloadInput {
+                doi: None,
+                url: Some(format!("{}/download", mock_server.uri())),
+                filename: None,
+                directory: None,
+                category: None,
+                overwrite: false,
+                verify_integrity: Some(false),
+                title: None,
+                write_sidecar: None,
+                headers: None,
+            })
+            .await
+            .unwrap();
+
+        let file_path = result.file_path.unwrap();
+        assert_eq!(file_path, temp_dir.path().join("Smith_2021__Review.pdf"));
+        assert!(file_path.exists());
+    }
+
+    #[tokio::test]
+    async fn test_download_paper_sub_second_download_reports_average_speed() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, ResponseTemplate};
+
+        let mut body = b"%PDF-1.4\n".to_vec();
+        body.resize(64 * 1024, b'0');
+        body.extend_from_slice(b"\n%%EOF\n");
+        let mock_server = wiremock::MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/fast.pdf"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_bytes(body.clone())
+                    .insert_header("content-type", "application/pdf"),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let temp_dir = TempDir::new().unwrap();
+        let mut config = (*create_test_config()).clone();
+        config.downloads.directory = temp_dir.path().to_path_buf();
+        let tool = DownloadTool::new(
+            Arc::new(MetaSearchClient::with_providers(
+                Vec::new(),
+                crate::client::MetaSearchConfig::default(),
+            )),
+            Arc::new(config),
+        )
+        .unwrap();
+
+        let result = tool
+            .download_paper(DownloadInput {
+                doi: None,
+                url: Some(format!("{}/fast.pdf", mock_server.uri())),
+                filename: Some("fast.pdf".to_string()),
+                directory: None,
+                category: None,
+                overwrite: false,
+                verify_integrity: Some(false),
+                title: None,
+                write_sidecar: None,
+                headers: None,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(result.file_size, Some(body.len() as u64));
+        assert!(result.duration_seconds > 0.0 && result.duration_seconds < 1.0);
+        // Faster than a second, so more than the file's size per second
+        assert!(result.average_speed >= body.len() as u64);
+        #[allow(clippy::cast_precision_loss)]
+        let error =
+            (result.average_speed as f64 - body.len() as f64 / result.duration_seconds).abs();
+        assert!(error <= 1.0);
+    }
+
+    #[tokio::test]
+    async fn test_download_paper_verify_integrity_default_disabled_skips_hash() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, ResponseTemplate};
+
+        let mock_server = wiremock::MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/paper.pdf"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_bytes(b"%PDF-1.4\n% unverified\n%%EOF\n".to_vec())
+                    .insert_header("content-type", "application/pdf"),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let temp_dir = TempDir::new().unwrap();
+        let mut config = (*create_test_config()).clone();
+        config.downloads.directory = temp_dir.path().to_path_buf();
+        config.downloads.verify_integrity_default = false;
+        let tool = DownloadTool::new(
+            Arc::new(MetaSearchClient::with_providers(
+                Vec::new(),
+                crate::client::MetaSearchConfig::default(),
+            )),
+            Arc::new(config),
+        )
+        .unwrap();
+
+        let result = tool
+            .download_paper(DownloadInput {
+                doi: None,
+                url: Some(format!("{}/paper.pdf", mock_server.uri())),
+                filename: Some("unverified.pdf".to_string()),
+                directory: None,
+                category: None,
+                overwrite: false,
+                verify_integrity: None,
+                title: None,
+                write_sidecar: None,
+                headers: None,
+            })
+            .await
+            .unwrap();
+
+        assert!(result.file_path.unwrap().exists());
+        assert!(result.sha256_hash.is_none());
+        assert!(!result.integrity_verified);
+    }
+
+    #[tokio::test]
+    async fn test_download_papers_batch_bounded_by_download_workers() {
+        use wiremock::matchers::{method, path_regex};
+        use wiremock::{Mock, ResponseTemplate};
+
+        let mock_server = wiremock::MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path_regex(r"^/paper\d+\.pdf$"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_bytes(b"%PDF-1.4\n% batch\n%%EOF\n".to_vec())
+                    .insert_header("content-type", "application/pdf")
+                    .set_delay(Duration::from_millis(300)),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let temp_dir = TempDir::new().unwrap();
+        let mut config = (*create_test_config()).clone();
+        config.downloads.directory = temp_dir.path().to_path_buf();
+        config.concurrency.download_workers = 2;
+        let tool = DownloadTool::new(
+            Arc::new(MetaSearchClient::with_providers(
+                Vec::new(),
+                crate::client::MetaSearchConfig::default(),
+            )),
+            Arc::new(config),
+        )
+        .unwrap();
+
+        let papers = (0..6)
+            .map(|i| BatchDownloadRequest {
+                doi: None,
+                url: Some(format!("{}/paper{i}.pdf", mock_server.uri())),
+                filename: Some(format!("paper{i}.pdf")),
+                category: None,
+            })
+            .collect();
+        let batch = tokio::spawn({
+            let tool = tool.clone();
+            async move {
+                tool.download_papers_batch(BatchDownloadInput {
+                    papers,
+                    max_concurrent: None,
+                    continue_on_error: true,
+                    shared_settings: BatchDownloadSettings::default(),
+                })
+                .await
+            }
+        });
+
+        let mut max_in_flight = 0;
+        while !batch.is_finished() {
+            let in_flight = tool
+                .get_queue_status()
+                .await
+                .iter()
+                .filter(|item| item.started_at.is_some())
+                .count();
+            max_in_flight = max_in_flight.max(in_flight);
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+
+        let result = batch.await.unwrap().unwrap();
+        assert_eq!(result.summary.successful, 6);
+        assert_eq!(max_in_flight, 2);
+    }
+
+    #[tokio::test]
+    async fn test_repair_download_replaces_truncated_pdf() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, ResponseTemplate};
+
+        let valid_pdf = b"%PDF-1.4\n1 0 obj\n<< /Type /Catalog >>\nendobj\n%%EOF\n".to_vec();
+        let mock_server = wiremock::MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/paper.pdf"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_bytes(valid_pdf.clone())
+                    .insert_header("content-type", "application/pdf"),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = temp_dir.path().join("paper.pdf");
+        std::fs::write(&file_path, b"%PDF-1.4\n1 0 obj\n<< /Type /Cat").unwrap();
+
+        let (tool, _) =
+            create_title_fallback_tool(Some(&format!("{}/paper.pdf", mock_server.uri())), false);
+        let input = RepairDownloadInput {
+            doi: Some("10.1000/hosted".to_string()),
+            file_path: Some(file_path.to_string_lossy().to_string()),
+            url: None,
+        };
+
+        let result = tool.repair_download(input.clone()).await.unwrap();
+        assert!(result.repaired);
+        assert!(result.corruption.unwrap().contains("%%EOF"));
+        assert_eq!(result.file_path, file_path);
+        assert_eq!(std::fs::read(&file_path).unwrap(), valid_pdf);
+        assert!(!temp_dir.path().join(".paper.pdf.repair").exists());
+
+        // The repaired file passes the check, so nothing is downloaded again
+        let result = tool.repair_download(input).await.unwrap();
+        assert!(!result.repaired);
+        assert!(result.corruption.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_download_paper_write_sidecar_saves_metadata() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, ResponseTemplate};
+
+        let mock_server = wiremock::MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/paper.pdf"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_bytes(b"%PDF-1.4\n% sidecar test\n%%EOF\n".to_vec())
+                    .insert_header("content-type", "application/pdf"),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let download_dir = TempDir::new().unwrap();
+        let mut config = (*create_test_config()).clone();
+        config.downloads.directory = download_dir.path().to_path_buf();
+        config.downloads.write_sidecar = true;
+        let client = Arc::new(MetaSearchClient::with_providers(
+            vec![Arc::new(mirror_provider(vec![format!(
+                "{}/paper.pdf",
+                mock_server.uri()
+            )]))],
+            crate::client::MetaSearchConfig::default(),
+        ));
+        let tool = DownloadTool::new(client, Arc::new(config)).unwrap();
+        let download = |filename: &str, write_sidecar: Option<bool>| {
+            tool.download_paper(DownloadInput {
+                doi: Some("10.1000/sidecar".to_string()),
+                url: None,
+                filename: Some(filename.to_string()),
+                directory: None,
+                category: None,
+                overwrite: false,
+                verify_integrity: Some(false),
+                title: None,
+                write_sidecar,
+                headers: None,
+            })
+        };
+
+        let result = download("with_sidecar.pdf", None).await.unwrap();
+        assert!(matches!(result.status, DownloadStatus::Completed));
+        let sidecar =
+            std::fs::read_to_string(download_dir.path().join("with_sidecar.json")).unwrap();
+        let metadata: PaperMetadata = serde_json::from_str(&sidecar).unwrap();
+        assert_eq!(metadata.doi, "10.1000/sidecar");
+        assert_eq!(metadata.title.as_deref(), Some("Mirrored Paper"));
+
+        // The per-call override wins over the config
+        download("without_sidecar.pdf", Some(false)).await.unwrap();
+        assert!(download_dir.path().join("without_sidecar.pdf").exists());
+        assert!(!download_dir.path().join("without_sidecar.json").exists());
+    }
+
+    #[tokio::test]
+    async fn test_download_paper_mock_provider_records_source_provider() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, ResponseTemplate};
+
+        let mock_server = wiremock::MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/paper.pdf"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_bytes(b"%PDF-1.4\n% provenance test\n%%EOF\n".to_vec())
+                    .insert_header("content-type", "application/pdf"),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let download_dir = TempDir::new().unwrap();
+        let mut config = (*create_test_config()).clone();
+        config.downloads.directory = download_dir.path().to_path_buf();
+        config.downloads.write_sidecar = true;
+        config.downloads.write_manifest = true;
+        let client = Arc::new(MetaSearchClient::with_providers(
+            vec![Arc::new(ranked_mirror_provider(
+                "repository",
+                50,
+                format!("{}/paper.pdf", mock_server.uri()),
+            ))],
+            crate::client::MetaSearchConfig::default(),
+        ));
+        let tool = DownloadTool::new(client, Arc::new(config)).unwrap();
+
+        let result = tool
+            .download_paper(DownloadInput {
+                doi: Some("10.1000/provenance".to_string()),
+                url: None,
+                filename: Some("provenance.pdf".to_string()),
+                directory: None,
+                category: None,
+                overwrite: false,
+                verify_integrity: Some(false),
+                title: None,
+                write_sidecar: None,
+                headers: None,
+            })
+            .await
+            .unwrap();
+        assert!(matches!(result.status, DownloadStatus::Completed));
+        assert_eq!(result.source_provider.as_deref(), Some("repository"));
+
+        let sidecar: serde_json::Value = serde_json::from_str(
+            &std::fs::read_to_string(download_dir.path().join("provenance.json")).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(sidecar["source_provider"], "repository");
+        assert_eq!(sidecar["doi"], "10.1000/provenance");
+
+        let manifest = std::fs::read_to_string(download_dir.path().join(MANIFEST_FILE)).unwrap();
+        let entries: Vec<ManifestEntry> = manifest
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].source_provider.as_deref(), Some("repository"));
+        assert_eq!(entries[0].doi.as_deref(), Some("10.1000/provenance"));
+        assert_eq!(
+            entries[0].download_url,
+            Some(format!("{}/paper.pdf", mock_server.uri()))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_download_paper_concurrent_downloads_write_one_manifest_line_each() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, ResponseTemplate};
+
+        const DOWNLOADS: usize = 8;
+        let mock_server = wiremock::MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/paper.pdf"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_bytes(b"%PDF-1.4\n% manifest lock test\n%%EOF\n".to_vec())
+                    .insert_header("content-type", "application/pdf"),
+            )
+            .mount(&mock_server)
+            .await;
+
+        // File locking uses flock, which only exists on unix
+        let manifest_locks: &[ManifestLock] = if cfg!(unix) {
+            &[ManifestLock::Process, ManifestLock::File]
+        } else {
+            &[ManifestLock::Process]
+        };
+        for &manifest_lock in manifest_locks {
+            let download_dir = TempDir::new().unwrap();
+            let mut config = (*create_test_config()).clone();
+            config.downloads.directory = download_dir.path().to_path_buf();
+            config.downloads.max_concurrent = DOWNLOADS;
+            config.downloads.write_manifest = true;
+            config.downloads.manifest_lock = manifest_lock;
+            let tool = Arc::new(
+                DownloadTool::new(
+                    Arc::new(MetaSearchClient::with_providers(
+                        Vec::new(),
+                        crate::client::MetaSearchConfig::default(),
+                    )),
+                    Arc::new(config),
+                )
+                .unwrap(),
+            );
+
+            let handles: Vec<_> = (0..DOWNLOADS)
+                .map(|i| {
+                    let tool = tool.clone();
+                    let url = format!("{}/paper.pdf", mock_server.uri());
+                    tokio::spawn(async move {
+                        tool.download_paper(DownloadInput {
+                            doi: None,
+                            url: Some(url),
+                            filename: Some(format!("paper_{i}.pdf")),
+                            directory: None,
+                            category: None,
+                            overwrite: false,
+                            verify_integrity: Some(true),
+                            title: None,
+                            write_sidecar: None,
+                            headers: None,
+                        })
+                        .await
+                    })
+                })
+                .collect();
+            let mut download_ids = std::collections::HashSet::new();
+            for handle in handles {
+                let result = handle.await.unwrap().unwrap();
+                download_ids.insert(result.download_id);
+            }
+
+            let manifest =
+                std::fs::read_to_string(download_dir.path().join(MANIFEST_FILE)).unwrap();
+            let entries: Vec<ManifestEntry> = manifest
+                .lines()
+                .map(|line| serde_json::from_str(line).unwrap())
+                .collect();
+            assert_eq!(entries.len(), DOWNLOADS, "{manifest_lock:?}");
+            let recorded: std::collections::HashSet<String> =
+                entries.iter().map(|e| e.download_id.clone()).collect();
+            assert_eq!(recorded, download_ids, "{manifest_lock:?}");
+            assert!(entries.iter().all(|e| e.sha256_hash.is_some()));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_download_paper_not_found_suggests_corrected_doi() {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, ResponseTemplate};
+
+        let crossref = wiremock::MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/works"))
+            .and(query_param("filter", "prefix:10.1000"))
+            .and(query_param(
+                "query.bibliographic",
+                "Folding kinetics of small proteins",
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "status": "ok",
+                "message": {
+                    "items": [
+                        {
+                            "DOI": "10.1000/jmb.2019.11.042",
+                            "title": ["Folding kinetics of large proteins"]
+                        },
+                        {
+                            "DOI": "10.1000/jmb.2020.01.001",
+                            "title": ["Folding kinetics of small proteins"]
+                        }
+                    ]
+                }
+            })))
+            .expect(1)
+            .mount(&crossref)
+            .await;
+
+        let mut config = (*create_test_config()).clone();
+        config.doi_suggestions.enabled = true;
+        config.doi_suggestions.crossref_url = format!("{}/works", crossref.uri());
+        config.doi_suggestions.max_suggestions = 1;
+        let client = Arc::new(MetaSearchClient::with_providers(
+            vec![Arc::new(mirror_provider(vec![]))],
+            crate::client::MetaSearchConfig::default(),
+        ));
+        let tool = DownloadTool::new(client, Arc::new(config)).unwrap();
+
+        // Letter O typed instead of a zero
+        let result = tool
+            .download_paper(DownloadInput {
+                doi: Some("10.1000/jmb.2O20.01.001".to_string()),
+                url: None,
+                filename: None,
+                directory: None,
+                category: None,
+                overwrite: false,
+                verify_integrity: Some(false),
+                title: Some("Folding kinetics of small proteins".to_string()),
+                write_sidecar: None,
+                headers: None,
+            })
+            .await;
+
+        let Err(crate::Error::ServiceUnavailable { reason, .. }) = result else {
+            panic!("expected a not-found error, got {result:?}");
+        };
+        assert!(reason.contains("Did you mean"), "{reason}");
+        assert!(reason.contains("10.1000/jmb.2020.01.001"), "{reason}");
+        assert!(!reason.contains("10.1000/jmb.2019.11.042"), "{reason}");
+    }
 
     #[test]
     fn test_batch_download_input_validation() {
         // Empty papers list should fail
         let empty_batch = BatchDownloadInput {
             papers: vec![],
-            max_concurrent: 3,
+            max_concurrent: Some(3),
             continue_on_error: true,
             shared_settings: BatchDownloadSettings::default(),
         };
@@ -2371,7 +5710,7 @@ mod tests {
             .collect();
         let too_many_batch = BatchDownloadInput {
             papers,
-            max_concurrent: 3,
+            max_concurrent: Some(3),
             continue_on_error: true,
             shared_settings: BatchDownloadSettings::default(),
         };
@@ -2385,7 +5724,7 @@ mod tests {
                 filename: None,
                 category: None,
             }],
-            max_concurrent: 0,
+            max_concurrent: Some(0),
             continue_on_error: true,
             shared_settings: BatchDownloadSettings::default(),
         };
@@ -2407,7 +5746,7 @@ mod tests {
                     category: None,
                 },
             ],
-            max_concurrent: 3,
+            max_concurrent: Some(3),
             continue_on_error: true,
             shared_settings: BatchDownloadSettings::default(),
         };
@@ -2427,7 +5766,7 @@ mod tests {
             directory: Some("/test/dir".to_string()),
             category: Some("default_category".to_string()),
             overwrite: true,
-            verify_integrity: false,
+            verify_integrity: Some(false),
         };
 
         let download_input =
@@ -2439,7 +5778,7 @@ mod tests {
         assert_eq!(download_input.directory, Some("/test/dir".to_string()));
         assert_eq!(download_input.category, Some("research".to_string())); // Request overrides shared
         assert_eq!(download_input.overwrite, true);
-        assert_eq!(download_input.verify_integrity, false);
+        assert_eq!(download_input.verify_integrity, Some(false));
     }
 
     #[test]
@@ -2499,7 +5838,7 @@ mod tests {
 
         let batch_input = BatchDownloadInput {
             papers,
-            max_concurrent: 9,
+            max_concurrent: Some(9),
             continue_on_error: true,
             shared_settings: BatchDownloadSettings::default(),
         };
@@ -2523,7 +5862,7 @@ mod tests {
                 filename: None,
                 category: None,
             }],
-            max_concurrent: 0,
+            max_concurrent: Some(0),
             continue_on_error: true,
             shared_settings: BatchDownloadSettings::default(),
         };
@@ -2544,7 +5883,7 @@ mod tests {
                 filename: None,
                 category: None,
             }],
-            max_concurrent: 25,
+            max_concurrent: Some(25),
             continue_on_error: true,
             shared_settings: BatchDownloadSettings::default(),
         };
@@ -2599,7 +5938,10 @@ mod tests {
             directory: None,
             category: None,
             overwrite: false,
-            verify_integrity: true,
+            verify_integrity: Some(true),
+            title: None,
+            write_sidecar: None,
+            headers: None,
         };
 
         let result = DownloadTool::validate_input(&both_input);
@@ -2616,7 +5958,10 @@ mod tests {
             directory: None,
             category: None,
             overwrite: false,
-            verify_integrity: true,
+            verify_integrity: Some(true),
+            title: None,
+            write_sidecar: None,
+            headers: None,
         };
 
         let result_neither = DownloadTool::validate_input(&neither_input);
@@ -2638,7 +5983,7 @@ mod tests {
 
         let batch_input = BatchDownloadInput {
             papers: vec![invalid_request],
-            max_concurrent: 9,
+            max_concurrent: Some(9),
             continue_on_error: true,
             shared_settings: BatchDownloadSettings::default(),
         };
@@ -2659,7 +6004,7 @@ mod tests {
 
         let batch_input_both = BatchDownloadInput {
             papers: vec![invalid_both_request],
-            max_concurrent: 9,
+            max_concurrent: Some(9),
             continue_on_error: true,
             shared_settings: BatchDownloadSettings::default(),
         };
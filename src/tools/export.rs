@@ -0,0 +1,465 @@
+//! # Library Export
+//!
+//! Writes every downloaded paper with a metadata sidecar to a single file
+//! that reference managers can import (BibTeX, RIS or CSL-JSON).
+
+use crate::client::PaperMetadata;
+use crate::tools::library::load_library;
+use crate::{Config, Result};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tracing::{info, instrument, warn};
+
+/// File format of a library export
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum LibraryFormat {
+    /// BibTeX `.bib`
+    #[default]
+    Bibtex,
+    /// RIS `.ris`
+    Ris,
+    /// CSL-JSON array
+    CslJson,
+}
+
+/// Input for the `export_library` tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ExportLibraryInput {
+    /// File the library is written to; relative paths resolve against the
+    /// download directory and parent directories are created
+    pub path: String,
+    /// Output format (default: bibtex)
+    #[serde(default)]
+    pub format: LibraryFormat,
+}
+
+/// A downloaded paper left out of the export
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SkippedEntry {
+    /// DOI of the paper
+    pub doi: String,
+    /// Required fields the paper's metadata is missing
+    pub missing: Vec<String>,
+}
+
+/// Outcome of a library export
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ExportLibraryResult {
+    /// File that was written
+    pub path: PathBuf,
+    /// Format that was written
+    pub format: LibraryFormat,
+    /// Number of entries written
+    pub exported: usize,
+    /// Papers skipped for missing required fields
+    pub skipped: Vec<SkippedEntry>,
+}
+
+/// Exports the downloaded papers, using their metadata sidecars, to a
+/// reference manager file
+#[derive(Debug, Clone)]
+pub struct ExportLibraryTool {
+    config: Arc<Config>,
+}
+
+impl ExportLibraryTool {
+    /// Create an export tool for the configured download directory
+    #[must_use]
+    pub const fn new(config: Arc<Config>) -> Self {
+        Self { config }
+    }
+
+    /// Serialize every downloaded paper into `input.format` and write it to
+    /// `input.path`, replacing any existing file
+    #[instrument(skip(self))]
+    pub async fn export(&self, input: ExportLibraryInput) -> Result<ExportLibraryResult> {
+        let path = PathBuf::from(input.path.trim());
+        if path.as_os_str().is_empty() {
+            return Err(crate::Error::InvalidInput {
+                field: "path".to_string(),
+                reason: "Export path cannot be empty".to_string(),
+            });
+        }
+        let path = if path.is_absolute() {
+            path
+        } else {
+            self.config.downloads.directory.join(path)
+        };
+
+        let mut papers: Vec<PaperMetadata> = load_library(
+            &self.config.downloads.directory,
+            self.config.downloads.quarantine_dir.as_deref(),
+        )
+        .await?
+        .into_iter()
+        .map(|paper| paper.metadata)
+        .collect();
+        papers.sort_by(|a, b| a.doi.cmp(&b.doi));
+
+        let mut skipped = Vec::new();
+        let mut exportable = Vec::new();
+        for paper in papers {
+            let missing = missing_fields(&paper, input.format);
+            if missing.is_empty() {
+                exportable.push(paper);
+            } else {
+                warn!(
+                    "Skipping {} in library export: missing {:?}",
+                    paper.doi, missing
+                );
+                skipped.push(SkippedEntry {
+                    doi: paper.doi,
+                    missing,
+                });
+            }
+        }
+
+        let contents = match input.format {
+            LibraryFormat::Bibtex => to_bibtex(&exportable),
+            LibraryFormat::Ris => to_ris(&exportable),
+            LibraryFormat::CslJson => to_csl_json(&exportable)?,
+        };
+
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        tokio::fs::write(&path, contents).await?;
+
+        info!(
+            "Exported {} papers to {} ({} skipped)",
+            exportable.len(),
+            path.display(),
+            skipped.len()
+        );
+        Ok(ExportLibraryResult {
+            path,
+            format: input.format,
+            exported: exportable.len(),
+            skipped,
+        })
+    }
+}
+
+/// Fields `format` requires that `paper` does not have
+fn missing_fields(paper: &PaperMetadata, format: LibraryFormat) -> Vec<String> {
+    let mut missing = Vec::new();
+    if paper.title.as_deref().map_or(true, |t| t.trim().is_empty()) {
+        missing.push("title".to_string());
+    }
+    // BibTeX @article requires author and year; RIS and CSL-JSON do not
+    if format == LibraryFormat::Bibtex {
+        if paper.authors.is_empty() {
+            missing.push("authors".to_string());
+        }
+        if paper.year.is_none() {
+            missing.push("year".to_string());
+        }
+    }
+    missing
+}
+
+fn to_bibtex(papers: &[PaperMetadata]) -> String {
+    let mut used_keys = HashSet::new();
+    let mut out = String::new();
+
+    for paper in papers {
+        let key = unique_key(bibtex_key(paper), &mut used_keys);
+        let entry_type = if paper.journal.is_some() {
+            "article"
+        } else {
+            "misc"
+        };
+
+        let mut fields = vec![
+            ("title", paper.title.clone().unwrap_or_default()),
+            ("author", paper.authors.join(" and ")),
+        ];
+        if let Some(year) = paper.year {
+            fields.push(("year", year.to_string()));
+        }
+        if let Some(journal) = &paper.journal {
+            fields.push(("journal", journal.clone()));
+        }
+        if !paper.doi.is_empty() {
+            fields.push(("doi", paper.doi.clone()));
+        }
+        if let Some(url) = &paper.pdf_url {
+            fields.push(("url", url.clone()));
+        }
+        if let Some(abstract_text) = &paper.abstract_text {
+            fields.push(("abstract", abstract_text.clone()));
+        }
+
+        out.push_str(&format!("@{entry_type}{{{key},\n"));
+        for (name, value) in fields {
+            // DOIs and URLs are verbatim; everything else is TeX-escaped
+            let value = if matches!(name, "doi" | "url") {
+                value
+            } else {
+                escape_bibtex(&value)
+            };
+            out.push_str(&format!("  {name} = {{{value}}},\n"));
+        }
+        out.push_str("}\n\n");
+    }
+    out
+}
+
+/// Citation key of the form `smith2020attention`
+fn bibtex_key(paper: &PaperMetadata) -> String {
+    let author = paper
+        .authors
+        .first()
+        .and_then(|name| name.split_whitespace().last())
+        .unwrap_or("anon");
+    let year = paper.year.map(|y| y.to_string()).unwrap_or_default();
+    let word = paper
+        .title
+        .as_deref()
+        .and_then(|t| t.split_whitespace().find(|w| w.len() > 3))
+        .unwrap_or("");
+
+    format!("{author}{year}{word}")
+        .chars()
+        .filter(char::is_ascii_alphanumeric)
+        .collect::<String>()
+        .to_ascii_lowercase()
+}
+
+/// Append `a`, `b`, ... to keys that are already taken
+fn unique_key(key: String, used: &mut HashSet<String>) -> String {
+    if used.insert(key.clone()) {
+        return key;
+    }
+    ('a'..='z')
+        .map(|suffix| format!("{key}{suffix}"))
+        // One of these is free: `used` cannot hold all of them
+        .chain((1..=used.len() + 1).map(|n| format!("{key}_{n}")))
+        .find(|candidate| used.insert(candidate.clone()))
+        .unwrap_or(key)
+}
+
+fn escape_bibtex(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' | '%' | '$' | '#' | '_' | '{' | '}' => {
+                out.push('\\');
+                out.push(c);
+            }
+            '\n' | '\r' => out.push(' '),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+fn to_ris(papers: &[PaperMetadata]) -> String {
+    let mut out = String::new();
+    for paper in papers {
+        let entry_type = if paper.journal.is_some() {
+            "JOUR"
+        } else {
+            "GEN"
+        };
+        out.push_str(&format!("TY  - {entry_type}\n"));
+        if let Some(title) = &paper.title {
+            out.push_str(&format!("TI  - {}\n", single_line(title)));
+        }
+        for author in &paper.authors {
+            out.push_str(&format!("AU  - {author}\n"));
+        }
+        if let Some(year) = paper.year {
+            out.push_str(&format!("PY  - {year}\n"));
+        }
+        if let Some(journal) = &paper.journal {
+            out.push_str(&format!("JO  - {}\n", single_line(journal)));
+        }
+        if !paper.doi.is_empty() {
+            out.push_str(&format!("DO  - {}\n", paper.doi));
+        }
+        if let Some(url) = &paper.pdf_url {
+            out.push_str(&format!("UR  - {url}\n"));
+        }
+        if let Some(abstract_text) = &paper.abstract_text {
+            out.push_str(&format!("AB  - {}\n", single_line(abstract_text)));
+        }
+        out.push_str("ER  - \n\n");
+    }
+    out
+}
+
+fn single_line(value: &str) -> String {
+    value.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn to_csl_json(papers: &[PaperMetadata]) -> Result<String> {
+    let items: Vec<serde_json::Value> = papers.iter().map(csl_item).collect();
+    Ok(serde_json::to_string_pretty(&items)?)
+}
+
+/// CSL-JSON item for `paper`; optional fields are only present when known
+fn csl_item(paper: &PaperMetadata) -> serde_json::Value {
+    let mut fields = serde_json::Map::new();
+    fields.insert("id".to_string(), json!(paper.doi));
+    fields.insert(
+        "type".to_string(),
+        json!(if paper.journal.is_some() {
+            "article-journal"
+        } else {
+            "article"
+        }),
+    );
+    fields.insert("title".to_string(), json!(paper.title));
+    fields.insert(
+        "author".to_string(),
+        paper.authors.iter().map(|name| csl_name(name)).collect(),
+    );
+    if !paper.doi.is_empty() {
+        fields.insert("DOI".to_string(), json!(paper.doi));
+    }
+    if let Some(year) = paper.year {
+        fields.insert("issued".to_string(), json!({ "date-parts": [[year]] }));
+    }
+    if let Some(journal) = &paper.journal {
+        fields.insert("container-title".to_string(), json!(journal));
+    }
+    if let Some(url) = &paper.pdf_url {
+        fields.insert("URL".to_string(), json!(url));
+    }
+    if let Some(abstract_text) = &paper.abstract_text {
+        fields.insert("abstract".to_string(), json!(abstract_text));
+    }
+    if let Some(license) = &paper.license {
+        fields.insert("license".to_string(), json!(license));
+    }
+    serde_json::Value::Object(fields)
+}
+
+/// CSL name object; names without a space are kept as a literal
+fn csl_name(name: &str) -> serde_json::Value {
+    match name.rsplit_once(' ') {
+        Some((given, family)) => json!({ "given": given, "family": family }),
+        None => json!({ "literal": name }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::path::Path;
+    use tempfile::TempDir;
+
+    fn write_paper(pdf: &Path, metadata: &PaperMetadata) {
+        fs::create_dir_all(pdf.parent().unwrap()).unwrap();
+        fs::write(pdf, b"%PDF-1.4\n%%EOF\n").unwrap();
+        fs::write(
+            pdf.with_extension("json"),
+            serde_json::to_vec(metadata).unwrap(),
+        )
+        .unwrap();
+    }
+
+    fn stored_paper(doi: &str, title: &str, author: &str, year: u32) -> PaperMetadata {
+        let mut paper = PaperMetadata::new(doi.to_string());
+        paper.title = Some(title.to_string());
+        paper.authors = vec![author.to_string()];
+        paper.year = Some(year);
+        paper.journal = Some("Journal of Tests".to_string());
+        paper
+    }
+
+    #[tokio::test]
+    async fn test_export_library_writes_every_downloaded_paper() {
+        let downloads = TempDir::new().unwrap();
+        let root = downloads.path();
+        write_paper(
+            &root.join("cs/attention.pdf"),
+            &stored_paper(
+                "10.1000/first",
+                "Attention Is All You Need",
+                "Ashish Vaswani",
+                2017,
+            ),
+        );
+        write_paper(
+            &root.join("cs/resnet.pdf"),
+            &stored_paper(
+                "10.1000/second",
+                "Deep Residual Learning",
+                "Kaiming He",
+                2016,
+            ),
+        );
+        // No title, so it cannot be exported
+        write_paper(
+            &root.join("untitled.pdf"),
+            &PaperMetadata::new("10.1000/untitled".to_string()),
+        );
+
+        let mut config = Config::default();
+        config.downloads.directory = root.to_path_buf();
+        let tool = ExportLibraryTool::new(Arc::new(config));
+        let out = TempDir::new().unwrap();
+
+        for (format, file) in [
+            (LibraryFormat::Bibtex, "library/all.bib"),
+            (LibraryFormat::Ris, "all.ris"),
+            (LibraryFormat::CslJson, "all.json"),
+        ] {
+            let path = out.path().join(file);
+            let result = tool
+                .export(ExportLibraryInput {
+                    path: path.to_string_lossy().into_owned(),
+                    format,
+                })
+                .await
+                .unwrap();
+
+            assert_eq!(result.exported, 2, "{format:?}");
+            assert_eq!(result.skipped.len(), 1, "{format:?}");
+            assert_eq!(result.skipped[0].doi, "10.1000/untitled");
+            assert!(result.skipped[0].missing.contains(&"title".to_string()));
+
+            let contents = fs::read_to_string(&path).unwrap();
+            for expected in [
+                "10.1000/first",
+                "Attention Is All You Need",
+                "10.1000/second",
+                "Deep Residual Learning",
+            ] {
+                assert!(contents.contains(expected), "{format:?} missing {expected}");
+            }
+            assert!(!contents.contains("10.1000/untitled"), "{format:?}");
+        }
+
+        let bibtex = fs::read_to_string(out.path().join("library/all.bib")).unwrap();
+        assert!(bibtex.contains("@article{vaswani2017attention,"));
+        assert!(bibtex.contains("@article{he2016deep,"));
+
+        let csl: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(out.path().join("all.json")).unwrap())
+                .unwrap();
+        assert_eq!(csl[0]["DOI"], "10.1000/first");
+        assert_eq!(csl[0]["issued"]["date-parts"][0][0], 2017);
+        assert_eq!(csl[0]["author"][0]["family"], "Vaswani");
+    }
+
+    #[tokio::test]
+    async fn test_export_library_empty_path_rejected() {
+        let tool = ExportLibraryTool::new(Arc::new(Config::default()));
+        let result = tool
+            .export(ExportLibraryInput {
+                path: "  ".to_string(),
+                format: LibraryFormat::Ris,
+            })
+            .await;
+        assert!(matches!(result, Err(crate::Error::InvalidInput { .. })));
+    }
+}
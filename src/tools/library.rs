@@ -0,0 +1,223 @@
+use crate::client::PaperMetadata;
+use crate::services::{duplicate_groups, DuplicateStrategy};
+use crate::tools::recategorize::collect_pdfs;
+use crate::{Config, Result};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tracing::{info, instrument, warn};
+
+/// A downloaded paper together with the metadata from its sidecar
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct LibraryPaper {
+    /// Path of the downloaded PDF
+    pub path: PathBuf,
+    /// Metadata read from the `.json` sidecar next to the PDF
+    #[serde(flatten)]
+    pub metadata: PaperMetadata,
+}
+
+/// How two library records are matched as duplicates
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DuplicateMatch {
+    /// Same DOI, ignoring case and resolver prefixes
+    #[default]
+    ExactDoi,
+    /// Near-identical titles, ignoring case and punctuation
+    FuzzyTitle,
+}
+
+/// Input for finding duplicate papers in the download directory
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct FindDuplicatesInput {
+    /// How records are matched (default: exact_doi)
+    #[serde(default)]
+    pub strategy: DuplicateMatch,
+    /// Minimum title similarity (0.0-1.0) for fuzzy_title (default: 0.9)
+    #[serde(default)]
+    pub min_similarity: Option<f64>,
+}
+
+/// Outcome of a duplicate search over the download directory
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct FindDuplicatesResult {
+    /// Groups of papers that likely describe the same paper
+    pub groups: Vec<Vec<LibraryPaper>>,
+    /// Papers with a readable metadata sidecar that were compared
+    pub scanned: usize,
+}
+
+/// Queries over the downloaded papers that have metadata sidecars, the
+/// closest thing this server has to a paper repository
+#[derive(Debug, Clone)]
+pub struct LibraryTool {
+    config: Arc<Config>,
+}
+
+impl LibraryTool {
+    /// Create a library tool for the configured download directory
+    #[must_use]
+    pub const fn new(config: Arc<Config>) -> Self {
+        Self { config }
+    }
+
+    /// Group downloaded papers that likely describe the same paper
+    #[instrument(skip(self))]
+    pub async fn find_duplicates(
+        &self,
+        input: FindDuplicatesInput,
+    ) -> Result<FindDuplicatesResult> {
+        let strategy = match input.strategy {
+            DuplicateMatch::ExactDoi => DuplicateStrategy::ExactDoi,
+            DuplicateMatch::FuzzyTitle => match input.min_similarity {
+                Some(min_similarity) if (0.0..=1.0).contains(&min_similarity) => {
+                    DuplicateStrategy::FuzzyTitle { min_similarity }
+                }
+                Some(_) => {
+                    return Err(crate::Error::InvalidInput {
+                        field: "min_similarity".to_string(),
+                        reason: "Must be between 0.0 and 1.0".to_string(),
+                    })
+                }
+                None => DuplicateStrategy::fuzzy_title(),
+            },
+        };
+
+        let papers = self.load().await?;
+        let metadata: Vec<PaperMetadata> = papers.iter().map(|p| p.metadata.clone()).collect();
+        let groups: Vec<Vec<LibraryPaper>> = duplicate_groups(&metadata, strategy)
+            .into_iter()
+            .map(|group| group.into_iter().map(|i| papers[i].clone()).collect())
+            .collect();
+
+        info!(
+            "Found {} duplicate groups among {} papers",
+            groups.len(),
+            papers.len()
+        );
+        Ok(FindDuplicatesResult {
+            groups,
+            scanned: papers.len(),
+        })
+    }
+
+    /// Every downloaded paper with a readable sidecar
+    pub async fn load(&self) -> Result<Vec<LibraryPaper>> {
+        load_library(
+            &self.config.downloads.directory,
+            self.config.downloads.quarantine_dir.as_deref(),
+        )
+        .await
+    }
+}
+
+/// Downloaded PDFs below `dir` that have a readable metadata sidecar, in path
+/// order. Quarantined files are skipped, and so are PDFs without a sidecar.
+pub async fn load_library(dir: &Path, quarantine_dir: Option<&Path>) -> Result<Vec<LibraryPaper>> {
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut papers = Vec::new();
+    for pdf in collect_pdfs(dir, quarantine_dir).await? {
+        let sidecar = pdf.with_extension("json");
+        let Ok(contents) = tokio::fs::read(&sidecar).await else {
+            continue;
+        };
+        match serde_json::from_slice::<PaperMetadata>(&contents) {
+            Ok(metadata) => papers.push(LibraryPaper {
+                path: pdf,
+                metadata,
+            }),
+            Err(e) => warn!("Ignoring unreadable sidecar {:?}: {}", sidecar, e),
+        }
+    }
+    Ok(papers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn write_paper(path: &Path, doi: &str, title: &str) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, b"%PDF-1.4\n%%EOF\n").unwrap();
+        let mut metadata = PaperMetadata::new(doi.to_string());
+        metadata.title = Some(title.to_string());
+        fs::write(
+            path.with_extension("json"),
+            serde_json::to_vec(&metadata).unwrap(),
+        )
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_find_duplicates_sidecars_grouped_by_doi_and_title() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        write_paper(
+            &root.join("a/attention.pdf"),
+            "10.48550/arXiv.1706.03762",
+            "Attention Is All You Need",
+        );
+        write_paper(
+            &root.join("b/attention.pdf"),
+            "https://doi.org/10.48550/arxiv.1706.03762",
+            "Attention is all you need.",
+        );
+        write_paper(
+            &root.join("b/preprint.pdf"),
+            "10.5555/attention-copy",
+            "Attention Is All You Ned",
+        );
+        write_paper(
+            &root.join("c/vision.pdf"),
+            "10.1000/vision",
+            "Vision transformers",
+        );
+        // No sidecar: not part of the library
+        fs::write(root.join("c/notes.pdf"), b"%PDF-1.4\n").unwrap();
+
+        let mut config = Config::default();
+        config.downloads.directory = root.to_path_buf();
+        let tool = LibraryTool::new(Arc::new(config));
+
+        let by_doi = tool
+            .find_duplicates(FindDuplicatesInput::default())
+            .await
+            .unwrap();
+        assert_eq!(by_doi.scanned, 4);
+        assert_eq!(by_doi.groups.len(), 1);
+        let paths: Vec<&PathBuf> = by_doi.groups[0].iter().map(|p| &p.path).collect();
+        assert_eq!(
+            paths,
+            [&root.join("a/attention.pdf"), &root.join("b/attention.pdf")]
+        );
+
+        let by_title = tool
+            .find_duplicates(FindDuplicatesInput {
+                strategy: DuplicateMatch::FuzzyTitle,
+                min_similarity: None,
+            })
+            .await
+            .unwrap();
+        assert_eq!(by_title.groups.len(), 1);
+        assert_eq!(by_title.groups[0].len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_find_duplicates_similarity_out_of_range_rejected() {
+        let tool = LibraryTool::new(Arc::new(Config::default()));
+        let result = tool
+            .find_duplicates(FindDuplicatesInput {
+                strategy: DuplicateMatch::FuzzyTitle,
+                min_similarity: Some(1.5),
+            })
+            .await;
+        assert!(matches!(result, Err(crate::Error::InvalidInput { .. })));
+    }
+}
@@ -1,3 +1,5 @@
+use crate::client::http_client_builder;
+use crate::tools::bibliography::{BibliographyTool, PaperMetadata as BibliographyPaper};
 use crate::{Config, Result};
 use futures::StreamExt;
 use lopdf::{Document, Object};
@@ -34,11 +36,36 @@ pub struct MetadataInput {
     #[schemars(description = "Extract references and citations from the PDF (default: false)")]
     #[serde(default = "default_extract_refs")]
     pub extract_references: bool,
+    /// Whether to collect figure and table captions
+    #[schemars(
+        description = "Collect figure and table captions (lines like 'Figure 2: ...' or 'Table 1. ...') in document order (default: false)"
+    )]
+    #[serde(default)]
+    pub extract_captions: bool,
     /// Batch processing file list
     #[schemars(
-        description = "Array of PDF file paths for batch processing. Processes up to 12 files concurrently. No limit on total files."
+        description = "Array of PDF file paths for batch processing. Processes up to concurrency.metadata_workers files concurrently (12 by default). No limit on total files."
     )]
     pub batch_files: Option<Vec<String>>,
+    /// How the result is rendered as text
+    #[schemars(
+        description = "Text rendering of the result: 'json' (default), 'bibtex' for a BibTeX entry, or 'summary' for a short readable overview. The full result is always returned as structured content."
+    )]
+    #[serde(default)]
+    pub output_format: MetadataOutputFormat,
+}
+
+/// Text rendering of a metadata extraction result
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum MetadataOutputFormat {
+    /// The full result as pretty-printed JSON
+    #[default]
+    Json,
+    /// A BibTeX entry for the extracted paper
+    Bibtex,
+    /// A short human-readable overview
+    Summary,
 }
 
 /// Extracted metadata structure
@@ -60,12 +87,22 @@ pub struct ExtractedMetadata {
     pub keywords: Vec<String>,
     /// References/citations
     pub references: Vec<Reference>,
+    /// Figure and table captions, in document order
+    #[serde(default)]
+    pub captions: Vec<Caption>,
     /// Volume number
     pub volume: Option<String>,
     /// Issue number
     pub issue: Option<String>,
     /// Page range
     pub pages: Option<String>,
+    /// Number of pages in the PDF (None if the page tree is unreadable)
+    #[serde(default)]
+    pub page_count: Option<u32>,
+    /// Number of words in the decoded text (None if the PDF is encrypted or
+    /// its text can't be decoded)
+    #[serde(default)]
+    pub word_count: Option<u64>,
     /// Confidence score (0.0 to 1.0)
     pub confidence_score: f64,
     /// Source of metadata (pdf, crossref, etc.)
@@ -74,6 +111,42 @@ pub struct ExtractedMetadata {
     pub extracted_at: SystemTime,
 }
 
+impl ExtractedMetadata {
+    /// Year of publication, the first four-digit number in the date
+    #[must_use]
+    pub fn year(&self) -> Option<i32> {
+        let date = self.publication_date.as_deref()?;
+        date.as_bytes()
+            .windows(4)
+            .position(|window| window.iter().all(u8::is_ascii_digit))
+            .and_then(|start| date[start..start + 4].parse().ok())
+    }
+
+    /// A BibTeX `@article` entry for the paper, including its abstract and
+    /// keywords when known
+    #[must_use]
+    pub fn to_bibtex(&self) -> String {
+        let paper = BibliographyPaper {
+            title: self.title.clone().unwrap_or_else(|| "Untitled".to_string()),
+            authors: self.authors.iter().map(|a| a.name.clone()).collect(),
+            year: self.year(),
+            journal: self.journal.clone(),
+            volume: self.volume.clone(),
+            issue: self.issue.clone(),
+            pages: self.pages.clone(),
+            doi: self.doi.clone(),
+            url: self
+                .doi
+                .as_ref()
+                .map(|doi| format!("https://doi.org/{doi}")),
+            abstract_text: self.abstract_text.clone(),
+            keywords: self.keywords.clone(),
+            publication_date: self.publication_date.clone(),
+        };
+        BibliographyTool::format_bibtex_static(&paper, true, true)
+    }
+}
+
 /// Author information
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Author {
@@ -110,6 +183,25 @@ pub struct Reference {
     pub doi: Option<String>,
 }
 
+/// Figure or table caption
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct Caption {
+    /// Whether the caption belongs to a figure or a table
+    pub kind: CaptionKind,
+    /// Number as printed, e.g. "3", "2a" or "S1"
+    pub label: String,
+    /// Caption text following the label
+    pub text: String,
+}
+
+/// Kind of captioned element
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CaptionKind {
+    Figure,
+    Table,
+}
+
 /// Result of metadata extraction
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct MetadataResult {
@@ -138,6 +230,19 @@ pub struct BatchMetadataResult {
     pub failure_count: usize,
 }
 
+/// Metadata extracted from every PDF in a directory
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DirectoryMetadataResult {
+    /// One result per PDF, in path order; failed files carry their error
+    pub results: Vec<MetadataResult>,
+    /// Files that aren't PDFs and were left alone
+    pub skipped: Vec<PathBuf>,
+    /// Total processing time
+    pub total_time_ms: u64,
+    /// Number of PDFs whose extraction failed
+    pub failure_count: usize,
+}
+
 /// Extraction status
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
@@ -207,6 +312,7 @@ struct ExtractionPatterns {
     reference_pattern: Regex,
     journal_pattern: Regex,
     volume_issue_pattern: Regex,
+    caption_pattern: Regex,
 }
 
 impl Default for ExtractionPatterns {
@@ -238,7 +344,57 @@ impl Default for ExtractionPatterns {
 
             // Volume/Issue pattern
             volume_issue_pattern: Regex::new(r"(?i)vol(?:ume)?\.?\s*(\d+)(?:.*?(?:no|issue)\.?\s*(\d+))?").unwrap(),
+
+            // Caption pattern - "Figure 2: ...", "Fig. 3. ...", "Table S1 - ..."
+            caption_pattern: Regex::new(r"(?i)^(fig(?:ure)?\.?|table)\s+([a-z]?\d+(?:\.\d+)*[a-z]?)\s*(?:[:.|\-–—]\s*(.*)|$)").unwrap(),
+        }
+    }
+}
+
+impl ExtractionPatterns {
+    /// Figure and table captions in document order. A figure or table is
+    /// reported once, at its first caption line; a label on a line of its own
+    /// takes its text from the next line.
+    fn extract_captions(&self, text: &str) -> Vec<Caption> {
+        let mut captions: Vec<Caption> = Vec::new();
+        let mut lines = text.lines().map(str::trim).peekable();
+
+        while let Some(line) = lines.next() {
+            let Some(captures) = self.caption_pattern.captures(line) else {
+                continue;
+            };
+            let kind = if captures[1].to_ascii_lowercase().starts_with("fig") {
+                CaptionKind::Figure
+            } else {
+                CaptionKind::Table
+            };
+            let label = captures[2].to_string();
+            if captions
+                .iter()
+                .any(|c| c.kind == kind && c.label.eq_ignore_ascii_case(&label))
+            {
+                continue;
+            }
+
+            let mut caption_text = captures
+                .get(3)
+                .map_or("", |m| m.as_str())
+                .trim()
+                .to_string();
+            if caption_text.is_empty() {
+                if let Some(next) = lines.next_if(|next| !next.is_empty()) {
+                    caption_text = next.to_string();
+                }
+            }
+
+            captions.push(Caption {
+                kind,
+                label,
+                text: caption_text,
+            });
         }
+
+        captions
     }
 }
 
@@ -321,7 +477,7 @@ impl MetadataExtractor {
         };
 
         // Create HTTP client for CrossRef API if needed
-        let crossref_client = reqwest::Client::builder()
+        let crossref_client = http_client_builder(&config.http)
             .timeout(Duration::from_secs(10))
             .user_agent("knowledge_accumulator_mcp/0.1.0")
             .build()
@@ -350,6 +506,7 @@ impl MetadataExtractor {
                 batch_files,
                 input.use_cache,
                 input.validate_external,
+                input.extract_captions,
             ))
             .await;
         }
@@ -372,7 +529,12 @@ impl MetadataExtractor {
 
         // Check cache if enabled
         if input.use_cache {
-            if let Some(cached) = self.get_cached_metadata(&file_path).await? {
+            // Entries cached without captions can't answer a caption request
+            if let Some(cached) = self
+                .get_cached_metadata(&file_path)
+                .await?
+                .filter(|cached| !input.extract_captions || !cached.captions.is_empty())
+            {
                 info!("Returning cached metadata for: {}", input.file_path);
                 let processing_time = start_time.elapsed().unwrap_or_default();
 
@@ -395,7 +557,7 @@ impl MetadataExtractor {
 
         // Extract metadata from PDF
         let metadata = match self
-            .extract_from_pdf(&file_path, input.extract_references)
+            .extract_from_pdf(&file_path, input.extract_references, input.extract_captions)
             .await
         {
             Ok(mut meta) => {
@@ -463,6 +625,7 @@ impl MetadataExtractor {
         &self,
         file_path: &Path,
         extract_refs: bool,
+        extract_captions: bool,
     ) -> Result<ExtractedMetadata> {
         debug!("Loading PDF document: {:?}", file_path);
 
@@ -508,6 +671,16 @@ impl MetadataExtractor {
         // Try to extract metadata from PDF info dictionary
         Self::extract_pdf_info(&doc, &mut metadata);
 
+        // Captions and word counts need decoded text; the raw content
+        // streams above keep each line wrapped in text operators
+        let decoded_text = Self::extract_decoded_text(&doc);
+        if extract_captions {
+            metadata.captions = self.extraction_patterns.extract_captions(&decoded_text);
+            debug!("Found {} captions", metadata.captions.len());
+        }
+        (metadata.page_count, metadata.word_count) =
+            Self::document_stats(&doc, &decoded_text, file_path);
+
         // Calculate confidence score
         metadata.confidence_score = Self::calculate_confidence(&metadata);
         metadata.metadata_source = "pdf".to_string();
@@ -540,6 +713,52 @@ impl MetadataExtractor {
         Ok(all_text)
     }
 
+    /// Page and word counts. Either is `None`, with a warning, when it can't
+    /// be read from the document.
+    fn document_stats(
+        doc: &Document,
+        decoded_text: &str,
+        file_path: &Path,
+    ) -> (Option<u32>, Option<u64>) {
+        let page_count = u32::try_from(doc.get_pages().len())
+            .ok()
+            .filter(|&pages| pages > 0);
+        if page_count.is_none() {
+            warn!("Could not count the pages of {:?}", file_path);
+        }
+
+        let word_count = if doc.is_encrypted() {
+            warn!("{:?} is encrypted, not counting its words", file_path);
+            None
+        } else {
+            let words = decoded_text.split_whitespace().count() as u64;
+            if words == 0 {
+                warn!(
+                    "No decodable text in {:?}, not counting its words",
+                    file_path
+                );
+            }
+            (words > 0).then_some(words)
+        };
+
+        (page_count, word_count)
+    }
+
+    /// Decoded text of every page, skipping pages lopdf cannot decode
+    fn extract_decoded_text(doc: &Document) -> String {
+        let mut text = String::new();
+        for page_number in doc.get_pages().keys() {
+            match doc.extract_text(&[*page_number]) {
+                Ok(page_text) => {
+                    text.push_str(&page_text);
+                    text.push('\n');
+                }
+                Err(e) => debug!("Skipping page {} for captions: {}", page_number, e),
+            }
+        }
+        text
+    }
+
     /// Parse metadata from extracted text
     fn parse_metadata_from_text(&self, text: &str, extract_refs: bool) -> ExtractedMetadata {
         let mut metadata = ExtractedMetadata {
@@ -551,9 +770,12 @@ impl MetadataExtractor {
             doi: None,
             keywords: Vec::new(),
             references: Vec::new(),
+            captions: Vec::new(),
             volume: None,
             issue: None,
             pages: None,
+            page_count: None,
+            word_count: None,
             confidence_score: 0.0,
             metadata_source: String::new(),
             extracted_at: SystemTime::now(),
@@ -971,7 +1193,12 @@ impl MetadataExtractor {
 
         // Check cache if enabled
         if input.use_cache {
-            if let Some(cached) = self.get_cached_metadata(&file_path).await? {
+            // Entries cached without captions can't answer a caption request
+            if let Some(cached) = self
+                .get_cached_metadata(&file_path)
+                .await?
+                .filter(|cached| !input.extract_captions || !cached.captions.is_empty())
+            {
                 debug!("Returning cached metadata for: {}", input.file_path);
                 let processing_time = start_time.elapsed().unwrap_or_default();
 
@@ -987,7 +1214,7 @@ impl MetadataExtractor {
 
         // Extract metadata from PDF
         let metadata = match self
-            .extract_from_pdf(&file_path, input.extract_references)
+            .extract_from_pdf(&file_path, input.extract_references, input.extract_captions)
             .await
         {
             Ok(mut meta) => {
@@ -1035,6 +1262,7 @@ impl MetadataExtractor {
         files: Vec<String>,
         use_cache: bool,
         validate_external: bool,
+        extract_captions: bool,
     ) -> Result<MetadataResult> {
         let start_time = SystemTime::now();
         let num_files = files.len();
@@ -1044,8 +1272,9 @@ impl MetadataExtractor {
             num_files
         );
 
-        // Use semaphore to limit concurrent extractions (tripled to 12 for CPU-bound work)
-        let semaphore = Arc::new(tokio::sync::Semaphore::new(12));
+        // Use semaphore to limit concurrent extractions (CPU-bound work)
+        let workers = self.config.concurrency.metadata_workers.max(1);
+        let semaphore = Arc::new(tokio::sync::Semaphore::new(workers));
 
         // Process files in parallel using futures::stream
         let results: Vec<MetadataResult> = futures::stream::iter(files.into_iter())
@@ -1078,7 +1307,9 @@ impl MetadataExtractor {
                         use_cache,
                         validate_external,
                         extract_references: false,
+                        extract_captions,
                         batch_files: None,
+                        output_format: MetadataOutputFormat::default(),
                     };
 
                     // Process single file directly without recursion
@@ -1092,7 +1323,7 @@ impl MetadataExtractor {
                     result
                 }
             })
-            .buffer_unordered(12) // Process up to 12 files concurrently
+            .buffer_unordered(workers)
             .collect::<Vec<Result<MetadataResult>>>()
             .await
             .into_iter()
@@ -1158,6 +1389,81 @@ impl MetadataExtractor {
         })
     }
 
+    /// Extract metadata from every PDF in `dir`, and in its subdirectories
+    /// when `recursive`, with at most `workers` files processed at once.
+    /// Hidden files are ignored; other non-PDF files are skipped with a
+    /// warning.
+    #[instrument(skip(self))]
+    pub async fn extract_directory(
+        &self,
+        dir: &Path,
+        recursive: bool,
+        workers: usize,
+    ) -> Result<DirectoryMetadataResult> {
+        if !dir.is_dir() {
+            return Err(crate::Error::InvalidInput {
+                field: "dir".to_string(),
+                reason: format!("Not a directory: {}", dir.display()),
+            });
+        }
+        let start_time = SystemTime::now();
+        let (pdfs, skipped) = collect_directory_files(dir, recursive).await?;
+        for path in &skipped {
+            warn!("Skipping {:?}: not a PDF", path);
+        }
+        info!(
+            "Extracting metadata from {} PDFs in {:?} ({} other files skipped)",
+            pdfs.len(),
+            dir,
+            skipped.len()
+        );
+
+        let results: Vec<MetadataResult> = futures::stream::iter(pdfs)
+            .map(|path| async move {
+                let file_path = path.to_string_lossy().to_string();
+                let input = MetadataInput {
+                    file_path: file_path.clone(),
+                    use_cache: true,
+                    validate_external: false,
+                    extract_references: false,
+                    extract_captions: false,
+                    batch_files: None,
+                    output_format: MetadataOutputFormat::default(),
+                };
+                self.extract_metadata(input)
+                    .await
+                    .unwrap_or_else(|e| MetadataResult {
+                        status: ExtractionStatus::Failed,
+                        metadata: None,
+                        error: Some(e.to_string()),
+                        processing_time_ms: 0,
+                        file_path,
+                    })
+            })
+            .buffered(workers.max(1))
+            .collect()
+            .await;
+
+        let failure_count = results
+            .iter()
+            .filter(|result| matches!(result.status, ExtractionStatus::Failed))
+            .count();
+        let total_time = start_time.elapsed().unwrap_or_default();
+        info!(
+            "Directory metadata extraction completed: {}/{} failed in {:.2}s",
+            failure_count,
+            results.len(),
+            total_time.as_secs_f64()
+        );
+
+        Ok(DirectoryMetadataResult {
+            results,
+            skipped,
+            total_time_ms: total_time.as_millis().try_into().unwrap_or(u64::MAX),
+            failure_count,
+        })
+    }
+
     /// Update extraction statistics
     async fn update_stats(&self, success: bool, cache_hit: bool, time_ms: u64) {
         let mut stats = self.stats.write().await;
@@ -1221,9 +1527,51 @@ impl std::fmt::Debug for MetadataExtractor {
     }
 }
 
+/// PDFs and other files in `dir` (and below it when `recursive`), each in
+/// path order. Hidden entries and symlinks are ignored.
+async fn collect_directory_files(
+    dir: &Path,
+    recursive: bool,
+) -> Result<(Vec<PathBuf>, Vec<PathBuf>)> {
+    let mut pdfs = Vec::new();
+    let mut others = Vec::new();
+    let mut pending = vec![dir.to_path_buf()];
+
+    while let Some(current) = pending.pop() {
+        let mut entries = tokio::fs::read_dir(&current).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            if entry.file_name().to_string_lossy().starts_with('.') {
+                continue;
+            }
+            let file_type = entry.file_type().await?;
+            let path = entry.path();
+            if file_type.is_dir() {
+                if recursive {
+                    pending.push(path);
+                }
+            } else if file_type.is_file() {
+                if path
+                    .extension()
+                    .is_some_and(|e| e.eq_ignore_ascii_case("pdf"))
+                {
+                    pdfs.push(path);
+                } else {
+                    others.push(path);
+                }
+            }
+        }
+    }
+
+    pdfs.sort();
+    others.sort();
+    Ok((pdfs, others))
+}
+
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
     use super::*;
+    use lopdf::content::{Content, Operation};
+    use lopdf::{dictionary, Stream};
     use tempfile::TempDir;
 
     fn create_test_extractor() -> MetadataExtractor {
@@ -1238,7 +1586,9 @@ mod tests {
             use_cache: true,
             validate_external: false,
             extract_references: false,
+            extract_captions: false,
             batch_files: None,
+            output_format: MetadataOutputFormat::default(),
         };
 
         assert!(input.use_cache);
@@ -1267,9 +1617,12 @@ mod tests {
             doi: Some("10.1234/test".to_string()),
             keywords: vec![],
             references: vec![],
+            captions: vec![],
             volume: None,
             issue: None,
             pages: None,
+            page_count: None,
+            word_count: None,
             confidence_score: 0.0,
             metadata_source: "pdf".to_string(),
             extracted_at: SystemTime::now(),
@@ -1288,9 +1641,12 @@ mod tests {
             doi: None,
             keywords: vec![],
             references: vec![],
+            captions: vec![],
             volume: None,
             issue: None,
             pages: None,
+            page_count: None,
+            word_count: None,
             confidence_score: 0.0,
             metadata_source: "pdf".to_string(),
             extracted_at: SystemTime::now(),
@@ -1366,6 +1722,206 @@ References
         assert_eq!(refs[1].year, Some("2022".to_string()));
     }
 
+    /// A one-page PDF showing `lines`
+    pub fn create_sample_pdf(path: &Path, lines: &[&str]) {
+        create_sample_pdf_pages(path, &[lines]);
+    }
+
+    /// A PDF with one page per entry of `pages`, each showing its lines
+    fn create_sample_pdf_pages(path: &Path, pages: &[&[&str]]) {
+        let mut doc = Document::with_version("1.5");
+        let page_tree_id = doc.new_object_id();
+        let font_id = doc.add_object(dictionary! {
+            "Type" => "Font",
+            "Subtype" => "Type1",
+            "BaseFont" => "Helvetica",
+            "Encoding" => "WinAnsiEncoding",
+        });
+        let resources_id = doc.add_object(dictionary! {
+            "Font" => dictionary! { "F1" => font_id },
+        });
+
+        let mut kids: Vec<Object> = Vec::new();
+        for lines in pages {
+            let mut operations = Vec::new();
+            for (i, line) in lines.iter().enumerate() {
+                let y = 780 - 14 * i64::try_from(i).unwrap();
+                operations.push(Operation::new("BT", vec![]));
+                operations.push(Operation::new("Tf", vec!["F1".into(), 10.into()]));
+                operations.push(Operation::new("Td", vec![40.into(), y.into()]));
+                operations.push(Operation::new("Tj", vec![Object::string_literal(*line)]));
+                operations.push(Operation::new("ET", vec![]));
+            }
+            let content = Content { operations };
+            let content_id = doc.add_object(Stream::new(dictionary! {}, content.encode().unwrap()));
+            let page_id = doc.add_object(dictionary! {
+                "Type" => "Page",
+                "Parent" => page_tree_id,
+                "Contents" => content_id,
+            });
+            kids.push(page_id.into());
+        }
+        let count = i64::try_from(kids.len()).unwrap();
+        doc.objects.insert(
+            page_tree_id,
+            Object::Dictionary(dictionary! {
+                "Type" => "Pages",
+                "Kids" => kids,
+                "Count" => count,
+                "Resources" => resources_id,
+                "MediaBox" => vec![0.into(), 0.into(), 595.into(), 842.into()],
+            }),
+        );
+        let catalog_id = doc.add_object(dictionary! {
+            "Type" => "Catalog",
+            "Pages" => page_tree_id,
+        });
+        doc.trailer.set("Root", catalog_id);
+        // Extraction rejects files under 1 KB as truncated
+        doc.add_object(Stream::new(dictionary! {}, vec![b' '; 1024]));
+        doc.save(path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_extract_metadata_collects_captions_in_order() {
+        let extractor = create_test_extractor();
+        let temp_dir = TempDir::new().unwrap();
+        let pdf_path = temp_dir.path().join("captions.pdf");
+        create_sample_pdf(
+            &pdf_path,
+            &[
+                "Scaling Laws for Sparse Attention",
+                "1 Introduction",
+                "Sparse attention reduces the quadratic cost of transformers.",
+                "Figure 2 shows the speedup we measured on long inputs.",
+                "Figure 1: Overview of the block-sparse attention pattern.",
+                "Our method keeps a fixed budget of key blocks per query.",
+                "Table 1. Perplexity on the validation split.",
+                "Model Params PPL",
+                "Fig. 2 - Throughput as sequence length grows.",
+                "Results hold across all model sizes we trained.",
+                "Table 2",
+                "Ablation of the block size.",
+                "As Figure 1: indicates, repeated labels are reported once.",
+                "Figure 1: Overview of the block-sparse attention pattern.",
+                "2 Conclusion",
+                "Sparse attention scales to much longer contexts at equal cost.",
+            ],
+        );
+
+        let input = MetadataInput {
+            file_path: pdf_path.to_string_lossy().to_string(),
+            use_cache: false,
+            validate_external: false,
+            extract_references: false,
+            extract_captions: true,
+            batch_files: None,
+            output_format: MetadataOutputFormat::default(),
+        };
+        let result = extractor.extract_metadata(input).await.unwrap();
+        let captions = result.metadata.unwrap().captions;
+
+        let collected: Vec<(CaptionKind, &str, &str)> = captions
+            .iter()
+            .map(|c| (c.kind, c.label.as_str(), c.text.as_str()))
+            .collect();
+        assert_eq!(
+            collected,
+            vec![
+                (
+                    CaptionKind::Figure,
+                    "1",
+                    "Overview of the block-sparse attention pattern."
+                ),
+                (
+                    CaptionKind::Table,
+                    "1",
+                    "Perplexity on the validation split."
+                ),
+                (
+                    CaptionKind::Figure,
+                    "2",
+                    "Throughput as sequence length grows."
+                ),
+                (CaptionKind::Table, "2", "Ablation of the block size."),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_extract_metadata_counts_pages_and_words() {
+        let extractor = create_test_extractor();
+        let temp_dir = TempDir::new().unwrap();
+        let pdf_path = temp_dir.path().join("three_pages.pdf");
+        create_sample_pdf_pages(
+            &pdf_path,
+            &[
+                &[
+                    "Sparse Attention at Scale",
+                    "Transformers spend most of their time on attention.",
+                ],
+                &["We keep a fixed budget of key blocks per query."],
+                &["Results hold across all model sizes."],
+            ],
+        );
+
+        let input = MetadataInput {
+            file_path: pdf_path.to_string_lossy().to_string(),
+            use_cache: false,
+            validate_external: false,
+            extract_references: false,
+            extract_captions: false,
+            batch_files: None,
+            output_format: MetadataOutputFormat::default(),
+        };
+        let result = extractor.extract_metadata(input).await.unwrap();
+        let metadata = result.metadata.unwrap();
+
+        assert_eq!(metadata.page_count, Some(3));
+        assert_eq!(metadata.word_count, Some(28));
+    }
+
+    #[tokio::test]
+    async fn test_extract_directory_processes_pdfs_and_skips_others() {
+        let extractor = create_test_extractor();
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        create_sample_pdf(
+            &root.join("attention.pdf"),
+            &[
+                "Sparse Attention at Scale",
+                "Attention is cheaper when sparse.",
+            ],
+        );
+        std::fs::create_dir(root.join("nested")).unwrap();
+        create_sample_pdf(
+            &root.join("nested/folding.pdf"),
+            &["Protein Folding Dynamics", "Proteins fold in microseconds."],
+        );
+        std::fs::write(root.join("notes.txt"), "not a paper").unwrap();
+
+        let result = extractor.extract_directory(root, true, 2).await.unwrap();
+        let processed: Vec<&str> = result
+            .results
+            .iter()
+            .map(|r| r.file_path.as_str())
+            .collect();
+        assert_eq!(
+            processed,
+            vec![
+                root.join("attention.pdf").to_str().unwrap(),
+                root.join("nested/folding.pdf").to_str().unwrap(),
+            ]
+        );
+        assert!(result.results.iter().all(|r| r.metadata.is_some()));
+        assert_eq!(result.failure_count, 0);
+        assert_eq!(result.skipped, vec![root.join("notes.txt")]);
+
+        // Without recursion the nested PDF is left out
+        let result = extractor.extract_directory(root, false, 2).await.unwrap();
+        assert_eq!(result.results.len(), 1);
+    }
+
     #[test]
     fn test_extraction_status_serialization() {
         let status = ExtractionStatus::Success;
@@ -1,17 +1,37 @@
 pub mod bibliography;
+pub mod bibtex_validation;
 pub mod categorize;
+pub mod cleanup;
 pub mod code_search;
 // pub mod command;
 // pub mod command_examples;
 pub mod download;
+pub mod export;
+pub mod library;
 pub mod metadata;
+pub mod paper_text;
+pub mod recategorize;
+pub mod references;
+pub mod resolve_doi;
 pub mod search;
+pub mod search_diff;
+pub mod signals;
 
 pub use bibliography::BibliographyTool;
+pub use bibtex_validation::BibtexValidationTool;
 pub use categorize::CategorizeTool;
+pub use cleanup::CleanupTool;
 pub use code_search::CodeSearchTool;
 // pub use command::{Command, CommandExecutor, CommandResult, ExecutionContext};
 // pub use command_examples::CommandPatternDemo;
 pub use download::DownloadTool;
+pub use export::ExportLibraryTool;
+pub use library::LibraryTool;
 pub use metadata::MetadataExtractor;
+pub use paper_text::PaperTextTool;
+pub use recategorize::RecategorizeTool;
+pub use references::ReferenceExtractionTool;
+pub use resolve_doi::ResolveDoiTool;
 pub use search::SearchTool;
+pub use search_diff::SearchDiffTool;
+pub use signals::PaperSignalsTool;
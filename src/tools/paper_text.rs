@@ -0,0 +1,213 @@
+use crate::tools::download::{DownloadInput, DownloadTool};
+use crate::tools::references::extract_pdf_text;
+use crate::Result;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::Arc;
+use tracing::{debug, info, instrument, warn};
+
+/// Characters returned per call unless the request asks for fewer
+pub const DEFAULT_MAX_CHARS: usize = 50_000;
+/// Upper bound on `max_chars`, keeping a single response a manageable size
+pub const MAX_CHARS_LIMIT: usize = 200_000;
+
+/// Input for reading the text of a paper without keeping its PDF
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PaperTextInput {
+    /// DOI of the paper
+    #[serde(default)]
+    pub doi: Option<String>,
+    /// Direct URL of the PDF, instead of a DOI
+    #[serde(default)]
+    pub url: Option<String>,
+    /// Character to start from, to read past the end of an earlier call
+    #[serde(default)]
+    pub offset: usize,
+    /// Most characters to return (default 50000, at most 200000)
+    #[serde(default)]
+    pub max_chars: Option<usize>,
+}
+
+/// Text of a paper, or one page of it
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PaperTextResult {
+    /// DOI the PDF was found for
+    pub doi: Option<String>,
+    pub title: Option<String>,
+    /// Provider that supplied the PDF
+    pub source: Option<String>,
+    /// Characters of text in the whole paper
+    pub total_chars: usize,
+    /// Character the returned text starts at
+    pub offset: usize,
+    pub text: String,
+    /// Offset to pass for the rest of the text, when it was cut short
+    pub next_offset: Option<usize>,
+}
+
+/// Downloads a paper's PDF to a temporary file, returns its text and deletes
+/// the file again
+#[derive(Debug, Clone)]
+pub struct PaperTextTool {
+    download_tool: Arc<DownloadTool>,
+    /// Where PDFs are kept while their text is extracted
+    temp_dir: PathBuf,
+}
+
+impl PaperTextTool {
+    /// Create a paper text tool fetching PDFs through `download_tool` into
+    /// the system temp directory
+    #[must_use]
+    pub fn new(download_tool: Arc<DownloadTool>) -> Self {
+        Self {
+            download_tool,
+            temp_dir: std::env::temp_dir(),
+        }
+    }
+
+    /// Keep PDFs in `temp_dir` instead of the system temp directory
+    #[must_use]
+    pub fn with_temp_dir(mut self, temp_dir: PathBuf) -> Self {
+        self.temp_dir = temp_dir;
+        self
+    }
+
+    /// Fetch the PDF, extract its text and return the requested slice
+    #[instrument(skip(self), fields(doi = ?input.doi, url = ?input.url))]
+    pub async fn get_text(&self, input: PaperTextInput) -> Result<PaperTextResult> {
+        let max_chars = input.max_chars.unwrap_or(DEFAULT_MAX_CHARS);
+        if max_chars == 0 || max_chars > MAX_CHARS_LIMIT {
+            return Err(crate::Error::InvalidInput {
+                field: "max_chars".to_string(),
+                reason: format!("max_chars must be between 1 and {MAX_CHARS_LIMIT}"),
+            });
+        }
+
+        let download_input = DownloadInput {
+            doi: input.doi.clone(),
+            url: input.url.clone(),
+            filename: None,
+            directory: None,
+            category: None,
+            overwrite: true,
+            verify_integrity: Some(false),
+            title: None,
+            write_sidecar: Some(false),
+            headers: None,
+        };
+        let temp_path = self
+            .temp_dir
+            .join(format!("rust-research-mcp-{}.pdf", uuid::Uuid::new_v4()));
+        let downloaded = self
+            .download_tool
+            .download_transient(&download_input, &temp_path)
+            .await?;
+
+        let extracted = {
+            let path = temp_path.clone();
+            tokio::task::spawn_blocking(move || extract_pdf_text(&path))
+                .await
+                .map_err(|e| crate::Error::Service(format!("Text extraction task failed: {e}")))
+        };
+        // The text is all the caller gets; the PDF never outlives the call
+        if let Err(e) = tokio::fs::remove_file(&temp_path).await {
+            warn!("Failed to remove temporary PDF {:?}: {}", temp_path, e);
+        }
+        let text = extracted??;
+
+        let total_chars = text.chars().count();
+        let page: String = text.chars().skip(input.offset).take(max_chars).collect();
+        let end = input.offset.saturating_add(max_chars);
+        let next_offset = (end < total_chars).then_some(end);
+        debug!(
+            "Returning characters {}..{} of {}",
+            input.offset,
+            end.min(total_chars),
+            total_chars
+        );
+
+        let metadata = downloaded.metadata;
+        info!(
+            "Extracted {} characters of text from {:?}",
+            total_chars, downloaded.download_url
+        );
+        Ok(PaperTextResult {
+            doi: metadata
+                .as_ref()
+                .map(|m| m.doi.clone())
+                .filter(|doi| !doi.is_empty())
+                .or(input.doi),
+            title: metadata.and_then(|m| m.title),
+            source: downloaded.source_provider,
+            total_chars,
+            offset: input.offset,
+            text: page,
+            next_offset,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::MetaSearchClient;
+    use crate::tools::metadata::tests::create_sample_pdf;
+    use crate::Config;
+    use tempfile::TempDir;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_get_text_mock_pdf_returns_text_and_removes_file() {
+        let source_dir = TempDir::new().unwrap();
+        let pdf_path = source_dir.path().join("paper.pdf");
+        create_sample_pdf(
+            &pdf_path,
+            &["Transient Reading of Papers", "The quick brown fox."],
+        );
+        let pdf = std::fs::read(&pdf_path).unwrap();
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/paper.pdf"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_bytes(pdf)
+                    .insert_header("content-type", "application/pdf"),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let download_dir = TempDir::new().unwrap();
+        let mut config = Config::default();
+        config.downloads.directory = download_dir.path().to_path_buf();
+        let config = Arc::new(config);
+        let client = Arc::new(MetaSearchClient::with_providers(
+            Vec::new(),
+            crate::client::MetaSearchConfig::default(),
+        ));
+        let temp_dir = TempDir::new().unwrap();
+        let tool = PaperTextTool::new(Arc::new(DownloadTool::new(client, config).unwrap()))
+            .with_temp_dir(temp_dir.path().to_path_buf());
+
+        let result = tool
+            .get_text(PaperTextInput {
+                doi: None,
+                url: Some(format!("{}/paper.pdf", mock_server.uri())),
+                offset: 0,
+                max_chars: None,
+            })
+            .await
+            .unwrap();
+
+        assert!(result.text.contains("Transient Reading of Papers"));
+        assert!(result.text.contains("The quick brown fox."));
+        assert_eq!(result.total_chars, result.text.chars().count());
+        assert_eq!(result.next_offset, None);
+
+        // Nothing is kept, neither in the download directory nor in temp
+        assert_eq!(std::fs::read_dir(download_dir.path()).unwrap().count(), 0);
+        assert_eq!(std::fs::read_dir(temp_dir.path()).unwrap().count(), 0);
+    }
+}
@@ -0,0 +1,299 @@
+use crate::client::PaperMetadata;
+use crate::services::CategorizationService;
+use crate::{Config, Result};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tracing::{debug, info, instrument, warn};
+
+/// Input for moving downloads into the categories the current rules assign
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct RecategorizeDownloadsInput {
+    /// Only report which files would move
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+/// A downloaded paper moved (or, in a dry run, that would be moved) to
+/// another category directory
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct RecategorizedFile {
+    /// Where the PDF was
+    pub from: PathBuf,
+    /// Where the PDF is now; its sidecar moves with it
+    pub to: PathBuf,
+    /// Category the paper now belongs to
+    pub category: String,
+}
+
+/// Outcome of re-categorizing the download directory
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct RecategorizeDownloadsResult {
+    /// Papers whose category changed
+    pub moved: Vec<RecategorizedFile>,
+    /// Papers already in the directory of their category
+    pub unchanged: usize,
+    /// PDFs left in place because they have no metadata sidecar, or no
+    /// category could be inferred from it
+    pub skipped: Vec<PathBuf>,
+    /// Whether nothing was actually moved
+    pub dry_run: bool,
+}
+
+/// Re-runs automatic categorization over downloaded papers, using the
+/// metadata sidecars written next to them.
+///
+/// Each PDF and its sidecar move into the directory of its new category. A
+/// file already present under the same name gets a numbered suffix instead of
+/// being overwritten.
+#[derive(Clone)]
+pub struct RecategorizeTool {
+    config: Arc<Config>,
+    categorization_service: CategorizationService,
+}
+
+impl std::fmt::Debug for RecategorizeTool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RecategorizeTool")
+            .field("config", &"Config")
+            .field("categorization_service", &"CategorizationService")
+            .finish()
+    }
+}
+
+impl RecategorizeTool {
+    /// Create a re-categorization tool for the configured download directory
+    pub fn new(config: Arc<Config>) -> Result<Self> {
+        let categorization_service = CategorizationService::new(config.categorization.clone())?;
+        Ok(Self {
+            config,
+            categorization_service,
+        })
+    }
+
+    /// Move every categorizable download into its category directory
+    #[instrument(skip(self))]
+    pub async fn recategorize(
+        &self,
+        input: RecategorizeDownloadsInput,
+    ) -> Result<RecategorizeDownloadsResult> {
+        if !self.categorization_service.is_enabled() {
+            return Err(crate::Error::InvalidInput {
+                field: "categorization.enabled".to_string(),
+                reason: "Categorization is disabled in the configuration".to_string(),
+            });
+        }
+
+        let root = &self.config.downloads.directory;
+        let pdfs = if root.is_dir() {
+            collect_pdfs(root, self.config.downloads.quarantine_dir.as_deref()).await?
+        } else {
+            Vec::new()
+        };
+
+        let mut result = RecategorizeDownloadsResult {
+            moved: Vec::new(),
+            unchanged: 0,
+            skipped: Vec::new(),
+            dry_run: input.dry_run,
+        };
+        let mut vacated = BTreeSet::new();
+
+        for pdf in pdfs {
+            let Some(category) = self.category_for(&pdf).await else {
+                result.skipped.push(pdf);
+                continue;
+            };
+            let category = self
+                .categorization_service
+                .resolve_category_conflict(root, &category);
+            let target_dir = root.join(&category);
+            if pdf.parent() == Some(target_dir.as_path()) {
+                result.unchanged += 1;
+                continue;
+            }
+
+            let target = free_target_path(&target_dir, &pdf);
+            if !input.dry_run {
+                if let Err(e) = move_with_sidecar(&pdf, &target).await {
+                    warn!("Failed to move {:?} to {:?}: {}", pdf, target, e);
+                    result.skipped.push(pdf);
+                    continue;
+                }
+                debug!("Moved {:?} to {:?}", pdf, target);
+                if let Some(parent) = pdf.parent().filter(|parent| *parent != root.as_path()) {
+                    vacated.insert(parent.to_path_buf());
+                }
+            }
+            result.moved.push(RecategorizedFile {
+                from: pdf,
+                to: target,
+                category,
+            });
+        }
+
+        // Old category directories that are now empty go away
+        for dir in vacated {
+            if tokio::fs::remove_dir(&dir).await.is_ok() {
+                debug!("Removed empty category directory {:?}", dir);
+            }
+        }
+
+        info!(
+            "{} {} papers into new categories ({} unchanged, {} skipped)",
+            if input.dry_run { "Would move" } else { "Moved" },
+            result.moved.len(),
+            result.unchanged,
+            result.skipped.len()
+        );
+        Ok(result)
+    }
+
+    /// Category inferred from the metadata sidecar next to `pdf`
+    async fn category_for(&self, pdf: &Path) -> Option<String> {
+        let sidecar = pdf.with_extension("json");
+        let contents = tokio::fs::read(&sidecar).await.ok()?;
+        let metadata: PaperMetadata = match serde_json::from_slice(&contents) {
+            Ok(metadata) => metadata,
+            Err(e) => {
+                warn!("Ignoring unreadable sidecar {:?}: {}", sidecar, e);
+                return None;
+            }
+        };
+        self.categorization_service.infer_category(&metadata)
+    }
+}
+
+/// `pdf`'s file name in `dir`, numbered (`name_2.pdf`, ...) when that name
+/// or its sidecar is taken
+fn free_target_path(dir: &Path, pdf: &Path) -> PathBuf {
+    let stem = pdf
+        .file_stem()
+        .map_or_else(|| "paper".to_string(), |s| s.to_string_lossy().to_string());
+    let extension = pdf
+        .extension()
+        .map_or_else(|| "pdf".to_string(), |e| e.to_string_lossy().to_string());
+
+    let mut candidate = dir.join(format!("{stem}.{extension}"));
+    let mut counter = 2;
+    while candidate.exists() || candidate.with_extension("json").exists() {
+        candidate = dir.join(format!("{stem}_{counter}.{extension}"));
+        counter += 1;
+    }
+    candidate
+}
+
+/// Move `pdf` to `target`, and its `.json` sidecar alongside it
+async fn move_with_sidecar(pdf: &Path, target: &Path) -> std::io::Result<()> {
+    if let Some(parent) = target.parent() {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+    tokio::fs::rename(pdf, target).await?;
+
+    let sidecar = pdf.with_extension("json");
+    if sidecar.exists() {
+        tokio::fs::rename(&sidecar, target.with_extension("json")).await?;
+    }
+    Ok(())
+}
+
+/// PDFs below `dir`, skipping hidden files, symlinks and anything inside
+/// `quarantine_dir`, in path order
+pub(crate) async fn collect_pdfs(
+    dir: &Path,
+    quarantine_dir: Option<&Path>,
+) -> Result<Vec<PathBuf>> {
+    let mut pdfs = Vec::new();
+    let mut pending = vec![dir.to_path_buf()];
+
+    while let Some(current) = pending.pop() {
+        if quarantine_dir.is_some_and(|quarantine| current.starts_with(quarantine)) {
+            continue;
+        }
+
+        let mut entries = tokio::fs::read_dir(&current).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            if entry.file_name().to_string_lossy().starts_with('.') {
+                continue;
+            }
+            let file_type = entry.file_type().await?;
+            let path = entry.path();
+            if file_type.is_dir() {
+                pending.push(path);
+            } else if file_type.is_file()
+                && path
+                    .extension()
+                    .is_some_and(|e| e.eq_ignore_ascii_case("pdf"))
+            {
+                pdfs.push(path);
+            }
+        }
+    }
+
+    pdfs.sort();
+    Ok(pdfs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn write_paper(path: &Path, title: &str, journal: &str) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, b"%PDF-1.4\n%%EOF\n").unwrap();
+        let mut metadata = PaperMetadata::new("10.1000/test".to_string());
+        metadata.title = Some(title.to_string());
+        metadata.journal = Some(journal.to_string());
+        fs::write(
+            path.with_extension("json"),
+            serde_json::to_vec(&metadata).unwrap(),
+        )
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_recategorize_moves_paper_to_new_category() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let old = root.join("research_papers/folding.pdf");
+        write_paper(&old, "Protein folding dynamics", "Cell");
+        // Same name already taken in the new category
+        write_paper(
+            &root.join("Biology/folding.pdf"),
+            "Gene regulation",
+            "Genome Biology",
+        );
+        fs::write(root.join("research_papers/notes.pdf"), b"%PDF-1.4\n").unwrap();
+
+        let mut config = Config::default();
+        config.downloads.directory = root.to_path_buf();
+        let tool = RecategorizeTool::new(Arc::new(config)).unwrap();
+
+        let preview = tool
+            .recategorize(RecategorizeDownloadsInput { dry_run: true })
+            .await
+            .unwrap();
+        assert_eq!(preview.moved.len(), 1);
+        assert!(old.exists());
+
+        let result = tool
+            .recategorize(RecategorizeDownloadsInput::default())
+            .await
+            .unwrap();
+        let expected = root.join("Biology/folding_2.pdf");
+        assert_eq!(result.moved.len(), 1);
+        assert_eq!(result.moved[0].from, old);
+        assert_eq!(result.moved[0].to, expected);
+        assert_eq!(result.moved[0].category, "Biology");
+        assert_eq!(result.unchanged, 1);
+        assert_eq!(result.skipped, vec![root.join("research_papers/notes.pdf")]);
+        assert!(!old.exists());
+        assert!(expected.exists());
+        assert!(expected.with_extension("json").exists());
+        assert!(root.join("Biology/folding.pdf").exists());
+    }
+}
@@ -0,0 +1,525 @@
+use crate::tools::metadata::Reference;
+use crate::{Config, Result};
+use lopdf::Document;
+use regex::Regex;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tracing::{debug, info, instrument, warn};
+
+/// Section headings that start a reference list
+const REFERENCE_HEADINGS: &[&str] = &[
+    "references",
+    "reference list",
+    "bibliography",
+    "literature cited",
+    "works cited",
+    "cited literature",
+];
+
+/// Headings that end the reference list when they follow it
+const TRAILING_HEADINGS: &[&str] = &[
+    "appendix",
+    "appendices",
+    "acknowledgment",
+    "acknowledgments",
+    "acknowledgement",
+    "acknowledgements",
+    "supplementary material",
+    "supplementary information",
+    "author contributions",
+    "about the authors",
+];
+
+/// Upper bound on references returned for a single paper
+const MAX_REFERENCES: usize = 500;
+
+/// Regex patterns for splitting and parsing reference entries
+#[derive(Debug, Clone)]
+struct ReferencePatterns {
+    doi: Regex,
+    year: Regex,
+    /// `[12]`, `12.` or `12)` at the start of a line
+    numbered_marker: Regex,
+    /// `Surname, X.` / `Surname X,` at the start of a line (author-year styles)
+    author_start: Regex,
+}
+
+impl Default for ReferencePatterns {
+    fn default() -> Self {
+        Self {
+            doi: Regex::new(r#"(?i)\b(10\.\d{4,9}/[^\s"<>]+)"#).unwrap(),
+            year: Regex::new(r"\b((?:19|20)\d{2})[a-z]?\b").unwrap(),
+            numbered_marker: Regex::new(r"^\s*(?:\[(\d{1,3})\]|(\d{1,3})[.)])\s+\S").unwrap(),
+            author_start: Regex::new(
+                r"^\s*(?:van |von |de |del |da |di |le |la )?[A-Z][\p{L}'’\-]+(?:,\s*[A-Z]|\s+[A-Z]{1,3}[,.])",
+            )
+            .unwrap(),
+        }
+    }
+}
+
+/// Input parameters for reference extraction
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ReferenceExtractionInput {
+    /// Path to the PDF
+    #[schemars(
+        description = "Path to a downloaded PDF. Relative paths are resolved against the download directory."
+    )]
+    pub file_path: String,
+}
+
+/// References extracted from a PDF
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ReferenceExtractionResult {
+    /// Resolved path of the PDF
+    pub file_path: String,
+    /// Whether a references/bibliography heading was found
+    pub section_found: bool,
+    /// Parsed references, in document order
+    pub references: Vec<Reference>,
+    /// Number of references with a DOI
+    pub doi_count: usize,
+}
+
+/// Tool that lists the references of a downloaded paper
+#[derive(Debug, Clone)]
+pub struct ReferenceExtractionTool {
+    config: Arc<Config>,
+    patterns: ReferencePatterns,
+}
+
+impl ReferenceExtractionTool {
+    /// Create a new reference extraction tool
+    #[must_use]
+    pub fn new(config: Arc<Config>) -> Self {
+        Self {
+            config,
+            patterns: ReferencePatterns::default(),
+        }
+    }
+
+    /// Extract the reference list from a PDF
+    #[instrument(skip(self), fields(file = %input.file_path))]
+    pub async fn extract_references(
+        &self,
+        input: ReferenceExtractionInput,
+    ) -> Result<ReferenceExtractionResult> {
+        let path = self.resolve_path(&input.file_path);
+        if !path.is_file() {
+            return Err(crate::Error::InvalidInput {
+                field: "file_path".to_string(),
+                reason: format!("File not found: {}", path.display()),
+            });
+        }
+
+        let text = {
+            let path = path.clone();
+            tokio::task::spawn_blocking(move || extract_pdf_text(&path))
+                .await
+                .map_err(|e| {
+                    crate::Error::Service(format!("Reference extraction task failed: {e}"))
+                })??
+        };
+
+        let section = find_reference_section(&text);
+        if section.is_none() {
+            warn!("No references section found in {:?}", path);
+        }
+        let references = section
+            .map(|s| self.parse_references(s))
+            .unwrap_or_default();
+        let doi_count = references.iter().filter(|r| r.doi.is_some()).count();
+
+        info!(
+            "Extracted {} references ({} with DOI) from {:?}",
+            references.len(),
+            doi_count,
+            path
+        );
+
+        Ok(ReferenceExtractionResult {
+            file_path: path.display().to_string(),
+            section_found: section.is_some(),
+            references,
+            doi_count,
+        })
+    }
+
+    /// Resolve relative paths against the download directory
+    fn resolve_path(&self, file_path: &str) -> PathBuf {
+        let path = PathBuf::from(file_path);
+        if path.is_absolute() || path.exists() {
+            path
+        } else {
+            self.config.downloads.directory.join(path)
+        }
+    }
+
+    /// Split a reference section into entries and parse each one
+    #[must_use]
+    pub fn parse_references(&self, section: &str) -> Vec<Reference> {
+        self.patterns
+            .split_references(section)
+            .into_iter()
+            .take(MAX_REFERENCES)
+            .enumerate()
+            .map(|(index, (label, raw_text))| self.patterns.parse_reference(index, label, raw_text))
+            .collect()
+    }
+}
+
+/// Extract the text of every page, skipping pages lopdf cannot decode
+pub(crate) fn extract_pdf_text(path: &Path) -> Result<String> {
+    let doc = Document::load(path).map_err(|e| crate::Error::Parse {
+        context: "PDF loading".to_string(),
+        message: e.to_string(),
+    })?;
+
+    let mut text = String::new();
+    for page_number in doc.get_pages().keys() {
+        match doc.extract_text(&[*page_number]) {
+            Ok(page_text) => {
+                text.push_str(&page_text);
+                text.push('\n');
+            }
+            Err(e) => debug!("Skipping page {} of {:?}: {}", page_number, path, e),
+        }
+    }
+
+    if text.trim().is_empty() {
+        return Err(crate::Error::Parse {
+            context: "PDF text extraction".to_string(),
+            message: "No text content found in PDF".to_string(),
+        });
+    }
+    Ok(text)
+}
+
+/// Normalize a line for heading comparison: strip section numbers and
+/// punctuation, lowercase
+fn heading_key(line: &str) -> String {
+    line.trim()
+        .trim_start_matches(|c: char| c.is_ascii_digit() || c == '.' || c.is_whitespace())
+        .trim_end_matches([':', '.'])
+        .trim()
+        .to_lowercase()
+}
+
+/// Locate the reference list: the text after the last references heading, up
+/// to the next trailing section heading. The last heading is used because a
+/// table of contents may also mention "References".
+#[must_use]
+pub fn find_reference_section(text: &str) -> Option<&str> {
+    let mut offset = 0;
+    let mut start = None;
+    for line in text.split_inclusive('\n') {
+        if REFERENCE_HEADINGS.contains(&heading_key(line).as_str()) {
+            start = Some(offset + line.len());
+        }
+        offset += line.len();
+    }
+
+    let start = start?;
+    let section = &text[start..];
+    let mut end = section.len();
+    let mut offset = 0;
+    for line in section.split_inclusive('\n') {
+        let key = heading_key(line);
+        let key = key
+            .strip_prefix("appendix ")
+            .map_or(key.as_str(), |_| "appendix");
+        if TRAILING_HEADINGS.contains(&key) {
+            end = offset;
+            break;
+        }
+        offset += line.len();
+    }
+
+    let section = &section[..end];
+    (!section.trim().is_empty()).then_some(section)
+}
+
+/// Whether an entry looks complete: it ends a sentence or with a DOI/URL
+fn ends_entry(text: &str) -> bool {
+    text.ends_with('.')
+        || text
+            .rsplit(char::is_whitespace)
+            .next()
+            .is_some_and(|word| word.contains("10.") || word.contains("://"))
+}
+
+/// Join a continuation line, undoing hyphenation and keeping DOIs and URLs
+/// that were wrapped at a slash or hyphen intact
+fn append_line(text: &mut String, line: &str) {
+    if text.ends_with('/') || (text.ends_with('-') && !text.ends_with(" -")) {
+        let wrapped_identifier = text
+            .rsplit(char::is_whitespace)
+            .next()
+            .is_some_and(|word| word.contains("10.") || word.contains("://"));
+        if !wrapped_identifier && text.ends_with('-') {
+            text.pop();
+        }
+    } else {
+        text.push(' ');
+    }
+    text.push_str(line);
+}
+
+impl ReferencePatterns {
+    /// Split into `(label, text)` entries. Numbered styles split on the markers;
+    /// otherwise a new entry starts at blank lines or lines that begin with an
+    /// author name after a line that ended a sentence.
+    fn split_references(&self, section: &str) -> Vec<(Option<String>, String)> {
+        let lines: Vec<&str> = section
+            .lines()
+            .map(str::trim_end)
+            .skip_while(|l| l.trim().is_empty())
+            .collect();
+        let numbered = lines
+            .iter()
+            .filter(|l| self.numbered_marker.is_match(l))
+            .count();
+        let non_empty = lines.iter().filter(|l| !l.trim().is_empty()).count();
+        let use_numbers = numbered >= 2 && numbered * 4 >= non_empty;
+
+        let mut entries: Vec<(Option<String>, String)> = Vec::new();
+        let mut current: Option<(Option<String>, String)> = None;
+        let mut previous_blank = true;
+
+        for line in lines {
+            let trimmed = line.trim();
+            if trimmed.is_empty() {
+                previous_blank = true;
+                continue;
+            }
+
+            let starts_entry = if use_numbers {
+                self.numbered_marker.is_match(trimmed)
+            } else {
+                previous_blank
+                    || (self.author_start.is_match(trimmed)
+                        && current.as_ref().is_some_and(|(_, text)| ends_entry(text)))
+            };
+            previous_blank = false;
+
+            if starts_entry || current.is_none() {
+                entries.extend(current.take());
+                let (label, text) = if use_numbers {
+                    self.split_marker(trimmed)
+                } else {
+                    (None, trimmed.to_string())
+                };
+                current = Some((label, text));
+            } else if let Some((_, text)) = current.as_mut() {
+                append_line(text, trimmed);
+            }
+        }
+        entries.extend(current);
+
+        entries
+            .into_iter()
+            .filter(|(_, text)| text.len() >= 10)
+            .collect()
+    }
+
+    /// Separate a leading `[n]` / `n.` marker from the reference text
+    fn split_marker(&self, line: &str) -> (Option<String>, String) {
+        let Some(captures) = self.numbered_marker.captures(line) else {
+            return (None, line.to_string());
+        };
+        let label = captures
+            .get(1)
+            .or_else(|| captures.get(2))
+            .map(|m| m.as_str().to_string());
+        let marker_end = captures.get(0).map_or(0, |m| m.end());
+        // The match includes the first character of the reference text
+        let text_start = line[..marker_end]
+            .char_indices()
+            .last()
+            .map_or(0, |(i, _)| i);
+        (label, line[text_start..].trim().to_string())
+    }
+
+    fn parse_reference(&self, index: usize, label: Option<String>, raw_text: String) -> Reference {
+        let doi = self.doi.captures(&raw_text).and_then(|captures| {
+            let doi = captures
+                .get(1)?
+                .as_str()
+                .trim_end_matches(['.', ',', ';', ')', ']']);
+            Some(doi.to_string())
+        });
+
+        let year_match = self.year.captures(&raw_text).and_then(|c| c.get(1));
+        let year = year_match.map(|m| m.as_str().to_string());
+
+        // Author-year styles put the authors before a parenthesized year and the
+        // title right after it; numbered styles are too varied to split
+        let (authors, title) = match year_match {
+            Some(m) if raw_text[..m.start()].trim_end().ends_with('(') => {
+                let authors = raw_text[..m.start()]
+                    .trim_end_matches(|c: char| c == '(' || c == ',' || c.is_whitespace());
+                let after = raw_text[m.end()..]
+                    .trim_start_matches(|c: char| c.is_ascii_lowercase())
+                    .trim_start_matches([')', '.', ',', ':', ' ']);
+                let title = after
+                    .split_once(". ")
+                    .map(|(title, _)| title.trim())
+                    .filter(|title| title.len() > 10);
+                (
+                    (!authors.is_empty()).then(|| authors.to_string()),
+                    title.map(str::to_string),
+                )
+            }
+            _ => (None, None),
+        };
+
+        Reference {
+            id: label.unwrap_or_else(|| (index + 1).to_string()),
+            raw_text,
+            title,
+            authors,
+            year,
+            journal: None,
+            doi,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lopdf::content::{Content, Operation};
+    use lopdf::{dictionary, Object, Stream};
+    use tempfile::TempDir;
+
+    /// Write a one-page PDF with one text line per `BT`/`ET` block
+    fn create_sample_pdf(path: &Path, lines: &[&str]) {
+        let mut doc = Document::with_version("1.5");
+        let page_tree_id = doc.new_object_id();
+        let font_id = doc.add_object(dictionary! {
+            "Type" => "Font",
+            "Subtype" => "Type1",
+            "BaseFont" => "Helvetica",
+            "Encoding" => "WinAnsiEncoding",
+        });
+        let resources_id = doc.add_object(dictionary! {
+            "Font" => dictionary! { "F1" => font_id },
+        });
+
+        let mut operations = Vec::new();
+        for (i, line) in lines.iter().enumerate() {
+            let y = 780 - 14 * i64::try_from(i).unwrap();
+            operations.push(Operation::new("BT", vec![]));
+            operations.push(Operation::new("Tf", vec!["F1".into(), 10.into()]));
+            operations.push(Operation::new("Td", vec![40.into(), y.into()]));
+            operations.push(Operation::new("Tj", vec![Object::string_literal(*line)]));
+            operations.push(Operation::new("ET", vec![]));
+        }
+        let content = Content { operations };
+        let content_id = doc.add_object(Stream::new(dictionary! {}, content.encode().unwrap()));
+        let page_id = doc.add_object(dictionary! {
+            "Type" => "Page",
+            "Parent" => page_tree_id,
+            "Contents" => content_id,
+        });
+        doc.objects.insert(
+            page_tree_id,
+            Object::Dictionary(dictionary! {
+                "Type" => "Pages",
+                "Kids" => vec![page_id.into()],
+                "Count" => 1,
+                "Resources" => resources_id,
+                "MediaBox" => vec![0.into(), 0.into(), 595.into(), 842.into()],
+            }),
+        );
+        let catalog_id = doc.add_object(dictionary! {
+            "Type" => "Catalog",
+            "Pages" => page_tree_id,
+        });
+        doc.trailer.set("Root", catalog_id);
+        doc.save(path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_extract_references_from_sample_pdf() {
+        let temp_dir = TempDir::new().unwrap();
+        let pdf_path = temp_dir.path().join("sample.pdf");
+        create_sample_pdf(
+            &pdf_path,
+            &[
+                "Learning Things from Data",
+                "1 Introduction",
+                "Prior work [1, 2] studied this; see also [3].",
+                "References",
+                "[1] Y. LeCun, Y. Bengio, and G. Hinton. Deep learning.",
+                "Nature, 521(7553):436-444, 2015. doi:10.1038/nature14539.",
+                "[2] A. Vaswani et al. Attention is all you need. In NeurIPS, 2017.",
+                "[3] K. He, X. Zhang, S. Ren, and J. Sun. Deep residual learning",
+                "for image recognition. In CVPR, pages 770-778, 2016.",
+                "Appendix A",
+                "[4] This numbered line belongs to the appendix.",
+            ],
+        );
+
+        let mut config = Config::default();
+        config.downloads.directory = temp_dir.path().to_path_buf();
+        let tool = ReferenceExtractionTool::new(Arc::new(config));
+
+        let result = tool
+            .extract_references(ReferenceExtractionInput {
+                file_path: "sample.pdf".to_string(),
+            })
+            .await
+            .unwrap();
+
+        assert!(result.section_found);
+        assert_eq!(result.references.len(), 3);
+        assert_eq!(result.doi_count, 1);
+        assert_eq!(
+            result.references[0].doi.as_deref(),
+            Some("10.1038/nature14539")
+        );
+        assert_eq!(result.references[2].id, "3");
+        assert!(result.references[2]
+            .raw_text
+            .contains("Deep residual learning for image recognition"));
+    }
+
+    #[tokio::test]
+    async fn test_extract_references_missing_file_error() {
+        let tool = ReferenceExtractionTool::new(Arc::new(Config::default()));
+        let result = tool
+            .extract_references(ReferenceExtractionInput {
+                file_path: "/nonexistent/paper.pdf".to_string(),
+            })
+            .await;
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_references_author_year_style() {
+        let text = "Introduction\nSee Smith (2019).\n\nREFERENCES\n\
+            Smith, J., & Doe, A. (2019). A study of citation graphs. Journal of\n\
+            Informetrics, 13(2), 1-20. https://doi.org/10.1016/j.joi.2019.01.002\n\
+            Zhang, L. (2021). Snowball sampling for literature reviews. Research\n\
+            Synthesis Methods, 12, 33-45.\n";
+
+        let section = find_reference_section(text).unwrap();
+        let tool = ReferenceExtractionTool::new(Arc::new(Config::default()));
+        let references = tool.parse_references(section);
+
+        assert_eq!(references.len(), 2);
+        assert_eq!(
+            references[0].doi.as_deref(),
+            Some("10.1016/j.joi.2019.01.002")
+        );
+        assert_eq!(references[0].year.as_deref(), Some("2019"));
+        assert_eq!(
+            references[1].title.as_deref(),
+            Some("Snowball sampling for literature reviews")
+        );
+        assert_eq!(references[1].authors.as_deref(), Some("Zhang, L."));
+    }
+}
@@ -0,0 +1,282 @@
+use crate::client::providers::{SearchQuery, SearchType};
+use crate::client::{MetaSearchClient, MetaSearchResult, PaperMetadata};
+use crate::Result;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use tracing::{debug, info, instrument};
+
+/// Results requested from the providers for one title
+const SEARCH_LIMIT: u32 = 10;
+/// Candidates listed when the title is ambiguous
+const MAX_CANDIDATES: usize = 5;
+/// Score a match needs before its DOI is returned on its own
+const CONFIDENT_SCORE: f64 = 0.9;
+/// Lead the best match needs over the runner-up to count as unambiguous
+const MIN_LEAD: f64 = 0.1;
+/// Deducted from a match whose authors or year contradict the input
+const MISMATCH_PENALTY: f64 = 0.2;
+
+/// Input for looking up the DOI of a paper by its title
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ResolveDoiInput {
+    /// Title of the paper
+    pub title: String,
+    /// An author's name, or just the surname, to tell apart papers with
+    /// similar titles
+    #[serde(default)]
+    pub author: Option<String>,
+    /// Publication year; a year either side still matches, since preprints
+    /// and published versions often differ by one
+    #[serde(default)]
+    pub year: Option<u32>,
+}
+
+/// A paper whose title matches the requested one
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DoiCandidate {
+    pub doi: String,
+    pub title: String,
+    pub authors: Vec<String>,
+    pub year: Option<u32>,
+    /// Provider that returned the paper
+    pub source: Option<String>,
+    /// How well the paper matches the input, from 0.0 to 1.0
+    pub score: f64,
+}
+
+/// Outcome of a DOI lookup
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ResolveDoiResult {
+    /// Title that was looked up
+    pub title: String,
+    /// DOI of the paper, when one match clearly stands out
+    pub doi: Option<String>,
+    /// Matches with a DOI, best first
+    pub candidates: Vec<DoiCandidate>,
+}
+
+/// Looks up the DOI of a paper from its title, returning only identifying
+/// metadata and no abstracts or PDF links
+#[derive(Clone)]
+pub struct ResolveDoiTool {
+    client: Arc<MetaSearchClient>,
+}
+
+impl std::fmt::Debug for ResolveDoiTool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ResolveDoiTool")
+            .field("client", &"MetaSearchClient")
+            .finish()
+    }
+}
+
+impl ResolveDoiTool {
+    /// Create a DOI lookup tool searching through `client`
+    #[must_use]
+    pub const fn new(client: Arc<MetaSearchClient>) -> Self {
+        Self { client }
+    }
+
+    /// Search the providers for the title and rank the results that have a DOI
+    #[instrument(skip(self), fields(title = %input.title))]
+    pub async fn resolve(&self, input: ResolveDoiInput) -> Result<ResolveDoiResult> {
+        let wanted = normalize_title(&input.title);
+        if wanted.is_empty() {
+            return Err(crate::Error::InvalidInput {
+                field: "title".to_string(),
+                reason: "Title must contain at least one letter or digit".to_string(),
+            });
+        }
+
+        let query = SearchQuery {
+            query: input.title.trim().to_string(),
+            search_type: SearchType::Title,
+            max_results: SEARCH_LIMIT,
+            offset: 0,
+            params: HashMap::new(),
+        };
+        let search_result = self.client.search(&query).await?;
+
+        let mut seen = HashSet::new();
+        let mut candidates: Vec<DoiCandidate> = search_result
+            .papers
+            .iter()
+            .filter(|paper| !paper.doi.trim().is_empty())
+            .filter(|paper| seen.insert(paper.doi.trim().to_lowercase()))
+            .filter_map(|paper| {
+                let title = paper.title.as_deref()?;
+                let score = score_match(&wanted, &input, paper);
+                debug!("Scored {} for '{}': {:.2}", paper.doi, title, score);
+                Some(DoiCandidate {
+                    doi: paper.doi.trim().to_string(),
+                    title: title.to_string(),
+                    authors: paper.authors.clone(),
+                    year: paper.year,
+                    source: source_of(&search_result, &paper.doi),
+                    score,
+                })
+            })
+            .collect();
+        candidates.sort_by(|a, b| b.score.total_cmp(&a.score));
+        candidates.truncate(MAX_CANDIDATES);
+
+        // A title matching exactly beats near misses like "... is not all
+        // you need"; otherwise the best match has to lead clearly
+        let exact: Vec<&DoiCandidate> = candidates
+            .iter()
+            .filter(|candidate| normalize_title(&candidate.title) == wanted)
+            .collect();
+        let doi = match (exact.as_slice(), candidates.as_slice()) {
+            ([only], [best, ..]) if only.doi == best.doi => Some(best.doi.clone()),
+            (_, [best, rest @ ..])
+                if best.score >= CONFIDENT_SCORE
+                    && rest
+                        .first()
+                        .map_or(true, |next| best.score - next.score >= MIN_LEAD) =>
+            {
+                Some(best.doi.clone())
+            }
+            _ => None,
+        };
+        if doi.is_some() {
+            candidates.truncate(1);
+        }
+
+        match &doi {
+            Some(doi) => info!("Resolved '{}' to {}", input.title, doi),
+            None => info!(
+                "No single DOI for '{}', {} candidates",
+                input.title,
+                candidates.len()
+            ),
+        }
+        Ok(ResolveDoiResult {
+            title: input.title,
+            doi,
+            candidates,
+        })
+    }
+}
+
+/// How well `paper` matches the input: the similarity of the titles, less a
+/// penalty for each of author and year that the input gives and the paper
+/// contradicts
+fn score_match(wanted: &str, input: &ResolveDoiInput, paper: &PaperMetadata) -> f64 {
+    let title = paper
+        .title
+        .as_deref()
+        .map(normalize_title)
+        .unwrap_or_default();
+    let mut score = title_similarity(wanted, &title);
+
+    // Names like "Vaswani, A." and "Ashish Vaswani" share the surname; any
+    // word beyond an initial counts as a match
+    let names: HashSet<String> = input
+        .author
+        .as_deref()
+        .map(normalize_title)
+        .unwrap_or_default()
+        .split(' ')
+        .filter(|word| word.chars().count() > 1)
+        .map(str::to_string)
+        .collect();
+    if !names.is_empty() && !paper.authors.is_empty() {
+        let listed = paper
+            .authors
+            .iter()
+            .chain(&paper.raw_authors)
+            .any(|author| {
+                normalize_title(author)
+                    .split(' ')
+                    .any(|word| names.contains(word))
+            });
+        if !listed {
+            score -= MISMATCH_PENALTY;
+        }
+    }
+    if let (Some(wanted_year), Some(year)) = (input.year, paper.year) {
+        if wanted_year.abs_diff(year) > 1 {
+            score -= MISMATCH_PENALTY;
+        }
+    }
+    score.max(0.0)
+}
+
+/// Provider that returned the paper with `doi`
+fn source_of(result: &MetaSearchResult, doi: &str) -> Option<String> {
+    result
+        .by_source
+        .iter()
+        .find(|(_, papers)| papers.iter().any(|paper| paper.doi == doi))
+        .map(|(source, _)| source.clone())
+}
+
+/// Lower-cased alphanumeric words of a title, separated by single spaces
+fn normalize_title(title: &str) -> String {
+    title
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Sørensen–Dice coefficient over character bigrams of normalized titles
+fn title_similarity(a: &str, b: &str) -> f64 {
+    fn bigrams(s: &str) -> HashMap<(char, char), usize> {
+        let chars: Vec<char> = s.chars().collect();
+        let mut counts = HashMap::new();
+        for pair in chars.windows(2) {
+            *counts.entry((pair[0], pair[1])).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    if a == b {
+        return 1.0;
+    }
+    let (a, b) = (bigrams(a), bigrams(b));
+    let total: usize = a.values().sum::<usize>() + b.values().sum::<usize>();
+    if total == 0 {
+        return 0.0;
+    }
+    let shared: usize = a
+        .iter()
+        .map(|(pair, count)| (*count).min(b.get(pair).copied().unwrap_or(0)))
+        .sum();
+    #[allow(clippy::cast_precision_loss)]
+    let similarity = 2.0 * shared as f64 / total as f64;
+    similarity
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn paper(title: &str, author: &str, year: u32) -> PaperMetadata {
+        let mut paper = PaperMetadata::new("10.1000/test".to_string());
+        paper.title = Some(title.to_string());
+        paper.authors = vec![author.to_string()];
+        paper.year = Some(year);
+        paper
+    }
+
+    #[test]
+    fn test_score_match_author_and_year_mismatch_penalized() {
+        let input = ResolveDoiInput {
+            title: "Attention Is All You Need".to_string(),
+            author: Some("Ashish Vaswani".to_string()),
+            year: Some(2017),
+        };
+        let wanted = normalize_title(&input.title);
+
+        let exact = paper("Attention is all you need.", "Vaswani, A.", 2018);
+        assert!((score_match(&wanted, &input, &exact) - 1.0).abs() < 1e-9);
+
+        let other = paper("Attention is all you need", "Smith, J.", 2021);
+        let expected = 1.0 - MISMATCH_PENALTY - MISMATCH_PENALTY;
+        assert!((score_match(&wanted, &input, &other) - expected).abs() < 1e-9);
+    }
+}
@@ -1,17 +1,27 @@
-use crate::client::providers::{SearchQuery, SearchType as ProviderSearchType};
-use crate::client::{MetaSearchClient, MetaSearchConfig, MetaSearchResult, PaperMetadata};
-use crate::services::CategorizationService;
+use crate::client::providers::{openreview, SearchQuery, SearchType as ProviderSearchType};
+use crate::client::{
+    relevance, MetaSearchClient, MetaSearchConfig, MetaSearchResult, PaperMetadata,
+    ABSTRACT_REQUIRED_PARAM, MIN_YEAR_PARAM,
+};
+use crate::services::{
+    sanitize_search_terms, BooleanQuery, CategorizationService, QueryExpander, TranslationProvider,
+    TranslationService,
+};
 // use crate::tools::command::{Command, CommandResult, ExecutionContext};
 use crate::{Config, Result};
+use futures::StreamExt;
 // use rmcp::tool; // Will be enabled when rmcp integration is complete
 // use async_trait::async_trait;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 // use std::any::Any;
 use std::collections::HashMap;
+use std::future::Future;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, SystemTime};
 use tokio::sync::RwLock;
+use tokio_util::sync::CancellationToken;
 use tracing::{debug, info, instrument, warn};
 
 /// Input parameters for the paper search tool
@@ -28,6 +38,33 @@ pub struct SearchInput {
     /// Offset for pagination (default: 0)
     #[serde(default)]
     pub offset: u32,
+    /// Only return papers with a downloadable PDF (default: false)
+    #[serde(default)]
+    pub require_pdf: bool,
+    /// With `require_pdf`, look up PDFs for papers without a PDF URL across
+    /// all providers instead of dropping them (slower, default: false)
+    #[serde(default)]
+    pub verify: bool,
+    /// Drop papers published before this year, overriding
+    /// `meta_search.min_year` (0 disables the cutoff)
+    #[serde(default)]
+    pub min_year: Option<u32>,
+    /// Only return papers with an abstract; providers that never supply
+    /// abstracts are queried last (default: false)
+    #[serde(default)]
+    pub abstract_required: bool,
+    /// Scope `OpenReview` results to a venue: a group ID such as `NeurIPS.cc`
+    /// or `ICLR.cc/2024/Conference`, or a short name like `NeurIPS`
+    #[serde(default)]
+    pub venue: Option<String>,
+    /// Conference year for `OpenReview` results; combined with `venue` it
+    /// selects that year's conference
+    #[serde(default)]
+    pub year: Option<u32>,
+    /// Only return papers whose abstract mentions at least one of these
+    /// keywords, ignoring case; papers without an abstract are dropped
+    #[serde(default)]
+    pub abstract_keywords: Vec<String>,
 }
 
 /// Type of search to perform
@@ -45,6 +82,9 @@ pub enum SearchType {
     Author,
     /// Search by combination of author and year
     AuthorYear,
+    /// Search within the body text of papers (only providers with a
+    /// full-text index are queried)
+    FullText,
 }
 
 /// Result of a paper search operation
@@ -60,6 +100,11 @@ pub struct SearchResult {
     pub total_count: u32,
     /// Number of results returned in this response
     pub returned_count: u32,
+    /// Results dropped by `require_pdf`, `abstract_required` and
+    /// `abstract_keywords`;
+    /// `total_count` and `has_more` still describe the provider results
+    #[serde(default)]
+    pub filtered_count: u32,
     /// Offset used for this search
     pub offset: u32,
     /// Whether there are more results available
@@ -78,6 +123,20 @@ pub struct SearchResult {
     pub provider_errors: HashMap<String, String>,
     /// Number of papers found per provider
     pub papers_per_provider: HashMap<String, u32>,
+    /// Milliseconds spent per provider, plus the whole search under `total`
+    #[serde(default)]
+    pub timings_ms: HashMap<String, u64>,
+}
+
+impl SearchResult {
+    /// Update the counts after a filter shrank `papers` from `before` entries
+    fn record_filtered(&mut self, before: usize) {
+        let dropped = before.saturating_sub(self.papers.len());
+        self.filtered_count = self
+            .filtered_count
+            .saturating_add(u32::try_from(dropped).unwrap_or(u32::MAX));
+        self.returned_count = u32::try_from(self.papers.len()).unwrap_or(u32::MAX);
+    }
 }
 
 /// Individual paper result
@@ -88,12 +147,22 @@ pub struct PaperResult {
     pub metadata: PaperMetadata,
     /// Relevance score (0.0 to 1.0)
     pub relevance_score: f64,
+    /// Components of `relevance_score` (`title_match`, `abstract_match`,
+    /// `provider_priority`, `recency`), when relevance scoring is enabled
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub score_breakdown: HashMap<String, f64>,
     /// Whether the full paper is available for download
     pub available: bool,
     /// Source where this result came from
     pub source: String,
+    /// Every provider that returned this paper, in name order
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub found_by: Vec<String>,
     /// Suggested category for organizing this paper
     pub category: Option<String>,
+    /// Abstract translated into the configured language, when translation is enabled
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub abstract_translated: Option<String>,
 }
 
 /// Cache entry for search results
@@ -118,6 +187,74 @@ impl CacheEntry {
     }
 }
 
+/// Caps the lookups made to enrich one search's results (PDF verification,
+/// abstract translation). Once the request cap or deadline is reached further
+/// lookups are skipped and those papers are returned as the providers gave
+/// them; lookups still running at the deadline are abandoned.
+struct EnrichmentBudget {
+    max_requests: Option<usize>,
+    deadline: Option<tokio::time::Instant>,
+    used: AtomicUsize,
+    skipped: AtomicUsize,
+}
+
+impl EnrichmentBudget {
+    fn new(max_requests: Option<usize>, max_duration: Option<Duration>) -> Self {
+        Self {
+            max_requests,
+            deadline: max_duration.map(|d| tokio::time::Instant::now() + d),
+            used: AtomicUsize::new(0),
+            skipped: AtomicUsize::new(0),
+        }
+    }
+
+    fn from_config(config: &Config) -> Self {
+        Self::new(
+            config.meta_search.max_enrichment_requests,
+            config
+                .meta_search
+                .max_enrichment_duration_secs
+                .map(Duration::from_secs),
+        )
+    }
+
+    /// Run `lookup` if the budget allows one more request; otherwise, or if
+    /// it is still running at the deadline, return `None`
+    async fn run<T>(&self, lookup: impl Future<Output = Option<T>>) -> Option<T> {
+        let expired = self
+            .deadline
+            .is_some_and(|deadline| tokio::time::Instant::now() >= deadline);
+        let allowed = !expired
+            && self
+                .used
+                .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |used| {
+                    self.max_requests
+                        .map_or(true, |max| used < max)
+                        .then_some(used + 1)
+                })
+                .is_ok();
+        if !allowed {
+            self.skipped.fetch_add(1, Ordering::Relaxed);
+            return None;
+        }
+
+        match self.deadline {
+            Some(deadline) => tokio::time::timeout_at(deadline, lookup)
+                .await
+                .unwrap_or_else(|_| {
+                    self.skipped.fetch_add(1, Ordering::Relaxed);
+                    None
+                }),
+            None => lookup.await,
+        }
+    }
+
+    /// Lookups skipped or abandoned because the budget ran out
+    fn skipped(&self) -> usize {
+        self.skipped.load(Ordering::Relaxed)
+    }
+}
+
 /// Paper search tool implementation
 #[derive(Clone)]
 pub struct SearchTool {
@@ -125,6 +262,8 @@ pub struct SearchTool {
     cache: Arc<RwLock<HashMap<String, CacheEntry>>>,
     config: Arc<Config>,
     categorization_service: CategorizationService,
+    translation_service: Option<TranslationService>,
+    query_expander: Option<QueryExpander>,
 }
 
 impl std::fmt::Debug for SearchTool {
@@ -134,6 +273,8 @@ impl std::fmt::Debug for SearchTool {
             .field("cache", &"RwLock<HashMap>")
             .field("config", &"Config")
             .field("categorization_service", &"CategorizationService")
+            .field("translation_service", &self.translation_service)
+            .field("query_expander", &self.query_expander)
             .finish()
     }
 }
@@ -155,24 +296,57 @@ impl SearchTool {
                 crate::Error::Service(format!("Failed to create categorization service: {e}"))
             })?;
 
+        let translation_service = TranslationService::from_config(&config.translation)?;
+        let query_expander = QueryExpander::from_config(&config.query_expansion);
+
         Ok(Self {
             meta_client: Arc::new(meta_client),
             cache: Arc::new(RwLock::new(HashMap::new())),
             config,
             categorization_service,
+            translation_service,
+            query_expander,
         })
     }
 
+    /// Search through `meta_client` instead of the client built from config
+    #[must_use]
+    pub fn with_meta_client(mut self, meta_client: Arc<MetaSearchClient>) -> Self {
+        self.meta_client = meta_client;
+        self
+    }
+
+    /// Translate non-English abstracts with `provider`, regardless of config
+    #[must_use]
+    pub fn with_translation_provider(mut self, provider: Arc<dyn TranslationProvider>) -> Self {
+        self.translation_service = Some(TranslationService::new(
+            provider,
+            self.config.translation.target_language.clone(),
+        ));
+        self
+    }
+
     /// Execute a paper search using meta-search across multiple providers
     // #[tool] // Will be enabled when rmcp integration is complete
-    #[instrument(skip(self), fields(query = %input.query, search_type = ?input.search_type))]
     pub async fn search_papers(&self, input: SearchInput) -> Result<SearchResult> {
+        self.search_papers_with_cancel(input, &CancellationToken::new())
+            .await
+    }
+
+    /// Execute a paper search that stops querying providers once `cancel` fires
+    #[instrument(skip(self, cancel), fields(query = %input.query, search_type = ?input.search_type))]
+    pub async fn search_papers_with_cancel(
+        &self,
+        input: SearchInput,
+        cancel: &CancellationToken,
+    ) -> Result<SearchResult> {
         info!(
             "Executing meta-search: query='{}', type={:?}",
             input.query, input.search_type
         );
 
         // Validate input
+        let input = self.sanitize_query(input)?;
         Self::validate_input(&input)?;
 
         // Check cache first
@@ -182,6 +356,31 @@ impl SearchTool {
             return Ok(cached_result);
         }
 
+        let result = self.execute_search(&input, cancel).await?;
+        self.cache_result(&cache_key, &result).await;
+        Ok(result)
+    }
+
+    /// Execute a paper search without consulting the cache. The result still
+    /// replaces any cached entry for the same input.
+    pub async fn search_papers_fresh(&self, input: SearchInput) -> Result<SearchResult> {
+        let input = self.sanitize_query(input)?;
+        Self::validate_input(&input)?;
+
+        let result = self
+            .execute_search(&input, &CancellationToken::new())
+            .await?;
+        self.cache_result(&Self::generate_cache_key(&input), &result)
+            .await;
+        Ok(result)
+    }
+
+    /// Run the meta-search for `input` and post-process the results
+    async fn execute_search(
+        &self,
+        input: &SearchInput,
+        cancel: &CancellationToken,
+    ) -> Result<SearchResult> {
         // Convert our SearchType to ProviderSearchType
         let provider_search_type = Self::convert_search_type(&input.search_type);
 
@@ -191,24 +390,57 @@ impl SearchTool {
             search_type: provider_search_type,
             max_results: input.limit,
             offset: input.offset,
-            params: HashMap::new(),
+            params: Self::query_params(input),
+        };
+
+        // Parse AND/OR operators and quoted phrases, if enabled
+        let boolean_query = if self.config.meta_search.parse_boolean
+            && !matches!(input.search_type, SearchType::Doi)
+        {
+            BooleanQuery::parse(&input.query)
+        } else {
+            None
         };
 
         // Execute meta-search
-        let meta_result = self
-            .meta_client
-            .search(&search_query)
-            .await
-            .map_err(|e| crate::Error::Service(format!("Meta-search failed: {e}")))?;
+        let mut meta_result = match &boolean_query {
+            Some(boolean_query) => {
+                self.search_boolean(boolean_query, &search_query, cancel)
+                    .await?
+            }
+            None => self
+                .meta_client
+                .search_with_cancel(&search_query, cancel)
+                .await
+                .map_err(|e| crate::Error::Service(format!("Meta-search failed: {e}")))?,
+        };
+
+        // Merge in searches for synonyms of the query, if enabled
+        if matches!(input.search_type, SearchType::Auto) && boolean_query.is_none() {
+            self.merge_expanded_searches(&search_query, &mut meta_result, cancel)
+                .await;
+        }
 
         // Convert to our SearchResult format
         let mut result = Self::convert_meta_result_to_search_result(
             input.query.clone(),
             input.search_type.clone(),
             meta_result,
-            &input,
+            input,
         );
 
+        let enrichment_budget = EnrichmentBudget::from_config(&self.config);
+        if input.require_pdf {
+            self.retain_downloadable(&mut result, input.verify, &enrichment_budget)
+                .await;
+        }
+        if input.abstract_required {
+            Self::retain_with_abstract(&mut result);
+        }
+        if !input.abstract_keywords.is_empty() {
+            Self::retain_with_abstract_keywords(&mut result, &input.abstract_keywords);
+        }
+
         // Add categorization if enabled and papers were found
         if self.categorization_service.is_enabled() && !result.papers.is_empty() {
             let category = self.categorize_papers(&input.query, &result.papers);
@@ -220,8 +452,15 @@ impl SearchTool {
             }
         }
 
-        // Cache the result
-        self.cache_result(&cache_key, &result).await;
+        // Translate non-English abstracts if a translator is configured
+        self.translate_abstracts(&mut result.papers, &enrichment_budget)
+            .await;
+        if enrichment_budget.skipped() > 0 {
+            warn!(
+                "Enrichment budget exhausted, {} lookups skipped",
+                enrichment_budget.skipped()
+            );
+        }
 
         // Enhanced logging with provider details
         info!(
@@ -250,6 +489,215 @@ impl SearchTool {
         Ok(result)
     }
 
+    /// Run one search per `OR` alternative of a boolean query and merge the
+    /// results. Only the first alternative has to succeed.
+    async fn search_boolean(
+        &self,
+        boolean_query: &BooleanQuery,
+        search_query: &SearchQuery,
+        cancel: &CancellationToken,
+    ) -> Result<MetaSearchResult> {
+        let mut queries = boolean_query.search_queries(search_query).into_iter();
+        let first = queries.next().unwrap_or_else(|| search_query.clone());
+        debug!("Running boolean search: '{}'", first.query);
+        let mut meta_result = self
+            .meta_client
+            .search_with_cancel(&first, cancel)
+            .await
+            .map_err(|e| crate::Error::Service(format!("Meta-search failed: {e}")))?;
+
+        for query in queries {
+            debug!("Running boolean search alternative: '{}'", query.query);
+            match self.meta_client.search_with_cancel(&query, cancel).await {
+                Ok(result) => meta_result.merge(result),
+                Err(e) => warn!("Boolean search alternative '{}' failed: {}", query.query, e),
+            }
+        }
+        Ok(meta_result)
+    }
+
+    /// Run the query's synonym expansions and merge their results into
+    /// `meta_result`. No-op when query expansion is disabled.
+    async fn merge_expanded_searches(
+        &self,
+        search_query: &SearchQuery,
+        meta_result: &mut MetaSearchResult,
+        cancel: &CancellationToken,
+    ) {
+        let Some(expander) = &self.query_expander else {
+            return;
+        };
+
+        for expanded in expander.expand(&search_query.query) {
+            debug!("Running expanded search: '{}'", expanded);
+            let query = SearchQuery {
+                query: expanded,
+                ..search_query.clone()
+            };
+            match self.meta_client.search_with_cancel(&query, cancel).await {
+                Ok(result) => meta_result.merge(result),
+                Err(e) => warn!("Expanded search '{}' failed: {}", query.query, e),
+            }
+        }
+    }
+
+    /// Fill `abstract_translated` for papers whose abstract is not in the
+    /// target language, within `budget`. No-op when translation is disabled.
+    async fn translate_abstracts(&self, papers: &mut [PaperResult], budget: &EnrichmentBudget) {
+        let Some(translation) = &self.translation_service else {
+            return;
+        };
+
+        // Collected up front: a stream borrowing `papers` would make the
+        // search future not `Send`
+        let abstracts: Vec<Option<String>> = papers
+            .iter()
+            .map(|paper| paper.metadata.abstract_text.clone())
+            .collect();
+        let translations: Vec<Option<String>> = futures::stream::iter(abstracts)
+            .map(|abstract_text| async move {
+                match abstract_text {
+                    Some(text) if translation.needs_translation(&text) => {
+                        budget.run(translation.translate_abstract(&text)).await
+                    }
+                    _ => None,
+                }
+            })
+            .buffered(4)
+            .collect()
+            .await;
+
+        let translated = translations.iter().filter(|t| t.is_some()).count();
+        if translated > 0 {
+            debug!("Translated {} of {} abstracts", translated, papers.len());
+        }
+        for (paper, translation) in papers.iter_mut().zip(translations) {
+            paper.abstract_translated = translation;
+        }
+    }
+
+    /// Drop papers without a PDF URL. With `verify`, papers that have a DOI
+    /// but no PDF URL are first looked up through the provider cascade,
+    /// within `budget`.
+    async fn retain_downloadable(
+        &self,
+        result: &mut SearchResult,
+        verify: bool,
+        budget: &EnrichmentBudget,
+    ) {
+        if verify {
+            let meta_client = &self.meta_client;
+            // Collected up front: a stream borrowing `result.papers` would
+            // make the search future not `Send`
+            let candidates: Vec<(bool, String)> = result
+                .papers
+                .iter()
+                .map(|paper| {
+                    let needs_lookup = paper
+                        .metadata
+                        .pdf_url
+                        .as_deref()
+                        .map_or(true, str::is_empty)
+                        && !paper.metadata.doi.is_empty();
+                    (needs_lookup, paper.metadata.doi.clone())
+                })
+                .collect();
+            let lookups: Vec<Option<String>> = futures::stream::iter(candidates)
+                .map(|(needs_lookup, doi)| async move {
+                    if !needs_lookup {
+                        return None;
+                    }
+                    budget
+                        .run(async {
+                            match meta_client.get_pdf_url_cascade(&doi).await {
+                                Ok(pdf_url) => pdf_url,
+                                Err(e) => {
+                                    debug!("PDF verification failed for {}: {}", doi, e);
+                                    None
+                                }
+                            }
+                        })
+                        .await
+                })
+                .buffered(4)
+                .collect()
+                .await;
+
+            for (paper, pdf_url) in result.papers.iter_mut().zip(lookups) {
+                if pdf_url.is_some() {
+                    paper.metadata.pdf_url = pdf_url;
+                }
+            }
+        }
+
+        let before = result.papers.len();
+        result.papers.retain(|paper| {
+            paper
+                .metadata
+                .pdf_url
+                .as_deref()
+                .is_some_and(|u| !u.is_empty())
+        });
+        debug!(
+            "require_pdf kept {} of {} papers",
+            result.papers.len(),
+            before
+        );
+
+        result.record_filtered(before);
+    }
+
+    /// Drop papers without a non-empty abstract
+    fn retain_with_abstract(result: &mut SearchResult) {
+        let before = result.papers.len();
+        result.papers.retain(|paper| {
+            paper
+                .metadata
+                .abstract_text
+                .as_deref()
+                .is_some_and(|text| !text.trim().is_empty())
+        });
+        debug!(
+            "abstract_required kept {} of {} papers",
+            result.papers.len(),
+            before
+        );
+
+        result.record_filtered(before);
+    }
+
+    /// Drop papers whose abstract mentions none of `keywords`
+    fn retain_with_abstract_keywords(result: &mut SearchResult, keywords: &[String]) {
+        let keywords: Vec<String> = keywords.iter().map(|k| k.to_lowercase()).collect();
+        let before = result.papers.len();
+        result.papers.retain(|paper| {
+            paper.metadata.abstract_text.as_deref().is_some_and(|text| {
+                let text = text.to_lowercase();
+                keywords.iter().any(|keyword| text.contains(keyword))
+            })
+        });
+        debug!(
+            "abstract_keywords kept {} of {} papers",
+            result.papers.len(),
+            before
+        );
+
+        result.record_filtered(before);
+    }
+
+    /// Strip control characters from the query and abstract keywords and
+    /// enforce the configured `search_terms` limits on them
+    fn sanitize_query(&self, mut input: SearchInput) -> Result<SearchInput> {
+        let words: Vec<&str> = input.query.split_whitespace().collect();
+        input.query = sanitize_search_terms("query", &words, &self.config.search_terms)?.join(" ");
+        input.abstract_keywords = sanitize_search_terms(
+            "abstract_keywords",
+            &input.abstract_keywords,
+            &self.config.search_terms,
+        )?;
+        Ok(input)
+    }
+
     /// Validate search input parameters
     fn validate_input(input: &SearchInput) -> Result<()> {
         if input.query.trim().is_empty() {
@@ -335,14 +783,39 @@ impl SearchTool {
     /// Generate cache key for search input
     fn generate_cache_key(input: &SearchInput) -> String {
         format!(
-            "{}:{}:{}:{}",
+            "{}:{}:{}:{}:{}:{}:{:?}:{}:{:?}:{:?}:{:?}",
             input.query.to_lowercase(),
             serde_json::to_string(&input.search_type).unwrap_or_default(),
             input.limit,
-            input.offset
+            input.offset,
+            input.require_pdf,
+            input.verify,
+            input.min_year,
+            input.abstract_required,
+            input.venue,
+            input.year,
+            input.abstract_keywords
         )
     }
 
+    /// Meta-search parameters for the options of `input`
+    fn query_params(input: &SearchInput) -> HashMap<String, String> {
+        let mut params = HashMap::new();
+        if let Some(year) = input.min_year {
+            params.insert(MIN_YEAR_PARAM.to_string(), year.to_string());
+        }
+        if input.abstract_required {
+            params.insert(ABSTRACT_REQUIRED_PARAM.to_string(), "true".to_string());
+        }
+        if let Some(venue) = &input.venue {
+            params.insert(openreview::VENUE_PARAM.to_string(), venue.clone());
+        }
+        if let Some(year) = input.year {
+            params.insert(openreview::YEAR_PARAM.to_string(), year.to_string());
+        }
+        params
+    }
+
     /// Get result from cache
     async fn get_from_cache(&self, cache_key: &str) -> Option<SearchResult> {
         let cache = self.cache.read().await;
@@ -394,6 +867,7 @@ impl SearchTool {
             SearchType::Title => ProviderSearchType::Title,
             SearchType::Author => ProviderSearchType::Author,
             SearchType::AuthorYear => ProviderSearchType::Keywords, // Fallback to keywords
+            SearchType::FullText => ProviderSearchType::FullText,
         }
     }
 
@@ -421,13 +895,38 @@ impl SearchTool {
                     })
                     .map_or_else(|| "Unknown".to_string(), |(source, _)| source.clone());
 
+                let key = MetaSearchResult::paper_key(&paper);
+                let mut found_by: Vec<String> = meta_result
+                    .by_source
+                    .iter()
+                    .filter(|(_, papers)| {
+                        papers.iter().any(|p| MetaSearchResult::paper_key(p) == key)
+                    })
+                    .map(|(source, _)| source.clone())
+                    .collect();
+                found_by.sort();
+
+                let score_breakdown = meta_result
+                    .score_breakdowns
+                    .get(&key)
+                    .cloned()
+                    .unwrap_or_default();
+                #[allow(clippy::cast_precision_loss)]
+                let relevance_score = if score_breakdown.is_empty() {
+                    (index as f64).mul_add(-0.01, 1.0) // Simple scoring based on order
+                } else {
+                    relevance::total_score(&score_breakdown)
+                };
+
                 PaperResult {
                     metadata: paper,
-                    #[allow(clippy::cast_precision_loss)]
-                    relevance_score: (index as f64).mul_add(-0.01, 1.0), // Simple scoring based on order
+                    relevance_score,
+                    score_breakdown,
                     available: true, // Assume available since providers returned them
                     source,
+                    found_by,
                     category: None, // Will be set later by categorization
+                    abstract_translated: None,
                 }
             })
             .collect();
@@ -462,6 +961,16 @@ impl SearchTool {
             .iter()
             .map(|(provider, papers)| (provider.clone(), u32::try_from(papers.len()).unwrap_or(0)))
             .collect();
+        let timings_ms: HashMap<String, u64> = meta_result
+            .timings
+            .iter()
+            .map(|(name, duration)| {
+                (
+                    name.clone(),
+                    u64::try_from(duration.as_millis()).unwrap_or(u64::MAX),
+                )
+            })
+            .collect();
 
         SearchResult {
             query,
@@ -469,6 +978,7 @@ impl SearchTool {
             papers,
             total_count: returned_count, // We don't have true total from meta-search
             returned_count,
+            filtered_count: 0,
             offset: input.offset,
             has_more: returned_count >= input.limit, // Estimate based on limit
             search_time_ms: u64::try_from(meta_result.total_search_time.as_millis())
@@ -479,6 +989,7 @@ impl SearchTool {
             failed_providers,
             provider_errors: meta_result.provider_errors,
             papers_per_provider,
+            timings_ms,
         }
     }
 
@@ -620,7 +1131,7 @@ impl Command for SearchTool {
                 "type": "object",
                 "properties": {
                     "query": {"type": "string", "description": "Query string - DOI, title, or author"},
-                    "search_type": {"type": "string", "enum": ["auto", "doi", "title", "author", "author_year"]},
+                    "search_type": {"type": "string", "enum": ["auto", "doi", "title", "author", "author_year", "full_text"]},
                     "limit": {"type": "integer", "minimum": 1, "maximum": 100, "default": 10},
                     "offset": {"type": "integer", "minimum": 0, "default": 0}
                 },
@@ -641,6 +1152,7 @@ impl Command for SearchTool {
                     "papers": {"type": "array"},
                     "total_count": {"type": "integer"},
                     "returned_count": {"type": "integer"},
+                    "filtered_count": {"type": "integer"},
                     "has_more": {"type": "boolean"}
                 }
             })
@@ -711,6 +1223,7 @@ impl Command for SearchTool {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::client::providers::traits::tests::{paper, MockProvider};
     use crate::client::providers::SearchType as ProviderSearchType;
     use crate::client::{MetaSearchResult, PaperMetadata};
     use crate::config::{Config, ResearchSourceConfig};
@@ -723,6 +1236,8 @@ mod tests {
             timeout_secs: 30,
             provider_timeout_secs: 60,
             max_retries: 2,
+            arxiv_version: "latest".to_string(),
+            author_format: crate::config::AuthorNameFormat::FirstLast,
         };
         Arc::new(config)
     }
@@ -740,6 +1255,13 @@ mod tests {
             search_type: SearchType::Auto,
             limit: 10,
             offset: 0,
+            require_pdf: false,
+            verify: false,
+            min_year: None,
+            abstract_required: false,
+            venue: None,
+            year: None,
+            abstract_keywords: Vec::new(),
         };
         assert!(SearchTool::validate_input(&empty_input).is_err());
 
@@ -749,6 +1271,13 @@ mod tests {
             search_type: SearchType::Auto,
             limit: 10,
             offset: 0,
+            require_pdf: false,
+            verify: false,
+            min_year: None,
+            abstract_required: false,
+            venue: None,
+            year: None,
+            abstract_keywords: Vec::new(),
         };
         assert!(SearchTool::validate_input(&long_input).is_err());
 
@@ -758,6 +1287,13 @@ mod tests {
             search_type: SearchType::Auto,
             limit: 0,
             offset: 0,
+            require_pdf: false,
+            verify: false,
+            min_year: None,
+            abstract_required: false,
+            venue: None,
+            year: None,
+            abstract_keywords: Vec::new(),
         };
         assert!(SearchTool::validate_input(&invalid_limit).is_err());
 
@@ -767,6 +1303,13 @@ mod tests {
             search_type: SearchType::Auto,
             limit: 10,
             offset: 0,
+            require_pdf: false,
+            verify: false,
+            min_year: None,
+            abstract_required: false,
+            venue: None,
+            year: None,
+            abstract_keywords: Vec::new(),
         };
         assert!(SearchTool::validate_input(&valid_input).is_ok());
     }
@@ -794,6 +1337,10 @@ mod tests {
             SearchTool::convert_search_type(&SearchType::AuthorYear),
             ProviderSearchType::Keywords
         ));
+        assert!(matches!(
+            SearchTool::convert_search_type(&SearchType::FullText),
+            ProviderSearchType::FullText
+        ));
     }
 
     #[test]
@@ -803,6 +1350,13 @@ mod tests {
             search_type: SearchType::Title,
             limit: 10,
             offset: 0,
+            require_pdf: false,
+            verify: false,
+            min_year: None,
+            abstract_required: false,
+            venue: None,
+            year: None,
+            abstract_keywords: Vec::new(),
         };
 
         let key1 = SearchTool::generate_cache_key(&input);
@@ -822,11 +1376,14 @@ mod tests {
             doi: "10.1038/test".to_string(),
             title: Some("Test Paper".to_string()),
             authors: vec!["Author 1".to_string()],
+            raw_authors: Vec::new(),
             journal: Some("Test Journal".to_string()),
             year: Some(2023),
             abstract_text: None,
             pdf_url: None,
             file_size: None,
+            license: None,
+            is_open_access: None,
         };
 
         let mut by_source = HashMap::new();
@@ -840,6 +1397,8 @@ mod tests {
             failed_providers: 0,
             provider_errors: HashMap::new(),
             provider_metadata: HashMap::new(),
+            timings: HashMap::new(),
+            score_breakdowns: HashMap::new(),
         };
 
         let input = SearchInput {
@@ -847,6 +1406,13 @@ mod tests {
             search_type: SearchType::Title,
             limit: 10,
             offset: 0,
+            require_pdf: false,
+            verify: false,
+            min_year: None,
+            abstract_required: false,
+            venue: None,
+            year: None,
+            abstract_keywords: Vec::new(),
         };
 
         let result = SearchTool::convert_meta_result_to_search_result(
@@ -863,6 +1429,476 @@ mod tests {
         assert!(!result.has_more);
     }
 
+    /// Provider returning one paper with a PDF link and two without, one of
+    /// which it can still resolve a PDF for by DOI. Only the paper with the
+    /// PDF link has an abstract; another has a blank one.
+    fn mixed_pdf_provider() -> MockProvider {
+        MockProvider::new("mixed_pdf").answering(|query, _| {
+            if query.search_type == ProviderSearchType::Doi {
+                let mut resolved = paper(&query.query, "Resolvable Paper");
+                resolved.pdf_url = (query.query == "10.1000/resolvable")
+                    .then(|| "https://example.com/resolved.pdf".to_string());
+                return Ok(vec![resolved]);
+            }
+            Ok([
+                (
+                    "10.1000/open",
+                    "Open Paper",
+                    Some("https://example.com/open.pdf"),
+                ),
+                ("10.1000/resolvable", "Resolvable Paper", None),
+                ("10.1000/closed", "Closed Paper", None),
+            ]
+            .into_iter()
+            .map(|(doi, title, pdf_url)| {
+                let mut paper = paper(doi, title);
+                paper.pdf_url = pdf_url.map(str::to_string);
+                paper.abstract_text = match doi {
+                    "10.1000/open" => Some("We study open access.".to_string()),
+                    "10.1000/closed" => Some("  ".to_string()),
+                    _ => None,
+                };
+                paper
+            })
+            .collect())
+        })
+    }
+
+    fn create_mixed_pdf_search_tool() -> SearchTool {
+        let client = MetaSearchClient::with_providers(
+            vec![Arc::new(mixed_pdf_provider())],
+            MetaSearchConfig::default(),
+        );
+        create_test_search_tool()
+            .unwrap()
+            .with_meta_client(Arc::new(client))
+    }
+
+    fn create_mixed_pdf_input(require_pdf: bool, verify: bool) -> SearchInput {
+        SearchInput {
+            query: "mixed availability".to_string(),
+            search_type: SearchType::Auto,
+            limit: 10,
+            offset: 0,
+            require_pdf,
+            verify,
+            min_year: None,
+            abstract_required: false,
+            venue: None,
+            year: None,
+            abstract_keywords: Vec::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_search_papers_require_pdf_drops_papers_without_pdf() {
+        let tool = create_mixed_pdf_search_tool();
+
+        let result = tool
+            .search_papers(create_mixed_pdf_input(false, false))
+            .await
+            .unwrap();
+        assert_eq!(result.papers.len(), 3);
+
+        let result = tool
+            .search_papers(create_mixed_pdf_input(true, false))
+            .await
+            .unwrap();
+        let dois: Vec<&str> = result
+            .papers
+            .iter()
+            .map(|p| p.metadata.doi.as_str())
+            .collect();
+        assert_eq!(dois, vec!["10.1000/open"]);
+        assert_eq!(result.returned_count, 1);
+        assert_eq!(result.filtered_count, 2);
+        // The provider total still describes the unfiltered results
+        assert_eq!(result.total_count, 3);
+    }
+
+    #[tokio::test]
+    async fn test_search_papers_abstract_required_drops_papers_without_abstract() {
+        let tool = create_mixed_pdf_search_tool();
+
+        let result = tool
+            .search_papers(SearchInput {
+                abstract_required: true,
+                ..create_mixed_pdf_input(false, false)
+            })
+            .await
+            .unwrap();
+        let dois: Vec<&str> = result
+            .papers
+            .iter()
+            .map(|p| p.metadata.doi.as_str())
+            .collect();
+        assert_eq!(dois, vec!["10.1000/open"]);
+        assert_eq!(result.returned_count, 1);
+    }
+
+    #[tokio::test]
+    async fn test_search_papers_abstract_keywords_keeps_matching_abstracts() {
+        let tool = create_mixed_pdf_search_tool();
+
+        let result = tool
+            .search_papers(SearchInput {
+                abstract_keywords: vec!["OPEN ACCESS".to_string(), "\u{7}missing".to_string()],
+                ..create_mixed_pdf_input(false, false)
+            })
+            .await
+            .unwrap();
+        let dois: Vec<&str> = result
+            .papers
+            .iter()
+            .map(|p| p.metadata.doi.as_str())
+            .collect();
+        assert_eq!(dois, vec!["10.1000/open"]);
+        assert_eq!(result.filtered_count, 2);
+    }
+
+    #[test]
+    fn test_query_params_venue_and_year_forwarded() {
+        let params = SearchTool::query_params(&SearchInput {
+            venue: Some("NeurIPS".to_string()),
+            year: Some(2023),
+            ..create_mixed_pdf_input(false, false)
+        });
+        assert_eq!(
+            params.get(openreview::VENUE_PARAM).map(String::as_str),
+            Some("NeurIPS")
+        );
+        assert_eq!(
+            params.get(openreview::YEAR_PARAM).map(String::as_str),
+            Some("2023")
+        );
+
+        let params = SearchTool::query_params(&create_mixed_pdf_input(false, false));
+        assert!(!params.contains_key(openreview::VENUE_PARAM));
+        assert!(!params.contains_key(openreview::YEAR_PARAM));
+    }
+
+    #[tokio::test]
+    async fn test_search_papers_require_pdf_verify_keeps_resolvable_papers() {
+        let tool = create_mixed_pdf_search_tool();
+
+        let result = tool
+            .search_papers(create_mixed_pdf_input(true, true))
+            .await
+            .unwrap();
+        let mut dois: Vec<&str> = result
+            .papers
+            .iter()
+            .map(|p| p.metadata.doi.as_str())
+            .collect();
+        dois.sort_unstable();
+        assert_eq!(dois, vec!["10.1000/open", "10.1000/resolvable"]);
+
+        let resolved = result
+            .papers
+            .iter()
+            .find(|p| p.metadata.doi == "10.1000/resolvable")
+            .unwrap();
+        assert_eq!(
+            resolved.metadata.pdf_url.as_deref(),
+            Some("https://example.com/resolved.pdf")
+        );
+    }
+
+    /// Provider returning one paper per distinct query and recording each query
+    fn recording_provider(queries: Arc<std::sync::Mutex<Vec<String>>>) -> MockProvider {
+        MockProvider::new("recording").answering(move |query, _| {
+            let mut queries = queries.lock().unwrap();
+            queries.push(query.query.clone());
+            Ok(vec![paper(
+                &format!("10.1000/{}", queries.len()),
+                &format!("Results for {}", query.query),
+            )])
+        })
+    }
+
+    #[tokio::test]
+    async fn test_search_papers_query_expansion_merges_synonym_results() {
+        let mut config = (*create_test_config()).clone();
+        config.query_expansion.enabled = true;
+        let queries = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let client = MetaSearchClient::with_providers(
+            vec![Arc::new(recording_provider(Arc::clone(&queries)))],
+            MetaSearchConfig::default(),
+        );
+        let tool = SearchTool::new(Arc::new(config))
+            .unwrap()
+            .with_meta_client(Arc::new(client));
+
+        let result = tool
+            .search_papers(SearchInput {
+                query: "heart attack outcomes".to_string(),
+                search_type: SearchType::Auto,
+                limit: 10,
+                offset: 0,
+                require_pdf: false,
+                verify: false,
+                min_year: None,
+                abstract_required: false,
+                venue: None,
+                year: None,
+                abstract_keywords: Vec::new(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            *queries.lock().unwrap(),
+            vec![
+                "heart attack outcomes".to_string(),
+                "myocardial infarction outcomes".to_string()
+            ]
+        );
+        let titles: Vec<&str> = result
+            .papers
+            .iter()
+            .filter_map(|p| p.metadata.title.as_deref())
+            .collect();
+        assert_eq!(
+            titles,
+            vec![
+                "Results for heart attack outcomes",
+                "Results for myocardial infarction outcomes"
+            ]
+        );
+        assert_eq!(result.papers_per_provider["recording"], 2);
+    }
+
+    /// Run `query` through a recording provider, returning the provider
+    /// queries it received and the titles of the merged results
+    async fn run_boolean_search(parse_boolean: bool, query: &str) -> (Vec<String>, Vec<String>) {
+        let mut config = (*create_test_config()).clone();
+        config.meta_search.parse_boolean = parse_boolean;
+        let queries = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let client = MetaSearchClient::with_providers(
+            vec![Arc::new(recording_provider(Arc::clone(&queries)))],
+            MetaSearchConfig::default(),
+        );
+        let tool = SearchTool::new(Arc::new(config))
+            .unwrap()
+            .with_meta_client(Arc::new(client));
+
+        let result = tool
+            .search_papers(SearchInput {
+                query: query.to_string(),
+                search_type: SearchType::Auto,
+                limit: 10,
+                offset: 0,
+                require_pdf: false,
+                verify: false,
+                min_year: None,
+                abstract_required: false,
+                venue: None,
+                year: None,
+                abstract_keywords: Vec::new(),
+            })
+            .await
+            .unwrap();
+        let titles = result
+            .papers
+            .iter()
+            .filter_map(|p| p.metadata.title.clone())
+            .collect();
+        let queries = queries.lock().unwrap().clone();
+        (queries, titles)
+    }
+
+    #[tokio::test]
+    async fn test_search_papers_boolean_and_sends_single_query() {
+        let (queries, titles) = run_boolean_search(true, "Smith AND \"gene editing\"").await;
+        assert_eq!(queries, vec!["Smith \"gene editing\"".to_string()]);
+        assert_eq!(titles.len(), 1);
+
+        // Without the flag the operator is part of the literal query
+        let (queries, _) = run_boolean_search(false, "Smith AND Jones").await;
+        assert_eq!(queries, vec!["Smith AND Jones".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_search_papers_boolean_or_merges_alternatives() {
+        let (queries, titles) = run_boolean_search(true, "Smith OR Jones").await;
+        assert_eq!(queries, vec!["Smith".to_string(), "Jones".to_string()]);
+        assert_eq!(
+            titles,
+            vec![
+                "Results for Smith".to_string(),
+                "Results for Jones".to_string()
+            ]
+        );
+    }
+
+    struct StubTranslator;
+
+    #[async_trait::async_trait]
+    impl TranslationProvider for StubTranslator {
+        fn name(&self) -> &'static str {
+            "stub"
+        }
+
+        async fn translate(&self, text: &str, target_language: &str) -> Result<String> {
+            Ok(format!("[{target_language}] {text}"))
+        }
+    }
+
+    fn create_paper_result(abstract_text: Option<&str>) -> PaperResult {
+        let mut metadata = PaperMetadata::new("10.1000/translated".to_string());
+        metadata.abstract_text = abstract_text.map(str::to_string);
+        PaperResult {
+            metadata,
+            relevance_score: 1.0,
+            score_breakdown: HashMap::new(),
+            available: true,
+            source: "test_source".to_string(),
+            found_by: vec!["test_source".to_string()],
+            category: None,
+            abstract_translated: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_translate_abstracts_enabled_populates_field() {
+        let tool = create_test_search_tool()
+            .unwrap()
+            .with_translation_provider(Arc::new(StubTranslator));
+        let mut papers = vec![
+            create_paper_result(Some("Nous proposons une méthode pour la segmentation.")),
+            create_paper_result(Some("We propose a method for the segmentation of images.")),
+            create_paper_result(None),
+        ];
+
+        tool.translate_abstracts(&mut papers, &EnrichmentBudget::new(None, None))
+            .await;
+
+        assert_eq!(
+            papers[0].abstract_translated.as_deref(),
+            Some("[en] Nous proposons une méthode pour la segmentation.")
+        );
+        assert_eq!(papers[1].abstract_translated, None);
+        assert_eq!(papers[2].abstract_translated, None);
+    }
+
+    #[tokio::test]
+    async fn test_translate_abstracts_disabled_by_default() {
+        let tool = create_test_search_tool().unwrap();
+        let mut papers = vec![create_paper_result(Some(
+            "Nous proposons une méthode pour la segmentation.",
+        ))];
+
+        tool.translate_abstracts(&mut papers, &EnrichmentBudget::new(None, None))
+            .await;
+
+        assert_eq!(papers[0].abstract_translated, None);
+    }
+
+    /// Provider returning `count` papers with French abstracts
+    fn french_abstracts_provider(count: usize) -> MockProvider {
+        MockProvider::new("french_abstracts").with_papers(
+            (0..count)
+                .map(|i| {
+                    let mut paper = paper(&format!("10.1000/fr{i}"), &format!("Segmentation {i}"));
+                    paper.abstract_text = Some(format!(
+                        "Nous proposons une méthode {i} pour la segmentation."
+                    ));
+                    paper
+                })
+                .collect(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_search_papers_enrichment_budget_leaves_rest_unenriched() {
+        let mut config = (*create_test_config()).clone();
+        config.meta_search.max_enrichment_requests = Some(3);
+        let client = MetaSearchClient::with_providers(
+            vec![Arc::new(french_abstracts_provider(12))],
+            MetaSearchConfig::default(),
+        );
+        let tool = SearchTool::new(Arc::new(config))
+            .unwrap()
+            .with_meta_client(Arc::new(client))
+            .with_translation_provider(Arc::new(StubTranslator));
+
+        let result = tool
+            .search_papers(SearchInput {
+                query: "segmentation".to_string(),
+                search_type: SearchType::Auto,
+                limit: 20,
+                offset: 0,
+                require_pdf: false,
+                verify: false,
+                min_year: None,
+                abstract_required: false,
+                venue: None,
+                year: None,
+                abstract_keywords: Vec::new(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(result.papers.len(), 12);
+        let translated = result
+            .papers
+            .iter()
+            .filter(|p| p.abstract_translated.is_some())
+            .count();
+        assert_eq!(translated, 3);
+        assert!(result
+            .papers
+            .iter()
+            .all(|p| p.metadata.abstract_text.is_some()));
+    }
+
+    #[tokio::test]
+    async fn test_search_papers_too_many_terms_rejected() {
+        let mut config = (*create_test_config()).clone();
+        config.search_terms.max_terms = 5;
+        let tool = SearchTool::new(Arc::new(config)).unwrap();
+
+        let result = tool
+            .search_papers(SearchInput {
+                query: "one two three four five six".to_string(),
+                search_type: SearchType::Auto,
+                limit: 10,
+                offset: 0,
+                require_pdf: false,
+                verify: false,
+                min_year: None,
+                abstract_required: false,
+                venue: None,
+                year: None,
+                abstract_keywords: Vec::new(),
+            })
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(crate::Error::InvalidInput { field, .. }) if field == "query"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_search_papers_too_many_abstract_keywords_rejected() {
+        let mut config = (*create_test_config()).clone();
+        config.search_terms.max_terms = 2;
+        let tool = SearchTool::new(Arc::new(config)).unwrap();
+
+        let result = tool
+            .search_papers(SearchInput {
+                abstract_keywords: vec!["a".to_string(), "b".to_string(), "c".to_string()],
+                ..create_mixed_pdf_input(false, false)
+            })
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(crate::Error::InvalidInput { field, .. }) if field == "abstract_keywords"
+        ));
+    }
+
     #[tokio::test]
     async fn test_cache_operations() {
         let tool = create_test_search_tool().unwrap();
@@ -872,6 +1908,13 @@ mod tests {
             search_type: SearchType::Title,
             limit: 10,
             offset: 0,
+            require_pdf: false,
+            verify: false,
+            min_year: None,
+            abstract_required: false,
+            venue: None,
+            year: None,
+            abstract_keywords: Vec::new(),
         };
 
         let result = SearchResult {
@@ -880,6 +1923,7 @@ mod tests {
             papers: vec![],
             total_count: 0,
             returned_count: 0,
+            filtered_count: 0,
             offset: 0,
             has_more: false,
             search_time_ms: 100,
@@ -889,6 +1933,7 @@ mod tests {
             failed_providers: vec![],
             provider_errors: HashMap::new(),
             papers_per_provider: HashMap::new(),
+            timings_ms: HashMap::new(),
         };
 
         let cache_key = SearchTool::generate_cache_key(&input);
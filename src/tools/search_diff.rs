@@ -0,0 +1,292 @@
+use crate::client::MetaSearchResult;
+use crate::tools::search::{PaperResult, SearchInput, SearchTool, SearchType};
+use crate::Result;
+use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tracing::{info, instrument, warn};
+
+/// Input for the search diff tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SearchDiffInput {
+    /// Query to run
+    pub query: String,
+    /// Type of search to perform
+    #[serde(default)]
+    pub search_type: SearchType,
+    /// Maximum number of results to fetch (default: 10)
+    #[serde(default = "default_limit")]
+    pub limit: u32,
+    /// `result_set_id` returned by a previous `search_diff` run. Omit it to
+    /// start tracking the query; every result is then new.
+    #[serde(default)]
+    pub since: Option<String>,
+}
+
+const fn default_limit() -> u32 {
+    10
+}
+
+/// Papers that appeared since a previous run of the same search
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SearchDiffResult {
+    /// Query that was executed
+    pub query: String,
+    /// Id to pass as `since` next time. It covers every paper seen so far.
+    pub result_set_id: String,
+    /// Result set the search was compared against
+    pub since: Option<String>,
+    /// When the compared result set was saved
+    pub since_created_at: Option<DateTime<Utc>>,
+    /// Papers not present in the compared result set
+    pub new_papers: Vec<PaperResult>,
+    /// Number of results that had been seen before
+    pub previously_seen: usize,
+}
+
+/// Signatures of every paper seen by a tracked search, saved between runs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ResultSet {
+    id: String,
+    query: String,
+    created_at: DateTime<Utc>,
+    signatures: BTreeSet<String>,
+}
+
+/// Runs a search and reports only the papers that are new since a saved
+/// result set
+#[derive(Debug, Clone)]
+pub struct SearchDiffTool {
+    search_tool: Arc<SearchTool>,
+    directory: PathBuf,
+}
+
+impl SearchDiffTool {
+    /// Create a diff tool that searches with `search_tool` and saves result
+    /// sets in `directory`
+    #[must_use]
+    pub fn new(search_tool: Arc<SearchTool>, directory: PathBuf) -> Self {
+        Self {
+            search_tool,
+            directory,
+        }
+    }
+
+    /// Run the search, compare it with the `since` result set and save the
+    /// union of both as a new result set
+    #[instrument(skip(self), fields(query = %input.query))]
+    pub async fn diff(&self, input: SearchDiffInput) -> Result<SearchDiffResult> {
+        let previous = match input.since.as_deref() {
+            Some(id) => Some(self.load(id).await?),
+            None => None,
+        };
+        if let Some(previous) = &previous {
+            if !previous.query.eq_ignore_ascii_case(input.query.trim()) {
+                warn!(
+                    "Result set {} was saved for query '{}', comparing with '{}'",
+                    previous.id, previous.query, input.query
+                );
+            }
+        }
+
+        // Always search fresh; a cached result would hide new papers
+        let result = self
+            .search_tool
+            .search_papers_fresh(SearchInput {
+                query: input.query.clone(),
+                search_type: input.search_type,
+                limit: input.limit,
+                offset: 0,
+                require_pdf: false,
+                verify: false,
+                min_year: None,
+                abstract_required: false,
+                venue: None,
+                year: None,
+                abstract_keywords: Vec::new(),
+            })
+            .await?;
+
+        let mut signatures = previous
+            .as_ref()
+            .map(|p| p.signatures.clone())
+            .unwrap_or_default();
+        let mut new_papers = Vec::new();
+        let mut previously_seen = 0;
+        for paper in result.papers {
+            if signatures.insert(MetaSearchResult::paper_key(&paper.metadata)) {
+                new_papers.push(paper);
+            } else {
+                previously_seen += 1;
+            }
+        }
+
+        let result_set = ResultSet {
+            id: uuid::Uuid::new_v4().to_string(),
+            query: input.query.trim().to_string(),
+            created_at: Utc::now(),
+            signatures,
+        };
+        self.save(&result_set).await?;
+
+        info!(
+            "Search diff for '{}': {} new, {} previously seen",
+            input.query,
+            new_papers.len(),
+            previously_seen
+        );
+        Ok(SearchDiffResult {
+            query: input.query,
+            result_set_id: result_set.id,
+            since: previous.as_ref().map(|p| p.id.clone()),
+            since_created_at: previous.map(|p| p.created_at),
+            new_papers,
+            previously_seen,
+        })
+    }
+
+    fn path_for(&self, id: &str) -> Result<PathBuf> {
+        if id.is_empty() || !id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+            return Err(crate::Error::InvalidInput {
+                field: "since".to_string(),
+                reason: format!("Invalid result set id: {id}"),
+            });
+        }
+        Ok(self.directory.join(format!("{id}.json")))
+    }
+
+    async fn load(&self, id: &str) -> Result<ResultSet> {
+        let path = self.path_for(id)?;
+        let contents = match tokio::fs::read_to_string(&path).await {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                return Err(crate::Error::InvalidInput {
+                    field: "since".to_string(),
+                    reason: format!("Unknown result set id: {id}"),
+                });
+            }
+            Err(e) => return Err(e.into()),
+        };
+        Ok(serde_json::from_str(&contents)?)
+    }
+
+    async fn save(&self, result_set: &ResultSet) -> Result<()> {
+        let path = self.path_for(&result_set.id)?;
+        tokio::fs::create_dir_all(&self.directory).await?;
+        tokio::fs::write(&path, serde_json::to_vec_pretty(result_set)?).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::client::providers::{
+        ProviderError, ProviderResult, SearchContext, SearchQuery,
+        SearchType as ProviderSearchType, SourceProvider,
+    };
+    use crate::client::{MetaSearchClient, MetaSearchConfig, PaperMetadata};
+    use crate::Config;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+    use std::time::Duration;
+    use tempfile::TempDir;
+
+    /// Provider returning the next DOI list on each search
+    struct RoundsProvider {
+        rounds: Mutex<Vec<Vec<&'static str>>>,
+    }
+
+    #[async_trait::async_trait]
+    impl SourceProvider for RoundsProvider {
+        fn name(&self) -> &'static str {
+            "rounds"
+        }
+
+        fn base_delay(&self) -> Duration {
+            Duration::ZERO
+        }
+
+        fn supported_search_types(&self) -> Vec<ProviderSearchType> {
+            vec![ProviderSearchType::Auto]
+        }
+
+        async fn search(
+            &self,
+            _query: &SearchQuery,
+            _context: &SearchContext,
+        ) -> std::result::Result<ProviderResult, ProviderError> {
+            let dois = self.rounds.lock().unwrap().remove(0);
+            let papers = dois
+                .into_iter()
+                .map(|doi| {
+                    let mut paper = PaperMetadata::new(doi.to_string());
+                    paper.title = Some(format!("Paper {doi}"));
+                    paper
+                })
+                .collect();
+
+            Ok(ProviderResult {
+                papers,
+                source: self.name().to_string(),
+                total_available: None,
+                search_time: Duration::ZERO,
+                has_more: false,
+                metadata: HashMap::new(),
+            })
+        }
+    }
+
+    /// A search tool whose only provider returns `rounds` in turn, one DOI
+    /// list per search
+    pub fn rounds_search_tool(rounds: Vec<Vec<&'static str>>) -> SearchTool {
+        let client = MetaSearchClient::with_providers(
+            vec![Arc::new(RoundsProvider {
+                rounds: Mutex::new(rounds),
+            })],
+            MetaSearchConfig::default(),
+        );
+        SearchTool::new(Arc::new(Config::default()))
+            .unwrap()
+            .with_meta_client(Arc::new(client))
+    }
+
+    #[tokio::test]
+    async fn test_diff_returns_only_new_papers() {
+        let search_tool = rounds_search_tool(vec![
+            vec!["10.1000/a", "10.1000/b"],
+            vec!["10.1000/b", "10.1000/c", "10.1000/a"],
+        ]);
+        let temp_dir = TempDir::new().unwrap();
+        let tool = SearchDiffTool::new(Arc::new(search_tool), temp_dir.path().to_path_buf());
+        let input = |since: Option<String>| SearchDiffInput {
+            query: "protein folding".to_string(),
+            search_type: SearchType::Auto,
+            limit: 10,
+            since,
+        };
+
+        let first = tool.diff(input(None)).await.unwrap();
+        assert_eq!(first.new_papers.len(), 2);
+        assert!(first.since.is_none());
+
+        let second = tool
+            .diff(input(Some(first.result_set_id.clone())))
+            .await
+            .unwrap();
+        let new_dois: Vec<&str> = second
+            .new_papers
+            .iter()
+            .map(|p| p.metadata.doi.as_str())
+            .collect();
+        assert_eq!(new_dois, vec!["10.1000/c"]);
+        assert_eq!(second.previously_seen, 2);
+        assert_eq!(second.since.as_deref(), Some(first.result_set_id.as_str()));
+
+        let unknown = tool.diff(input(Some("../../etc/passwd".to_string()))).await;
+        assert!(matches!(unknown, Err(crate::Error::InvalidInput { .. })));
+    }
+}
@@ -0,0 +1,460 @@
+use crate::client::providers::{
+    unpaywall, OpenAlexProvider, ProviderError, ProviderSignals, SearchContext,
+    SemanticScholarProvider, UnpaywallProvider,
+};
+use crate::{Config, Result};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{debug, info, instrument};
+
+/// Input for the paper signals tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PaperSignalsInput {
+    /// DOI of the paper to gather signals for
+    #[schemars(description = "DOI of the paper, e.g. 10.1038/nature14539")]
+    pub doi: String,
+}
+
+/// Triage signals for a paper. Signals no source reported are `None`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct PaperSignals {
+    /// DOI the signals were gathered for
+    pub doi: String,
+    /// Number of papers citing this one
+    pub citation_count: Option<u64>,
+    /// Citations Semantic Scholar classifies as influential
+    pub influential_citation_count: Option<u64>,
+    /// Number of works this paper references
+    pub reference_count: Option<u64>,
+    /// Whether a free, legal copy exists
+    pub is_open_access: Option<bool>,
+    /// Open access route (gold, green, hybrid, bronze, closed)
+    pub oa_status: Option<String>,
+    /// Source that reported each signal, keyed by signal name
+    pub sources: BTreeMap<String, String>,
+    /// Sources that could not be queried, with the reason
+    pub errors: BTreeMap<String, String>,
+}
+
+impl PaperSignals {
+    /// Compact, human-readable summary listing every signal that was found
+    #[must_use]
+    pub fn summary(&self) -> String {
+        let source = |signal: &str| {
+            self.sources
+                .get(signal)
+                .map(|source| format!(" ({source})"))
+                .unwrap_or_default()
+        };
+
+        let mut lines = vec![format!("📈 Signals for {}", self.doi)];
+        if let Some(count) = self.citation_count {
+            lines.push(format!("• Citations: {count}{}", source("citation_count")));
+        }
+        if let Some(count) = self.influential_citation_count {
+            lines.push(format!(
+                "• Influential citations: {count}{}",
+                source("influential_citation_count")
+            ));
+        }
+        if let Some(count) = self.reference_count {
+            lines.push(format!(
+                "• References: {count}{}",
+                source("reference_count")
+            ));
+        }
+        if let Some(is_oa) = self.is_open_access {
+            let status = self
+                .oa_status
+                .as_deref()
+                .map(|status| format!(", {status}"))
+                .unwrap_or_default();
+            lines.push(format!(
+                "• Open access: {}{status}{}",
+                if is_oa { "yes" } else { "no" },
+                source("is_open_access")
+            ));
+        }
+        if lines.len() == 1 {
+            lines.push("• No signals available".to_string());
+        }
+
+        if !self.errors.is_empty() {
+            let unavailable = self
+                .errors
+                .iter()
+                .map(|(source, reason)| format!("{source} ({reason})"))
+                .collect::<Vec<_>>()
+                .join(", ");
+            lines.push(format!("⚠️ Unavailable: {unavailable}"));
+        }
+
+        lines.join("\n")
+    }
+
+    fn set_count(&mut self, signal: CountSignal, value: Option<u64>, source: &str) {
+        let field = match signal {
+            CountSignal::Citations => &mut self.citation_count,
+            CountSignal::InfluentialCitations => &mut self.influential_citation_count,
+            CountSignal::References => &mut self.reference_count,
+        };
+        if field.is_none() && value.is_some() {
+            *field = value;
+            self.sources
+                .insert(signal.key().to_string(), source.to_string());
+        }
+    }
+
+    fn set_open_access(&mut self, is_oa: Option<bool>, oa_status: Option<String>, source: &str) {
+        if self.is_open_access.is_none() && is_oa.is_some() {
+            self.is_open_access = is_oa;
+            self.oa_status = oa_status;
+            self.sources
+                .insert("is_open_access".to_string(), source.to_string());
+        }
+    }
+
+    /// Fill in the signals `source` reported that no earlier source did, or
+    /// record why it was unavailable
+    fn merge(
+        &mut self,
+        source: &str,
+        result: std::result::Result<Option<ProviderSignals>, ProviderError>,
+    ) {
+        match result {
+            Ok(Some(reported)) => {
+                self.set_count(CountSignal::Citations, reported.citation_count, source);
+                self.set_count(
+                    CountSignal::InfluentialCitations,
+                    reported.influential_citation_count,
+                    source,
+                );
+                self.set_count(CountSignal::References, reported.reference_count, source);
+                self.set_open_access(reported.is_open_access, reported.oa_status, source);
+            }
+            Ok(None) => {
+                self.errors
+                    .insert(source.to_string(), "not found".to_string());
+            }
+            Err(e) => {
+                self.errors
+                    .insert(source.to_string(), unavailable_reason(&e));
+            }
+        }
+    }
+}
+
+/// Count signals, keyed in [`PaperSignals::sources`] by [`CountSignal::key`]
+#[derive(Debug, Clone, Copy)]
+enum CountSignal {
+    Citations,
+    InfluentialCitations,
+    References,
+}
+
+impl CountSignal {
+    const fn key(self) -> &'static str {
+        match self {
+            Self::Citations => "citation_count",
+            Self::InfluentialCitations => "influential_citation_count",
+            Self::References => "reference_count",
+        }
+    }
+}
+
+/// Gathers citation, reference and open access signals for a DOI from the
+/// Semantic Scholar, Unpaywall and OpenAlex providers
+pub struct PaperSignalsTool {
+    semantic_scholar: SemanticScholarProvider,
+    openalex: OpenAlexProvider,
+    unpaywall: UnpaywallProvider,
+    context: SearchContext,
+}
+
+impl std::fmt::Debug for PaperSignalsTool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PaperSignalsTool")
+            .field("context", &self.context)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PaperSignalsTool {
+    /// Create a new paper signals tool
+    pub fn new(config: Arc<Config>) -> Result<Self> {
+        let http = &config.http;
+        let provider_error =
+            |e: ProviderError| crate::Error::Service(format!("Failed to create provider: {e}"));
+
+        Ok(Self {
+            semantic_scholar: SemanticScholarProvider::with_http_config(None, http)
+                .map_err(provider_error)?,
+            openalex: OpenAlexProvider::with_http_config(http).map_err(provider_error)?,
+            unpaywall: UnpaywallProvider::with_http_config(
+                unpaywall::DEFAULT_EMAIL.to_string(),
+                http,
+            )
+            .map_err(provider_error)?,
+            context: SearchContext {
+                timeout: Duration::from_secs(config.research_source.timeout_secs),
+                user_agent: "knowledge_accumulator_mcp/0.2.1 (Academic Research Tool)".to_string(),
+                rate_limit: None,
+                headers: HashMap::new(),
+                max_response_bytes: (http.max_response_bytes > 0)
+                    .then_some(http.max_response_bytes),
+                default_charset: None,
+            },
+        })
+    }
+
+    /// Query these providers instead of the default ones
+    #[must_use]
+    pub fn with_providers(
+        mut self,
+        semantic_scholar: SemanticScholarProvider,
+        openalex: OpenAlexProvider,
+        unpaywall: UnpaywallProvider,
+    ) -> Self {
+        self.semantic_scholar = semantic_scholar;
+        self.openalex = openalex;
+        self.unpaywall = unpaywall;
+        self
+    }
+
+    /// Gather signals for a DOI. Sources that fail or do not know the paper
+    /// are recorded in `errors` and the remaining signals are still returned.
+    #[instrument(skip(self), fields(doi = %input.doi))]
+    pub async fn gather(&self, input: PaperSignalsInput) -> Result<PaperSignals> {
+        let doi = normalize_doi(&input.doi);
+        if doi.is_empty() || !doi.starts_with("10.") {
+            return Err(crate::Error::InvalidInput {
+                field: "doi".to_string(),
+                reason: format!("Not a valid DOI: {}", input.doi),
+            });
+        }
+        info!("Gathering paper signals for {}", doi);
+
+        let (semantic_scholar, openalex, unpaywall) = tokio::join!(
+            self.semantic_scholar.get_signals(doi, &self.context),
+            self.openalex.get_signals(doi, &self.context),
+            self.unpaywall.get_signals(doi, &self.context),
+        );
+
+        // Earlier sources win when several report the same signal
+        let mut signals = PaperSignals {
+            doi: doi.to_string(),
+            ..PaperSignals::default()
+        };
+        signals.merge("semantic_scholar", semantic_scholar);
+        signals.merge("unpaywall", unpaywall);
+        signals.merge("openalex", openalex);
+
+        debug!(
+            "Gathered {} signals for {} ({} sources unavailable)",
+            signals.sources.len(),
+            doi,
+            signals.errors.len()
+        );
+        Ok(signals)
+    }
+}
+
+/// Short reason a source could not be queried
+fn unavailable_reason(error: &ProviderError) -> String {
+    match error {
+        ProviderError::RateLimit => "rate limited".to_string(),
+        ProviderError::ServiceUnavailable(reason) | ProviderError::Network(reason) => {
+            reason.clone()
+        }
+        other => other.to_string(),
+    }
+}
+
+/// Strip `doi:` and doi.org URL prefixes
+fn normalize_doi(doi: &str) -> &str {
+    let doi = doi.trim();
+    ["https://doi.org/", "http://doi.org/", "doi:"]
+        .iter()
+        .find_map(|prefix| {
+            doi.get(..prefix.len())
+                .filter(|head| head.eq_ignore_ascii_case(prefix))
+                .map(|_| doi[prefix.len()..].trim())
+        })
+        .unwrap_or(doi)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    const DOI: &str = "10.1038/nature14539";
+
+    fn create_test_tool(mock_server: &MockServer) -> PaperSignalsTool {
+        PaperSignalsTool::new(Arc::new(Config::default()))
+            .unwrap()
+            .with_providers(
+                SemanticScholarProvider::new(None)
+                    .unwrap()
+                    .with_base_url(format!("{}/s2", mock_server.uri())),
+                OpenAlexProvider::new()
+                    .unwrap()
+                    .with_base_url(format!("{}/openalex/works", mock_server.uri())),
+                UnpaywallProvider::new_with_default_email()
+                    .unwrap()
+                    .with_base_url(format!("{}/unpaywall", mock_server.uri())),
+            )
+    }
+
+    /// Request paths of the three sources for `doi`
+    fn source_paths(doi: &str) -> [String; 3] {
+        let doi = urlencoding::encode(doi);
+        [
+            format!("/s2/paper/DOI:{doi}"),
+            format!("/openalex/works/doi:{doi}"),
+            format!("/unpaywall/v2/{doi}"),
+        ]
+    }
+
+    #[tokio::test]
+    async fn test_gather_combines_signals_from_all_sources() {
+        let mock_server = MockServer::start().await;
+        let [s2_path, openalex_path, unpaywall_path] = source_paths(DOI);
+        Mock::given(method("GET"))
+            .and(path(s2_path.as_str()))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "paperId": "abc",
+                "citationCount": 52000,
+                "influentialCitationCount": 2100,
+                "referenceCount": null
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path(openalex_path.as_str()))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "cited_by_count": 48000,
+                "referenced_works_count": 103,
+                "open_access": {"is_oa": false, "oa_status": "closed"}
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path(unpaywall_path.as_str()))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "doi": DOI,
+                "is_oa": true,
+                "oa_status": "bronze"
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let signals = create_test_tool(&mock_server)
+            .gather(PaperSignalsInput {
+                doi: format!("https://doi.org/{DOI}"),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(signals.citation_count, Some(52000));
+        assert_eq!(signals.reference_count, Some(103));
+        assert_eq!(signals.sources["reference_count"], "openalex");
+        assert!(signals.errors.is_empty());
+
+        let summary = signals.summary();
+        assert!(summary.contains("Citations: 52000 (semantic_scholar)"));
+        assert!(summary.contains("Influential citations: 2100 (semantic_scholar)"));
+        assert!(summary.contains("References: 103 (openalex)"));
+        assert!(summary.contains("Open access: yes, bronze (unpaywall)"));
+    }
+
+    #[tokio::test]
+    async fn test_gather_missing_sources_reported_as_unavailable() {
+        let mock_server = MockServer::start().await;
+        let [s2_path, openalex_path, unpaywall_path] = source_paths(DOI);
+        Mock::given(method("GET"))
+            .and(path(s2_path.as_str()))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path(openalex_path.as_str()))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "cited_by_count": 48000
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path(unpaywall_path.as_str()))
+            .respond_with(ResponseTemplate::new(503))
+            .mount(&mock_server)
+            .await;
+
+        let signals = create_test_tool(&mock_server)
+            .gather(PaperSignalsInput {
+                doi: DOI.to_string(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(signals.citation_count, Some(48000));
+        assert_eq!(signals.is_open_access, None);
+
+        let summary = signals.summary();
+        assert!(summary.contains("Citations: 48000 (openalex)"));
+        assert!(!summary.contains("Open access"));
+        assert!(summary.contains("semantic_scholar (not found)"));
+        assert!(summary.contains("unpaywall (HTTP 503)"));
+    }
+
+    #[tokio::test]
+    async fn test_gather_doi_special_characters_percent_encoded() {
+        // SICI-style DOIs contain characters that end a URL path
+        let doi = "10.1002/(SICI)1097-4571(199806)49:8<693::AID-ASI4>3.0.CO;2-#";
+        let mock_server = MockServer::start().await;
+        let [s2_path, openalex_path, unpaywall_path] = source_paths(doi);
+        assert!(s2_path.ends_with("3.0.CO%3B2-%23"));
+        for (path_str, body) in [
+            (s2_path, serde_json::json!({"citationCount": 7})),
+            (
+                openalex_path,
+                serde_json::json!({"referenced_works_count": 12}),
+            ),
+            (unpaywall_path, serde_json::json!({"is_oa": false})),
+        ] {
+            Mock::given(method("GET"))
+                .and(path(path_str.as_str()))
+                .respond_with(ResponseTemplate::new(200).set_body_json(body))
+                .expect(1)
+                .mount(&mock_server)
+                .await;
+        }
+
+        let signals = create_test_tool(&mock_server)
+            .gather(PaperSignalsInput {
+                doi: doi.to_string(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(signals.citation_count, Some(7));
+        assert_eq!(signals.reference_count, Some(12));
+        assert_eq!(signals.is_open_access, Some(false));
+        assert!(signals.errors.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_gather_invalid_doi_rejected() {
+        let mock_server = MockServer::start().await;
+        let result = create_test_tool(&mock_server)
+            .gather(PaperSignalsInput {
+                doi: "not a doi".to_string(),
+            })
+            .await;
+        assert!(matches!(result, Err(crate::Error::InvalidInput { .. })));
+    }
+}
@@ -37,11 +37,14 @@ async fn test_categorization_feature_integration() {
             doi: "10.1000/ml1".to_string(),
             title: Some("Deep Learning for Image Classification".to_string()),
             authors: vec!["Smith, J.".to_string()],
+            raw_authors: Vec::new(),
             journal: Some("Nature Machine Intelligence".to_string()),
             year: Some(2024),
             abstract_text: Some("This paper presents a deep learning approach using neural networks for image classification tasks.".to_string()),
             pdf_url: None,
             file_size: None,
+            license: None,
+            is_open_access: None,
         }
     ];
 
@@ -75,7 +78,10 @@ async fn test_categorization_feature_integration() {
         directory: Some(temp_dir.path().to_string_lossy().to_string()),
         category: Some("machine_learning".to_string()),
         overwrite: true,
-        verify_integrity: false,
+        verify_integrity: Some(false),
+        title: None,
+        write_sidecar: None,
+        headers: None,
     };
 
     // Note: This would fail in actual download because the DOI doesn't exist,
@@ -102,31 +108,40 @@ async fn test_categorization_workflow() {
             doi: "10.1000/ml".to_string(),
             title: Some("Machine Learning in Healthcare".to_string()),
             authors: vec!["AI Researcher".to_string()],
+            raw_authors: Vec::new(),
             journal: None,
             year: Some(2024),
             abstract_text: Some("This study explores machine learning applications in medical diagnosis and healthcare systems.".to_string()),
             pdf_url: None,
             file_size: None,
+            license: None,
+            is_open_access: None,
         },
         PaperMetadata {
             doi: "10.1000/quantum".to_string(),
             title: Some("Quantum Computing Algorithms".to_string()),
             authors: vec!["Quantum Physicist".to_string()],
+            raw_authors: Vec::new(),
             journal: None,
             year: Some(2024),
             abstract_text: Some("We present novel quantum algorithms for solving complex computational problems in quantum computing.".to_string()),
             pdf_url: None,
             file_size: None,
+            license: None,
+            is_open_access: None,
         },
         PaperMetadata {
             doi: "10.1000/agent".to_string(),
             title: Some("Multi-Agent Systems with Memory".to_string()),
             authors: vec!["Agent Researcher".to_string()],
+            raw_authors: Vec::new(),
             journal: None,
             year: Some(2024),
             abstract_text: Some("This work explores multi-agent systems, agent coordination, and episodic memory mechanisms.".to_string()),
             pdf_url: None,
             file_size: None,
+            license: None,
+            is_open_access: None,
         },
     ];
 
@@ -182,11 +197,14 @@ async fn test_categorization_disabled() {
         doi: "10.1000/test".to_string(),
         title: Some("Test Paper".to_string()),
         authors: vec!["Author".to_string()],
+        raw_authors: Vec::new(),
         journal: None,
         year: Some(2024),
         abstract_text: Some("Test abstract".to_string()),
         pdf_url: None,
         file_size: None,
+        license: None,
+        is_open_access: None,
     }];
 
     let result = tool
@@ -21,31 +21,40 @@ fn create_test_papers() -> Vec<PaperMetadata> {
             doi: "10.1000/ml1".to_string(),
             title: Some("Deep Learning for Medical Image Analysis".to_string()),
             authors: vec!["Smith, J.".to_string(), "Doe, A.".to_string()],
+            raw_authors: Vec::new(),
             journal: Some("Nature Machine Intelligence".to_string()),
             year: Some(2024),
             abstract_text: Some("This paper presents a novel deep learning approach for analyzing medical images using neural networks and machine learning techniques.".to_string()),
             pdf_url: None,
             file_size: None,
+            license: None,
+            is_open_access: None,
         },
         PaperMetadata {
             doi: "10.1000/quantum1".to_string(),
             title: Some("Quantum Computing Algorithms for Chemistry".to_string()),
             authors: vec!["Einstein, A.".to_string()],
+            raw_authors: Vec::new(),
             journal: Some("Physical Review A".to_string()),
             year: Some(2024),
             abstract_text: Some("We develop quantum algorithms for simulating molecular systems and quantum chemistry calculations.".to_string()),
             pdf_url: None,
             file_size: None,
+            license: None,
+            is_open_access: None,
         },
         PaperMetadata {
             doi: "10.1000/agent1".to_string(),
             title: Some("Multi-Agent Systems with Memory".to_string()),
             authors: vec!["Agent, M.".to_string()],
+            raw_authors: Vec::new(),
             journal: Some("Journal of AI Research".to_string()),
             year: Some(2024),
             abstract_text: Some("This work explores multi-agent systems with episodic memory and agent coordination mechanisms.".to_string()),
             pdf_url: None,
             file_size: None,
+            license: None,
+            is_open_access: None,
         },
     ]
 }
@@ -184,11 +193,14 @@ async fn test_categorize_tool_input_validation() {
             doi: format!("10.1000/paper{}", i),
             title: Some(format!("Paper {}", i)),
             authors: vec!["Author".to_string()],
+            raw_authors: Vec::new(),
             journal: None,
             year: Some(2024),
             abstract_text: None,
             pdf_url: None,
             file_size: None,
+            license: None,
+            is_open_access: None,
         })
         .collect();
 
@@ -245,11 +257,14 @@ async fn test_categorize_tool_heuristic_categorization() {
             doi: "10.1000/unknown".to_string(),
             title: Some("Some Random Paper".to_string()),
             authors: vec!["Unknown".to_string()],
+            raw_authors: Vec::new(),
             journal: None,
             year: Some(2024),
             abstract_text: Some("This is about something completely different.".to_string()),
             pdf_url: None,
             file_size: None,
+            license: None,
+            is_open_access: None,
         }],
         max_abstracts: Some(1),
     };
@@ -378,11 +393,14 @@ async fn test_categorization_prompt_truncation() {
         doi: "10.1000/long".to_string(),
         title: Some("Long Paper".to_string()),
         authors: vec!["Author".to_string()],
+        raw_authors: Vec::new(),
         journal: None,
         year: Some(2024),
         abstract_text: Some("A".repeat(2000)), // Very long abstract
         pdf_url: None,
         file_size: None,
+        license: None,
+        is_open_access: None,
     }];
 
     let prompt = service.generate_category_prompt("test query", &papers);
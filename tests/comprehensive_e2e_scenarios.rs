@@ -6,7 +6,7 @@ use rust_research_mcp::{
     tools::{
         categorize::CategorizeInput,
         download::DownloadInput,
-        metadata::MetadataInput,
+        metadata::{MetadataInput, MetadataOutputFormat},
         search::{SearchInput, SearchType as ToolSearchType},
         BibliographyTool, CategorizeTool, DownloadTool, MetadataExtractor, SearchTool,
     },
@@ -99,6 +99,7 @@ fn create_domain_queries() -> HashMap<String, Vec<SearchQuery>> {
 // =============================================================================
 
 #[tokio::test]
+#[allow(clippy::too_many_lines)] // One test walks the whole search, download and metadata journey
 async fn test_complete_research_workflow() {
     let config = create_comprehensive_test_config();
     let meta_config = MetaSearchConfig::default();
@@ -124,6 +125,13 @@ async fn test_complete_research_workflow() {
         search_type: ToolSearchType::Title,
         limit: 5,
         offset: 0,
+        require_pdf: false,
+        verify: false,
+        min_year: None,
+        abstract_required: false,
+        venue: None,
+        year: None,
+        abstract_keywords: Vec::new(),
     };
 
     let start_time = Instant::now();
@@ -150,7 +158,10 @@ async fn test_complete_research_workflow() {
                 directory: None,
                 category: Some("machine_learning".to_string()),
                 overwrite: true,
-                verify_integrity: true,
+                verify_integrity: Some(true),
+                title: None,
+                write_sidecar: None,
+                headers: None,
             };
 
             let download_start = Instant::now();
@@ -166,7 +177,9 @@ async fn test_complete_research_workflow() {
                             use_cache: false,
                             validate_external: true,
                             extract_references: true,
+                            extract_captions: false,
                             batch_files: None,
+                            output_format: MetadataOutputFormat::default(),
                         };
 
                         let metadata_start = Instant::now();
@@ -284,10 +297,18 @@ async fn test_concurrent_research_sessions() {
                         SearchType::Author => ToolSearchType::Author,
                         SearchType::Keywords => ToolSearchType::Title, // Map Keywords to Title
                         SearchType::Subject => ToolSearchType::Title,  // Map Subject to Title
-                                                                        // AuthorYear doesn't exist in client::providers::SearchType
+                        SearchType::FullText => ToolSearchType::FullText,
+                        // AuthorYear doesn't exist in client::providers::SearchType
                     },
                     limit: query.max_results,
                     offset: query.offset,
+                    require_pdf: false,
+                    verify: false,
+                    min_year: None,
+                    abstract_required: false,
+                    venue: None,
+                    year: None,
+                    abstract_keywords: Vec::new(),
                 };
 
                 let start_time = Instant::now();
@@ -377,7 +398,9 @@ async fn test_large_scale_batch_operations() {
         use_cache: true,
         validate_external: false,
         extract_references: false,
+        extract_captions: false,
         batch_files: Some(test_files.clone()),
+        output_format: MetadataOutputFormat::default(),
     };
 
     let batch_start = Instant::now();
@@ -423,6 +446,13 @@ async fn test_error_recovery_workflows() {
             search_type: ToolSearchType::Auto,
             limit: 10,
             offset: 0,
+            require_pdf: false,
+            verify: false,
+            min_year: None,
+            abstract_required: false,
+            venue: None,
+            year: None,
+            abstract_keywords: Vec::new(),
         };
 
         let result = search_tool.search_papers(search_input).await;
@@ -505,6 +535,13 @@ async fn test_circuit_breaker_behavior() {
                 search_type: ToolSearchType::Title,
                 limit: 1,
                 offset: 0,
+                require_pdf: false,
+                verify: false,
+                min_year: None,
+                abstract_required: false,
+                venue: None,
+                year: None,
+                abstract_keywords: Vec::new(),
             };
 
             search_tool.search_papers(search_input).await
@@ -558,7 +595,10 @@ async fn test_resource_cleanup_and_limits() {
         directory: None,
         category: None,
         overwrite: true,
-        verify_integrity: false,
+        verify_integrity: Some(false),
+        title: None,
+        write_sidecar: None,
+        headers: None,
     };
 
     // This should respect file size limits configured in the system
@@ -603,6 +643,13 @@ async fn test_concurrent_request_performance() {
                 search_type: ToolSearchType::Title,
                 limit: 1,
                 offset: 0,
+                require_pdf: false,
+                verify: false,
+                min_year: None,
+                abstract_required: false,
+                venue: None,
+                year: None,
+                abstract_keywords: Vec::new(),
             };
 
             let request_start = Instant::now();
@@ -695,6 +742,13 @@ async fn test_memory_usage_under_load() {
                     search_type: ToolSearchType::Title,
                     limit: 5,
                     offset: 0,
+                    require_pdf: false,
+                    verify: false,
+                    min_year: None,
+                    abstract_required: false,
+                    venue: None,
+                    year: None,
+                    abstract_keywords: Vec::new(),
                 };
 
                 search_tool.search_papers(search_input).await
@@ -766,7 +820,10 @@ async fn test_security_input_validation() {
             directory: None,
             category: None,
             overwrite: false,
-            verify_integrity: false,
+            verify_integrity: Some(false),
+            title: None,
+            write_sidecar: None,
+            headers: None,
         };
 
         let result = download_tool.download_paper(download_input).await;
@@ -795,6 +852,13 @@ async fn test_security_input_validation() {
             search_type: ToolSearchType::Auto,
             limit: 10,
             offset: 0,
+            require_pdf: false,
+            verify: false,
+            min_year: None,
+            abstract_required: false,
+            venue: None,
+            year: None,
+            abstract_keywords: Vec::new(),
         };
 
         let result = search_tool.search_papers(search_input).await;
@@ -834,6 +898,13 @@ async fn test_rate_limiting_enforcement() {
             search_type: ToolSearchType::Title,
             limit: 1,
             offset: 0,
+            require_pdf: false,
+            verify: false,
+            min_year: None,
+            abstract_required: false,
+            venue: None,
+            year: None,
+            abstract_keywords: Vec::new(),
         };
 
         let _result = search_tool.search_papers(search_input).await;
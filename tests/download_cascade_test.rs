@@ -30,7 +30,10 @@ async fn test_download_cascade_with_provider_failures() -> Result<()> {
         directory: None,
         category: None,
         overwrite: false,
-        verify_integrity: false,
+        verify_integrity: Some(false),
+        title: None,
+        write_sidecar: None,
+        headers: None,
     };
 
     // This should attempt the cascade and eventually fail gracefully
@@ -70,7 +73,10 @@ async fn test_url_validation_error_handling() -> Result<()> {
         directory: None,
         category: None,
         overwrite: false,
-        verify_integrity: false,
+        verify_integrity: Some(false),
+        title: None,
+        write_sidecar: None,
+        headers: None,
     };
 
     let result = download_tool.download_paper(download_input).await;
@@ -98,7 +104,10 @@ async fn test_input_validation() -> Result<()> {
         directory: None,
         category: None,
         overwrite: false,
-        verify_integrity: false,
+        verify_integrity: Some(false),
+        title: None,
+        write_sidecar: None,
+        headers: None,
     };
 
     let result = download_tool.download_paper(download_input).await;
@@ -1,6 +1,6 @@
 use rust_research_mcp::client::meta_search::{MetaSearchClient, MetaSearchConfig};
 use rust_research_mcp::tools::download::{DownloadInput, DownloadTool};
-use rust_research_mcp::tools::metadata::{MetadataExtractor, MetadataInput};
+use rust_research_mcp::tools::metadata::{MetadataExtractor, MetadataInput, MetadataOutputFormat};
 use rust_research_mcp::{Config, Result};
 use std::sync::Arc;
 use tempfile::TempDir;
@@ -31,7 +31,10 @@ async fn test_e2e_download_and_metadata_flow() -> Result<()> {
         directory: None,
         category: Some("machine_learning".to_string()),
         overwrite: true,
-        verify_integrity: false, // Skip integrity check for speed
+        verify_integrity: Some(false), // Skip integrity check for speed
+        title: None,
+        write_sidecar: None,
+        headers: None,
     };
 
     // Attempt the download (this might fail in CI environments without internet)
@@ -49,7 +52,9 @@ async fn test_e2e_download_and_metadata_flow() -> Result<()> {
                     use_cache: false,
                     validate_external: false,
                     extract_references: false,
+                    extract_captions: false,
                     batch_files: None,
+                    output_format: MetadataOutputFormat::default(),
                 };
 
                 let metadata_result = metadata_extractor.extract_metadata(metadata_input).await?;
@@ -104,7 +109,9 @@ async fn test_metadata_extraction_error_handling() -> Result<()> {
         use_cache: false,
         validate_external: false,
         extract_references: false,
+        extract_captions: false,
         batch_files: None,
+        output_format: MetadataOutputFormat::default(),
     };
 
     let result = metadata_extractor.extract_metadata(metadata_input).await?;
@@ -125,7 +132,9 @@ async fn test_metadata_extraction_error_handling() -> Result<()> {
         use_cache: false,
         validate_external: false,
         extract_references: false,
+        extract_captions: false,
         batch_files: None,
+        output_format: MetadataOutputFormat::default(),
     };
 
     let result = metadata_extractor.extract_metadata(metadata_input).await?;
@@ -157,7 +166,10 @@ async fn test_url_resolution_errors() -> Result<()> {
         directory: None,
         category: None,
         overwrite: false,
-        verify_integrity: false,
+        verify_integrity: Some(false),
+        title: None,
+        write_sidecar: None,
+        headers: None,
     };
 
     let result = download_tool.download_paper(download_input).await;
@@ -191,7 +203,10 @@ async fn test_provider_failure_messages() -> Result<()> {
         directory: None,
         category: None,
         overwrite: false,
-        verify_integrity: false,
+        verify_integrity: Some(false),
+        title: None,
+        write_sidecar: None,
+        headers: None,
     };
 
     let result = download_tool.download_paper(download_input).await;
@@ -236,7 +251,10 @@ async fn test_concurrent_downloads() -> Result<()> {
                 directory: None,
                 category: None,
                 overwrite: false,
-                verify_integrity: false,
+                verify_integrity: Some(false),
+                title: None,
+                write_sidecar: None,
+                headers: None,
             };
 
             tool_clone.download_paper(download_input).await
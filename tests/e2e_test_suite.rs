@@ -1,16 +1,20 @@
+// Provider metadata tests still cover the deprecated `supports_full_text`
+#![allow(deprecated)]
+
 use rmcp::ServerHandler;
 use rust_research_mcp::{
     client::{
         providers::{
-            ArxivProvider, BiorxivProvider, CoreProvider, CrossRefProvider, SearchQuery,
-            SearchType, SemanticScholarProvider, SourceProvider, SsrnProvider, UnpaywallProvider,
+            ArxivProvider, BiorxivProvider, CoreProvider, CrossRefProvider, ProviderTier,
+            SearchQuery, SearchType, SemanticScholarProvider, SourceProvider, SsrnProvider,
+            UnpaywallProvider,
         },
         MetaSearchClient, MetaSearchConfig,
     },
     server::ResearchServerHandler,
     tools::{
         download::DownloadInput as ActualDownloadInput,
-        metadata::MetadataInput as ActualMetadataInput,
+        metadata::{MetadataInput as ActualMetadataInput, MetadataOutputFormat},
         search::{SearchInput as ActualSearchInput, SearchType as ToolSearchType},
     },
     Config, DownloadTool, MetadataExtractor, SearchTool,
@@ -47,6 +51,8 @@ fn create_search_context() -> rust_research_mcp::client::providers::SearchContex
         user_agent: "test-client".to_string(),
         rate_limit: None,
         headers: HashMap::new(),
+        max_response_bytes: None,
+        default_charset: None,
     }
 }
 
@@ -59,6 +65,7 @@ async fn test_arxiv_provider() {
     assert_eq!(provider.name(), "arxiv");
     assert!(provider.supports_full_text());
     assert_eq!(provider.priority(), 80);
+    assert_eq!(provider.tier(), ProviderTier::FullTextOA);
 
     // Test search types
     let supported = provider.supported_search_types();
@@ -97,6 +104,7 @@ async fn test_crossref_provider() {
     assert_eq!(provider.name(), "crossref");
     assert!(!provider.supports_full_text()); // CrossRef doesn't provide PDFs
     assert_eq!(provider.priority(), 90); // High priority
+    assert_eq!(provider.tier(), ProviderTier::Authoritative);
 
     // Test DOI search
     let query = create_search_query("10.1038/nature12373", SearchType::Doi);
@@ -126,6 +134,7 @@ async fn test_semantic_scholar_provider() {
     assert_eq!(provider.name(), "semantic_scholar");
     assert!(provider.supports_full_text());
     assert_eq!(provider.priority(), 88);
+    assert_eq!(provider.tier(), ProviderTier::FullTextOA);
 
     // Test title search
     let query = create_search_query("deep learning", SearchType::Title);
@@ -158,6 +167,7 @@ async fn test_unpaywall_provider() {
     assert_eq!(provider.name(), "unpaywall");
     assert!(provider.supports_full_text());
     assert_eq!(provider.priority(), 87);
+    assert_eq!(provider.tier(), ProviderTier::FullTextOA);
 
     // Unpaywall only supports DOI search
     let supported = provider.supported_search_types();
@@ -191,6 +201,7 @@ async fn test_core_provider() {
     assert_eq!(provider.name(), "core");
     assert!(provider.supports_full_text());
     assert_eq!(provider.priority(), 86);
+    assert_eq!(provider.tier(), ProviderTier::FullTextOA);
 
     // Test keyword search
     let query = create_search_query("machine learning", SearchType::Keywords);
@@ -216,6 +227,7 @@ async fn test_biorxiv_provider() {
     assert_eq!(provider.name(), "biorxiv");
     assert!(provider.supports_full_text());
     assert_eq!(provider.priority(), 75);
+    assert_eq!(provider.tier(), ProviderTier::FullTextOA);
 
     // bioRxiv has limited search capabilities
     let query = create_search_query("covid", SearchType::Keywords);
@@ -244,6 +256,7 @@ async fn test_ssrn_provider() {
     assert_eq!(provider.name(), "ssrn");
     assert!(provider.supports_full_text());
     assert_eq!(provider.priority(), 85);
+    assert_eq!(provider.tier(), ProviderTier::FullTextOA);
 
     // Test SSRN DOI extraction
     let _query = create_search_query("10.2139/ssrn.1234567", SearchType::Doi);
@@ -317,6 +330,13 @@ async fn test_search_tool() {
         search_type: ToolSearchType::Auto,
         limit: 1,
         offset: 0,
+        require_pdf: false,
+        verify: false,
+        min_year: None,
+        abstract_required: false,
+        venue: None,
+        year: None,
+        abstract_keywords: Vec::new(),
     };
 
     let result = search_tool.search_papers(input).await;
@@ -362,7 +382,10 @@ async fn test_download_tool() {
         directory: None,
         category: None,
         overwrite: false,
-        verify_integrity: false,
+        verify_integrity: Some(false),
+        title: None,
+        write_sidecar: None,
+        headers: None,
     };
 
     let result = download_tool.download_paper(invalid_input).await;
@@ -376,7 +399,10 @@ async fn test_download_tool() {
         directory: None,
         category: None,
         overwrite: false,
-        verify_integrity: false,
+        verify_integrity: Some(false),
+        title: None,
+        write_sidecar: None,
+        headers: None,
     };
 
     let result = download_tool.download_paper(both_input).await;
@@ -394,7 +420,9 @@ async fn test_metadata_extractor() {
         use_cache: false,
         validate_external: false,
         extract_references: false,
+        extract_captions: false,
         batch_files: None,
+        output_format: MetadataOutputFormat::default(),
     };
 
     let result = extractor.extract_metadata(input).await;
@@ -558,6 +586,13 @@ async fn test_error_handling() {
         search_type: ToolSearchType::Auto,
         limit: 10,
         offset: 0,
+        require_pdf: false,
+        verify: false,
+        min_year: None,
+        abstract_required: false,
+        venue: None,
+        year: None,
+        abstract_keywords: Vec::new(),
     };
 
     let result = search_tool.search_papers(empty_input).await;
@@ -581,6 +616,13 @@ async fn test_error_handling() {
         search_type: ToolSearchType::Auto,
         limit: 0, // Invalid
         offset: 0,
+        require_pdf: false,
+        verify: false,
+        min_year: None,
+        abstract_required: false,
+        venue: None,
+        year: None,
+        abstract_keywords: Vec::new(),
     };
 
     let result = search_tool.search_papers(invalid_limit).await;
@@ -605,6 +647,13 @@ mod integration_tests {
             search_type: ToolSearchType::Doi,
             limit: 1,
             offset: 0,
+            require_pdf: false,
+            verify: false,
+            min_year: None,
+            abstract_required: false,
+            venue: None,
+            year: None,
+            abstract_keywords: Vec::new(),
         };
 
         let search_result = search_tool.search_papers(search_input).await;
@@ -630,7 +679,10 @@ mod integration_tests {
                     directory: Some("/tmp".to_string()),
                     category: None,
                     overwrite: true,
-                    verify_integrity: false,
+                    verify_integrity: Some(false),
+                    title: None,
+                    write_sidecar: None,
+                    headers: None,
                 };
 
                 let download_result = download_tool.download_paper(download_input).await;
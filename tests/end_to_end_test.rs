@@ -1,6 +1,6 @@
 use futures::future;
 use rust_research_mcp::tools::download::{DownloadInput, DownloadTool};
-use rust_research_mcp::tools::metadata::{MetadataExtractor, MetadataInput};
+use rust_research_mcp::tools::metadata::{MetadataExtractor, MetadataInput, MetadataOutputFormat};
 use rust_research_mcp::tools::search::{SearchInput, SearchTool, SearchType};
 use rust_research_mcp::{Config, MetaSearchClient, MetaSearchConfig, Server};
 use std::sync::Arc;
@@ -62,6 +62,13 @@ async fn test_complete_paper_search_workflow() {
         search_type: SearchType::Title,
         limit: 10,
         offset: 0,
+        require_pdf: false,
+        verify: false,
+        min_year: None,
+        abstract_required: false,
+        venue: None,
+        year: None,
+        abstract_keywords: Vec::new(),
     };
     let search_result = search_tool.search_papers(search_input).await;
     assert!(search_result.is_ok(), "Title search should succeed");
@@ -83,7 +90,10 @@ async fn test_complete_paper_search_workflow() {
         directory: None,
         category: None,
         overwrite: false,
-        verify_integrity: false,
+        verify_integrity: Some(false),
+        title: None,
+        write_sidecar: None,
+        headers: None,
     };
     let download_result = download_tool.download_paper(download_input).await;
     // Note: This might fail with mock server as we don't have actual PDF URLs
@@ -98,7 +108,9 @@ async fn test_complete_paper_search_workflow() {
         use_cache: false,
         validate_external: false,
         extract_references: false,
+        extract_captions: false,
         batch_files: None,
+        output_format: MetadataOutputFormat::default(),
     };
     let metadata_result = metadata_extractor.extract_metadata(metadata_input).await;
     // This should succeed even if it can't extract much from a dummy file
@@ -175,6 +187,13 @@ async fn test_error_recovery_workflow() {
         search_type: SearchType::Doi,
         limit: 10,
         offset: 0,
+        require_pdf: false,
+        verify: false,
+        min_year: None,
+        abstract_required: false,
+        venue: None,
+        year: None,
+        abstract_keywords: Vec::new(),
     };
     let result = search_tool.search_papers(search_input).await;
     // This might fail due to retry logic, but that's expected behavior
@@ -219,6 +238,13 @@ async fn test_concurrent_operations_scenario() {
                 search_type: SearchType::Doi,
                 limit: 10,
                 offset: 0,
+                require_pdf: false,
+                verify: false,
+                min_year: None,
+                abstract_required: false,
+                venue: None,
+                year: None,
+                abstract_keywords: Vec::new(),
             };
             search_tool_clone.search_papers(search_input).await
         });
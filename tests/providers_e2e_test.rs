@@ -1,6 +1,9 @@
+// Provider metadata tests still cover the deprecated `supports_full_text`
+#![allow(deprecated)]
+
 use rust_research_mcp::client::providers::{
-    ArxivProvider, CoreProvider, CrossRefProvider, OpenAlexProvider, SciHubProvider, SearchContext,
-    SearchQuery, SearchType, SemanticScholarProvider, SourceProvider, SsrnProvider,
+    ArxivProvider, CoreProvider, CrossRefProvider, OpenAlexProvider, ProviderTier, SciHubProvider,
+    SearchContext, SearchQuery, SearchType, SemanticScholarProvider, SourceProvider, SsrnProvider,
     UnpaywallProvider,
 };
 use std::collections::HashMap;
@@ -36,6 +39,8 @@ fn create_test_context() -> SearchContext {
         user_agent: "rust_research_mcp-test/0.2.1".to_string(),
         rate_limit: Some(Duration::from_millis(500)),
         headers: HashMap::new(),
+        max_response_bytes: None,
+        default_charset: None,
     }
 }
 
@@ -84,6 +89,7 @@ async fn test_arxiv_provider_creation() {
     let provider = provider.unwrap();
     assert_eq!(provider.name(), "arxiv");
     assert_eq!(provider.priority(), 80);
+    assert_eq!(provider.tier(), ProviderTier::FullTextOA);
     assert!(provider.supports_full_text());
 }
 
@@ -178,6 +184,7 @@ async fn test_openalex_provider_creation() {
     let provider = provider.unwrap();
     assert_eq!(provider.name(), "openalex");
     assert_eq!(provider.priority(), 180);
+    assert_eq!(provider.tier(), ProviderTier::Authoritative);
     assert!(provider.supports_full_text());
 }
 
@@ -309,6 +316,7 @@ async fn test_crossref_provider_creation() {
     let provider = provider.unwrap();
     assert_eq!(provider.name(), "crossref");
     assert_eq!(provider.priority(), 90);
+    assert_eq!(provider.tier(), ProviderTier::Authoritative);
     assert!(!provider.supports_full_text()); // CrossRef usually doesn't provide PDFs
 }
 
@@ -379,6 +387,7 @@ async fn test_ssrn_provider_creation() {
     let provider = provider.unwrap();
     assert_eq!(provider.name(), "ssrn");
     assert_eq!(provider.priority(), 85);
+    assert_eq!(provider.tier(), ProviderTier::FullTextOA);
     assert!(provider.supports_full_text());
 }
 
@@ -444,6 +453,7 @@ async fn test_semantic_scholar_provider_creation() {
     let provider = provider.unwrap();
     assert_eq!(provider.name(), "semantic_scholar");
     assert_eq!(provider.priority(), 88);
+    assert_eq!(provider.tier(), ProviderTier::FullTextOA);
     assert!(provider.supports_full_text());
 }
 
@@ -549,6 +559,7 @@ async fn test_unpaywall_provider_creation() {
     let provider = provider.unwrap();
     assert_eq!(provider.name(), "unpaywall");
     assert_eq!(provider.priority(), 87);
+    assert_eq!(provider.tier(), ProviderTier::FullTextOA);
     assert!(provider.supports_full_text());
 }
 
@@ -640,6 +651,7 @@ async fn test_core_provider_creation() {
     let provider = provider.unwrap();
     assert_eq!(provider.name(), "core");
     assert_eq!(provider.priority(), 86);
+    assert_eq!(provider.tier(), ProviderTier::FullTextOA);
     assert!(provider.supports_full_text());
 }
 
@@ -739,6 +751,7 @@ async fn test_scihub_provider_creation() {
     let provider = provider.unwrap();
     assert_eq!(provider.name(), "sci_hub");
     assert_eq!(provider.priority(), 10); // Lowest priority
+    assert_eq!(provider.tier(), ProviderTier::FullTextGray);
     assert!(provider.supports_full_text());
 }
 
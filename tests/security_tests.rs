@@ -30,6 +30,13 @@ async fn test_sql_injection_attempts() {
             search_type: SearchType::Doi,
             limit: 10,
             offset: 0,
+            require_pdf: false,
+            verify: false,
+            min_year: None,
+            abstract_required: false,
+            venue: None,
+            year: None,
+            abstract_keywords: Vec::new(),
         };
         let result = search_tool.search_papers(search_input).await;
         // Should fail validation or return empty results, not crash
@@ -69,6 +76,13 @@ async fn test_xss_injection_attempts() {
             search_type: SearchType::Title,
             limit: 10,
             offset: 0,
+            require_pdf: false,
+            verify: false,
+            min_year: None,
+            abstract_required: false,
+            venue: None,
+            year: None,
+            abstract_keywords: Vec::new(),
         };
         let result = search_tool.search_papers(search_input).await;
         // Should not execute any scripts, should be properly escaped/validated
@@ -112,7 +126,10 @@ async fn test_path_traversal_attempts() {
             directory: None,
             category: None,
             overwrite: false,
-            verify_integrity: false,
+            verify_integrity: Some(false),
+            title: None,
+            write_sidecar: None,
+            headers: None,
         };
         let result = download_tool.download_paper(download_input).await;
         // Should fail validation due to invalid filename
@@ -153,6 +170,13 @@ async fn test_large_input_dos_attempts() {
         search_type: SearchType::Title,
         limit: 10,
         offset: 0,
+        require_pdf: false,
+        verify: false,
+        min_year: None,
+        abstract_required: false,
+        venue: None,
+        year: None,
+        abstract_keywords: Vec::new(),
     };
     let result = search_tool.search_papers(search_input).await;
     assert!(result.is_err(), "Extremely large query should be rejected");
@@ -164,6 +188,13 @@ async fn test_large_input_dos_attempts() {
         search_type: SearchType::Doi,
         limit: 10,
         offset: 0,
+        require_pdf: false,
+        verify: false,
+        min_year: None,
+        abstract_required: false,
+        venue: None,
+        year: None,
+        abstract_keywords: Vec::new(),
     };
     let result = search_tool.search_papers(search_input).await;
     assert!(result.is_err(), "Extremely long DOI should be rejected");
@@ -196,6 +227,13 @@ async fn test_null_byte_injection() {
             search_type: SearchType::Doi,
             limit: 10,
             offset: 0,
+            require_pdf: false,
+            verify: false,
+            min_year: None,
+            abstract_required: false,
+            venue: None,
+            year: None,
+            abstract_keywords: Vec::new(),
         };
         let search_result = search_tool.search_papers(search_input).await;
         if search_result.is_ok() {
@@ -215,7 +253,10 @@ async fn test_null_byte_injection() {
             directory: None,
             category: None,
             overwrite: false,
-            verify_integrity: false,
+            verify_integrity: Some(false),
+            title: None,
+            write_sidecar: None,
+            headers: None,
         };
         let download_result = download_tool.download_paper(download_input).await;
         assert!(
@@ -256,6 +297,13 @@ async fn test_command_injection_attempts() {
             search_type: SearchType::Title,
             limit: 10,
             offset: 0,
+            require_pdf: false,
+            verify: false,
+            min_year: None,
+            abstract_required: false,
+            venue: None,
+            year: None,
+            abstract_keywords: Vec::new(),
         };
         let search_result = search_tool.search_papers(search_input).await;
         if search_result.is_ok() {
@@ -275,7 +323,10 @@ async fn test_command_injection_attempts() {
             directory: None,
             category: None,
             overwrite: false,
-            verify_integrity: false,
+            verify_integrity: Some(false),
+            title: None,
+            write_sidecar: None,
+            headers: None,
         };
         let download_result = download_tool.download_paper(download_input).await;
         assert!(
@@ -333,6 +384,13 @@ async fn test_unicode_handling() {
             search_type: SearchType::Title,
             limit: 10,
             offset: 0,
+            require_pdf: false,
+            verify: false,
+            min_year: None,
+            abstract_required: false,
+            venue: None,
+            year: None,
+            abstract_keywords: Vec::new(),
         };
         let _search_result = search_tool.search_papers(search_input).await;
         // Should not crash, may return empty results or error
@@ -345,7 +403,10 @@ async fn test_unicode_handling() {
             directory: None,
             category: None,
             overwrite: false,
-            verify_integrity: false,
+            verify_integrity: Some(false),
+            title: None,
+            write_sidecar: None,
+            headers: None,
         };
         let download_result = download_tool.download_paper(download_input).await;
         // Should either succeed with sanitized filename or fail validation
@@ -395,7 +456,10 @@ async fn test_memory_exhaustion_protection() {
         directory: None,
         category: None,
         overwrite: false,
-        verify_integrity: false,
+        verify_integrity: Some(false),
+        title: None,
+        write_sidecar: None,
+        headers: None,
     };
     let result = download_tool.download_paper(download_input).await;
     // Should either fail early with size check or handle gracefully